@@ -4,11 +4,11 @@
 //! Optionally uses `--output-schema` for structured JSON output.
 
 use super::{
-    extract_json, get_schema_file_path, parse_rate_limit_info, wait_with_timeout, AgentBackend,
-    BackendError, BackendResult, RawMarker,
+    extract_json, get_schema_file_path, parse_rate_limit_info, wait_with_timeout,
+    wait_with_timeout_streaming, AgentBackend, BackendError, BackendResult, RawMarker,
 };
 use crate::analyzer::TokenBudget;
-use std::process::{Command, Stdio};
+use std::process::{Child, Command, Stdio};
 use std::time::Duration;
 
 /// Backend for Codex CLI.
@@ -38,18 +38,9 @@ impl CodexBackend {
     fn command() -> &'static str {
         "codex"
     }
-}
-
-impl AgentBackend for CodexBackend {
-    fn name(&self) -> &'static str {
-        "Codex"
-    }
 
-    fn is_available(&self) -> bool {
-        super::command_exists(Self::command())
-    }
-
-    fn invoke(&self, prompt: &str, timeout: Duration, use_schema: bool) -> BackendResult<String> {
+    /// Spawn the codex process and write the prompt to its stdin.
+    fn spawn(&self, prompt: &str, use_schema: bool) -> BackendResult<Child> {
         if !self.is_available() {
             return Err(BackendError::NotAvailable(
                 "codex CLI not found in PATH".to_string(),
@@ -94,9 +85,14 @@ impl AgentBackend for CodexBackend {
             // stdin is dropped here, closing the pipe
         }
 
-        // Wait with timeout
-        let result = wait_with_timeout(&mut child, timeout.as_secs());
+        Ok(child)
+    }
 
+    /// Map the process output (or wait error) to a backend result.
+    fn handle_output(
+        result: std::io::Result<std::process::Output>,
+        timeout: Duration,
+    ) -> BackendResult<String> {
         match result {
             Ok(output) => {
                 let stdout = String::from_utf8_lossy(&output.stdout).to_string();
@@ -123,6 +119,34 @@ impl AgentBackend for CodexBackend {
             Err(e) => Err(BackendError::Io(e)),
         }
     }
+}
+
+impl AgentBackend for CodexBackend {
+    fn name(&self) -> &'static str {
+        "Codex"
+    }
+
+    fn is_available(&self) -> bool {
+        super::command_exists(Self::command())
+    }
+
+    fn invoke(&self, prompt: &str, timeout: Duration, use_schema: bool) -> BackendResult<String> {
+        let mut child = self.spawn(prompt, use_schema)?;
+        let result = wait_with_timeout(&mut child, timeout.as_secs());
+        Self::handle_output(result, timeout)
+    }
+
+    fn invoke_streaming(
+        &self,
+        prompt: &str,
+        timeout: Duration,
+        use_schema: bool,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> BackendResult<String> {
+        let mut child = self.spawn(prompt, use_schema)?;
+        let result = wait_with_timeout_streaming(&mut child, timeout.as_secs(), on_chunk);
+        Self::handle_output(result, timeout)
+    }
 
     fn parse_response(&self, response: &str) -> BackendResult<Vec<RawMarker>> {
         let analysis = extract_json(response)?;
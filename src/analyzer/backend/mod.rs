@@ -1,13 +1,14 @@
 //! Agent backend implementations for LLM analysis.
 //!
 //! This module provides the Strategy pattern for different AI agent backends.
-//! Each backend knows how to invoke its CLI and parse responses.
+//! Each backend knows how to invoke its CLI (or API) and parse responses.
 //!
 //! # Supported Agents
 //!
 //! - **Claude**: `claude --print --output-format json --json-schema --tools ""`
 //! - **Codex**: `codex exec --output-schema` (structured JSON output)
 //! - **Gemini**: `gemini --output-format json --approval-mode plan`
+//! - **OpenAI**: direct HTTP calls to the Chat Completions API (`OPENAI_API_KEY`)
 //!
 //! # Design
 //!
@@ -17,10 +18,14 @@
 mod claude;
 mod codex;
 mod gemini;
+mod openai;
+mod stream;
 
 pub use claude::ClaudeBackend;
 pub use codex::CodexBackend;
 pub use gemini::GeminiBackend;
+pub use openai::OpenAiBackend;
+pub use stream::MarkerStreamParser;
 
 use crate::analyzer::chunk::TokenBudget;
 use serde::{Deserialize, Serialize};
@@ -119,6 +124,102 @@ pub(crate) fn wait_with_timeout(
     }
 }
 
+/// Wait for child process with timeout, passing stdout lines to `on_line` as they arrive.
+///
+/// Like [`wait_with_timeout`], but stdout is read line by line on a background
+/// thread and forwarded to the caller's thread, so the callback does not need
+/// to be `Send`. The returned output still contains the complete stdout.
+pub(crate) fn wait_with_timeout_streaming(
+    child: &mut std::process::Child,
+    timeout_secs: u64,
+    on_line: &mut dyn FnMut(&str),
+) -> std::io::Result<std::process::Output> {
+    use std::io::{BufRead, BufReader, Read};
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::thread;
+    use std::time::Instant;
+
+    let (tx, rx) = mpsc::channel::<Vec<u8>>();
+    let stdout_handle = child.stdout.take().map(|pipe| {
+        thread::spawn(move || {
+            let mut reader = BufReader::new(pipe);
+            loop {
+                let mut line = Vec::new();
+                match reader.read_until(b'\n', &mut line) {
+                    Ok(0) | Err(_) => break,
+                    Ok(_) => {
+                        if tx.send(line).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        })
+    });
+
+    let stderr_handle = child.stderr.take().map(|pipe| {
+        thread::spawn(move || {
+            let mut buf = Vec::new();
+            let mut pipe = pipe;
+            pipe.read_to_end(&mut buf).ok();
+            buf
+        })
+    });
+
+    let timeout = Duration::from_secs(timeout_secs);
+    let start = Instant::now();
+    let poll_interval = Duration::from_millis(100);
+    let mut stdout = Vec::new();
+    let mut stdout_open = stdout_handle.is_some();
+
+    loop {
+        let remaining = match timeout.checked_sub(start.elapsed()) {
+            Some(remaining) if !remaining.is_zero() => remaining,
+            _ => {
+                // Kill and reap to prevent zombie process
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "Process timed out",
+                ));
+            }
+        };
+
+        if stdout_open {
+            match rx.recv_timeout(remaining.min(poll_interval)) {
+                Ok(line) => {
+                    on_line(&String::from_utf8_lossy(&line));
+                    stdout.extend_from_slice(&line);
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => stdout_open = false,
+            }
+            continue;
+        }
+
+        // Stdout closed — wait for the process to exit
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                if let Some(handle) = stdout_handle {
+                    let _ = handle.join();
+                }
+                let stderr = stderr_handle
+                    .and_then(|h| h.join().ok())
+                    .unwrap_or_default();
+
+                return Ok(std::process::Output {
+                    status,
+                    stdout,
+                    stderr,
+                });
+            }
+            Ok(None) => thread::sleep(remaining.min(poll_interval)),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 /// Result type for agent backend operations.
 pub type BackendResult<T> = Result<T, BackendError>;
 
@@ -146,6 +247,26 @@ pub trait AgentBackend: Send + Sync {
     /// The raw response string from the agent CLI.
     fn invoke(&self, prompt: &str, timeout: Duration, use_schema: bool) -> BackendResult<String>;
 
+    /// Invoke the agent, passing partial output to `on_chunk` as it arrives.
+    ///
+    /// Backends that cannot stream use this default, which calls
+    /// [`invoke`](Self::invoke) and passes the whole response as one chunk.
+    ///
+    /// # Returns
+    ///
+    /// The complete raw response, as `invoke` would return it.
+    fn invoke_streaming(
+        &self,
+        prompt: &str,
+        timeout: Duration,
+        use_schema: bool,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> BackendResult<String> {
+        let response = self.invoke(prompt, timeout, use_schema)?;
+        on_chunk(&response);
+        Ok(response)
+    }
+
     /// Parse raw response into markers.
     ///
     /// Handles JSON extraction and validation. For agents without
@@ -162,15 +283,25 @@ pub enum AgentType {
     Claude,
     Codex,
     Gemini,
+    OpenAi,
 }
 
 impl AgentType {
+    /// Every supported agent, in the order they are listed to users.
+    pub const ALL: [AgentType; 4] = [
+        AgentType::Claude,
+        AgentType::Codex,
+        AgentType::Gemini,
+        AgentType::OpenAi,
+    ];
+
     /// Create the appropriate backend for this agent type.
     pub fn create_backend(&self, extra_args: Vec<String>) -> Box<dyn AgentBackend> {
         match self {
             AgentType::Claude => Box::new(ClaudeBackend::with_extra_args(extra_args)),
             AgentType::Codex => Box::new(CodexBackend::with_extra_args(extra_args)),
             AgentType::Gemini => Box::new(GeminiBackend::with_extra_args(extra_args)),
+            AgentType::OpenAi => Box::new(OpenAiBackend::with_extra_args(extra_args)),
         }
     }
 
@@ -180,6 +311,7 @@ impl AgentType {
             AgentType::Claude => "claude",
             AgentType::Codex => "codex",
             AgentType::Gemini => "gemini",
+            AgentType::OpenAi => "openai",
         }
     }
 
@@ -189,6 +321,7 @@ impl AgentType {
             AgentType::Claude => TokenBudget::claude(),
             AgentType::Codex => TokenBudget::codex(),
             AgentType::Gemini => TokenBudget::gemini(),
+            AgentType::OpenAi => TokenBudget::openai(),
         }
     }
 }
@@ -199,6 +332,7 @@ impl std::fmt::Display for AgentType {
             AgentType::Claude => write!(f, "Claude"),
             AgentType::Codex => write!(f, "Codex"),
             AgentType::Gemini => write!(f, "Gemini"),
+            AgentType::OpenAi => write!(f, "OpenAI"),
         }
     }
 }
@@ -215,6 +349,9 @@ pub enum BackendError {
     #[error("Exit code {code}: {}", truncate_stderr(stderr))]
     ExitCode { code: i32, stderr: String },
 
+    #[error("HTTP {status}: {}", truncate_stderr(body))]
+    Http { status: u16, body: String },
+
     #[error("Rate limited: {0}")]
     RateLimited(RateLimitInfo),
 
@@ -247,7 +384,43 @@ impl std::fmt::Display for RateLimitInfo {
     }
 }
 
+/// Stderr fragments that mark a failed agent run as worth retrying.
+const TRANSIENT_STDERR_PATTERNS: &[&str] = &[
+    "overloaded",
+    "temporarily unavailable",
+    "service unavailable",
+    "internal server error",
+    "bad gateway",
+    "connection reset",
+    "connection refused",
+    "network error",
+    "timed out",
+    "try again",
+];
+
 impl BackendError {
+    /// Whether the failure is likely to go away on retry.
+    ///
+    /// Rate limits, timeouts, 408/5xx HTTP statuses, and exit codes whose
+    /// stderr looks like an outage or network hiccup are transient. Missing
+    /// agents and unparseable responses are not, so they fail fast.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            BackendError::RateLimited(_) | BackendError::Timeout(_) => true,
+            BackendError::Http { status, .. } => *status == 408 || *status >= 500,
+            BackendError::ExitCode { stderr, .. } => {
+                let stderr = stderr.to_lowercase();
+                TRANSIENT_STDERR_PATTERNS
+                    .iter()
+                    .any(|pattern| stderr.contains(pattern))
+            }
+            BackendError::NotAvailable(_)
+            | BackendError::JsonParse(_)
+            | BackendError::JsonExtraction { .. }
+            | BackendError::Io(_) => false,
+        }
+    }
+
     /// Extract wait duration for retry logic.
     ///
     /// Uses agent-provided retry_after if available, otherwise falls back
@@ -531,6 +704,41 @@ fn truncate_stderr(stderr: &str) -> String {
     }
 }
 
+/// Prompt sent by [`check_health`]; cheap for every agent to answer.
+pub const HEALTH_CHECK_PROMPT: &str = "Reply with the single word OK.";
+
+/// Whether an agent can be used for analysis, as found by [`check_health`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AgentHealth {
+    /// The CLI is not installed (or, for API backends, no key is set)
+    Unavailable,
+    /// Installed, but the test prompt failed (e.g. not logged in)
+    NoResponse(String),
+    /// Installed and answered the test prompt
+    Ready,
+}
+
+impl AgentHealth {
+    /// Whether the agent is usable for analysis.
+    pub fn is_ready(&self) -> bool {
+        matches!(self, AgentHealth::Ready)
+    }
+}
+
+/// Check that `backend` is installed and answers a trivial prompt.
+///
+/// The prompt is sent without schema enforcement; any successful response
+/// counts, since the point is to confirm the agent runs and is authorized.
+pub fn check_health(backend: &dyn AgentBackend, timeout: Duration) -> AgentHealth {
+    if !backend.is_available() {
+        return AgentHealth::Unavailable;
+    }
+    match backend.invoke(HEALTH_CHECK_PROMPT, timeout, false) {
+        Ok(_) => AgentHealth::Ready,
+        Err(e) => AgentHealth::NoResponse(e.to_string()),
+    }
+}
+
 /// Check if a command is available in PATH.
 ///
 /// Uses platform-specific command lookup:
@@ -549,6 +757,23 @@ pub fn command_exists(command: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Find the model named in per-agent extra args.
+///
+/// Accepts `--model <name>`, `--model=<name>` and `-m <name>`, which covers
+/// the flags of all supported CLIs.
+pub(crate) fn model_from_args(args: &[String]) -> Option<String> {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--model" || arg == "-m" {
+            return iter.next().cloned();
+        }
+        if let Some(model) = arg.strip_prefix("--model=") {
+            return Some(model.to_string());
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -797,6 +1022,55 @@ Done."#;
         );
     }
 
+    #[test]
+    fn model_from_args_accepts_all_flag_forms() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            model_from_args(&args(&["--model", "opus"])).as_deref(),
+            Some("opus")
+        );
+        assert_eq!(
+            model_from_args(&args(&["--model=gpt-4o"])).as_deref(),
+            Some("gpt-4o")
+        );
+        assert_eq!(
+            model_from_args(&args(&["--yolo", "-m", "gemini-2.5-flash"])).as_deref(),
+            Some("gemini-2.5-flash")
+        );
+        assert_eq!(model_from_args(&args(&["--verbose"])), None);
+    }
+
+    #[test]
+    fn backend_error_is_transient() {
+        assert!(BackendError::Timeout(Duration::from_secs(60)).is_transient());
+        assert!(BackendError::Http {
+            status: 503,
+            body: String::new()
+        }
+        .is_transient());
+        assert!(BackendError::ExitCode {
+            code: 1,
+            stderr: "API Error: Overloaded".to_string()
+        }
+        .is_transient());
+
+        assert!(!BackendError::ExitCode {
+            code: 1,
+            stderr: "unknown flag --foo".to_string()
+        }
+        .is_transient());
+        assert!(!BackendError::Http {
+            status: 401,
+            body: String::new()
+        }
+        .is_transient());
+        assert!(!BackendError::JsonExtraction {
+            response: String::new()
+        }
+        .is_transient());
+        assert!(!BackendError::NotAvailable("claude".to_string()).is_transient());
+    }
+
     #[test]
     fn backend_error_wait_duration_rate_limited_no_retry_after() {
         let err = BackendError::RateLimited(RateLimitInfo {
@@ -880,6 +1154,7 @@ Done."#;
         assert_eq!(AgentType::Claude.command_name(), "claude");
         assert_eq!(AgentType::Codex.command_name(), "codex");
         assert_eq!(AgentType::Gemini.command_name(), "gemini");
+        assert_eq!(AgentType::OpenAi.command_name(), "openai");
     }
 
     #[test]
@@ -895,5 +1170,125 @@ Done."#;
         let _ = AgentType::Claude.create_backend(vec![]);
         let _ = AgentType::Codex.create_backend(vec![]);
         let _ = AgentType::Gemini.create_backend(vec![]);
+        let _ = AgentType::OpenAi.create_backend(vec![]);
+    }
+
+    // ============================================
+    // Streaming Tests
+    // ============================================
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_with_timeout_streaming_forwards_lines() {
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "printf 'one\\ntwo\\n'"])
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut lines = Vec::new();
+        let output =
+            wait_with_timeout_streaming(&mut child, 5, &mut |line| lines.push(line.to_string()))
+                .unwrap();
+
+        assert_eq!(lines, vec!["one\n", "two\n"]);
+        assert_eq!(output.stdout, b"one\ntwo\n");
+        assert!(output.status.success());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_with_timeout_streaming_times_out() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let result = wait_with_timeout_streaming(&mut child, 1, &mut |_| {});
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::TimedOut);
+    }
+
+    // ============================================
+    // Health Check Tests
+    // ============================================
+
+    /// Backend with a fixed availability and reply.
+    struct StubBackend {
+        available: bool,
+        reply: fn() -> BackendResult<String>,
+    }
+
+    impl AgentBackend for StubBackend {
+        fn name(&self) -> &'static str {
+            "stub"
+        }
+
+        fn is_available(&self) -> bool {
+            self.available
+        }
+
+        fn invoke(
+            &self,
+            prompt: &str,
+            _timeout: Duration,
+            use_schema: bool,
+        ) -> BackendResult<String> {
+            assert_eq!(prompt, HEALTH_CHECK_PROMPT);
+            assert!(!use_schema);
+            (self.reply)()
+        }
+
+        fn parse_response(&self, _response: &str) -> BackendResult<Vec<RawMarker>> {
+            Ok(Vec::new())
+        }
+
+        fn token_budget(&self) -> TokenBudget {
+            TokenBudget::claude()
+        }
+    }
+
+    #[test]
+    fn check_health_reports_missing_agent_without_invoking() {
+        let backend = StubBackend {
+            available: false,
+            reply: || panic!("must not invoke an unavailable agent"),
+        };
+        assert_eq!(
+            check_health(&backend, Duration::from_secs(1)),
+            AgentHealth::Unavailable
+        );
+    }
+
+    #[test]
+    fn check_health_reports_ready_on_any_reply() {
+        let backend = StubBackend {
+            available: true,
+            reply: || Ok("OK".to_string()),
+        };
+        let health = check_health(&backend, Duration::from_secs(1));
+        assert_eq!(health, AgentHealth::Ready);
+        assert!(health.is_ready());
+    }
+
+    #[test]
+    fn check_health_reports_invoke_errors() {
+        let backend = StubBackend {
+            available: true,
+            reply: || {
+                Err(BackendError::ExitCode {
+                    code: 1,
+                    stderr: "not logged in".to_string(),
+                })
+            },
+        };
+        let health = check_health(&backend, Duration::from_secs(1));
+        assert_eq!(
+            health,
+            AgentHealth::NoResponse("Exit code 1: not logged in".to_string())
+        );
+        assert!(!health.is_ready());
     }
 }
@@ -0,0 +1,336 @@
+//! OpenAI backend implementation.
+//!
+//! Talks to the OpenAI Chat Completions endpoint directly over HTTP instead
+//! of shelling out to a CLI. Authenticates with `OPENAI_API_KEY`.
+
+use super::{
+    extract_json, model_from_args, parse_rate_limit_info, AgentBackend, BackendError,
+    BackendResult, RateLimitInfo, RawMarker, MARKER_JSON_SCHEMA,
+};
+use crate::analyzer::TokenBudget;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Environment variable holding the API key.
+const API_KEY_VAR: &str = "OPENAI_API_KEY";
+
+/// Default API host.
+const DEFAULT_BASE_URL: &str = "https://api.openai.com";
+
+/// Default model when none is configured.
+const DEFAULT_MODEL: &str = "gpt-4o-mini";
+
+/// Backend for the OpenAI Chat Completions HTTP API.
+///
+/// The model can be chosen per agent in the config with
+/// `extra_args = ["--model", "gpt-4o"]`; other extra args are ignored.
+#[derive(Debug, Clone)]
+pub struct OpenAiBackend {
+    /// Model name sent with each request.
+    model: String,
+    /// API host, without the `/v1/...` path.
+    base_url: String,
+}
+
+impl Default for OpenAiBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenAiBackend {
+    /// Create a new OpenAI backend using the default model.
+    pub fn new() -> Self {
+        Self {
+            model: DEFAULT_MODEL.to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    /// Create a new OpenAI backend, taking the model from `--model` in extra args.
+    pub fn with_extra_args(extra_args: Vec<String>) -> Self {
+        let mut backend = Self::new();
+        if let Some(model) = model_from_args(&extra_args) {
+            backend.model = model;
+        }
+        backend
+    }
+
+    /// Use a different model.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Use a different API host (e.g. a proxy or compatible server).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Get the configured model name.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Read the API key from the environment, ignoring empty values.
+    fn api_key() -> Option<String> {
+        std::env::var(API_KEY_VAR)
+            .ok()
+            .filter(|k| !k.trim().is_empty())
+    }
+
+    /// Build the request body, using structured outputs when a schema is
+    /// requested and plain JSON mode otherwise.
+    fn request_body(&self, prompt: &str, use_schema: bool) -> Value {
+        let response_format = if use_schema {
+            let schema: Value =
+                serde_json::from_str(MARKER_JSON_SCHEMA).expect("marker schema is valid JSON");
+            json!({
+                "type": "json_schema",
+                "json_schema": { "name": "markers", "strict": true, "schema": schema },
+            })
+        } else {
+            json!({ "type": "json_object" })
+        };
+
+        json!({
+            "model": self.model,
+            "messages": [{ "role": "user", "content": prompt }],
+            "response_format": response_format,
+        })
+    }
+
+    /// POST the prompt and return the assistant message content.
+    ///
+    /// The timeout covers the whole request, so no polling is needed.
+    fn send(
+        &self,
+        api_key: &str,
+        prompt: &str,
+        timeout: Duration,
+        use_schema: bool,
+    ) -> BackendResult<String> {
+        let url = format!("{}/v1/chat/completions", self.base_url);
+        let result = ureq::post(&url)
+            .timeout(timeout)
+            .set("Authorization", &format!("Bearer {}", api_key))
+            .send_json(self.request_body(prompt, use_schema));
+
+        match result {
+            Ok(response) => {
+                let body: Value = response.into_json()?;
+                extract_message_content(&body).ok_or_else(|| BackendError::JsonExtraction {
+                    response: body.to_string(),
+                })
+            }
+            Err(ureq::Error::Status(429, response)) => {
+                Err(BackendError::RateLimited(rate_limit_info(&response)))
+            }
+            Err(ureq::Error::Status(status, response)) => Err(BackendError::Http {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(transport)) => {
+                let io_error = std::error::Error::source(&transport)
+                    .and_then(|source| source.downcast_ref::<std::io::Error>());
+                match io_error.map(std::io::Error::kind) {
+                    Some(std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock) => {
+                        Err(BackendError::Timeout(timeout))
+                    }
+                    _ => Err(BackendError::Io(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        transport.to_string(),
+                    ))),
+                }
+            }
+        }
+    }
+}
+
+impl AgentBackend for OpenAiBackend {
+    fn name(&self) -> &'static str {
+        "OpenAI"
+    }
+
+    fn is_available(&self) -> bool {
+        Self::api_key().is_some()
+    }
+
+    fn invoke(&self, prompt: &str, timeout: Duration, use_schema: bool) -> BackendResult<String> {
+        let Some(api_key) = Self::api_key() else {
+            return Err(BackendError::NotAvailable(format!(
+                "{} is not set",
+                API_KEY_VAR
+            )));
+        };
+        self.send(&api_key, prompt, timeout, use_schema)
+    }
+
+    fn parse_response(&self, response: &str) -> BackendResult<Vec<RawMarker>> {
+        let analysis = extract_json(response)?;
+        Ok(analysis.markers)
+    }
+
+    fn token_budget(&self) -> TokenBudget {
+        TokenBudget::openai()
+    }
+}
+
+/// Extract `choices[0].message.content` from a Chat Completions response.
+fn extract_message_content(body: &Value) -> Option<String> {
+    body.pointer("/choices/0/message/content")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Build rate limit info from a 429 response.
+///
+/// Only a `Retry-After` value in whole seconds is used; the HTTP-date form
+/// is ignored so the caller's fallback backoff applies.
+fn rate_limit_info(response: &ureq::Response) -> RateLimitInfo {
+    let mut message = "HTTP 429 Too Many Requests".to_string();
+    if let Some(secs) = response
+        .header("Retry-After")
+        .and_then(|v| v.trim().parse::<u64>().ok())
+    {
+        message.push_str(&format!(": retry after {} seconds", secs));
+    }
+
+    parse_rate_limit_info(&message).unwrap_or(RateLimitInfo {
+        retry_after: None,
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Serve a single canned HTTP response on a local port and return its base URL.
+    fn serve_once(status: &'static str, headers: &'static str, body: &'static str) -> String {
+        let response = format!(
+            "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            headers,
+            body.len(),
+            body
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 65536];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    #[test]
+    fn openai_backend_name() {
+        assert_eq!(OpenAiBackend::new().name(), "OpenAI");
+    }
+
+    #[test]
+    fn openai_backend_token_budget() {
+        let budget = OpenAiBackend::new().token_budget();
+        assert_eq!(budget.max_input_tokens, 128_000);
+    }
+
+    #[test]
+    fn openai_backend_model_from_extra_args() {
+        let backend = OpenAiBackend::with_extra_args(vec!["--model".into(), "gpt-4o".into()]);
+        assert_eq!(backend.model(), "gpt-4o");
+
+        let backend = OpenAiBackend::with_extra_args(vec!["--model=gpt-4.1".into()]);
+        assert_eq!(backend.model(), "gpt-4.1");
+
+        let backend = OpenAiBackend::with_extra_args(vec!["--verbose".into()]);
+        assert_eq!(backend.model(), DEFAULT_MODEL);
+    }
+
+    #[test]
+    fn openai_backend_request_body_uses_json_mode() {
+        let backend = OpenAiBackend::new();
+
+        let body = backend.request_body("hi", false);
+        assert_eq!(body["response_format"]["type"], "json_object");
+        assert_eq!(body["messages"][0]["content"], "hi");
+
+        let body = backend.request_body("hi", true);
+        assert_eq!(body["response_format"]["type"], "json_schema");
+        assert_eq!(
+            body["response_format"]["json_schema"]["schema"]["required"][0],
+            "markers"
+        );
+    }
+
+    #[test]
+    fn openai_backend_extracts_message_content() {
+        let body = json!({
+            "choices": [{ "message": { "role": "assistant", "content": "{\"markers\": []}" } }]
+        });
+        assert_eq!(
+            extract_message_content(&body).as_deref(),
+            Some("{\"markers\": []}")
+        );
+        assert_eq!(extract_message_content(&json!({ "choices": [] })), None);
+    }
+
+    #[test]
+    fn openai_backend_send_returns_message_content() {
+        let base_url = serve_once(
+            "200 OK",
+            "Content-Type: application/json\r\n",
+            r#"{"choices":[{"message":{"content":"{\"markers\":[]}"}}]}"#,
+        );
+        let backend = OpenAiBackend::new().with_base_url(base_url);
+
+        let content = backend
+            .send("test-key", "hi", Duration::from_secs(5), false)
+            .unwrap();
+        assert_eq!(content, r#"{"markers":[]}"#);
+    }
+
+    #[test]
+    fn openai_backend_maps_429_to_rate_limited() {
+        let base_url = serve_once("429 Too Many Requests", "Retry-After: 17\r\n", "");
+        let backend = OpenAiBackend::new().with_base_url(base_url);
+
+        match backend.send("test-key", "hi", Duration::from_secs(5), false) {
+            Err(BackendError::RateLimited(info)) => {
+                assert_eq!(info.retry_after, Some(Duration::from_secs(17)));
+            }
+            other => panic!("expected RateLimited, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn openai_backend_maps_other_status_to_http_error() {
+        let base_url = serve_once("401 Unauthorized", "", "bad api key");
+        let backend = OpenAiBackend::new().with_base_url(base_url);
+
+        match backend.send("test-key", "hi", Duration::from_secs(5), false) {
+            Err(BackendError::Http { status, body }) => {
+                assert_eq!(status, 401);
+                assert_eq!(body, "bad api key");
+            }
+            other => panic!("expected Http error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn openai_backend_parse_response() {
+        let backend = OpenAiBackend::new();
+        let response =
+            r#"{"markers": [{"timestamp": 3.0, "label": "Build passed", "category": "success"}]}"#;
+
+        let markers = backend.parse_response(response).unwrap();
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].label, "Build passed");
+    }
+}
@@ -0,0 +1,105 @@
+//! Incremental marker parsing for streamed agent output.
+//!
+//! Agents emit the `{"markers": [...]}` response a piece at a time. The
+//! parser scans the text as it arrives and yields each marker object as
+//! soon as its closing brace is seen, without waiting for the full document.
+
+use super::RawMarker;
+
+/// Incremental parser that extracts `RawMarker` objects from partial output.
+#[derive(Debug, Default)]
+pub struct MarkerStreamParser {
+    /// All text received so far
+    buffer: String,
+    /// Byte offset where scanning resumes
+    scanned: usize,
+    /// Byte offsets of currently open `{`
+    open_braces: Vec<usize>,
+    /// Whether the scanner is inside a JSON string
+    in_string: bool,
+    /// Whether the previous character was a backslash inside a string
+    escaped: bool,
+}
+
+impl MarkerStreamParser {
+    /// Create an empty parser.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a chunk of output and return markers completed by it.
+    pub fn push(&mut self, chunk: &str) -> Vec<RawMarker> {
+        self.buffer.push_str(chunk);
+        let mut markers = Vec::new();
+
+        for (offset, c) in self.buffer[self.scanned..].char_indices() {
+            let idx = self.scanned + offset;
+            if self.in_string {
+                match c {
+                    _ if self.escaped => self.escaped = false,
+                    '\\' => self.escaped = true,
+                    '"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            match c {
+                '"' => self.in_string = true,
+                '{' => self.open_braces.push(idx),
+                '}' => {
+                    if let Some(start) = self.open_braces.pop() {
+                        if let Ok(marker) =
+                            serde_json::from_str::<RawMarker>(&self.buffer[start..=idx])
+                        {
+                            markers.push(marker);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.scanned = self.buffer.len();
+        markers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yields_markers_as_objects_close() {
+        let mut parser = MarkerStreamParser::new();
+
+        assert!(parser
+            .push(r#"{"markers": [{"timestamp": 1.5, "lab"#)
+            .is_empty());
+
+        let markers = parser.push(r#"el": "Start", "category": "planning"}, {"timest"#);
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].label, "Start");
+
+        let markers = parser.push(r#"amp": 9.0, "label": "Done", "category": "success"}]}"#);
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].timestamp, 9.0);
+    }
+
+    #[test]
+    fn ignores_braces_inside_strings() {
+        let mut parser = MarkerStreamParser::new();
+        let markers = parser.push(
+            r#"{"markers": [{"timestamp": 2.0, "label": "Fix {x} \"}\"", "category": "failure"}]}"#,
+        );
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].label, r#"Fix {x} "}""#);
+    }
+
+    #[test]
+    fn skips_objects_that_are_not_markers() {
+        let mut parser = MarkerStreamParser::new();
+        let markers = parser.push(r#"Thinking... {"status": "ok"} no markers yet"#);
+        assert!(markers.is_empty());
+    }
+}
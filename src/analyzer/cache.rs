@@ -0,0 +1,351 @@
+//! Per-segment marker cache for incremental re-analysis.
+//!
+//! After an analysis run the markers found in each segment are stored in a
+//! sidecar file next to the recording (`<name>.cast.analysis`), keyed by the
+//! segment's content fingerprint. When the recording grows and is analyzed
+//! again, segments whose fingerprint is still present reuse their cached
+//! markers and only the changed segments are sent to the backend.
+//!
+//! Marker times are stored as offsets from their segment's start, so cached
+//! markers follow their segment if its time range shifts slightly.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::backend::MarkerCategory;
+use super::chunk::TimeRange;
+use super::result::ValidatedMarker;
+use super::types::AnalysisSegment;
+
+/// Extension appended to the recording file name for the sidecar.
+const CACHE_SUFFIX: &str = ".analysis";
+
+/// Sidecar format version; caches with another version are ignored.
+const CACHE_VERSION: u32 = 1;
+
+/// Get the sidecar path for a recording (e.g. `session.cast.analysis`).
+pub fn cache_path(cast_path: &Path) -> PathBuf {
+    let mut name = cast_path.as_os_str().to_os_string();
+    name.push(CACHE_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// A marker stored relative to the start of its segment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedMarker {
+    /// Seconds from the segment start
+    pub offset: f64,
+    /// Marker label with category prefix
+    pub label: String,
+    /// Engineering category
+    pub category: MarkerCategory,
+}
+
+/// Markers found in one analyzed segment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedSegment {
+    /// Content fingerprint (see [`AnalysisSegment::fingerprint`])
+    pub fingerprint: u64,
+    /// Markers that fell within the segment
+    pub markers: Vec<CachedMarker>,
+}
+
+/// Markers of a previous analysis run, grouped by segment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisCache {
+    version: u32,
+    /// Analyzed segments in recording order
+    pub segments: Vec<CachedSegment>,
+}
+
+/// Split of the current segments into reused and pending work.
+#[derive(Debug, Default)]
+pub struct CachePartition {
+    /// Markers rebuilt from cached segments, in segment order
+    pub reused_markers: Vec<ValidatedMarker>,
+    /// Indices of segments that must be analyzed
+    pub pending: Vec<usize>,
+    /// Number of segments served from the cache
+    pub reused_segments: usize,
+}
+
+impl AnalysisCache {
+    /// Build a cache from the markers of a completed run.
+    ///
+    /// Each marker is assigned to the last segment starting at or before it
+    /// (or the first segment if it precedes all of them). Segments that
+    /// overlap a failed chunk are left out so they are analyzed again.
+    pub fn from_markers(
+        segments: &[AnalysisSegment],
+        markers: &[ValidatedMarker],
+        failed_ranges: &[TimeRange],
+    ) -> Self {
+        let mut cached: Vec<CachedSegment> = segments
+            .iter()
+            .map(|s| CachedSegment {
+                fingerprint: s.fingerprint(),
+                markers: Vec::new(),
+            })
+            .collect();
+
+        for marker in markers {
+            let index = segments
+                .iter()
+                .rposition(|s| s.start_time <= marker.timestamp)
+                .unwrap_or(0);
+            if let (Some(entry), Some(segment)) = (cached.get_mut(index), segments.get(index)) {
+                entry.markers.push(CachedMarker {
+                    offset: marker.timestamp - segment.start_time,
+                    label: marker.label.clone(),
+                    category: marker.category,
+                });
+            }
+        }
+
+        let segments = cached
+            .into_iter()
+            .zip(segments)
+            .filter(|(_, s)| {
+                !failed_ranges
+                    .iter()
+                    .any(|r| s.start_time <= r.end && r.start <= s.end_time)
+            })
+            .map(|(c, _)| c)
+            .collect();
+
+        Self {
+            version: CACHE_VERSION,
+            segments,
+        }
+    }
+
+    /// Replace the cached markers, e.g. after the user curated them.
+    ///
+    /// Only segments that were already cached are kept, so segments of
+    /// failed chunks are still analyzed on the next run.
+    pub fn replace_markers(&mut self, segments: &[AnalysisSegment], markers: &[ValidatedMarker]) {
+        let mut previous: HashMap<u64, usize> = HashMap::new();
+        for entry in &self.segments {
+            *previous.entry(entry.fingerprint).or_default() += 1;
+        }
+        let mut rebuilt = Self::from_markers(segments, markers, &[]);
+        rebuilt
+            .segments
+            .retain(|entry| match previous.get_mut(&entry.fingerprint) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    true
+                }
+                _ => false,
+            });
+        self.segments = rebuilt.segments;
+    }
+
+    /// Decide which segments can reuse cached markers.
+    ///
+    /// Segments are matched by fingerprint in order, so repeated identical
+    /// segments each consume their own cache entry.
+    pub fn partition(&self, segments: &[AnalysisSegment]) -> CachePartition {
+        let mut by_fingerprint: HashMap<u64, VecDeque<&CachedSegment>> = HashMap::new();
+        for entry in &self.segments {
+            by_fingerprint
+                .entry(entry.fingerprint)
+                .or_default()
+                .push_back(entry);
+        }
+
+        let mut partition = CachePartition::default();
+        for (i, segment) in segments.iter().enumerate() {
+            let hit = by_fingerprint
+                .get_mut(&segment.fingerprint())
+                .and_then(VecDeque::pop_front);
+            match hit {
+                Some(entry) => {
+                    partition.reused_segments += 1;
+                    partition
+                        .reused_markers
+                        .extend(entry.markers.iter().map(|m| {
+                            ValidatedMarker::new(
+                                segment.start_time + m.offset,
+                                m.label.clone(),
+                                m.category,
+                            )
+                        }));
+                }
+                None => partition.pending.push(i),
+            }
+        }
+        partition
+    }
+
+    /// Load the cache for a recording.
+    ///
+    /// Returns `None` if there is no sidecar, it cannot be parsed, or it was
+    /// written by an incompatible version.
+    pub fn load(cast_path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(cache_path(cast_path)).ok()?;
+        let cache: Self = serde_json::from_str(&content).ok()?;
+        (cache.version == CACHE_VERSION).then_some(cache)
+    }
+
+    /// Save the cache next to a recording.
+    pub fn save(&self, cast_path: &Path) -> Result<()> {
+        let path = cache_path(cast_path);
+        let json = serde_json::to_string(self).context("Failed to serialize analysis cache")?;
+        fs::write(&path, json)
+            .with_context(|| format!("Failed to write analysis cache: {}", path.display()))
+    }
+
+    /// Remove the cache for a recording, if any.
+    pub fn clear(cast_path: &Path) -> Result<()> {
+        let path = cache_path(cast_path);
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e)
+                .with_context(|| format!("Failed to remove analysis cache: {}", path.display())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn segment(start_time: f64, end_time: f64, content: &str) -> AnalysisSegment {
+        AnalysisSegment {
+            start_time,
+            end_time,
+            content: content.to_string(),
+            estimated_tokens: 10,
+            event_range: (0, 1),
+        }
+    }
+
+    fn marker(timestamp: f64, label: &str) -> ValidatedMarker {
+        ValidatedMarker::new(timestamp, label.to_string(), MarkerCategory::Success)
+    }
+
+    #[test]
+    fn cache_path_appends_suffix() {
+        let path = cache_path(Path::new("/tmp/claude/session.cast"));
+        assert_eq!(path, PathBuf::from("/tmp/claude/session.cast.analysis"));
+    }
+
+    #[test]
+    fn from_markers_assigns_markers_to_segments() {
+        let segments = vec![segment(0.0, 4.0, "build"), segment(10.0, 14.0, "test")];
+        let markers = vec![marker(1.0, "built"), marker(12.5, "tests passed")];
+
+        let cache = AnalysisCache::from_markers(&segments, &markers, &[]);
+
+        assert_eq!(cache.segments.len(), 2);
+        assert_eq!(cache.segments[0].markers[0].offset, 1.0);
+        assert_eq!(cache.segments[1].markers[0].offset, 2.5);
+        assert_eq!(cache.segments[1].markers[0].label, "tests passed");
+    }
+
+    #[test]
+    fn from_markers_skips_segments_of_failed_chunks() {
+        let segments = vec![segment(0.0, 4.0, "build"), segment(10.0, 14.0, "test")];
+
+        let cache = AnalysisCache::from_markers(&segments, &[], &[TimeRange::new(9.0, 20.0)]);
+
+        assert_eq!(cache.segments.len(), 1);
+        assert_eq!(cache.segments[0].fingerprint, segments[0].fingerprint());
+    }
+
+    #[test]
+    fn partition_reuses_unchanged_segments() {
+        let old = vec![segment(0.0, 4.0, "build"), segment(10.0, 14.0, "test")];
+        let cache = AnalysisCache::from_markers(&old, &[marker(11.0, "tests passed")], &[]);
+
+        // Appended content shifted the test segment slightly and added a new one
+        let new = vec![
+            segment(0.0, 4.0, "build"),
+            segment(10.5, 14.5, "test"),
+            segment(30.0, 32.0, "deploy"),
+        ];
+        let partition = cache.partition(&new);
+
+        assert_eq!(partition.reused_segments, 2);
+        assert_eq!(partition.pending, vec![2]);
+        assert_eq!(partition.reused_markers.len(), 1);
+        assert_eq!(partition.reused_markers[0].timestamp, 11.5);
+    }
+
+    #[test]
+    fn partition_matches_repeated_segments_once_each() {
+        let old = vec![segment(0.0, 1.0, "ls")];
+        let cache = AnalysisCache::from_markers(&old, &[], &[]);
+
+        let partition = cache.partition(&[segment(0.0, 1.0, "ls"), segment(5.0, 6.0, "ls")]);
+
+        assert_eq!(partition.reused_segments, 1);
+        assert_eq!(partition.pending, vec![1]);
+    }
+
+    #[test]
+    fn replace_markers_keeps_only_cached_segments() {
+        let segments = vec![segment(0.0, 4.0, "build"), segment(10.0, 14.0, "test")];
+        let mut cache = AnalysisCache::from_markers(
+            &segments,
+            &[marker(1.0, "built"), marker(11.0, "tests passed")],
+            &[TimeRange::new(9.0, 20.0)],
+        );
+
+        cache.replace_markers(&segments, &[marker(2.0, "curated build")]);
+
+        assert_eq!(cache.segments.len(), 1);
+        assert_eq!(cache.segments[0].markers.len(), 1);
+        assert_eq!(cache.segments[0].markers[0].label, "curated build");
+    }
+
+    #[test]
+    fn save_then_load_roundtrips() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+        let cache = AnalysisCache::from_markers(
+            &[segment(0.0, 4.0, "build")],
+            &[marker(1.0, "built")],
+            &[],
+        );
+
+        cache.save(&cast).unwrap();
+
+        assert_eq!(AnalysisCache::load(&cast), Some(cache));
+    }
+
+    #[test]
+    fn load_ignores_garbage_and_other_versions() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+        assert_eq!(AnalysisCache::load(&cast), None);
+
+        fs::write(cache_path(&cast), "not json").unwrap();
+        assert_eq!(AnalysisCache::load(&cast), None);
+
+        fs::write(cache_path(&cast), r#"{"version": 99, "segments": []}"#).unwrap();
+        assert_eq!(AnalysisCache::load(&cast), None);
+    }
+
+    #[test]
+    fn clear_removes_sidecar_and_tolerates_missing() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+        AnalysisCache::from_markers(&[], &[], &[])
+            .save(&cast)
+            .unwrap();
+
+        AnalysisCache::clear(&cast).unwrap();
+        assert!(!cache_path(&cast).exists());
+
+        AnalysisCache::clear(&cast).unwrap();
+    }
+}
@@ -79,6 +79,16 @@ impl TokenBudget {
         }
     }
 
+    /// Create budget for the OpenAI API (128K context).
+    pub fn openai() -> Self {
+        Self {
+            max_input_tokens: 128_000,
+            reserved_for_prompt: 2_000,
+            reserved_for_output: 8_000,
+            safety_margin_pct: 0.15,
+        }
+    }
+
     /// Calculate tokens available for actual content.
     ///
     /// Subtracts reserved tokens and applies safety margin.
@@ -462,6 +472,7 @@ mod tests {
         assert_eq!(AgentType::Claude.token_budget().max_input_tokens, 100_000);
         assert_eq!(AgentType::Codex.token_budget().max_input_tokens, 192_000);
         assert_eq!(AgentType::Gemini.token_budget().max_input_tokens, 1_000_000);
+        assert_eq!(AgentType::OpenAi.token_budget().max_input_tokens, 128_000);
     }
 
     // ============================================
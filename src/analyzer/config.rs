@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::transforms::DEFAULT_COLLAPSE_FORMAT;
+
 /// Configuration for the content extraction pipeline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractionConfig {
@@ -25,14 +27,26 @@ pub struct ExtractionConfig {
     pub segment_time_gap: f64,
     /// Enable similarity-based line collapsing (targets redundant log lines)
     pub collapse_similar_lines: bool,
-    /// Similarity threshold (0.0 to 1.0) for collapsing lines
+    /// Similarity threshold (0.0 to 1.0) for collapsing lines.
+    ///
+    /// Consecutive lines are compared by word-level Levenshtein distance,
+    /// normalized by the longer line's word count, with digit runs masked so
+    /// changing counters don't count as a difference. The same threshold also
+    /// gates event coalescing.
     pub similarity_threshold: f64,
+    /// Template for a collapsed run of similar lines. `{line}` is the last
+    /// line of the run and `{count}` the number of lines it replaces.
+    #[serde(default = "default_similar_lines_format")]
+    pub similar_lines_format: String,
     /// Enable coalescing of rapid, similar events (targets TUI redrawing)
     pub coalesce_events: bool,
     /// Time threshold for event coalescing (seconds)
     pub coalesce_time_threshold: f64,
     /// Enable truncation of large output blocks
     pub truncate_large_blocks: bool,
+    /// Which part of an oversized block survives truncation
+    #[serde(default)]
+    pub truncation_mode: TruncationMode,
     /// Max times a specific line can repeat globally across the session
     pub max_line_repeats: usize,
     /// Window size for event hashing (number of events to check for redraws)
@@ -41,8 +55,50 @@ pub struct ExtractionConfig {
     pub max_burst_lines: usize,
     /// Maximum size of an output block before truncation (bytes)
     pub max_block_size: usize,
-    /// Number of lines to keep at head/tail during truncation
+    /// Number of lines to keep at each retained end (head and/or tail)
+    /// during truncation
     pub truncation_context_lines: usize,
+    /// Maximum number of rendered-line hashes the terminal transform keeps for
+    /// redraw deduplication, evicted oldest first.
+    ///
+    /// Each entry costs roughly 16 bytes (hash set plus eviction queue), so
+    /// the default of 50 000 stays under 1 MB. A larger cap catches redraws of
+    /// lines first seen long ago at the cost of memory; a smaller cap bounds
+    /// memory tightly but lets old lines through again once they are evicted.
+    #[serde(default = "default_max_story_hashes")]
+    pub max_story_hashes: usize,
+    /// Extra substrings that mark a rendered line as noise (e.g. your agent's
+    /// status line). Matched case-sensitively, in addition to the built-in
+    /// structural heuristics, which always apply.
+    #[serde(default)]
+    pub noise_substrings: Vec<String>,
+    /// Extra regular expressions that mark a rendered line as noise.
+    ///
+    /// Compiled once per extractor; invalid patterns are skipped with a warning.
+    #[serde(default)]
+    pub noise_patterns: Vec<String>,
+}
+
+/// Which part of an oversized output block [`ExtractionConfig::truncate_large_blocks`]
+/// keeps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TruncationMode {
+    /// Keep context from both the start and the end of the block
+    #[default]
+    HeadTail,
+    /// Keep only the end of the block (e.g. the result of a long dump)
+    TailOnly,
+    /// Keep only the start of the block
+    HeadOnly,
+}
+
+fn default_max_story_hashes() -> usize {
+    50_000
+}
+
+fn default_similar_lines_format() -> String {
+    DEFAULT_COLLAPSE_FORMAT.to_string()
 }
 
 impl Default for ExtractionConfig {
@@ -59,14 +115,19 @@ impl Default for ExtractionConfig {
             segment_time_gap: 2.0,
             collapse_similar_lines: true,
             similarity_threshold: 0.80,
+            similar_lines_format: default_similar_lines_format(),
             coalesce_events: true,
             coalesce_time_threshold: 0.2, // 200ms
             max_line_repeats: 10,
             event_window_size: 50,
             max_burst_lines: 500,
             truncate_large_blocks: true,
+            truncation_mode: TruncationMode::default(),
             max_block_size: 8 * 1024, // 8KB
             truncation_context_lines: 50,
+            max_story_hashes: default_max_story_hashes(),
+            noise_substrings: Vec::new(),
+            noise_patterns: Vec::new(),
         }
     }
 }
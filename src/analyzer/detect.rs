@@ -0,0 +1,190 @@
+//! Agent detection from recording metadata and output.
+//!
+//! [`detect_agent`] classifies a recording as one of the known agents by
+//! looking at the header first (the recorder stores the agent command and a
+//! `"<agent> session"` title) and falling back to characteristic output:
+//! banner phrases, status-line hints and agent-specific glyphs.
+//!
+//! The recorder names storage directories and the `{agent}` filename tag with
+//! [`recording_agent`], which goes through [`detect_agent`] as well, so the
+//! TUI agent filter (which lists those directories) and the tag always agree.
+
+use super::config::ExtractionConfig;
+use super::transforms::ContentCleaner;
+use crate::asciicast::{AsciicastFile, Header};
+
+/// Number of output events scanned for characteristic output.
+///
+/// Banners and the first status lines appear early, so there is no need to
+/// read the whole recording.
+const MAX_SCANNED_EVENTS: usize = 500;
+
+/// What identifies an agent in its terminal output.
+struct AgentSignature {
+    /// Agent name, as used for storage directories and `{agent}`
+    name: &'static str,
+    /// Phrases matched against ANSI-stripped output
+    phrases: &'static [&'static str],
+    /// Glyphs matched against raw output. Only glyphs no other tool draws
+    /// belong here; generic braille spinners would match any CLI.
+    glyphs: &'static [char],
+}
+
+const SIGNATURES: &[AgentSignature] = &[
+    AgentSignature {
+        name: "claude",
+        phrases: &["Claude Code", "claude.ai/code", "tokens · esc to interrupt"],
+        glyphs: &['✻', '✳', '✢', '✶', '✽'],
+    },
+    AgentSignature {
+        name: "codex",
+        phrases: &["OpenAI Codex", "codex-cli"],
+        glyphs: &[],
+    },
+    AgentSignature {
+        name: "gemini",
+        phrases: &["Gemini CLI", "GEMINI.md", "(esc to cancel"],
+        // The response bullet; its braille spinner is shared with many tools
+        glyphs: &['✦'],
+    },
+];
+
+/// Classify a recording as `claude`, `codex` or `gemini`.
+///
+/// The header `command` (its program name) and the first word of the
+/// `title` are checked first. Otherwise the first output events are
+/// searched for an agent's phrases, and then for its glyphs.
+/// The header `env` only captures `SHELL` and `TERM`, which say nothing
+/// about the agent. Returns `None` if nothing matches.
+pub fn detect_agent(cast: &AsciicastFile) -> Option<String> {
+    detect_from_header(&cast.header)
+        .or_else(|| detect_from_output(cast))
+        .map(str::to_string)
+}
+
+/// Agent name a recording of `agent` is stored and tagged under.
+///
+/// Runs [`detect_agent`] on the header the recorder writes (the command and
+/// a `"<agent> session"` title), so `agr record /usr/local/bin/claude` files
+/// the session under `claude` just like `agr record claude`. Commands that
+/// aren't a known agent keep their name.
+pub fn recording_agent(agent: &str) -> String {
+    let header = Header::builder()
+        .command(agent)
+        .title(format!("{} session", agent))
+        .build();
+    detect_agent(&AsciicastFile::new(header)).unwrap_or_else(|| agent.to_string())
+}
+
+/// Known agent whose name is the program name of `word`, if any.
+fn agent_named(word: &str) -> Option<&'static str> {
+    let program = word.rsplit('/').next().unwrap_or(word).to_lowercase();
+    SIGNATURES
+        .iter()
+        .map(|s| s.name)
+        .find(|name| *name == program)
+}
+
+fn detect_from_header(header: &Header) -> Option<&'static str> {
+    let first_word = |s: &Option<String>| {
+        s.as_deref()
+            .and_then(|s| s.split_whitespace().next())
+            .and_then(agent_named)
+    };
+    first_word(&header.command).or_else(|| first_word(&header.title))
+}
+
+fn detect_from_output(cast: &AsciicastFile) -> Option<&'static str> {
+    let mut cleaner = ContentCleaner::new(&ExtractionConfig::default());
+    let mut glyph_hits = vec![0usize; SIGNATURES.len()];
+
+    for event in cast
+        .events
+        .iter()
+        .filter(|e| e.is_output())
+        .take(MAX_SCANNED_EVENTS)
+    {
+        let text = cleaner.clean(&event.data);
+        if let Some(signature) = SIGNATURES
+            .iter()
+            .find(|s| s.phrases.iter().any(|p| text.contains(p)))
+        {
+            return Some(signature.name);
+        }
+        for (hits, signature) in glyph_hits.iter_mut().zip(SIGNATURES) {
+            *hits += event
+                .data
+                .chars()
+                .filter(|c| signature.glyphs.contains(c))
+                .count();
+        }
+    }
+
+    glyph_hits
+        .iter()
+        .zip(SIGNATURES)
+        .filter(|(hits, _)| **hits > 0)
+        .max_by_key(|(hits, _)| **hits)
+        .map(|(_, signature)| signature.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::Event;
+
+    fn cast_with(header: Header, outputs: &[&str]) -> AsciicastFile {
+        let mut cast = AsciicastFile::new(header);
+        cast.events = outputs.iter().map(|o| Event::output(0.1, *o)).collect();
+        cast
+    }
+
+    #[test]
+    fn header_command_wins() {
+        let header = Header::builder()
+            .command("/usr/local/bin/codex --full-auto")
+            .title("claude session")
+            .build();
+        let cast = cast_with(header, &["Welcome to Claude Code"]);
+        assert_eq!(detect_agent(&cast).as_deref(), Some("codex"));
+    }
+
+    #[test]
+    fn recorder_title_is_used_without_command() {
+        let cast = cast_with(Header::builder().title("Gemini session").build(), &[]);
+        assert_eq!(detect_agent(&cast).as_deref(), Some("gemini"));
+    }
+
+    #[test]
+    fn unknown_command_falls_back_to_output() {
+        let header = Header::builder().command("bash").build();
+        let cast = cast_with(header, &["\x1b[1m>_ OpenAI\x1b[0m Codex (v0.40.0)"]);
+        assert_eq!(detect_agent(&cast).as_deref(), Some("codex"));
+    }
+
+    #[test]
+    fn glyphs_are_a_last_resort() {
+        let cast = cast_with(Header::v3(80, 24), &["\r✻ Pondering", "\r✶ Pondering"]);
+        assert_eq!(detect_agent(&cast).as_deref(), Some("claude"));
+    }
+
+    #[test]
+    fn braille_spinner_alone_is_unknown() {
+        let cast = cast_with(Header::v3(80, 24), &["\r⠋ Installing", "\r⠙ Installing"]);
+        assert_eq!(detect_agent(&cast), None);
+    }
+
+    #[test]
+    fn recording_agent_normalizes_agent_commands() {
+        assert_eq!(recording_agent("claude"), "claude");
+        assert_eq!(recording_agent("/usr/local/bin/Codex"), "codex");
+        assert_eq!(recording_agent("aider"), "aider");
+    }
+
+    #[test]
+    fn plain_shell_session_is_unknown() {
+        let header = Header::builder().command("zsh").title("deploy").build();
+        let cast = cast_with(header, &["$ ls\r\n", "Cargo.toml src\r\n"]);
+        assert_eq!(detect_agent(&cast), None);
+    }
+}
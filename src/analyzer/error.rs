@@ -166,6 +166,7 @@ impl AnalysisError {
                     "claude" => AgentType::Claude,
                     "codex" => AgentType::Codex,
                     "gemini" => AgentType::Gemini,
+                    "openai" => AgentType::OpenAi,
                     _ => AgentType::Claude, // Default
                 };
                 AnalysisError::AgentNotAvailable { agent }
@@ -192,6 +193,10 @@ impl AnalysisError {
                 chunk_id,
                 reason: format!("Exit code {}: {}", code, truncate_response(stderr, 100)),
             },
+            BackendError::Http { status, body } => AnalysisError::ChunkFailed {
+                chunk_id,
+                reason: format!("HTTP {}: {}", status, truncate_response(body, 100)),
+            },
             BackendError::Io(e) => AnalysisError::ChunkFailed {
                 chunk_id,
                 reason: format!("IO error: {}", e),
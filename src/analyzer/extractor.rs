@@ -3,12 +3,12 @@
 //! The [`ContentExtractor`] coordinates the transform pipeline and creates
 //! [`AnalysisSegment`]s from cleaned events.
 
-use crate::asciicast::{Event, Transform};
+use crate::asciicast::{AsciicastFile, Event, Progress, Transform};
 
 use super::config::ExtractionConfig;
 use super::transforms::{
     BlockTruncator, ContentCleaner, EmptyLineFilter, EventCoalescer, FileDumpFilter,
-    FilterEmptyEvents, GlobalDeduplicator, NormalizeWhitespace, SimilarityFilter,
+    FilterEmptyEvents, GlobalDeduplicator, NoiseFilter, NormalizeWhitespace, SimilarityFilter,
     TerminalTransform, WindowedLineDeduplicator,
 };
 use super::types::{AnalysisContent, AnalysisSegment, ExtractionStats, TokenEstimator};
@@ -16,22 +16,50 @@ use super::types::{AnalysisContent, AnalysisSegment, ExtractionStats, TokenEstim
 /// Extracts analysis content from asciicast events using the transform pipeline.
 pub struct ContentExtractor {
     config: ExtractionConfig,
+    /// Noise filter compiled from the config's noise patterns
+    noise_filter: NoiseFilter,
 }
 
 impl ContentExtractor {
     /// Create a new content extractor with the given configuration.
     pub fn new(config: ExtractionConfig) -> Self {
-        Self { config }
+        let noise_filter = NoiseFilter::new(&config);
+        Self {
+            config,
+            noise_filter,
+        }
     }
 
     /// Extract analysis content from events.
     ///
     /// Applies the transform pipeline and creates segments from the cleaned events.
     pub fn extract(&self, events: &mut Vec<Event>, cols: usize, rows: usize) -> AnalysisContent {
+        self.extract_with_progress(events, cols, rows, None)
+    }
+
+    /// Extract analysis content from events, reporting progress.
+    ///
+    /// Behaves like [`extract`](Self::extract). `progress` receives
+    /// increasing fractions from 0.0 to 1.0, one after each pipeline stage.
+    pub fn extract_with_progress(
+        &self,
+        events: &mut Vec<Event>,
+        cols: usize,
+        rows: usize,
+        progress: Option<&mut dyn FnMut(f64)>,
+    ) -> AnalysisContent {
+        let mut progress = Progress::new(progress);
         let original_bytes: usize = events.iter().map(|e| e.data.len()).sum();
         let original_event_count = events.len();
 
-        let stats = self.apply_transforms(events, cols, rows, original_bytes, original_event_count);
+        let stats = self.apply_transforms(
+            events,
+            cols,
+            rows,
+            original_bytes,
+            original_event_count,
+            &mut progress,
+        );
 
         // Redistribute artificially concentrated time from the transform pipeline.
         // TerminalTransform accumulates time from filtered events and dumps it on the
@@ -40,10 +68,28 @@ impl ContentExtractor {
         Self::redistribute_time(events, self.config.segment_time_gap);
 
         // Create segments from events
-        self.create_segments(events, stats)
+        let content = self.create_segments(events, stats);
+        progress.report(1.0);
+        content
+    }
+
+    /// Extract analysis content from a whole recording, leaving it untouched.
+    ///
+    /// Returns the content together with a copy of its [`ExtractionStats`],
+    /// which break down how much each pipeline stage removed. Useful for
+    /// tuning [`ExtractionConfig`] against real recordings.
+    pub fn extract_with_stats(&self, cast: &AsciicastFile) -> (AnalysisContent, ExtractionStats) {
+        let mut events = cast.events.clone();
+        let (cols, rows) = cast.terminal_size();
+        let content = self.extract(&mut events, cols as usize, rows as usize);
+        let stats = content.stats.clone();
+        (content, stats)
     }
 
     /// Apply all configured cleaning and deduplication transforms.
+    ///
+    /// Progress is reported after each stage. Terminal rendering dominates
+    /// the run time, so it accounts for half of the total.
     fn apply_transforms(
         &self,
         events: &mut Vec<Event>,
@@ -51,47 +97,64 @@ impl ContentExtractor {
         rows: usize,
         original_bytes: usize,
         original_event_count: usize,
+        progress: &mut Progress<'_>,
     ) -> ExtractionStats {
         // 1. Terminal Rendering (Layout preservation, ANSI stripping, Redraw reduction)
-        let mut term_transform = TerminalTransform::new(cols, rows);
+        let mut term_transform = TerminalTransform::new(cols, rows, self.config.max_story_hashes)
+            .with_noise_filter(self.noise_filter.clone());
         term_transform.transform(events);
+        let terminal_bytes: usize = events.iter().map(|e| e.data.len()).sum();
+        progress.report(0.5);
 
         // 1b. Windowed Line Deduplication (Keeps ONLY the LAST version of status lines)
         let windowed_lines_deduped = self.apply_windowed_dedupe(events);
+        progress.report(0.55);
 
         // 1c. Basic Cleaning (Controls, Visual noise)
         let mut cleaner = ContentCleaner::new(&self.config);
         cleaner.transform(events);
+        progress.report(0.6);
 
         // 1d. Collapse consecutive empty lines
         EmptyLineFilter::new().transform(events);
+        progress.report(0.65);
 
         // 2. Event Coalescing (Rapid, similar events)
         let events_coalesced = self.apply_coalescing(events);
+        progress.report(0.7);
 
         // 3. Global Deduplication (Frequent lines & windowed event hashing)
         let (global_lines_deduped, window_events_deduped) = self.apply_global_dedupe(events);
+        progress.report(0.75);
 
         // 3b. File Dump Filtering (Long bursts of output)
         let bursts_collapsed = self.apply_file_dump_filter(events);
+        progress.report(0.8);
 
         // 5. Similarity Filtering (Consecutive redundant lines)
         let lines_collapsed = self.apply_similarity_filter(events);
+        progress.report(0.85);
 
         // 6. Large Block Truncation
         let blocks_truncated = self.apply_truncation(events);
+        progress.report(0.9);
 
         // 7. Final Normalization
         self.apply_normalization(events);
+        progress.report(0.95);
 
         // Calculate final stats
         let extracted_bytes: usize = events.iter().map(|e| e.data.len()).sum();
         ExtractionStats {
             original_bytes,
             extracted_bytes,
-            ansi_sequences_stripped: cleaner.ansi_stripped_count(),
+            terminal_bytes,
+            ansi_sequences_stripped: term_transform.ansi_sequences_rendered()
+                + cleaner.ansi_stripped_count(),
             control_chars_stripped: cleaner.control_stripped_count(),
             progress_lines_deduplicated: 0,
+            terminal_noise_lines: term_transform.noise_lines_filtered(),
+            terminal_lines_deduped: term_transform.redraw_lines_deduped(),
             events_coalesced,
             global_lines_deduped,
             windowed_lines_deduped,
@@ -101,6 +164,7 @@ impl ContentExtractor {
             bursts_collapsed,
             events_processed: original_event_count,
             events_retained: events.len(),
+            segments: 0,
         }
     }
 
@@ -138,7 +202,8 @@ impl ContentExtractor {
 
     fn apply_similarity_filter(&self, events: &mut Vec<Event>) -> usize {
         if self.config.collapse_similar_lines {
-            let mut sim_filter = SimilarityFilter::new(self.config.similarity_threshold);
+            let mut sim_filter = SimilarityFilter::new(self.config.similarity_threshold)
+                .with_format(self.config.similar_lines_format.clone());
             sim_filter.transform(events);
             sim_filter.collapsed_count()
         } else {
@@ -151,7 +216,8 @@ impl ContentExtractor {
             let mut truncator = BlockTruncator::new(
                 self.config.max_block_size,
                 self.config.truncation_context_lines,
-            );
+            )
+            .with_mode(self.config.truncation_mode);
             truncator.transform(events);
             truncator.truncated_count()
         } else {
@@ -221,7 +287,7 @@ impl ContentExtractor {
     ///
     /// Events in asciicast use relative timestamps (time since previous event).
     /// A new segment starts when an event's relative time exceeds the gap threshold.
-    fn create_segments(&self, events: &[Event], stats: ExtractionStats) -> AnalysisContent {
+    fn create_segments(&self, events: &[Event], mut stats: ExtractionStats) -> AnalysisContent {
         let estimator = TokenEstimator::default();
         let mut segments = Vec::new();
         let mut current_segment_start = 0;
@@ -270,6 +336,7 @@ impl ContentExtractor {
 
         let total_tokens = segments.iter().map(|s| s.estimated_tokens).sum();
         let total_duration = cumulative_time;
+        stats.segments = segments.len();
 
         AnalysisContent {
             segments,
@@ -314,6 +381,20 @@ mod tests {
         assert!(all_content.contains("after gap"));
     }
 
+    #[test]
+    fn extract_with_progress_reports_increasing_fractions() {
+        let extractor = ContentExtractor::default();
+        let mut events = vec![Event::output(0.1, "hello\n"), Event::output(5.0, "world\n")];
+        let mut seen = Vec::new();
+        let mut callback = |fraction| seen.push(fraction);
+
+        extractor.extract_with_progress(&mut events, 80, 24, Some(&mut callback));
+
+        assert!(seen.len() > 2);
+        assert!(seen.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(seen.last(), Some(&1.0));
+    }
+
     #[test]
     fn extractor_processes_ansi() {
         let extractor = ContentExtractor::default();
@@ -345,4 +426,46 @@ mod tests {
         assert!(content.total_tokens > 0);
         assert!(content.total_tokens < 100);
     }
+
+    #[test]
+    fn extract_with_stats_reports_each_stage() {
+        let content = r#"{"version":3,"term":{"cols":80,"rows":24}}
+[0.1,"o","\u001b[32m$ cargo build\u001b[0m\r\n"]
+[0.1,"o","Shimmying…\r\n"]
+[0.1,"o","Compiling agr\r\n"]
+[0.05,"o","Compiling agr\r\n"]
+[5.0,"o","\u001b[1mFinished\u001b[0m\r\n"]
+"#;
+        let cast = AsciicastFile::parse_str(content).unwrap();
+        let extractor = ContentExtractor::default();
+
+        let (analysis, stats) = extractor.extract_with_stats(&cast);
+
+        // The recording itself is left untouched
+        assert_eq!(cast.events.len(), 5);
+        assert_eq!(stats.events_processed, 5);
+        assert_eq!(
+            stats.original_bytes,
+            content
+                .lines()
+                .skip(1)
+                .map(|l| crate::asciicast::Event::from_json(l).unwrap().data.len())
+                .sum::<usize>()
+        );
+
+        // Terminal emulation: four SGR sequences rendered away, the spinner
+        // dropped as noise and the repeated "Compiling" line deduplicated
+        assert_eq!(stats.ansi_sequences_stripped, 4);
+        assert_eq!(stats.terminal_noise_lines, 1);
+        assert_eq!(stats.terminal_lines_deduped, 1);
+        assert!(stats.terminal_bytes < stats.original_bytes);
+        assert!(stats.extracted_bytes <= stats.terminal_bytes);
+
+        // Segment count matches what was actually produced
+        assert!(stats.segments > 0);
+        assert_eq!(stats.segments, analysis.segments.len());
+        assert_eq!(analysis.stats.segments, stats.segments);
+        assert!(analysis.text().contains("cargo build"));
+        assert!(analysis.text().contains("Finished"));
+    }
 }
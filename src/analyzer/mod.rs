@@ -21,16 +21,22 @@
 //! - [`types`] - Data structures for analysis content and segments
 //! - [`chunk`] - Token budget and chunking for parallel analysis
 //! - [`backend`] - Agent backend implementations (Strategy pattern)
+//! - [`cache`] - Per-segment marker cache for incremental re-analysis
+//! - [`detect_agent`] - Agent detection from recording metadata and output
 //! - [`worker`] - Parallel execution using Rayon
+//! - [`pricing`] - Per-model price table for cost estimates
 //! - [`progress`] - Progress reporting for analysis
 //! - [`result`] - Result aggregation and marker writing
 //! - [`service`] - AnalyzerService facade (main entry point)
 
 pub mod backend;
+pub mod cache;
 pub mod chunk;
 mod config;
+mod detect;
 pub mod error;
 mod extractor;
+pub mod pricing;
 pub mod progress;
 mod prompt;
 pub mod result;
@@ -42,24 +48,30 @@ pub mod worker;
 
 // Re-export main types from backend
 pub use backend::{
-    extract_json, AgentBackend, AgentType, AnalysisResponse, BackendError, BackendResult,
-    ClaudeBackend, CodexBackend, GeminiBackend, MarkerCategory, RateLimitInfo, RawMarker,
+    check_health, extract_json, AgentBackend, AgentHealth, AgentType, AnalysisResponse,
+    BackendError, BackendResult, ClaudeBackend, CodexBackend, GeminiBackend, MarkerCategory,
+    MarkerStreamParser, OpenAiBackend, RateLimitInfo, RawMarker,
 };
 
 // Re-export chunk types (AgentType moved to backend)
+pub use cache::AnalysisCache;
 pub use chunk::{AnalysisChunk, ChunkCalculator, ChunkConfig, TimeRange, TokenBudget};
 
 // Re-export other types
 pub use crate::config::{AgentAnalysisConfig, AnalysisConfig};
-pub use config::ExtractionConfig;
+pub use config::{ExtractionConfig, TruncationMode};
+pub use detect::{detect_agent, recording_agent};
 pub use extractor::ContentExtractor;
 pub use progress::DefaultProgressReporter;
 pub use transforms::{
     ContentCleaner, DeduplicateProgressLines, FilterEmptyEvents, NormalizeWhitespace,
 };
-pub use types::{AnalysisContent, AnalysisSegment, ExtractionStats, TokenEstimator};
+pub use types::{
+    to_csv, to_markdown, AnalysisContent, AnalysisSegment, ExtractionStats, TokenEstimator,
+};
 pub use worker::{
-    ChunkResult, ParallelExecutor, ProgressReporter, RetryExecutor, WorkerConfig, WorkerScaler,
+    ChunkResult, MarkerCallback, ParallelExecutor, ProgressReporter, RetryExecutor, WorkerConfig,
+    WorkerScaler,
 };
 
 // Re-export result types
@@ -72,8 +84,11 @@ pub use result::{
 pub use error::AnalysisError;
 
 // Re-export tracker types
-pub use tracker::{ChunkUsage, RetryCoordinator, RetryPolicy, TokenTracker, UsageSummary};
+pub use tracker::{
+    invoke_with_retry, retry_with_policy, ChunkUsage, RetryCoordinator, RetryPolicy, TokenTracker,
+    UsageSummary,
+};
 
 // Re-export service types (main entry point)
-pub use prompt::build_analyze_prompt;
-pub use service::{AnalysisResult, AnalyzeOptions, AnalyzerService};
+pub use prompt::{build_analyze_prompt, render_analyze_prompt};
+pub use service::{AnalysisEstimate, AnalysisResult, AnalyzeOptions, AnalyzerService};
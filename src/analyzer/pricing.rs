@@ -0,0 +1,116 @@
+//! Per-model price table for analysis cost estimates.
+//!
+//! Prices are list prices in USD per 1K tokens and only meant for rough
+//! "how big is this job" estimates before running `agr analyze`. Models are
+//! matched by substring of the `--model` extra arg, falling back to the
+//! agent's default model when no argument is given.
+
+use super::backend::AgentType;
+
+/// Price of one model in USD per 1K tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPrice {
+    /// Display name of the priced model
+    pub model: &'static str,
+    /// USD per 1K input tokens
+    pub input_per_1k: f64,
+    /// USD per 1K output tokens
+    pub output_per_1k: f64,
+}
+
+impl ModelPrice {
+    /// Estimated cost in USD for the given token counts.
+    pub fn cost(&self, input_tokens: usize, output_tokens: usize) -> f64 {
+        input_tokens as f64 / 1000.0 * self.input_per_1k
+            + output_tokens as f64 / 1000.0 * self.output_per_1k
+    }
+}
+
+const fn price(model: &'static str, input_per_1k: f64, output_per_1k: f64) -> ModelPrice {
+    ModelPrice {
+        model,
+        input_per_1k,
+        output_per_1k,
+    }
+}
+
+/// Price table per agent. The first entry is the agent's default model;
+/// more specific names must come before names they contain.
+const CLAUDE_PRICES: &[ModelPrice] = &[
+    price("sonnet", 0.003, 0.015),
+    price("opus", 0.015, 0.075),
+    price("haiku", 0.0008, 0.004),
+];
+
+const CODEX_PRICES: &[ModelPrice] = &[
+    price("gpt-5-codex", 0.00125, 0.01),
+    price("codex-mini", 0.00025, 0.002),
+    price("gpt-5", 0.00125, 0.01),
+];
+
+const GEMINI_PRICES: &[ModelPrice] = &[
+    price("gemini-2.5-pro", 0.00125, 0.01),
+    price("flash-lite", 0.0001, 0.0004),
+    price("flash", 0.0003, 0.0025),
+];
+
+const OPENAI_PRICES: &[ModelPrice] = &[
+    price("gpt-4o-mini", 0.00015, 0.0006),
+    price("gpt-4.1-mini", 0.0004, 0.0016),
+    price("gpt-4.1", 0.002, 0.008),
+    price("gpt-4o", 0.0025, 0.01),
+];
+
+/// Look up the price for an agent and optional model name.
+///
+/// Returns `None` when a model is given but not in the table, so callers
+/// can report the cost as unknown instead of guessing.
+pub fn lookup_price(agent: AgentType, model: Option<&str>) -> Option<ModelPrice> {
+    let table = match agent {
+        AgentType::Claude => CLAUDE_PRICES,
+        AgentType::Codex => CODEX_PRICES,
+        AgentType::Gemini => GEMINI_PRICES,
+        AgentType::OpenAi => OPENAI_PRICES,
+    };
+
+    match model {
+        None => table.first().copied(),
+        Some(model) => {
+            let model = model.to_lowercase();
+            table.iter().find(|p| model.contains(p.model)).copied()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_price_defaults_to_first_model() {
+        let price = lookup_price(AgentType::Claude, None).unwrap();
+        assert_eq!(price.model, "sonnet");
+    }
+
+    #[test]
+    fn lookup_price_matches_model_substring() {
+        let price = lookup_price(AgentType::Claude, Some("claude-opus-4-1")).unwrap();
+        assert_eq!(price.model, "opus");
+
+        // More specific names win over names they contain
+        let price = lookup_price(AgentType::OpenAi, Some("gpt-4o-mini-2024")).unwrap();
+        assert_eq!(price.model, "gpt-4o-mini");
+    }
+
+    #[test]
+    fn lookup_price_unknown_model() {
+        assert!(lookup_price(AgentType::Gemini, Some("some-local-model")).is_none());
+    }
+
+    #[test]
+    fn model_price_cost() {
+        let price = price("test", 0.003, 0.015);
+        let cost = price.cost(100_000, 2_000);
+        assert!((cost - 0.33).abs() < 1e-9);
+    }
+}
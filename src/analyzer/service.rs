@@ -8,33 +8,42 @@
 //! 1. Parse cast file
 //! 2. Check for existing markers (warn if present)
 //! 3. Extract content (Stage 1)
-//! 4. Calculate chunks (Stage 2)
-//! 5. Execute parallel analysis (Stage 3+4)
-//! 6. Aggregate results (Stage 5)
-//! 7. Write markers to file
-//! 8. Report summary (Stage 6)
-
+//! 4. Reuse cached markers of unchanged segments still in the file (incremental mode)
+//! 5. Calculate chunks (Stage 2)
+//! 6. Execute parallel analysis (Stage 3+4)
+//! 7. Aggregate results (Stage 5)
+//! 8. Write markers to file and update the cache
+//! 9. Report summary (Stage 6)
+
+use std::collections::HashMap;
 use std::path::Path;
 use std::time::Duration;
 
-use crate::asciicast::AsciicastFile;
+use crate::asciicast::{AsciicastFile, MarkerInfo, MarkerManager};
 
-use super::backend::{AgentBackend, AgentType};
-use super::chunk::{ChunkCalculator, ChunkConfig};
+use super::backend::{model_from_args, AgentBackend, AgentType};
+use super::cache::{AnalysisCache, CachePartition};
+use super::chunk::{ChunkCalculator, ChunkConfig, TimeRange};
 use super::config::ExtractionConfig;
 use super::error::AnalysisError;
 use super::extractor::ContentExtractor;
+use super::pricing::lookup_price;
 use super::progress::DefaultProgressReporter;
 use super::prompt::{
     build_analyze_prompt, build_curation_prompt, build_rename_prompt, extract_rename_response,
+    render_analyze_prompt,
 };
 use super::result::{MarkerWriter, ResultAggregator, ValidatedMarker, WriteReport};
-use super::tracker::UsageSummary;
-use super::worker::{ProgressReporter, RetryExecutor, WorkerConfig, WorkerScaler};
+use super::tracker::{RetryPolicy, UsageSummary};
+use super::types::{AnalysisContent, AnalysisSegment};
+use super::worker::{MarkerCallback, ProgressReporter, RetryExecutor, WorkerConfig, WorkerScaler};
 
 /// Default timeout for agent invocations in seconds.
 const DEFAULT_TIMEOUT_SECS: u64 = 120;
 
+/// Typical response size per chunk, used for cost estimates.
+const ESTIMATED_OUTPUT_TOKENS_PER_CHUNK: usize = 1_000;
+
 /// Configuration options for analysis.
 #[derive(Debug, Clone)]
 pub struct AnalyzeOptions {
@@ -62,6 +71,12 @@ pub struct AnalyzeOptions {
     pub rename_extra_args: Vec<String>,
     /// Override the token budget for chunk calculation
     pub token_budget_override: Option<usize>,
+    /// Custom analysis prompt template (None = built-in template)
+    pub prompt_template: Option<String>,
+    /// Retry policy for transient chunk failures
+    pub retry_policy: RetryPolicy,
+    /// Reuse cached markers for segments unchanged since the last run
+    pub incremental: bool,
 }
 
 impl Default for AnalyzeOptions {
@@ -79,6 +94,9 @@ impl Default for AnalyzeOptions {
             curate_extra_args: Vec::new(),
             rename_extra_args: Vec::new(),
             token_budget_override: None,
+            prompt_template: None,
+            retry_policy: RetryPolicy::default(),
+            incremental: false,
         }
     }
 }
@@ -157,6 +175,24 @@ impl AnalyzeOptions {
         self.token_budget_override = Some(budget);
         self
     }
+
+    /// Use a custom analysis prompt template instead of the built-in one.
+    pub fn prompt_template(mut self, template: String) -> Self {
+        self.prompt_template = Some(template);
+        self
+    }
+
+    /// Set the retry policy for transient chunk failures.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Enable incremental re-analysis using the per-segment marker cache.
+    pub fn incremental(mut self, enabled: bool) -> Self {
+        self.incremental = enabled;
+        self
+    }
 }
 
 /// Result of an analysis operation.
@@ -174,6 +210,8 @@ pub struct AnalysisResult {
     pub existing_marker_count: usize,
     /// Total duration of the recording in seconds
     pub total_duration: f64,
+    /// Number of segments whose markers came from the analysis cache
+    pub cached_segments: usize,
 }
 
 impl AnalysisResult {
@@ -193,6 +231,30 @@ impl AnalysisResult {
     }
 }
 
+/// Size and cost estimate for an analysis run, produced without calling
+/// any backend.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AnalysisEstimate {
+    /// Agent that would run the analysis
+    pub agent: String,
+    /// Priced model name (None if the configured model is not in the table)
+    pub model: Option<String>,
+    /// Number of content segments after extraction
+    pub segments: usize,
+    /// Estimated content tokens
+    pub content_tokens: usize,
+    /// Number of chunks the content would be split into
+    pub chunks: usize,
+    /// Number of parallel workers that would be used
+    pub workers: usize,
+    /// Estimated input tokens, including the prompt template per chunk
+    pub input_tokens: usize,
+    /// Estimated output tokens
+    pub output_tokens: usize,
+    /// Estimated cost in USD (None if the model price is unknown)
+    pub estimated_cost_usd: Option<f64>,
+}
+
 /// Main service for analyzing cast files.
 ///
 /// Facade pattern - coordinates all analysis components.
@@ -240,8 +302,98 @@ impl AnalyzerService {
     ///
     /// Analysis result with markers and statistics.
     pub fn analyze<P: AsRef<Path>>(&self, path: P) -> Result<AnalysisResult, AnalysisError> {
-        let path = path.as_ref();
+        self.run_analysis(path.as_ref(), None)
+    }
 
+    /// Analyze a cast file, reporting markers as soon as the agent emits them.
+    ///
+    /// `on_marker` is called from worker threads with the chunk time range and
+    /// the partial marker. The returned result still holds the final
+    /// validated and deduplicated markers.
+    pub fn analyze_streaming<P: AsRef<Path>>(
+        &self,
+        path: P,
+        on_marker: MarkerCallback<'_>,
+    ) -> Result<AnalysisResult, AnalysisError> {
+        self.run_analysis(path.as_ref(), Some(on_marker))
+    }
+
+    /// Extract the content the backend would receive for a cast file.
+    ///
+    /// Runs the full extraction pipeline without invoking the backend or
+    /// modifying the file. Unlike `analyze`, empty content is not an error.
+    pub fn extract_content<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<AnalysisContent, AnalysisError> {
+        let cast = AsciicastFile::parse(path.as_ref()).map_err(|e| AnalysisError::IoError {
+            operation: "reading cast file".to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(self.extract_cast_content(cast))
+    }
+
+    /// Extract the content the backend would receive for an in-memory cast.
+    ///
+    /// Like [`extract_content`](Self::extract_content), for recordings that
+    /// are not read from a file (e.g. piped through standard input).
+    pub fn extract_cast_content(&self, mut cast: AsciicastFile) -> AnalysisContent {
+        MarkerManager::clear_markers_from_cast(&mut cast);
+
+        let extractor = ContentExtractor::new(ExtractionConfig::default());
+        let (cols, rows) = cast.terminal_size();
+        extractor.extract(&mut cast.events, cols as usize, rows as usize)
+    }
+
+    /// Estimate the size and cost of analyzing a cast file.
+    ///
+    /// Runs extraction and chunking exactly like `analyze`, but never
+    /// invokes the backend and never modifies the file.
+    pub fn estimate<P: AsRef<Path>>(&self, path: P) -> Result<AnalysisEstimate, AnalysisError> {
+        self.estimate_content(self.extract_content(path)?)
+    }
+
+    /// Estimate the size and cost of analyzing an in-memory cast.
+    pub fn estimate_cast(&self, cast: AsciicastFile) -> Result<AnalysisEstimate, AnalysisError> {
+        self.estimate_content(self.extract_cast_content(cast))
+    }
+
+    /// Shared implementation of `estimate` and `estimate_cast`.
+    fn estimate_content(
+        &self,
+        content: AnalysisContent,
+    ) -> Result<AnalysisEstimate, AnalysisError> {
+        if content.total_tokens == 0 || content.segments.is_empty() {
+            return Err(AnalysisError::NoContent);
+        }
+
+        let chunks = self.chunk_calculator().calculate_chunks(&content);
+        let prompt_tokens = self.options.agent.token_budget().reserved_for_prompt;
+        let input_tokens = content.total_tokens + chunks.len() * prompt_tokens;
+        let output_tokens = chunks.len() * ESTIMATED_OUTPUT_TOKENS_PER_CHUNK;
+
+        let configured_model = model_from_args(&self.options.extra_args);
+        let price = lookup_price(self.options.agent, configured_model.as_deref());
+
+        Ok(AnalysisEstimate {
+            agent: self.options.agent.to_string(),
+            model: configured_model.or_else(|| price.map(|p| p.model.to_string())),
+            segments: content.segments.len(),
+            content_tokens: content.total_tokens,
+            chunks: chunks.len(),
+            workers: self.calculate_worker_count(chunks.len(), content.total_tokens),
+            input_tokens,
+            output_tokens,
+            estimated_cost_usd: price.map(|p| p.cost(input_tokens, output_tokens)),
+        })
+    }
+
+    /// Shared implementation of `analyze` and `analyze_streaming`.
+    fn run_analysis(
+        &self,
+        path: &Path,
+        on_marker: Option<MarkerCallback<'_>>,
+    ) -> Result<AnalysisResult, AnalysisError> {
         // 1. Parse cast file
         let mut cast = AsciicastFile::parse(path).map_err(|e| AnalysisError::IoError {
             operation: "reading cast file".to_string(),
@@ -257,6 +409,10 @@ impl AnalyzerService {
                 existing_marker_count
             );
         }
+        let existing_markers = MarkerManager::list_markers_from_cast(&cast).unwrap_or_default();
+        // Markers carry no terminal content but would otherwise absorb the
+        // time of filtered output and shift segment boundaries
+        MarkerManager::clear_markers_from_cast(&mut cast);
 
         // 3. Extract content (Stage 1)
         let config = ExtractionConfig::default();
@@ -287,7 +443,15 @@ impl AnalyzerService {
                 "──────────────────────────────────────────────────────────────────────────────"
             );
             eprintln!(
-                "  Redraw Cleanup:    {:>8} redraw frames coalesced",
+                "  Redraw Cleanup:    {:>8} redrawn lines dropped by terminal emulation",
+                stats.terminal_lines_deduped
+            );
+            eprintln!(
+                "                     {:>8} noise lines dropped by terminal emulation",
+                stats.terminal_noise_lines
+            );
+            eprintln!(
+                "                     {:>8} redraw frames coalesced",
                 stats.events_coalesced
             );
             eprintln!(
@@ -355,28 +519,29 @@ impl AnalyzerService {
             return Err(AnalysisError::NoContent);
         }
 
-        // 4. Calculate chunks (Stage 2)
-        let calculator = if let Some(budget_tokens) = self.options.token_budget_override {
-            if budget_tokens < 10000 {
-                eprintln!(
-                    "Warning: token_budget {} is below minimum (10000). Using default budget.",
-                    budget_tokens
-                );
-                ChunkCalculator::for_agent(self.options.agent)
-            } else {
-                // Use overridden token budget from per-agent config
-                let mut budget = self.options.agent.token_budget();
-                budget.max_input_tokens = budget_tokens;
-                ChunkCalculator::new(budget, ChunkConfig::default())
-            }
+        // 4. Reuse cached markers for segments unchanged since the last run.
+        // Cached markers the user removed from the file stay removed.
+        let mut partition = self.partition_segments(path, &content.segments);
+        partition.reused_markers = markers_in_file(partition.reused_markers, &existing_markers);
+        let pending = pending_content(&content, &partition.pending);
+        if partition.reused_segments > 0 && !self.options.quiet {
+            eprintln!(
+                "Reusing cached markers for {} of {} segment(s).",
+                partition.reused_segments,
+                content.segments.len()
+            );
+        }
+
+        // 5. Calculate chunks (Stage 2)
+        let chunks = if pending.segments.is_empty() {
+            Vec::new()
         } else {
-            ChunkCalculator::for_agent(self.options.agent)
+            self.chunk_calculator().calculate_chunks(&pending)
         };
-        let chunks = calculator.calculate_chunks(&content);
 
-        // 5. Execute analysis (Stage 3+4)
+        // 6. Execute analysis (Stage 3+4)
         let timeout = Duration::from_secs(self.options.timeout_secs);
-        let worker_count = self.calculate_worker_count(chunks.len(), content.total_tokens);
+        let worker_count = self.calculate_worker_count(chunks.len(), pending.total_tokens);
 
         // Return early if in debug output mode (after showing useful info)
         if save_debug_output {
@@ -384,7 +549,7 @@ impl AnalyzerService {
                 eprintln!(
                     "Analysis would use {} chunks, {} tokens, {} workers",
                     chunks.len(),
-                    content.total_tokens,
+                    pending.total_tokens,
                     worker_count
                 );
             }
@@ -395,6 +560,20 @@ impl AnalyzerService {
                 had_existing_markers,
                 existing_marker_count,
                 total_duration: content.total_duration,
+                cached_segments: partition.reused_segments,
+            });
+        }
+
+        // Every segment is cached and its markers are already in the file
+        if chunks.is_empty() {
+            return Ok(AnalysisResult {
+                markers: partition.reused_markers,
+                write_report: WriteReport::default(),
+                usage_summary: UsageSummary::default(),
+                had_existing_markers,
+                existing_marker_count,
+                total_duration: content.total_duration,
+                cached_segments: partition.reused_segments,
             });
         }
 
@@ -404,35 +583,65 @@ impl AnalyzerService {
         } else {
             DefaultProgressReporter::new(chunks.len())
         };
-        progress.start(chunks.len(), content.total_tokens);
+        progress.start(chunks.len(), pending.total_tokens);
 
         // Build prompt builder with template
         let total_duration = content.total_duration;
         let total_chunks = chunks.len();
+        let prompt_template = self.options.prompt_template.as_deref();
         let prompt_builder = |chunk: &super::chunk::AnalysisChunk| -> String {
-            build_analyze_prompt(chunk, total_duration, total_chunks)
+            match prompt_template {
+                Some(template) => {
+                    render_analyze_prompt(template, chunk, total_duration, total_chunks)
+                }
+                None => build_analyze_prompt(chunk, total_duration, total_chunks),
+            }
         };
 
         // Execute with retry
         // use_schema = true unless --fast flag was passed
         let use_schema = !self.options.fast;
         let worker_progress = ProgressReporter::new(chunks.len());
-        let executor = RetryExecutor::new(self.backend.as_ref(), timeout, worker_count, use_schema);
+        let mut executor =
+            RetryExecutor::new(self.backend.as_ref(), timeout, worker_count, use_schema)
+                .with_retry_policy(self.options.retry_policy.clone());
+        if let Some(on_marker) = on_marker {
+            executor = executor.with_marker_callback(on_marker);
+        }
         let (results, tracker) =
             executor.execute_with_retry(chunks.clone(), &worker_progress, prompt_builder);
 
-        // 6. Aggregate results (Stage 5)
+        // 7. Aggregate results (Stage 5)
         let aggregator = ResultAggregator::new(content.total_duration);
-        let (markers, agg_report) = aggregator.aggregate(results);
+        let (new_markers, agg_report) = aggregator.aggregate(results);
 
-        // 7. Write markers to file
-        let write_report =
-            MarkerWriter::write_markers(path, &markers).map_err(|e| AnalysisError::IoError {
+        // 8. Write new markers to file (reused ones are already there)
+        let write_report = MarkerWriter::write_markers(path, &new_markers).map_err(|e| {
+            AnalysisError::IoError {
                 operation: "writing markers".to_string(),
                 message: e.to_string(),
-            })?;
+            }
+        })?;
+
+        let mut markers = partition.reused_markers;
+        markers.extend(new_markers);
+        markers.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+
+        if self.options.incremental {
+            let failed_ranges: Vec<TimeRange> = chunks
+                .iter()
+                .filter(|c| agg_report.failed_chunks.contains(&c.id))
+                .map(|c| c.time_range.clone())
+                .collect();
+            let cache = AnalysisCache::from_markers(&content.segments, &markers, &failed_ranges);
+            if let Err(e) = cache.save(path) {
+                if !self.options.quiet {
+                    eprintln!("Warning: {}", e);
+                }
+            }
+        }
 
-        // 8. Report summary (Stage 6)
+        // 9. Report summary (Stage 6)
         let usage_summary = tracker.summary();
 
         if !self.options.quiet {
@@ -474,10 +683,49 @@ impl AnalyzerService {
             had_existing_markers,
             existing_marker_count,
             total_duration,
+            cached_segments: partition.reused_segments,
         })
     }
 
+    /// Split segments into those served from the cache and those to analyze.
+    ///
+    /// Without incremental mode or a usable cache every segment is pending.
+    fn partition_segments(&self, path: &Path, segments: &[AnalysisSegment]) -> CachePartition {
+        let cache = if self.options.incremental {
+            AnalysisCache::load(path)
+        } else {
+            None
+        };
+        match cache {
+            Some(cache) => cache.partition(segments),
+            None => CachePartition {
+                pending: (0..segments.len()).collect(),
+                ..CachePartition::default()
+            },
+        }
+    }
+
     /// Calculate worker count based on options and content.
+    /// Build the chunk calculator, honoring the per-agent token budget override.
+    fn chunk_calculator(&self) -> ChunkCalculator {
+        if let Some(budget_tokens) = self.options.token_budget_override {
+            if budget_tokens < 10000 {
+                eprintln!(
+                    "Warning: token_budget {} is below minimum (10000). Using default budget.",
+                    budget_tokens
+                );
+                ChunkCalculator::for_agent(self.options.agent)
+            } else {
+                // Use overridden token budget from per-agent config
+                let mut budget = self.options.agent.token_budget();
+                budget.max_input_tokens = budget_tokens;
+                ChunkCalculator::new(budget, ChunkConfig::default())
+            }
+        } else {
+            ChunkCalculator::for_agent(self.options.agent)
+        }
+    }
+
     fn calculate_worker_count(&self, chunk_count: usize, total_tokens: usize) -> usize {
         if self.options.no_parallel {
             return 1;
@@ -492,6 +740,32 @@ impl AnalyzerService {
         scaler.calculate_workers(chunk_count, total_tokens)
     }
 
+    /// Replace the cached markers of a recording with the given ones.
+    ///
+    /// Call this after the markers in the file were replaced (e.g. by
+    /// curation) so incremental re-analysis reuses the new set. Does
+    /// nothing unless incremental mode is on and a cache exists.
+    pub fn update_cache<P: AsRef<Path>>(
+        &self,
+        path: P,
+        markers: &[ValidatedMarker],
+    ) -> Result<(), AnalysisError> {
+        let path = path.as_ref();
+        if !self.options.incremental {
+            return Ok(());
+        }
+        let Some(mut cache) = AnalysisCache::load(path) else {
+            return Ok(());
+        };
+
+        let content = self.extract_content(path)?;
+        cache.replace_markers(&content.segments, markers);
+        cache.save(path).map_err(|e| AnalysisError::IoError {
+            operation: "writing analysis cache".to_string(),
+            message: e.to_string(),
+        })
+    }
+
     /// Curate markers using LLM to select the most significant ones.
     ///
     /// Call this after analyze() if the marker count is too high.
@@ -595,6 +869,44 @@ impl AnalyzerService {
     }
 }
 
+/// Build the content holding only the segments that still need analysis.
+fn pending_content(content: &AnalysisContent, pending: &[usize]) -> AnalysisContent {
+    let segments: Vec<AnalysisSegment> = pending
+        .iter()
+        .filter_map(|&i| content.segments.get(i).cloned())
+        .collect();
+    AnalysisContent {
+        total_tokens: segments.iter().map(|s| s.estimated_tokens).sum(),
+        segments,
+        total_duration: content.total_duration,
+        stats: content.stats.clone(),
+    }
+}
+
+/// Cached markers that are still present in the file.
+///
+/// Markers the user removed (`agr marker remove`/`clear`) are dropped so
+/// incremental analysis never puts them back. Markers are matched by label,
+/// each file marker accounting for at most one cached marker. Times are not
+/// compared because time redistribution during extraction can move a
+/// segment between runs.
+fn markers_in_file(cached: Vec<ValidatedMarker>, existing: &[MarkerInfo]) -> Vec<ValidatedMarker> {
+    let mut in_file: HashMap<&str, usize> = HashMap::new();
+    for marker in existing {
+        *in_file.entry(marker.label.as_str()).or_default() += 1;
+    }
+    cached
+        .into_iter()
+        .filter(|m| match in_file.get_mut(m.label.as_str()) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -602,8 +914,8 @@ mod tests {
     use crate::analyzer::chunk::TokenBudget;
     use crate::asciicast::{Event, Header};
     use std::io::Write;
-    use std::sync::Mutex;
-    use tempfile::NamedTempFile;
+    use std::sync::{Arc, Mutex};
+    use tempfile::{NamedTempFile, TempDir};
 
     // ============================================
     // Mock Backend for Testing
@@ -612,6 +924,7 @@ mod tests {
     struct MockBackend {
         responses: Mutex<Vec<Result<String, BackendError>>>,
         available: bool,
+        prompts: Arc<Mutex<Vec<String>>>,
     }
 
     impl MockBackend {
@@ -619,6 +932,7 @@ mod tests {
             Self {
                 responses: Mutex::new(responses),
                 available: true,
+                prompts: Arc::default(),
             }
         }
 
@@ -626,8 +940,14 @@ mod tests {
             Self {
                 responses: Mutex::new(vec![]),
                 available: false,
+                prompts: Arc::default(),
             }
         }
+
+        /// Shared log of every prompt this backend receives.
+        fn prompt_log(&self) -> Arc<Mutex<Vec<String>>> {
+            Arc::clone(&self.prompts)
+        }
     }
 
     impl AgentBackend for MockBackend {
@@ -641,10 +961,11 @@ mod tests {
 
         fn invoke(
             &self,
-            _prompt: &str,
+            prompt: &str,
             _timeout: Duration,
             _use_schema: bool,
         ) -> Result<String, BackendError> {
+            self.prompts.lock().unwrap().push(prompt.to_string());
             let mut responses = self.responses.lock().unwrap();
             if responses.is_empty() {
                 Ok(r#"{"markers": []}"#.to_string())
@@ -821,6 +1142,40 @@ mod tests {
         assert!(!service.is_agent_available());
     }
 
+    #[test]
+    fn analyzer_service_estimate_does_not_need_backend() {
+        let file = create_test_cast_file();
+        let opts = AnalyzeOptions::with_agent(AgentType::OpenAi)
+            .extra_args(vec!["--model".to_string(), "gpt-4o".to_string()])
+            .quiet();
+        let backend = Box::new(MockBackend::unavailable());
+        let service = AnalyzerService::with_backend(opts, backend);
+
+        let estimate = service.estimate(file.path()).unwrap();
+
+        assert_eq!(estimate.agent, "OpenAI");
+        assert_eq!(estimate.model.as_deref(), Some("gpt-4o"));
+        assert!(estimate.segments > 0);
+        assert_eq!(estimate.chunks, 1);
+        assert!(estimate.input_tokens > estimate.content_tokens);
+        assert_eq!(estimate.output_tokens, ESTIMATED_OUTPUT_TOKENS_PER_CHUNK);
+        assert!(estimate.estimated_cost_usd.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn analyzer_service_estimate_unknown_model_has_no_cost() {
+        let file = create_test_cast_file();
+        let opts = AnalyzeOptions::with_agent(AgentType::Gemini)
+            .extra_args(vec!["-m".to_string(), "local-llm".to_string()])
+            .quiet();
+        let service = AnalyzerService::with_backend(opts, Box::new(MockBackend::unavailable()));
+
+        let estimate = service.estimate(file.path()).unwrap();
+
+        assert_eq!(estimate.model.as_deref(), Some("local-llm"));
+        assert!(estimate.estimated_cost_usd.is_none());
+    }
+
     #[test]
     fn analyzer_service_analyze_small_file() {
         let file = create_test_cast_file();
@@ -856,6 +1211,157 @@ mod tests {
         assert!(result.is_success());
     }
 
+    /// Append a 50-line phase that starts after the given pause.
+    ///
+    /// Phases match the windowed deduplicator's window, so each one
+    /// extracts to the same segment however many phases follow it.
+    fn push_phase(cast: &mut AsciicastFile, name: &str, pause: f64) {
+        for i in 0..50 {
+            let time = if i == 0 { pause } else { 0.01 };
+            let filler = "abcdefghij".repeat(i % 7 + 1);
+            cast.events.push(Event::output(
+                time,
+                format!("{} step {}: {}\r\n", name, i, filler),
+            ));
+        }
+    }
+
+    /// Write a cast made of the given phases.
+    fn write_phased_cast(path: &Path, phases: &[(&str, f64)]) {
+        let mut cast = AsciicastFile::new(Header::v3(120, 10));
+        for (name, pause) in phases {
+            push_phase(&mut cast, name, *pause);
+        }
+        cast.write(path).unwrap();
+    }
+
+    /// Run an incremental analysis and return the result with the prompts sent.
+    fn analyze_incrementally(path: &Path) -> (AnalysisResult, Vec<String>) {
+        let backend = MockBackend::new(vec![Ok(mock_response_with_markers())]);
+        let prompts = backend.prompt_log();
+        let opts = AnalyzeOptions::default().incremental(true).quiet();
+        let service = AnalyzerService::with_backend(opts, Box::new(backend));
+        let result = service.analyze(path).unwrap();
+        let prompts = prompts.lock().unwrap().clone();
+        (result, prompts)
+    }
+
+    #[test]
+    fn analyzer_service_incremental_only_analyzes_appended_segment() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write_phased_cast(&path, &[("build", 0.0), ("test", 30.0), ("lint", 1.5)]);
+
+        let (first, prompts) = analyze_incrementally(&path);
+        assert_eq!(prompts.len(), 1);
+        assert_eq!(first.cached_segments, 0);
+        assert!(super::super::cache::cache_path(&path).exists());
+
+        // The session continues after the first analysis
+        let mut cast = AsciicastFile::parse(&path).unwrap();
+        push_phase(&mut cast, "deploy", 1.5);
+        cast.write(&path).unwrap();
+        let (second, prompts) = analyze_incrementally(&path);
+
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains("deploy step"));
+        assert!(!prompts[0].contains("build step"));
+        assert!(!prompts[0].contains("lint step"));
+        assert_eq!(second.cached_segments, 2);
+        assert!(second.markers.len() > first.markers.len());
+        // Cached markers were already in the file and are not written twice
+        assert_eq!(
+            MarkerManager::count_markers(&path).unwrap(),
+            second.markers.len()
+        );
+    }
+
+    #[test]
+    fn analyzer_service_incremental_skips_backend_when_unchanged() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write_phased_cast(&path, &[("build", 0.0), ("test", 30.0), ("lint", 1.5)]);
+
+        let (first, _) = analyze_incrementally(&path);
+        let (second, prompts) = analyze_incrementally(&path);
+
+        assert!(prompts.is_empty());
+        assert_eq!(second.markers.len(), first.markers.len());
+        // Cached markers are already in the file
+        assert_eq!(second.write_report.markers_written, 0);
+    }
+
+    #[test]
+    fn analyzer_service_does_not_restore_cleared_markers() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write_phased_cast(&path, &[("build", 0.0), ("test", 30.0), ("lint", 1.5)]);
+
+        analyze_incrementally(&path);
+        MarkerManager::clear_markers(&path).unwrap();
+        let (second, prompts) = analyze_incrementally(&path);
+
+        assert!(prompts.is_empty());
+        assert!(second.markers.is_empty());
+        assert_eq!(second.write_report.markers_written, 0);
+        assert_eq!(MarkerManager::count_markers(&path).unwrap(), 0);
+    }
+
+    #[test]
+    fn analyzer_service_does_not_restore_removed_marker() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write_phased_cast(&path, &[("build", 0.0), ("test", 30.0), ("lint", 1.5)]);
+
+        let (first, _) = analyze_incrementally(&path);
+        assert!(first.markers.len() > 1);
+        let removed = MarkerManager::remove_marker(&path, 0, 1).unwrap();
+        let (second, prompts) = analyze_incrementally(&path);
+
+        assert!(prompts.is_empty());
+        assert_eq!(second.markers.len(), first.markers.len() - 1);
+        assert!(second.markers.iter().all(|m| m.label != removed.label));
+        assert_eq!(
+            MarkerManager::count_markers(&path).unwrap(),
+            first.markers.len() - 1
+        );
+    }
+
+    #[test]
+    fn analyzer_service_update_cache_replaces_cached_markers() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write_phased_cast(&path, &[("build", 0.0), ("test", 30.0), ("lint", 1.5)]);
+        let (first, _) = analyze_incrementally(&path);
+        let opts = AnalyzeOptions::default().incremental(true).quiet();
+        let service = AnalyzerService::with_backend(opts, Box::new(MockBackend::new(vec![])));
+
+        // Curation kept only the first marker
+        let curated = &first.markers[..1];
+        MarkerManager::clear_markers(&path).unwrap();
+        MarkerManager::add_marker(&path, curated[0].timestamp, &curated[0].label, 1).unwrap();
+        service.update_cache(&path, curated).unwrap();
+        let (second, prompts) = analyze_incrementally(&path);
+
+        assert!(prompts.is_empty());
+        assert_eq!(second.markers.len(), 1);
+        assert_eq!(second.markers[0].label, first.markers[0].label);
+        assert_eq!(second.write_report.markers_written, 0);
+    }
+
+    #[test]
+    fn analyzer_service_without_incremental_writes_no_cache() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write_phased_cast(&path, &[("build", 0.0)]);
+        let opts = AnalyzeOptions::default().quiet();
+        let service = AnalyzerService::with_backend(opts, Box::new(MockBackend::new(vec![])));
+
+        service.analyze(&path).unwrap();
+
+        assert!(!super::super::cache::cache_path(&path).exists());
+    }
+
     #[test]
     fn analyzer_service_preserves_file_integrity() {
         let file = create_test_cast_file();
@@ -1018,6 +1524,7 @@ mod tests {
             had_existing_markers: false,
             existing_marker_count: 0,
             total_duration: 120.0,
+            cached_segments: 0,
         };
 
         assert!(result.is_success());
@@ -1048,6 +1555,7 @@ mod tests {
             had_existing_markers: false,
             existing_marker_count: 0,
             total_duration: 180.0,
+            cached_segments: 0,
         };
 
         assert!(result.is_success());
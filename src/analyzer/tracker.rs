@@ -3,14 +3,16 @@
 //! This module provides:
 //! - `RetryPolicy` - configuration for retry behavior
 //! - `RetryCoordinator` - manages retry logic with exponential backoff
+//! - `invoke_with_retry` - backend invocation wrapped in the retry policy
 //! - `TokenTracker` - Observer pattern for usage metrics
 //! - `ChunkUsage` - per-chunk usage information
 //!
 //! # Retry Strategy
 //!
 //! - Max 3 attempts per chunk
-//! - Exponential backoff: 1s -> 2s -> 4s, capped at 60s
+//! - Exponential backoff: 1s -> 2s -> 4s, capped at 60s, plus up to 20% jitter
 //! - Respects agent-provided retry-after duration for rate limits
+//! - Only transient errors are retried (see `BackendError::is_transient`)
 //!
 //! # Token Tracking (R6)
 //!
@@ -20,8 +22,12 @@
 //! - Success/failure rates
 //! - Summary report at end
 
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
 use std::time::{Duration, Instant};
 
+use super::backend::{AgentBackend, BackendError, BackendResult};
+
 /// Configuration for retry behavior.
 #[derive(Debug, Clone)]
 pub struct RetryPolicy {
@@ -33,6 +39,8 @@ pub struct RetryPolicy {
     pub backoff_multiplier: f64,
     /// Maximum delay in milliseconds (default: 60000)
     pub max_delay_ms: u64,
+    /// Random extra delay as a fraction of the backoff (default: 0.2)
+    pub jitter: f64,
 }
 
 impl Default for RetryPolicy {
@@ -42,6 +50,7 @@ impl Default for RetryPolicy {
             initial_delay_ms: 1000,
             backoff_multiplier: 2.0,
             max_delay_ms: 60000,
+            jitter: 0.2,
         }
     }
 }
@@ -59,6 +68,15 @@ impl RetryPolicy {
             initial_delay_ms,
             backoff_multiplier,
             max_delay_ms,
+            jitter: 0.2,
+        }
+    }
+
+    /// Policy that makes a single attempt and never retries.
+    pub fn no_retry() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
         }
     }
 
@@ -115,6 +133,26 @@ impl RetryCoordinator {
         self.policy.delay_for_attempt(attempt)
     }
 
+    /// Wait duration with random jitter added to the exponential backoff.
+    ///
+    /// Spreads out retries from parallel workers so they do not all hit the
+    /// agent at once. Agent-provided retry-after values are used as-is.
+    pub fn jittered_wait_duration(
+        &self,
+        attempt: usize,
+        agent_retry_after: Option<Duration>,
+    ) -> Duration {
+        let wait = self.wait_duration(attempt, agent_retry_after);
+        if agent_retry_after.is_some() || self.policy.jitter <= 0.0 {
+            return wait;
+        }
+
+        // RandomState is seeded randomly per instance; good enough for jitter
+        let random = RandomState::new().build_hasher().finish();
+        let fraction = (random % 1000) as f64 / 1000.0 * self.policy.jitter;
+        wait + wait.mul_f64(fraction)
+    }
+
     /// Check if we should retry based on attempt count.
     pub fn should_retry(&self, attempt: usize) -> bool {
         self.policy.should_retry(attempt)
@@ -126,6 +164,46 @@ impl RetryCoordinator {
     }
 }
 
+/// Run `attempt` until it succeeds, fails with a non-transient error, or the
+/// policy runs out of attempts, sleeping with backoff in between.
+///
+/// Returns the final result together with the number of attempts made.
+pub fn retry_with_policy<T>(
+    policy: &RetryPolicy,
+    mut attempt: impl FnMut() -> BackendResult<T>,
+) -> (BackendResult<T>, usize) {
+    let coordinator = RetryCoordinator::new(policy.clone());
+    let mut attempts = 0;
+    loop {
+        let result = attempt();
+        attempts += 1;
+        match result {
+            Err(e) if e.is_transient() && coordinator.should_retry(attempts) => {
+                let retry_after = match &e {
+                    BackendError::RateLimited(info) => info.retry_after,
+                    _ => None,
+                };
+                std::thread::sleep(coordinator.jittered_wait_duration(attempts - 1, retry_after));
+            }
+            result => return (result, attempts),
+        }
+    }
+}
+
+/// Invoke a backend, retrying rate limits, timeouts, and other transient
+/// failures according to `policy`.
+///
+/// Non-transient errors such as `JsonExtraction` are returned immediately.
+pub fn invoke_with_retry<B: AgentBackend + ?Sized>(
+    backend: &B,
+    prompt: &str,
+    timeout: Duration,
+    use_schema: bool,
+    policy: &RetryPolicy,
+) -> BackendResult<String> {
+    retry_with_policy(policy, || backend.invoke(prompt, timeout, use_schema)).0
+}
+
 /// Usage information for a single chunk.
 #[derive(Debug, Clone)]
 pub struct ChunkUsage {
@@ -269,11 +347,9 @@ impl TokenTracker {
 
         let total_duration = self.elapsed();
 
-        let avg_tokens_per_chunk = if chunks_processed > 0 {
-            total_estimated_tokens / chunks_processed
-        } else {
-            0
-        };
+        let avg_tokens_per_chunk = total_estimated_tokens
+            .checked_div(chunks_processed)
+            .unwrap_or(0);
 
         let avg_duration_per_chunk = if chunks_processed > 0 {
             total_duration / chunks_processed as u32
@@ -401,6 +477,15 @@ mod tests {
         assert_eq!(policy.initial_delay_ms, 1000);
         assert!((policy.backoff_multiplier - 2.0).abs() < f64::EPSILON);
         assert_eq!(policy.max_delay_ms, 60000);
+        assert!((policy.jitter - 0.2).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn retry_policy_no_retry_makes_one_attempt() {
+        let policy = RetryPolicy::no_retry();
+
+        assert!(policy.should_retry(0));
+        assert!(!policy.should_retry(1));
     }
 
     #[test]
@@ -517,6 +602,144 @@ mod tests {
         assert_eq!(coordinator.max_attempts(), 3);
     }
 
+    #[test]
+    fn retry_coordinator_jitter_only_extends_backoff() {
+        let coordinator = RetryCoordinator::with_defaults();
+
+        for _ in 0..20 {
+            let wait = coordinator.jittered_wait_duration(1, None);
+            assert!(wait >= Duration::from_millis(2000));
+            assert!(wait <= Duration::from_millis(2400));
+        }
+
+        // Agent-provided retry_after is honored exactly
+        let wait = coordinator.jittered_wait_duration(1, Some(Duration::from_secs(7)));
+        assert_eq!(wait, Duration::from_secs(7));
+    }
+
+    // ============================================
+    // invoke_with_retry Tests
+    // ============================================
+
+    use crate::analyzer::backend::{RateLimitInfo, RawMarker};
+    use crate::analyzer::chunk::TokenBudget;
+    use std::sync::Mutex;
+
+    /// Backend that returns queued results, then succeeds.
+    struct FlakyBackend {
+        failures: Mutex<Vec<BackendError>>,
+        calls: Mutex<usize>,
+    }
+
+    impl FlakyBackend {
+        fn new(failures: Vec<BackendError>) -> Self {
+            Self {
+                failures: Mutex::new(failures),
+                calls: Mutex::new(0),
+            }
+        }
+
+        fn calls(&self) -> usize {
+            *self.calls.lock().unwrap()
+        }
+    }
+
+    impl AgentBackend for FlakyBackend {
+        fn name(&self) -> &'static str {
+            "flaky"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn invoke(
+            &self,
+            _prompt: &str,
+            _timeout: Duration,
+            _use_schema: bool,
+        ) -> BackendResult<String> {
+            *self.calls.lock().unwrap() += 1;
+            let mut failures = self.failures.lock().unwrap();
+            if failures.is_empty() {
+                Ok(r#"{"markers": []}"#.to_string())
+            } else {
+                Err(failures.remove(0))
+            }
+        }
+
+        fn parse_response(&self, _response: &str) -> BackendResult<Vec<RawMarker>> {
+            Ok(Vec::new())
+        }
+
+        fn token_budget(&self) -> TokenBudget {
+            TokenBudget::claude()
+        }
+    }
+
+    fn fast_policy(max_attempts: usize) -> RetryPolicy {
+        RetryPolicy::new(max_attempts, 1, 2.0, 10)
+    }
+
+    #[test]
+    fn invoke_with_retry_succeeds_after_two_failures() {
+        let backend = FlakyBackend::new(vec![
+            BackendError::RateLimited(RateLimitInfo {
+                retry_after: Some(Duration::from_millis(1)),
+                message: "Rate limited".to_string(),
+            }),
+            BackendError::Timeout(Duration::from_secs(60)),
+        ]);
+
+        let result = invoke_with_retry(
+            &backend,
+            "prompt",
+            Duration::from_secs(60),
+            true,
+            &fast_policy(3),
+        );
+
+        assert_eq!(result.unwrap(), r#"{"markers": []}"#);
+        assert_eq!(backend.calls(), 3);
+    }
+
+    #[test]
+    fn invoke_with_retry_gives_up_after_max_attempts() {
+        let backend = FlakyBackend::new(vec![
+            BackendError::Timeout(Duration::from_secs(60)),
+            BackendError::Timeout(Duration::from_secs(60)),
+        ]);
+
+        let result = invoke_with_retry(
+            &backend,
+            "prompt",
+            Duration::from_secs(60),
+            true,
+            &fast_policy(2),
+        );
+
+        assert!(matches!(result, Err(BackendError::Timeout(_))));
+        assert_eq!(backend.calls(), 2);
+    }
+
+    #[test]
+    fn invoke_with_retry_fails_fast_on_non_transient_error() {
+        let backend = FlakyBackend::new(vec![BackendError::JsonExtraction {
+            response: "garbage".to_string(),
+        }]);
+
+        let result = invoke_with_retry(
+            &backend,
+            "prompt",
+            Duration::from_secs(60),
+            true,
+            &fast_policy(3),
+        );
+
+        assert!(matches!(result, Err(BackendError::JsonExtraction { .. })));
+        assert_eq!(backend.calls(), 1);
+    }
+
     // ============================================
     // ChunkUsage Tests
     // ============================================
@@ -4,31 +4,64 @@
 //! highly redundant or excessively large data blocks that are not
 //! useful for LLM analysis.
 
+use super::super::config::TruncationMode;
 use crate::asciicast::{Event, Transform};
 use std::collections::{HashMap, HashSet, VecDeque};
 
-/// Collapses consecutive lines that are highly similar.
+/// Default annotation for a collapsed run: the representative line followed
+/// by how many lines it stands for.
+pub const DEFAULT_COLLAPSE_FORMAT: &str = "{line} (x{count})";
+
+/// Shortest line (in bytes, after trimming) that can take part in a run.
+///
+/// Short lines such as `}`, `fi` or a bare prompt repeat legitimately and
+/// carry structure, so they are always kept.
+const MIN_ANCHOR_LEN: usize = 30;
+
+/// Collapses runs of consecutive near-identical lines into one.
 ///
-/// Uses a Jaccard-based similarity threshold to identify redundant log lines
-/// that vary slightly (e.g. timestamps or IDs).
+/// Lines are compared word by word (see [`SimilarityFilter::line_similarity`]),
+/// so a run such as a counter ticking up collapses while lines that differ in
+/// a real word survive. Each run is replaced by its last line, annotated with
+/// the run length using a `{line}`/`{count}` template, so the analyzer still
+/// sees how much was folded away. Lines shorter than 30 characters are never
+/// collapsed.
 pub struct SimilarityFilter {
     threshold: f64,
-    last_line: Option<String>,
-    skip_count: usize,
+    format: String,
+    run: Option<LineRun>,
     total_collapsed: usize,
 }
 
+/// A run of similar lines waiting to be emitted.
+struct LineRun {
+    /// First line of the run, trimmed; later lines are compared against it
+    anchor: String,
+    /// Most recent line of the run, including its line ending
+    latest: String,
+    /// Number of lines in the run
+    count: usize,
+}
+
 impl SimilarityFilter {
     /// Create a new similarity filter with the given threshold (0.0 to 1.0).
     pub fn new(threshold: f64) -> Self {
         Self {
             threshold,
-            last_line: None,
-            skip_count: 0,
+            format: DEFAULT_COLLAPSE_FORMAT.to_string(),
+            run: None,
             total_collapsed: 0,
         }
     }
 
+    /// Use `format` to annotate collapsed runs. `{line}` is replaced by the
+    /// representative line and `{count}` by the number of lines collapsed
+    /// into it.
+    pub fn with_format(mut self, format: impl Into<String>) -> Self {
+        self.format = format.into();
+        self
+    }
+
     /// Calculate a shift-resistant similarity score between two strings.
     /// Uses a prefix-weighted approach to prevent collapsing different commands.
     pub fn calculate_similarity(s1: &str, s2: &str) -> f64 {
@@ -71,22 +104,83 @@ impl SimilarityFilter {
         }
     }
 
-    fn flush_skips(&mut self, accumulated_time: f64) -> Option<Event> {
-        if self.skip_count > 0 {
-            let msg = format!("\n[... collapsed {} similar lines ...]\n", self.skip_count);
-            self.total_collapsed += self.skip_count;
-            self.skip_count = 0;
-            // Always return time with the skip message to preserve duration
-            Some(Event::output(accumulated_time, msg))
-        } else {
-            None
+    /// Word-level similarity between two lines, from 0.0 to 1.0.
+    ///
+    /// Computed as `1 - d / n`, where `d` is the Levenshtein distance over
+    /// whitespace-separated words and `n` is the word count of the longer
+    /// line. Runs of digits are masked before comparing, so words that differ
+    /// only in a number (`3/7` vs `4/7`, `12MB` vs `13MB`) count as equal.
+    pub fn line_similarity(a: &str, b: &str) -> f64 {
+        let words_a: Vec<String> = a.split_whitespace().map(mask_digits).collect();
+        let words_b: Vec<String> = b.split_whitespace().map(mask_digits).collect();
+        let longest = words_a.len().max(words_b.len());
+        if longest == 0 {
+            return 1.0;
         }
+        1.0 - word_distance(&words_a, &words_b) as f64 / longest as f64
     }
 
     /// Get the total number of lines collapsed by this filter.
     pub fn collapsed_count(&self) -> usize {
         self.total_collapsed
     }
+
+    /// Whether `trimmed_line` extends the pending run.
+    fn continues_run(&self, trimmed_line: &str) -> bool {
+        self.run.as_ref().is_some_and(|run| {
+            trimmed_line.len() >= MIN_ANCHOR_LEN
+                && Self::line_similarity(&run.anchor, trimmed_line) >= self.threshold
+        })
+    }
+
+    /// Emit the pending run, annotating it if it covers more than one line.
+    fn flush_run(&mut self) -> Option<String> {
+        let run = self.run.take()?;
+        if run.count == 1 {
+            return Some(run.latest);
+        }
+        self.total_collapsed += run.count - 1;
+        let content = run.latest.trim_end();
+        let ending = &run.latest[content.len()..];
+        let annotated = self
+            .format
+            .replace("{count}", &run.count.to_string())
+            .replace("{line}", content);
+        Some(format!("{}{}", annotated, ending))
+    }
+}
+
+/// Replace each run of ASCII digits with a single `#`.
+fn mask_digits(word: &str) -> String {
+    let mut masked = String::with_capacity(word.len());
+    let mut in_number = false;
+    for c in word.chars() {
+        if c.is_ascii_digit() {
+            if !in_number {
+                masked.push('#');
+            }
+            in_number = true;
+        } else {
+            masked.push(c);
+            in_number = false;
+        }
+    }
+    masked
+}
+
+/// Levenshtein distance between two word sequences.
+fn word_distance(a: &[String], b: &[String]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, word_a) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, word_b) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(word_a != word_b);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
 }
 
 impl Transform for SimilarityFilter {
@@ -95,13 +189,10 @@ impl Transform for SimilarityFilter {
     fn transform(&mut self, events: &mut Vec<Event>) {
         let mut output_events = Vec::with_capacity(events.len());
         let mut accumulated_time = 0.0;
+        let mut input = events.drain(..).peekable();
 
-        for mut event in events.drain(..) {
+        while let Some(mut event) = input.next() {
             if !event.is_output() {
-                if let Some(skip_event) = self.flush_skips(accumulated_time) {
-                    output_events.push(skip_event);
-                    accumulated_time = 0.0;
-                }
                 event.time += accumulated_time;
                 accumulated_time = 0.0;
                 output_events.push(event);
@@ -111,27 +202,41 @@ impl Transform for SimilarityFilter {
             let mut new_data = String::with_capacity(event.data.len());
             for line in event.data.split_inclusive('\n') {
                 let trimmed_line = line.trim();
-
-                let similarity = if let Some(ref last) = self.last_line {
-                    Self::calculate_similarity(last, trimmed_line)
-                } else {
-                    0.0
-                };
-
-                if similarity >= self.threshold {
-                    self.skip_count += 1;
-                } else {
-                    if let Some(skip_event) = self.flush_skips(accumulated_time) {
-                        new_data.push_str(&skip_event.data);
-                        accumulated_time = 0.0;
+                if self.continues_run(trimmed_line) {
+                    if let Some(run) = self.run.as_mut() {
+                        run.count += 1;
+                        run.latest = line.to_string();
                     }
+                    continue;
+                }
+                if let Some(flushed) = self.flush_run() {
+                    new_data.push_str(&flushed);
+                }
+                if trimmed_line.len() < MIN_ANCHOR_LEN {
                     new_data.push_str(line);
-                    // Only track as last_line if it was substantial
-                    if trimmed_line.len() >= 30 {
-                        self.last_line = Some(trimmed_line.to_string());
-                    } else {
-                        self.last_line = None;
-                    }
+                } else {
+                    self.run = Some(LineRun {
+                        anchor: trimmed_line.to_string(),
+                        latest: line.to_string(),
+                        count: 1,
+                    });
+                }
+            }
+
+            // Hold the run open across the event boundary only if the next
+            // event continues it; otherwise its lines stay in this event. A
+            // run therefore never outlives the output events it spans.
+            let next_continues = input.peek().is_some_and(|next| {
+                next.is_output()
+                    && next
+                        .data
+                        .split_inclusive('\n')
+                        .next()
+                        .is_some_and(|first| self.continues_run(first.trim()))
+            });
+            if !next_continues {
+                if let Some(flushed) = self.flush_run() {
+                    new_data.push_str(&flushed);
                 }
             }
 
@@ -145,9 +250,9 @@ impl Transform for SimilarityFilter {
             }
         }
 
-        if let Some(skip_event) = self.flush_skips(accumulated_time) {
-            output_events.push(skip_event);
-        } else if accumulated_time > 0.0 {
+        drop(input);
+
+        if accumulated_time > 0.0 {
             if let Some(last) = output_events.last_mut() {
                 last.time += accumulated_time;
             }
@@ -159,11 +264,13 @@ impl Transform for SimilarityFilter {
 
 /// Truncates large contiguous blocks of output.
 ///
-/// Preserves head and tail context while removing the middle of massive
-/// output events (e.g. large file dumps).
+/// Removes the middle (or one end, depending on [`TruncationMode`]) of
+/// massive output events (e.g. large file dumps), leaving a marker with the
+/// number of lines or bytes removed.
 pub struct BlockTruncator {
     max_size: usize,
     context_lines: usize,
+    mode: TruncationMode,
     total_truncated: usize,
 }
 
@@ -173,10 +280,17 @@ impl BlockTruncator {
         Self {
             max_size,
             context_lines,
+            mode: TruncationMode::default(),
             total_truncated: 0,
         }
     }
 
+    /// Choose which end(s) of an oversized block to keep.
+    pub fn with_mode(mut self, mode: TruncationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Get the total number of blocks truncated.
     pub fn truncated_count(&self) -> usize {
         self.total_truncated
@@ -188,6 +302,15 @@ impl BlockTruncator {
         }
         self.total_truncated += 1;
         let lines: Vec<&str> = data.split_inclusive('\n').collect();
+        match self.mode {
+            TruncationMode::HeadTail => self.truncate_head_tail(data, &lines),
+            TruncationMode::TailOnly | TruncationMode::HeadOnly => {
+                self.truncate_one_end(data, &lines)
+            }
+        }
+    }
+
+    fn truncate_head_tail(&self, data: &str, lines: &[&str]) -> String {
         if lines.len() <= self.context_lines * 2 {
             let head_len = self.max_size / 2;
             let head: String = data.chars().take(head_len).collect();
@@ -215,6 +338,35 @@ impl BlockTruncator {
             tail
         )
     }
+
+    /// Keep `context_lines` lines from the start or end only. Blocks with too
+    /// few lines to trim (a handful of huge lines) keep half of `max_size`
+    /// bytes instead.
+    fn truncate_one_end(&self, data: &str, lines: &[&str]) -> String {
+        let keep_head = self.mode == TruncationMode::HeadOnly;
+        if lines.len() <= self.context_lines {
+            let keep_chars = self.max_size / 2;
+            let kept: String = if keep_head {
+                data.chars().take(keep_chars).collect()
+            } else {
+                let skip = data.chars().count().saturating_sub(keep_chars);
+                data.chars().skip(skip).collect()
+            };
+            let marker = format!("[... {} bytes omitted ...]", data.len() - kept.len());
+            return if keep_head {
+                format!("{}\n{}\n", kept, marker)
+            } else {
+                format!("{}\n{}", marker, kept)
+            };
+        }
+        let omitted = lines.len() - self.context_lines;
+        let marker = format!("[... {} lines omitted ...]\n", omitted);
+        if keep_head {
+            format!("{}{}", lines[..self.context_lines].concat(), marker)
+        } else {
+            format!("{}{}", marker, lines[omitted..].concat())
+        }
+    }
 }
 
 impl Transform for BlockTruncator {
@@ -652,3 +804,178 @@ impl Transform for WindowedLineDeduplicator {
         *events = output;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collapse(filter: &mut SimilarityFilter, chunks: &[&str]) -> String {
+        let mut events = chunks.iter().map(|d| Event::output(0.1, *d)).collect();
+        filter.transform(&mut events);
+        events.iter().map(|e| e.data.as_str()).collect()
+    }
+
+    #[test]
+    fn line_similarity_ignores_changing_numbers() {
+        assert_eq!(
+            SimilarityFilter::line_similarity("compiling foo.rs (3/7)", "compiling foo.rs (4/7)"),
+            1.0
+        );
+        assert_eq!(
+            SimilarityFilter::line_similarity("Downloaded 12MB", "Downloaded 130MB"),
+            1.0
+        );
+    }
+
+    #[test]
+    fn line_similarity_counts_changed_words() {
+        let score = SimilarityFilter::line_similarity("compiling foo.rs", "compiling bar.rs");
+        assert!((score - 0.5).abs() < f64::EPSILON);
+        let score = SimilarityFilter::line_similarity("a b c d", "a b c");
+        assert!((score - 0.75).abs() < f64::EPSILON);
+        assert_eq!(SimilarityFilter::line_similarity("ls", "pwd"), 0.0);
+    }
+
+    #[test]
+    fn run_differing_by_a_number_collapses_to_annotated_last_line() {
+        let lines: String = (1..=7)
+            .map(|i| format!("compiling src/analyzer/worker.rs ({}/7)\n", i))
+            .collect();
+        let mut filter = SimilarityFilter::new(0.8);
+        let out = collapse(&mut filter, &["$ cargo build\n", &lines, "done\n"]);
+        assert_eq!(
+            out,
+            "$ cargo build\ncompiling src/analyzer/worker.rs (7/7) (x7)\ndone\n"
+        );
+        assert_eq!(filter.collapsed_count(), 6);
+    }
+
+    #[test]
+    fn run_spanning_events_collapses_once() {
+        let mut filter = SimilarityFilter::new(0.8);
+        let out = collapse(
+            &mut filter,
+            &[
+                "waiting for the build directory lock (1s)\n",
+                "waiting for the build directory lock (2s)\n",
+                "waiting for the build directory lock (3s)\n",
+                "other line\n",
+            ],
+        );
+        assert_eq!(
+            out,
+            "waiting for the build directory lock (3s) (x3)\nother line\n"
+        );
+        assert_eq!(filter.collapsed_count(), 2);
+    }
+
+    #[test]
+    fn dissimilar_lines_pass_through_unchanged() {
+        let mut filter = SimilarityFilter::new(0.8);
+        let input = "compiling foo.rs\ncompiling bar.rs\n\nrunning tests\n";
+        assert_eq!(collapse(&mut filter, &[input]), input);
+        assert_eq!(filter.collapsed_count(), 0);
+    }
+
+    #[test]
+    fn repeated_short_lines_are_kept() {
+        let mut filter = SimilarityFilter::new(0.8);
+        let input = "        }\n    }\n}\n}\nfi\nfi\ntick 1\ntick 2\n";
+        assert_eq!(collapse(&mut filter, &[input]), input);
+        assert_eq!(filter.collapsed_count(), 0);
+    }
+
+    #[test]
+    fn annotation_format_is_configurable() {
+        let mut filter = SimilarityFilter::new(0.8).with_format("[{count}x] {line}");
+        let out = collapse(
+            &mut filter,
+            &["  applying database migration step 1\n  applying database migration step 2\n"],
+        );
+        assert_eq!(out, "[2x]   applying database migration step 2\n");
+    }
+
+    #[test]
+    fn collapsing_preserves_total_time() {
+        let mut filter = SimilarityFilter::new(0.8);
+        let mut events = vec![
+            Event::output(0.5, "waiting for the build directory lock (1s)\n"),
+            Event::output(0.5, "waiting for the build directory lock (2s)\n"),
+            Event::output(1.0, "waiting for the build directory lock (3s)\n"),
+        ];
+        filter.transform(&mut events);
+        assert_eq!(events.len(), 1);
+        assert!((events[0].time - 2.0).abs() < f64::EPSILON);
+    }
+
+    /// 100 numbered lines of 10 bytes each (1000 bytes).
+    fn numbered_block() -> String {
+        (0..100).map(|i| format!("line {:04}\n", i)).collect()
+    }
+
+    fn truncate_with(mode: TruncationMode, data: &str) -> (String, usize) {
+        let mut truncator = BlockTruncator::new(500, 3).with_mode(mode);
+        let mut events = vec![Event::output(0.1, data)];
+        truncator.transform(&mut events);
+        (events[0].data.clone(), truncator.truncated_count())
+    }
+
+    #[test]
+    fn head_tail_keeps_both_ends() {
+        let (out, count) = truncate_with(TruncationMode::HeadTail, &numbered_block());
+        assert_eq!(
+            out,
+            "line 0000\nline 0001\nline 0002\n\n[... truncated 94 lines ...]\n\
+             line 0097\nline 0098\nline 0099\n"
+        );
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn tail_only_keeps_the_end() {
+        let (out, count) = truncate_with(TruncationMode::TailOnly, &numbered_block());
+        assert_eq!(
+            out,
+            "[... 97 lines omitted ...]\nline 0097\nline 0098\nline 0099\n"
+        );
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn head_only_keeps_the_start() {
+        let (out, count) = truncate_with(TruncationMode::HeadOnly, &numbered_block());
+        assert_eq!(
+            out,
+            "line 0000\nline 0001\nline 0002\n[... 97 lines omitted ...]\n"
+        );
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn single_end_modes_fall_back_to_bytes_for_few_long_lines() {
+        let data = "x".repeat(1000);
+        let (tail, _) = truncate_with(TruncationMode::TailOnly, &data);
+        assert_eq!(
+            tail,
+            format!("[... 750 bytes omitted ...]\n{}", "x".repeat(250))
+        );
+        let (head, _) = truncate_with(TruncationMode::HeadOnly, &data);
+        assert_eq!(
+            head,
+            format!("{}\n[... 750 bytes omitted ...]\n", "x".repeat(250))
+        );
+    }
+
+    #[test]
+    fn blocks_within_limit_are_untouched_in_every_mode() {
+        for mode in [
+            TruncationMode::HeadTail,
+            TruncationMode::TailOnly,
+            TruncationMode::HeadOnly,
+        ] {
+            let (out, count) = truncate_with(mode, "short\n");
+            assert_eq!(out, "short\n");
+            assert_eq!(count, 0);
+        }
+    }
+}
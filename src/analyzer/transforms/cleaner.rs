@@ -276,6 +276,16 @@ mod tests {
         assert_eq!(output, "hello");
     }
 
+    #[test]
+    fn strips_bracketed_paste_and_focus_modes() {
+        let config = ExtractionConfig::default();
+        let mut cleaner = ContentCleaner::new(&config);
+
+        let input = "\x1b[?2004h\x1b[?1004h\x1b[200~pasted\x1b[201~ text";
+        let output = cleaner.clean(input);
+        assert_eq!(output, "pasted text");
+    }
+
     #[test]
     fn strips_osc_sequences() {
         let config = ExtractionConfig::default();
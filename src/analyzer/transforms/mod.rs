@@ -7,10 +7,11 @@
 //! - [`DeduplicateProgressLines`] - Keeps only final state of `\r`-rewritten lines
 //! - [`NormalizeWhitespace`] - Collapses excessive whitespace
 //! - [`FilterEmptyEvents`] - Removes events with no remaining content
-//! - [`SimilarityFilter`] - Collapses consecutive lines that are highly similar
+//! - [`SimilarityFilter`] - Collapses runs of near-identical lines into one annotated line
 //! - [`BlockTruncator`] - Truncates large contiguous blocks of output
 //! - [`EventCoalescer`] - Merges rapid, similar consecutive events
 //! - [`GlobalDeduplicator`] - Caps global line frequency and hashes redundant redraws
+//! - [`NoiseFilter`] - Structural and configured noise line detection
 
 mod aggressive;
 mod cleaner;
@@ -21,9 +22,10 @@ mod terminal;
 
 pub use aggressive::{
     BlockTruncator, EventCoalescer, FileDumpFilter, GlobalDeduplicator, SimilarityFilter,
-    WindowedLineDeduplicator,
+    WindowedLineDeduplicator, DEFAULT_COLLAPSE_FORMAT,
 };
 pub use cleaner::ContentCleaner;
 pub use dedupe::DeduplicateProgressLines;
+pub use noise::NoiseFilter;
 pub use normalize::{EmptyLineFilter, FilterEmptyEvents, NormalizeWhitespace};
 pub use terminal::TerminalTransform;
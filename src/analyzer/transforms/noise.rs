@@ -5,6 +5,13 @@
 //! fallback for one-shot noise that the behavioral row-rewrite detector in
 //! [`super::TerminalTransform`] cannot catch (lines that appear exactly once
 //! before scrolling off).
+//!
+//! [`NoiseFilter`] layers user-configured substrings and regular expressions
+//! from [`ExtractionConfig`] on top of the classifier.
+
+use regex::Regex;
+
+use super::super::config::ExtractionConfig;
 
 /// Minimum number of key-binding patterns required to classify a line as a
 /// keybinding hint bar.
@@ -143,6 +150,51 @@ impl NoiseClassifier {
     }
 }
 
+/// Structural noise classification plus configured extra patterns.
+#[derive(Debug, Clone, Default)]
+pub struct NoiseFilter {
+    substrings: Vec<String>,
+    regexes: Vec<Regex>,
+}
+
+impl NoiseFilter {
+    /// Build a filter from the configured noise substrings and patterns.
+    ///
+    /// Patterns are compiled here, once. Invalid ones are skipped with a
+    /// warning rather than failing the whole extraction.
+    pub fn new(config: &ExtractionConfig) -> Self {
+        let regexes = config
+            .noise_patterns
+            .iter()
+            .filter_map(|pattern| match Regex::new(pattern) {
+                Ok(re) => Some(re),
+                Err(e) => {
+                    tracing::warn!("Ignoring invalid noise pattern {:?}: {}", pattern, e);
+                    None
+                }
+            })
+            .collect();
+        Self {
+            substrings: config
+                .noise_substrings
+                .iter()
+                .filter(|s| !s.is_empty())
+                .cloned()
+                .collect(),
+            regexes,
+        }
+    }
+
+    /// Returns `true` if the line is built-in noise or matches a configured pattern.
+    pub fn is_noise(&self, line: &str) -> bool {
+        if NoiseClassifier::is_noise(line) {
+            return true;
+        }
+        self.substrings.iter().any(|s| line.contains(s.as_str()))
+            || self.regexes.iter().any(|re| re.is_match(line))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,4 +362,37 @@ mod tests {
         assert!(!NoiseClassifier::is_noise(""));
         assert!(!NoiseClassifier::is_noise("   "));
     }
+
+    // ── Configured patterns ─────────────────────────────────────────
+
+    fn filter(substrings: &[&str], patterns: &[&str]) -> NoiseFilter {
+        NoiseFilter::new(&ExtractionConfig {
+            noise_substrings: substrings.iter().map(|s| s.to_string()).collect(),
+            noise_patterns: patterns.iter().map(|s| s.to_string()).collect(),
+            ..ExtractionConfig::default()
+        })
+    }
+
+    #[test]
+    fn filter_applies_configured_substrings_and_patterns() {
+        let filter = filter(&["[agent busy]"], &[r"^\d+ tokens used$"]);
+        assert!(filter.is_noise("  [agent busy] please wait"));
+        assert!(filter.is_noise("1234 tokens used"));
+        assert!(!filter.is_noise("used 1234 tokens on the refactor"));
+    }
+
+    #[test]
+    fn filter_keeps_builtin_heuristics() {
+        let filter = filter(&["[agent busy]"], &[]);
+        assert!(filter.is_noise("Shimmying…"));
+        assert!(!filter.is_noise("fn main() {"));
+    }
+
+    #[test]
+    fn filter_skips_invalid_patterns() {
+        let filter = filter(&[""], &["(unclosed", "ok$"]);
+        assert!(filter.is_noise("looks ok"));
+        // An empty substring would match everything; it is ignored
+        assert!(!filter.is_noise("real content"));
+    }
 }
@@ -10,19 +10,15 @@
 //!    Rows with high rewrite counts (spinners, progress bars, status bars)
 //!    are classified as noise without examining content.
 //! 2. **Structural fallback**: [`super::noise::NoiseClassifier`] catches
-//!    one-shot noise (tips, hints, update banners) that appear exactly once.
+//!    one-shot noise (tips, hints, update banners) that appear exactly once,
+//!    plus any patterns configured through [`NoiseFilter`].
 
-use super::noise::NoiseClassifier;
+use super::noise::NoiseFilter;
 use crate::asciicast::{Event, EventType, Transform};
 use crate::terminal::TerminalBuffer;
 use std::collections::{HashSet, VecDeque};
 use std::hash::{Hash, Hasher};
 
-/// Maximum number of line hashes to retain. Limits memory for long sessions
-/// while still catching redraws within a ~50K-line window. Each entry is 8
-/// bytes, so 50 000 entries ≈ 400 KB.
-const MAX_STORY_HASHES: usize = 50_000;
-
 /// Minimum number of writes to a terminal row before its content is
 /// classified as noise. Normal content writes each row once; spinners and
 /// status bars rewrite the same row many times.
@@ -40,6 +36,16 @@ pub struct TerminalTransform {
     story_hashes: HashSet<u64>,
     /// Insertion order for FIFO eviction of story_hashes
     story_hash_order: VecDeque<u64>,
+    /// Maximum number of hashes kept in story_hashes before evicting the oldest
+    max_story_hashes: usize,
+    /// Content-based noise detection (structural heuristics + configured patterns)
+    noise_filter: NoiseFilter,
+    /// Escape sequences consumed by the virtual terminal
+    ansi_sequences_rendered: usize,
+    /// Lines dropped by behavioral or content-based noise detection
+    noise_lines_filtered: usize,
+    /// Lines dropped because the story already contained them (redraws)
+    redraw_lines_deduped: usize,
     /// Per-row write counter. Indexed by terminal row; length = terminal height.
     /// Rows with count >= NOISE_REWRITE_THRESHOLD are considered noise (spinners,
     /// progress bars, status bars that rewrite in-place).
@@ -48,27 +54,75 @@ pub struct TerminalTransform {
 
 impl TerminalTransform {
     /// Create a new terminal transform with given dimensions.
-    pub fn new(width: usize, height: usize) -> Self {
+    ///
+    /// `max_story_hashes` bounds the redraw-dedup memory; see
+    /// [`ExtractionConfig::max_story_hashes`](crate::analyzer::ExtractionConfig::max_story_hashes).
+    pub fn new(width: usize, height: usize, max_story_hashes: usize) -> Self {
         Self {
             buffer: TerminalBuffer::new(width, height),
             stable_lines_count: 0,
             last_cursor_pos: (0, 0),
-            story_hashes: HashSet::with_capacity(MAX_STORY_HASHES),
-            story_hash_order: VecDeque::with_capacity(MAX_STORY_HASHES),
+            story_hashes: HashSet::with_capacity(max_story_hashes),
+            story_hash_order: VecDeque::with_capacity(max_story_hashes),
+            max_story_hashes,
+            noise_filter: NoiseFilter::default(),
+            ansi_sequences_rendered: 0,
+            noise_lines_filtered: 0,
+            redraw_lines_deduped: 0,
             row_write_counts: vec![0; height],
         }
     }
 
+    /// Use `filter` for content-based noise detection instead of the
+    /// built-in heuristics alone.
+    pub fn with_noise_filter(mut self, filter: NoiseFilter) -> Self {
+        self.noise_filter = filter;
+        self
+    }
+
+    /// Number of ANSI escape sequences rendered away by the virtual terminal.
+    pub fn ansi_sequences_rendered(&self) -> usize {
+        self.ansi_sequences_rendered
+    }
+
+    /// Number of lines dropped as noise (rewritten rows or noise patterns).
+    pub fn noise_lines_filtered(&self) -> usize {
+        self.noise_lines_filtered
+    }
+
+    /// Number of lines dropped because an identical line was already emitted.
+    pub fn redraw_lines_deduped(&self) -> usize {
+        self.redraw_lines_deduped
+    }
+
+    /// Count escape sequences in `data`, not counting the `ESC \` string
+    /// terminator that closes OSC/DCS sequences.
+    fn count_escape_sequences(data: &str) -> usize {
+        let bytes = data.as_bytes();
+        bytes
+            .iter()
+            .enumerate()
+            .filter(|&(i, &b)| b == 0x1b && bytes.get(i + 1) != Some(&b'\\'))
+            .count()
+    }
+
     /// Returns `true` if the given row has been rewritten enough times to be
     /// considered noise (behavioral detection).
     fn is_noisy_row(&self, row: usize) -> bool {
         self.row_write_counts.get(row).copied().unwrap_or(0) >= NOISE_REWRITE_THRESHOLD
     }
 
-    /// Shift row_write_counts after `n` lines scrolled off the top.
-    fn shift_row_counts(&mut self, n: usize) {
-        let drain = n.min(self.row_write_counts.len());
-        self.row_write_counts.drain(0..drain);
+    /// Shift row_write_counts after `n` lines scrolled out of the top of
+    /// the scroll region `top..=bottom`. Rows outside the region stay put.
+    fn shift_row_counts(&mut self, n: usize, top: usize, bottom: usize) {
+        let end = (bottom + 1).min(self.row_write_counts.len());
+        if top < end {
+            let drain = n.min(end - top);
+            self.row_write_counts.drain(top..top + drain);
+            for _ in 0..drain {
+                self.row_write_counts.insert(end - drain, 0);
+            }
+        }
         self.row_write_counts.resize(self.buffer.height(), 0);
     }
 
@@ -87,7 +141,7 @@ impl TerminalTransform {
         }
         self.story_hash_order.push_back(h);
         // Evict oldest when over capacity
-        while self.story_hashes.len() > MAX_STORY_HASHES {
+        while self.story_hashes.len() > self.max_story_hashes {
             if let Some(old) = self.story_hash_order.pop_front() {
                 self.story_hashes.remove(&old);
             }
@@ -104,17 +158,17 @@ impl TerminalTransform {
         let mut result = Vec::new();
         for (line, behaviorally_noisy) in lines {
             // Layer 1: behavioral — row was rewritten many times
-            if behaviorally_noisy {
-                continue;
-            }
             // Layer 2: structural fallback — one-shot noise patterns
-            if NoiseClassifier::is_noise(&line) {
+            if behaviorally_noisy || self.noise_filter.is_noise(&line) {
+                self.noise_lines_filtered += 1;
                 continue;
             }
             // Hash dedup against the story
             let h = Self::hash_line(&line);
             if self.insert_hash(h) {
                 result.push(line);
+            } else {
+                self.redraw_lines_deduped += 1;
             }
         }
         result
@@ -129,40 +183,46 @@ impl Transform for TerminalTransform {
         for event in events.drain(..) {
             match event.event_type {
                 EventType::Output => {
+                    self.ansi_sequences_rendered += Self::count_escape_sequences(&event.data);
                     let mut scrolled_lines = Vec::new();
                     {
                         let mut scroll_cb = |cells: Vec<crate::terminal::Cell>| {
-                            let line: String = cells.iter().map(|c| c.char).collect::<String>();
+                            let line = crate::terminal::cells_to_text(&cells);
                             scrolled_lines.push(line.trim_end().to_string());
                         };
                         self.buffer.process(&event.data, Some(&mut scroll_cb));
                     }
                     accumulated_time += event.time;
 
-                    // Track which row the cursor landed on after processing
-                    let cursor_row = self.buffer.cursor_row();
-                    if cursor_row < self.row_write_counts.len() {
-                        self.row_write_counts[cursor_row] += 1;
-                    }
-
                     // 1. Emit lines that were scrolled off the screen immediately
                     let had_scroll = !scrolled_lines.is_empty();
                     let scroll_count = scrolled_lines.len();
                     if had_scroll {
-                        // Tag each scrolled line with its row's noise status
-                        // before shifting counts. Scrolled lines come from the
-                        // top rows (0..scroll_count).
+                        // Lines leave from the top of the scroll region, so
+                        // the k-th scrolled line was on row `top + k`. Rows
+                        // outside the region (pinned headers and footers)
+                        // never scroll. Rows below `stable_lines_count` were
+                        // already emitted.
+                        let (top, bottom) = self.buffer.scroll_region();
                         let tagged: Vec<(String, bool)> = scrolled_lines
                             .into_iter()
                             .enumerate()
+                            .filter(|(i, _)| top + i >= self.stable_lines_count)
                             .map(|(i, line)| {
-                                let noisy = self.is_noisy_row(i);
+                                let noisy = self.is_noisy_row(top + i);
                                 (line, noisy)
                             })
                             .collect();
 
-                        // Shift row counts now that those rows are gone
-                        self.shift_row_counts(scroll_count);
+                        // Shift row counts and the stable boundary now that
+                        // those rows are gone
+                        self.shift_row_counts(scroll_count, top, bottom);
+                        if self.stable_lines_count > top {
+                            self.stable_lines_count = self
+                                .stable_lines_count
+                                .saturating_sub(scroll_count)
+                                .max(top);
+                        }
 
                         let new_lines = self.filter_new_lines(tagged);
                         if !new_lines.is_empty() {
@@ -173,13 +233,27 @@ impl Transform for TerminalTransform {
                     }
 
                     let current_cursor = (self.buffer.cursor_row(), self.buffer.cursor_col());
+                    let has_newline = event.data.contains('\n');
+
+                    // Count a write against the row the event finished on. A
+                    // trailing line feed moves the cursor to a fresh row, so
+                    // the row that was written is the one above it. Bare line
+                    // breaks only finish a row and don't count as a rewrite.
+                    let written_row = if event.data.ends_with('\n') {
+                        current_cursor.0.saturating_sub(1)
+                    } else {
+                        current_cursor.0
+                    };
+                    let writes_text = event.data.chars().any(|c| c != '\r' && c != '\n');
+                    if writes_text && written_row < self.row_write_counts.len() {
+                        self.row_write_counts[written_row] += 1;
+                    }
 
                     // Optimization: only snapshot the buffer when something
                     // interesting happened (cursor moved, scroll, newline, or
                     // long pause). Skipping to_string() for typing-within-line
                     // events eliminates the dominant cost on large files.
                     let cursor_moved = current_cursor != self.last_cursor_pos;
-                    let has_newline = event.data.contains('\n');
                     let long_pause = event.time > 2.0;
 
                     if cursor_moved || had_scroll || has_newline || long_pause {
@@ -200,9 +274,12 @@ impl Transform for TerminalTransform {
                             self.stable_lines_count += 1;
                         }
 
-                        // 3. Emit the current line IF it was finalized
-                        let is_stable =
-                            has_newline || current_cursor.0 < self.last_cursor_pos.0 || long_pause;
+                        // 3. Emit a snapshot of the current line if the cursor
+                        //    left it for a row above, or it sat through a long
+                        //    pause. After a line feed the cursor row holds the
+                        //    start of a new, unfinished line, so it waits for
+                        //    step 2 or the final flush instead.
+                        let is_stable = current_cursor.0 < self.last_cursor_pos.0 || long_pause;
 
                         if is_stable
                             && current_cursor.0 < current_lines.len()
@@ -211,9 +288,6 @@ impl Transform for TerminalTransform {
                             let row = current_cursor.0;
                             let noisy = self.is_noisy_row(row);
                             lines_to_emit.push((current_lines[row].clone(), noisy));
-                            if has_newline {
-                                self.stable_lines_count = current_cursor.0 + 1;
-                            }
                         }
 
                         if !lines_to_emit.is_empty() {
@@ -274,3 +348,176 @@ impl Transform for TerminalTransform {
         *events = output_events;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn story(transform: &mut TerminalTransform, data: &str) -> Vec<String> {
+        let mut events = vec![Event::output(0.1, data)];
+        transform.transform(&mut events);
+        events
+            .iter()
+            .filter(|e| e.is_output())
+            .flat_map(|e| e.data.lines().map(str::to_string).collect::<Vec<_>>())
+            .collect()
+    }
+
+    #[test]
+    fn insert_hash_evicts_oldest_beyond_configured_cap() {
+        let mut transform = TerminalTransform::new(80, 24, 2);
+        assert!(transform.insert_hash(1));
+        assert!(transform.insert_hash(2));
+        assert!(!transform.insert_hash(1));
+        assert!(transform.insert_hash(3));
+
+        // 1 was evicted first, so it counts as new again
+        assert_eq!(transform.story_hashes.len(), 2);
+        assert!(transform.insert_hash(1));
+        assert!(!transform.insert_hash(3));
+    }
+
+    #[test]
+    fn configured_noise_pattern_is_filtered() {
+        let filter = NoiseFilter::new(&crate::analyzer::ExtractionConfig {
+            noise_substrings: vec!["Frobnicating".to_string()],
+            ..Default::default()
+        });
+        let mut transform = TerminalTransform::new(80, 24, 50_000).with_noise_filter(filter);
+        let lines = "Frobnicating the widgets\r\nShimmying…\r\nreal output\r\n";
+        assert_eq!(story(&mut transform, lines), vec!["real output"]);
+    }
+
+    #[test]
+    fn counters_track_rendered_escapes_noise_and_redraws() {
+        let mut transform = TerminalTransform::new(80, 24, 50_000);
+        let lines = "\x1b[31mred\x1b[0m\r\nShimmying…\r\n\x1b]0;title\x1b\\red\r\n";
+        assert_eq!(story(&mut transform, lines), vec!["red"]);
+        assert_eq!(transform.ansi_sequences_rendered(), 3);
+        assert_eq!(transform.noise_lines_filtered(), 1);
+        assert_eq!(transform.redraw_lines_deduped(), 1);
+    }
+
+    #[test]
+    fn small_cap_lets_evicted_lines_through_again() {
+        let lines = "alpha\r\nbeta\r\ngamma\r\nalpha\r\n";
+
+        let mut default_cap = TerminalTransform::new(80, 24, 50_000);
+        assert_eq!(
+            story(&mut default_cap, lines),
+            vec!["alpha", "beta", "gamma"]
+        );
+
+        let mut small_cap = TerminalTransform::new(80, 24, 2);
+        assert_eq!(
+            story(&mut small_cap, lines),
+            vec!["alpha", "beta", "gamma", "alpha"]
+        );
+    }
+
+    /// Replay `chunks` as separate output events and collect the story.
+    fn replay(height: usize, chunks: &[&str]) -> Vec<String> {
+        let mut transform = TerminalTransform::new(40, height, 50_000);
+        let mut events = chunks.iter().map(|d| Event::output(0.1, *d)).collect();
+        transform.transform(&mut events);
+        events
+            .iter()
+            .filter(|e| e.is_output())
+            .flat_map(|e| e.data.lines().map(str::to_string).collect::<Vec<_>>())
+            .collect()
+    }
+
+    #[test]
+    fn progress_line_then_newline_is_emitted_once() {
+        let story = replay(
+            24,
+            &["Downloading 10%", "\rDownloading 100%", "\r\n", "done\r\n"],
+        );
+        assert_eq!(story, vec!["Downloading 100%", "done"]);
+    }
+
+    #[test]
+    fn progress_line_at_eof_without_newline_is_flushed() {
+        let story = replay(24, &["start\r\n", "Downloading 10%", "\rDownloading 100%"]);
+        assert_eq!(story, vec!["start", "Downloading 100%"]);
+    }
+
+    #[test]
+    fn line_started_in_same_event_as_newline_is_not_emitted_early() {
+        let story = replay(
+            24,
+            &["Downloading 10%", "\rDownloading 100%\r\nnext", " line\r\n"],
+        );
+        assert_eq!(story, vec!["Downloading 100%", "next line"]);
+    }
+
+    #[test]
+    fn progress_line_finalized_by_scroll_is_emitted_once() {
+        let story = replay(
+            3,
+            &[
+                "one\r\n",
+                "two\r\n",
+                "Downloading 10%",
+                "\rDownloading 100%\r\n",
+                "after\r\n",
+                "tail",
+            ],
+        );
+        assert_eq!(
+            story,
+            vec!["one", "two", "Downloading 100%", "after", "tail"]
+        );
+    }
+
+    #[test]
+    fn lines_finalized_while_scrolling_are_not_skipped() {
+        let story = replay(
+            3,
+            &["one\r\n", "two\r\n", "three\r\n", "four\r\n", "fi", "ve"],
+        );
+        assert_eq!(story, vec!["one", "two", "three", "four", "five"]);
+    }
+
+    #[test]
+    fn repeated_lines_scrolled_off_are_not_emitted_twice() {
+        let lines = ["a\r\n", "b\r\n", "c\r\n", "d\r\n"];
+
+        // With no dedup memory, duplicates could only come from the
+        // emission logic itself
+        let mut transform = TerminalTransform::new(40, 2, 0);
+        let mut events = lines.iter().map(|d| Event::output(0.1, *d)).collect();
+        transform.transform(&mut events);
+        let story: Vec<&str> = events.iter().flat_map(|e| e.data.lines()).collect();
+        assert_eq!(story, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn heavily_rewritten_progress_line_is_noise_with_or_without_newline() {
+        let burst = ["Downloading 10%", "\rDownloading 50%", "\rDownloading 100%"];
+        assert_eq!(replay(24, &burst), Vec::<String>::new());
+
+        let mut with_newline = burst.to_vec();
+        with_newline.push("\r\n");
+        assert_eq!(replay(24, &with_newline), Vec::<String>::new());
+    }
+
+    #[test]
+    fn pinned_header_scroll_region_emits_each_line_once() {
+        // Header on row 1 and footer on row 6; the body scrolls in rows 2-5
+        let mut chunks = vec!["Agent v1\x1b[6;1H-- ready --\x1b[2;5r\x1b[2;1H".to_string()];
+        chunks.extend((1..=8).map(|i| format!("line {}\r\n", i)));
+        let chunks: Vec<&str> = chunks.iter().map(String::as_str).collect();
+
+        let mut expected = vec!["Agent v1".to_string()];
+        expected.extend((1..=8).map(|i| format!("line {}", i)));
+        // The blank last body row sits above the footer at the final flush
+        expected.push(String::new());
+        expected.push("-- ready --".to_string());
+        assert_eq!(replay(6, &chunks), expected);
+
+        // Scrolling several lines within one event loses none of them
+        let burst: String = chunks[1..].concat();
+        assert_eq!(replay(6, &[chunks[0], &burst]), expected);
+    }
+}
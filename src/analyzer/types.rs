@@ -2,6 +2,10 @@
 //!
 //! These types represent the cleaned content extracted from asciicast recordings,
 //! organized into segments with timestamp ranges and token estimates.
+//! Also holds the report formatters for analysis results.
+
+use super::backend::RawMarker;
+use crate::util::format_duration;
 
 /// A segment of analysis content with time range mapping.
 ///
@@ -25,6 +29,23 @@ pub struct AnalysisSegment {
     pub event_range: (usize, usize),
 }
 
+impl AnalysisSegment {
+    /// Stable fingerprint of the segment's cleaned content.
+    ///
+    /// Uses 64-bit FNV-1a so the value is identical across runs and builds,
+    /// which lets the analysis cache recognize segments that are unchanged
+    /// since a previous run. Time ranges are deliberately not included:
+    /// time redistribution can shift them slightly when content is appended.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        self.content.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
+
 /// Complete analysis content extracted from a cast file.
 #[derive(Debug)]
 pub struct AnalysisContent {
@@ -74,12 +95,19 @@ pub struct ExtractionStats {
     pub original_bytes: usize,
     /// Extracted content size in bytes
     pub extracted_bytes: usize,
-    /// Number of ANSI sequences stripped
+    /// Content size in bytes after terminal emulation (first pipeline stage)
+    pub terminal_bytes: usize,
+    /// Number of ANSI sequences stripped (rendered by the virtual terminal
+    /// plus any left for the content cleaner)
     pub ansi_sequences_stripped: usize,
     /// Number of control characters stripped
     pub control_chars_stripped: usize,
     /// Number of progress lines deduplicated via \r
     pub progress_lines_deduplicated: usize,
+    /// Number of rendered lines dropped as noise during terminal emulation
+    pub terminal_noise_lines: usize,
+    /// Number of rendered lines dropped as redraws during terminal emulation
+    pub terminal_lines_deduped: usize,
     /// Number of rapid events coalesced (TUI redraws)
     pub events_coalesced: usize,
     /// Number of lines removed by global frequency cap (repetitive separators/logs)
@@ -98,6 +126,8 @@ pub struct ExtractionStats {
     pub events_processed: usize,
     /// Number of events retained after filtering
     pub events_retained: usize,
+    /// Number of segments created from the retained events
+    pub segments: usize,
 }
 
 impl ExtractionStats {
@@ -119,6 +149,9 @@ impl ExtractionStats {
 ///
 /// Uses chars/3 heuristic for terminal content - simple, fast, no dependencies.
 /// Applied AFTER cleanup since raw content is 55-89% noise.
+///
+/// With the `tokenizer` feature, counts come from the `cl100k_base` BPE
+/// instead, so chunking against a `TokenBudget` matches what the model sees.
 #[derive(Debug, Clone)]
 pub struct TokenEstimator {
     /// Base ratio: characters per token (default: 4.0)
@@ -138,6 +171,10 @@ impl TokenEstimator {
 
     /// Estimate token count for the given text.
     pub fn estimate(&self, text: &str) -> usize {
+        if let Some(count) = bpe_token_count(text) {
+            return count;
+        }
+
         let char_count = text.chars().count();
         let raw_estimate = (char_count as f64 / self.chars_per_token).ceil() as usize;
         (raw_estimate as f64 * self.safety_factor) as usize
@@ -148,6 +185,10 @@ impl TokenEstimator {
     /// Code typically has 3.0-3.5 chars per token due to short identifiers
     /// and many special characters.
     pub fn estimate_code(&self, text: &str) -> usize {
+        if let Some(count) = bpe_token_count(text) {
+            return count;
+        }
+
         let char_count = text.chars().count();
         if char_count == 0 {
             return 0;
@@ -168,6 +209,22 @@ impl TokenEstimator {
     }
 }
 
+/// Exact token count using the BPE tokenizer.
+#[cfg(feature = "tokenizer")]
+fn bpe_token_count(text: &str) -> Option<usize> {
+    Some(
+        tiktoken_rs::cl100k_base_singleton()
+            .encode_ordinary(text)
+            .len(),
+    )
+}
+
+/// Without the `tokenizer` feature there is no exact count; use the heuristic.
+#[cfg(not(feature = "tokenizer"))]
+fn bpe_token_count(_text: &str) -> Option<usize> {
+    None
+}
+
 impl Default for TokenEstimator {
     fn default() -> Self {
         Self {
@@ -181,10 +238,78 @@ impl Default for TokenEstimator {
     }
 }
 
+/// Format markers as a markdown table of `timestamp | category | label`.
+///
+/// Timestamps are absolute and rendered as MM:SS like the player.
+pub fn to_markdown(markers: &[RawMarker]) -> String {
+    let mut out = String::from("| Timestamp | Category | Label |\n|---|---|---|\n");
+    for marker in markers {
+        out.push_str(&format!(
+            "| {} | {} | {} |\n",
+            format_duration(marker.timestamp),
+            category_name(marker),
+            marker.label.replace('|', "\\|").replace('\n', " ")
+        ));
+    }
+    out
+}
+
+/// Format markers as CSV with `timestamp,category,label` columns.
+///
+/// Timestamps are absolute and rendered as MM:SS like the player.
+pub fn to_csv(markers: &[RawMarker]) -> String {
+    let mut out = String::from("timestamp,category,label\n");
+    for marker in markers {
+        out.push_str(&format!(
+            "{},{},{}\n",
+            format_duration(marker.timestamp),
+            category_name(marker),
+            csv_field(&marker.label)
+        ));
+    }
+    out
+}
+
+/// Lowercase category name, as used in the marker JSON.
+fn category_name(marker: &RawMarker) -> String {
+    format!("{:?}", marker.category).to_lowercase()
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn segment(start_time: f64, content: &str) -> AnalysisSegment {
+        AnalysisSegment {
+            start_time,
+            end_time: start_time + 1.0,
+            content: content.to_string(),
+            estimated_tokens: 1,
+            event_range: (0, 1),
+        }
+    }
+
+    #[test]
+    fn segment_fingerprint_depends_only_on_content() {
+        let a = segment(0.0, "$ cargo build");
+        let shifted = segment(5.0, "$ cargo build");
+        let other = segment(0.0, "$ cargo test");
+
+        assert_eq!(a.fingerprint(), shifted.fingerprint());
+        assert_ne!(a.fingerprint(), other.fingerprint());
+        // FNV-1a of the empty string is the offset basis
+        assert_eq!(segment(0.0, "").fingerprint(), 0xcbf2_9ce4_8422_2325);
+    }
+
     #[test]
     fn token_estimator_default_values() {
         let estimator = TokenEstimator::default();
@@ -193,6 +318,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "tokenizer"))]
     fn token_estimator_estimate_basic() {
         let estimator = TokenEstimator::default();
 
@@ -207,6 +333,14 @@ mod tests {
         assert_eq!(estimator.estimate(""), 0);
     }
 
+    #[test]
+    #[cfg(feature = "tokenizer")]
+    fn token_estimator_uses_bpe_count() {
+        let estimator = TokenEstimator::default();
+        assert_eq!(estimator.estimate("hello world"), 2);
+        assert_eq!(estimator.estimate_code("hello world"), 2);
+    }
+
     #[test]
     fn token_estimator_estimate_code_with_whitespace() {
         let estimator = TokenEstimator::default();
@@ -337,4 +471,41 @@ mod tests {
 
         assert_eq!(content.text(), "first\nsecond");
     }
+
+    fn report_markers() -> Vec<RawMarker> {
+        use crate::analyzer::backend::MarkerCategory;
+        vec![
+            RawMarker {
+                timestamp: 5.4,
+                label: "Planned the fix".to_string(),
+                category: MarkerCategory::Planning,
+            },
+            RawMarker {
+                timestamp: 754.0,
+                label: "Tests pass, \"finally\" | done".to_string(),
+                category: MarkerCategory::Success,
+            },
+        ]
+    }
+
+    #[test]
+    fn to_markdown_renders_table_with_mmss_timestamps() {
+        assert_eq!(
+            to_markdown(&report_markers()),
+            "| Timestamp | Category | Label |\n\
+             |---|---|---|\n\
+             | 00:05 | planning | Planned the fix |\n\
+             | 12:34 | success | Tests pass, \"finally\" \\| done |\n"
+        );
+    }
+
+    #[test]
+    fn to_csv_quotes_fields_that_need_it() {
+        assert_eq!(
+            to_csv(&report_markers()),
+            "timestamp,category,label\n\
+             00:05,planning,Planned the fix\n\
+             12:34,success,\"Tests pass, \"\"finally\"\" | done\"\n"
+        );
+    }
 }
@@ -10,6 +10,9 @@
 //! - `ChunkResult` holds the result of analyzing a single chunk
 //! - `RetryExecutor` provides retry with fallback to sequential
 //! - Progress is reported via `ProgressReporter` callback
+//! - Markers can be surfaced while responses stream in via a `MarkerCallback`
+//! - Transient failures (rate limits, timeouts) back off and retry on their
+//!   own worker according to a `RetryPolicy`, so one 429 does not fail the run
 //!
 //! # Retry & Fallback Strategy
 //!
@@ -19,9 +22,9 @@
 //! 3. Sequential execution with small delay between chunks
 //! 4. Each chunk retried up to 3 times with exponential backoff
 
-use crate::analyzer::backend::{AgentBackend, BackendError, RawMarker};
+use crate::analyzer::backend::{AgentBackend, BackendError, MarkerStreamParser, RawMarker};
 use crate::analyzer::chunk::{AnalysisChunk, TimeRange};
-use crate::analyzer::tracker::TokenTracker;
+use crate::analyzer::tracker::{retry_with_policy, RetryPolicy, TokenTracker};
 use rayon::prelude::*;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -113,6 +116,8 @@ pub struct ChunkResult {
     pub time_range: TimeRange,
     /// Analysis result (markers or error)
     pub result: Result<Vec<RawMarker>, BackendError>,
+    /// Number of backend invocations made for this chunk
+    pub attempts: usize,
 }
 
 impl ChunkResult {
@@ -122,6 +127,7 @@ impl ChunkResult {
             chunk_id,
             time_range,
             result: Ok(markers),
+            attempts: 1,
         }
     }
 
@@ -131,9 +137,16 @@ impl ChunkResult {
             chunk_id,
             time_range,
             result: Err(error),
+            attempts: 1,
         }
     }
 
+    /// Record how many backend invocations the chunk took.
+    pub fn with_attempts(mut self, attempts: usize) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
     /// Check if this result is successful.
     pub fn is_success(&self) -> bool {
         self.result.is_ok()
@@ -203,25 +216,47 @@ impl ProgressReporter {
     }
 }
 
+/// Callback for markers discovered while a chunk's response is still streaming.
+///
+/// Receives the chunk's time range (to resolve the relative timestamp) and
+/// the partial marker. Called from worker threads.
+pub type MarkerCallback<'a> = &'a (dyn Fn(&TimeRange, &RawMarker) + Sync);
+
 /// Executor for parallel chunk analysis.
 pub struct ParallelExecutor<'a, B: AgentBackend + ?Sized> {
     backend: &'a B,
     timeout: Duration,
     worker_count: usize,
     use_schema: bool,
+    on_marker: Option<MarkerCallback<'a>>,
+    retry_policy: RetryPolicy,
 }
 
 impl<'a, B: AgentBackend + ?Sized> ParallelExecutor<'a, B> {
-    /// Create a new parallel executor.
+    /// Create a new parallel executor that makes a single attempt per chunk.
     pub fn new(backend: &'a B, timeout: Duration, worker_count: usize, use_schema: bool) -> Self {
         Self {
             backend,
             timeout,
             worker_count,
             use_schema,
+            on_marker: None,
+            retry_policy: RetryPolicy::no_retry(),
         }
     }
 
+    /// Stream agent output and report markers as they are parsed.
+    pub fn with_marker_callback(mut self, on_marker: MarkerCallback<'a>) -> Self {
+        self.on_marker = Some(on_marker);
+        self
+    }
+
+    /// Retry transient failures of a chunk according to `policy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Execute analysis on chunks, returning results for each.
     ///
     /// For a single chunk, processes directly without creating a thread pool.
@@ -303,6 +338,9 @@ impl<'a, B: AgentBackend + ?Sized> ParallelExecutor<'a, B> {
     }
 
     /// Analyze a single chunk using the backend.
+    ///
+    /// A transient failure sleeps per the retry policy (or the agent's
+    /// retry-after) and is retried on the same worker; other chunks keep going.
     fn analyze_chunk(
         &self,
         chunk: &AnalysisChunk,
@@ -310,12 +348,36 @@ impl<'a, B: AgentBackend + ?Sized> ParallelExecutor<'a, B> {
     ) -> ChunkResult {
         let prompt = prompt_builder(chunk);
 
-        match self.backend.invoke(&prompt, self.timeout, self.use_schema) {
+        let (response, attempts) =
+            retry_with_policy(&self.retry_policy, || self.invoke(chunk, &prompt));
+
+        let result = match response {
             Ok(response) => match self.backend.parse_response(&response) {
                 Ok(markers) => ChunkResult::success(chunk.id, chunk.time_range.clone(), markers),
                 Err(e) => ChunkResult::failure(chunk.id, chunk.time_range.clone(), e),
             },
             Err(e) => ChunkResult::failure(chunk.id, chunk.time_range.clone(), e),
+        };
+        result.with_attempts(attempts)
+    }
+
+    /// Invoke the backend once, streaming markers if a callback is set.
+    fn invoke(&self, chunk: &AnalysisChunk, prompt: &str) -> Result<String, BackendError> {
+        match self.on_marker {
+            Some(on_marker) => {
+                let mut parser = MarkerStreamParser::new();
+                self.backend.invoke_streaming(
+                    prompt,
+                    self.timeout,
+                    self.use_schema,
+                    &mut |partial| {
+                        for marker in parser.push(partial) {
+                            on_marker(&chunk.time_range, &marker);
+                        }
+                    },
+                )
+            }
+            None => self.backend.invoke(prompt, self.timeout, self.use_schema),
         }
     }
 }
@@ -330,19 +392,35 @@ pub struct RetryExecutor<'a, B: AgentBackend + ?Sized> {
     timeout: Duration,
     worker_count: usize,
     use_schema: bool,
+    on_marker: Option<MarkerCallback<'a>>,
+    retry_policy: RetryPolicy,
 }
 
 impl<'a, B: AgentBackend + ?Sized> RetryExecutor<'a, B> {
-    /// Create a new executor.
+    /// Create a new executor using the default retry policy.
     pub fn new(backend: &'a B, timeout: Duration, worker_count: usize, use_schema: bool) -> Self {
         Self {
             backend,
             timeout,
             worker_count,
             use_schema,
+            on_marker: None,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    /// Stream agent output and report markers as they are parsed.
+    pub fn with_marker_callback(mut self, on_marker: MarkerCallback<'a>) -> Self {
+        self.on_marker = Some(on_marker);
+        self
+    }
+
+    /// Override the retry policy for transient chunk failures.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Execute analysis with tracking.
     ///
     /// Returns tuple of (results, tracker) for visibility.
@@ -364,12 +442,16 @@ impl<'a, B: AgentBackend + ?Sized> RetryExecutor<'a, B> {
             chunks.iter().map(|c| (c.id, c.estimated_tokens)).collect();
 
         // Try parallel execution first
-        let parallel_executor = ParallelExecutor::new(
+        let mut parallel_executor = ParallelExecutor::new(
             self.backend,
             self.timeout,
             self.worker_count,
             self.use_schema,
-        );
+        )
+        .with_retry_policy(self.retry_policy.clone());
+        if let Some(on_marker) = self.on_marker {
+            parallel_executor = parallel_executor.with_marker_callback(on_marker);
+        }
 
         let results = parallel_executor.execute(chunks, progress, &prompt_builder);
 
@@ -398,9 +480,11 @@ impl<'a, B: AgentBackend + ?Sized> RetryExecutor<'a, B> {
                     result.chunk_id,
                     tokens,
                     Duration::ZERO, // Parallel doesn't track individual durations
-                    1,
+                    result.attempts,
                 ),
-                Err(_) => tracker.record_failure(result.chunk_id, tokens, Duration::ZERO, 1),
+                Err(_) => {
+                    tracker.record_failure(result.chunk_id, tokens, Duration::ZERO, result.attempts)
+                }
             }
         }
 
@@ -866,6 +950,150 @@ mod tests {
         assert_eq!(ids, vec![0, 1, 2]);
     }
 
+    #[test]
+    fn parallel_executor_reports_streamed_markers() {
+        let backend = MockBackend::new(vec![Ok(
+            r#"{"markers": [{"timestamp": 5.0, "label": "Found", "category": "success"}]}"#
+                .to_string(),
+        )]);
+        let seen = Mutex::new(Vec::new());
+        let on_marker = |range: &TimeRange, marker: &RawMarker| {
+            seen.lock()
+                .unwrap()
+                .push((range.start, marker.label.clone()));
+        };
+        let executor = ParallelExecutor::new(&backend, Duration::from_secs(60), 1, true)
+            .with_marker_callback(&on_marker);
+        let progress = ProgressReporter::new(1);
+
+        let results = executor.execute(vec![create_test_chunk(0, 100.0, 200.0)], &progress, |_| {
+            "test".to_string()
+        });
+
+        assert!(results[0].is_success());
+        assert_eq!(
+            seen.into_inner().unwrap(),
+            vec![(100.0, "Found".to_string())]
+        );
+    }
+
+    /// Backend that answers every prompt with the prompt itself.
+    struct EchoBackend;
+
+    impl AgentBackend for EchoBackend {
+        fn name(&self) -> &'static str {
+            "echo"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn invoke(
+            &self,
+            prompt: &str,
+            _timeout: Duration,
+            _use_schema: bool,
+        ) -> Result<String, BackendError> {
+            // Finish out of order so ordering does not come from timing
+            std::thread::sleep(Duration::from_millis(prompt.len() as u64 % 7));
+            Ok(prompt.to_string())
+        }
+
+        fn parse_response(&self, response: &str) -> Result<Vec<RawMarker>, BackendError> {
+            crate::analyzer::extract_json(response).map(|r| r.markers)
+        }
+
+        fn token_budget(&self) -> TokenBudget {
+            TokenBudget::claude()
+        }
+    }
+
+    #[test]
+    fn parallel_executor_pool_preserves_chunk_and_marker_order() {
+        use crate::analyzer::result::ResultAggregator;
+
+        let chunk_count = 12;
+        let executor = ParallelExecutor::new(&EchoBackend, Duration::from_secs(60), 4, true);
+        let chunks: Vec<_> = (0..chunk_count)
+            .map(|i| create_test_chunk(i, i as f64 * 100.0, (i + 1) as f64 * 100.0))
+            .collect();
+        let progress = ProgressReporter::new(chunk_count);
+
+        let results = executor.execute(chunks, &progress, |chunk| {
+            format!(
+                r#"{{"markers": [{{"timestamp": 50.0, "label": "Chunk {}", "category": "implementation"}}]}}"#,
+                chunk.id
+            )
+        });
+
+        assert_eq!(progress.get_progress(), (chunk_count, chunk_count));
+        let ids: Vec<_> = results.iter().map(|r| r.chunk_id).collect();
+        assert_eq!(ids, (0..chunk_count).collect::<Vec<_>>());
+
+        let (markers, _) = ResultAggregator::new(chunk_count as f64 * 100.0).aggregate(results);
+        assert_eq!(markers.len(), chunk_count);
+        for (i, marker) in markers.iter().enumerate() {
+            assert_eq!(marker.timestamp, i as f64 * 100.0 + 50.0);
+        }
+    }
+
+    #[test]
+    fn parallel_executor_backs_off_rate_limited_chunk() {
+        use crate::analyzer::backend::RateLimitInfo;
+
+        let backend = MockBackend::new(vec![
+            Err(BackendError::RateLimited(RateLimitInfo {
+                retry_after: Some(Duration::from_millis(10)),
+                message: "Rate limited".to_string(),
+            })),
+            Ok(
+                r#"{"markers": [{"timestamp": 1.0, "label": "Ok", "category": "success"}]}"#
+                    .to_string(),
+            ),
+        ]);
+        let executor = ParallelExecutor::new(&backend, Duration::from_secs(60), 1, true)
+            .with_retry_policy(RetryPolicy::default());
+        let progress = ProgressReporter::new(1);
+
+        let results = executor.execute(vec![create_test_chunk(0, 0.0, 100.0)], &progress, |_| {
+            "test".to_string()
+        });
+
+        assert!(results[0].is_success());
+        assert_eq!(results[0].attempts, 2);
+        assert_eq!(backend.invocation_count(), 2);
+    }
+
+    #[test]
+    fn parallel_executor_gives_up_after_max_attempts() {
+        use crate::analyzer::backend::RateLimitInfo;
+
+        let rate_limited = || {
+            Err(BackendError::RateLimited(RateLimitInfo {
+                retry_after: Some(Duration::from_millis(1)),
+                message: "Rate limited".to_string(),
+            }))
+        };
+        let backend = MockBackend::new(vec![rate_limited(), rate_limited(), rate_limited()]);
+        let executor = ParallelExecutor::new(&backend, Duration::from_secs(60), 1, true)
+            .with_retry_policy(RetryPolicy::default());
+        let progress = ProgressReporter::new(1);
+
+        let results = executor.execute(vec![create_test_chunk(0, 0.0, 100.0)], &progress, |_| {
+            "test".to_string()
+        });
+
+        assert!(matches!(
+            results[0].result,
+            Err(BackendError::RateLimited(_))
+        ));
+        assert_eq!(
+            backend.invocation_count(),
+            RetryPolicy::default().max_attempts
+        );
+    }
+
     // ============================================
     // Integration Tests (WorkerScaler + Executor)
     // ============================================
@@ -0,0 +1,161 @@
+//! Resize normalization transform for asciicast recordings.
+//!
+//! This module provides the [`DedupeResizes`] transform that drops resize
+//! events which repeat the dimensions of the previous resize. Some terminals
+//! emit a resize on every focus change or redraw, which bloats the file and
+//! makes the player rebuild its buffer for nothing.
+//!
+//! # Semantics
+//!
+//! A resize is redundant when the last resize before it had the same
+//! dimensions; events of other types in between do not matter. The time
+//! delta of a dropped resize is folded into the next kept event, so every
+//! other event keeps its absolute timestamp and the total duration is
+//! unchanged. Resize events with malformed data are always kept.
+//!
+//! # Example
+//!
+//! ```
+//! use agr::asciicast::{DedupeResizes, Event, EventType, Transform};
+//!
+//! let mut transform = DedupeResizes::new();
+//! let mut events = vec![
+//!     Event::new(0.5, EventType::Resize, "100x30"),
+//!     Event::new(0.2, EventType::Resize, "100x30"),
+//!     Event::output(0.3, "hello"),
+//! ];
+//!
+//! transform.transform(&mut events);
+//!
+//! assert_eq!(events.len(), 2);
+//! assert!((events[1].time - 0.5).abs() < 0.001);
+//! assert_eq!(transform.removed(), 1);
+//! ```
+
+use super::{Event, Transform};
+
+/// A transform that drops resize events repeating the previous dimensions.
+///
+/// The transform is stateful: after running it reports how many resize
+/// events it removed. Running it again accumulates into the same counter.
+#[derive(Debug, Clone, Default)]
+pub struct DedupeResizes {
+    removed: usize,
+}
+
+impl DedupeResizes {
+    /// Create a new resize normalization transform.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of resize events removed so far.
+    pub fn removed(&self) -> usize {
+        self.removed
+    }
+}
+
+impl Transform for DedupeResizes {
+    fn transform(&mut self, events: &mut Vec<Event>) {
+        let mut last_size = None;
+        let mut carried = 0.0;
+        let mut dropped_tail = None;
+
+        events.retain_mut(|event| {
+            if let Some(size) = event.parse_resize() {
+                if last_size == Some(size) {
+                    carried += event.time;
+                    self.removed += 1;
+                    dropped_tail = Some(event.clone());
+                    return false;
+                }
+                last_size = Some(size);
+            }
+            event.time += carried;
+            carried = 0.0;
+            dropped_tail = None;
+            true
+        });
+
+        // Nothing follows a trailing redundant resize to absorb its delta, so
+        // keep one of them rather than shortening the recording
+        if let Some(mut event) = dropped_tail {
+            event.time = carried;
+            events.push(event);
+            self.removed -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::EventType;
+
+    fn resize(time: f64, size: &str) -> Event {
+        Event::new(time, EventType::Resize, size)
+    }
+
+    fn total(events: &[Event]) -> f64 {
+        events.iter().map(|e| e.time).sum()
+    }
+
+    #[test]
+    fn drops_repeated_resize_and_keeps_duration() {
+        let mut events = vec![
+            resize(0.5, "80x24"),
+            Event::output(0.25, "a"),
+            resize(1.0, "80x24"),
+            resize(0.5, "120x40"),
+            Event::output(0.25, "b"),
+        ];
+        let before = total(&events);
+
+        let mut transform = DedupeResizes::new();
+        transform.transform(&mut events);
+
+        let sizes: Vec<_> = events.iter().filter_map(Event::parse_resize).collect();
+        assert_eq!(sizes, vec![(80, 24), (120, 40)]);
+        assert_eq!(transform.removed(), 1);
+        assert!((events[2].time - 1.5).abs() < 1e-9);
+        assert!((total(&events) - before).abs() < 1e-9);
+    }
+
+    #[test]
+    fn keeps_resize_back_to_earlier_size() {
+        let mut events = vec![
+            resize(0.1, "80x24"),
+            resize(0.1, "100x30"),
+            resize(0.1, "80x24"),
+        ];
+
+        DedupeResizes::new().transform(&mut events);
+
+        assert_eq!(events.len(), 3);
+    }
+
+    #[test]
+    fn trailing_repeats_collapse_into_one_event() {
+        let mut events = vec![
+            resize(0.5, "80x24"),
+            resize(0.25, "80x24"),
+            resize(0.25, "80x24"),
+        ];
+
+        let mut transform = DedupeResizes::new();
+        transform.transform(&mut events);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(transform.removed(), 1);
+        assert!((total(&events) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn malformed_resizes_are_kept() {
+        let mut events = vec![resize(0.1, "bogus"), resize(0.1, "bogus")];
+
+        DedupeResizes::new().transform(&mut events);
+
+        assert_eq!(events.len(), 2);
+    }
+}
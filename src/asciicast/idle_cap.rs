@@ -0,0 +1,188 @@
+//! Idle-time capping transform for asciicast recordings.
+//!
+//! This module provides the [`CapIdleTime`] transform that clamps every gap
+//! between events to a maximum value. Unlike [`SilenceRemoval`](super::SilenceRemoval),
+//! which exists to strip pauses down to a short default, this transform is meant
+//! for user-chosen limits and keeps track of how much time it removed.
+//!
+//! # Semantics
+//!
+//! The clamping matches how asciinema applies `idle_time_limit` at playback:
+//! a gap longer than the limit is shortened to exactly the limit, never removed.
+//! Baking the cap into the events does not touch the header, so a stricter
+//! `idle_time_limit` in the header still applies when the file is played.
+//!
+//! # Example
+//!
+//! ```
+//! use agr::asciicast::{CapIdleTime, Event, Transform};
+//!
+//! let mut transform = CapIdleTime::new(3.0);
+//! let mut events = vec![
+//!     Event::output(0.5, "prompt"),
+//!     Event::output(45.0, "model replied"),  // waited on the model
+//!     Event::output(0.1, "more output"),
+//! ];
+//!
+//! transform.transform(&mut events);
+//!
+//! assert!((events[1].time - 3.0).abs() < 0.001);
+//! assert!((transform.time_removed() - 42.0).abs() < 0.001);
+//! assert_eq!(transform.gaps_capped(), 1);
+//! ```
+
+use super::{Event, SilenceRemoval, Transform};
+
+/// Default maximum gap for idle-time capping (5.0 seconds).
+///
+/// Longer than the silence removal default so that model "thinking" pauses
+/// remain noticeable during playback.
+pub const DEFAULT_MAX_IDLE_GAP: f64 = 5.0;
+
+/// A transform that clamps each event interval to a maximum gap.
+///
+/// The clamping itself is [`SilenceRemoval`]'s; this transform adds
+/// bookkeeping. After running it reports the total time removed and the
+/// number of gaps that were shortened. Running it again accumulates into the
+/// same counters.
+///
+/// # Validation
+///
+/// The maximum gap must be a positive, finite number. Construction with invalid
+/// values (zero, negative, NaN, infinity) will panic.
+#[derive(Debug, Clone)]
+pub struct CapIdleTime {
+    gap: SilenceRemoval,
+    time_removed: f64,
+    gaps_capped: usize,
+}
+
+impl CapIdleTime {
+    /// Create a new idle-time capping transform.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_gap` is zero, negative, NaN, or infinite.
+    pub fn new(max_gap: f64) -> Self {
+        assert!(
+            max_gap > 0.0 && max_gap.is_finite(),
+            "Max gap must be positive and finite, got: {}",
+            max_gap
+        );
+        Self {
+            gap: SilenceRemoval::new(max_gap),
+            time_removed: 0.0,
+            gaps_capped: 0,
+        }
+    }
+
+    /// Get the configured maximum gap in seconds.
+    pub fn max_gap(&self) -> f64 {
+        self.gap.threshold()
+    }
+
+    /// Total time removed by this transform in seconds.
+    pub fn time_removed(&self) -> f64 {
+        self.time_removed
+    }
+
+    /// Number of event intervals that were shortened.
+    pub fn gaps_capped(&self) -> usize {
+        self.gaps_capped
+    }
+}
+
+impl Transform for CapIdleTime {
+    fn transform(&mut self, events: &mut Vec<Event>) {
+        for event in events.iter_mut() {
+            let removed = self.gap.cap_gap(event);
+            if removed > 0.0 {
+                self.time_removed += removed;
+                self.gaps_capped += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaps_above_max_are_clamped() {
+        let mut events = vec![
+            Event::output(1.0, "a"),
+            Event::output(30.0, "b"),
+            Event::output(7.5, "c"),
+        ];
+
+        let mut transform = CapIdleTime::new(5.0);
+        transform.transform(&mut events);
+
+        assert!((events[0].time - 1.0).abs() < 0.001);
+        assert!((events[1].time - 5.0).abs() < 0.001);
+        assert!((events[2].time - 5.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn reports_time_removed_and_gaps_capped() {
+        let mut events = vec![
+            Event::output(1.0, "a"),
+            Event::output(30.0, "b"),
+            Event::output(7.5, "c"),
+        ];
+
+        let mut transform = CapIdleTime::new(5.0);
+        transform.transform(&mut events);
+
+        assert!((transform.time_removed() - 27.5).abs() < 0.001);
+        assert_eq!(transform.gaps_capped(), 2);
+    }
+
+    #[test]
+    fn gap_equal_to_max_is_unchanged() {
+        let mut events = vec![Event::output(5.0, "a")];
+
+        let mut transform = CapIdleTime::new(5.0);
+        transform.transform(&mut events);
+
+        assert!((events[0].time - 5.0).abs() < 0.001);
+        assert_eq!(transform.gaps_capped(), 0);
+        assert_eq!(transform.time_removed(), 0.0);
+    }
+
+    #[test]
+    fn applies_to_all_event_types() {
+        let mut events = vec![
+            Event::marker(10.0, "m"),
+            Event::new(10.0, crate::asciicast::EventType::Resize, "80x24"),
+        ];
+
+        let mut transform = CapIdleTime::new(2.0);
+        transform.transform(&mut events);
+
+        assert!(events.iter().all(|e| (e.time - 2.0).abs() < 0.001));
+    }
+
+    #[test]
+    fn empty_events_is_noop() {
+        let mut events: Vec<Event> = vec![];
+        let mut transform = CapIdleTime::new(2.0);
+        transform.transform(&mut events);
+
+        assert!(events.is_empty());
+        assert_eq!(transform.time_removed(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Max gap must be positive")]
+    fn zero_max_gap_panics() {
+        CapIdleTime::new(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Max gap must be positive")]
+    fn nan_max_gap_panics() {
+        CapIdleTime::new(f64::NAN);
+    }
+}
@@ -1,12 +1,44 @@
 //! Marker operations for asciicast files
 //!
-//! Provides functionality for adding and listing markers in asciicast recordings.
+//! Provides functionality for adding, listing and removing markers in
+//! asciicast recordings.
 
 use std::path::Path;
 
 use anyhow::{bail, Result};
 
-use super::types::{AsciicastFile, Event, EventType};
+use super::types::{AsciicastFile, EventType};
+use crate::files::backup::create_backup;
+
+/// Parse a marker timestamp given as `MM:SS` or raw seconds.
+///
+/// Accepts `1:23`, `01:23.5` and `83.5`. Returns `None` for negative or
+/// malformed input, including seconds of 60 or more in `MM:SS` form.
+pub fn parse_marker_timestamp(s: &str) -> Option<f64> {
+    let seconds = match s.split_once(':') {
+        Some((minutes, secs)) => {
+            let minutes: u64 = minutes.parse().ok()?;
+            let secs: f64 = secs.parse().ok()?;
+            if !(0.0..60.0).contains(&secs) {
+                return None;
+            }
+            minutes as f64 * 60.0 + secs
+        }
+        None => s.parse().ok()?,
+    };
+    (seconds.is_finite() && seconds >= 0.0).then_some(seconds)
+}
+
+/// A marker with its absolute position in the recording.
+///
+/// Returned by [`AsciicastFile::marker_positions`], in recording order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkerPosition {
+    /// Cumulative time when the marker occurs
+    pub time: f64,
+    /// Marker label (from the cast file)
+    pub label: String,
+}
 
 /// Information about a marker
 #[derive(Debug, Clone)]
@@ -25,8 +57,17 @@ impl std::fmt::Display for MarkerInfo {
 pub struct MarkerManager;
 
 impl MarkerManager {
-    /// Add a marker to an asciicast file at the specified timestamp
-    pub fn add_marker<P: AsRef<Path>>(path: P, timestamp: f64, label: &str) -> Result<()> {
+    /// Add a marker to a recording on disk, backing it up first.
+    ///
+    /// The timestamp must lie within the recording, and the events after the
+    /// marker keep their absolute times. `backup_limit` is the number of
+    /// backup generations to keep (`storage.backup_limit`).
+    pub fn add_marker<P: AsRef<Path>>(
+        path: P,
+        timestamp: f64,
+        label: &str,
+        backup_limit: usize,
+    ) -> Result<()> {
         let path = path.as_ref();
 
         if timestamp < 0.0 {
@@ -38,6 +79,16 @@ impl MarkerManager {
         }
 
         let mut cast = AsciicastFile::parse(path)?;
+        let duration = cast.duration();
+        if timestamp > duration {
+            bail!(
+                "timestamp {:.1}s is past the end of the recording ({:.1}s)",
+                timestamp,
+                duration
+            );
+        }
+
+        create_backup(path, backup_limit)?;
         Self::add_marker_to_cast(&mut cast, timestamp, label)?;
         cast.write(path)?;
 
@@ -45,44 +96,60 @@ impl MarkerManager {
     }
 
     /// Add a marker to an asciicast file in memory
+    ///
+    /// Events after the marker keep their absolute times; see
+    /// [`AsciicastFile::insert_marker`].
     pub fn add_marker_to_cast(cast: &mut AsciicastFile, timestamp: f64, label: &str) -> Result<()> {
-        let index = cast.find_insertion_index(timestamp);
-        let relative_time = cast.calculate_relative_time(index, timestamp);
-
-        let marker = Event::marker(relative_time, label);
-        cast.events.insert(index, marker);
-
-        // Adjust the next event's time if there is one
-        if index + 1 < cast.events.len() {
-            let cumulative_times = {
-                let mut times = Vec::with_capacity(cast.events.len());
-                let mut cumulative = 0.0;
-                for event in &cast.events {
-                    cumulative += event.time;
-                    times.push(cumulative);
-                }
-                times
-            };
-
-            // The next event's cumulative time needs to remain the same
-            // So we need to adjust its relative time
-            if let Some(next_event) = cast.events.get_mut(index + 1) {
-                let original_cumulative = cumulative_times.get(index + 1).copied().unwrap_or(0.0);
-                let new_cumulative_for_marker = timestamp;
-                let adjustment = original_cumulative - new_cumulative_for_marker - next_event.time;
-                if adjustment > 0.0 {
-                    next_event.time += adjustment;
-                } else {
-                    // If the marker is at exactly the same time or after, set next event time to 0
-                    let marker_cumulative = cumulative_times.get(index).copied().unwrap_or(0.0);
-                    if marker_cumulative >= original_cumulative {
-                        next_event.time = 0.0;
-                    }
-                }
-            }
+        cast.insert_marker(timestamp, label);
+        Ok(())
+    }
+
+    /// Remove the marker at `index` (0-based, in recording order) from a
+    /// recording on disk, backing it up first.
+    ///
+    /// `backup_limit` is the number of backup generations to keep
+    /// (`storage.backup_limit`). Returns the removed marker.
+    pub fn remove_marker(path: &Path, index: usize, backup_limit: usize) -> Result<MarkerInfo> {
+        let mut cast = AsciicastFile::parse(path)?;
+        let count = Self::count_markers_from_cast(&cast);
+        let Some(removed) = Self::remove_marker_from_cast(&mut cast, index) else {
+            bail!(
+                "No marker #{} (recording has {} marker{})",
+                index + 1,
+                count,
+                if count == 1 { "" } else { "s" }
+            );
+        };
+
+        create_backup(path, backup_limit)?;
+        cast.write(path)?;
+        Ok(removed)
+    }
+
+    /// Remove the marker at `index` (0-based, in recording order) in memory.
+    ///
+    /// The marker's relative time is carried over to the next event so that
+    /// cumulative timestamps are preserved. Returns `None` if there is no
+    /// such marker.
+    pub fn remove_marker_from_cast(cast: &mut AsciicastFile, index: usize) -> Option<MarkerInfo> {
+        let cumulative_times = cast.cumulative_times();
+        let (position, _) = cast
+            .events
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.is_marker())
+            .nth(index)?;
+
+        let timestamp = cumulative_times[position];
+        let marker = cast.events.remove(position);
+        if let Some(next) = cast.events.get_mut(position) {
+            next.time += marker.time;
         }
 
-        Ok(())
+        Some(MarkerInfo {
+            timestamp,
+            label: marker.data,
+        })
     }
 
     /// List all markers in an asciicast file
@@ -93,20 +160,14 @@ impl MarkerManager {
 
     /// List all markers from an asciicast file in memory
     pub fn list_markers_from_cast(cast: &AsciicastFile) -> Result<Vec<MarkerInfo>> {
-        let cumulative_times = cast.cumulative_times();
-        let mut markers = Vec::new();
-
-        for (i, event) in cast.events.iter().enumerate() {
-            if event.event_type == EventType::Marker {
-                let timestamp = cumulative_times.get(i).copied().unwrap_or(0.0);
-                markers.push(MarkerInfo {
-                    timestamp,
-                    label: event.data.clone(),
-                });
-            }
-        }
-
-        Ok(markers)
+        Ok(cast
+            .marker_positions()
+            .into_iter()
+            .map(|marker| MarkerInfo {
+                timestamp: marker.time,
+                label: marker.label,
+            })
+            .collect())
     }
 
     /// Clear all markers from an asciicast file
@@ -164,7 +225,7 @@ impl MarkerManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::asciicast::Header;
+    use crate::asciicast::{Event, Header};
 
     fn create_test_cast() -> AsciicastFile {
         let mut cast = AsciicastFile::new(Header {
@@ -196,6 +257,16 @@ mod tests {
         assert_eq!(cast.events[1].data, "test");
     }
 
+    #[test]
+    fn add_marker_to_cast_keeps_later_events_in_place() {
+        let mut cast = create_test_cast();
+        MarkerManager::add_marker_to_cast(&mut cast, 0.15, "test").unwrap();
+
+        let times = cast.cumulative_times();
+        assert!((times[2] - 0.3).abs() < 1e-9);
+        assert!((times[3] - 0.6).abs() < 1e-9);
+    }
+
     #[test]
     fn add_marker_at_start() {
         let mut cast = create_test_cast();
@@ -228,6 +299,103 @@ mod tests {
         assert_eq!(markers[1].label, "second");
     }
 
+    #[test]
+    fn parse_marker_timestamp_accepts_mm_ss() {
+        assert_eq!(parse_marker_timestamp("1:23"), Some(83.0));
+        assert_eq!(parse_marker_timestamp("01:05.5"), Some(65.5));
+        assert_eq!(parse_marker_timestamp("90:00"), Some(5400.0));
+    }
+
+    #[test]
+    fn parse_marker_timestamp_accepts_raw_seconds() {
+        assert_eq!(parse_marker_timestamp("83"), Some(83.0));
+        assert_eq!(parse_marker_timestamp("2.5"), Some(2.5));
+    }
+
+    #[test]
+    fn parse_marker_timestamp_rejects_invalid() {
+        assert_eq!(parse_marker_timestamp("1:75"), None);
+        assert_eq!(parse_marker_timestamp("-3"), None);
+        assert_eq!(parse_marker_timestamp("abc"), None);
+        assert_eq!(parse_marker_timestamp("1:2:3"), None);
+    }
+
+    fn write_temp_cast(content: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("session.cast");
+        std::fs::write(&path, content).unwrap();
+        (temp, path)
+    }
+
+    #[test]
+    fn add_marker_writes_marker_and_backup() {
+        let (_temp, path) = write_temp_cast(
+            "{\"version\":3,\"term\":{\"cols\":80,\"rows\":24}}\n\
+             [0.5,\"o\",\"hello\"]\n\
+             [1.5,\"o\",\"world\"]\n",
+        );
+
+        MarkerManager::add_marker(&path, 1.0, "checkpoint", 1).unwrap();
+
+        assert!(crate::files::backup::has_backup(&path));
+        let cast = AsciicastFile::parse(&path).unwrap();
+        let markers = MarkerManager::list_markers_from_cast(&cast).unwrap();
+        assert_eq!(markers.len(), 1);
+        assert_eq!(markers[0].label, "checkpoint");
+        assert!((markers[0].timestamp - 1.0).abs() < 1e-9);
+        assert!((cast.duration() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn add_marker_rejects_timestamp_past_end() {
+        let (_temp, path) = write_temp_cast(
+            "{\"version\":3,\"term\":{\"cols\":80,\"rows\":24}}\n[0.5,\"o\",\"hi\"]\n",
+        );
+
+        assert!(MarkerManager::add_marker(&path, 10.0, "late", 1).is_err());
+        assert!(!crate::files::backup::has_backup(&path));
+    }
+
+    #[test]
+    fn remove_marker_from_cast_preserves_timing() {
+        let mut cast = create_test_cast();
+        cast.events.insert(1, Event::marker(0.05, "first"));
+        cast.events[2].time = 0.15;
+        cast.events.push(Event::marker(0.1, "second"));
+        let duration = cast.duration();
+        let times_before: Vec<f64> = cast
+            .events
+            .iter()
+            .zip(cast.cumulative_times())
+            .filter(|(e, _)| !e.is_marker())
+            .map(|(_, t)| t)
+            .collect();
+
+        let removed = MarkerManager::remove_marker_from_cast(&mut cast, 0).unwrap();
+
+        assert_eq!(removed.label, "first");
+        assert!((removed.timestamp - 0.15).abs() < 1e-9);
+        let times_after: Vec<f64> = cast
+            .events
+            .iter()
+            .zip(cast.cumulative_times())
+            .filter(|(e, _)| !e.is_marker())
+            .map(|(_, t)| t)
+            .collect();
+        for (before, after) in times_before.iter().zip(&times_after) {
+            assert!((before - after).abs() < 1e-9);
+        }
+        assert!((cast.duration() - duration).abs() < 1e-9);
+        assert_eq!(MarkerManager::count_markers_from_cast(&cast), 1);
+    }
+
+    #[test]
+    fn remove_marker_from_cast_out_of_range_is_none() {
+        let mut cast = create_test_cast();
+        assert!(MarkerManager::remove_marker_from_cast(&mut cast, 0).is_none());
+        assert_eq!(cast.events.len(), 3);
+    }
+
     #[test]
     fn marker_info_display() {
         let info = MarkerInfo {
@@ -12,31 +12,67 @@
 //! - [`writer`] - Writing asciicast files to various destinations
 //! - [`marker`] - Adding and listing markers in recordings
 //! - [`transform`] - Event transformation pipeline utilities
+//! - [`progress`] - Progress reporting for long-running transforms
+//! - [`redact`] - Secret redaction before sharing
+//! - [`dedupe_resizes`] - Dropping redundant resize events
 //! - [`transform_ops`] - High-level file transform operations (backup, restore)
+//! - [`validate`] - Recording validation (`agr validate`)
 
+pub mod dedupe_resizes;
+mod idle_cap;
 pub mod integrity;
 pub mod marker;
+pub mod progress;
 mod reader;
+pub mod redact;
+mod scale_time;
 mod silence_removal;
 mod transform;
 pub mod transform_ops;
 mod types;
+pub mod validate;
 mod writer;
 
 // Re-export marker types
-pub use marker::{MarkerInfo, MarkerManager};
+pub use marker::{parse_marker_timestamp, MarkerInfo, MarkerManager, MarkerPosition};
+
+// Re-export idle-time capping types
+pub use idle_cap::{CapIdleTime, DEFAULT_MAX_IDLE_GAP};
+
+// Re-export resize normalization transform
+pub use dedupe_resizes::DedupeResizes;
+
+// Re-export speed transform
+pub use scale_time::ScaleTime;
+
+// Re-export secret redaction types
+pub use redact::{RedactSecrets, DEFAULT_SECRET_PATTERNS, REDACTED};
 
 // Re-export silence removal types
-pub use silence_removal::{SilenceRemoval, DEFAULT_SILENCE_THRESHOLD};
+pub use silence_removal::{SilenceRemoval, SilenceRemovalStats, DEFAULT_SILENCE_THRESHOLD};
 
 // Re-export transform types
 pub use transform::{Transform, TransformChain};
 
+// Re-export progress reporting
+pub use progress::{Progress, PROGRESS_INTERVAL};
+
 // Re-export transform_ops types for convenience
-pub use transform_ops::{apply_transforms, TransformResult};
+pub use transform_ops::{
+    apply_transforms, apply_transforms_with, apply_transforms_with_progress, OptimizeMode,
+    TransformResult,
+};
 
 // Re-export integrity types
 pub use integrity::{check_file_integrity, diagnose, repair, DiagnoseResult, LineDiagnostic};
 
+// Re-export validation types
+pub use validate::{validate_file, validate_str, Severity, ValidationIssue, ValidationReport};
+
 // Re-export core types
-pub use types::{AsciicastFile, EnvInfo, Event, EventType, Header, TermInfo};
+pub use types::{
+    AsciicastFile, EnvInfo, Event, EventType, Header, HeaderBuilder, RgbColor, TermInfo, TtyTheme,
+};
+
+// Re-export writer options
+pub use writer::{TimePrecision, WriteOptions};
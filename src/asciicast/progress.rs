@@ -0,0 +1,120 @@
+//! Progress reporting for long-running transforms.
+//!
+//! Callers pass an optional `&mut dyn FnMut(f64)` that receives the fraction
+//! of work done, from 0.0 to 1.0. [`Progress`] wraps that callback so it only
+//! ever sees increasing values, and [`transform_in_batches`] runs a per-event
+//! transform over a large recording in batches, reporting after each one.
+
+use super::{Event, Transform};
+
+/// Number of events processed between progress reports.
+pub const PROGRESS_INTERVAL: usize = 4096;
+
+/// Optional progress callback that only sees increasing fractions.
+pub struct Progress<'a> {
+    callback: Option<&'a mut dyn FnMut(f64)>,
+    last: Option<f64>,
+}
+
+impl<'a> Progress<'a> {
+    /// Wrap a caller's progress callback, if any.
+    pub fn new(callback: Option<&'a mut dyn FnMut(f64)>) -> Self {
+        Self {
+            callback,
+            last: None,
+        }
+    }
+
+    /// Report `fraction` of the work as done.
+    ///
+    /// The value is clamped to 0.0..=1.0. Values that do not exceed the last
+    /// reported one are dropped.
+    pub fn report(&mut self, fraction: f64) {
+        let fraction = fraction.clamp(0.0, 1.0);
+        if self.last.is_some_and(|last| fraction <= last) {
+            return;
+        }
+        if let Some(callback) = self.callback.as_mut() {
+            self.last = Some(fraction);
+            callback(fraction);
+        }
+    }
+}
+
+/// Apply a per-event transform in batches of [`PROGRESS_INTERVAL`] events.
+///
+/// After each batch, progress is reported proportionally between `start` and
+/// `end`. Only use this with transforms that treat every event on its own,
+/// such as [`CapIdleTime`](super::CapIdleTime): a transform that looks at
+/// neighbouring events would not see across batch boundaries.
+pub fn transform_in_batches(
+    transform: &mut dyn Transform,
+    events: &mut Vec<Event>,
+    progress: &mut Progress<'_>,
+    start: f64,
+    end: f64,
+) {
+    let total = events.len();
+    let mut remaining = std::mem::take(events).into_iter();
+    let mut processed = 0;
+
+    loop {
+        let mut batch: Vec<Event> = remaining.by_ref().take(PROGRESS_INTERVAL).collect();
+        if batch.is_empty() {
+            break;
+        }
+        processed += batch.len();
+        transform.transform(&mut batch);
+        events.append(&mut batch);
+        progress.report(start + (end - start) * processed as f64 / total as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::CapIdleTime;
+
+    #[test]
+    fn report_drops_values_that_do_not_increase() {
+        let mut seen = Vec::new();
+        let mut callback = |fraction| seen.push(fraction);
+        let mut progress = Progress::new(Some(&mut callback));
+
+        progress.report(0.2);
+        progress.report(0.2);
+        progress.report(0.1);
+        progress.report(1.5);
+
+        assert_eq!(seen, vec![0.2, 1.0]);
+    }
+
+    #[test]
+    fn report_without_callback_is_a_no_op() {
+        let mut progress = Progress::new(None);
+        progress.report(0.5);
+    }
+
+    #[test]
+    fn transform_in_batches_reports_each_batch() {
+        let mut events: Vec<Event> = (0..PROGRESS_INTERVAL * 2 + 1)
+            .map(|_| Event::output(10.0, "x"))
+            .collect();
+        let mut seen = Vec::new();
+        let mut callback = |fraction| seen.push(fraction);
+        let mut progress = Progress::new(Some(&mut callback));
+
+        transform_in_batches(
+            &mut CapIdleTime::new(1.0),
+            &mut events,
+            &mut progress,
+            0.0,
+            1.0,
+        );
+
+        assert_eq!(events.len(), PROGRESS_INTERVAL * 2 + 1);
+        assert!(events.iter().all(|e| e.time == 1.0));
+        assert_eq!(seen.len(), 3);
+        assert_eq!(seen.last(), Some(&1.0));
+    }
+}
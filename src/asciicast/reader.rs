@@ -20,6 +20,21 @@
 //! - JSON parsing errors include the line number
 //! - Version mismatches report the found version
 //!
+//! # Strict Mode
+//!
+//! The default parser is lenient: event arrays may carry extra trailing
+//! elements (ignored) and times are taken as-is. The `*_strict` variants
+//! reject anything outside the spec instead: arrays with more than three
+//! elements, unknown event codes (which the lenient parser keeps as
+//! [`EventType::Other`]), and negative or non-finite times.
+//!
+//! # Recovery
+//!
+//! [`AsciicastFile::parse_reader_lenient`] goes one step further for damaged
+//! recordings: event lines that fail to parse are skipped and reported
+//! alongside the events that could be recovered. The header must still be
+//! valid.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -48,7 +63,8 @@ impl Event {
     ///
     /// Expects an array format: `[time, type_code, data]` where:
     /// - `time` is a number (seconds since previous event)
-    /// - `type_code` is a string ("o", "i", "m", "r", or "x")
+    /// - `type_code` is a string ("o", "i", "m", "r", or "x"); other
+    ///   single-character codes are kept as [`EventType::Other`]
     /// - `data` is a string (event payload)
     ///
     /// # Errors
@@ -57,9 +73,23 @@ impl Event {
     /// - The line is not valid JSON
     /// - The JSON is not an array with at least 3 elements
     /// - The time is not a number
-    /// - The type code is not a recognized string
+    /// - The type code is not a single-character string
     /// - The data is not a string
     pub fn from_json(line: &str) -> Result<Self> {
+        Self::from_json_with(line, false)
+    }
+
+    /// Parse an event from a JSON line, rejecting anything outside the spec.
+    ///
+    /// Like [`from_json`](Self::from_json), but also fails when:
+    /// - The array has more than 3 elements
+    /// - The type code is not one of the known event types
+    /// - The time is negative or not finite
+    pub fn from_json_strict(line: &str) -> Result<Self> {
+        Self::from_json_with(line, true)
+    }
+
+    fn from_json_with(line: &str, strict: bool) -> Result<Self> {
         let value: serde_json::Value =
             serde_json::from_str(line).context("Failed to parse event JSON")?;
 
@@ -68,13 +98,26 @@ impl Event {
         if arr.len() < 3 {
             bail!("Event array must have at least 3 elements");
         }
+        if strict && arr.len() > 3 {
+            bail!(
+                "Event array must have exactly 3 elements (got {})",
+                arr.len()
+            );
+        }
 
         let time = arr[0].as_f64().context("Event time must be a number")?;
+        if strict && !(time.is_finite() && time >= 0.0) {
+            bail!(
+                "Event time must be a non-negative finite number (got {})",
+                time
+            );
+        }
 
         let code = arr[1].as_str().context("Event type must be a string")?;
 
-        let event_type =
-            EventType::from_code(code).with_context(|| format!("Unknown event type: {}", code))?;
+        let event_type = EventType::from_code(code)
+            .filter(|t| !strict || t.is_known())
+            .with_context(|| format!("Unknown event type: {}", code))?;
 
         let data = arr[2]
             .as_str()
@@ -106,6 +149,22 @@ impl AsciicastFile {
         Self::parse_reader(reader)
     }
 
+    /// Parse an asciicast v3 file from a filesystem path in strict mode.
+    ///
+    /// See [`parse_reader_strict`](Self::parse_reader_strict).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or parsed.
+    pub fn parse_strict<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let file =
+            fs::File::open(path).with_context(|| format!("Failed to open file: {:?}", path))?;
+        let reader = BufReader::new(file);
+
+        Self::parse_reader_strict(reader)
+    }
+
     /// Parse an asciicast v3 file from any buffered reader.
     ///
     /// Reads the first line as a JSON header, then parses each subsequent
@@ -119,6 +178,63 @@ impl AsciicastFile {
     /// - The version is not 3
     /// - Any event line fails to parse
     pub fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        Self::parse_reader_with(reader, false)
+    }
+
+    /// Parse an asciicast v3 file from any buffered reader in strict mode.
+    ///
+    /// Same as [`parse_reader`](Self::parse_reader), but every event line
+    /// goes through [`Event::from_json_strict`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails or any event is outside the spec.
+    pub fn parse_reader_strict<R: BufRead>(reader: R) -> Result<Self> {
+        Self::parse_reader_with(reader, true)
+    }
+
+    /// Parse an asciicast v3 file from any buffered reader, skipping bad lines.
+    ///
+    /// Event lines that fail to parse are left out of the result and
+    /// reported as `(line_number, message)` pairs, with 1-based line
+    /// numbers. Use this to salvage truncated or partially corrupted
+    /// recordings; [`parse_reader`](Self::parse_reader) stays the default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header is missing, invalid or not version 3,
+    /// or if the reader fails.
+    pub fn parse_reader_lenient<R: BufRead>(reader: R) -> Result<(Self, Vec<(usize, String)>)> {
+        Self::parse_reader_recovering(reader, false)
+    }
+
+    fn parse_reader_with<R: BufRead>(reader: R, strict: bool) -> Result<Self> {
+        Self::parse_lines(reader, strict, |line_num, e| {
+            Err(e.context(format!("Failed to parse event on line {}", line_num)))
+        })
+    }
+
+    /// Parse like [`parse_reader_lenient`](Self::parse_reader_lenient),
+    /// optionally with strict event parsing.
+    pub(crate) fn parse_reader_recovering<R: BufRead>(
+        reader: R,
+        strict: bool,
+    ) -> Result<(Self, Vec<(usize, String)>)> {
+        let mut diagnostics = Vec::new();
+        let file = Self::parse_lines(reader, strict, |line_num, e| {
+            diagnostics.push((line_num, format!("{:#}", e)));
+            Ok(())
+        })?;
+        Ok((file, diagnostics))
+    }
+
+    /// Parse the header and events, handing each bad event line (with its
+    /// 1-based line number) to `on_error`, which decides whether to abort.
+    fn parse_lines<R, F>(reader: R, strict: bool, mut on_error: F) -> Result<Self>
+    where
+        R: BufRead,
+        F: FnMut(usize, anyhow::Error) -> Result<()>,
+    {
         let mut lines = reader.lines();
 
         // First line is the header
@@ -147,9 +263,10 @@ impl AsciicastFile {
                 continue;
             }
 
-            let event = Event::from_json(&line)
-                .with_context(|| format!("Failed to parse event on line {}", line_num + 2))?;
-            events.push(event);
+            match Event::from_json_with(&line, strict) {
+                Ok(event) => events.push(event),
+                Err(e) => on_error(line_num + 2, e)?,
+            }
         }
 
         Ok(AsciicastFile { header, events })
@@ -166,6 +283,30 @@ impl AsciicastFile {
         let reader = BufReader::new(content.as_bytes());
         Self::parse_reader(reader)
     }
+
+    /// Parse an asciicast v3 file from a string in strict mode.
+    ///
+    /// Convenience wrapper around [`parse_reader_strict`](Self::parse_reader_strict).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails (see `parse_reader_strict`).
+    pub fn parse_str_strict(content: &str) -> Result<Self> {
+        let reader = BufReader::new(content.as_bytes());
+        Self::parse_reader_strict(reader)
+    }
+
+    /// Parse an asciicast v3 file from a string, skipping bad event lines.
+    ///
+    /// Convenience wrapper around [`parse_reader_lenient`](Self::parse_reader_lenient).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header cannot be parsed (see `parse_reader_lenient`).
+    pub fn parse_str_lenient(content: &str) -> Result<(Self, Vec<(usize, String)>)> {
+        let reader = BufReader::new(content.as_bytes());
+        Self::parse_reader_lenient(reader)
+    }
 }
 
 #[cfg(test)]
@@ -241,4 +382,89 @@ mod tests {
         let result = AsciicastFile::parse_str(&content);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn lenient_parse_ignores_extra_elements() {
+        let event = Event::from_json(r#"[0.5, "o", "hello", {"extra": true}]"#).unwrap();
+        assert_eq!(event.data, "hello");
+    }
+
+    #[test]
+    fn strict_parse_accepts_valid_event() {
+        let event = Event::from_json_strict(r#"[0.5, "o", "hello"]"#).unwrap();
+        assert_eq!(event.event_type, EventType::Output);
+        assert_eq!(event.data, "hello");
+    }
+
+    #[test]
+    fn strict_parse_rejects_extra_elements() {
+        let err = Event::from_json_strict(r#"[0.5, "o", "hello", 1]"#).unwrap_err();
+        assert!(err.to_string().contains("exactly 3 elements (got 4)"));
+    }
+
+    #[test]
+    fn lenient_parse_keeps_unknown_code() {
+        let event = Event::from_json(r#"[0.5, "z", "future"]"#).unwrap();
+        assert_eq!(event.event_type, EventType::Other('z'));
+        assert_eq!(event.data, "future");
+    }
+
+    #[test]
+    fn parse_rejects_multi_char_code() {
+        let err = Event::from_json(r#"[0.5, "zz", "hello"]"#).unwrap_err();
+        assert!(err.to_string().contains("Unknown event type: zz"));
+    }
+
+    #[test]
+    fn strict_parse_rejects_unknown_code() {
+        let err = Event::from_json_strict(r#"[0.5, "z", "hello"]"#).unwrap_err();
+        assert!(err.to_string().contains("Unknown event type: z"));
+    }
+
+    #[test]
+    fn strict_parse_rejects_negative_time() {
+        assert!(Event::from_json(r#"[-0.5, "o", "hello"]"#).is_ok());
+        let err = Event::from_json_strict(r#"[-0.5, "o", "hello"]"#).unwrap_err();
+        assert!(err.to_string().contains("non-negative finite"));
+    }
+
+    #[test]
+    fn strict_parse_rejects_out_of_range_time() {
+        // JSON has no infinity; overflowing literals must not slip through
+        assert!(Event::from_json_strict(r#"[1e999, "o", "hello"]"#).is_err());
+    }
+
+    #[test]
+    fn strict_file_parse_reports_line_number() {
+        let content = r#"{"version":3}
+[0.1, "o", "hello"]
+[0.2, "o", " world", "extra"]"#;
+
+        assert_eq!(AsciicastFile::parse_str(content).unwrap().events.len(), 2);
+        let err = AsciicastFile::parse_str_strict(content).unwrap_err();
+        assert!(format!("{:#}", err).contains("line 3"));
+    }
+
+    #[test]
+    fn lenient_reader_skips_garbage_line() {
+        let content = r#"{"version":3}
+[0.1, "o", "hello"]
+{not an event
+[0.2, "o", " world"]"#;
+
+        assert!(AsciicastFile::parse_str(content).is_err());
+
+        let (file, diagnostics) = AsciicastFile::parse_str_lenient(content).unwrap();
+        assert_eq!(file.events.len(), 2);
+        assert_eq!(file.events[1].data, " world");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, 3);
+        assert!(diagnostics[0].1.contains("Failed to parse event JSON"));
+    }
+
+    #[test]
+    fn lenient_reader_still_rejects_bad_header() {
+        assert!(AsciicastFile::parse_str_lenient("garbage\n[0.1, \"o\", \"x\"]").is_err());
+        assert!(AsciicastFile::parse_str_lenient(r#"{"version":2}"#).is_err());
+    }
 }
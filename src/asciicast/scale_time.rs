@@ -0,0 +1,142 @@
+//! Playback speed transform for asciicast recordings.
+//!
+//! This module provides the [`ScaleTime`] transform that bakes a playback
+//! speed multiplier into the event timings, so a recording plays faster (or
+//! slower) in any player without adjusting the live speed.
+//!
+//! # Semantics
+//!
+//! Every interval is divided by the factor: `2.0` plays twice as fast,
+//! `0.5` half as fast. Relative timing is preserved, so markers stay at the
+//! same point in the output.
+//!
+//! # Example
+//!
+//! ```
+//! use agr::asciicast::{Event, ScaleTime, Transform};
+//!
+//! let mut transform = ScaleTime::new(2.0);
+//! let mut events = vec![Event::output(1.0, "a"), Event::output(3.0, "b")];
+//!
+//! transform.transform(&mut events);
+//!
+//! assert!((events[0].time - 0.5).abs() < 0.001);
+//! assert!((events[1].time - 1.5).abs() < 0.001);
+//! ```
+
+use super::{AsciicastFile, Event, Transform};
+
+/// A transform that divides every event interval by a speed factor.
+///
+/// # Validation
+///
+/// The factor must be a positive, finite number. Construction with invalid
+/// values (zero, negative, NaN, infinity) will panic.
+#[derive(Debug, Clone)]
+pub struct ScaleTime {
+    factor: f64,
+}
+
+impl ScaleTime {
+    /// Create a new speed transform. Factors above 1.0 speed playback up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero, negative, NaN, or infinite.
+    pub fn new(factor: f64) -> Self {
+        assert!(
+            factor > 0.0 && factor.is_finite(),
+            "Speed factor must be positive and finite, got: {}",
+            factor
+        );
+        Self { factor }
+    }
+
+    /// Get the configured speed factor.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Scale a whole recording: the events and the header's `duration`.
+    ///
+    /// [`Transform::transform`] only sees events, so file-level callers use
+    /// this to keep a recorded `duration` consistent with the new timings.
+    pub fn apply(&mut self, cast: &mut AsciicastFile) {
+        self.transform(&mut cast.events);
+        if let Some(duration) = cast.header.duration.as_mut() {
+            *duration /= self.factor;
+        }
+    }
+}
+
+impl Transform for ScaleTime {
+    fn transform(&mut self, events: &mut Vec<Event>) {
+        for event in events.iter_mut() {
+            event.time /= self.factor;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cast_with_events(events: Vec<Event>) -> AsciicastFile {
+        let mut cast = AsciicastFile::parse_str(r#"{"version":3,"duration":4.0}"#).unwrap();
+        cast.events = events;
+        cast
+    }
+
+    #[test]
+    fn factor_two_halves_cumulative_times() {
+        let mut cast = cast_with_events(vec![
+            Event::output(0.5, "a"),
+            Event::marker(1.0, "mark"),
+            Event::output(2.5, "b"),
+        ]);
+        let before = cast.cumulative_times();
+
+        ScaleTime::new(2.0).apply(&mut cast);
+
+        let after = cast.cumulative_times();
+        for (b, a) in before.iter().zip(&after) {
+            assert!((a - b / 2.0).abs() < 1e-9);
+        }
+        assert_eq!(cast.header.duration, Some(2.0));
+    }
+
+    #[test]
+    fn factor_below_one_slows_down() {
+        let mut events = vec![Event::output(1.0, "a")];
+        ScaleTime::new(0.5).transform(&mut events);
+        assert!((events[0].time - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn missing_header_duration_stays_unset() {
+        let mut cast = AsciicastFile::parse_str(r#"{"version":3}"#).unwrap();
+        cast.events = vec![Event::output(1.0, "a")];
+
+        ScaleTime::new(2.0).apply(&mut cast);
+
+        assert_eq!(cast.header.duration, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Speed factor must be positive and finite")]
+    fn reject_zero_factor() {
+        ScaleTime::new(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Speed factor must be positive and finite")]
+    fn reject_negative_factor() {
+        ScaleTime::new(-2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Speed factor must be positive and finite")]
+    fn reject_nan_factor() {
+        ScaleTime::new(f64::NAN);
+    }
+}
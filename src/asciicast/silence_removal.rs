@@ -34,7 +34,8 @@
 //! assert!((events[2].time - 0.1).abs() < 0.001);   // unchanged
 //! ```
 
-use super::{Event, Transform};
+use super::progress::{transform_in_batches, Progress};
+use super::{AsciicastFile, Event, Transform};
 
 /// Default threshold for silence removal (2.0 seconds).
 ///
@@ -98,18 +99,104 @@ impl SilenceRemoval {
     pub fn threshold(&self) -> f64 {
         self.threshold
     }
+
+    /// Clamp one event's interval to the threshold.
+    ///
+    /// Returns the seconds removed, or 0.0 if the interval was already within
+    /// the threshold.
+    pub(crate) fn cap_gap(&self, event: &mut Event) -> f64 {
+        if event.time > self.threshold {
+            let removed = event.time - self.threshold;
+            event.time = self.threshold;
+            removed
+        } else {
+            0.0
+        }
+    }
 }
 
 impl Transform for SilenceRemoval {
     fn transform(&mut self, events: &mut Vec<Event>) {
         for event in events.iter_mut() {
-            if event.time > self.threshold {
-                event.time = self.threshold;
-            }
+            self.cap_gap(event);
         }
     }
 }
 
+/// Outcome of [`AsciicastFile::remove_silence`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SilenceRemovalStats {
+    /// Duration before silence removal (seconds).
+    pub original_duration: f64,
+    /// Duration after silence removal (seconds).
+    pub new_duration: f64,
+    /// Number of intervals that were longer than the threshold.
+    pub gaps_capped: usize,
+}
+
+impl SilenceRemovalStats {
+    /// Seconds removed from the recording.
+    pub fn time_saved(&self) -> f64 {
+        self.original_duration - self.new_duration
+    }
+}
+
+impl AsciicastFile {
+    /// Return a copy of this recording with every interval longer than
+    /// `threshold` clamped to `threshold`, plus before/after statistics.
+    ///
+    /// This is the primitive behind `agr optimize --remove-silence` and the
+    /// TUI optimize action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is not positive and finite (see
+    /// [`SilenceRemoval::new`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use agr::asciicast::{AsciicastFile, Event};
+    ///
+    /// let mut cast = AsciicastFile::parse_str(r#"{"version":3}"#).unwrap();
+    /// cast.events = vec![Event::output(0.5, "a"), Event::output(10.0, "b")];
+    ///
+    /// let (squashed, stats) = cast.remove_silence(1.0);
+    /// assert!((squashed.duration() - 1.5).abs() < 1e-9);
+    /// assert_eq!(stats.gaps_capped, 1);
+    /// ```
+    pub fn remove_silence(&self, threshold: f64) -> (AsciicastFile, SilenceRemovalStats) {
+        self.remove_silence_with_progress(threshold, None)
+    }
+
+    /// Like [`remove_silence`](Self::remove_silence), reporting progress.
+    ///
+    /// `progress` receives increasing fractions from 0.0 to 1.0, one every
+    /// [`PROGRESS_INTERVAL`](super::PROGRESS_INTERVAL) events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is not positive and finite.
+    pub fn remove_silence_with_progress(
+        &self,
+        threshold: f64,
+        progress: Option<&mut dyn FnMut(f64)>,
+    ) -> (AsciicastFile, SilenceRemovalStats) {
+        let mut progress = Progress::new(progress);
+        let mut transform = SilenceRemoval::new(threshold);
+        let mut result = self.clone();
+        let gaps_capped = result.events.iter().filter(|e| e.time > threshold).count();
+        transform_in_batches(&mut transform, &mut result.events, &mut progress, 0.0, 1.0);
+
+        let stats = SilenceRemovalStats {
+            original_duration: self.duration(),
+            new_duration: result.duration(),
+            gaps_capped,
+        };
+        (result, stats)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -534,6 +621,70 @@ mod tests {
         assert!((events[3].time - 0.2).abs() < 0.001);
     }
 
+    // ========================================================================
+    // AsciicastFile::remove_silence
+    // ========================================================================
+
+    fn cast_with_events(events: Vec<Event>) -> AsciicastFile {
+        let mut cast = AsciicastFile::parse_str(r#"{"version":3}"#).unwrap();
+        cast.events = events;
+        cast
+    }
+
+    /// Scenario: a 10s gap with a 1s threshold
+    #[test]
+    fn remove_silence_clamps_ten_second_gap() {
+        let cast = cast_with_events(vec![
+            Event::output(0.5, "a"),
+            Event::output(10.0, "b"),
+            Event::output(0.2, "c"),
+        ]);
+
+        let (squashed, stats) = cast.remove_silence(1.0);
+
+        assert!((stats.original_duration - 10.7).abs() < 0.001);
+        assert!((stats.new_duration - 1.7).abs() < 0.001);
+        assert!((squashed.duration() - 1.7).abs() < 0.001);
+        assert!((stats.time_saved() - 9.0).abs() < 0.001);
+        assert_eq!(stats.gaps_capped, 1);
+    }
+
+    /// Test: the source recording is left untouched
+    #[test]
+    fn remove_silence_does_not_modify_original() {
+        let cast = cast_with_events(vec![Event::output(10.0, "a")]);
+
+        let (squashed, _) = cast.remove_silence(1.0);
+
+        assert!((cast.events[0].time - 10.0).abs() < 0.001);
+        assert!((squashed.events[0].time - 1.0).abs() < 0.001);
+    }
+
+    /// Test: progress ends at 1.0 and matches the plain result
+    #[test]
+    fn remove_silence_with_progress_reports_completion() {
+        let cast = cast_with_events(vec![Event::output(0.5, "a"), Event::output(10.0, "b")]);
+        let mut seen = Vec::new();
+        let mut callback = |fraction: f64| seen.push(fraction);
+
+        let (squashed, stats) = cast.remove_silence_with_progress(1.0, Some(&mut callback));
+
+        assert_eq!(seen.last(), Some(&1.0));
+        assert_eq!(stats, cast.remove_silence(1.0).1);
+        assert!((squashed.duration() - 1.5).abs() < 0.001);
+    }
+
+    /// Test: nothing to clamp reports zero savings
+    #[test]
+    fn remove_silence_below_threshold_is_noop() {
+        let cast = cast_with_events(vec![Event::output(0.5, "a"), Event::output(0.5, "b")]);
+
+        let (_, stats) = cast.remove_silence(2.0);
+
+        assert_eq!(stats.gaps_capped, 0);
+        assert_eq!(stats.time_saved(), 0.0);
+    }
+
     // ========================================================================
     // Validation Tests
     // ========================================================================
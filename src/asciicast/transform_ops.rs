@@ -4,13 +4,13 @@
 //! transform application, and restore operations. These are higher-level operations
 //! built on top of the core transform traits.
 
-use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 
-use super::{AsciicastFile, SilenceRemoval, Transform, DEFAULT_SILENCE_THRESHOLD};
-use crate::files::backup::backup_path_for;
+use super::progress::{transform_in_batches, Progress};
+use super::{AsciicastFile, CapIdleTime, DEFAULT_SILENCE_THRESHOLD};
+use crate::files::backup::{backup_path_for, create_backup};
 
 /// Result of applying transforms to a recording.
 #[derive(Debug, Clone)]
@@ -23,6 +23,8 @@ pub struct TransformResult {
     pub backup_path: Option<PathBuf>,
     /// Whether a new backup was created (vs using existing).
     pub backup_created: bool,
+    /// Number of event gaps that were shortened.
+    pub gaps_capped: usize,
 }
 
 impl TransformResult {
@@ -41,10 +43,21 @@ impl TransformResult {
     }
 }
 
+/// Which optimization [`apply_transforms_with`] runs on a recording.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OptimizeMode {
+    /// Silence removal with the header's `idle_time_limit` or the default threshold.
+    RemoveSilence,
+    /// Clamp every gap between events to `max_gap` seconds.
+    CapIdleTime { max_gap: f64 },
+}
+
 /// Apply all transforms to a recording file.
 ///
+/// Equivalent to [`apply_transforms_with`] using [`OptimizeMode::RemoveSilence`].
+///
 /// This function:
-/// 1. Creates a backup if one doesn't already exist
+/// 1. Creates a backup (see [`create_backup`] for the rotation rules)
 /// 2. Parses the file
 /// 3. Applies silence removal with threshold from header or default
 /// 4. Writes the modified file back
@@ -52,6 +65,7 @@ impl TransformResult {
 /// # Arguments
 ///
 /// * `path` - Path to the `.cast` file to transform
+/// * `backup_limit` - Backup generations to keep (`storage.backup_limit`)
 ///
 /// # Returns
 ///
@@ -63,44 +77,87 @@ impl TransformResult {
 /// - The file cannot be read or parsed
 /// - Backup creation fails
 /// - Writing the transformed file fails
-pub fn apply_transforms(path: &Path) -> Result<TransformResult> {
+pub fn apply_transforms(path: &Path, backup_limit: usize) -> Result<TransformResult> {
+    apply_transforms_with(path, OptimizeMode::RemoveSilence, backup_limit)
+}
+
+/// Apply the given optimization to a recording file.
+///
+/// Behaves like [`apply_transforms`] (backup, atomic write) but lets the caller
+/// choose which transform runs.
+///
+/// # Errors
+///
+/// Returns the same errors as [`apply_transforms`].
+pub fn apply_transforms_with(
+    path: &Path,
+    mode: OptimizeMode,
+    backup_limit: usize,
+) -> Result<TransformResult> {
+    apply_transforms_with_progress(path, mode, backup_limit, None)
+}
+
+/// Apply the given optimization, reporting progress along the way.
+///
+/// Behaves like [`apply_transforms_with`]. `progress` receives increasing
+/// fractions from 0.0 to 1.0: parsing and writing are reported as single
+/// steps, the transform every [`PROGRESS_INTERVAL`](super::PROGRESS_INTERVAL)
+/// events.
+///
+/// # Errors
+///
+/// Returns the same errors as [`apply_transforms`].
+pub fn apply_transforms_with_progress(
+    path: &Path,
+    mode: OptimizeMode,
+    backup_limit: usize,
+    progress: Option<&mut dyn FnMut(f64)>,
+) -> Result<TransformResult> {
+    let mut progress = Progress::new(progress);
+
     // Parse the file first to get original duration
     let mut cast = AsciicastFile::parse(path)
         .with_context(|| format!("Failed to parse asciicast file: {}", path.display()))?;
+    progress.report(0.1);
 
     let original_duration = cast.duration();
 
-    // Create backup if it doesn't exist
+    // Create backup (kept as-is if one exists, unless rotation is enabled)
     let backup = backup_path_for(path);
-    let backup_created = if !backup.exists() {
-        fs::copy(path, &backup)
-            .with_context(|| format!("Failed to create backup: {}", backup.display()))?;
-        true
-    } else {
-        false
+    let backup_created = create_backup(path, backup_limit)?;
+
+    let gaps_capped = match mode {
+        OptimizeMode::RemoveSilence => {
+            // Resolve threshold: header's idle_time_limit or default
+            let threshold = cast
+                .header
+                .idle_time_limit
+                .unwrap_or(DEFAULT_SILENCE_THRESHOLD);
+            let mut report = |fraction: f64| progress.report(0.1 + 0.8 * fraction);
+            let (squashed, stats) = cast.remove_silence_with_progress(threshold, Some(&mut report));
+            cast = squashed;
+            stats.gaps_capped
+        }
+        OptimizeMode::CapIdleTime { max_gap } => {
+            let mut transform = CapIdleTime::new(max_gap);
+            transform_in_batches(&mut transform, &mut cast.events, &mut progress, 0.1, 0.9);
+            transform.gaps_capped()
+        }
     };
 
-    // Resolve threshold: header's idle_time_limit or default
-    let threshold = cast
-        .header
-        .idle_time_limit
-        .unwrap_or(DEFAULT_SILENCE_THRESHOLD);
-
-    // Apply silence removal transform
-    let mut transform = SilenceRemoval::new(threshold);
-    transform.transform(&mut cast.events);
-
     let new_duration = cast.duration();
 
     // Write back to original path (AsciicastFile::write uses atomic temp+rename)
     cast.write(path)
         .with_context(|| format!("Failed to write transformed file: {}", path.display()))?;
+    progress.report(1.0);
 
     Ok(TransformResult {
         original_duration,
         new_duration,
         backup_path: Some(backup),
         backup_created,
+        gaps_capped,
     })
 }
 
@@ -108,7 +165,8 @@ pub fn apply_transforms(path: &Path) -> Result<TransformResult> {
 mod tests {
     use super::*;
     use crate::asciicast::{Event, Header};
-    use crate::files::backup::{has_backup, restore_from_backup};
+    use crate::files::backup::{backup_paths, has_backup, restore_from_backup};
+    use std::fs;
     use std::io::Write;
     use tempfile::TempDir;
 
@@ -217,7 +275,7 @@ mod tests {
             ],
         );
 
-        let result = apply_transforms(&path).unwrap();
+        let result = apply_transforms(&path, 1).unwrap();
 
         assert!(result.backup_created);
         assert!(result.backup_path.is_some());
@@ -241,7 +299,7 @@ mod tests {
         let mut backup_file = fs::File::create(&backup).unwrap();
         backup_file.write_all(b"ORIGINAL_BACKUP").unwrap();
 
-        let result = apply_transforms(&path).unwrap();
+        let result = apply_transforms(&path, 1).unwrap();
 
         // Should not have created new backup
         assert!(!result.backup_created);
@@ -251,6 +309,33 @@ mod tests {
         assert_eq!(backup_content, "ORIGINAL_BACKUP");
     }
 
+    #[test]
+    fn apply_transforms_rotates_one_generation_per_transform() {
+        let dir = TempDir::new().unwrap();
+        let path = create_test_cast_file(
+            &dir,
+            "test.cast",
+            vec![
+                Event::output(0.5, "prompt"),
+                Event::output(60.0, "model replied"),
+            ],
+        );
+        let original = fs::read_to_string(&path).unwrap();
+
+        apply_transforms_with(&path, OptimizeMode::CapIdleTime { max_gap: 10.0 }, 3).unwrap();
+        let after_first = fs::read_to_string(&path).unwrap();
+        let result =
+            apply_transforms_with(&path, OptimizeMode::CapIdleTime { max_gap: 5.0 }, 3).unwrap();
+        assert!(result.backup_created);
+
+        // Each transform adds exactly one generation: newest first
+        let backups = backup_paths(&path);
+        assert_eq!(backups.len(), 2);
+        assert_eq!(fs::read_to_string(&backups[0]).unwrap(), after_first);
+        assert_eq!(fs::read_to_string(&backups[1]).unwrap(), original);
+        assert_ne!(after_first, original);
+    }
+
     #[test]
     fn apply_transforms_reduces_duration_with_silence() {
         let dir = TempDir::new().unwrap();
@@ -264,7 +349,7 @@ mod tests {
             ],
         );
 
-        let result = apply_transforms(&path).unwrap();
+        let result = apply_transforms(&path, 1).unwrap();
 
         // Original: 0.1 + 10.0 + 0.2 = 10.3s
         assert!((result.original_duration - 10.3).abs() < 0.001);
@@ -290,7 +375,7 @@ mod tests {
             ],
         );
 
-        let result = apply_transforms(&path).unwrap();
+        let result = apply_transforms(&path, 1).unwrap();
 
         // Original: 0.1 + 3.0 + 0.2 = 3.3s
         assert!((result.original_duration - 3.3).abs() < 0.001);
@@ -312,7 +397,7 @@ mod tests {
             ],
         );
 
-        let result = apply_transforms(&path).unwrap();
+        let result = apply_transforms(&path, 1).unwrap();
 
         // No gaps exceed threshold, so duration unchanged
         assert!((result.original_duration - result.new_duration).abs() < 0.001);
@@ -331,13 +416,106 @@ mod tests {
             ],
         );
 
-        apply_transforms(&path).unwrap();
+        apply_transforms(&path, 1).unwrap();
 
         // Re-read the file and check duration
         let modified = AsciicastFile::parse(&path).unwrap();
         assert!((modified.duration() - 2.1).abs() < 0.001); // 0.1 + 2.0
     }
 
+    #[test]
+    fn apply_transforms_reports_gaps_capped() {
+        let dir = TempDir::new().unwrap();
+        let path = create_test_cast_file(
+            &dir,
+            "test.cast",
+            vec![
+                Event::output(3.0, "a"),
+                Event::output(0.1, "b"),
+                Event::output(10.0, "c"),
+            ],
+        );
+
+        let result = apply_transforms(&path, 1).unwrap();
+
+        assert_eq!(result.gaps_capped, 2);
+    }
+
+    // ========================================================================
+    // apply_transforms_with tests
+    // ========================================================================
+
+    #[test]
+    fn cap_idle_time_mode_clamps_to_max_gap() {
+        let dir = TempDir::new().unwrap();
+        let path = create_test_cast_file(
+            &dir,
+            "test.cast",
+            vec![
+                Event::output(0.5, "prompt"),
+                Event::output(60.0, "model replied"),
+                Event::output(4.0, "more"),
+            ],
+        );
+
+        let result =
+            apply_transforms_with(&path, OptimizeMode::CapIdleTime { max_gap: 5.0 }, 1).unwrap();
+
+        // Original: 64.5s, new: 0.5 + 5.0 + 4.0 = 9.5s
+        assert!((result.original_duration - 64.5).abs() < 0.001);
+        assert!((result.new_duration - 9.5).abs() < 0.001);
+        assert!((result.time_saved() - 55.0).abs() < 0.001);
+        assert_eq!(result.gaps_capped, 1);
+        assert!(result.backup_created);
+    }
+
+    #[test]
+    fn cap_idle_time_mode_ignores_header_idle_time_limit() {
+        let dir = TempDir::new().unwrap();
+        let path = create_test_cast_with_idle_limit(
+            &dir,
+            "test.cast",
+            1.0,
+            vec![Event::output(0.1, "a"), Event::output(3.0, "b")],
+        );
+
+        let result =
+            apply_transforms_with(&path, OptimizeMode::CapIdleTime { max_gap: 5.0 }, 1).unwrap();
+
+        // 3s gap is below max_gap, so nothing changes and the header is kept
+        assert!((result.time_saved()).abs() < 0.001);
+        let modified = AsciicastFile::parse(&path).unwrap();
+        assert_eq!(modified.header.idle_time_limit, Some(1.0));
+    }
+
+    #[test]
+    fn apply_transforms_with_progress_reports_increasing_fractions() {
+        let dir = TempDir::new().unwrap();
+        let events = (0..crate::asciicast::PROGRESS_INTERVAL * 3)
+            .map(|i| Event::output(if i % 2 == 0 { 10.0 } else { 0.1 }, "x"))
+            .collect();
+        let path = create_test_cast_file(&dir, "test.cast", events);
+
+        let mut seen = Vec::new();
+        let mut callback = |fraction| seen.push(fraction);
+        let result = apply_transforms_with_progress(
+            &path,
+            OptimizeMode::RemoveSilence,
+            1,
+            Some(&mut callback),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.gaps_capped,
+            crate::asciicast::PROGRESS_INTERVAL * 3 / 2
+        );
+        // Parse, one report per batch, then write
+        assert_eq!(seen.len(), 5);
+        assert!(seen.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(seen.last(), Some(&1.0));
+    }
+
     // ========================================================================
     // restore_from_backup tests
     // ========================================================================
@@ -355,7 +533,7 @@ mod tests {
         let original_bytes = fs::read(&path).unwrap();
 
         // Transform (creates backup)
-        apply_transforms(&path).unwrap();
+        apply_transforms(&path, 1).unwrap();
 
         // File should now be different
         let transformed_bytes = fs::read(&path).unwrap();
@@ -391,6 +569,7 @@ mod tests {
             new_duration: 30.0,
             backup_path: None,
             backup_created: false,
+            gaps_capped: 0,
         };
 
         assert!((result.time_saved() - 70.0).abs() < 0.001);
@@ -403,6 +582,7 @@ mod tests {
             new_duration: 30.0,
             backup_path: None,
             backup_created: false,
+            gaps_capped: 0,
         };
 
         assert!((result.percent_saved() - 70.0).abs() < 0.001);
@@ -415,6 +595,7 @@ mod tests {
             new_duration: 0.0,
             backup_path: None,
             backup_created: false,
+            gaps_capped: 0,
         };
 
         assert!((result.percent_saved()).abs() < 0.001);
@@ -434,7 +615,7 @@ mod tests {
         );
 
         // Apply transforms
-        apply_transforms(&path).unwrap();
+        apply_transforms(&path, 1).unwrap();
 
         // Verify no .tmp file exists after successful operation
         let temp_path = path.with_extension("cast.tmp");
@@ -454,7 +635,7 @@ mod tests {
         );
 
         // Create backup and transform
-        apply_transforms(&path).unwrap();
+        apply_transforms(&path, 1).unwrap();
 
         // Restore
         restore_from_backup(&path).unwrap();
@@ -477,7 +658,7 @@ mod tests {
         );
 
         // Create backup via transform
-        apply_transforms(&path).unwrap();
+        apply_transforms(&path, 1).unwrap();
         let backup_path = backup_path_for(&path);
         assert!(backup_path.exists(), "Backup should exist after transform");
 
@@ -517,7 +698,7 @@ mod tests {
         fs::set_permissions(dir_path, readonly_perms).unwrap();
 
         // Try to apply transforms - should fail because rename cannot write
-        let result = apply_transforms(&path);
+        let result = apply_transforms(&path, 1);
 
         // Restore permissions before assertions (so TempDir can clean up)
         fs::set_permissions(dir_path, original_perms).unwrap();
@@ -549,7 +730,7 @@ mod tests {
         );
 
         // Apply transforms to create a backup
-        apply_transforms(&path).unwrap();
+        apply_transforms(&path, 1).unwrap();
 
         // Make the directory read-only to cause rename to fail
         let dir_path = dir.path();
@@ -600,7 +781,7 @@ mod tests {
         let original_bytes = fs::read(&path).unwrap();
 
         // Transform 1 - should create backup
-        let result1 = apply_transforms(&path).unwrap();
+        let result1 = apply_transforms(&path, 1).unwrap();
         assert!(result1.backup_created);
 
         // Restore - deletes backup
@@ -608,7 +789,7 @@ mod tests {
         assert!(!has_backup(&path), "Backup should be deleted after restore");
 
         // Transform 2 - should create NEW backup (previous was deleted by restore)
-        let result2 = apply_transforms(&path).unwrap();
+        let result2 = apply_transforms(&path, 1).unwrap();
         assert!(
             result2.backup_created,
             "New backup should be created since previous was deleted"
@@ -23,8 +23,14 @@
 //! [0.1,"m","marker label"]
 //! ```
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
 use serde::{Deserialize, Serialize};
 
+use super::marker::MarkerPosition;
+use crate::terminal::{cells_to_text, TerminalBuffer};
+
 // ============================================================================
 // Header Types
 // ============================================================================
@@ -75,6 +81,99 @@ pub struct Header {
     pub idle_time_limit: Option<f64>,
 }
 
+impl Header {
+    /// Create a v3 header with the given terminal size and no other metadata.
+    pub fn v3(cols: u32, rows: u32) -> Self {
+        Self::builder().cols(cols).rows(rows).build()
+    }
+
+    /// Start building a v3 header; unset fields are left out.
+    pub fn builder() -> HeaderBuilder {
+        HeaderBuilder::default()
+    }
+}
+
+/// Chainable constructor for [`Header`], created by [`Header::builder`].
+///
+/// Dimensions go in `term.cols`/`term.rows`; the deprecated top-level
+/// `width`/`height` fields are left unset.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderBuilder {
+    cols: Option<u32>,
+    rows: Option<u32>,
+    title: Option<String>,
+    timestamp: Option<i64>,
+    idle_time_limit: Option<f64>,
+    env: Option<EnvInfo>,
+    command: Option<String>,
+}
+
+impl HeaderBuilder {
+    /// Set the terminal width in columns.
+    pub fn cols(mut self, cols: u32) -> Self {
+        self.cols = Some(cols);
+        self
+    }
+
+    /// Set the terminal height in rows.
+    pub fn rows(mut self, rows: u32) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    /// Set the recording title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the Unix timestamp when the recording started.
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Set the maximum idle time between events.
+    pub fn idle_time_limit(mut self, limit: f64) -> Self {
+        self.idle_time_limit = Some(limit);
+        self
+    }
+
+    /// Set the captured environment.
+    pub fn env(mut self, env: EnvInfo) -> Self {
+        self.env = Some(env);
+        self
+    }
+
+    /// Set the recorded command.
+    pub fn command(mut self, command: impl Into<String>) -> Self {
+        self.command = Some(command.into());
+        self
+    }
+
+    /// Build the header.
+    pub fn build(self) -> Header {
+        let term = (self.cols.is_some() || self.rows.is_some()).then_some(TermInfo {
+            cols: self.cols,
+            rows: self.rows,
+            term_type: None,
+            theme: None,
+        });
+        Header {
+            version: 3,
+            width: None,
+            height: None,
+            term,
+            timestamp: self.timestamp,
+            duration: None,
+            title: self.title,
+            command: self.command,
+            env: self.env,
+            idle_time_limit: self.idle_time_limit,
+        }
+    }
+}
+
 /// Terminal information embedded in the header.
 ///
 /// Contains the terminal dimensions and type. This is the preferred way to
@@ -92,12 +191,141 @@ pub struct TermInfo {
     /// Terminal type (e.g., "xterm-256color").
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub term_type: Option<String>,
+
+    /// Color theme of the recording terminal, if captured.
+    ///
+    /// A malformed theme is dropped rather than failing the whole file.
+    #[serde(
+        default,
+        deserialize_with = "lenient_theme",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub theme: Option<TtyTheme>,
+}
+
+/// Deserialize an optional theme, falling back to `None` if it is invalid.
+///
+/// The theme is only informational, so a bad palette should not stop the
+/// recording from being played, analyzed or optimized.
+fn lenient_theme<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<TtyTheme>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|v| match serde_json::from_value(v) {
+        Ok(theme) => Some(theme),
+        Err(e) => {
+            tracing::warn!("Ignoring invalid terminal theme: {}", e);
+            None
+        }
+    }))
+}
+
+/// Terminal color theme captured at recording time.
+///
+/// Serialized as hex triplets (`"#rrggbb"`), with the palette stored as a
+/// single colon-separated string of 8 or 16 colors, as in the v3 spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TtyTheme {
+    /// Default foreground color.
+    #[serde(with = "hex_color")]
+    pub fg: RgbColor,
+
+    /// Default background color.
+    #[serde(with = "hex_color")]
+    pub bg: RgbColor,
+
+    /// Indexed color palette (8 or 16 entries).
+    #[serde(with = "hex_palette")]
+    pub palette: Vec<RgbColor>,
+}
+
+/// A 24-bit RGB color used in [`TtyTheme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RgbColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl RgbColor {
+    /// Create a color from its components.
+    pub fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parse a `#rrggbb` hex triplet.
+    ///
+    /// Returns `None` if the string is not exactly a `#` followed by six hex digits.
+    pub fn from_hex(s: &str) -> Option<Self> {
+        let hex = s.strip_prefix('#')?;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+        Some(Self { r, g, b })
+    }
+
+    /// Format as a lowercase `#rrggbb` hex triplet.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+mod hex_color {
+    use super::RgbColor;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &RgbColor, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.to_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RgbColor, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        RgbColor::from_hex(&s).ok_or_else(|| de::Error::custom(format!("invalid color: {}", s)))
+    }
+}
+
+mod hex_palette {
+    use super::RgbColor;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        palette: &[RgbColor],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let joined: Vec<String> = palette.iter().map(RgbColor::to_hex).collect();
+        serializer.serialize_str(&joined.join(":"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RgbColor>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let palette = s
+            .split(':')
+            .map(|c| {
+                RgbColor::from_hex(c)
+                    .ok_or_else(|| de::Error::custom(format!("invalid palette color: {}", c)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if palette.len() != 8 && palette.len() != 16 {
+            return Err(de::Error::custom(format!(
+                "palette must have 8 or 16 colors (got {})",
+                palette.len()
+            )));
+        }
+        Ok(palette)
+    }
 }
 
 /// Environment variables captured during recording.
 ///
 /// Stores shell and terminal type information that can be useful for playback.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Any other captured variables are kept in `other` so they survive a
+/// parse/write roundtrip.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EnvInfo {
     /// The shell used during recording (e.g., "/bin/zsh").
     #[serde(rename = "SHELL", skip_serializing_if = "Option::is_none")]
@@ -106,6 +334,10 @@ pub struct EnvInfo {
     /// The TERM environment variable value.
     #[serde(rename = "TERM", skip_serializing_if = "Option::is_none")]
     pub term: Option<String>,
+
+    /// Other captured variables, written back unchanged.
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
 }
 
 // ============================================================================
@@ -115,7 +347,7 @@ pub struct EnvInfo {
 /// Event type codes representing different kinds of terminal events.
 ///
 /// Each variant maps to a single-character code used in the JSON format.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
     /// Output event ("o"): Data written to the terminal (stdout/stderr).
     Output,
@@ -131,12 +363,17 @@ pub enum EventType {
 
     /// Exit event ("x"): Process exit code.
     Exit,
+
+    /// Any other single-character code, kept so files written by newer
+    /// tools roundtrip without losing events.
+    Other(char),
 }
 
 impl EventType {
     /// Parse an event type from its single-character code.
     ///
-    /// Returns `None` for unrecognized codes.
+    /// Unrecognized single-character codes become [`EventType::Other`].
+    /// Returns `None` for empty or multi-character codes.
     pub fn from_code(code: &str) -> Option<Self> {
         match code {
             "o" => Some(EventType::Output),
@@ -144,20 +381,32 @@ impl EventType {
             "m" => Some(EventType::Marker),
             "r" => Some(EventType::Resize),
             "x" => Some(EventType::Exit),
-            _ => None,
+            _ => {
+                let mut chars = code.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(EventType::Other(c)),
+                    _ => None,
+                }
+            }
         }
     }
 
     /// Convert the event type to its single-character code.
-    pub fn to_code(&self) -> &'static str {
+    pub fn to_code(&self) -> Cow<'static, str> {
         match self {
-            EventType::Output => "o",
-            EventType::Input => "i",
-            EventType::Marker => "m",
-            EventType::Resize => "r",
-            EventType::Exit => "x",
+            EventType::Output => Cow::Borrowed("o"),
+            EventType::Input => Cow::Borrowed("i"),
+            EventType::Marker => Cow::Borrowed("m"),
+            EventType::Resize => Cow::Borrowed("r"),
+            EventType::Exit => Cow::Borrowed("x"),
+            EventType::Other(c) => Cow::Owned(c.to_string()),
         }
     }
+
+    /// Check if this is one of the event types defined by asciicast v3.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, EventType::Other(_))
+    }
 }
 
 /// A single event in an asciicast recording.
@@ -209,6 +458,11 @@ impl Event {
         self.event_type == EventType::Output
     }
 
+    /// Check if this is an input event.
+    pub fn is_input(&self) -> bool {
+        self.event_type == EventType::Input
+    }
+
     /// Check if this is a marker event.
     pub fn is_marker(&self) -> bool {
         self.event_type == EventType::Marker
@@ -236,6 +490,21 @@ impl Event {
             None
         }
     }
+
+    /// Check if this is an exit event.
+    pub fn is_exit(&self) -> bool {
+        self.event_type == EventType::Exit
+    }
+
+    /// Parse exit event data into the process exit code.
+    ///
+    /// Returns `None` if this is not an exit event or the data is not an integer.
+    pub fn parse_exit_code(&self) -> Option<i32> {
+        if !self.is_exit() {
+            return None;
+        }
+        self.data.trim().parse().ok()
+    }
 }
 
 // ============================================================================
@@ -289,6 +558,27 @@ impl AsciicastFile {
         self.events.iter().filter(|e| e.is_marker()).collect()
     }
 
+    /// Get all markers with their absolute (cumulative) times, in order.
+    ///
+    /// A marker's position in the returned list is its index as used by
+    /// [`MarkerManager::remove_marker`](super::MarkerManager::remove_marker).
+    pub fn marker_positions(&self) -> Vec<MarkerPosition> {
+        let mut markers = Vec::new();
+        let mut cumulative = 0.0f64;
+
+        for event in &self.events {
+            cumulative += event.time;
+            if event.is_marker() {
+                markers.push(MarkerPosition {
+                    time: cumulative,
+                    label: event.data.clone(),
+                });
+            }
+        }
+
+        markers
+    }
+
     /// Get all output events in the recording.
     pub fn outputs(&self) -> Vec<&Event> {
         self.events.iter().filter(|e| e.is_output()).collect()
@@ -344,9 +634,83 @@ impl AsciicastFile {
         }
     }
 
+    /// Insert a marker at an absolute timestamp.
+    ///
+    /// The marker goes after every event at or before `absolute_time`, and
+    /// the following event's delta is shortened so it and everything after
+    /// it keep their absolute times. Timestamps past the end append the
+    /// marker, extending the recording.
+    pub fn insert_marker(&mut self, absolute_time: f64, label: &str) {
+        let index = self.find_insertion_index(absolute_time);
+        let relative_time = self.calculate_relative_time(index, absolute_time);
+
+        // The following event keeps its absolute time, so it now waits less
+        if let Some(next) = self.events.get_mut(index) {
+            next.time = (next.time - relative_time).max(0.0);
+        }
+        self.events
+            .insert(index, Event::marker(relative_time, label));
+    }
+
+    /// Keep only the events between the absolute times `from` and `to`.
+    ///
+    /// Events at `from` or `to` are kept, allowing for floating point noise
+    /// in the accumulated times. The first kept event is
+    /// timed relative to `from`, so the result starts at `from` and later
+    /// events keep their spacing. The last resize before `from` is kept as
+    /// an event at the start, so the terminal keeps its size. A recorded
+    /// header `duration` is updated to match.
+    pub fn trim(&mut self, from: f64, to: f64) {
+        const SLACK: f64 = 1e-9;
+        let times = self.cumulative_times();
+        let mut previous = from;
+        let mut last_resize = None;
+        let mut kept: Vec<Event> = Vec::new();
+        for (mut event, time) in std::mem::take(&mut self.events).into_iter().zip(times) {
+            if time < from - SLACK {
+                if event.is_resize() {
+                    last_resize = Some(event);
+                }
+            } else if time <= to + SLACK {
+                event.time = (time - previous).max(0.0);
+                previous = time;
+                kept.push(event);
+            }
+        }
+
+        // A resize right at `from` already sets the size
+        let starts_with_resize = kept
+            .first()
+            .is_some_and(|e| e.is_resize() && e.time <= SLACK);
+        if let Some(mut resize) = last_resize.filter(|_| !starts_with_resize) {
+            resize.time = 0.0;
+            kept.insert(0, resize);
+        }
+        self.events = kept;
+
+        if self.header.duration.is_some() {
+            self.header.duration = Some(self.duration());
+        }
+    }
+
     /// Get the total duration of the recording in seconds.
+    ///
+    /// Sums the event times in one pass, without building the
+    /// [`cumulative_times`](Self::cumulative_times) vector.
     pub fn duration(&self) -> f64 {
-        self.cumulative_times().last().copied().unwrap_or(0.0)
+        self.events.iter().fold(0.0, |total, e| total + e.time)
+    }
+
+    /// Count the events of each type.
+    ///
+    /// Types that do not occur are absent from the map. Unknown event codes
+    /// are counted per code.
+    pub fn event_count_by_type(&self) -> HashMap<EventType, usize> {
+        let mut counts = HashMap::new();
+        for event in &self.events {
+            *counts.entry(event.event_type).or_insert(0) += 1;
+        }
+        counts
     }
 
     /// Get concatenated output text up to a specific timestamp.
@@ -370,6 +734,70 @@ impl AsciicastFile {
         output
     }
 
+    /// Render the terminal screen as it looks at a specific timestamp.
+    ///
+    /// Replays all events up to (and including) the given absolute timestamp
+    /// through a fresh [`TerminalBuffer`] of `cols` x `rows`, applying resize
+    /// events along the way. Returns one string per screen row with styles
+    /// stripped and trailing whitespace trimmed. Useful for headless snapshot
+    /// tests of recorded output.
+    pub fn render_at(&self, timestamp: f64, cols: usize, rows: usize) -> Vec<String> {
+        let mut buffer = TerminalBuffer::new(cols, rows);
+        let mut cumulative = 0.0;
+
+        for event in &self.events {
+            cumulative += event.time;
+            if cumulative > timestamp {
+                break;
+            }
+            if event.is_output() {
+                buffer.process(&event.data, None);
+            } else if let Some((new_cols, new_rows)) = event.parse_resize() {
+                buffer.resize(new_cols as usize, new_rows as usize);
+            }
+        }
+
+        (0..buffer.height())
+            .filter_map(|row| buffer.row(row))
+            .map(|cells| cells_to_text(cells).trim_end().to_string())
+            .collect()
+    }
+
+    /// Render the whole recording as plain text.
+    ///
+    /// Replays every output event through a [`TerminalBuffer`] of the
+    /// recording's size, applying resize events. Lines that scroll off the
+    /// top of the screen are kept in order, followed by the final screen, so
+    /// redraws and cursor movement read the way a viewer saw them. Styles
+    /// are stripped, trailing whitespace is trimmed and trailing blank lines
+    /// are dropped.
+    pub fn to_transcript(&self) -> String {
+        let (cols, rows) = self.terminal_size();
+        let mut buffer = TerminalBuffer::new(cols as usize, rows as usize);
+        let mut lines = Vec::new();
+
+        for event in &self.events {
+            if event.is_output() {
+                let mut collect = |cells: Vec<_>| {
+                    lines.push(cells_to_text(&cells).trim_end().to_string());
+                };
+                buffer.process(&event.data, Some(&mut collect));
+            } else if let Some((new_cols, new_rows)) = event.parse_resize() {
+                buffer.resize(new_cols as usize, new_rows as usize);
+            }
+        }
+
+        lines.extend(
+            (0..buffer.height())
+                .filter_map(|row| buffer.row(row))
+                .map(|cells| cells_to_text(cells).trim_end().to_string()),
+        );
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+
     /// Get the count of marker events in the recording.
     pub fn marker_count(&self) -> usize {
         self.events.iter().filter(|e| e.is_marker()).count()
@@ -383,6 +811,20 @@ impl AsciicastFile {
         let rows = self.header.term.as_ref().and_then(|t| t.rows).unwrap_or(24);
         (cols, rows)
     }
+
+    /// Get the largest terminal size used anywhere in the recording.
+    ///
+    /// Takes the header size (see [`terminal_size`](Self::terminal_size)) and
+    /// grows each dimension to fit any resize event, so a buffer of this
+    /// size never clips output written after the terminal was enlarged.
+    pub fn effective_dimensions(&self) -> (u32, u32) {
+        self.events
+            .iter()
+            .filter_map(Event::parse_resize)
+            .fold(self.terminal_size(), |(cols, rows), (c, r)| {
+                (cols.max(c), rows.max(r))
+            })
+    }
 }
 
 #[cfg(test)]
@@ -409,6 +851,42 @@ mod tests {
         file
     }
 
+    #[test]
+    fn header_v3_sets_term_size_only() {
+        let header = Header::v3(120, 40);
+        assert_eq!(header.version, 3);
+        let term = header.term.as_ref().unwrap();
+        assert_eq!((term.cols, term.rows), (Some(120), Some(40)));
+        assert!(header.width.is_none() && header.title.is_none());
+        assert_eq!(
+            serde_json::to_string(&header).unwrap(),
+            r#"{"version":3,"term":{"cols":120,"rows":40}}"#
+        );
+    }
+
+    #[test]
+    fn header_builder_sets_chosen_fields() {
+        let header = Header::builder()
+            .cols(80)
+            .title("demo")
+            .timestamp(1_700_000_000)
+            .idle_time_limit(2.5)
+            .command("bash")
+            .env(EnvInfo {
+                shell: Some("/bin/zsh".to_string()),
+                ..Default::default()
+            })
+            .build();
+        let term = header.term.as_ref().unwrap();
+        assert_eq!((term.cols, term.rows), (Some(80), None));
+        assert_eq!(header.title.as_deref(), Some("demo"));
+        assert_eq!(header.timestamp, Some(1_700_000_000));
+        assert_eq!(header.idle_time_limit, Some(2.5));
+        assert_eq!(header.command.as_deref(), Some("bash"));
+        assert_eq!(header.env.unwrap().shell.as_deref(), Some("/bin/zsh"));
+        assert!(Header::builder().build().term.is_none());
+    }
+
     #[test]
     fn event_type_from_code() {
         assert_eq!(EventType::from_code("o"), Some(EventType::Output));
@@ -416,7 +894,9 @@ mod tests {
         assert_eq!(EventType::from_code("m"), Some(EventType::Marker));
         assert_eq!(EventType::from_code("r"), Some(EventType::Resize));
         assert_eq!(EventType::from_code("x"), Some(EventType::Exit));
-        assert_eq!(EventType::from_code("z"), None);
+        assert_eq!(EventType::from_code("z"), Some(EventType::Other('z')));
+        assert_eq!(EventType::from_code("zz"), None);
+        assert_eq!(EventType::from_code(""), None);
     }
 
     #[test]
@@ -426,6 +906,13 @@ mod tests {
         assert_eq!(EventType::Marker.to_code(), "m");
         assert_eq!(EventType::Resize.to_code(), "r");
         assert_eq!(EventType::Exit.to_code(), "x");
+        assert_eq!(EventType::Other('z').to_code(), "z");
+    }
+
+    #[test]
+    fn other_event_type_is_not_known() {
+        assert!(EventType::Output.is_known());
+        assert!(!EventType::Other('z').is_known());
     }
 
     #[test]
@@ -477,6 +964,52 @@ mod tests {
         assert_eq!(file.output_at(1.0), "hello world!");
     }
 
+    #[test]
+    fn render_at_returns_screen_lines_up_to_timestamp() {
+        let file = create_test_file();
+
+        let screen = file.render_at(0.35, 20, 3);
+        assert_eq!(screen, vec!["hello world", "", ""]);
+
+        let screen = file.render_at(0.0, 20, 3);
+        assert!(screen.iter().all(|line| line.is_empty()));
+    }
+
+    #[test]
+    fn render_at_strips_styles_and_applies_resize() {
+        let mut file = create_test_file();
+        file.events = vec![
+            Event::output(0.1, "\x1b[1;31mred\x1b[0m\r\nnext"),
+            Event::new(0.1, EventType::Resize, "10x5"),
+        ];
+
+        let screen = file.render_at(1.0, 20, 3);
+        assert_eq!(screen, vec!["red", "next", "", "", ""]);
+    }
+
+    #[test]
+    fn to_transcript_keeps_scrolled_lines_and_final_screen() {
+        let mut file = AsciicastFile::new(Header::v3(20, 2));
+        file.events = vec![
+            Event::output(0.1, "\x1b[32mone\x1b[0m\r\ntwo\r\n"),
+            Event::new(0.1, EventType::Input, "ignored"),
+            Event::output(0.1, "three\r\nfour   \r\n"),
+        ];
+
+        assert_eq!(file.to_transcript(), "one\ntwo\nthree\nfour");
+    }
+
+    #[test]
+    fn to_transcript_resolves_redraws() {
+        let mut file = AsciicastFile::new(Header::v3(20, 3));
+        file.events = vec![
+            Event::output(0.1, "working..."),
+            Event::output(0.1, "\r\x1b[Kdone\r\n"),
+        ];
+
+        assert_eq!(file.to_transcript(), "done");
+    }
+
     #[test]
     fn find_insertion_index_works() {
         let file = create_test_file();
@@ -485,6 +1018,133 @@ mod tests {
         assert_eq!(file.find_insertion_index(1.0), 4);
     }
 
+    #[test]
+    fn insert_marker_keeps_surrounding_events_in_place() {
+        let mut file = create_test_file();
+        let before = file.cumulative_times();
+
+        file.insert_marker(0.15, "inserted");
+
+        assert_eq!(file.events[1].data, "inserted");
+        let after = file.cumulative_times();
+        assert!((after[1] - 0.15).abs() < 1e-9);
+        // Every original event keeps its absolute time
+        let original: Vec<f64> = after
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != 1)
+            .map(|(_, &t)| t)
+            .collect();
+        assert_eq!(original.len(), before.len());
+        for (a, b) in original.iter().zip(&before) {
+            assert!((a - b).abs() < 1e-9, "{} moved to {}", b, a);
+        }
+        assert!((file.duration() - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn insert_marker_at_start_and_past_end() {
+        let mut file = create_test_file();
+        file.insert_marker(0.0, "start");
+        assert!(file.events[0].is_marker());
+        assert_eq!(file.events[0].time, 0.0);
+        assert!((file.events[1].time - 0.1).abs() < 1e-9);
+
+        file.insert_marker(1.0, "end");
+        let last = file.events.last().unwrap();
+        assert_eq!(last.data, "end");
+        assert!((file.duration() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn insert_marker_at_existing_event_time_goes_after_it() {
+        let mut file = create_test_file();
+        file.insert_marker(0.1, "same time");
+        // "hello" was written at exactly 0.1, so the marker follows it
+        assert_eq!(file.events[0].data, "hello");
+        assert_eq!(file.events[1].data, "same time");
+        assert_eq!(file.events[1].time, 0.0);
+        let times = file.cumulative_times();
+        assert!((times[2] - 0.3).abs() < 1e-9);
+        assert!((times[4] - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn trim_keeps_events_in_range_relative_to_from() {
+        let mut file = create_test_file();
+        file.trim(0.2, 0.4);
+
+        let data: Vec<&str> = file.events.iter().map(|e| e.data.as_str()).collect();
+        assert_eq!(data, vec![" world", "test marker"]);
+        assert!((file.events[0].time - 0.1).abs() < 1e-9);
+        assert!((file.duration() - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn trim_carries_last_resize_before_from() {
+        let mut file = create_test_file();
+        file.events = vec![
+            Event::new(0.1, EventType::Resize, "100x30"),
+            Event::output(0.1, "a"),
+            Event::new(0.1, EventType::Resize, "120x40"),
+            Event::output(0.1, "b"),
+            Event::output(0.1, "c"),
+        ];
+        file.trim(0.35, 0.5);
+
+        let data: Vec<&str> = file.events.iter().map(|e| e.data.as_str()).collect();
+        assert_eq!(data, vec!["120x40", "b", "c"]);
+        assert_eq!(file.events[0].time, 0.0);
+        assert_eq!(file.events[0].parse_resize(), Some((120, 40)));
+        assert!((file.duration() - 0.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn trim_does_not_duplicate_resize_at_from() {
+        let mut file = create_test_file();
+        file.events = vec![
+            Event::new(0.1, EventType::Resize, "100x30"),
+            Event::output(0.1, "a"),
+            Event::new(0.1, EventType::Resize, "120x40"),
+            Event::output(0.1, "b"),
+        ];
+        file.trim(0.3, 0.4);
+
+        let data: Vec<&str> = file.events.iter().map(|e| e.data.as_str()).collect();
+        assert_eq!(data, vec!["120x40", "b"]);
+    }
+
+    #[test]
+    fn trim_updates_recorded_duration() {
+        let mut file = create_test_file();
+        file.header.duration = Some(0.7);
+        file.trim(0.0, 0.3);
+
+        assert_eq!(file.events.len(), 2);
+        assert!((file.header.duration.unwrap() - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn event_count_by_type_counts_each_type() {
+        let mut file = create_test_file();
+        file.events
+            .push(Event::new(0.1, EventType::Other('z'), "?"));
+
+        let counts = file.event_count_by_type();
+        assert_eq!(counts.get(&EventType::Output), Some(&3));
+        assert_eq!(counts.get(&EventType::Marker), Some(&1));
+        assert_eq!(counts.get(&EventType::Other('z')), Some(&1));
+        assert_eq!(counts.get(&EventType::Input), None);
+    }
+
+    #[test]
+    fn duration_of_empty_recording_is_zero() {
+        let mut file = create_test_file();
+        file.events.clear();
+        assert_eq!(file.duration(), 0.0);
+        assert!(file.duration().is_sign_positive());
+    }
+
     #[test]
     fn marker_count_returns_correct_count() {
         let file = create_test_file();
@@ -518,10 +1178,74 @@ mod tests {
             cols: Some(120),
             rows: Some(40),
             term_type: None,
+            theme: None,
         });
         assert_eq!(file.terminal_size(), (120, 40));
     }
 
+    #[test]
+    fn rgb_color_hex_roundtrip() {
+        let color = RgbColor::from_hex("#1a2B3c").unwrap();
+        assert_eq!(color, RgbColor::new(0x1a, 0x2b, 0x3c));
+        assert_eq!(color.to_hex(), "#1a2b3c");
+    }
+
+    #[test]
+    fn rgb_color_rejects_malformed_hex() {
+        assert_eq!(RgbColor::from_hex("1a2b3c"), None);
+        assert_eq!(RgbColor::from_hex("#1a2b3"), None);
+        assert_eq!(RgbColor::from_hex("#gggggg"), None);
+    }
+
+    #[test]
+    fn term_info_without_theme_omits_key() {
+        let term = TermInfo {
+            cols: Some(80),
+            rows: Some(24),
+            term_type: None,
+            theme: None,
+        };
+        let json = serde_json::to_string(&term).unwrap();
+        assert!(!json.contains("theme"));
+    }
+
+    #[test]
+    fn term_info_drops_theme_with_palette_of_wrong_length() {
+        let json =
+            r##"{"cols":80,"theme":{"fg":"#ffffff","bg":"#000000","palette":"#000000:#111111"}}"##;
+        let term = serde_json::from_str::<TermInfo>(json).unwrap();
+        assert_eq!(term.cols, Some(80));
+        assert_eq!(term.theme, None);
+    }
+
+    #[test]
+    fn tty_theme_rejects_palette_of_wrong_length() {
+        let json = r##"{"fg":"#ffffff","bg":"#000000","palette":"#000000:#111111"}"##;
+        assert!(serde_json::from_str::<TtyTheme>(json).is_err());
+    }
+
+    #[test]
+    fn effective_dimensions_grow_to_largest_resize() {
+        let mut file = create_test_file();
+        file.header.term = Some(TermInfo {
+            cols: Some(80),
+            rows: Some(24),
+            term_type: None,
+            theme: None,
+        });
+        assert_eq!(file.effective_dimensions(), (80, 24));
+
+        file.events
+            .push(Event::new(0.1, EventType::Resize, "200x50"));
+        file.events
+            .push(Event::new(0.1, EventType::Resize, "100x60"));
+        file.events
+            .push(Event::new(0.1, EventType::Resize, "40x10"));
+        assert_eq!(file.effective_dimensions(), (200, 60));
+        // The header itself is left alone
+        assert_eq!(file.terminal_size(), (80, 24));
+    }
+
     #[test]
     fn parse_resize_returns_dimensions() {
         let event = Event::new(0.1, EventType::Resize, "100x50");
@@ -540,6 +1264,33 @@ mod tests {
         assert_eq!(event.parse_resize(), None);
     }
 
+    #[test]
+    fn parse_exit_code_returns_code() {
+        assert_eq!(
+            Event::new(0.1, EventType::Exit, "0").parse_exit_code(),
+            Some(0)
+        );
+        assert_eq!(
+            Event::new(0.1, EventType::Exit, "130").parse_exit_code(),
+            Some(130)
+        );
+        assert_eq!(
+            Event::new(0.1, EventType::Exit, "-1").parse_exit_code(),
+            Some(-1)
+        );
+    }
+
+    #[test]
+    fn parse_exit_code_returns_none_for_other_events() {
+        assert_eq!(Event::output(0.1, "0").parse_exit_code(), None);
+    }
+
+    #[test]
+    fn parse_exit_code_returns_none_for_malformed() {
+        let event = Event::new(0.1, EventType::Exit, "done");
+        assert_eq!(event.parse_exit_code(), None);
+    }
+
     #[test]
     fn is_resize_returns_true_for_resize_events() {
         let event = Event::new(0.1, EventType::Resize, "80x24");
@@ -0,0 +1,223 @@
+//! Recording validation for `agr validate`.
+//!
+//! Runs the regular parser over a recording and adds checks the parser does
+//! not make (negative event times). Every unparseable event line is reported
+//! with its line number, not just the first one, along with how many events
+//! could still be recovered. Strict mode switches to the
+//! strict parser, so unknown event codes and extra array elements become
+//! errors, and adds warnings for recordings that are technically valid but
+//! probably not intended.
+
+use std::fs;
+use std::io::BufReader;
+use std::path::Path;
+
+use super::types::AsciicastFile;
+
+/// How serious a validation finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The file cannot be used as-is.
+    Error,
+    /// The file loads, but something looks off (strict mode only).
+    Warning,
+}
+
+/// A single validation finding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    /// 1-based line number, if the issue belongs to a specific line.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// All findings for one recording.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+    /// `(recovered, total)` event counts when some event lines were unparseable.
+    pub recovered: Option<(usize, usize)>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if there are no errors (warnings are allowed).
+    pub fn is_ok(&self) -> bool {
+        !self.issues.iter().any(|i| i.severity == Severity::Error)
+    }
+
+    fn push(&mut self, severity: Severity, line: Option<usize>, message: impl Into<String>) {
+        self.issues.push(ValidationIssue {
+            severity,
+            line,
+            message: message.into(),
+        });
+    }
+}
+
+/// Validate the recording at `path`.
+///
+/// See [`validate_str`] for the checks performed.
+pub fn validate_file<P: AsRef<Path>>(path: P, strict: bool) -> ValidationReport {
+    match fs::read_to_string(path.as_ref()) {
+        Ok(content) => validate_str(&content, strict),
+        Err(e) => {
+            let mut report = ValidationReport::default();
+            report.push(Severity::Error, None, format!("Failed to read file: {}", e));
+            report
+        }
+    }
+}
+
+/// Validate recording `content`.
+///
+/// Errors: anything [`AsciicastFile::parse_str`] rejects (unsupported header
+/// version, malformed event lines) and negative event times. With `strict`,
+/// anything [`AsciicastFile::parse_str_strict`] rejects is an error too
+/// (unknown event codes, extra array elements), and zero-duration recordings
+/// are reported as warnings.
+pub fn validate_str(content: &str, strict: bool) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    let reader = BufReader::new(content.as_bytes());
+    let (cast, diagnostics) = match AsciicastFile::parse_reader_recovering(reader, strict) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            // Header problems are not tied to an event line
+            report.push(Severity::Error, None, format!("{:#}", e));
+            return report;
+        }
+    };
+
+    if !diagnostics.is_empty() {
+        let total = cast.events.len() + diagnostics.len();
+        report.recovered = Some((cast.events.len(), total));
+        for (line, message) in diagnostics {
+            report.push(Severity::Error, Some(line), message);
+        }
+        return report;
+    }
+
+    // Line numbers of event lines, in the order the parser reads them
+    let event_lines = content
+        .lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, _)| i + 1);
+
+    for (event, line) in cast.events.iter().zip(event_lines) {
+        if event.time < 0.0 {
+            report.push(
+                Severity::Error,
+                Some(line),
+                format!("negative event time {}", event.time),
+            );
+        }
+    }
+
+    if strict && cast.duration() <= 0.0 {
+        report.push(Severity::Warning, None, "recording has zero duration");
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = r#"{"version":3,"term":{"cols":80,"rows":24}}"#;
+
+    #[test]
+    fn valid_recording_has_no_issues() {
+        let content = format!("{}\n[0.5,\"o\",\"hi\"]\n[1.0,\"m\",\"mark\"]\n", HEADER);
+        let report = validate_str(&content, true);
+        assert!(report.is_ok());
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn reports_every_bad_line_with_line_number() {
+        let content = format!(
+            "{}\n[0.5,\"o\",\"hi\"]\n\n[0.1,\"z\",\"?\"]\nnot json\n",
+            HEADER
+        );
+        let report = validate_str(&content, false);
+        assert!(!report.is_ok());
+        let lines: Vec<_> = report.issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![Some(5)]);
+
+        let report = validate_str(&content, true);
+        let lines: Vec<_> = report.issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![Some(4), Some(5)]);
+        assert!(report.issues[0].message.contains("Unknown event type: z"));
+    }
+
+    #[test]
+    fn reports_recovered_event_count() {
+        let content = format!(
+            "{}\n[0.5,\"o\",\"a\"]\ngarbage\n[0.5,\"o\",\"b\"]\n",
+            HEADER
+        );
+        let report = validate_str(&content, false);
+        assert!(!report.is_ok());
+        assert_eq!(report.recovered, Some((2, 3)));
+
+        let valid = format!("{}\n[0.5,\"o\",\"a\"]\n", HEADER);
+        assert_eq!(validate_str(&valid, false).recovered, None);
+    }
+
+    #[test]
+    fn unknown_event_code_is_an_error_only_in_strict_mode() {
+        let content = format!("{}\n[0.5,\"o\",\"hi\"]\n[0.1,\"z\",\"?\"]\n", HEADER);
+        assert!(validate_str(&content, false).is_ok());
+
+        let report = validate_str(&content, true);
+        assert!(!report.is_ok());
+        assert_eq!(report.issues[0].line, Some(3));
+    }
+
+    #[test]
+    fn unsupported_version_is_an_error() {
+        let report = validate_str("{\"version\":2,\"width\":80,\"height\":24}\n", false);
+        assert!(!report.is_ok());
+        assert_eq!(report.issues[0].line, None);
+        assert!(report.issues[0].message.contains("got version 2"));
+    }
+
+    #[test]
+    fn negative_event_time_is_an_error() {
+        let content = format!("{}\n[0.5,\"o\",\"a\"]\n[-0.25,\"o\",\"b\"]\n", HEADER);
+        let report = validate_str(&content, false);
+        assert!(!report.is_ok());
+        assert_eq!(report.issues[0].line, Some(3));
+        assert!(report.issues[0].message.contains("-0.25"));
+    }
+
+    #[test]
+    fn zero_duration_warns_only_in_strict_mode() {
+        let content = format!("{}\n[0.0,\"o\",\"a\"]\n", HEADER);
+        assert!(validate_str(&content, false).issues.is_empty());
+
+        let report = validate_str(&content, true);
+        assert!(report.is_ok());
+        assert_eq!(report.issues[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn empty_content_is_an_error() {
+        let report = validate_str("", false);
+        assert!(!report.is_ok());
+        assert!(report.issues[0].message.contains("File is empty"));
+    }
+}
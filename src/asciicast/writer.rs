@@ -40,6 +40,12 @@
 //! let content = file.to_string()?;
 //! # Ok::<(), anyhow::Error>(())
 //! ```
+//!
+//! # Time precision
+//!
+//! Event times are written exactly as stored by default. [`WriteOptions`]
+//! can round them to microseconds or milliseconds instead, e.g. to match the
+//! precision of the source recording after a parse/write roundtrip.
 
 use std::fs;
 use std::io::Write;
@@ -49,14 +55,57 @@ use anyhow::{Context, Result};
 
 use super::types::{AsciicastFile, Event};
 
+/// Granularity that event times are rounded to when writing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimePrecision {
+    /// Write times exactly as stored
+    #[default]
+    Full,
+    /// Round times to whole microseconds
+    Micros,
+    /// Round times to whole milliseconds
+    Millis,
+}
+
+impl TimePrecision {
+    /// Round `time` (in seconds) to this precision.
+    pub fn quantize(self, time: f64) -> f64 {
+        let units_per_sec = match self {
+            TimePrecision::Full => return time,
+            TimePrecision::Micros => 1_000_000.0,
+            TimePrecision::Millis => 1_000.0,
+        };
+        (time * units_per_sec).round() / units_per_sec
+    }
+}
+
+/// Options controlling how an asciicast file is serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WriteOptions {
+    /// Precision of event times
+    pub precision: TimePrecision,
+}
+
+impl WriteOptions {
+    /// Write event times rounded to `precision`.
+    pub fn with_precision(precision: TimePrecision) -> Self {
+        Self { precision }
+    }
+}
+
 impl Event {
     /// Serialize the event to a JSON string.
     ///
     /// Produces the array format: `[time, type_code, data]`.
     /// This method cannot fail as all event fields are JSON-safe.
     pub fn to_json(&self) -> String {
+        self.to_json_with(TimePrecision::Full)
+    }
+
+    /// Serialize the event to a JSON string, rounding its time to `precision`.
+    pub fn to_json_with(&self, precision: TimePrecision) -> String {
         serde_json::to_string(&serde_json::json!([
-            self.time,
+            precision.quantize(self.time),
             self.event_type.to_code(),
             self.data
         ]))
@@ -104,6 +153,15 @@ impl AsciicastFile {
     ///
     /// Returns an error if writing fails or header serialization fails.
     pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.write_to_with(writer, WriteOptions::default())
+    }
+
+    /// Write the asciicast file to any writer using `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails or header serialization fails.
+    pub fn write_to_with<W: Write>(&self, writer: &mut W, options: WriteOptions) -> Result<()> {
         // Write header
         let header_json =
             serde_json::to_string(&self.header).context("Failed to serialize header")?;
@@ -111,7 +169,7 @@ impl AsciicastFile {
 
         // Write events
         for event in &self.events {
-            writeln!(writer, "{}", event.to_json())?;
+            writeln!(writer, "{}", event.to_json_with(options.precision))?;
         }
 
         Ok(())
@@ -126,8 +184,17 @@ impl AsciicastFile {
     /// Returns an error if serialization fails or the result is not valid UTF-8.
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> Result<String> {
+        self.to_string_with(WriteOptions::default())
+    }
+
+    /// Serialize the asciicast file to a string using `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the result is not valid UTF-8.
+    pub fn to_string_with(&self, options: WriteOptions) -> Result<String> {
         let mut buffer = Vec::new();
-        self.write_to(&mut buffer)?;
+        self.write_to_with(&mut buffer, options)?;
         Ok(String::from_utf8(buffer)?)
     }
 }
@@ -151,6 +218,12 @@ mod tests {
         assert_eq!(json, r#"[1.0,"m","test"]"#);
     }
 
+    #[test]
+    fn event_to_json_roundtrips_other_code() {
+        let event = Event::from_json(r#"[0.5,"z","future"]"#).unwrap();
+        assert_eq!(event.to_json(), r#"[0.5,"z","future"]"#);
+    }
+
     #[test]
     fn file_to_string() {
         let mut file = AsciicastFile::new(Header {
@@ -195,4 +268,45 @@ mod tests {
         let lines: Vec<&str> = output.lines().collect();
         assert_eq!(lines.len(), 2); // header + 1 event
     }
+
+    #[test]
+    fn quantize_full_keeps_time() {
+        assert_eq!(TimePrecision::Full.quantize(0.1234567), 0.1234567);
+    }
+
+    #[test]
+    fn millis_drop_sub_millisecond_jitter_and_micros_keep_it() {
+        let mut file = AsciicastFile::new(Header {
+            version: 3,
+            width: None,
+            height: None,
+            term: None,
+            timestamp: None,
+            duration: None,
+            title: None,
+            command: None,
+            env: None,
+            idle_time_limit: None,
+        });
+        file.events.push(Event::output(0.1004, "a"));
+        file.events.push(Event::output(0.2500004, "b"));
+
+        let millis = file
+            .to_string_with(WriteOptions::with_precision(TimePrecision::Millis))
+            .unwrap();
+        assert!(millis.contains(r#"[0.1,"o","a"]"#));
+        assert!(millis.contains(r#"[0.25,"o","b"]"#));
+
+        let micros = file
+            .to_string_with(WriteOptions::with_precision(TimePrecision::Micros))
+            .unwrap();
+        assert!(micros.contains(r#"[0.1004,"o","a"]"#));
+        assert!(micros.contains(r#"[0.25,"o","b"]"#));
+    }
+
+    #[test]
+    fn default_options_write_full_precision() {
+        let event = Event::output(0.2500004, "b");
+        assert_eq!(event.to_json(), r#"[0.2500004,"o","b"]"#);
+    }
 }
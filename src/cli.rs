@@ -4,9 +4,12 @@
 //! so they can be accessed by xtask for documentation generation (man pages, markdown, wiki).
 
 use clap::builder::styling::{AnsiColor, Effects, Styles};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use clap_complete::Shell as CompletionShell;
 
+use crate::theme::ColorChoice;
+use crate::tui::widgets::SortField;
+
 /// Build clap styles using our theme colors.
 ///
 /// Maps theme colors to clap's styling system for consistent CLI appearance.
@@ -47,6 +50,17 @@ For more information, see: https://github.com/thiscantbeserious/agent-session-re
 )]
 #[command(version)]
 pub struct Cli {
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "WHEN",
+        default_value_t = ColorChoice::Auto,
+        hide_possible_values = true,
+        help = "When to color output: auto, always or never (auto honors NO_COLOR)"
+    )]
+    pub color: ColorChoice,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -93,6 +107,30 @@ OUTPUT:
        Oldest: 2025-01-01 (20 days ago)")]
     Status,
 
+    /// Show disk usage by agent and month
+    #[command(long_about = "Report how much disk space recordings use.
+
+Sizes include each recording's backup and sidecar files. The report
+breaks usage down by agent and by month of last change, and lists the
+largest recordings.
+
+EXAMPLES:
+    agr storage                  Show the usage report
+    agr storage --top 20         List the 20 largest recordings
+    agr storage --json           Print the report as JSON")]
+    Storage {
+        /// Number of largest recordings to list
+        #[arg(
+            long,
+            default_value_t = 10,
+            help = "Number of largest recordings to list"
+        )]
+        top: usize,
+        /// Print the report as JSON
+        #[arg(long, help = "Print the report as JSON")]
+        json: bool,
+    },
+
     /// Interactive cleanup of old sessions
     #[command(
         long_about = "Interactively delete old session recordings to free up disk space.
@@ -101,11 +139,19 @@ Displays a list of sessions sorted by age and lets you choose how many
 to delete. Supports filtering by agent and age. Sessions older than
 the configured threshold (default: 30 days) are marked with *.
 
+With --dry-run or --yes, cleanup runs without prompting: matching sessions
+are listed with the space they would free, and with --yes (and no
+--dry-run) they are deleted together with their backups. --yes requires
+--agent or --older-than, so it is safe to run from cron.
+
 EXAMPLES:
     agr cleanup                          Interactive cleanup of all sessions
     agr cleanup --agent claude           Only show Claude sessions
     agr cleanup --older-than 60          Only show sessions older than 60 days
     agr cleanup --agent codex --older-than 30
+    agr cleanup --older-than 30d --dry-run
+                                         Show what would be deleted
+    agr cleanup --older-than 4w --yes    Delete sessions older than 4 weeks
 
 INTERACTIVE OPTIONS:
     [number]    Delete the N oldest sessions
@@ -118,8 +164,23 @@ INTERACTIVE OPTIONS:
         #[arg(long, help = "Only show sessions from this agent")]
         agent: Option<String>,
         /// Only show sessions older than N days
-        #[arg(long, help = "Only show sessions older than N days")]
+        #[arg(
+            long,
+            value_name = "AGE",
+            value_parser = parse_age_days,
+            help = "Only show sessions older than AGE (days: 30 or 30d, weeks: 4w)"
+        )]
         older_than: Option<u32>,
+        /// List matching sessions and the space they would free, without deleting
+        #[arg(long, help = "Show what would be deleted without deleting")]
+        dry_run: bool,
+        /// Delete matching sessions without prompting
+        #[arg(
+            long,
+            short,
+            help = "Delete matching sessions without prompting (needs --agent or --older-than)"
+        )]
+        yes: bool,
     },
 
     /// List recorded sessions
@@ -134,12 +195,38 @@ EXAMPLES:
     agr list                List all sessions
     agr ls                  Same as 'agr list' (alias)
     agr list claude         List only Claude sessions
-    agr list codex          List only Codex sessions"
+    agr list codex          List only Codex sessions
+    agr ls --json           Print sessions as a JSON array
+    agr ls --json --sort size --filter-agent codex
+                            Largest Codex sessions first, as JSON"
     )]
     List {
         /// Filter by agent name
         #[arg(help = "Filter sessions by agent name")]
         agent: Option<String>,
+        /// Filter by agent name (flag form of the positional argument)
+        #[arg(
+            long,
+            value_name = "AGENT",
+            conflicts_with = "agent",
+            help = "Filter sessions by agent name"
+        )]
+        filter_agent: Option<String>,
+        /// Print the sessions as JSON instead of a table or the explorer
+        #[arg(long, help = "Print sessions as a JSON array (for scripting)")]
+        json: bool,
+        /// Sort field for JSON output
+        #[arg(
+            long,
+            value_name = "FIELD",
+            value_parser = parse_sort_field,
+            requires = "json",
+            help = "Sort JSON output by name, date, size or agent (default: date, descending)"
+        )]
+        sort: Option<SortField>,
+        /// Sort ascending instead of descending
+        #[arg(long, requires = "json", help = "Sort JSON output in ascending order")]
+        asc: bool,
     },
 
     /// Analyze a recording with AI
@@ -152,6 +239,14 @@ are added directly to the file using the native asciicast v3 format.
 For large files, analysis is parallelized across multiple chunks, with
 automatic retry and rate limit handling.
 
+Markers found per segment are cached next to the recording
+(<file>.cast.analysis). When a recording grows, re-analysis reuses the cached
+markers of unchanged segments and only sends the new content to the agent.
+Removing existing markers at the prompt, or passing --full, starts fresh.
+
+Pass - to read the recording from standard input. Markers cannot be written
+back to standard input, so it requires --dry-run or --dump-content.
+
 The default agent is configured in ~/.config/agr/config.toml under
 [analysis].agent. Use --agent to override for a single run.
 
@@ -161,17 +256,25 @@ EXAMPLES:
     agr analyze session.cast --workers 4         Use 4 parallel workers
     agr analyze session.cast --no-parallel       Sequential mode
     agr analyze session.cast --timeout 180       3 minute timeout per chunk
+    agr analyze session.cast --output-format markdown  Print a markdown report
+    agr analyze session.cast --dry-run           Estimate size and cost only
+    agr analyze session.cast --full              Ignore cached results
+    agr analyze session.cast --dump-content      Print the text sent to the agent
+    agr analyze session.cast --dump-content --segments-only
+                                                 Print segment boundaries only
+    cat session.cast | agr analyze - --dry-run   Estimate a piped recording
 
 SUPPORTED AGENTS:
     claude      Claude Code CLI (default)
     codex       OpenAI Codex CLI
-    gemini      Google Gemini CLI")]
+    gemini      Google Gemini CLI
+    openai      OpenAI API (requires OPENAI_API_KEY)")]
     Analyze {
         /// Path to the .cast file to analyze
         #[arg(help = "Path to the .cast recording file")]
         file: String,
         /// Override the configured analysis agent
-        #[arg(long, short, help = "Agent to use: claude, codex, gemini")]
+        #[arg(long, short, help = "Agent to use: claude, codex, gemini, openai")]
         agent: Option<String>,
         /// Number of parallel workers (default: auto-scale based on content)
         #[arg(long, short, help = "Number of parallel workers")]
@@ -202,11 +305,66 @@ SUPPORTED AGENTS:
         /// Skip JSON schema enforcement for faster analysis (less reliable)
         #[arg(long, help = "Skip JSON schema enforcement (faster but less reliable)")]
         fast: bool,
+        /// Print a report of the final markers in the given format
+        #[arg(
+            long,
+            value_enum,
+            value_name = "FORMAT",
+            help = "Print a marker report: json, markdown, csv"
+        )]
+        output_format: Option<OutputFormat>,
+        /// Estimate tokens, chunks and cost without running the agent
+        #[arg(
+            long,
+            help = "Estimate tokens, chunks and cost without running the agent (JSON with --output-format json)"
+        )]
+        dry_run: bool,
+        /// Print the extracted content without running the agent
+        #[arg(
+            long,
+            help = "Print the extracted content segments without running the agent"
+        )]
+        dump_content: bool,
+        /// With --dump-content, print only segment time ranges and token estimates
+        #[arg(
+            long,
+            requires = "dump_content",
+            help = "With --dump-content, print only the segment boundaries"
+        )]
+        segments_only: bool,
+        /// Re-analyze every segment instead of reusing cached markers
+        #[arg(long, help = "Re-analyze everything, ignoring cached results")]
+        full: bool,
         /// Wait for keypress before exiting (used by TUI)
         #[arg(long, hide = true)]
         wait: bool,
     },
 
+    /// Check which analysis agents are usable
+    #[command(long_about = "Check which analysis agents are installed and working.
+
+Each supported agent is checked for its CLI in PATH (or, for openai, the
+OPENAI_API_KEY variable). Installed agents are sent a trivial prompt to
+confirm they run and are logged in. The agents are checked in parallel,
+using any analyze args set in their [agents.<name>] config section.
+
+Exits with a non-zero status if the configured analysis agent (see
+[analysis].agent) is not usable.
+
+EXAMPLES:
+    agr doctor                   Check all agents
+    agr doctor --timeout 60      Allow slow agents a minute to answer")]
+    Doctor {
+        /// Seconds to wait for each agent's reply
+        #[arg(
+            long,
+            short,
+            default_value_t = 30,
+            help = "Seconds to wait for each agent's reply"
+        )]
+        timeout: u64,
+    },
+
     /// Play a recording with the native player
     #[command(long_about = "Play an asciicast recording using the native player.
 
@@ -218,18 +376,56 @@ EXAMPLES:
     agr play session.cast                 Play by filename (fuzzy match)
     agr play claude/session.cast          Play using short format
     agr play /path/to/session.cast        Play by absolute path
+    agr play --resume session.cast        Continue where you left off
+    agr play --skip-idle 2 session.cast   Cut pauses longer than 2s short
+    agr play --speed 2 --loop session.cast  Play at 2x and restart at the end
+
+Defaults for speed, viewport mode, looping and --skip-idle can be set in
+the [player] config section; flags given here take precedence. Speed,
+viewport mode and looping changed in the player are saved there on quit.
 
 PLAYER CONTROLS:
     q, Esc      Quit
     Space       Pause/resume
     +/-         Adjust playback speed
-    <, > or ,, .  Seek backward/forward 5s
+    <, > or ,, .  Seek backward/forward by the seek step (default 5s)
+    [, ]        Shrink/grow the seek step (1, 2, 5, 10, 30, 60s)
+    0-9         Jump to 0%-90% of the recording
     m           Jump to next marker
     ?           Show help overlay")]
     Play {
         /// Path to the .cast file to play
         #[arg(help = "Path to the .cast recording file")]
         file: String,
+        /// Resume from the last saved position and save it again on quit
+        #[arg(long, help = "Resume from the last saved position")]
+        resume: bool,
+        /// Skip ahead when a pause between events exceeds this many seconds
+        #[arg(
+            long,
+            value_name = "SECONDS",
+            value_parser = parse_idle_limit,
+            help = "Skip pauses longer than this many seconds (file is unchanged)"
+        )]
+        skip_idle: Option<f64>,
+        /// Initial playback speed multiplier
+        #[arg(
+            long,
+            value_name = "FACTOR",
+            value_parser = parse_speed,
+            help = "Initial playback speed (0.25 to 16, default from config or 1)"
+        )]
+        speed: Option<f64>,
+        /// Restart from the beginning when playback reaches the end
+        #[arg(long = "loop", overrides_with = "no_loop", help = "Loop playback")]
+        loop_playback: bool,
+        /// Play once, even if looping is enabled in the config
+        #[arg(
+            long,
+            overrides_with = "loop_playback",
+            help = "Play once even if [player] loop_playback is set"
+        )]
+        no_loop: bool,
     },
 
     /// Copy a recording to the clipboard
@@ -255,16 +451,21 @@ EXAMPLES:
     /// Manage markers in cast files
     #[command(
         subcommand,
-        long_about = "Add and list markers in asciicast recording files.
+        visible_alias = "markers",
+        long_about = "Add, list and remove markers in asciicast recording files.
 
 Markers are annotations at specific timestamps in a recording,
 useful for highlighting key moments like errors, decisions, or
 milestones. Markers use the native asciicast v3 marker format.
 
+Timestamps accept MM:SS (e.g. 2:00.5) or seconds (e.g. 120.5).
+Files are backed up before the first change (see 'agr optimize').
+
 EXAMPLES:
-    agr marker add session.cast 45.2 \"Build failed\"
-    agr marker add session.cast 120.5 \"Deployment complete\"
-    agr marker list session.cast"
+    agr markers add session.cast 45.2 \"Build failed\"
+    agr markers add session.cast 2:00.5 \"Deployment complete\"
+    agr markers list session.cast
+    agr markers remove session.cast 1"
     )]
     Marker(MarkerCommands),
 
@@ -317,11 +518,31 @@ After installing, restart your shell or run: source ~/.zshrc"
     )]
     Shell(ShellCommands),
 
-    /// Optimize asciicast recordings (removes silence)
-    #[command(long_about = "Optimize asciicast recording files by removing silence.
+    /// Optimize asciicast recordings (removes silence, changes speed, redacts secrets)
+    #[command(
+        long_about = "Optimize asciicast recording files by trimming them, removing
+silence, changing their speed, dropping redundant resizes or redacting
+secrets.
 
 Optimization modifies the timing of recordings by capping long pauses
-at a configurable threshold.
+at a configurable threshold and/or baking a playback speed multiplier
+into the timings. When both are given, silence is removed first.
+
+--redact replaces AWS keys, sk- tokens, bearer tokens and email addresses
+in the output and input with ***REDACTED***, plus any regular expressions listed
+under [redact] patterns in the config. Timing is left untouched.
+
+--dedupe-resizes drops resize events that repeat the previous terminal
+size. Their time is folded into the next event, so the duration stays
+the same.
+
+--from and --to keep only the part of the recording between two times,
+given as MM:SS or seconds. Trimming happens before any other step, and
+times past the end of the recording are clamped with a warning.
+
+Whenever the file is modified in place it is backed up first (.bak).
+
+Pass - to read the recording from standard input; --output is then required.
 
 THRESHOLD RESOLUTION:
     1. CLI argument (explicit user intent)
@@ -336,7 +557,23 @@ EXAMPLES:
         Use explicit 1.5s threshold (note: requires = for value)
 
     agr optimize --remove-silence --output fast.cast session.cast
-        Write to separate file, preserving original")]
+        Write to separate file, preserving original
+
+    agr optimize --speed 2.0 session.cast
+        Make the recording play twice as fast everywhere
+
+    agr optimize --redact --output shareable.cast session.cast
+        Scrub secrets before sharing a recording
+
+    agr optimize --dedupe-resizes session.cast
+        Drop resize events that do not change the terminal size
+
+    agr optimize --from 1:30 --to 4:00 --remove-silence session.cast
+        Keep 1:30-4:00, then remove silence from what is left
+
+    cat session.cast | agr optimize --remove-silence --output fast.cast -
+        Optimize a piped recording"
+    )]
     Optimize {
         /// Remove silence by capping intervals at threshold
         #[arg(
@@ -349,6 +586,46 @@ EXAMPLES:
         )]
         remove_silence: Option<String>,
 
+        /// Divide every interval by this factor (2.0 = twice as fast)
+        #[arg(
+            long,
+            value_name = "FACTOR",
+            help = "Bake a playback speed multiplier into timings (e.g. 2.0)"
+        )]
+        speed: Option<f64>,
+
+        /// Replace secrets in the output and input with ***REDACTED***
+        #[arg(
+            long,
+            help = "Redact API keys, tokens and emails (plus [redact] patterns)"
+        )]
+        redact: bool,
+
+        /// Drop resize events that repeat the previous dimensions
+        #[arg(
+            long = "dedupe-resizes",
+            help = "Drop resize events that repeat the previous terminal size"
+        )]
+        dedupe_resizes: bool,
+
+        /// Drop everything before this time
+        #[arg(
+            long,
+            value_name = "TIME",
+            value_parser = parse_marker_time,
+            help = "Trim the start: keep events from MM:SS or seconds"
+        )]
+        from: Option<f64>,
+
+        /// Drop everything after this time
+        #[arg(
+            long,
+            value_name = "TIME",
+            value_parser = parse_marker_time,
+            help = "Trim the end: keep events up to MM:SS or seconds"
+        )]
+        to: Option<f64>,
+
         /// Write output to a separate file instead of modifying in-place
         #[arg(long, short, value_name = "FILE", help = "Output file path")]
         output: Option<String>,
@@ -358,6 +635,146 @@ EXAMPLES:
         file: String,
     },
 
+    /// Check that recordings parse cleanly
+    #[command(long_about = "Check that one or more recordings parse cleanly.
+
+Each file is parsed like any other agr command would, and every broken
+event line is reported with its line number, together with how many
+events could still be recovered. Event times must not be
+negative and the header must be asciicast v3. Unknown single-character
+event codes are accepted and preserved, as agr does everywhere else.
+
+With --strict, event lines must match the asciicast v3 spec exactly:
+unknown event codes and extra array elements are errors. Files that
+parse but look suspicious (zero duration) are reported with warnings.
+Warnings do not change the exit code.
+
+Pass - to read a recording from standard input.
+
+Exits with a non-zero status if any file fails.
+
+EXAMPLES:
+    agr validate session.cast
+        Check a single recording
+
+    agr validate --strict recordings/*.cast
+        Check every recording before committing them
+
+    cat session.cast | agr validate -
+        Check a piped recording")]
+    Validate {
+        /// Enforce the asciicast v3 spec and warn about suspicious recordings
+        #[arg(
+            long,
+            help = "Reject unknown event codes and warn on zero-duration recordings"
+        )]
+        strict: bool,
+
+        /// Paths to the .cast files to check
+        #[arg(
+            id = "file",
+            value_name = "FILE",
+            required = true,
+            help = "Path to a .cast recording file (repeatable)"
+        )]
+        files: Vec<String>,
+    },
+
+    /// Summarize a recording without playing it
+    #[command(long_about = "Print an at-a-glance summary of a recording.
+
+Shows the terminal size, title, recorded command, detected agent, start
+time, duration, event counts by type, marker count and file size. The
+duration is computed from the events, not taken from the header.
+
+Pass - to read the recording from standard input.
+
+EXAMPLES:
+    agr info claude/session.cast
+        Summarize a recording
+
+    agr info --json claude/session.cast
+        Print the summary as JSON for scripting")]
+    Info {
+        /// Print the summary as JSON
+        #[arg(long, help = "Print the summary as JSON")]
+        json: bool,
+
+        /// Path to the .cast file
+        #[arg(help = "Path to the .cast recording file")]
+        file: String,
+    },
+
+    /// Compare two recordings
+    #[command(long_about = "Show what changed between two recordings.
+
+Both recordings are rendered to plain-text transcripts, the same text
+'Copy transcript' produces in the TUI, and compared line by line. The
+result is printed as a unified diff. Neither file is modified.
+
+With --markers-only, only the marker lists (timestamp and label) are
+compared.
+
+EXAMPLES:
+    agr diff claude/run1.cast claude/run2.cast
+        Compare what two agent runs printed
+
+    agr diff --markers-only before.cast after.cast
+        Compare just the markers")]
+    Diff {
+        /// Compare only the markers
+        #[arg(long, help = "Compare only the marker lists (timestamp and label)")]
+        markers_only: bool,
+
+        /// The original recording
+        #[arg(help = "Path to the original .cast recording file")]
+        old: String,
+
+        /// The changed recording
+        #[arg(help = "Path to the changed .cast recording file")]
+        new: String,
+    },
+
+    /// Export a recording to another format
+    #[command(long_about = "Export a recording as an animated GIF.
+
+The recording is replayed at a fixed frame rate and each frame is drawn
+with a bundled bitmap font, using the recording's color theme when it has
+one. Pauses longer than the header's idle_time_limit are shortened, and
+terminal resizes are padded to the largest size reached.
+
+GIF export is an optional feature; builds without it report how to
+enable it (cargo install agr --features gif-export).
+
+EXAMPLES:
+    agr export session.cast
+        Write session.gif next to the recording at 10 fps
+
+    agr export --fps 20 --output demo.gif claude/session.cast
+        Smoother animation written to demo.gif")]
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value = "gif", help = "Output format")]
+        format: ExportFormat,
+
+        /// Frames per second
+        #[arg(
+            long,
+            default_value = "10",
+            value_parser = clap::value_parser!(u32).range(1..=50),
+            help = "Frames per second (1-50)"
+        )]
+        fps: u32,
+
+        /// Write output to this path instead of next to the recording
+        #[arg(long, short, value_name = "FILE", help = "Output file path")]
+        output: Option<String>,
+
+        /// Path to the .cast file to export
+        #[arg(help = "Path to the .cast recording file")]
+        file: String,
+    },
+
     /// Generate shell completions (internal use)
     #[command(hide = true)]
     Completions {
@@ -387,22 +804,46 @@ EXAMPLES:
     },
 }
 
+/// Report format for `agr analyze --output-format`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Raw marker JSON
+    Json,
+    /// Markdown table
+    Markdown,
+    /// Comma-separated values
+    Csv,
+}
+
+/// Target format for `agr export --format`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Animated GIF
+    Gif,
+}
+
 #[derive(Subcommand)]
 pub enum MarkerCommands {
     /// Add a marker to a cast file at a specific timestamp
     #[command(long_about = "Add a marker to a cast file at a specific timestamp.
 
 Markers are injected into the asciicast file using the native v3 marker
-format. The timestamp is cumulative seconds from the start of the recording.
+format. The timestamp is measured from the start of the recording, as
+MM:SS or seconds, and must not be past the end of the recording. The
+file is backed up before the first change.
 
-EXAMPLE:
-    agr marker add ~/recorded_agent_sessions/claude/session.cast 45.2 \"Build error\"")]
+EXAMPLES:
+    agr markers add ~/recorded_agent_sessions/claude/session.cast 45.2 \"Build error\"
+    agr markers add claude/session.cast 1:30 \"Tests green\"")]
     Add {
         /// Path to the .cast file
         #[arg(help = "Path to the .cast recording file")]
         file: String,
-        /// Timestamp in seconds from start of recording
-        #[arg(help = "Timestamp in seconds (e.g., 45.2)")]
+        /// Timestamp from start of recording
+        #[arg(
+            value_parser = parse_marker_time,
+            help = "Timestamp as MM:SS or seconds (e.g., 1:30 or 45.2)"
+        )]
         time: f64,
         /// Marker label/description
         #[arg(help = "Description of the marker (e.g., \"Build failed\")")]
@@ -412,19 +853,84 @@ EXAMPLE:
     #[command(
         long_about = "List all markers in a cast file with their timestamps and labels.
 
-EXAMPLE:
-    agr marker list ~/recorded_agent_sessions/claude/session.cast
+Markers are numbered in recording order; use the number with
+'agr markers remove'. Pass - to read the recording from standard input.
+
+EXAMPLES:
+    agr markers list ~/recorded_agent_sessions/claude/session.cast
+    cat session.cast | agr markers list -
 
 OUTPUT:
     Markers:
-      [45.2s] Build error
-      [120.5s] Deployment complete"
+      1  00:45  Build error
+      2  02:00  Deployment complete"
     )]
     List {
         /// Path to the .cast file
         #[arg(help = "Path to the .cast recording file")]
         file: String,
     },
+    /// Remove a marker from a cast file
+    #[command(long_about = "Remove a marker from a cast file by its number.
+
+Marker numbers are shown by 'agr markers list'. Output events keep their
+timestamps. The file is backed up before the first change.
+
+EXAMPLE:
+    agr markers remove claude/session.cast 2")]
+    Remove {
+        /// Path to the .cast file
+        #[arg(help = "Path to the .cast recording file")]
+        file: String,
+        /// Marker number as shown by `agr markers list`
+        #[arg(
+            value_parser = clap::value_parser!(u64).range(1..),
+            help = "Marker number from 'agr markers list' (starting at 1)"
+        )]
+        index: u64,
+    },
+}
+
+/// Parse a cleanup age given in days (`30`, `30d`) or weeks (`4w`).
+fn parse_age_days(s: &str) -> Result<u32, String> {
+    let (number, factor) = if let Some(weeks) = s.strip_suffix('w') {
+        (weeks, 7)
+    } else {
+        (s.strip_suffix('d').unwrap_or(s), 1)
+    };
+    number
+        .parse::<u32>()
+        .ok()
+        .and_then(|n| n.checked_mul(factor))
+        .ok_or_else(|| format!("expected days (30 or 30d) or weeks (4w), got '{}'", s))
+}
+
+/// Parse an idle limit in seconds, which must be positive.
+fn parse_idle_limit(s: &str) -> Result<f64, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|secs| secs.is_finite() && *secs > 0.0)
+        .ok_or_else(|| format!("expected a positive number of seconds, got '{}'", s))
+}
+
+/// Parse a playback speed multiplier within the player's supported range.
+fn parse_speed(s: &str) -> Result<f64, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|speed| (0.25..=16.0).contains(speed))
+        .ok_or_else(|| format!("expected a speed between 0.25 and 16, got '{}'", s))
+}
+
+/// Parse a file explorer sort field (`name`, `date`, `size`, `agent`).
+fn parse_sort_field(s: &str) -> Result<SortField, String> {
+    SortField::parse(s)
+        .ok_or_else(|| format!("expected one of name, date, size, agent, got '{}'", s))
+}
+
+/// Parse a marker timestamp argument given as `MM:SS` or seconds.
+fn parse_marker_time(s: &str) -> Result<f64, String> {
+    crate::asciicast::parse_marker_timestamp(s)
+        .ok_or_else(|| format!("expected MM:SS or seconds, got '{}'", s))
 }
 
 #[derive(Subcommand)]
@@ -541,6 +1047,52 @@ EXAMPLE:
     agr config edit
     EDITOR=nano agr config edit")]
     Edit,
+    /// Print a single config value
+    #[command(long_about = "Print the effective value of a single config key.
+
+Keys are dotted paths: <section>.<field>, or agents.<agent>.<field> for
+per-agent settings. Unset optional fields print nothing.
+
+EXAMPLES:
+    agr config get analysis.timeout
+    agr config get agents.claude.token_budget")]
+    Get {
+        /// Dotted config key
+        #[arg(help = "Config key, e.g. analysis.timeout")]
+        key: String,
+    },
+    /// Set a single config value
+    #[command(long_about = "Set a single config value without opening an editor.
+
+The value is parsed as TOML (numbers, booleans, [\"lists\"]) and checked
+against the config schema before writing. Comments and other settings
+in the file are preserved.
+
+EXAMPLES:
+    agr config set analysis.timeout 300
+    agr config set tui.theme ocean
+    agr config set agents.enabled '[\"claude\", \"codex\"]'")]
+    Set {
+        /// Dotted config key
+        #[arg(help = "Config key, e.g. analysis.timeout")]
+        key: String,
+        /// New value
+        #[arg(help = "Value to store (parsed as TOML, falls back to a string)")]
+        value: String,
+    },
+    /// Check the config file for errors
+    #[command(
+        long_about = "Check the configuration for errors without running anything.
+
+Loads the config file and runs every validation check: analysis limits,
+per-agent token budgets, the prompt template file, the recording filename
+template, and configured paths. Prints \"Config OK\" or the first error
+and exits non-zero, so it can gate CI jobs.
+
+EXAMPLE:
+    agr config validate"
+    )]
+    Validate,
     /// Add missing fields to config file
     #[command(long_about = "Add missing fields to your config file.
 
@@ -80,11 +80,27 @@ impl Copy {
 
         // Fall back to content copy
         let content = std::fs::read_to_string(path)?;
-        let size = content.len();
+        self.copy_text(&content, last_error)
+    }
+
+    /// Copy text to the clipboard.
+    ///
+    /// Uses the first available tool that accepts text.
+    pub fn text(&self, text: &str) -> Result<CopyResult, ClipboardError> {
+        self.copy_text(text, None)
+    }
+
+    /// Try each available tool's text copy, remembering the last failure.
+    fn copy_text(
+        &self,
+        text: &str,
+        mut last_error: Option<String>,
+    ) -> Result<CopyResult, ClipboardError> {
+        let size = text.len();
 
         for tool in &self.tools {
             if tool.is_available() {
-                match tool.try_copy_text(&content) {
+                match tool.try_copy_text(text) {
                     Ok(()) => {
                         return Ok(CopyResult::content_copied(tool.method(), size));
                     }
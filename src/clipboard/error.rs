@@ -11,7 +11,7 @@ pub enum ClipboardError {
     #[error("File not found: {path}")]
     FileNotFound { path: PathBuf },
 
-    #[error("No clipboard tool available. On Linux, install xclip, xsel, or wl-copy.")]
+    #[error("No clipboard tool available. On Linux, install xclip, xsel, or wl-copy, or use a terminal with OSC 52 support.")]
     NoToolAvailable,
 
     #[error("File too large for clipboard ({size_mb:.1} MB). Maximum is {max_mb} MB.")]
@@ -20,6 +20,6 @@ pub enum ClipboardError {
     #[error("Failed to read file: {0}")]
     ReadError(#[from] std::io::Error),
 
-    #[error("Platform not supported (only macOS and Linux)")]
+    #[error("Platform not supported (only macOS, Linux and Windows)")]
     UnsupportedPlatform,
 }
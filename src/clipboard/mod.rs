@@ -2,8 +2,9 @@
 //!
 //! This module provides cross-platform clipboard support for copying
 //! `.cast` recording files. On macOS, files can be copied as file references
-//! for direct paste into Slack/email. On Linux, it falls back to content copy
-//! when file copy isn't supported.
+//! for direct paste into Slack/email. On Linux and Windows, it falls back to
+//! content copy when file copy isn't supported. Plain text, such as a
+//! rendered transcript, can be copied with [`copy_text_to_clipboard`].
 //!
 //! # Example
 //!
@@ -39,3 +40,11 @@ use std::path::Path;
 pub fn copy_file_to_clipboard(path: &Path) -> Result<CopyResult, ClipboardError> {
     Copy::new().file(path)
 }
+
+/// Copy text to the system clipboard.
+///
+/// # Errors
+/// - `ClipboardError::NoToolAvailable` - no clipboard tool accepted the text
+pub fn copy_text_to_clipboard(text: &str) -> Result<CopyResult, ClipboardError> {
+    Copy::new().text(text)
+}
@@ -54,6 +54,10 @@ pub enum CopyMethod {
     Xsel,
     /// Linux Wayland
     WlCopy,
+    /// Windows clip.exe
+    WinClip,
+    /// Terminal OSC 52 escape sequence
+    Osc52,
 }
 
 impl CopyMethod {
@@ -65,6 +69,8 @@ impl CopyMethod {
             Self::Xclip => "xclip",
             Self::Xsel => "xsel",
             Self::WlCopy => "wl-copy",
+            Self::WinClip => "clip",
+            Self::Osc52 => "osc52",
         }
     }
 }
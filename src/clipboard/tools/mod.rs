@@ -1,13 +1,17 @@
 //! Platform-specific clipboard tools.
 
 mod osascript;
+mod osc52;
 mod pbcopy;
+mod win_clip;
 mod wl_copy;
 mod xclip;
 mod xsel;
 
 pub use osascript::OsaScript;
+pub use osc52::Osc52;
 pub use pbcopy::Pbcopy;
+pub use win_clip::WinClip;
 pub use wl_copy::WlCopy;
 pub use xclip::Xclip;
 pub use xsel::Xsel;
@@ -15,10 +19,16 @@ pub use xsel::Xsel;
 use super::tool::CopyTool;
 
 /// Get the platform-appropriate tools in priority order.
+///
+/// OSC 52 is always last, as a fallback for SSH sessions without native tools.
 pub fn platform_tools() -> Vec<Box<dyn CopyTool>> {
     #[cfg(target_os = "macos")]
     {
-        vec![Box::new(OsaScript::new()), Box::new(Pbcopy::new())]
+        vec![
+            Box::new(OsaScript::new()),
+            Box::new(Pbcopy::new()),
+            Box::new(Osc52::new()),
+        ]
     }
 
     #[cfg(target_os = "linux")]
@@ -27,11 +37,17 @@ pub fn platform_tools() -> Vec<Box<dyn CopyTool>> {
             Box::new(Xclip::new()),
             Box::new(Xsel::new()),
             Box::new(WlCopy::new()),
+            Box::new(Osc52::new()),
         ]
     }
 
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    #[cfg(target_os = "windows")]
+    {
+        vec![Box::new(WinClip::new()), Box::new(Osc52::new())]
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
     {
-        vec![]
+        vec![Box::new(Osc52::new())]
     }
 }
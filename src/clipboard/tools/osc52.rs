@@ -0,0 +1,88 @@
+//! OSC 52 terminal clipboard tool.
+
+use crate::clipboard::result::CopyMethod;
+use crate::clipboard::tool::{CopyTool, CopyToolError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::io::Write;
+use std::path::Path;
+
+/// Terminal clipboard tool using the OSC 52 escape sequence.
+///
+/// Asks the terminal emulator itself to set the clipboard, so it works over
+/// SSH and inside tmux where no native clipboard binary is reachable.
+/// Does not support file copy.
+pub struct Osc52 {
+    tmux: bool,
+}
+
+impl Osc52 {
+    /// Create a new Osc52 tool, wrapping for tmux when `$TMUX` is set.
+    pub fn new() -> Self {
+        Self {
+            tmux: std::env::var_os("TMUX").is_some(),
+        }
+    }
+
+    /// Build the escape sequence that sets the clipboard to `text`.
+    ///
+    /// With `tmux` set, the sequence is wrapped in a DCS passthrough so tmux
+    /// forwards it to the outer terminal.
+    pub fn escape_sequence(text: &str, tmux: bool) -> String {
+        let osc = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+        if tmux {
+            format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+        } else {
+            osc
+        }
+    }
+
+    /// Open the controlling terminal for writing.
+    fn open_tty() -> std::io::Result<Box<dyn Write>> {
+        #[cfg(unix)]
+        {
+            let tty = std::fs::OpenOptions::new().write(true).open("/dev/tty")?;
+            Ok(Box::new(tty))
+        }
+
+        #[cfg(not(unix))]
+        {
+            Ok(Box::new(std::io::stdout()))
+        }
+    }
+}
+
+impl CopyTool for Osc52 {
+    fn method(&self) -> CopyMethod {
+        CopyMethod::Osc52
+    }
+
+    fn is_available(&self) -> bool {
+        if cfg!(unix) {
+            Self::open_tty().is_ok()
+        } else {
+            atty::is(atty::Stream::Stdout)
+        }
+    }
+
+    fn can_copy_files(&self) -> bool {
+        false
+    }
+
+    fn try_copy_file(&self, _path: &Path) -> Result<(), CopyToolError> {
+        Err(CopyToolError::NotSupported)
+    }
+
+    fn try_copy_text(&self, text: &str) -> Result<(), CopyToolError> {
+        let mut tty = Self::open_tty().map_err(|e| CopyToolError::Failed(e.to_string()))?;
+        tty.write_all(Self::escape_sequence(text, self.tmux).as_bytes())
+            .and_then(|()| tty.flush())
+            .map_err(|e| CopyToolError::Failed(e.to_string()))
+    }
+}
+
+impl Default for Osc52 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
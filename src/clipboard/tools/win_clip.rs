@@ -0,0 +1,79 @@
+//! Windows clip.exe clipboard tool.
+
+use crate::clipboard::result::CopyMethod;
+use crate::clipboard::tool::{CopyTool, CopyToolError};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Windows clipboard tool using clip.exe.
+///
+/// Uses `clip.exe` to copy text content to the clipboard.
+/// Does not support file copy.
+pub struct WinClip;
+
+impl WinClip {
+    /// Create a new WinClip tool.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Encode text as UTF-16LE with a byte order mark.
+    ///
+    /// clip.exe reads stdin using the console code page unless it starts
+    /// with a BOM, so UTF-16 keeps non-ASCII content intact.
+    pub fn encode_utf16le(text: &str) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in text.encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+impl CopyTool for WinClip {
+    fn method(&self) -> CopyMethod {
+        CopyMethod::WinClip
+    }
+
+    fn is_available(&self) -> bool {
+        cfg!(target_os = "windows")
+    }
+
+    fn can_copy_files(&self) -> bool {
+        false
+    }
+
+    fn try_copy_file(&self, _path: &Path) -> Result<(), CopyToolError> {
+        Err(CopyToolError::NotSupported)
+    }
+
+    fn try_copy_text(&self, text: &str) -> Result<(), CopyToolError> {
+        let mut child = Command::new("clip.exe")
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(|e| CopyToolError::Failed(e.to_string()))?;
+
+        if let Some(stdin) = child.stdin.as_mut() {
+            stdin
+                .write_all(&Self::encode_utf16le(text))
+                .map_err(|e| CopyToolError::Failed(e.to_string()))?;
+        }
+
+        let status = child
+            .wait()
+            .map_err(|e| CopyToolError::Failed(e.to_string()))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(CopyToolError::Failed("clip.exe failed".to_string()))
+        }
+    }
+}
+
+impl Default for WinClip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
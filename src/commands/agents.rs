@@ -27,7 +27,7 @@ pub fn handle_list() -> Result<()> {
 /// Add an agent to the configuration.
 #[cfg(not(tarpaulin_include))]
 pub fn handle_add(name: &str) -> Result<()> {
-    let mut config = Config::load()?;
+    let mut config = Config::load_file()?;
     let theme = current_theme();
 
     if config.add_agent(name) {
@@ -46,7 +46,7 @@ pub fn handle_add(name: &str) -> Result<()> {
 /// Remove an agent from the configuration.
 #[cfg(not(tarpaulin_include))]
 pub fn handle_remove(name: &str) -> Result<()> {
-    let mut config = Config::load()?;
+    let mut config = Config::load_file()?;
     let theme = current_theme();
 
     if config.remove_agent(name) {
@@ -107,7 +107,7 @@ pub fn handle_nowrap_list() -> Result<()> {
 /// Add an agent to the no-wrap list.
 #[cfg(not(tarpaulin_include))]
 pub fn handle_nowrap_add(name: &str) -> Result<()> {
-    let mut config = Config::load()?;
+    let mut config = Config::load_file()?;
     let theme = current_theme();
 
     if config.add_no_wrap(name) {
@@ -132,7 +132,7 @@ pub fn handle_nowrap_add(name: &str) -> Result<()> {
 /// Remove an agent from the no-wrap list.
 #[cfg(not(tarpaulin_include))]
 pub fn handle_nowrap_remove(name: &str) -> Result<()> {
-    let mut config = Config::load()?;
+    let mut config = Config::load_file()?;
     let theme = current_theme();
 
     if config.remove_no_wrap(name) {
@@ -12,15 +12,23 @@
 //! 9. Suggest better filename via LLM based on analysis
 
 use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use anyhow::Result;
 
-use agr::analyzer::{AgentType, AnalyzeOptions, AnalyzerService};
-use agr::{Config, MarkerManager};
+use agr::analyzer::{
+    resolve_timestamp, to_csv, to_markdown, AgentType, AnalysisCache, AnalysisContent,
+    AnalysisEstimate, AnalyzeOptions, AnalyzerService, RawMarker, RetryPolicy, TimeRange,
+    ValidatedMarker,
+};
+use agr::cli::OutputFormat;
+use agr::{AsciicastFile, Config, MarkerManager};
 
 use agr::asciicast::integrity::check_file_integrity;
+use agr::files::backup::create_backup;
 use agr::files::resolve::resolve_file_path;
+use agr::files::stdin::{is_stdin, parse_stdin, reject_stdin};
 
 /// Threshold for offering marker curation.
 const CURATION_THRESHOLD: usize = 12;
@@ -41,6 +49,11 @@ pub fn handle(
     debug: bool,
     output: Option<String>,
     fast: bool,
+    output_format: Option<OutputFormat>,
+    dry_run: bool,
+    dump_content: bool,
+    segments_only: bool,
+    full: bool,
     wait: bool,
 ) -> Result<()> {
     let config = Config::load()?;
@@ -52,23 +65,15 @@ pub fn handle(
     };
     let agent = parse_agent_type(&resolved_agent)?;
 
-    // Resolve file path (supports short format like "claude/session.cast")
-    let filepath = resolve_file_path(file, &config)?;
-    if !filepath.exists() {
-        anyhow::bail!(
-            "File not found: {}\nHint: Use format 'agent/file.cast'. Run 'agr list' to see available sessions.",
-            file
-        );
+    // Standard input can only be inspected: markers are written back
+    if !dry_run && !dump_content {
+        reject_stdin(
+            file,
+            "write markers to",
+            "Use --dry-run or --dump-content, or pass a .cast file path.",
+        )?;
     }
 
-    // Check file has .cast extension
-    if filepath.extension().and_then(|e| e.to_str()) != Some("cast") {
-        eprintln!("Warning: File does not have .cast extension");
-    }
-
-    // Check for file corruption before proceeding
-    check_file_integrity(&filepath)?;
-
     // Look up per-agent config
     let agent_config = config.analysis_agent_config(&resolved_agent);
 
@@ -98,12 +103,35 @@ pub fn handle(
     if let Some(out) = output {
         options = options.output(out);
     }
+    options = options.incremental(!full);
 
     // Fast: CLI true wins, else config, else false
     if fast || config.analysis.fast.unwrap_or(false) {
         options = options.fast(true);
     }
 
+    // Retry policy: config > defaults
+    let mut retry_policy = RetryPolicy::default();
+    if let Some(attempts) = config.analysis.retry_attempts {
+        retry_policy.max_attempts = attempts;
+    }
+    if let Some(delay) = config.analysis.retry_delay_ms {
+        retry_policy.initial_delay_ms = delay;
+    }
+    if let Some(max_delay) = config.analysis.retry_max_delay_ms {
+        retry_policy.max_delay_ms = max_delay;
+    }
+    options = options.retry_policy(retry_policy);
+
+    // Custom prompt template: per-agent config > global config > built-in
+    if let Some(template) = config
+        .analysis
+        .load_prompt_template_for(&resolved_agent, agent_config)
+        .map_err(anyhow::Error::msg)?
+    {
+        options = options.prompt_template(template);
+    }
+
     // Pass per-task extra_args and token_budget_override from per-agent config
     if let Some(ac) = agent_config {
         let analyze_args = ac.effective_analyze_args();
@@ -127,11 +155,52 @@ pub fn handle(
     let service = AnalyzerService::new(options);
     let agent_name = &resolved_agent;
 
+    if is_stdin(file) {
+        let cast = parse_stdin()?;
+        if dry_run {
+            print_dry_run(file, &service.estimate_cast(cast)?, output_format)?;
+        } else {
+            let content = service.extract_cast_content(cast);
+            print!("{}", format_content_dump(&content, segments_only));
+        }
+        return Ok(());
+    }
+
+    // Resolve file path (supports short format like "claude/session.cast")
+    let filepath = resolve_file_path(file, &config)?;
+    if !filepath.exists() {
+        anyhow::bail!(
+            "File not found: {}\nHint: Use format 'agent/file.cast'. Run 'agr list' to see available sessions.",
+            file
+        );
+    }
+
+    // Check file has .cast extension
+    if filepath.extension().and_then(|e| e.to_str()) != Some("cast") {
+        eprintln!("Warning: File does not have .cast extension");
+    }
+
+    // Check for file corruption before proceeding
+    check_file_integrity(&filepath)?;
+
+    // Dry run: report size and cost without invoking the agent
+    if dry_run {
+        print_dry_run(file, &service.estimate(&filepath)?, output_format)?;
+        return Ok(());
+    }
+
+    // Dump content: show what the agent would receive without invoking it
+    if dump_content {
+        let content = service.extract_content(&filepath)?;
+        print!("{}", format_content_dump(&content, segments_only));
+        return Ok(());
+    }
+
     // Check agent is available
     if !service.is_agent_available() {
         anyhow::bail!(
             "Analysis agent '{}' is not installed. Install it or use --agent to specify another.\n\
-             Supported agents: claude, codex, gemini",
+             Supported agents: claude, codex, gemini, openai",
             agent_name
         );
     }
@@ -150,13 +219,20 @@ pub fn handle(
 
         if input.trim().eq_ignore_ascii_case("y") || input.trim().eq_ignore_ascii_case("yes") {
             let removed = MarkerManager::clear_markers(&filepath)?;
+            // Analyze every segment again instead of reusing cached ones
+            AnalysisCache::clear(&filepath)?;
             println!("Removed {} marker(s).", removed);
         }
     }
 
     // Run analysis
     println!("Analyzing {} with {}...", file, agent);
-    let result = service.analyze(&filepath)?;
+    let streamed = AtomicUsize::new(0);
+    let on_marker = |range: &TimeRange, marker: &RawMarker| {
+        streamed.fetch_add(1, Ordering::Relaxed);
+        print_partial_marker(resolve_timestamp(range, marker.timestamp), &marker.label);
+    };
+    let result = service.analyze_streaming(&filepath, &on_marker)?;
 
     // Report results
     if result.is_partial() {
@@ -166,16 +242,21 @@ pub fn handle(
         );
     }
 
-    // Print markers verbosely
-    println!("\nMarkers found ({}):", result.markers.len());
-    for marker in &result.markers {
-        print_marker(marker.timestamp, &marker.label);
+    // Print markers verbosely, unless they were already printed as they
+    // streamed in
+    if streamed.load(Ordering::Relaxed) == 0 {
+        println!("\nMarkers found ({}):", result.markers.len());
+        for marker in &result.markers {
+            print_marker(marker.timestamp, &marker.label);
+        }
+    } else {
+        println!("\nMarkers found: {}", result.markers.len());
     }
 
     // Handle curation if we have many markers
     // Curate: CLI true wins, else config, else false
     let effective_curate = curate || config.analysis.curate.unwrap_or(false);
-    let final_marker_count = if result.markers.len() > CURATION_THRESHOLD {
+    let final_markers = if result.markers.len() > CURATION_THRESHOLD {
         let should_curate = if effective_curate {
             // Auto-curate with --curate flag
             println!(
@@ -200,35 +281,48 @@ pub fn handle(
             let timeout_duration = Duration::from_secs(timeout.unwrap_or(120));
             match service.curate_markers(&result.markers, result.total_duration, timeout_duration) {
                 Ok(curated) => {
-                    // Write curated markers to file (replacing the ones from analyze)
-                    MarkerManager::clear_markers(&filepath)?;
+                    // Write curated markers to file (replacing the ones from
+                    // analyze), backing it up once before the rewrite
+                    let mut cast = AsciicastFile::parse(&filepath)?;
+                    MarkerManager::clear_markers_from_cast(&mut cast);
                     for marker in &curated {
-                        MarkerManager::add_marker(&filepath, marker.timestamp, &marker.label)?;
+                        MarkerManager::add_marker_to_cast(
+                            &mut cast,
+                            marker.timestamp,
+                            &marker.label,
+                        )?;
                     }
+                    create_backup(&filepath, config.storage.backup_limit)?;
+                    cast.write(&filepath)?;
+                    service.update_cache(&filepath, &curated)?;
 
                     println!("\nCurated markers ({}):", curated.len());
                     for marker in &curated {
                         print_marker(marker.timestamp, &marker.label);
                     }
-                    curated.len()
+                    curated
                 }
                 Err(e) => {
                     eprintln!("Warning: Curation failed ({}), keeping all markers.", e);
-                    result.markers.len()
+                    result.markers.clone()
                 }
             }
         } else {
-            result.markers.len()
+            result.markers.clone()
         }
     } else {
-        result.markers.len()
+        result.markers.clone()
     };
 
     println!(
         "\nAnalysis complete. {} markers in file.",
-        final_marker_count
+        final_markers.len()
     );
 
+    if let Some(format) = output_format {
+        println!("\n{}", render_report(format, &final_markers)?);
+    }
+
     // Suggest a descriptive filename via LLM
     if !result.markers.is_empty() {
         let current_filename = filepath
@@ -285,14 +379,128 @@ fn print_marker(timestamp: f64, label: &str) {
     println!("  {:02}:{:05.2} - {}", minutes, seconds, label);
 }
 
+/// Print a dry-run estimate as JSON or human-readable text.
+fn print_dry_run(
+    file: &str,
+    estimate: &AnalysisEstimate,
+    output_format: Option<OutputFormat>,
+) -> Result<()> {
+    if output_format == Some(OutputFormat::Json) {
+        println!("{}", serde_json::to_string_pretty(estimate)?);
+    } else {
+        print_estimate(file, estimate);
+    }
+    Ok(())
+}
+
+/// Print a human-readable dry-run estimate.
+fn print_estimate(file: &str, estimate: &AnalysisEstimate) {
+    println!("Dry run for {} with {}:", file, estimate.agent);
+    println!(
+        "  Model:          {}",
+        estimate.model.as_deref().unwrap_or("unknown")
+    );
+    println!("  Segments:       {}", estimate.segments);
+    println!("  Content tokens: ~{}", estimate.content_tokens);
+    println!(
+        "  Chunks:         {} ({} worker(s))",
+        estimate.chunks, estimate.workers
+    );
+    println!(
+        "  Input tokens:   ~{} (including prompt)",
+        estimate.input_tokens
+    );
+    println!("  Output tokens:  ~{}", estimate.output_tokens);
+    match estimate.estimated_cost_usd {
+        Some(cost) => println!("  Estimated cost: ~${:.4}", cost),
+        None => println!("  Estimated cost: unknown (model not in price table)"),
+    }
+}
+
+/// Format extracted content segments for `--dump-content`.
+///
+/// Each segment gets a header with its time range and token estimate,
+/// followed by its text unless `segments_only` is set.
+fn format_content_dump(content: &AnalysisContent, segments_only: bool) -> String {
+    let mut out = format!(
+        "{} segment(s), ~{} tokens, {} duration\n",
+        content.segments.len(),
+        content.total_tokens,
+        format_dump_time(content.total_duration)
+    );
+    for (i, segment) in content.segments.iter().enumerate() {
+        if !segments_only {
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "Segment {} [{} - {}] ~{} tokens\n",
+            i + 1,
+            format_dump_time(segment.start_time),
+            format_dump_time(segment.end_time),
+            segment.estimated_tokens
+        ));
+        if !segments_only {
+            out.push_str(&segment.content);
+            if !segment.content.ends_with('\n') {
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+/// Format seconds as MM:SS.ss for the content dump.
+fn format_dump_time(seconds: f64) -> String {
+    let minutes = (seconds / 60.0).floor() as u32;
+    format!("{:02}:{:05.2}", minutes, seconds % 60.0)
+}
+
+/// Render the final markers as a shareable report.
+///
+/// Category prefixes added to labels during validation are stripped, since
+/// every format has its own category column.
+fn render_report(format: OutputFormat, markers: &[ValidatedMarker]) -> Result<String> {
+    let markers: Vec<RawMarker> = markers
+        .iter()
+        .map(|m| {
+            let prefix = format!("[{}] ", m.category);
+            RawMarker {
+                timestamp: m.timestamp,
+                label: m
+                    .label
+                    .strip_prefix(&prefix)
+                    .unwrap_or(&m.label)
+                    .to_string(),
+                category: m.category,
+            }
+        })
+        .collect();
+
+    Ok(match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(&serde_json::json!({ "markers": markers }))?
+        }
+        OutputFormat::Markdown => to_markdown(&markers),
+        OutputFormat::Csv => to_csv(&markers),
+    })
+}
+
+/// Print a marker as soon as the agent reports it, before aggregation.
+fn print_partial_marker(timestamp: f64, label: &str) {
+    let minutes = (timestamp / 60.0).floor() as u32;
+    let seconds = timestamp % 60.0;
+    eprintln!("  + {:02}:{:05.2} - {}", minutes, seconds, label);
+}
+
 /// Parse agent name string to AgentType enum.
-fn parse_agent_type(name: &str) -> Result<AgentType> {
+pub(crate) fn parse_agent_type(name: &str) -> Result<AgentType> {
     match name.to_lowercase().as_str() {
         "claude" => Ok(AgentType::Claude),
         "codex" => Ok(AgentType::Codex),
         "gemini" => Ok(AgentType::Gemini),
+        "openai" => Ok(AgentType::OpenAi),
         _ => anyhow::bail!(
-            "Unknown agent: '{}'. Supported agents: claude, codex, gemini",
+            "Unknown agent: '{}'. Supported agents: claude, codex, gemini, openai",
             name
         ),
     }
@@ -316,10 +524,73 @@ mod tests {
     #[test]
     fn parse_agent_type_gemini() {
         assert_eq!(parse_agent_type("gemini").unwrap(), AgentType::Gemini);
+        assert_eq!(parse_agent_type("openai").unwrap(), AgentType::OpenAi);
     }
 
     #[test]
     fn parse_agent_type_unknown() {
         assert!(parse_agent_type("unknown").is_err());
     }
+
+    #[test]
+    fn format_content_dump_lists_segments() {
+        use agr::analyzer::{AnalysisSegment, ExtractionStats};
+
+        let content = AnalysisContent {
+            segments: vec![
+                AnalysisSegment {
+                    start_time: 0.0,
+                    end_time: 65.5,
+                    content: "$ cargo build".to_string(),
+                    estimated_tokens: 4,
+                    event_range: (0, 3),
+                },
+                AnalysisSegment {
+                    start_time: 65.5,
+                    end_time: 70.0,
+                    content: "error[E0308]\n".to_string(),
+                    estimated_tokens: 3,
+                    event_range: (3, 5),
+                },
+            ],
+            total_duration: 70.0,
+            total_tokens: 7,
+            stats: ExtractionStats::default(),
+        };
+
+        assert_eq!(
+            format_content_dump(&content, false),
+            "2 segment(s), ~7 tokens, 01:10.00 duration\n\
+             \nSegment 1 [00:00.00 - 01:05.50] ~4 tokens\n$ cargo build\n\
+             \nSegment 2 [01:05.50 - 01:10.00] ~3 tokens\nerror[E0308]\n"
+        );
+        assert_eq!(
+            format_content_dump(&content, true),
+            "2 segment(s), ~7 tokens, 01:10.00 duration\n\
+             Segment 1 [00:00.00 - 01:05.50] ~4 tokens\n\
+             Segment 2 [01:05.50 - 01:10.00] ~3 tokens\n"
+        );
+    }
+
+    #[test]
+    fn render_report_strips_category_prefix() {
+        use agr::analyzer::MarkerCategory;
+
+        let markers = vec![ValidatedMarker::new(
+            65.0,
+            "[SUCCESS] Build passed".to_string(),
+            MarkerCategory::Success,
+        )];
+
+        let csv = render_report(OutputFormat::Csv, &markers).unwrap();
+        assert_eq!(
+            csv,
+            "timestamp,category,label\n01:05,success,Build passed\n"
+        );
+
+        let json = render_report(OutputFormat::Json, &markers).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["markers"][0]["label"], "Build passed");
+        assert_eq!(value["markers"][0]["timestamp"], 65.0);
+    }
 }
@@ -2,7 +2,7 @@
 
 use std::io::IsTerminal;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use std::io::{self, BufRead, Write};
 
 use agr::storage::{SessionInfo, StorageStats};
@@ -19,19 +19,25 @@ use super::truncate_string;
 /// When stdout is a TTY, shows an interactive file explorer with multi-select.
 /// When piped, shows a text-based prompt interface (fallback).
 /// Supports filtering by agent and age threshold.
+///
+/// With `dry_run` or `yes` nothing is prompted: matching sessions are listed,
+/// and deleted only if `yes` is set and `dry_run` is not.
 #[cfg(not(tarpaulin_include))]
-pub fn handle(agent_filter: Option<&str>, older_than: Option<u32>) -> Result<()> {
+pub fn handle(
+    agent_filter: Option<&str>,
+    older_than: Option<u32>,
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    // Refuse to wipe every recording from an unattended run
+    if yes && !dry_run && agent_filter.is_none() && older_than.is_none() {
+        bail!("--yes needs --agent or --older-than to select what to delete");
+    }
+
     let config = Config::load()?;
     let age_threshold = config.storage.age_threshold_days;
     let storage = StorageManager::new(config);
-
-    // Get sessions, optionally filtered by agent
-    let mut sessions = storage.list_sessions(agent_filter)?;
-
-    // Apply older_than filter if specified
-    if let Some(days) = older_than {
-        sessions.retain(|s| s.age_days > days as i64);
-    }
+    let sessions = select_sessions(&storage, agent_filter, older_than)?;
 
     if sessions.is_empty() {
         let theme = current_theme();
@@ -46,21 +52,101 @@ pub fn handle(agent_filter: Option<&str>, older_than: Option<u32>) -> Result<()>
         return Ok(());
     }
 
+    if dry_run || yes {
+        return handle_batch(&sessions, dry_run, &storage);
+    }
+
     // Check if we're in a TTY - if so, use interactive TUI
     if std::io::stdout().is_terminal() {
-        handle_tui(sessions, agent_filter)
+        handle_tui(sessions, agent_filter, storage)
     } else {
         handle_text(sessions, agent_filter, older_than, age_threshold, storage)
     }
 }
 
+/// Sessions matching the agent and age filters, oldest first.
+fn select_sessions(
+    storage: &StorageManager,
+    agent_filter: Option<&str>,
+    older_than: Option<u32>,
+) -> Result<Vec<SessionInfo>> {
+    // Get sessions, optionally filtered by agent
+    let mut sessions = storage.list_sessions(agent_filter)?;
+
+    // Apply older_than filter if specified
+    if let Some(days) = older_than {
+        sessions.retain(|s| s.age_days > days as i64);
+    }
+    Ok(sessions)
+}
+
+/// Handle cleanup without prompting (`--dry-run` / `--yes`).
+///
+/// Lists every matching session with its footprint (recording plus backup
+/// and sidecar files), then deletes them unless this is a dry run.
+fn handle_batch(sessions: &[SessionInfo], dry_run: bool, storage: &StorageManager) -> Result<()> {
+    let theme = current_theme();
+    let reclaimable: u64 = sessions
+        .iter()
+        .map(|s| StorageManager::recording_footprint(&s.path))
+        .sum();
+
+    let verb = if dry_run { "Would delete" } else { "Deleting" };
+    println!(
+        "{}",
+        theme.primary_text(&format!(
+            "{} {} sessions ({}):",
+            verb,
+            sessions.len(),
+            humansize::format_size(reclaimable, humansize::BINARY)
+        ))
+    );
+    for session in sessions {
+        println!(
+            "{}",
+            theme.primary_text(&format!(
+                "  {}/{} ({}, {})",
+                session.agent,
+                session.filename,
+                session.format_age().trim(),
+                session.size_human()
+            ))
+        );
+    }
+
+    if dry_run {
+        println!(
+            "{}",
+            theme.primary_text(
+                "Dry run: nothing was deleted. Pass --yes without --dry-run to delete."
+            )
+        );
+        return Ok(());
+    }
+
+    let freed = storage.delete_sessions(sessions)?;
+    println!(
+        "{}",
+        theme.primary_text(&format!(
+            "Deleted {} sessions (freed {}).",
+            sessions.len(),
+            humansize::format_size(freed, humansize::BINARY)
+        ))
+    );
+    Ok(())
+}
+
 /// Handle cleanup command with interactive TUI.
-fn handle_tui(sessions: Vec<SessionInfo>, agent_filter: Option<&str>) -> Result<()> {
+fn handle_tui(
+    sessions: Vec<SessionInfo>,
+    agent_filter: Option<&str>,
+    storage: StorageManager,
+) -> Result<()> {
     // Convert sessions to FileItems
     let items: Vec<FileItem> = sessions.into_iter().map(FileItem::from).collect();
 
     // Create and run the cleanup app
-    let mut app = CleanupApp::new(items)?;
+    let mut app = CleanupApp::new(items, storage)?;
 
     // If agent filter was specified on command line, it's already applied
     // (sessions were filtered before being passed to this function)
@@ -5,9 +5,9 @@ use std::fs;
 use std::io::{self, BufRead, Write};
 use std::path::Path;
 
-use agr::config::migrate_config;
-use agr::theme::ansi;
+use agr::config::{format_value, get_value, migrate_config, set_value};
 use agr::theme::current_theme;
+use agr::theme::{ansi, paint};
 use agr::Config;
 
 /// Show current configuration as TOML with inline documentation comments.
@@ -58,6 +58,53 @@ pub fn handle_edit() -> Result<()> {
     Ok(())
 }
 
+/// Load the config and run all validation checks.
+///
+/// Prints "Config OK" on success; otherwise returns the first error so the
+/// process exits non-zero.
+pub fn handle_validate() -> Result<()> {
+    let config = Config::load()?;
+    config
+        .validate()
+        .map_err(|e| anyhow::anyhow!("Invalid config: {}", e))?;
+    let theme = current_theme();
+    println!("{}", theme.success_text("Config OK"));
+    Ok(())
+}
+
+/// Print the effective value of a single dotted config key.
+///
+/// Unset optional fields print nothing.
+pub fn handle_get(key: &str) -> Result<()> {
+    let config = Config::load()?;
+    if let Some(value) = get_value(&config, key)? {
+        println!("{}", format_value(&value));
+    }
+    Ok(())
+}
+
+/// Set a single dotted config key, preserving the rest of the file.
+pub fn handle_set(key: &str, raw: &str) -> Result<()> {
+    let theme = current_theme();
+    let config_path = Config::config_path()?;
+
+    let content = if config_path.exists() {
+        fs::read_to_string(&config_path)?
+    } else {
+        String::new()
+    };
+
+    let updated = set_value(&content, key, raw)?;
+
+    if let Some(parent) = config_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    atomic_write(&config_path, &updated)?;
+    println!("{}", theme.success_text(&format!("Set {} = {}", key, raw)));
+
+    Ok(())
+}
+
 /// Migrate config file to the latest schema version.
 ///
 /// Reads the existing config file (or empty if it doesn't exist),
@@ -137,7 +184,7 @@ pub fn handle_migrate(auto_confirm: bool) -> Result<()> {
             ))
         );
         for field in &result.removed_fields {
-            println!("{}  - {}{}", ansi::RED, field, ansi::RESET);
+            println!("{}  - {}{}", paint(ansi::RED), field, paint(ansi::RESET));
         }
     }
 
@@ -241,6 +288,10 @@ pub fn handle_reset(auto_confirm: bool) -> Result<()> {
 /// Shows lines that contain added fields with a green `+` prefix.
 /// For new files, shows all content as additions.
 fn print_diff_preview(new_content: &str, added_fields: &[String], is_new_file: bool) {
+    // Raw codes are blanked when color is disabled (--color never, NO_COLOR)
+    let green = paint(ansi::GREEN);
+    let reset = paint(ansi::RESET);
+
     // Build a set of full field paths (section.key) for accurate matching
     let added_field_set: std::collections::HashSet<&str> =
         added_fields.iter().map(|s| s.as_str()).collect();
@@ -282,11 +333,11 @@ fn print_diff_preview(new_content: &str, added_fields: &[String], is_new_file: b
             if is_new_file || is_added {
                 // Print pending section header if we have one
                 if let Some(header) = pending_section_header.take() {
-                    println!("{}+{} {}{}", ansi::GREEN, ansi::RESET, ansi::GREEN, header);
+                    println!("{}+{} {}{}", green, reset, green, header);
                 }
 
                 // Print added line with green + prefix
-                println!("{}+ {}{}", ansi::GREEN, line, ansi::RESET);
+                println!("{}+ {}{}", green, line, reset);
             } else if section_has_additions {
                 // Show context lines in the section (without + prefix)
                 // Only show the section header once we know there are additions
@@ -298,9 +349,9 @@ fn print_diff_preview(new_content: &str, added_fields: &[String], is_new_file: b
         } else if is_new_file && !trimmed.is_empty() {
             // For new files, show comments too
             if let Some(header) = pending_section_header.take() {
-                println!("{}+{} {}{}", ansi::GREEN, ansi::RESET, ansi::GREEN, header);
+                println!("{}+{} {}{}", green, reset, green, header);
             }
-            println!("{}+ {}{}", ansi::GREEN, line, ansi::RESET);
+            println!("{}+ {}{}", green, line, reset);
         }
     }
 }
@@ -0,0 +1,130 @@
+//! Diff command handler
+//!
+//! Compares two recordings through their plain-text transcripts.
+
+use anyhow::{Context, Result};
+use similar::TextDiff;
+
+use agr::asciicast::integrity::check_file_integrity;
+use agr::asciicast::AsciicastFile;
+use agr::files::resolve::resolve_file_path;
+use agr::theme::current_theme;
+use agr::{Config, MarkerManager};
+
+/// Lines of unchanged context shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+/// Print a unified diff between two recordings.
+///
+/// Compares the rendered transcripts, or with `markers_only` just the
+/// marker lists. Both files are only read.
+#[cfg(not(tarpaulin_include))]
+pub fn handle(old: &str, new: &str, markers_only: bool) -> Result<()> {
+    let config = Config::load()?;
+    let old_cast = load_cast(old, &config)?;
+    let new_cast = load_cast(new, &config)?;
+
+    let (old_text, new_text) = if markers_only {
+        (marker_text(&old_cast)?, marker_text(&new_cast)?)
+    } else {
+        (transcript_text(&old_cast), transcript_text(&new_cast))
+    };
+
+    let theme = current_theme();
+    let diff = unified_diff(&old_text, &new_text, old, new);
+    if diff.is_empty() {
+        println!("{}", theme.primary_text("No differences."));
+        return Ok(());
+    }
+
+    for line in diff.lines() {
+        let styled = if line.starts_with("+++") || line.starts_with("---") {
+            theme.primary_text(line)
+        } else if line.starts_with("@@") {
+            theme.accent_text(line)
+        } else if line.starts_with('+') {
+            theme.success_text(line)
+        } else if line.starts_with('-') {
+            theme.error_text(line)
+        } else {
+            line.to_string()
+        };
+        println!("{}", styled);
+    }
+    Ok(())
+}
+
+/// Resolve, check and parse one recording.
+fn load_cast(file: &str, config: &Config) -> Result<AsciicastFile> {
+    // Resolve file path (supports short format like "claude/session.cast")
+    let filepath = resolve_file_path(file, config)?;
+    if !filepath.exists() {
+        anyhow::bail!(
+            "File not found: {}\nHint: Use format 'agent/file.cast'. Run 'agr list' to see available sessions.",
+            file
+        );
+    }
+    check_file_integrity(&filepath)?;
+    AsciicastFile::parse(&filepath)
+        .with_context(|| format!("Failed to parse asciicast file: {}", filepath.display()))
+}
+
+/// The recording's transcript, newline-terminated for line diffing.
+fn transcript_text(cast: &AsciicastFile) -> String {
+    terminate_lines(cast.to_transcript())
+}
+
+/// One `<seconds>s: <label>` line per marker, in recording order.
+fn marker_text(cast: &AsciicastFile) -> Result<String> {
+    let lines: Vec<String> = MarkerManager::list_markers_from_cast(cast)?
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    Ok(terminate_lines(lines.join("\n")))
+}
+
+fn terminate_lines(mut text: String) -> String {
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text
+}
+
+/// Unified diff of two texts, empty when they are equal.
+fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    let diff = TextDiff::from_lines(old, new);
+    if diff.ratio() == 1.0 {
+        return String::new();
+    }
+    diff.unified_diff()
+        .context_radius(CONTEXT_LINES)
+        .header(old_name, new_name)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unified_diff_of_equal_texts_is_empty() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "x", "y"), "");
+        assert_eq!(unified_diff("", "", "x", "y"), "");
+    }
+
+    #[test]
+    fn unified_diff_marks_changed_lines() {
+        let diff = unified_diff("a\nb\nc\n", "a\nB\nc\n", "old.cast", "new.cast");
+        assert!(diff.starts_with("--- old.cast\n+++ new.cast\n"));
+        assert!(diff.contains("\n-b\n+B\n"));
+    }
+
+    #[test]
+    fn marker_text_lists_markers_in_order() {
+        let cast = AsciicastFile::parse_str(
+            "{\"version\":3,\"term\":{\"cols\":80,\"rows\":24}}\n[0.5,\"m\",\"one\"]\n[1.0,\"m\",\"two\"]\n",
+        )
+        .unwrap();
+        assert_eq!(marker_text(&cast).unwrap(), "0.5s: one\n1.5s: two\n");
+    }
+}
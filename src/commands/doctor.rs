@@ -0,0 +1,113 @@
+//! Doctor command handler
+//!
+//! Checks every analysis agent before a batch run: is it installed, and does
+//! it answer a trivial prompt (which also confirms it is logged in).
+
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+
+use agr::analyzer::{check_health, AgentHealth, AgentType};
+use agr::Config;
+
+use super::analyze::parse_agent_type;
+
+/// Check all analysis agents and print a summary table.
+///
+/// Agents are probed in parallel, each with its configured analyze args.
+/// Fails if the configured (or default) analysis agent is unusable.
+#[cfg(not(tarpaulin_include))]
+pub fn handle(timeout: u64) -> Result<()> {
+    let config = Config::load()?;
+    let default_name = config.resolve_analysis_agent();
+    let default_agent = parse_agent_type(&default_name)?;
+    let timeout = Duration::from_secs(timeout);
+
+    eprintln!(
+        "Checking analysis agents (timeout {}s)...",
+        timeout.as_secs()
+    );
+    let results: Vec<(AgentType, AgentHealth)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = AgentType::ALL
+            .iter()
+            .map(|&agent| {
+                let extra_args = config
+                    .analysis_agent_config(agent.command_name())
+                    .map(|ac| ac.effective_analyze_args().to_vec())
+                    .unwrap_or_default();
+                scope.spawn(move || {
+                    let backend = agent.create_backend(extra_args);
+                    (agent, check_health(backend.as_ref(), timeout))
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("health check thread panicked"))
+            .collect()
+    });
+
+    print!("{}", format_health_table(&results, default_agent));
+
+    match results.iter().find(|(agent, _)| *agent == default_agent) {
+        Some((_, AgentHealth::Ready)) => Ok(()),
+        Some((_, AgentHealth::Unavailable)) => {
+            bail!("Analysis agent '{}' is not installed", default_name)
+        }
+        Some((_, AgentHealth::NoResponse(reason))) => bail!(
+            "Analysis agent '{}' did not respond: {}",
+            default_name,
+            reason
+        ),
+        None => bail!("Analysis agent '{}' was not checked", default_name),
+    }
+}
+
+/// Render one row per agent: name, installed, answered the test prompt.
+///
+/// The default agent is flagged; failure reasons follow on their own line.
+fn format_health_table(results: &[(AgentType, AgentHealth)], default_agent: AgentType) -> String {
+    let mut out = format!("{:<18}{:<11}{}\n", "AGENT", "AVAILABLE", "RESPONDS");
+    for (agent, health) in results {
+        let name = if *agent == default_agent {
+            format!("{} (default)", agent.command_name())
+        } else {
+            agent.command_name().to_string()
+        };
+        let (available, responds) = match health {
+            AgentHealth::Unavailable => ("no", "-"),
+            AgentHealth::NoResponse(_) => ("yes", "no"),
+            AgentHealth::Ready => ("yes", "yes"),
+        };
+        out.push_str(&format!("{:<18}{:<11}{}\n", name, available, responds));
+        if let AgentHealth::NoResponse(reason) = health {
+            out.push_str(&format!("  {}\n", reason));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_health_table_lists_each_agent() {
+        let results = vec![
+            (AgentType::Claude, AgentHealth::Ready),
+            (AgentType::Codex, AgentHealth::Unavailable),
+            (
+                AgentType::Gemini,
+                AgentHealth::NoResponse("Exit code 1: not logged in".to_string()),
+            ),
+        ];
+        assert_eq!(
+            format_health_table(&results, AgentType::Claude),
+            "AGENT             AVAILABLE  RESPONDS\n\
+             claude (default)  yes        yes\n\
+             codex             no         -\n\
+             gemini            yes        no\n\
+             \x20 Exit code 1: not logged in\n"
+        );
+    }
+}
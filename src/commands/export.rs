@@ -0,0 +1,112 @@
+//! Export command handler
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+
+use agr::cli::ExportFormat;
+use agr::files::resolve::resolve_file_path;
+use agr::Config;
+
+/// Default output path: the recording's path with the format's extension.
+pub fn default_output_path(input: &Path, format: ExportFormat) -> PathBuf {
+    match format {
+        ExportFormat::Gif => input.with_extension("gif"),
+    }
+}
+
+/// Export a recording to `format`.
+///
+/// Writes next to the recording unless `output` is given.
+pub fn handle(file: &str, format: ExportFormat, fps: u32, output: Option<&str>) -> Result<()> {
+    let config = Config::load()?;
+
+    // Resolve file path (supports short format like "claude/session.cast")
+    let filepath = resolve_file_path(file, &config)?;
+    if !filepath.exists() {
+        bail!(
+            "File not found: {}\nHint: Use format 'agent/file.cast'. Run 'agr list' to see available sessions.",
+            file
+        );
+    }
+
+    let output_path = match output {
+        Some(path) => PathBuf::from(path),
+        None => default_output_path(&filepath, format),
+    };
+
+    match format {
+        ExportFormat::Gif => export_gif(&filepath, fps, &output_path),
+    }
+}
+
+#[cfg(feature = "gif-export")]
+fn export_gif(input: &Path, fps: u32, output: &Path) -> Result<()> {
+    use std::fs::{self, File};
+    use std::io::BufWriter;
+
+    use anyhow::Context;
+
+    use agr::asciicast::integrity::check_file_integrity;
+    use agr::theme::current_theme;
+    use agr::AsciicastFile;
+
+    let theme = current_theme();
+
+    check_file_integrity(input)?;
+    let cast = AsciicastFile::parse(input)
+        .with_context(|| format!("Failed to parse asciicast file: {}", input.display()))?;
+
+    // Encode into a temp file and rename it into place, so a failed export
+    // never leaves a truncated GIF behind
+    let mut temp_path = output.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = PathBuf::from(temp_path);
+
+    let result = File::create(&temp_path)
+        .with_context(|| format!("Failed to create output file: {}", temp_path.display()))
+        .and_then(|file| {
+            agr::export::export_gif(&cast, fps, BufWriter::new(file))
+                .with_context(|| format!("Failed to export {}", input.display()))
+        })
+        .and_then(|stats| {
+            fs::rename(&temp_path, output)
+                .with_context(|| format!("Failed to write output file: {}", output.display()))?;
+            Ok(stats)
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    let stats = result?;
+
+    println!(
+        "{}",
+        theme.primary_text(&format!(
+            "Exported {} frames ({}x{} px, {:.1}s) to {}",
+            stats.frames,
+            stats.width,
+            stats.height,
+            stats.duration,
+            output.display()
+        ))
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "gif-export"))]
+fn export_gif(_input: &Path, _fps: u32, _output: &Path) -> Result<()> {
+    bail!(
+        "GIF export is not included in this build.\nHint: Reinstall with 'cargo install agr --features gif-export'."
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_output_replaces_cast_extension() {
+        let path = default_output_path(Path::new("/tmp/claude/session.cast"), ExportFormat::Gif);
+        assert_eq!(path, PathBuf::from("/tmp/claude/session.gif"));
+    }
+}
@@ -0,0 +1,216 @@
+//! Info command handler
+//!
+//! Prints an at-a-glance summary of a recording without playing it.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+use agr::analyzer::detect_agent;
+use agr::asciicast::integrity::check_file_integrity;
+use agr::asciicast::{AsciicastFile, EventType};
+use agr::files::resolve::resolve_file_path;
+use agr::files::stdin::{is_stdin, read_stdin};
+use agr::util::format_duration;
+use agr::Config;
+
+/// Summary of a recording, as printed by `agr info`.
+#[derive(Debug, Serialize)]
+struct CastInfo {
+    file: String,
+    size: u64,
+    cols: u32,
+    rows: u32,
+    title: Option<String>,
+    command: Option<String>,
+    /// Agent detected from the header or output
+    agent: Option<String>,
+    timestamp: Option<i64>,
+    /// Seconds, computed from the event times
+    duration: f64,
+    events: EventCounts,
+    markers: usize,
+}
+
+/// Number of events of each type.
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct EventCounts {
+    total: usize,
+    output: usize,
+    input: usize,
+    marker: usize,
+    resize: usize,
+    exit: usize,
+    other: usize,
+}
+
+impl EventCounts {
+    fn from_cast(cast: &AsciicastFile) -> Self {
+        let mut counts = Self {
+            total: cast.events.len(),
+            ..Self::default()
+        };
+        for (event_type, n) in cast.event_count_by_type() {
+            let count = match event_type {
+                EventType::Output => &mut counts.output,
+                EventType::Input => &mut counts.input,
+                EventType::Marker => &mut counts.marker,
+                EventType::Resize => &mut counts.resize,
+                EventType::Exit => &mut counts.exit,
+                EventType::Other(_) => &mut counts.other,
+            };
+            *count += n;
+        }
+        counts
+    }
+}
+
+impl CastInfo {
+    fn new(file: &str, size: u64, cast: &AsciicastFile) -> Self {
+        let (cols, rows) = cast.terminal_size();
+        Self {
+            file: file.to_string(),
+            size,
+            cols,
+            rows,
+            title: cast.header.title.clone(),
+            command: cast.header.command.clone(),
+            agent: detect_agent(cast),
+            timestamp: cast.header.timestamp,
+            duration: cast.duration(),
+            events: EventCounts::from_cast(cast),
+            markers: cast.marker_count(),
+        }
+    }
+}
+
+/// Print a summary of a recording.
+///
+/// `-` reads the recording from standard input; its size is the number of
+/// bytes read.
+#[cfg(not(tarpaulin_include))]
+pub fn handle(file: &str, json: bool) -> Result<()> {
+    let info = if is_stdin(file) {
+        let content = read_stdin()?;
+        let cast = AsciicastFile::parse_str(&content)
+            .context("Failed to parse asciicast from standard input")?;
+        CastInfo::new(file, content.len() as u64, &cast)
+    } else {
+        let config = Config::load()?;
+        // Resolve file path (supports short format like "claude/session.cast")
+        let filepath = resolve_file_path(file, &config)?;
+        if !filepath.exists() {
+            anyhow::bail!(
+                "File not found: {}\nHint: Use format 'agent/file.cast'. Run 'agr list' to see available sessions.",
+                file
+            );
+        }
+        check_file_integrity(&filepath)?;
+        let cast = AsciicastFile::parse(&filepath)
+            .with_context(|| format!("Failed to parse asciicast file: {}", filepath.display()))?;
+        let size = std::fs::metadata(&filepath)
+            .with_context(|| format!("Failed to read file metadata: {}", filepath.display()))?
+            .len();
+        CastInfo::new(file, size, &cast)
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+    } else {
+        print!("{}", format_info(&info));
+    }
+    Ok(())
+}
+
+/// Render the human-readable summary, one labelled field per line.
+fn format_info(info: &CastInfo) -> String {
+    let recorded = info
+        .timestamp
+        .and_then(|ts| DateTime::from_timestamp(ts, 0))
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        });
+    let fields = [
+        ("File", info.file.clone()),
+        ("Size", humansize::format_size(info.size, humansize::BINARY)),
+        ("Terminal", format!("{}x{}", info.cols, info.rows)),
+        ("Title", info.title.clone().unwrap_or_else(|| "-".into())),
+        (
+            "Command",
+            info.command.clone().unwrap_or_else(|| "-".into()),
+        ),
+        ("Agent", info.agent.clone().unwrap_or_else(|| "-".into())),
+        ("Recorded", recorded.unwrap_or_else(|| "-".into())),
+        (
+            "Duration",
+            format!("{} ({:.1}s)", format_duration(info.duration), info.duration),
+        ),
+        ("Events", format_event_counts(&info.events)),
+        ("Markers", info.markers.to_string()),
+    ];
+
+    fields
+        .iter()
+        .map(|(label, value)| format!("{:<10}{}\n", format!("{}:", label), value))
+        .collect()
+}
+
+/// Render the event total followed by the non-zero counts per type.
+fn format_event_counts(counts: &EventCounts) -> String {
+    let parts: Vec<String> = [
+        (counts.output, "output"),
+        (counts.input, "input"),
+        (counts.marker, "marker"),
+        (counts.resize, "resize"),
+        (counts.exit, "exit"),
+        (counts.other, "other"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, name)| format!("{} {}", count, name))
+    .collect();
+
+    if parts.is_empty() {
+        counts.total.to_string()
+    } else {
+        format!("{} ({})", counts.total, parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agr::asciicast::{Event, Header};
+
+    #[test]
+    fn event_counts_group_by_type() {
+        let mut cast = AsciicastFile::new(Header::v3(80, 24));
+        cast.events = vec![
+            Event::output(0.5, "$ ls\r\n"),
+            Event::output(0.1, "file\r\n"),
+            Event::marker(0.1, "listed"),
+            Event::new(0.1, EventType::Resize, "100x30"),
+            Event::new(0.1, EventType::Other('z'), "?"),
+        ];
+
+        let counts = EventCounts::from_cast(&cast);
+
+        assert_eq!(
+            counts,
+            EventCounts {
+                total: 5,
+                output: 2,
+                marker: 1,
+                resize: 1,
+                other: 1,
+                ..EventCounts::default()
+            }
+        );
+        assert_eq!(
+            format_event_counts(&counts),
+            "5 (2 output, 1 marker, 1 resize, 1 other)"
+        );
+    }
+}
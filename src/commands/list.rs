@@ -3,25 +3,67 @@
 use std::io::IsTerminal;
 
 use anyhow::Result;
+use serde::Serialize;
 
 use agr::theme::current_theme;
 use agr::tui::app::TuiApp;
-use agr::tui::widgets::FileItem;
+use agr::tui::widgets::{FileExplorer, FileItem, SortDirection, SortField};
 use agr::tui::ListApp;
 use agr::{Config, StorageManager};
 
 use super::truncate_string;
 
+/// One session in `agr ls --json`, mirroring the explorer's [`FileItem`].
+#[derive(Debug, Serialize)]
+struct ListEntry<'a> {
+    path: &'a str,
+    name: &'a str,
+    agent: &'a str,
+    size: u64,
+    /// RFC 3339 timestamp in local time
+    modified: String,
+    has_backup: bool,
+}
+
+impl<'a> From<&'a FileItem> for ListEntry<'a> {
+    fn from(item: &'a FileItem) -> Self {
+        Self {
+            path: &item.path,
+            name: &item.name,
+            agent: &item.agent,
+            size: item.size,
+            modified: item.modified.to_rfc3339(),
+            has_backup: item.has_backup,
+        }
+    }
+}
+
 /// List all recorded sessions with details.
 ///
-/// When stdout is a TTY, shows an interactive file explorer.
-/// When piped, shows a simple text table (fallback).
+/// With `json`, prints the sessions as a JSON array sorted by `sort`
+/// (date by default, descending unless `asc`). Otherwise, when stdout is a
+/// TTY, shows an interactive file explorer; when piped, shows a simple text
+/// table (fallback).
 #[cfg(not(tarpaulin_include))]
-pub fn handle(agent: Option<&str>) -> Result<()> {
+pub fn handle(agent: Option<&str>, json: bool, sort: Option<SortField>, asc: bool) -> Result<()> {
     let config = Config::load()?;
     let storage = StorageManager::new(config);
     let sessions = storage.list_sessions(agent)?;
 
+    if json {
+        let items = sessions.into_iter().map(FileItem::from).collect();
+        let direction = if asc {
+            SortDirection::Ascending
+        } else {
+            SortDirection::Descending
+        };
+        println!(
+            "{}",
+            render_json(items, sort.unwrap_or_default(), direction)?
+        );
+        return Ok(());
+    }
+
     if sessions.is_empty() {
         let theme = current_theme();
         if let Some(agent_name) = agent {
@@ -43,6 +85,17 @@ pub fn handle(agent: Option<&str>) -> Result<()> {
     }
 }
 
+/// Sort `items` the way the explorer does and serialize them as JSON.
+fn render_json(items: Vec<FileItem>, field: SortField, direction: SortDirection) -> Result<String> {
+    let mut explorer = FileExplorer::new(items);
+    explorer.set_sort_order(field, direction);
+    let entries: Vec<ListEntry> = explorer
+        .visible_items()
+        .map(|(_, item, _)| ListEntry::from(item))
+        .collect();
+    Ok(serde_json::to_string_pretty(&entries)?)
+}
+
 /// Handle list command with interactive TUI.
 fn handle_tui(sessions: Vec<agr::storage::SessionInfo>, agent: Option<&str>) -> Result<()> {
     // Convert sessions to FileItems
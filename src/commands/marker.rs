@@ -3,38 +3,55 @@
 use anyhow::Result;
 
 use agr::theme::current_theme;
+use agr::util::format_duration;
 use agr::{Config, MarkerManager};
 
 use agr::asciicast::integrity::check_file_integrity;
 use agr::files::resolve::resolve_file_path;
+use agr::files::stdin::{is_stdin, parse_stdin, reject_stdin};
+
+/// Hint shown when a marker-editing command is given standard input.
+const STDIN_HINT: &str = "Pass a .cast file path instead.";
 
 /// Add a marker to a cast file at a specific timestamp.
 ///
-/// Markers use the native asciicast v3 marker format.
+/// Markers use the native asciicast v3 marker format. The file is backed up
+/// before the first change.
 #[cfg(not(tarpaulin_include))]
 pub fn handle_add(file: &str, time: f64, label: &str) -> Result<()> {
+    reject_stdin(file, "add markers to", STDIN_HINT)?;
     let config = Config::load()?;
     let theme = current_theme();
     // Resolve file path (supports short format like "claude/session.cast")
     let filepath = resolve_file_path(file, &config)?;
     check_file_integrity(&filepath)?;
-    MarkerManager::add_marker(&filepath, time, label)?;
+    MarkerManager::add_marker(&filepath, time, label, config.storage.backup_limit)?;
     println!(
         "{}",
-        theme.primary_text(&format!("Marker added at {:.1}s: \"{}\"", time, label))
+        theme.primary_text(&format!(
+            "Marker added at {}: \"{}\"",
+            format_duration(time),
+            label
+        ))
     );
     Ok(())
 }
 
 /// List all markers in a cast file with their timestamps and labels.
+///
+/// `-` reads the recording from standard input.
 #[cfg(not(tarpaulin_include))]
 pub fn handle_list(file: &str) -> Result<()> {
     let config = Config::load()?;
     let theme = current_theme();
-    // Resolve file path (supports short format like "claude/session.cast")
-    let filepath = resolve_file_path(file, &config)?;
-    check_file_integrity(&filepath)?;
-    let markers = MarkerManager::list_markers(&filepath)?;
+    let markers = if is_stdin(file) {
+        MarkerManager::list_markers_from_cast(&parse_stdin()?)?
+    } else {
+        // Resolve file path (supports short format like "claude/session.cast")
+        let filepath = resolve_file_path(file, &config)?;
+        check_file_integrity(&filepath)?;
+        MarkerManager::list_markers(&filepath)?
+    };
 
     if markers.is_empty() {
         println!("{}", theme.primary_text("No markers found in file."));
@@ -42,9 +59,41 @@ pub fn handle_list(file: &str) -> Result<()> {
     }
 
     println!("{}", theme.primary_text("Markers:"));
-    for marker in markers {
-        println!("{}", theme.primary_text(&format!("  {}", marker)));
+    for (i, marker) in markers.iter().enumerate() {
+        println!(
+            "{}",
+            theme.primary_text(&format!(
+                "  {}  {}  {}",
+                i + 1,
+                format_duration(marker.timestamp),
+                marker.label
+            ))
+        );
     }
 
     Ok(())
 }
+
+/// Remove a marker by its 1-based number from `agr markers list`.
+///
+/// The file is backed up before the first change.
+#[cfg(not(tarpaulin_include))]
+pub fn handle_remove(file: &str, number: u64) -> Result<()> {
+    reject_stdin(file, "remove markers from", STDIN_HINT)?;
+    let config = Config::load()?;
+    let theme = current_theme();
+    // Resolve file path (supports short format like "claude/session.cast")
+    let filepath = resolve_file_path(file, &config)?;
+    check_file_integrity(&filepath)?;
+    let index = usize::try_from(number.saturating_sub(1)).unwrap_or(usize::MAX);
+    let removed = MarkerManager::remove_marker(&filepath, index, config.storage.backup_limit)?;
+    println!(
+        "{}",
+        theme.primary_text(&format!(
+            "Marker removed at {}: \"{}\"",
+            format_duration(removed.timestamp),
+            removed.label
+        ))
+    );
+    Ok(())
+}
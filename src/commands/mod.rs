@@ -9,13 +9,19 @@ pub mod cleanup;
 pub mod completions;
 pub mod config;
 pub mod copy;
+pub mod diff;
+pub mod doctor;
+pub mod export;
+pub mod info;
 pub mod list;
 pub mod marker;
 pub mod play;
 pub mod record;
 pub mod shell;
 pub mod status;
+pub mod storage;
 pub mod transform;
+pub mod validate;
 
 /// Truncate a string to a maximum length, adding ellipsis if needed.
 pub fn truncate_string(s: &str, max_len: usize) -> String {
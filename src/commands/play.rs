@@ -2,18 +2,34 @@
 
 use anyhow::Result;
 
-use agr::{play_session, Config};
+use agr::player::play_session_native;
+use agr::player::render::{set_color_depth, ColorDepth};
+use agr::Config;
 
 use agr::asciicast::integrity::check_file_integrity;
 use agr::files::resolve::resolve_file_path;
+use agr::files::stdin::reject_stdin;
 
 /// Play a recording file using the native player.
 ///
 /// Resolves the file path and invokes the native player for playback.
 /// Supports absolute paths, short format (agent/file.cast), and fuzzy matching.
+/// With `resume`, playback continues from the position saved on the last quit.
+/// Playback starts from the `[player]` config section; `skip_idle`, `speed`
+/// and `loop_playback` given on the command line override it (`Some(false)`
+/// turns off looping enabled in the config).
 #[cfg(not(tarpaulin_include))]
-pub fn handle(file: &str) -> Result<()> {
+pub fn handle(
+    file: &str,
+    resume: bool,
+    skip_idle: Option<f64>,
+    speed: Option<f64>,
+    loop_playback: Option<bool>,
+) -> Result<()> {
+    // The player needs standard input for keyboard controls
+    reject_stdin(file, "play", "Pass a .cast file path instead.")?;
     let config = Config::load()?;
+    set_color_depth(ColorDepth::detect_from_env(&config.player.color_depth));
 
     // Resolve file path (supports short format like "claude/session.cast")
     let filepath = resolve_file_path(file, &config)?;
@@ -33,7 +49,18 @@ pub fn handle(file: &str) -> Result<()> {
     check_file_integrity(&filepath)?;
 
     // Play the session using the native player
-    let result = play_session(&filepath)?;
+    let mut player = config.player;
+    if skip_idle.is_some() {
+        player.skip_idle = skip_idle;
+    }
+    if let Some(speed) = speed {
+        player.speed = speed;
+    }
+    if let Some(loop_playback) = loop_playback {
+        player.loop_playback = loop_playback;
+    }
+
+    let result = play_session_native(&filepath, resume, &player)?;
     println!("{}", result.message());
     Ok(())
 }
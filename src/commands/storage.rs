@@ -0,0 +1,104 @@
+//! Storage command handler
+
+use anyhow::Result;
+use humansize::{format_size, BINARY};
+
+use agr::storage::{UsageBucket, UsageReport};
+use agr::{Config, StorageManager};
+
+/// Report disk usage of recordings by agent and by month.
+///
+/// Sizes include backups and sidecar files. `top` limits the list of
+/// largest recordings.
+#[cfg(not(tarpaulin_include))]
+pub fn handle(top: usize, json: bool) -> Result<()> {
+    let config = Config::load()?;
+    let storage = StorageManager::new(config);
+    let report = storage.usage_report(top)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", format_usage_report(&report));
+    }
+    Ok(())
+}
+
+/// Render the usage report as aligned sections.
+fn format_usage_report(report: &UsageReport) -> String {
+    let mut out = format!(
+        "Total: {} in {} sessions ({} backups and sidecars)\n",
+        format_size(report.total_size, BINARY),
+        report.session_count,
+        format_size(report.associated_size, BINARY)
+    );
+    if report.session_count == 0 {
+        return out;
+    }
+
+    out.push_str("\nBy agent:\n");
+    push_buckets(&mut out, &report.by_agent);
+    out.push_str("\nBy month:\n");
+    push_buckets(&mut out, &report.by_month);
+
+    out.push_str("\nLargest recordings:\n");
+    for file in &report.largest {
+        out.push_str(&format!(
+            "  {:>10}  {}\n",
+            format_size(file.size, BINARY),
+            file.path
+        ));
+    }
+    out
+}
+
+fn push_buckets(out: &mut String, buckets: &[UsageBucket]) {
+    for bucket in buckets {
+        out.push_str(&format!(
+            "  {:<12} {:>5}  {:>10}\n",
+            bucket.name,
+            bucket.sessions,
+            format_size(bucket.size, BINARY)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agr::storage::UsageFile;
+
+    #[test]
+    fn format_usage_report_lists_each_section() {
+        let report = UsageReport {
+            total_size: 3072,
+            associated_size: 1024,
+            session_count: 2,
+            by_agent: vec![UsageBucket {
+                name: "claude".to_string(),
+                sessions: 2,
+                size: 3072,
+            }],
+            by_month: vec![UsageBucket {
+                name: "2026-10".to_string(),
+                sessions: 2,
+                size: 3072,
+            }],
+            largest: vec![UsageFile {
+                path: "claude/big.cast".to_string(),
+                size: 2048,
+            }],
+        };
+
+        assert_eq!(
+            format_usage_report(&report),
+            "Total: 3 KiB in 2 sessions (1 KiB backups and sidecars)\n\
+             \n\
+             By agent:\n  claude           2       3 KiB\n\
+             \n\
+             By month:\n  2026-10          2       3 KiB\n\
+             \n\
+             Largest recordings:\n       2 KiB  claude/big.cast\n"
+        );
+    }
+}
@@ -1,18 +1,23 @@
 //! Transform command handler for asciicast file transformations.
 //!
 //! Provides CLI support for applying transforms to asciicast recordings,
-//! such as silence removal.
+//! such as trimming, silence removal and secret redaction.
 
 use std::path::PathBuf;
 
 use anyhow::{bail, Context, Result};
 
-use agr::asciicast::{AsciicastFile, SilenceRemoval, Transform, DEFAULT_SILENCE_THRESHOLD};
+use agr::asciicast::{
+    AsciicastFile, DedupeResizes, RedactSecrets, ScaleTime, Transform, DEFAULT_SILENCE_THRESHOLD,
+};
 use agr::theme::current_theme;
+use agr::util::format_duration;
 use agr::Config;
 
 use agr::asciicast::integrity::check_file_integrity;
+use agr::files::backup::{backup_path_for, create_backup};
 use agr::files::resolve::resolve_file_path;
+use agr::files::stdin::{is_stdin, parse_stdin, reject_stdin};
 
 /// Resolve the threshold to use for silence removal.
 ///
@@ -49,72 +54,152 @@ pub fn validate_threshold(threshold: f64) -> Result<()> {
     Ok(())
 }
 
-/// Handle the transform command with silence removal.
+/// Validate that a speed factor is usable for [`ScaleTime`].
 ///
-/// Applies silence removal transform to the specified file, either modifying
-/// it in-place or writing to a separate output file.
-#[cfg(not(tarpaulin_include))]
-pub fn handle_remove_silence(
-    file: &str,
-    threshold: Option<f64>,
-    output: Option<&str>,
-) -> Result<()> {
-    let config = Config::load()?;
-    let theme = current_theme();
-
-    // Resolve file path (supports short format like "claude/session.cast")
-    let filepath = resolve_file_path(file, &config)?;
-    if !filepath.exists() {
-        bail!(
-            "File not found: {}\nHint: Use format 'agent/file.cast'. Run 'agr list' to see available sessions.",
-            file
-        );
+/// Returns an error if the factor is zero, negative, NaN or infinite.
+pub fn validate_speed(factor: f64) -> Result<()> {
+    if !(factor > 0.0 && factor.is_finite()) {
+        bail!("Speed factor must be a positive number (got: {})", factor);
     }
+    Ok(())
+}
 
-    // Check file has .cast extension
-    if filepath.extension().and_then(|e| e.to_str()) != Some("cast") {
-        eprintln!("Warning: File does not have .cast extension");
-    }
+/// What `agr optimize` should do to a recording.
+#[derive(Debug, Clone, Default)]
+pub struct OptimizeOptions<'a> {
+    /// Cap pauses longer than the silence threshold
+    pub remove_silence: bool,
+    /// Silence threshold from the CLI (falls back to the header or default)
+    pub threshold: Option<f64>,
+    /// Playback speed factor
+    pub speed: Option<f64>,
+    /// Redact secrets
+    pub redact: bool,
+    /// Drop resize events that do not change the size
+    pub dedupe_resizes: bool,
+    /// Trim start (seconds on the original timeline)
+    pub from: Option<f64>,
+    /// Trim end (seconds on the original timeline)
+    pub to: Option<f64>,
+    /// Write here instead of modifying the file in place
+    pub output: Option<&'a str>,
+}
 
-    // Check for file corruption before transforming
-    check_file_integrity(&filepath)?;
+/// Handle the optimize command.
+///
+/// Applies trimming, secret redaction, resize deduplication, silence removal
+/// and/or a speed change to the specified file, either modifying it in-place
+/// (after backing it up) or writing to a separate output file. Standard input
+/// (`-`) is accepted when `--output` is given. Trimming runs first, so `from`
+/// and `to` refer to the original timeline, and silence removal runs before
+/// the speed change so its threshold applies to the original timings.
+#[cfg(not(tarpaulin_include))]
+pub fn handle_optimize(file: &str, options: &OptimizeOptions) -> Result<()> {
+    let OptimizeOptions {
+        remove_silence,
+        threshold,
+        speed,
+        redact,
+        dedupe_resizes,
+        from,
+        to,
+        output,
+    } = *options;
+
+    // Validate the explicit speed and trim range before touching any files
+    if let Some(factor) = speed {
+        validate_speed(factor)?;
+    }
+    if let (Some(start), Some(end)) = (from, to) {
+        validate_trim_range(start, end)?;
+    }
 
-    // Parse the file
-    let mut cast = AsciicastFile::parse(&filepath)
-        .with_context(|| format!("Failed to parse asciicast file: {}", filepath.display()))?;
+    // Standard input has no file to modify in place
+    if output.is_none() {
+        reject_stdin(
+            file,
+            "optimize",
+            "Use --output to write the result to a file.",
+        )?;
+    }
 
-    // Resolve threshold: CLI arg > header idle_time_limit > default
-    let effective_threshold = resolve_threshold(threshold, cast.header.idle_time_limit);
+    let config = Config::load()?;
+    let theme = current_theme();
 
-    // Validate threshold before any modifications
-    validate_threshold(effective_threshold)?;
+    // Compile the redaction patterns before touching any files
+    let redactor = if redact {
+        Some(
+            RedactSecrets::new(&config.redact.patterns)
+                .context("Invalid pattern in [redact] patterns")?,
+        )
+    } else {
+        None
+    };
 
-    // Report which threshold source is being used
-    let threshold_source = if threshold.is_some() {
-        "CLI argument"
-    } else if cast.header.idle_time_limit.is_some() {
-        "header idle_time_limit"
+    let (mut cast, filepath) = if is_stdin(file) {
+        (parse_stdin()?, PathBuf::from(file))
     } else {
-        "default"
+        // Resolve file path (supports short format like "claude/session.cast")
+        let filepath = resolve_file_path(file, &config)?;
+        if !filepath.exists() {
+            bail!(
+                "File not found: {}\nHint: Use format 'agent/file.cast'. Run 'agr list' to see available sessions.",
+                file
+            );
+        }
+
+        // Check file has .cast extension
+        if filepath.extension().and_then(|e| e.to_str()) != Some("cast") {
+            eprintln!("Warning: File does not have .cast extension");
+        }
+
+        // Check for file corruption before transforming
+        check_file_integrity(&filepath)?;
+
+        // Parse the file
+        let cast = AsciicastFile::parse(&filepath)
+            .with_context(|| format!("Failed to parse asciicast file: {}", filepath.display()))?;
+        (cast, filepath)
     };
 
-    println!(
-        "{}",
-        theme.primary_text(&format!(
-            "Applying silence removal with {:.2}s threshold (from {})",
-            effective_threshold, threshold_source
-        ))
-    );
+    if from.is_some() || to.is_some() {
+        trim_reporting(&mut cast, from, to)?;
+    }
 
-    // Calculate original duration for reporting
     let original_duration = cast.duration();
+    if let Some(mut redactor) = redactor {
+        redactor.transform(&mut cast.events);
+        println!(
+            "{}",
+            theme.primary_text(&format!("Redacted {} secret(s)", redactor.redactions()))
+        );
+    }
 
-    // Apply the transform
-    let mut transform = SilenceRemoval::new(effective_threshold);
-    transform.transform(&mut cast.events);
+    if dedupe_resizes {
+        let mut deduper = DedupeResizes::new();
+        deduper.transform(&mut cast.events);
+        println!(
+            "{}",
+            theme.primary_text(&format!(
+                "Removed {} redundant resize event(s)",
+                deduper.removed()
+            ))
+        );
+    }
 
-    // Calculate new duration
-    let new_duration = cast.duration();
+    let mut cast = if remove_silence {
+        remove_silence_reporting(cast, threshold)?
+    } else {
+        cast
+    };
+
+    if let Some(factor) = speed {
+        println!(
+            "{}",
+            theme.primary_text(&format!("Applying {}x playback speed", factor))
+        );
+        ScaleTime::new(factor).apply(&mut cast);
+    }
 
     // Determine output path
     let output_path: PathBuf = match output {
@@ -122,24 +207,51 @@ pub fn handle_remove_silence(
         None => filepath.clone(),
     };
 
+    // Every in-place edit can be undone from the backup, taken just before
+    // the write so failed runs do not use up a backup generation
+    if output.is_none() && create_backup(&filepath, config.storage.backup_limit)? {
+        println!(
+            "{}",
+            theme.primary_text(&format!(
+                "Backup saved to {}",
+                backup_path_for(&filepath).display()
+            ))
+        );
+    }
+
     // Write the result
     cast.write(&output_path)
         .with_context(|| format!("Failed to write output file: {}", output_path.display()))?;
 
     // Report results
-    let time_saved = original_duration - new_duration;
-    if time_saved > 0.0 {
+    let new_duration = cast.duration();
+    if !remove_silence && speed.is_none() {
+        // Trimming was reported above; redaction and resize deduplication
+        // leave the timing unchanged
+    } else if (original_duration - new_duration).abs() < f64::EPSILON {
+        let reason = if remove_silence {
+            "No changes needed (all intervals below threshold)"
+        } else {
+            "No changes needed (1x speed leaves the timing unchanged)"
+        };
+        println!("{}", theme.primary_text(reason));
+    } else if new_duration < original_duration {
         println!(
             "{}",
             theme.primary_text(&format!(
                 "Duration reduced from {:.1}s to {:.1}s (saved {:.1}s)",
-                original_duration, new_duration, time_saved
+                original_duration,
+                new_duration,
+                original_duration - new_duration
             ))
         );
     } else {
         println!(
             "{}",
-            theme.primary_text("No changes needed (all intervals below threshold)")
+            theme.primary_text(&format!(
+                "Duration increased from {:.1}s to {:.1}s",
+                original_duration, new_duration
+            ))
         );
     }
 
@@ -155,6 +267,89 @@ pub fn handle_remove_silence(
     Ok(())
 }
 
+/// Reject a trim range that ends before it starts.
+pub fn validate_trim_range(from: f64, to: f64) -> Result<()> {
+    if from >= to {
+        bail!(
+            "--from ({}) must be before --to ({})",
+            format_duration(from),
+            format_duration(to)
+        );
+    }
+    Ok(())
+}
+
+/// Trim `cast` to `from..=to`, clamping times past the end with a warning.
+fn trim_reporting(cast: &mut AsciicastFile, from: Option<f64>, to: Option<f64>) -> Result<()> {
+    let theme = current_theme();
+    let duration = cast.duration();
+
+    let clamp = |flag: &str, time: f64| {
+        if time > duration {
+            eprintln!(
+                "Warning: {} {} is past the end of the recording ({}), using the end",
+                flag,
+                format_duration(time),
+                format_duration(duration)
+            );
+            duration
+        } else {
+            time
+        }
+    };
+    let start = from.map_or(0.0, |t| clamp("--from", t));
+    let end = to.map_or(duration, |t| clamp("--to", t));
+    if from.is_some() && start >= end {
+        bail!(
+            "Nothing left to keep: --from {} is at or past the end of the recording",
+            format_duration(start)
+        );
+    }
+
+    cast.trim(start, end);
+    println!(
+        "{}",
+        theme.primary_text(&format!(
+            "Trimmed to {}-{} ({:.1}s)",
+            format_duration(start),
+            format_duration(end),
+            cast.duration()
+        ))
+    );
+    Ok(())
+}
+
+/// Remove silence from `cast`, reporting which threshold is used.
+fn remove_silence_reporting(cast: AsciicastFile, threshold: Option<f64>) -> Result<AsciicastFile> {
+    let theme = current_theme();
+
+    // Resolve threshold: CLI arg > header idle_time_limit > default
+    let effective_threshold = resolve_threshold(threshold, cast.header.idle_time_limit);
+
+    // Validate threshold before any modifications
+    validate_threshold(effective_threshold)?;
+
+    // Report which threshold source is being used
+    let threshold_source = if threshold.is_some() {
+        "CLI argument"
+    } else if cast.header.idle_time_limit.is_some() {
+        "header idle_time_limit"
+    } else {
+        "default"
+    };
+
+    println!(
+        "{}",
+        theme.primary_text(&format!(
+            "Applying silence removal with {:.2}s threshold (from {})",
+            effective_threshold, threshold_source
+        ))
+    );
+
+    let (cast, _) = cast.remove_silence(effective_threshold);
+    Ok(cast)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +435,34 @@ mod tests {
         assert!(result.is_err());
         // Could be rejected as negative or non-finite, both are valid
     }
+
+    // ========================================================================
+    // Speed Validation Tests
+    // ========================================================================
+
+    #[test]
+    fn validate_speed_accepts_positive_factors() {
+        assert!(validate_speed(2.0).is_ok());
+        assert!(validate_speed(0.5).is_ok());
+    }
+
+    #[test]
+    fn validate_speed_rejects_non_positive_and_non_finite() {
+        for factor in [0.0, -2.0, f64::NAN, f64::INFINITY] {
+            let err = validate_speed(factor).unwrap_err().to_string();
+            assert!(err.contains("positive"), "{}: {}", factor, err);
+        }
+    }
+
+    // ========================================================================
+    // Trim Range Validation Tests
+    // ========================================================================
+
+    #[test]
+    fn validate_trim_range_requires_from_before_to() {
+        assert!(validate_trim_range(10.0, 90.0).is_ok());
+        let err = validate_trim_range(90.0, 90.0).unwrap_err().to_string();
+        assert!(err.contains("must be before --to (01:30)"), "{}", err);
+        assert!(validate_trim_range(120.0, 30.0).is_err());
+    }
 }
@@ -0,0 +1,57 @@
+//! Validate command handler
+
+use anyhow::{bail, Result};
+
+use agr::asciicast::{validate_file, validate_str, Severity};
+use agr::files::resolve::resolve_file_path;
+use agr::files::stdin::{is_stdin, read_stdin};
+use agr::theme::current_theme;
+use agr::Config;
+
+/// Validate each recording and print a per-file result.
+///
+/// `-` reads a recording from standard input. Fails if any file has errors;
+/// warnings are printed but do not fail.
+pub fn handle(files: &[String], strict: bool) -> Result<()> {
+    let config = Config::load()?;
+    let theme = current_theme();
+    let mut failed = 0;
+
+    for file in files {
+        let report = if is_stdin(file) {
+            validate_str(&read_stdin()?, strict)
+        } else {
+            // Resolve file path (supports short format like "claude/session.cast")
+            let filepath = resolve_file_path(file, &config)?;
+            validate_file(&filepath, strict)
+        };
+
+        if report.is_ok() {
+            println!("{}", theme.success_text(&format!("OK    {}", file)));
+        } else {
+            failed += 1;
+            println!("{}", theme.error_text(&format!("FAIL  {}", file)));
+        }
+        for issue in &report.issues {
+            let prefix = match issue.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            };
+            println!(
+                "{}",
+                theme.secondary_text(&format!("      {}: {}", prefix, issue))
+            );
+        }
+        if let Some((recovered, total)) = report.recovered {
+            println!(
+                "{}",
+                theme.secondary_text(&format!("      recovered {}/{} events", recovered, total))
+            );
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} of {} files failed validation", failed, files.len());
+    }
+    Ok(())
+}
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Analysis configuration for the `analyze` command.
 ///
@@ -12,7 +13,7 @@ use std::collections::HashMap;
 /// to override. CLI flags take priority over config, which overrides defaults.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisConfig {
-    /// Preferred agent for analysis ("claude", "codex", "gemini")
+    /// Preferred agent for analysis ("claude", "codex", "gemini", "openai")
     #[serde(default = "default_analysis_agent")]
     pub agent: Option<String>,
     /// Number of parallel workers (None = auto-scale)
@@ -27,6 +28,18 @@ pub struct AnalysisConfig {
     /// Auto-curate markers when count exceeds threshold
     #[serde(default = "default_analysis_curate")]
     pub curate: Option<bool>,
+    /// Custom analysis prompt template file (must contain `{content}`)
+    #[serde(default)]
+    pub prompt_template: Option<PathBuf>,
+    /// Attempts per chunk for transient failures, including the first
+    #[serde(default = "default_analysis_retry_attempts")]
+    pub retry_attempts: Option<usize>,
+    /// Initial retry backoff in milliseconds (doubles each attempt)
+    #[serde(default = "default_analysis_retry_delay_ms")]
+    pub retry_delay_ms: Option<u64>,
+    /// Maximum retry backoff in milliseconds
+    #[serde(default = "default_analysis_retry_max_delay_ms")]
+    pub retry_max_delay_ms: Option<u64>,
 }
 
 pub fn default_analysis_agent() -> Option<String> {
@@ -45,6 +58,18 @@ pub fn default_analysis_curate() -> Option<bool> {
     Some(true)
 }
 
+pub fn default_analysis_retry_attempts() -> Option<usize> {
+    Some(3)
+}
+
+pub fn default_analysis_retry_delay_ms() -> Option<u64> {
+    Some(1000)
+}
+
+pub fn default_analysis_retry_max_delay_ms() -> Option<u64> {
+    Some(60000)
+}
+
 impl Default for AnalysisConfig {
     fn default() -> Self {
         Self {
@@ -53,6 +78,10 @@ impl Default for AnalysisConfig {
             timeout: default_analysis_timeout(),
             fast: default_analysis_fast(),
             curate: default_analysis_curate(),
+            prompt_template: None,
+            retry_attempts: default_analysis_retry_attempts(),
+            retry_delay_ms: default_analysis_retry_delay_ms(),
+            retry_max_delay_ms: default_analysis_retry_max_delay_ms(),
         }
     }
 }
@@ -64,7 +93,7 @@ impl AnalysisConfig {
     /// or an error describing the first invalid value found.
     pub fn validate(&self) -> Result<(), String> {
         if let Some(ref agent) = self.agent {
-            let valid = ["claude", "codex", "gemini"];
+            let valid = ["claude", "codex", "gemini", "openai"];
             if !valid.contains(&agent.as_str()) {
                 return Err(format!(
                     "Unknown agent '{}'. Valid: {}",
@@ -89,9 +118,59 @@ impl AnalysisConfig {
                 return Err(format!("analysis.workers {} exceeds maximum (32)", w));
             }
         }
+        if let Some(0) = self.retry_attempts {
+            return Err("analysis.retry_attempts must be > 0".to_string());
+        }
+        if let Some(a) = self.retry_attempts {
+            if a > 10 {
+                return Err(format!(
+                    "analysis.retry_attempts {} exceeds maximum (10)",
+                    a
+                ));
+            }
+        }
+        if let (Some(delay), Some(max)) = (self.retry_delay_ms, self.retry_max_delay_ms) {
+            if delay > max {
+                return Err(format!(
+                    "analysis.retry_delay_ms {} exceeds analysis.retry_max_delay_ms {}",
+                    delay, max
+                ));
+            }
+        }
+        self.load_prompt_template()?;
         Ok(())
     }
 
+    /// Read the custom prompt template, if one is configured.
+    ///
+    /// A leading `~/` is expanded to the home directory. Returns an error if
+    /// the file cannot be read or has neither a `{content}` nor a
+    /// `{cleaned_content}` placeholder.
+    pub fn load_prompt_template(&self) -> Result<Option<String>, String> {
+        match self.prompt_template {
+            Some(ref path) => read_prompt_template(path, "analysis.prompt_template").map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the prompt template that applies to one agent.
+    ///
+    /// The agent's own `prompt_template` wins over the global one; without
+    /// either the built-in prompt is used (`Ok(None)`).
+    pub fn load_prompt_template_for(
+        &self,
+        agent_name: &str,
+        agent_config: Option<&AgentAnalysisConfig>,
+    ) -> Result<Option<String>, String> {
+        match agent_config.and_then(|ac| ac.prompt_template.as_ref()) {
+            Some(path) => {
+                let key = format!("agents.{}.prompt_template", agent_name);
+                read_prompt_template(path, &key).map(Some)
+            }
+            None => self.load_prompt_template(),
+        }
+    }
+
     /// Validate per-agent configs (called from Config level where agents are accessible).
     pub fn validate_agent_configs(
         &self,
@@ -106,14 +185,40 @@ impl AnalysisConfig {
                     ));
                 }
             }
+            if let Some(ref path) = agent_config.prompt_template {
+                read_prompt_template(path, &format!("agents.{}.prompt_template", name))?;
+            }
         }
         Ok(())
     }
 }
 
+/// Read a prompt template file, naming the config `key` in any error.
+///
+/// A leading `~/` is expanded to the home directory. The template must
+/// contain the `{content}` placeholder, or `{cleaned_content}` as used by
+/// the built-in prompt.
+fn read_prompt_template(path: &Path, key: &str) -> Result<String, String> {
+    let path = match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(stripped), Some(home)) => home.join(stripped),
+        _ => path.to_path_buf(),
+    };
+    let template = std::fs::read_to_string(&path)
+        .map_err(|e| format!("{} {} could not be read: {}", key, path.display(), e))?;
+    if !template.contains("{content}") && !template.contains("{cleaned_content}") {
+        return Err(format!(
+            "{} {} must contain the {{content}} or {{cleaned_content}} placeholder",
+            key,
+            path.display()
+        ));
+    }
+    Ok(template)
+}
+
 /// Per-agent analysis configuration.
 ///
-/// Allows customizing extra CLI arguments and token budgets for individual agents.
+/// Allows customizing extra CLI arguments, token budgets and the analysis
+/// prompt for individual agents.
 /// Each task type (analyze, curate, rename) can override the global `extra_args`.
 ///
 /// ```toml
@@ -122,6 +227,7 @@ impl AnalysisConfig {
 /// analyze_extra_args = ["--model", "gpt-5.2-codex"]        # override for analysis
 /// curate_extra_args = ["--model", "gpt-5.1-codex-mini"]    # override for curation
 /// rename_extra_args = ["--model", "gpt-5.1-codex-mini"]    # override for rename
+/// prompt_template = "~/.config/agr/codex-prompt.txt"       # override analysis.prompt_template
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AgentAnalysisConfig {
@@ -140,6 +246,9 @@ pub struct AgentAnalysisConfig {
     /// Override the token budget for this agent
     #[serde(default)]
     pub token_budget: Option<usize>,
+    /// Analysis prompt template file for this agent (overrides analysis.prompt_template)
+    #[serde(default)]
+    pub prompt_template: Option<PathBuf>,
 }
 
 impl AgentAnalysisConfig {
@@ -56,6 +56,11 @@ pub const CONFIG_SECTIONS: &[SectionDoc] = &[
                 description: "Age threshold in days for cleanup suggestions",
                 default_display: "30",
             },
+            FieldDoc {
+                name: "backup_limit",
+                description: "Backups kept per recording (.bak, .bak.1, ...); 1 keeps only the pre-first-change original",
+                default_display: "1",
+            },
         ],
     },
     SectionDoc {
@@ -69,7 +74,7 @@ pub const CONFIG_SECTIONS: &[SectionDoc] = &[
             },
             FieldDoc {
                 name: "filename_template",
-                description: "Filename template using {directory}, {date}, {time} tags",
+                description: "Filename template using {directory}, {date}, {time}, {agent}, {hostname}, {counter} tags",
                 default_display: "{directory}_{date}_{time}",
             },
             FieldDoc {
@@ -85,7 +90,7 @@ pub const CONFIG_SECTIONS: &[SectionDoc] = &[
         fields: &[
             FieldDoc {
                 name: "agent",
-                description: "Preferred agent for analysis (claude, codex, gemini)",
+                description: "Preferred agent for analysis (claude, codex, gemini, openai)",
                 default_display: "auto-detect",
             },
             FieldDoc {
@@ -108,6 +113,26 @@ pub const CONFIG_SECTIONS: &[SectionDoc] = &[
                 description: "Auto-curate markers when count exceeds threshold",
                 default_display: "true",
             },
+            FieldDoc {
+                name: "prompt_template",
+                description: "Custom analysis prompt file ({content} or {cleaned_content}, {duration}, {segments})",
+                default_display: "built-in",
+            },
+            FieldDoc {
+                name: "retry_attempts",
+                description: "Attempts per chunk on rate limits, timeouts and outages",
+                default_display: "3",
+            },
+            FieldDoc {
+                name: "retry_delay_ms",
+                description: "Initial retry backoff in milliseconds (doubles each attempt)",
+                default_display: "1000",
+            },
+            FieldDoc {
+                name: "retry_max_delay_ms",
+                description: "Maximum retry backoff in milliseconds",
+                default_display: "60000",
+            },
         ],
     },
     SectionDoc {
@@ -126,6 +151,67 @@ pub const CONFIG_SECTIONS: &[SectionDoc] = &[
             },
         ],
     },
+    SectionDoc {
+        name: "tui",
+        description: "Interactive TUI preferences",
+        fields: &[
+            FieldDoc {
+                name: "sort_field",
+                description: "File explorer sort field (name, date, size, agent)",
+                default_display: "date",
+            },
+            FieldDoc {
+                name: "sort_direction",
+                description: "File explorer sort direction (asc, desc)",
+                default_display: "desc",
+            },
+            FieldDoc {
+                name: "theme",
+                description: "Color theme (claude_code, classic, ocean)",
+                default_display: "claude_code",
+            },
+        ],
+    },
+    SectionDoc {
+        name: "player",
+        description: "Native player defaults for `agr play`; CLI flags take precedence, and speed, viewport mode and looping changed in the player are saved on quit",
+        fields: &[
+            FieldDoc {
+                name: "speed",
+                description: "Initial playback speed multiplier (0.25 to 16)",
+                default_display: "1.0",
+            },
+            FieldDoc {
+                name: "viewport_mode",
+                description: "Start in viewport mode, where arrow keys scroll instead of seeking",
+                default_display: "false",
+            },
+            FieldDoc {
+                name: "loop_playback",
+                description: "Restart from the beginning when playback reaches the end",
+                default_display: "false",
+            },
+            FieldDoc {
+                name: "skip_idle",
+                description: "Cut pauses longer than this many seconds, like `agr play --skip-idle`",
+                default_display: "off",
+            },
+            FieldDoc {
+                name: "color_depth",
+                description: "Color depth for RGB colors (auto, truecolor, 256); auto uses 256 colors only in terminals known to lack truecolor",
+                default_display: "auto",
+            },
+        ],
+    },
+    SectionDoc {
+        name: "redact",
+        description: "Secret redaction for `agr optimize --redact`",
+        fields: &[FieldDoc {
+            name: "patterns",
+            description: "Extra regular expressions to redact on top of the built-in AWS key, sk- token, bearer and email patterns",
+            default_display: "[]",
+        }],
+    },
 ];
 
 /// Per-agent sub-section fields (applies to [agents.claude], [agents.codex], etc.)
@@ -155,6 +241,11 @@ pub const AGENT_FIELDS: &[FieldDoc] = &[
         description: "Override the token budget for this agent",
         default_display: "auto",
     },
+    FieldDoc {
+        name: "prompt_template",
+        description: "Analysis prompt file for this agent (overrides analysis.prompt_template)",
+        default_display: "inherit",
+    },
 ];
 
 /// Insert commented-out template lines for optional fields that are absent.
@@ -198,6 +289,7 @@ pub fn insert_optional_field_templates(toml_str: &str) -> String {
         .chain(std::iter::once(("agents.claude", AGENT_FIELDS)))
         .chain(std::iter::once(("agents.codex", AGENT_FIELDS)))
         .chain(std::iter::once(("agents.gemini", AGENT_FIELDS)))
+        .chain(std::iter::once(("agents.openai", AGENT_FIELDS)))
         .collect();
 
     // Process sections in reverse so line insertions don't shift indices
@@ -267,11 +359,12 @@ pub fn annotate_config(toml_str: &str) -> String {
             lookup.insert((section.name, field.name), field.description);
         }
     }
-    // Per-agent fields apply to agents.claude, agents.codex, agents.gemini
+    // Per-agent fields apply to agents.claude, agents.codex, agents.gemini, agents.openai
     for field in AGENT_FIELDS {
         lookup.insert(("agents.claude", field.name), field.description);
         lookup.insert(("agents.codex", field.name), field.description);
         lookup.insert(("agents.gemini", field.name), field.description);
+        lookup.insert(("agents.openai", field.name), field.description);
     }
 
     let mut result = String::new();
@@ -368,6 +461,9 @@ pub fn generate_config_markdown() -> String {
     );
     md.push_str("| `{time}` | Time in HHMM format | `1430` |\n");
     md.push_str("| `{time:FORMAT}` | Time with custom strftime | `{time:%H:%M}` → `14:30` |\n");
+    md.push_str("| `{agent}` | Agent being recorded | `claude` |\n");
+    md.push_str("| `{hostname}` | Machine hostname | `workstation` |\n");
+    md.push_str("| `{counter}` | Next free number in the agent's folder | `3` |\n");
     md.push('\n');
 
     md.push_str("### Example Templates\n\n");
@@ -378,7 +474,9 @@ pub fn generate_config_markdown() -> String {
     md.push_str("# ISO date: project_2026-01-29.cast\n");
     md.push_str("filename_template = \"{directory}_{date:%Y-%m-%d}\"\n\n");
     md.push_str("# Simple timestamp: 260129-143022.cast\n");
-    md.push_str("filename_template = \"{date:%y%m%d}-{time:%H%M%S}\"\n");
+    md.push_str("filename_template = \"{date:%y%m%d}-{time:%H%M%S}\"\n\n");
+    md.push_str("# Numbered per day: project_260129_3.cast\n");
+    md.push_str("filename_template = \"{directory}_{date}_{counter}\"\n");
     md.push_str("```\n\n");
 
     md.push_str("### Sanitization\n\n");
@@ -469,7 +567,16 @@ mod tests {
         let names: Vec<&str> = CONFIG_SECTIONS.iter().map(|s| s.name).collect();
         assert_eq!(
             names,
-            vec!["shell", "storage", "recording", "analysis", "agents"]
+            vec![
+                "shell",
+                "storage",
+                "recording",
+                "analysis",
+                "agents",
+                "tui",
+                "player",
+                "redact"
+            ]
         );
     }
 }
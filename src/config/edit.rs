@@ -0,0 +1,361 @@
+//! Single-value config access for `agr config get` / `agr config set`.
+//!
+//! Keys are dotted paths such as `analysis.timeout` or
+//! `agents.claude.token_budget`. Only documented fields (see
+//! [`super::docs`]) are accepted, so typos fail loudly instead of
+//! writing keys the config loader silently ignores. The same path syntax
+//! backs `AGR_*` environment overrides.
+
+use anyhow::{bail, Context, Result};
+use toml_edit::{DocumentMut, Item, Table};
+
+use super::docs::{AGENT_FIELDS, CONFIG_SECTIONS};
+use super::{AgentsConfig, Config};
+use crate::theme::{Theme, THEME_NAMES};
+
+/// Prefix for environment variable overrides.
+const ENV_PREFIX: &str = "AGR_";
+
+/// Check whether `key` names a documented config field.
+pub fn is_known_key(key: &str) -> bool {
+    let parts: Vec<&str> = key.split('.').collect();
+    match parts.as_slice() {
+        [section, field] => CONFIG_SECTIONS
+            .iter()
+            .any(|s| s.name == *section && s.fields.iter().any(|f| f.name == *field)),
+        ["agents", agent, field] => {
+            // Agents with a per-agent `[agents.<name>]` sub-section
+            AgentsConfig::default().agent_config(agent).is_some()
+                && AGENT_FIELDS.iter().any(|f| f.name == *field)
+        }
+        _ => false,
+    }
+}
+
+/// Look up the effective value of `key` in `config`.
+///
+/// Returns `Ok(None)` for known fields that are unset (e.g. `analysis.workers`
+/// when auto-scaling).
+pub fn get_value(config: &Config, key: &str) -> Result<Option<toml::Value>> {
+    ensure_known_key(key)?;
+    let root = toml::Value::try_from(config).context("Failed to serialize config")?;
+    let mut current = &root;
+    for part in key.split('.') {
+        match current.get(part) {
+            Some(next) => current = next,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(current.clone()))
+}
+
+/// Format a value for `agr config get`: strings print bare, everything else
+/// as TOML.
+pub fn format_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Set `key` to `raw` in the TOML `content`, returning the updated text.
+///
+/// The value is parsed as a TOML literal (`300`, `true`, `["a", "b"]`) and
+/// falls back to a plain string, except for string fields where the raw text
+/// is always kept verbatim. The result must deserialize into [`Config`] and
+/// pass [`Config::validate`]. Comments, formatting and unrelated keys are
+/// preserved.
+pub fn set_value(content: &str, key: &str, raw: &str) -> Result<String> {
+    // The loader falls back to the default theme, so catch typos here
+    if key == "tui.theme" && Theme::by_name(raw).is_none() {
+        bail!(
+            "Unknown theme '{}'. Available themes: {}",
+            raw,
+            THEME_NAMES.join(", ")
+        );
+    }
+
+    let mut doc: DocumentMut = content
+        .parse()
+        .context("Failed to parse existing config as TOML")?;
+    insert_value(&mut doc, key, raw)?;
+
+    let updated = doc.to_string();
+    let config: Config = toml::from_str(&updated)
+        .map_err(|e| anyhow::anyhow!("Invalid value '{}' for {}: {}", raw, key, e.message()))?;
+    config
+        .validate()
+        .map_err(|e| anyhow::anyhow!("Invalid config: {}", e))?;
+
+    Ok(updated)
+}
+
+/// Set several keys in the TOML `content` with [`set_value`], in order.
+///
+/// Used to write preferences back without losing the user's comments.
+pub fn set_values(content: &str, values: &[(&str, &str)]) -> Result<String> {
+    values
+        .iter()
+        .try_fold(content.to_string(), |content, (key, raw)| {
+            set_value(&content, key, raw)
+        })
+}
+
+/// Apply `AGR_<SECTION>__<FIELD>` environment overrides to the TOML `content`.
+///
+/// Double underscores separate path segments and names are case-insensitive,
+/// so `AGR_ANALYSIS__TIMEOUT=60` sets `analysis.timeout` and
+/// `AGR_AGENTS__CLAUDE__TOKEN_BUDGET=50000` sets
+/// `agents.claude.token_budget`. Values are parsed like `agr config set`.
+/// Variables naming an unknown key are skipped with a warning. Returns
+/// `Ok(None)` when no usable override variables are present. Validation is
+/// left to the caller.
+pub fn apply_env_overrides<I>(content: &str, vars: I) -> Result<Option<String>>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut overrides: Vec<(String, String, String)> = vars
+        .into_iter()
+        .filter_map(|(name, raw)| {
+            let rest = name.strip_prefix(ENV_PREFIX)?;
+            if !rest.contains("__") {
+                return None;
+            }
+            let key = rest
+                .split("__")
+                .collect::<Vec<_>>()
+                .join(".")
+                .to_lowercase();
+            if !is_known_key(&key) {
+                eprintln!("Warning: ignoring {}: unknown config key '{}'", name, key);
+                return None;
+            }
+            Some((name, key, raw))
+        })
+        .collect();
+    if overrides.is_empty() {
+        return Ok(None);
+    }
+    overrides.sort();
+
+    let mut doc: DocumentMut = content
+        .parse()
+        .context("Failed to parse existing config as TOML")?;
+    for (name, key, raw) in &overrides {
+        insert_value(&mut doc, key, raw).with_context(|| format!("Invalid override {}", name))?;
+    }
+    Ok(Some(doc.to_string()))
+}
+
+/// Write `raw` at `key` in `doc`, creating sections as needed.
+fn insert_value(doc: &mut DocumentMut, key: &str, raw: &str) -> Result<()> {
+    ensure_known_key(key)?;
+
+    let defaults = get_value(&Config::default(), key)?;
+    let mut new_value = match defaults {
+        Some(toml::Value::String(_)) => toml_edit::Value::from(raw),
+        _ => raw
+            .parse::<toml_edit::Value>()
+            .unwrap_or_else(|_| toml_edit::Value::from(raw)),
+    };
+    new_value.decor_mut().clear();
+
+    let parts: Vec<&str> = key.split('.').collect();
+    let (field, sections) = parts.split_last().expect("key has at least one part");
+    let mut table = doc.as_table_mut();
+    for section in sections {
+        let entry = table
+            .entry(section)
+            .or_insert_with(|| Item::Table(Table::new()));
+        table = match entry.as_table_mut() {
+            Some(t) => t,
+            None => bail!("Config entry '{}' is not a table", section),
+        };
+    }
+
+    // Keep trailing comments on the line being replaced
+    match table.get_mut(field).and_then(Item::as_value_mut) {
+        Some(existing) => {
+            let decor = existing.decor().clone();
+            *existing = new_value;
+            *existing.decor_mut() = decor;
+        }
+        None => {
+            table.insert(field, Item::Value(new_value));
+        }
+    }
+    Ok(())
+}
+
+fn ensure_known_key(key: &str) -> Result<()> {
+    if !is_known_key(key) {
+        bail!(
+            "Unknown config key '{}'. Run 'agr config show' to see available keys",
+            key
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_keys_cover_sections_and_agent_fields() {
+        assert!(is_known_key("analysis.timeout"));
+        assert!(is_known_key("tui.theme"));
+        assert!(is_known_key("agents.claude.token_budget"));
+        assert!(!is_known_key("analysis.timeot"));
+        assert!(!is_known_key("agents.unknown.token_budget"));
+        assert!(!is_known_key("analysis"));
+        assert!(!is_known_key(""));
+    }
+
+    #[test]
+    fn get_value_returns_defaults() {
+        let config = Config::default();
+        let timeout = get_value(&config, "analysis.timeout").unwrap().unwrap();
+        assert_eq!(format_value(&timeout), "120");
+        let theme = get_value(&config, "tui.theme").unwrap().unwrap();
+        assert_eq!(format_value(&theme), "claude_code");
+    }
+
+    #[test]
+    fn get_value_unset_optional_is_none() {
+        let config = Config::default();
+        assert!(get_value(&config, "analysis.workers").unwrap().is_none());
+    }
+
+    #[test]
+    fn get_value_rejects_unknown_key() {
+        let err = get_value(&Config::default(), "nope.nothing").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unknown config key 'nope.nothing'"));
+    }
+
+    #[test]
+    fn set_value_preserves_comments_and_other_keys() {
+        let content = "# my config\n[analysis]\ntimeout = 120 # seconds\nfast = true\n";
+        let updated = set_value(content, "analysis.timeout", "300").unwrap();
+        assert_eq!(
+            updated,
+            "# my config\n[analysis]\ntimeout = 300 # seconds\nfast = true\n"
+        );
+    }
+
+    #[test]
+    fn set_values_updates_each_key_and_keeps_comments() {
+        let content = "# my config\n[tui]\ntheme = \"claude_code\" # colors\n";
+        let updated = set_values(
+            content,
+            &[("tui.theme", "classic"), ("tui.sort_direction", "asc")],
+        )
+        .unwrap();
+        assert_eq!(
+            updated,
+            "# my config\n[tui]\ntheme = \"classic\" # colors\nsort_direction = \"asc\"\n"
+        );
+    }
+
+    #[test]
+    fn set_value_creates_missing_sections() {
+        let updated = set_value("", "agents.claude.token_budget", "50000").unwrap();
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert_eq!(config.agents.claude.token_budget, Some(50000));
+    }
+
+    #[test]
+    fn set_value_keeps_string_fields_verbatim() {
+        let updated = set_value("", "storage.directory", "123").unwrap();
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert_eq!(config.storage.directory, "123");
+    }
+
+    #[test]
+    fn set_value_parses_lists() {
+        let updated = set_value("", "agents.enabled", r#"["claude", "codex"]"#).unwrap();
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert_eq!(config.agents.enabled, vec!["claude", "codex"]);
+    }
+
+    #[test]
+    fn set_value_rejects_type_mismatch() {
+        let err = set_value("", "analysis.timeout", "soon").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Invalid value 'soon' for analysis.timeout"));
+    }
+
+    #[test]
+    fn set_value_rejects_unknown_theme() {
+        let err = set_value("", "tui.theme", "neon").unwrap_err();
+        assert!(err.to_string().contains("Unknown theme 'neon'"));
+
+        let updated = set_value("", "tui.theme", "ocean").unwrap();
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert_eq!(config.tui.theme, "ocean");
+    }
+
+    #[test]
+    fn set_value_runs_analysis_validation() {
+        let err = set_value("", "analysis.timeout", "0").unwrap_err();
+        assert!(err.to_string().contains("analysis.timeout must be > 0"));
+    }
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn env_overrides_absent_returns_none() {
+        let vars = env(&[("HOME", "/home/me"), ("AGR_UNRELATED", "1")]);
+        assert!(apply_env_overrides("", vars).unwrap().is_none());
+    }
+
+    #[test]
+    fn env_override_sets_analysis_agent() {
+        let vars = env(&[("AGR_ANALYSIS__AGENT", "claude")]);
+        let updated = apply_env_overrides("[analysis]\nagent = \"codex\"\n", vars)
+            .unwrap()
+            .unwrap();
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert_eq!(config.analysis.agent.as_deref(), Some("claude"));
+        assert_eq!(config.resolve_analysis_agent(), "claude");
+    }
+
+    #[test]
+    fn env_override_parses_typed_values() {
+        let vars = env(&[
+            ("AGR_ANALYSIS__TIMEOUT", "60"),
+            ("AGR_AGENTS__CLAUDE__TOKEN_BUDGET", "50000"),
+        ]);
+        let updated = apply_env_overrides("", vars).unwrap().unwrap();
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert_eq!(config.analysis.timeout, Some(60));
+        assert_eq!(config.agents.claude.token_budget, Some(50000));
+    }
+
+    #[test]
+    fn env_override_unknown_key_is_skipped() {
+        let vars = env(&[("AGR_ANALYSIS__TIMEOT", "60")]);
+        assert!(apply_env_overrides("", vars).unwrap().is_none());
+
+        let vars = env(&[
+            ("AGR_ANALYSIS__TIMEOT", "60"),
+            ("AGR_ANALYSIS__TIMEOUT", "30"),
+        ]);
+        let updated = apply_env_overrides("", vars).unwrap().unwrap();
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert_eq!(config.analysis.timeout, Some(30));
+    }
+
+    #[test]
+    fn set_value_validates_agent_token_budget() {
+        let err = set_value("", "agents.codex.token_budget", "10").unwrap_err();
+        assert!(err.to_string().contains("below minimum"));
+    }
+}
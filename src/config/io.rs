@@ -2,8 +2,10 @@
 
 use anyhow::{Context, Result};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use super::edit::{apply_env_overrides, set_values};
+use super::project::{find_project_config, merge_config, strip_untrusted_keys};
 use super::types::Config;
 
 /// Get the config file path (~/.config/agr/config.toml)
@@ -18,23 +20,71 @@ pub fn config_dir() -> Result<PathBuf> {
     Ok(home.join(".config").join("agr"))
 }
 
-/// Load configuration from file, or return defaults if not found
+/// Load configuration for the current directory with all layers applied
 pub fn load() -> Result<Config> {
-    let config_path = config_path()?;
+    load_layered(std::env::current_dir().ok().as_deref())
+}
+
+/// Load configuration as seen from `dir` with all layers applied
+pub fn load_from(dir: &Path) -> Result<Config> {
+    load_layered(Some(dir))
+}
+
+/// Layer the user file, the nearest project file and `AGR_*` overrides
+fn load_layered(project_dir: Option<&Path>) -> Result<Config> {
+    let (config_path, mut contents) = read_config_file()?;
+    let mut config = parse_config_file(&config_path, &contents)?;
+
+    if let Some(project_path) = project_dir.and_then(find_project_config) {
+        let project = fs::read_to_string(&project_path)
+            .with_context(|| format!("Failed to read config file: {:?}", project_path))?;
+        let (project, ignored) = strip_untrusted_keys(&project)
+            .with_context(|| format!("Failed to parse config file: {:?}", project_path))?;
+        for key in ignored {
+            eprintln!(
+                "Warning: ignoring {} in {:?}: only the user config may set it",
+                key, project_path
+            );
+        }
+        parse_config_file(&project_path, &project)?;
+        contents = merge_config(&contents, &project)?;
+        config = parse_config_file(&project_path, &contents)?;
+    }
 
-    if config_path.exists() {
-        let contents = fs::read_to_string(&config_path)
-            .with_context(|| format!("Failed to read config file: {:?}", config_path))?;
-        let config: Config = toml::from_str(&contents)
-            .with_context(|| format!("Failed to parse config file: {:?}", config_path))?;
-        config
-            .analysis
-            .validate()
-            .map_err(|e| anyhow::anyhow!("Invalid config: {}", e))?;
-        Ok(config)
-    } else {
-        Ok(Config::default())
+    if let Some(overridden) = apply_env_overrides(&contents, std::env::vars())? {
+        config = toml::from_str(&overridden).context("Invalid AGR_* environment override")?;
     }
+    validate(config)
+}
+
+/// Load configuration from file only, or return defaults if not found
+pub fn load_file() -> Result<Config> {
+    let (config_path, contents) = read_config_file()?;
+    validate(parse_config_file(&config_path, &contents)?)
+}
+
+/// Read the config file, or an empty string if it does not exist
+fn read_config_file() -> Result<(PathBuf, String)> {
+    let config_path = config_path()?;
+    if !config_path.exists() {
+        return Ok((config_path, String::new()));
+    }
+    let contents = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read config file: {:?}", config_path))?;
+    Ok((config_path, contents))
+}
+
+fn parse_config_file(config_path: &Path, contents: &str) -> Result<Config> {
+    toml::from_str(contents)
+        .with_context(|| format!("Failed to parse config file: {:?}", config_path))
+}
+
+fn validate(config: Config) -> Result<Config> {
+    config
+        .analysis
+        .validate()
+        .map_err(|e| anyhow::anyhow!("Invalid config: {}", e))?;
+    Ok(config)
 }
 
 /// Save configuration to file
@@ -53,3 +103,18 @@ pub fn save(config: &Config) -> Result<()> {
 
     Ok(())
 }
+
+/// Set dotted keys in the config file, preserving its comments and formatting
+pub fn save_values(values: &[(&str, &str)]) -> Result<()> {
+    let (config_path, contents) = read_config_file()?;
+    let updated = set_values(&contents, values)?;
+
+    if let Some(parent) = config_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create config directory: {:?}", parent))?;
+    }
+    fs::write(&config_path, updated)
+        .with_context(|| format!("Failed to write config file: {:?}", config_path))?;
+
+    Ok(())
+}
@@ -124,6 +124,9 @@ const SECTION_ORDER: &[&str] = &[
     "recording",
     "analysis",
     "agents",
+    "tui",
+    "player",
+    "redact",
 ];
 
 /// Reorder top-level sections to match `SECTION_ORDER`.
@@ -365,12 +368,15 @@ agent = "claude"
     fn empty_input_returns_full_default_config() {
         let result = migrate_config("").unwrap();
 
-        assert_eq!(result.sections_added.len(), 5);
+        assert_eq!(result.sections_added.len(), 8);
         assert!(result.sections_added.contains(&"storage".to_string()));
         assert!(result.sections_added.contains(&"agents".to_string()));
         assert!(result.sections_added.contains(&"shell".to_string()));
         assert!(result.sections_added.contains(&"recording".to_string()));
         assert!(result.sections_added.contains(&"analysis".to_string()));
+        assert!(result.sections_added.contains(&"tui".to_string()));
+        assert!(result.sections_added.contains(&"player".to_string()));
+        assert!(result.sections_added.contains(&"redact".to_string()));
 
         let parsed: Config = toml::from_str(&result.content).unwrap();
         assert_eq!(parsed.storage.directory, "~/recorded_agent_sessions");
@@ -387,7 +393,7 @@ directory = "~/my-recordings"
 
         let result = migrate_config(input).unwrap();
 
-        assert_eq!(result.sections_added.len(), 4);
+        assert_eq!(result.sections_added.len(), 7);
         assert!(result.sections_added.contains(&"agents".to_string()));
         assert!(result.sections_added.contains(&"shell".to_string()));
         assert!(result.sections_added.contains(&"recording".to_string()));
@@ -425,8 +431,11 @@ auto_analyze = true
 
         let result = migrate_config(input).unwrap();
 
-        assert_eq!(result.sections_added.len(), 1);
+        assert_eq!(result.sections_added.len(), 4);
         assert!(result.sections_added.contains(&"analysis".to_string()));
+        assert!(result.sections_added.contains(&"tui".to_string()));
+        assert!(result.sections_added.contains(&"player".to_string()));
+        assert!(result.sections_added.contains(&"redact".to_string()));
 
         assert!(result
             .added_fields
@@ -617,7 +626,7 @@ default_agent = "claude"
     #[test]
     fn whitespace_only_input_treated_as_empty() {
         let result = migrate_config("   \n\n   ").unwrap();
-        assert_eq!(result.sections_added.len(), 5);
+        assert_eq!(result.sections_added.len(), 8);
     }
 
     #[test]
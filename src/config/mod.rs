@@ -2,18 +2,24 @@
 
 pub mod analysis;
 pub mod docs;
+mod edit;
 mod io;
 mod migrate;
+mod project;
 mod types;
 
 pub use analysis::*;
+pub use edit::{apply_env_overrides, format_value, get_value, is_known_key, set_value, set_values};
 pub use migrate::*;
+pub use project::*;
 pub use types::*;
 
 use anyhow::Result;
 use std::path::PathBuf;
 
 use crate::analyzer::backend::command_exists;
+use crate::files::backup::MAX_BACKUP_LIMIT;
+use crate::files::filename::Template;
 
 impl Config {
     /// Get the config file path (~/.config/agr/config.toml)
@@ -26,9 +32,39 @@ impl Config {
         io::config_dir()
     }
 
-    /// Load configuration from file, or return defaults if not found
+    /// Load the effective configuration for the current directory.
+    ///
+    /// Sources are layered with later ones winning:
+    /// 1. Built-in defaults
+    /// 2. `~/.config/agr/config.toml`
+    /// 3. The nearest `.agr.toml` in the current directory or a parent,
+    ///    limited to the keys in [`PROJECT_KEYS`]
+    /// 4. `AGR_<SECTION>__<FIELD>` environment variables
+    ///    (e.g. `AGR_ANALYSIS__TIMEOUT=60`)
+    ///
+    /// Command-line flags are applied by each command on top of the result.
+    /// Use [`Config::load_file`] when the config will be modified and saved,
+    /// so project and environment layers are not written back to disk.
+    ///
+    /// The loaded `tui.theme` becomes the active theme, so every command
+    /// draws with it (unknown names keep the default).
     pub fn load() -> Result<Self> {
-        io::load()
+        let config = io::load()?;
+        crate::theme::set_theme(&config.tui.theme);
+        Ok(config)
+    }
+
+    /// Like [`Config::load`], but discovers `.agr.toml` starting from `dir`.
+    pub fn load_from(dir: &std::path::Path) -> Result<Self> {
+        io::load_from(dir)
+    }
+
+    /// Load configuration from the user config file only, or defaults if not found.
+    ///
+    /// Ignores project files and environment overrides; use this before
+    /// calling [`Config::save`].
+    pub fn load_file() -> Result<Self> {
+        io::load_file()
     }
 
     /// Save configuration to file
@@ -36,6 +72,14 @@ impl Config {
         io::save(self)
     }
 
+    /// Set the given dotted keys in the user config file.
+    ///
+    /// Unlike [`Config::save`], comments and formatting in the file are
+    /// preserved. Values are parsed like `agr config set`.
+    pub fn save_values(values: &[(&str, &str)]) -> Result<()> {
+        io::save_values(values)
+    }
+
     /// Expand ~ in storage directory path
     pub fn storage_directory(&self) -> PathBuf {
         let dir = &self.storage.directory;
@@ -120,6 +164,61 @@ impl Config {
         "claude".to_string()
     }
 
+    /// Run every config check and return the first failure.
+    ///
+    /// Covers analysis limits and the prompt template file, per-agent token
+    /// budgets, the recording filename template, `shell.script_path`, the
+    /// backup limit, and the player speed and idle limit.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        self.analysis.validate()?;
+
+        let agent_configs = self
+            .agents
+            .agent_configs_map()
+            .into_iter()
+            .map(|(name, cfg)| (name, cfg.clone()))
+            .collect();
+        self.analysis.validate_agent_configs(&agent_configs)?;
+
+        Template::parse(&self.recording.filename_template)
+            .map_err(|e| format!("recording.filename_template: {}", e))?;
+
+        if let Some(ref script_path) = self.shell.script_path {
+            if !script_path.exists() {
+                return Err(format!(
+                    "shell.script_path {} does not exist",
+                    script_path.display()
+                ));
+            }
+        }
+
+        let limit = self.storage.backup_limit;
+        if !(1..=MAX_BACKUP_LIMIT).contains(&limit) {
+            return Err(format!(
+                "storage.backup_limit must be between 1 and {}, got {}",
+                MAX_BACKUP_LIMIT, limit
+            ));
+        }
+
+        let speed = self.player.speed;
+        if !(0.25..=16.0).contains(&speed) {
+            return Err(format!(
+                "player.speed must be between 0.25 and 16, got {}",
+                speed
+            ));
+        }
+        if let Some(limit) = self.player.skip_idle {
+            if !limit.is_finite() || limit <= 0.0 {
+                return Err(format!(
+                    "player.skip_idle must be a positive number of seconds, got {}",
+                    limit
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Look up per-agent analysis configuration.
     ///
     /// Returns `None` if the agent name is not recognized.
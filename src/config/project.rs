@@ -0,0 +1,222 @@
+//! Per-project config discovery.
+//!
+//! A `.agr.toml` file in the working directory or any parent is layered over
+//! the user config, so subprojects of a monorepo can use different agents or
+//! analysis settings. The nearest file wins; fields it omits inherit from the
+//! user config.
+//!
+//! A project file comes with the repository, so it is not trusted: it may only
+//! set the keys in [`PROJECT_KEYS`]. Anything that runs commands, reads files
+//! or changes where recordings and backups live stays in the user config.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, TableLike};
+
+/// File name searched for when discovering project config.
+pub const PROJECT_CONFIG_FILE: &str = ".agr.toml";
+
+/// Keys a project file may set; `*` matches any single key segment.
+pub const PROJECT_KEYS: &[&str] = &[
+    "analysis.agent",
+    "analysis.workers",
+    "analysis.timeout",
+    "analysis.fast",
+    "analysis.curate",
+    "analysis.retry_attempts",
+    "analysis.retry_delay_ms",
+    "analysis.retry_max_delay_ms",
+    "agents.enabled",
+    "agents.no_wrap",
+    "agents.*.token_budget",
+    "recording.filename_template",
+    "recording.directory_max_length",
+    "tui.*",
+    "player.*",
+];
+
+/// Find the nearest `.agr.toml`, starting at `start` and walking up.
+pub fn find_project_config(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(PROJECT_CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Remove the keys a project file may not set (see [`PROJECT_KEYS`]).
+///
+/// Returns the remaining TOML and the dotted names of the removed keys.
+pub fn strip_untrusted_keys(project: &str) -> Result<(String, Vec<String>)> {
+    let mut doc: DocumentMut = project
+        .parse()
+        .context("Failed to parse project config as TOML")?;
+    let mut removed = Vec::new();
+    strip_table(doc.as_table_mut(), "", &mut removed);
+    Ok((doc.to_string(), removed))
+}
+
+fn strip_table(table: &mut dyn TableLike, prefix: &str, removed: &mut Vec<String>) {
+    let keys: Vec<String> = table.iter().map(|(key, _)| key.to_string()).collect();
+    for key in keys {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        if is_project_key(&path) {
+            continue;
+        }
+        match table.get_mut(&key).and_then(Item::as_table_like_mut) {
+            Some(nested) => strip_table(nested, &path, removed),
+            None => {
+                table.remove(&key);
+                removed.push(path);
+            }
+        }
+    }
+}
+
+/// Whether a project file may set the dotted key `path`.
+fn is_project_key(path: &str) -> bool {
+    PROJECT_KEYS.iter().any(|pattern| {
+        let mut segments = path.split('.');
+        pattern.split('.').all(|want| {
+            segments
+                .next()
+                .is_some_and(|got| want == "*" || want == got)
+        }) && segments.next().is_none()
+    })
+}
+
+/// Merge the TOML `overlay` over `base`, returning the combined text.
+///
+/// Tables, inline or not, are merged recursively; any other value in
+/// `overlay` replaces the one in `base`, so lists are replaced rather than
+/// concatenated.
+pub fn merge_config(base: &str, overlay: &str) -> Result<String> {
+    let mut base_doc: DocumentMut = base.parse().context("Failed to parse config as TOML")?;
+    let overlay_doc: DocumentMut = overlay
+        .parse()
+        .context("Failed to parse project config as TOML")?;
+    merge_tables(base_doc.as_table_mut(), overlay_doc.as_table());
+    Ok(base_doc.to_string())
+}
+
+fn merge_tables(base: &mut dyn TableLike, overlay: &dyn TableLike) {
+    for (key, item) in overlay.iter() {
+        match (
+            base.get_mut(key).and_then(Item::as_table_like_mut),
+            item.as_table_like(),
+        ) {
+            (Some(base_table), Some(overlay_table)) => merge_tables(base_table, overlay_table),
+            _ => {
+                base.insert(key, item.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+    use tempfile::TempDir;
+
+    #[test]
+    fn find_project_config_walks_up() {
+        let root = TempDir::new().unwrap();
+        let nested = root.path().join("apps").join("web").join("src");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.path().join("apps").join(PROJECT_CONFIG_FILE), "").unwrap();
+
+        let found = find_project_config(&nested).unwrap();
+        assert_eq!(found, root.path().join("apps").join(PROJECT_CONFIG_FILE));
+    }
+
+    #[test]
+    fn find_project_config_prefers_nearest() {
+        let root = TempDir::new().unwrap();
+        let nested = root.path().join("web");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.path().join(PROJECT_CONFIG_FILE), "").unwrap();
+        std::fs::write(nested.join(PROJECT_CONFIG_FILE), "").unwrap();
+
+        assert_eq!(
+            find_project_config(&nested).unwrap(),
+            nested.join(PROJECT_CONFIG_FILE)
+        );
+    }
+
+    #[test]
+    fn merge_project_wins_and_absent_fields_inherit() {
+        let user = "[analysis]\nagent = \"codex\"\ntimeout = 300\n\n[agents]\nenabled = [\"claude\", \"codex\"]\n";
+        let project = "[analysis]\nagent = \"gemini\"\n\n[agents]\nenabled = [\"gemini\"]\n";
+
+        let merged = merge_config(user, project).unwrap();
+        let config: Config = toml::from_str(&merged).unwrap();
+        assert_eq!(config.analysis.agent.as_deref(), Some("gemini"));
+        assert_eq!(config.analysis.timeout, Some(300));
+        assert_eq!(config.agents.enabled, vec!["gemini"]);
+    }
+
+    #[test]
+    fn merge_inline_table_merges_key_by_key() {
+        let user = "[agents.claude]\ntoken_budget = 5000\nextra_args = [\"--verbose\"]\n";
+        let project = "[agents]\nclaude = { token_budget = 8000 }\n";
+
+        let merged = merge_config(user, project).unwrap();
+        let config: Config = toml::from_str(&merged).unwrap();
+        assert_eq!(config.agents.claude.token_budget, Some(8000));
+        assert_eq!(config.agents.claude.extra_args, vec!["--verbose"]);
+    }
+
+    #[test]
+    fn merge_into_inline_table_base() {
+        let user = "agents = { claude = { token_budget = 5000, extra_args = [\"-v\"] } }\n";
+        let project = "[agents.claude]\ntoken_budget = 8000\n";
+
+        let merged = merge_config(user, project).unwrap();
+        let config: Config = toml::from_str(&merged).unwrap();
+        assert_eq!(config.agents.claude.token_budget, Some(8000));
+        assert_eq!(config.agents.claude.extra_args, vec!["-v"]);
+    }
+
+    #[test]
+    fn strip_untrusted_keys_removes_commands_and_paths() {
+        let project = "[analysis]\nagent = \"gemini\"\nprompt_template = \"/etc/passwd\"\n\n\
+                       [agents.claude]\ntoken_budget = 8000\nanalyze_extra_args = [\"--yolo\"]\n\n\
+                       [storage]\ndirectory = \"/\"\n";
+
+        let (kept, removed) = strip_untrusted_keys(project).unwrap();
+        let config: Config = toml::from_str(&merge_config("", &kept).unwrap()).unwrap();
+        assert_eq!(config.analysis.agent.as_deref(), Some("gemini"));
+        assert_eq!(config.agents.claude.token_budget, Some(8000));
+        assert_eq!(config.analysis.prompt_template, None);
+        assert!(config.agents.claude.analyze_extra_args.is_empty());
+        assert_eq!(
+            removed,
+            vec![
+                "analysis.prompt_template",
+                "agents.claude.analyze_extra_args",
+                "storage.directory"
+            ]
+        );
+    }
+
+    #[test]
+    fn strip_untrusted_keys_checks_inline_tables() {
+        let project =
+            "agents = { codex = { extra_args = [\"--dangerous\"] } }\ntui = { theme = \"nord\" }\n";
+
+        let (kept, removed) = strip_untrusted_keys(project).unwrap();
+        assert_eq!(removed, vec!["agents.codex.extra_args"]);
+        assert!(kept.contains("nord"));
+    }
+
+    #[test]
+    fn merge_adds_sections_missing_from_base() {
+        let merged = merge_config("", "[agents.claude]\ntoken_budget = 5000\n").unwrap();
+        let config: Config = toml::from_str(&merged).unwrap();
+        assert_eq!(config.agents.claude.token_budget, Some(5000));
+    }
+}
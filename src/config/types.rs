@@ -23,6 +23,12 @@ pub struct Config {
     pub analysis: AnalysisConfig,
     #[serde(default)]
     pub agents: AgentsConfig,
+    #[serde(default)]
+    pub tui: TuiConfig,
+    #[serde(default)]
+    pub player: PlayerConfig,
+    #[serde(default)]
+    pub redact: RedactConfig,
 }
 
 fn default_config_version() -> u32 {
@@ -38,6 +44,9 @@ impl Default for Config {
             recording: RecordingConfig::default(),
             analysis: AnalysisConfig::default(),
             agents: AgentsConfig::default(),
+            tui: TuiConfig::default(),
+            player: PlayerConfig::default(),
+            redact: RedactConfig::default(),
         }
     }
 }
@@ -72,7 +81,7 @@ pub struct RecordingConfig {
     /// Whether to automatically analyze the recording after session ends
     #[serde(default)]
     pub auto_analyze: bool,
-    /// Filename template using tags like {directory}, {date}, {time}
+    /// Filename template using tags like {directory}, {date}, {time}, {agent}, {hostname}, {counter}
     #[serde(default = "default_filename_template")]
     pub filename_template: String,
     /// Maximum length for directory component in filename
@@ -107,6 +116,9 @@ pub struct StorageConfig {
     pub size_threshold_gb: f64,
     #[serde(default = "default_age_threshold")]
     pub age_threshold_days: u32,
+    /// Backups kept per recording (1 = keep the pre-first-change original)
+    #[serde(default = "default_backup_limit")]
+    pub backup_limit: usize,
 }
 
 pub fn default_directory() -> String {
@@ -121,16 +133,105 @@ pub fn default_age_threshold() -> u32 {
     30
 }
 
+pub fn default_backup_limit() -> usize {
+    1
+}
+
 impl Default for StorageConfig {
     fn default() -> Self {
         Self {
             directory: default_directory(),
             size_threshold_gb: default_size_threshold(),
             age_threshold_days: default_age_threshold(),
+            backup_limit: default_backup_limit(),
+        }
+    }
+}
+
+/// Interactive TUI preferences
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuiConfig {
+    /// Sort field for the file explorer (name, date, size, agent)
+    #[serde(default = "default_sort_field")]
+    pub sort_field: String,
+    /// Sort direction for the file explorer (asc, desc)
+    #[serde(default = "default_sort_direction")]
+    pub sort_direction: String,
+    /// Color theme (claude_code, classic, ocean)
+    #[serde(default = "default_theme")]
+    pub theme: String,
+}
+
+pub fn default_sort_field() -> String {
+    "date".to_string()
+}
+
+pub fn default_sort_direction() -> String {
+    "desc".to_string()
+}
+
+pub fn default_theme() -> String {
+    crate::theme::DEFAULT_THEME_NAME.to_string()
+}
+
+impl Default for TuiConfig {
+    fn default() -> Self {
+        Self {
+            sort_field: default_sort_field(),
+            sort_direction: default_sort_direction(),
+            theme: default_theme(),
         }
     }
 }
 
+/// Native player defaults (`agr play`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerConfig {
+    /// Initial playback speed multiplier
+    #[serde(default = "default_player_speed")]
+    pub speed: f64,
+    /// Start in viewport mode (arrow keys scroll instead of seeking)
+    #[serde(default)]
+    pub viewport_mode: bool,
+    /// Restart from the beginning when playback reaches the end
+    #[serde(default)]
+    pub loop_playback: bool,
+    /// Cut pauses longer than this many seconds (unset = play pauses in full)
+    #[serde(default)]
+    pub skip_idle: Option<f64>,
+    /// Color depth for RGB colors (auto, truecolor, 256)
+    #[serde(default = "default_color_depth")]
+    pub color_depth: String,
+}
+
+pub fn default_player_speed() -> f64 {
+    1.0
+}
+
+pub fn default_color_depth() -> String {
+    "auto".to_string()
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        Self {
+            speed: default_player_speed(),
+            viewport_mode: false,
+            loop_playback: false,
+            skip_idle: None,
+            color_depth: default_color_depth(),
+        }
+    }
+}
+
+/// Secret redaction settings (`agr optimize --redact`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RedactConfig {
+    /// Extra regular expressions redacted on top of the built-in secret patterns
+    #[serde(default)]
+    pub patterns: Vec<String>,
+}
+
 /// Agents configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentsConfig {
@@ -146,6 +247,8 @@ pub struct AgentsConfig {
     pub codex: AgentAnalysisConfig,
     #[serde(default)]
     pub gemini: AgentAnalysisConfig,
+    #[serde(default)]
+    pub openai: AgentAnalysisConfig,
 }
 
 pub fn default_agents() -> Vec<String> {
@@ -164,6 +267,7 @@ impl Default for AgentsConfig {
             claude: AgentAnalysisConfig::default(),
             codex: AgentAnalysisConfig::default(),
             gemini: AgentAnalysisConfig::default(),
+            openai: AgentAnalysisConfig::default(),
         }
     }
 }
@@ -175,6 +279,7 @@ impl AgentsConfig {
             "claude" => Some(&self.claude),
             "codex" => Some(&self.codex),
             "gemini" => Some(&self.gemini),
+            "openai" => Some(&self.openai),
             _ => None,
         }
     }
@@ -185,6 +290,7 @@ impl AgentsConfig {
         map.insert("claude".to_string(), &self.claude);
         map.insert("codex".to_string(), &self.codex);
         map.insert("gemini".to_string(), &self.gemini);
+        map.insert("openai".to_string(), &self.openai);
         map
     }
 }
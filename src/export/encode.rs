@@ -0,0 +1,195 @@
+//! Animated GIF encoding.
+
+use std::io::Write;
+
+use anyhow::{Context, Result};
+
+use crate::asciicast::AsciicastFile;
+
+use super::frames::sample_frames;
+use super::raster::{Canvas, Palette, CELL_HEIGHT, CELL_WIDTH};
+
+/// Summary of a finished GIF export.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GifStats {
+    /// Number of frames written (identical screens are merged)
+    pub frames: usize,
+    /// Image width in pixels
+    pub width: usize,
+    /// Image height in pixels
+    pub height: usize,
+    /// Playback length in seconds, after idle capping
+    pub duration: f64,
+}
+
+/// Smallest frame delay in centiseconds. Many viewers treat shorter delays
+/// as "as fast as possible" or bump them to 100ms.
+const MIN_DELAY_CS: u16 = 2;
+
+/// Render `cast` as a looping animated GIF at `fps` frames per second.
+///
+/// Every frame is drawn at the largest terminal size the recording reaches,
+/// so resizes pad with the background color instead of changing the image
+/// size mid-animation.
+pub fn export_gif<W: Write>(cast: &AsciicastFile, fps: u32, out: W) -> Result<GifStats> {
+    let palette = Palette::from_theme(cast.header.term.as_ref().and_then(|t| t.theme.as_ref()));
+    let frames = sample_frames(cast, fps);
+    let (cols, rows) = cast.effective_dimensions();
+    let (cols, rows) = (cols as usize, rows as usize);
+    let width = cols * CELL_WIDTH;
+    let height = rows * CELL_HEIGHT;
+    let too_large = || {
+        anyhow::anyhow!(
+            "Terminal size {}x{} is too large for a GIF ({}x{} pixels, max 65535)",
+            cols,
+            rows,
+            width,
+            height
+        )
+    };
+    let width_px = u16::try_from(width).map_err(|_| too_large())?;
+    let height_px = u16::try_from(height).map_err(|_| too_large())?;
+
+    let mut encoder =
+        gif::Encoder::new(out, width_px, height_px, &[]).context("Failed to start GIF")?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .context("Failed to write GIF header")?;
+
+    // Delays are derived from rounded cumulative times so rounding errors
+    // do not accumulate over long recordings.
+    let mut elapsed = 0.0;
+    let mut elapsed_cs: u64 = 0;
+    for frame in &frames {
+        let mut canvas = Canvas::new(width, height, palette.bg);
+        canvas.draw_frame(frame, &palette);
+
+        elapsed += frame.duration;
+        let target_cs = (elapsed * 100.0).round() as u64;
+        let delay = target_cs
+            .saturating_sub(elapsed_cs)
+            .clamp(u64::from(MIN_DELAY_CS), u64::from(u16::MAX)) as u16;
+        elapsed_cs += u64::from(delay);
+
+        let mut gif_frame = encode_frame(width_px, height_px, canvas.pixels());
+        gif_frame.delay = delay;
+        encoder
+            .write_frame(&gif_frame)
+            .context("Failed to write GIF frame")?;
+    }
+    encoder
+        .into_inner()
+        .context("Failed to finish GIF")?
+        .flush()
+        .context("Failed to finish GIF")?;
+
+    Ok(GifStats {
+        frames: frames.len(),
+        width,
+        height,
+        duration: elapsed,
+    })
+}
+
+/// Encode one RGB frame, using an exact palette when the frame has at most
+/// 256 distinct colors and NeuQuant quantization otherwise.
+fn encode_frame(width: u16, height: u16, rgb: &[u8]) -> gif::Frame<'static> {
+    let mut palette: Vec<[u8; 3]> = Vec::new();
+    let mut indices = Vec::with_capacity(rgb.len() / 3);
+    for px in rgb.chunks_exact(3) {
+        let color = [px[0], px[1], px[2]];
+        let index = match palette.iter().position(|c| *c == color) {
+            Some(i) => i,
+            None if palette.len() < 256 => {
+                palette.push(color);
+                palette.len() - 1
+            }
+            None => return gif::Frame::from_rgb_speed(width, height, rgb, 10),
+        };
+        indices.push(index as u8);
+    }
+    let flat: Vec<u8> = palette.into_iter().flatten().collect();
+    gif::Frame::from_palette_pixels(width, height, indices, flat, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::{Event, EventType, Header, TermInfo};
+
+    fn make_cast(events: Vec<Event>) -> AsciicastFile {
+        let mut cast = AsciicastFile::new(Header {
+            version: 3,
+            width: None,
+            height: None,
+            term: Some(TermInfo {
+                cols: Some(4),
+                rows: Some(2),
+                term_type: None,
+                theme: None,
+            }),
+            timestamp: None,
+            duration: None,
+            title: None,
+            command: None,
+            env: None,
+            idle_time_limit: None,
+        });
+        cast.events = events;
+        cast
+    }
+
+    fn decode(bytes: &[u8]) -> (u16, u16, Vec<u16>) {
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(bytes).unwrap();
+        let (w, h) = (decoder.width(), decoder.height());
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        (w, h, delays)
+    }
+
+    #[test]
+    fn writes_decodable_gif_with_frame_delays() {
+        let cast = make_cast(vec![
+            Event::output(0.0, "hi"),
+            Event::output(0.5, "\x1b[31m!"),
+        ]);
+        let mut out = Vec::new();
+
+        let stats = export_gif(&cast, 10, &mut out).unwrap();
+
+        let (w, h, delays) = decode(&out);
+        assert_eq!((w as usize, h as usize), (stats.width, stats.height));
+        assert_eq!(delays.len(), stats.frames);
+        assert_eq!(delays[0], 50);
+    }
+
+    #[test]
+    fn resize_pads_to_largest_size() {
+        let cast = make_cast(vec![
+            Event::output(0.0, "a"),
+            Event::new(0.2, EventType::Resize, "6x3"),
+            Event::new(0.2, EventType::Resize, "2x1"),
+        ]);
+        let mut out = Vec::new();
+
+        let stats = export_gif(&cast, 10, &mut out).unwrap();
+
+        assert_eq!(stats.width, 6 * CELL_WIDTH);
+        assert_eq!(stats.height, 3 * CELL_HEIGHT);
+        let (w, h, _) = decode(&out);
+        assert_eq!((w as usize, h as usize), (6 * CELL_WIDTH, 3 * CELL_HEIGHT));
+    }
+
+    #[test]
+    fn many_colors_fall_back_to_quantization() {
+        let rgb: Vec<u8> = (0..300u32)
+            .flat_map(|i| [(i % 256) as u8, (i / 256) as u8, 0])
+            .collect();
+        let frame = encode_frame(300, 1, &rgb);
+        assert_eq!(frame.width, 300);
+    }
+}
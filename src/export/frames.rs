@@ -0,0 +1,182 @@
+//! Fixed-rate frame sampling for export.
+//!
+//! Replays events the same way the player's seek does (output is fed to a
+//! [`TerminalBuffer`], resize events resize it), but advances in steps of
+//! `1 / fps` seconds and snapshots the screen after each step.
+
+use crate::asciicast::{AsciicastFile, CapIdleTime, Transform};
+use crate::terminal::{Cell, TerminalBuffer};
+
+/// A snapshot of the terminal screen and how long it stays visible.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenFrame {
+    /// Screen width in columns
+    pub cols: usize,
+    /// Screen height in rows
+    pub rows: usize,
+    /// Screen contents, one `Vec` per row
+    pub cells: Vec<Vec<Cell>>,
+    /// Seconds this frame is displayed
+    pub duration: f64,
+}
+
+impl ScreenFrame {
+    fn capture(buffer: &TerminalBuffer, duration: f64) -> Self {
+        let cells = (0..buffer.height())
+            .map(|row| buffer.row(row).map(<[Cell]>::to_vec).unwrap_or_default())
+            .collect();
+        Self {
+            cols: buffer.width(),
+            rows: buffer.height(),
+            cells,
+            duration,
+        }
+    }
+
+    fn same_screen(&self, other: &ScreenFrame) -> bool {
+        self.cols == other.cols && self.rows == other.rows && self.cells == other.cells
+    }
+}
+
+/// Replay `cast` and capture the screen every `1 / fps` seconds.
+///
+/// Gaps longer than the header's `idle_time_limit` are capped first, so long
+/// pauses do not turn into long stretches of unchanged frames. Consecutive
+/// identical screens are merged into one frame with a longer duration. The
+/// last frame always shows the final state of the recording.
+///
+/// # Panics
+///
+/// Panics if `fps` is zero.
+pub fn sample_frames(cast: &AsciicastFile, fps: u32) -> Vec<ScreenFrame> {
+    assert!(fps > 0, "fps must be positive");
+
+    let mut events = cast.events.clone();
+    if let Some(limit) = cast
+        .header
+        .idle_time_limit
+        .filter(|l| l.is_finite() && *l > 0.0)
+    {
+        CapIdleTime::new(limit).transform(&mut events);
+    }
+
+    let (cols, rows) = cast.terminal_size();
+    let mut buffer = TerminalBuffer::new(cols as usize, rows as usize);
+    let total: f64 = events.iter().map(|e| e.time).sum();
+    let interval = 1.0 / f64::from(fps);
+
+    let mut frames: Vec<ScreenFrame> = Vec::new();
+    let mut next_event = 0;
+    let mut event_time = 0.0f64;
+    let mut step: u64 = 0;
+
+    loop {
+        let t = step as f64 * interval;
+        while let Some(event) = events.get(next_event) {
+            if event_time + event.time > t {
+                break;
+            }
+            event_time += event.time;
+            if event.is_output() {
+                buffer.process(&event.data, None);
+            } else if let Some((new_cols, new_rows)) = event.parse_resize() {
+                buffer.resize(new_cols as usize, new_rows as usize);
+            }
+            next_event += 1;
+        }
+
+        let frame = ScreenFrame::capture(&buffer, interval);
+        match frames.last_mut() {
+            Some(last) if last.same_screen(&frame) => last.duration += interval,
+            _ => frames.push(frame),
+        }
+
+        if t >= total {
+            break;
+        }
+        step += 1;
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::{Event, EventType, Header, TermInfo};
+
+    fn make_cast(events: Vec<Event>, idle_time_limit: Option<f64>) -> AsciicastFile {
+        let mut cast = AsciicastFile::new(Header {
+            version: 3,
+            width: None,
+            height: None,
+            term: Some(TermInfo {
+                cols: Some(10),
+                rows: Some(2),
+                term_type: None,
+                theme: None,
+            }),
+            timestamp: None,
+            duration: None,
+            title: None,
+            command: None,
+            env: None,
+            idle_time_limit,
+        });
+        cast.events = events;
+        cast
+    }
+
+    fn row_text(frame: &ScreenFrame, row: usize) -> String {
+        frame.cells[row].iter().map(|c| c.char).collect::<String>()
+    }
+
+    #[test]
+    fn identical_screens_are_merged() {
+        let cast = make_cast(vec![Event::output(0.0, "a"), Event::output(1.0, "b")], None);
+
+        let frames = sample_frames(&cast, 10);
+
+        assert_eq!(frames.len(), 2);
+        assert!(row_text(&frames[0], 0).starts_with('a'));
+        assert!((frames[0].duration - 1.0).abs() < 1e-6);
+        assert!(row_text(&frames[1], 0).starts_with("ab"));
+    }
+
+    #[test]
+    fn idle_time_limit_caps_dead_air() {
+        let cast = make_cast(
+            vec![Event::output(0.0, "a"), Event::output(30.0, "b")],
+            Some(2.0),
+        );
+
+        let frames = sample_frames(&cast, 10);
+
+        let total: f64 = frames.iter().map(|f| f.duration).sum();
+        assert!(total < 2.5, "total duration {}", total);
+    }
+
+    #[test]
+    fn resize_changes_frame_dimensions() {
+        let cast = make_cast(
+            vec![
+                Event::output(0.0, "a"),
+                Event::new(0.5, EventType::Resize, "20x4"),
+            ],
+            None,
+        );
+
+        let frames = sample_frames(&cast, 10);
+
+        assert_eq!((frames[0].cols, frames[0].rows), (10, 2));
+        let last = frames.last().unwrap();
+        assert_eq!((last.cols, last.rows), (20, 4));
+        assert_eq!(cast.effective_dimensions(), (20, 4));
+    }
+
+    #[test]
+    fn empty_cast_yields_one_frame() {
+        let cast = make_cast(Vec::new(), None);
+        assert_eq!(sample_frames(&cast, 10).len(), 1);
+    }
+}
@@ -0,0 +1,25 @@
+//! Animated GIF export of recordings (`agr export --format gif`).
+//!
+//! Built only with the `gif-export` feature. Export runs in three stages:
+//!
+//! - [`frames`] replays the cast through [`TerminalBuffer`](crate::TerminalBuffer)
+//!   and samples the screen at a fixed frame rate, capping dead air at the
+//!   header's `idle_time_limit`.
+//! - [`raster`] draws each sampled screen with a bundled 8x13 bitmap font and
+//!   the recording's color palette.
+//! - [`encode`] writes the rasterized frames as an animated GIF.
+
+pub mod encode;
+pub mod frames;
+pub mod raster;
+
+pub use encode::{export_gif, GifStats};
+pub use frames::{sample_frames, ScreenFrame};
+pub use raster::{Canvas, Palette, CELL_HEIGHT, CELL_WIDTH};
+
+/// Default frames per second for GIF export.
+pub const DEFAULT_FPS: u32 = 10;
+
+/// Highest supported frame rate; GIF frame delays have 10ms resolution and
+/// most viewers clamp anything faster than 20ms.
+pub const MAX_FPS: u32 = 50;
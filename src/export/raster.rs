@@ -0,0 +1,357 @@
+//! Rasterization of terminal screens into RGB pixel buffers.
+//!
+//! Glyphs come from the 8x13 bitmap font bundled with `embedded-graphics`
+//! (ISO 8859-1 coverage; other characters render as `?`). Colors resolve
+//! through the recording's own theme when the header carries one, otherwise
+//! through the standard xterm palette.
+
+use embedded_graphics::mono_font::iso_8859_1::{FONT_8X13, FONT_8X13_BOLD};
+use embedded_graphics::mono_font::MonoTextStyleBuilder;
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::prelude::*;
+use embedded_graphics::text::{Baseline, Text};
+
+use crate::asciicast::TtyTheme;
+use crate::terminal::{Cell, Color};
+
+use super::frames::ScreenFrame;
+
+/// Width of one terminal cell in pixels.
+pub const CELL_WIDTH: usize = 8;
+
+/// Height of one terminal cell in pixels.
+pub const CELL_HEIGHT: usize = 13;
+
+/// xterm's default 16-color palette.
+const XTERM_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Foreground, background and 16 ANSI colors used for rasterization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub fg: (u8, u8, u8),
+    pub bg: (u8, u8, u8),
+    pub ansi: [(u8, u8, u8); 16],
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            fg: (229, 229, 229),
+            bg: (0, 0, 0),
+            ansi: XTERM_PALETTE,
+        }
+    }
+}
+
+impl Palette {
+    /// Build a palette from a recording's theme, falling back to the xterm
+    /// colors for any palette entries the theme does not define.
+    pub fn from_theme(theme: Option<&TtyTheme>) -> Self {
+        let mut palette = Self::default();
+        if let Some(theme) = theme {
+            palette.fg = (theme.fg.r, theme.fg.g, theme.fg.b);
+            palette.bg = (theme.bg.r, theme.bg.g, theme.bg.b);
+            for (slot, color) in palette.ansi.iter_mut().zip(&theme.palette) {
+                *slot = (color.r, color.g, color.b);
+            }
+        }
+        palette
+    }
+
+    /// Resolve a terminal color to RGB. `Color::Default` resolves to
+    /// `default`, which is the foreground or background depending on layer.
+    pub fn resolve(&self, color: Color, default: (u8, u8, u8)) -> (u8, u8, u8) {
+        match color {
+            Color::Default => default,
+            Color::Black => self.ansi[0],
+            Color::Red => self.ansi[1],
+            Color::Green => self.ansi[2],
+            Color::Yellow => self.ansi[3],
+            Color::Blue => self.ansi[4],
+            Color::Magenta => self.ansi[5],
+            Color::Cyan => self.ansi[6],
+            Color::White => self.ansi[7],
+            Color::BrightBlack => self.ansi[8],
+            Color::BrightRed => self.ansi[9],
+            Color::BrightGreen => self.ansi[10],
+            Color::BrightYellow => self.ansi[11],
+            Color::BrightBlue => self.ansi[12],
+            Color::BrightMagenta => self.ansi[13],
+            Color::BrightCyan => self.ansi[14],
+            Color::BrightWhite => self.ansi[15],
+            Color::Indexed(i) => self.indexed(i),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    fn indexed(&self, index: u8) -> (u8, u8, u8) {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+        match index {
+            0..=15 => self.ansi[index as usize],
+            16..=231 => {
+                let i = (index - 16) as usize;
+                (
+                    CUBE_LEVELS[i / 36],
+                    CUBE_LEVELS[(i / 6) % 6],
+                    CUBE_LEVELS[i % 6],
+                )
+            }
+            _ => {
+                let level = 8 + 10 * (index - 232);
+                (level, level, level)
+            }
+        }
+    }
+}
+
+/// An RGB pixel buffer that `embedded-graphics` can draw glyphs into.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// Create a canvas of `width` x `height` pixels filled with `fill`.
+    pub fn new(width: usize, height: usize, fill: (u8, u8, u8)) -> Self {
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for _ in 0..width * height {
+            pixels.extend_from_slice(&[fill.0, fill.1, fill.2]);
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Canvas width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Canvas height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Raw RGB bytes, row-major, three bytes per pixel.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Color of the pixel at (`x`, `y`).
+    pub fn pixel(&self, x: usize, y: usize) -> (u8, u8, u8) {
+        let i = (y * self.width + x) * 3;
+        (self.pixels[i], self.pixels[i + 1], self.pixels[i + 2])
+    }
+
+    fn set(&mut self, x: usize, y: usize, color: (u8, u8, u8)) {
+        if x < self.width && y < self.height {
+            let i = (y * self.width + x) * 3;
+            self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+        }
+    }
+
+    fn fill_rect(&mut self, x: usize, y: usize, w: usize, h: usize, color: (u8, u8, u8)) {
+        for py in y..(y + h).min(self.height) {
+            for px in x..(x + w).min(self.width) {
+                self.set(px, py, color);
+            }
+        }
+    }
+
+    /// Draw `frame` at the top-left corner. Areas outside the frame's screen
+    /// keep their current color, which pads smaller frames out to the canvas.
+    pub fn draw_frame(&mut self, frame: &ScreenFrame, palette: &Palette) {
+        for (row, cells) in frame.cells.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                self.draw_cell(col, row, cell, palette);
+            }
+        }
+    }
+
+    fn draw_cell(&mut self, col: usize, row: usize, cell: &Cell, palette: &Palette) {
+        let style = &cell.style;
+        let mut fg = palette.resolve(style.fg, palette.fg);
+        let mut bg = palette.resolve(style.bg, palette.bg);
+        if style.reverse {
+            std::mem::swap(&mut fg, &mut bg);
+        }
+        if style.dim {
+            fg = blend(fg, bg);
+        }
+
+        let x = col * CELL_WIDTH;
+        let y = row * CELL_HEIGHT;
+        self.fill_rect(x, y, CELL_WIDTH, CELL_HEIGHT, bg);
+
+        if cell.is_wide_continuation() || (cell.char == ' ' && !style.underline) {
+            return;
+        }
+
+        let font = if style.bold {
+            &FONT_8X13_BOLD
+        } else {
+            &FONT_8X13
+        };
+        let fg_color = Rgb888::new(fg.0, fg.1, fg.2);
+        let mut builder = MonoTextStyleBuilder::new().font(font).text_color(fg_color);
+        if style.underline {
+            builder = builder.underline();
+        }
+        let mut utf8 = [0u8; 4];
+        let text = cell.char.encode_utf8(&mut utf8);
+        let position = Point::new(x as i32, y as i32);
+        // Drawing into the canvas is infallible
+        let _ = Text::with_baseline(text, position, builder.build(), Baseline::Top).draw(self);
+    }
+}
+
+/// Halfway point between two colors, used for dim text.
+fn blend(a: (u8, u8, u8), b: (u8, u8, u8)) -> (u8, u8, u8) {
+    let mix = |x: u8, y: u8| ((u16::from(x) + u16::from(y)) / 2) as u8;
+    (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+impl OriginDimensions for Canvas {
+    fn size(&self) -> Size {
+        Size::new(self.width as u32, self.height as u32)
+    }
+}
+
+impl DrawTarget for Canvas {
+    type Color = Rgb888;
+    type Error = std::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if point.x >= 0 && point.y >= 0 {
+                self.set(
+                    point.x as usize,
+                    point.y as usize,
+                    (color.r(), color.g(), color.b()),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::RgbColor;
+    use crate::terminal::CellStyle;
+
+    fn frame_with(cells: Vec<Cell>) -> ScreenFrame {
+        ScreenFrame {
+            cols: cells.len(),
+            rows: 1,
+            cells: vec![cells],
+            duration: 1.0,
+        }
+    }
+
+    fn cell(c: char, style: CellStyle) -> Cell {
+        Cell {
+            char: c,
+            style,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn palette_uses_theme_colors() {
+        let theme = TtyTheme {
+            fg: RgbColor::new(1, 2, 3),
+            bg: RgbColor::new(4, 5, 6),
+            palette: vec![RgbColor::new(7, 8, 9); 8],
+        };
+        let palette = Palette::from_theme(Some(&theme));
+        assert_eq!(palette.resolve(Color::Default, palette.fg), (1, 2, 3));
+        assert_eq!(palette.resolve(Color::Red, palette.fg), (7, 8, 9));
+        // Bright colors are not covered by an 8-color theme
+        assert_eq!(palette.resolve(Color::BrightRed, palette.fg), (255, 0, 0));
+    }
+
+    #[test]
+    fn palette_resolves_indexed_cube_and_grayscale() {
+        let palette = Palette::default();
+        assert_eq!(palette.resolve(Color::Indexed(16), palette.fg), (0, 0, 0));
+        assert_eq!(
+            palette.resolve(Color::Indexed(196), palette.fg),
+            (255, 0, 0)
+        );
+        assert_eq!(palette.resolve(Color::Indexed(232), palette.fg), (8, 8, 8));
+        assert_eq!(
+            palette.resolve(Color::Indexed(255), palette.fg),
+            (238, 238, 238)
+        );
+    }
+
+    #[test]
+    fn cell_background_fills_cell_area() {
+        let palette = Palette::default();
+        let style = CellStyle {
+            bg: Color::Blue,
+            ..CellStyle::default()
+        };
+        let mut canvas = Canvas::new(CELL_WIDTH * 2, CELL_HEIGHT, palette.bg);
+        canvas.draw_frame(&frame_with(vec![cell(' ', style)]), &palette);
+
+        assert_eq!(canvas.pixel(0, 0), palette.ansi[4]);
+        assert_eq!(
+            canvas.pixel(CELL_WIDTH - 1, CELL_HEIGHT - 1),
+            palette.ansi[4]
+        );
+        // Second column is outside the frame and stays padding
+        assert_eq!(canvas.pixel(CELL_WIDTH, 0), palette.bg);
+    }
+
+    #[test]
+    fn glyph_pixels_use_foreground_color() {
+        let palette = Palette::default();
+        let mut canvas = Canvas::new(CELL_WIDTH, CELL_HEIGHT, palette.bg);
+        canvas.draw_frame(&frame_with(vec![cell('#', CellStyle::default())]), &palette);
+
+        let lit = (0..CELL_HEIGHT)
+            .flat_map(|y| (0..CELL_WIDTH).map(move |x| (x, y)))
+            .filter(|&(x, y)| canvas.pixel(x, y) == palette.fg)
+            .count();
+        assert!(lit > 0);
+    }
+
+    #[test]
+    fn reverse_swaps_colors() {
+        let palette = Palette::default();
+        let style = CellStyle {
+            reverse: true,
+            ..CellStyle::default()
+        };
+        let mut canvas = Canvas::new(CELL_WIDTH, CELL_HEIGHT, palette.bg);
+        canvas.draw_frame(&frame_with(vec![cell(' ', style)]), &palette);
+        assert_eq!(canvas.pixel(0, 0), palette.fg);
+    }
+}
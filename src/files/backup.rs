@@ -2,12 +2,20 @@
 //!
 //! Provides backup creation, restore, and path helpers used by both
 //! the CLI analyze command and the TUI.
+//!
+//! The most recent backup is `<name>.cast.bak`. When more than one backup is
+//! kept (`storage.backup_limit`, passed in by the caller), older generations
+//! are rotated to `<name>.cast.bak.1`, `<name>.cast.bak.2`, ... with higher
+//! numbers being older.
 
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 
+/// Largest accepted `storage.backup_limit`.
+pub const MAX_BACKUP_LIMIT: usize = 20;
+
 /// Get the backup path for a given file.
 ///
 /// The backup path is the original path with `.bak` appended.
@@ -17,33 +25,93 @@ pub fn backup_path_for(path: &Path) -> PathBuf {
     PathBuf::from(backup)
 }
 
-/// Check if a backup exists for the given file.
+/// Get the path of one backup generation.
+///
+/// Generation 0 is the most recent backup ([`backup_path_for`]); generation
+/// `n` is `<name>.bak.<n>`.
+pub fn backup_generation_path(path: &Path, generation: usize) -> PathBuf {
+    let backup = backup_path_for(path);
+    if generation == 0 {
+        return backup;
+    }
+    let mut numbered = backup.into_os_string();
+    numbered.push(format!(".{}", generation));
+    PathBuf::from(numbered)
+}
+
+/// Existing backups of the given file, most recent first.
+pub fn backup_paths(path: &Path) -> Vec<PathBuf> {
+    (0..MAX_BACKUP_LIMIT)
+        .map(|generation| backup_generation_path(path, generation))
+        .filter(|p| p.exists())
+        .collect()
+}
+
+/// Check if any backup exists for the given file.
 pub fn has_backup(path: &Path) -> bool {
-    backup_path_for(path).exists()
+    !backup_paths(path).is_empty()
 }
 
-/// Create a backup of the given file if one doesn't already exist.
+/// Back up the given file, keeping at most `keep` generations.
 ///
-/// Returns `Ok(true)` if a new backup was created, `Ok(false)` if one already existed.
-pub fn create_backup(path: &Path) -> Result<bool> {
+/// `keep` is normally `storage.backup_limit`. Returns `Ok(true)` if a new
+/// backup was created.
+///
+/// With `keep` of 1 the single backup holds the state before the first
+/// change: it is only created if it doesn't already exist, and `Ok(false)`
+/// is returned otherwise. With more generations every call shifts the
+/// existing backups one generation older, drops the ones past `keep`, and
+/// copies the file to the most recent slot.
+pub fn create_backup(path: &Path, keep: usize) -> Result<bool> {
     let backup = backup_path_for(path);
-    if backup.exists() {
-        return Ok(false);
+    if keep <= 1 {
+        if backup.exists() {
+            return Ok(false);
+        }
+    } else {
+        for generation in (keep - 1)..MAX_BACKUP_LIMIT {
+            let _ = fs::remove_file(backup_generation_path(path, generation));
+        }
+        for generation in (0..keep - 1).rev() {
+            let from = backup_generation_path(path, generation);
+            if from.exists() {
+                let to = backup_generation_path(path, generation + 1);
+                fs::rename(&from, &to)
+                    .with_context(|| format!("Failed to rotate backup: {}", from.display()))?;
+            }
+        }
     }
     fs::copy(path, &backup)
         .with_context(|| format!("Failed to create backup: {}", backup.display()))?;
     Ok(true)
 }
 
-/// Restore a file from its backup.
+/// Restore a file from its most recent backup.
 ///
 /// Uses an atomic temp+rename pattern for crash safety.
-/// Deletes the backup file after successful restore.
+/// Deletes the backup file after successful restore; older generations
+/// move up one slot.
 pub fn restore_from_backup(path: &Path) -> Result<()> {
-    let backup = backup_path_for(path);
+    restore_from_backup_n(path, 0)
+}
+
+/// Restore a file from a specific backup generation (0 = most recent).
+///
+/// Uses an atomic temp+rename pattern for crash safety. The restored
+/// generation is deleted and older generations move up one slot; newer
+/// ones are kept.
+pub fn restore_from_backup_n(path: &Path, generation: usize) -> Result<()> {
+    let backup = backup_generation_path(path, generation);
 
     if !backup.exists() {
-        anyhow::bail!("No backup exists for: {}", path.display());
+        if generation == 0 {
+            anyhow::bail!("No backup exists for: {}", path.display());
+        }
+        anyhow::bail!(
+            "No backup generation {} exists for: {}",
+            generation,
+            path.display()
+        );
     }
 
     // Use atomic temp+rename pattern for crash safety
@@ -62,5 +130,130 @@ pub fn restore_from_backup(path: &Path) -> Result<()> {
     // Delete backup file after successful restore (best-effort, ignore errors)
     let _ = fs::remove_file(&backup);
 
+    // Close the gap left by the restored generation (best-effort)
+    for older in (generation + 1)..MAX_BACKUP_LIMIT {
+        let from = backup_generation_path(path, older);
+        if !from.exists() {
+            break;
+        }
+        let _ = fs::rename(&from, backup_generation_path(path, older - 1));
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        fs::write(path, content).unwrap();
+    }
+
+    fn read(path: &Path) -> String {
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn generation_paths_number_older_backups() {
+        let path = Path::new("/rec/session.cast");
+        assert_eq!(
+            backup_generation_path(path, 0),
+            PathBuf::from("/rec/session.cast.bak")
+        );
+        assert_eq!(
+            backup_generation_path(path, 2),
+            PathBuf::from("/rec/session.cast.bak.2")
+        );
+    }
+
+    #[test]
+    fn single_generation_keeps_first_backup() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write(&path, "v1");
+        assert!(create_backup(&path, 1).unwrap());
+
+        write(&path, "v2");
+        assert!(!create_backup(&path, 1).unwrap());
+
+        assert_eq!(read(&backup_path_for(&path)), "v1");
+        assert_eq!(backup_paths(&path).len(), 1);
+    }
+
+    #[test]
+    fn rotation_keeps_last_n_backups() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        for version in ["v1", "v2", "v3", "v4"] {
+            write(&path, version);
+            assert!(create_backup(&path, 3).unwrap());
+        }
+
+        let backups: Vec<String> = backup_paths(&path).iter().map(|p| read(p)).collect();
+        assert_eq!(backups, vec!["v4", "v3", "v2"]);
+        assert!(!backup_generation_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn restore_specific_generation_and_compact() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        for version in ["v1", "v2", "v3"] {
+            write(&path, version);
+            create_backup(&path, 3).unwrap();
+        }
+        write(&path, "current");
+
+        restore_from_backup_n(&path, 1).unwrap();
+
+        assert_eq!(read(&path), "v2");
+        let backups: Vec<String> = backup_paths(&path).iter().map(|p| read(p)).collect();
+        assert_eq!(backups, vec!["v3", "v1"]);
+    }
+
+    #[test]
+    fn restore_most_recent_promotes_older_generation() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        for version in ["v1", "v2"] {
+            write(&path, version);
+            create_backup(&path, 2).unwrap();
+        }
+
+        restore_from_backup(&path).unwrap();
+
+        assert_eq!(read(&path), "v2");
+        assert!(has_backup(&path));
+        assert_eq!(read(&backup_path_for(&path)), "v1");
+    }
+
+    #[test]
+    fn restore_missing_generation_fails() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        write(&path, "v1");
+        create_backup(&path, 2).unwrap();
+
+        let err = restore_from_backup_n(&path, 1).unwrap_err();
+        assert!(err.to_string().contains("No backup generation 1"));
+        assert_eq!(read(&path), "v1");
+    }
+
+    #[test]
+    fn lowering_the_limit_drops_extra_generations() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.cast");
+        for version in ["v1", "v2", "v3"] {
+            write(&path, version);
+            create_backup(&path, 3).unwrap();
+        }
+
+        write(&path, "v4");
+        create_backup(&path, 2).unwrap();
+
+        let backups: Vec<String> = backup_paths(&path).iter().map(|p| read(p)).collect();
+        assert_eq!(backups, vec!["v4", "v3"]);
+    }
+}
@@ -1,24 +1,40 @@
 //! Filename generation and sanitization for recordings.
 //!
 //! Provides configurable filename templates with tags like `{directory}`, `{date}`, `{time}`,
-//! and comprehensive sanitization to ensure filesystem-safe names.
+//! `{agent}`, `{hostname}`, `{counter}`, and comprehensive sanitization to ensure
+//! filesystem-safe names.
 
+use std::path::Path;
+
+use chrono::{DateTime, Local};
 use deunicode::deunicode;
 
 /// Minimum allowed value for directory_max_length.
 const MIN_DIRECTORY_MAX_LENGTH: usize = 1;
 
+/// Default extension for generated recording filenames.
+const DEFAULT_EXTENSION: &str = ".cast";
+
 /// Configuration for filename generation.
 #[derive(Debug, Clone)]
 pub struct Config {
     /// Maximum length for the directory component (default: 50, minimum: 1).
     pub directory_max_length: usize,
+    /// Extension appended to generated filenames, including the leading dot (default: `.cast`).
+    pub extension: String,
+    /// Keep non-ASCII characters instead of transliterating them (default: false).
+    pub preserve_unicode: bool,
+    /// Name used when sanitization produces an empty result (default: `recording`).
+    pub fallback_name: String,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             directory_max_length: 50,
+            extension: DEFAULT_EXTENSION.to_string(),
+            preserve_unicode: false,
+            fallback_name: FALLBACK_NAME.to_string(),
         }
     }
 }
@@ -28,8 +44,31 @@ impl Config {
     pub fn new(directory_max_length: usize) -> Self {
         Self {
             directory_max_length: directory_max_length.max(MIN_DIRECTORY_MAX_LENGTH),
+            ..Self::default()
         }
     }
+
+    /// Sets the filename extension, adding a leading dot if missing (e.g. `cast.gz` → `.cast.gz`).
+    pub fn with_extension(mut self, extension: &str) -> Self {
+        self.extension = if extension.starts_with('.') {
+            extension.to_string()
+        } else {
+            format!(".{}", extension)
+        };
+        self
+    }
+
+    /// Keeps non-ASCII characters such as CJK or Cyrillic letters instead of transliterating.
+    pub fn with_preserve_unicode(mut self, preserve_unicode: bool) -> Self {
+        self.preserve_unicode = preserve_unicode;
+        self
+    }
+
+    /// Sets the name used when sanitization leaves nothing behind.
+    pub fn with_fallback_name(mut self, fallback_name: &str) -> Self {
+        self.fallback_name = fallback_name.to_string();
+        self
+    }
 }
 
 /// Windows reserved device names that cannot be used as filenames.
@@ -50,23 +89,28 @@ const MAX_FILENAME_LENGTH: usize = 255;
 /// Sanitizes a string for use in filenames.
 ///
 /// Applies the following transformations in order:
-/// 1. Unicode → ASCII transliteration
+/// 1. Unicode → ASCII transliteration (skipped when `config.preserve_unicode` is set,
+///    in which case non-ASCII characters are kept and only control characters dropped)
 /// 2. Whitespace → hyphens
 /// 3. Invalid filesystem characters removed
 /// 4. Multiple hyphens collapsed to single
 /// 5. Leading/trailing dots, spaces, hyphens trimmed
 /// 6. Windows reserved names prefixed with `_`
-/// 7. Empty results → "recording" fallback
+/// 7. Empty results → `config.fallback_name` ("recording" by default)
 #[allow(dead_code)]
-pub fn sanitize(input: &str, _config: &Config) -> String {
+pub fn sanitize(input: &str, config: &Config) -> String {
     // Step 1: Unicode transliteration
-    let ascii = deunicode(input);
+    let text = if config.preserve_unicode {
+        input.to_string()
+    } else {
+        deunicode(input)
+    };
 
     // Step 2 & 3: Process characters
-    let mut result = String::with_capacity(ascii.len());
+    let mut result = String::with_capacity(text.len());
     let mut last_was_hyphen = false;
 
-    for c in ascii.chars() {
+    for c in text.chars() {
         if c.is_whitespace() {
             // Whitespace → hyphen (collapse multiple)
             if !last_was_hyphen {
@@ -86,6 +130,10 @@ pub fn sanitize(input: &str, _config: &Config) -> String {
             // Valid chars preserved
             result.push(c);
             last_was_hyphen = false;
+        } else if config.preserve_unicode && !c.is_ascii() && !c.is_control() {
+            // Non-ASCII chars preserved as-is in preserve mode
+            result.push(c);
+            last_was_hyphen = false;
         } else if c == '(' || c == ')' || c == '[' || c == ']' {
             // Common brackets → removed (they become empty after deunicode)
             continue;
@@ -101,7 +149,7 @@ pub fn sanitize(input: &str, _config: &Config) -> String {
 
     // Step 6: Fallback for empty result
     if final_name.is_empty() {
-        FALLBACK_NAME.to_string()
+        config.fallback_name.clone()
     } else {
         final_name
     }
@@ -131,23 +179,38 @@ pub fn validate_length(filename: &str) -> Result<(), FilenameError> {
     }
 }
 
-/// Generates a filename from a template and directory name.
+/// Generates a filename from a template, directory name, and agent name.
 ///
 /// This is the main entry point for filename generation. It:
 /// 1. Parses the template
-/// 2. Renders it with the directory and current datetime
-/// 3. Adds `.cast` extension
+/// 2. Renders it with the directory, agent, and current datetime
+/// 3. Adds the configured extension (`.cast` by default)
 /// 4. Validates the final length
-#[allow(dead_code)]
-pub fn generate(directory: &str, template: &str, config: &Config) -> Result<String, GenerateError> {
+///
+/// If the template contains `{counter}`, `target_dir` is probed and the
+/// lowest counter (starting at 1) whose filename does not exist yet is used.
+pub fn generate(
+    directory: &str,
+    agent: &str,
+    template: &str,
+    config: &Config,
+    target_dir: &Path,
+) -> Result<String, GenerateError> {
     let parsed = Template::parse(template)?;
-    let rendered = parsed.render(directory, config);
-
-    // Add .cast extension if not present
-    let filename = if rendered.ends_with(".cast") {
-        rendered
+    let mut values = TemplateValues::new(directory).agent(agent);
+
+    let filename = if parsed.has_counter() {
+        // Look up the hostname once instead of on every probe
+        values.hostname = Some(system_hostname());
+        loop {
+            let candidate = with_extension(parsed.render_with(&values, config), &config.extension);
+            if !target_dir.join(&candidate).exists() {
+                break candidate;
+            }
+            values.counter += 1;
+        }
     } else {
-        format!("{}.cast", rendered)
+        with_extension(parsed.render_with(&values, config), &config.extension)
     };
 
     // Validate final length
@@ -156,6 +219,74 @@ pub fn generate(directory: &str, template: &str, config: &Config) -> Result<Stri
     Ok(filename)
 }
 
+/// Generates a filename that does not collide with an existing file in `target_dir`.
+///
+/// Renders the template like [`generate`]; if `target_dir/<name><extension>` already exists,
+/// appends `-2`, `-3`, ... before the extension until a free name is found. The base
+/// name is truncated when needed so the result stays within the 255-character limit.
+pub fn generate_unique(
+    directory: &str,
+    agent: &str,
+    template: &str,
+    config: &Config,
+    target_dir: &Path,
+) -> Result<String, GenerateError> {
+    let filename = generate(directory, agent, template, config, target_dir)?;
+    if !target_dir.join(&filename).exists() {
+        return Ok(filename);
+    }
+
+    let base = filename
+        .strip_suffix(config.extension.as_str())
+        .unwrap_or(&filename);
+    let mut suffix_number = 2;
+    loop {
+        let suffix = format!("-{}{}", suffix_number, config.extension);
+        let base = truncate_to_bytes(base, MAX_FILENAME_LENGTH.saturating_sub(suffix.len()));
+        let candidate = format!("{}{}", base, suffix);
+        if !target_dir.join(&candidate).exists() {
+            validate_length(&candidate).map_err(GenerateError::from)?;
+            return Ok(candidate);
+        }
+        suffix_number += 1;
+    }
+}
+
+/// Truncates a string to at most `max_bytes` bytes without splitting a character.
+fn truncate_to_bytes(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Adds the extension if the rendered name does not already end with it.
+fn with_extension(rendered: String, extension: &str) -> String {
+    if rendered.ends_with(extension) {
+        rendered
+    } else {
+        format!("{}{}", rendered, extension)
+    }
+}
+
+/// Returns the machine's hostname, or `localhost` if it cannot be determined.
+///
+/// Checks the `HOSTNAME` and `COMPUTERNAME` environment variables before
+/// falling back to `/etc/hostname`.
+pub fn system_hostname() -> String {
+    ["HOSTNAME", "COMPUTERNAME"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .chain(std::fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .find(|name| !name.is_empty())
+        .unwrap_or_else(|| "localhost".to_string())
+}
+
 /// Errors that can occur during filename generation.
 #[derive(Debug)]
 pub enum GenerateError {
@@ -492,6 +623,12 @@ pub enum Segment {
     Date(String),
     /// Time tag with format string.
     Time(String),
+    /// Agent name tag.
+    Agent,
+    /// Hostname tag.
+    Hostname,
+    /// Counter tag, resolved to the next free integer in the target directory.
+    Counter,
 }
 
 /// Default date format for {date} tag.
@@ -503,6 +640,55 @@ const DEFAULT_TIME_FORMAT: &str = "%H%M%S";
 /// Default template string.
 const DEFAULT_TEMPLATE: &str = "{directory}_{date}_{time}";
 
+/// Value rendered for {agent} when no agent name is known.
+const UNKNOWN_AGENT: &str = "unknown";
+
+/// Values substituted into template tags during rendering.
+#[derive(Debug, Clone)]
+pub struct TemplateValues<'a> {
+    /// Directory name for {directory}.
+    pub directory: &'a str,
+    /// Agent name for {agent}.
+    pub agent: Option<&'a str>,
+    /// Hostname for {hostname}; looked up from the system if not set.
+    pub hostname: Option<String>,
+    /// Value for {counter}.
+    pub counter: u32,
+    /// Timestamp for {date} and {time}.
+    pub now: DateTime<Local>,
+}
+
+impl<'a> TemplateValues<'a> {
+    /// Creates values for the given directory, using the current time and counter 1.
+    pub fn new(directory: &'a str) -> Self {
+        Self {
+            directory,
+            agent: None,
+            hostname: None,
+            counter: 1,
+            now: Local::now(),
+        }
+    }
+
+    /// Sets the agent name.
+    pub fn agent(mut self, agent: &'a str) -> Self {
+        self.agent = Some(agent);
+        self
+    }
+
+    /// Sets the hostname.
+    pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = Some(hostname.into());
+        self
+    }
+
+    /// Sets the counter value.
+    pub fn counter(mut self, counter: u32) -> Self {
+        self.counter = counter;
+        self
+    }
+}
+
 /// A parsed filename template.
 #[derive(Debug, Clone)]
 pub struct Template {
@@ -577,28 +763,44 @@ impl Template {
         &self.segments
     }
 
+    /// Returns true if the template contains a {counter} tag.
+    pub fn has_counter(&self) -> bool {
+        self.segments.contains(&Segment::Counter)
+    }
+
     /// Renders the template with the given directory name and config.
     pub fn render(&self, directory: &str, config: &Config) -> String {
-        use chrono::Local;
+        self.render_with(&TemplateValues::new(directory), config)
+    }
 
-        let now = Local::now();
+    /// Renders the template with explicit tag values.
+    pub fn render_with(&self, values: &TemplateValues, config: &Config) -> String {
         let mut result = String::new();
 
         for segment in &self.segments {
             match segment {
                 Segment::Literal(s) => result.push_str(s),
                 Segment::Directory => {
-                    let sanitized = sanitize_directory(directory, config);
+                    let sanitized = sanitize_directory(values.directory, config);
                     result.push_str(&sanitized);
                 }
                 Segment::Date(fmt) => {
-                    let formatted = now.format(fmt).to_string();
+                    let formatted = values.now.format(fmt).to_string();
                     result.push_str(&formatted);
                 }
                 Segment::Time(fmt) => {
-                    let formatted = now.format(fmt).to_string();
+                    let formatted = values.now.format(fmt).to_string();
                     result.push_str(&formatted);
                 }
+                Segment::Agent => {
+                    let agent = values.agent.unwrap_or(UNKNOWN_AGENT);
+                    result.push_str(&sanitize(agent, config));
+                }
+                Segment::Hostname => {
+                    let hostname = values.hostname.clone().unwrap_or_else(system_hostname);
+                    result.push_str(&sanitize(&hostname, config));
+                }
+                Segment::Counter => result.push_str(&values.counter.to_string()),
             }
         }
 
@@ -646,6 +848,19 @@ fn parse_tag(content: &str) -> Result<Segment, TemplateError> {
             validate_strftime_format(fmt)?;
             Ok(Segment::Time(fmt.to_string()))
         }
+        "agent" | "hostname" | "counter" => {
+            if format.is_some() {
+                return Err(TemplateError::InvalidFormat(format!(
+                    "{} tag does not accept format",
+                    tag_name
+                )));
+            }
+            Ok(match tag_name {
+                "agent" => Segment::Agent,
+                "hostname" => Segment::Hostname,
+                _ => Segment::Counter,
+            })
+        }
         _ => Err(TemplateError::UnknownTag(tag_name.to_string())),
     }
 }
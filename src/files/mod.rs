@@ -3,3 +3,5 @@
 pub mod backup;
 pub mod filename;
 pub mod resolve;
+pub mod sidecar;
+pub mod stdin;
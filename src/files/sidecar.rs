@@ -0,0 +1,28 @@
+//! Paths of sidecar files kept next to a recording.
+//!
+//! The player stores its resume position in `<name>.cast.pos`. Storage
+//! operations (delete, move) carry the sidecars along with the recording.
+
+use std::path::{Path, PathBuf};
+
+/// Extension appended to the recording file name for the resume sidecar.
+const POSITION_SUFFIX: &str = ".pos";
+
+/// Get the resume position sidecar path for a recording
+/// (e.g. `session.cast.pos`).
+pub fn position_path(cast_path: &Path) -> PathBuf {
+    let mut name = cast_path.as_os_str().to_os_string();
+    name.push(POSITION_SUFFIX);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_path_appends_suffix() {
+        let path = position_path(Path::new("/tmp/claude/session.cast"));
+        assert_eq!(path, PathBuf::from("/tmp/claude/session.cast.pos"));
+    }
+}
@@ -0,0 +1,70 @@
+//! Reading recordings from standard input.
+//!
+//! Read-only commands accept `-` in place of a file path so recordings can
+//! be piped in (`cat session.cast | agr validate -`). Commands that write
+//! back to the file they read reject it with [`reject_stdin`].
+
+use std::io::{self, Read};
+
+use anyhow::{bail, Context, Result};
+
+use crate::asciicast::AsciicastFile;
+
+/// File argument that stands for standard input.
+pub const STDIN_ARG: &str = "-";
+
+/// Whether a file argument stands for standard input.
+pub fn is_stdin(file: &str) -> bool {
+    file == STDIN_ARG
+}
+
+/// Read all of standard input as text.
+pub fn read_stdin() -> Result<String> {
+    let mut content = String::new();
+    io::stdin()
+        .read_to_string(&mut content)
+        .context("Failed to read recording from standard input")?;
+    Ok(content)
+}
+
+/// Parse a recording from standard input.
+pub fn parse_stdin() -> Result<AsciicastFile> {
+    AsciicastFile::parse_str(&read_stdin()?)
+        .context("Failed to parse asciicast from standard input")
+}
+
+/// Fail if `file` is standard input, for commands that write back to it.
+///
+/// `action` completes the sentence "Cannot ... standard input", e.g.
+/// `"add markers to"`. `hint` suggests how to proceed instead.
+pub fn reject_stdin(file: &str, action: &str, hint: &str) -> Result<()> {
+    if is_stdin(file) {
+        bail!("Cannot {} standard input. {}", action, hint);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dash_means_stdin() {
+        assert!(is_stdin("-"));
+        assert!(!is_stdin("session.cast"));
+        assert!(!is_stdin("--"));
+    }
+
+    #[test]
+    fn reject_stdin_only_fails_for_dash() {
+        assert!(reject_stdin("session.cast", "add markers to", "").is_ok());
+
+        let err = reject_stdin("-", "add markers to", "Pass a .cast file path instead.")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Cannot add markers to standard input. Pass a .cast file path instead."
+        );
+    }
+}
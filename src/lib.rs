@@ -18,6 +18,10 @@ pub mod shell;
 pub mod storage;
 pub mod terminal;
 pub mod tui;
+pub mod util;
+
+#[cfg(feature = "gif-export")]
+pub mod export;
 
 pub use asciicast::{AsciicastFile, Event, EventType, Header, MarkerInfo, MarkerManager};
 pub use config::Config;
@@ -241,6 +241,26 @@ fn print_themed_help(err: &clap::Error) {
     print!("{}", colored);
 }
 
+/// Find the `--color` choice in the raw arguments.
+///
+/// Help is printed from clap's parse error, before any parsed `Cli` exists,
+/// so the flag is looked up by hand. Unknown values fall back to auto and
+/// are reported by clap afterwards.
+fn color_choice_from_args(args: &[String]) -> theme::ColorChoice {
+    use clap::ValueEnum;
+
+    let value = args.iter().enumerate().rev().find_map(|(i, arg)| {
+        if arg == "--color" {
+            args.get(i + 1).map(String::as_str)
+        } else {
+            arg.strip_prefix("--color=")
+        }
+    });
+    value
+        .and_then(|v| theme::ColorChoice::from_str(v, true).ok())
+        .unwrap_or_default()
+}
+
 #[cfg(not(tarpaulin_include))]
 fn main() -> Result<()> {
     // Check for interactive TUI help
@@ -248,9 +268,18 @@ fn main() -> Result<()> {
         return show_tui_help();
     }
 
+    let args: Vec<String> = std::env::args().collect();
+    let color = color_choice_from_args(&args);
+    theme::init_color(color);
+
     // Build command with styles, logo, and custom version
     let cmd = Cli::command()
         .styles(build_cli_styles())
+        .color(match color {
+            theme::ColorChoice::Auto => clap::ColorChoice::Auto,
+            theme::ColorChoice::Always => clap::ColorChoice::Always,
+            theme::ColorChoice::Never => clap::ColorChoice::Never,
+        })
         .before_help(build_logo())
         .version(build_version());
 
@@ -289,10 +318,20 @@ fn main() -> Result<()> {
             commands::record::handle(&agent, name.as_deref(), &args)
         }
         Commands::Status => commands::status::handle(),
-        Commands::Cleanup { agent, older_than } => {
-            commands::cleanup::handle(agent.as_deref(), older_than)
-        }
-        Commands::List { agent } => commands::list::handle(agent.as_deref()),
+        Commands::Storage { top, json } => commands::storage::handle(top, json),
+        Commands::Cleanup {
+            agent,
+            older_than,
+            dry_run,
+            yes,
+        } => commands::cleanup::handle(agent.as_deref(), older_than, dry_run, yes),
+        Commands::List {
+            agent,
+            filter_agent,
+            json,
+            sort,
+            asc,
+        } => commands::list::handle(agent.or(filter_agent).as_deref(), json, sort, asc),
         Commands::Analyze {
             file,
             agent,
@@ -303,6 +342,11 @@ fn main() -> Result<()> {
             debug,
             output,
             fast,
+            output_format,
+            dry_run,
+            dump_content,
+            segments_only,
+            full,
             wait,
         } => commands::analyze::handle(
             &file,
@@ -314,15 +358,49 @@ fn main() -> Result<()> {
             debug,
             output,
             fast,
+            output_format,
+            dry_run,
+            dump_content,
+            segments_only,
+            full,
             wait,
         ),
-        Commands::Play { file } => commands::play::handle(&file),
+        Commands::Doctor { timeout } => commands::doctor::handle(timeout),
+        Commands::Play {
+            file,
+            resume,
+            skip_idle,
+            speed,
+            loop_playback,
+            no_loop,
+        } => {
+            let loop_playback = match (loop_playback, no_loop) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            };
+            commands::play::handle(&file, resume, skip_idle, speed, loop_playback)
+        }
         Commands::Copy { file } => commands::copy::handle(&file),
+        Commands::Validate { strict, files } => commands::validate::handle(&files, strict),
+        Commands::Info { json, file } => commands::info::handle(&file, json),
+        Commands::Diff {
+            markers_only,
+            old,
+            new,
+        } => commands::diff::handle(&old, &new, markers_only),
+        Commands::Export {
+            format,
+            fps,
+            output,
+            file,
+        } => commands::export::handle(&file, format, fps, output.as_deref()),
         Commands::Marker(cmd) => match cmd {
             MarkerCommands::Add { file, time, label } => {
                 commands::marker::handle_add(&file, time, &label)
             }
             MarkerCommands::List { file } => commands::marker::handle_list(&file),
+            MarkerCommands::Remove { file, index } => commands::marker::handle_remove(&file, index),
         },
         Commands::Agents(cmd) => match cmd {
             AgentCommands::List => commands::agents::handle_list(),
@@ -338,6 +416,9 @@ fn main() -> Result<()> {
         Commands::Config(cmd) => match cmd {
             ConfigCommands::Show => commands::config::handle_show(),
             ConfigCommands::Edit => commands::config::handle_edit(),
+            ConfigCommands::Validate => commands::config::handle_validate(),
+            ConfigCommands::Get { key } => commands::config::handle_get(&key),
+            ConfigCommands::Set { key, value } => commands::config::handle_set(&key, &value),
             ConfigCommands::Migrate { yes } => commands::config::handle_migrate(yes),
             ConfigCommands::Reset { yes } => commands::config::handle_reset(yes),
         },
@@ -348,6 +429,11 @@ fn main() -> Result<()> {
         },
         Commands::Optimize {
             remove_silence,
+            speed,
+            redact,
+            dedupe_resizes,
+            from,
+            to,
             output,
             file,
         } => {
@@ -362,12 +448,31 @@ fn main() -> Result<()> {
                 _ => None, // No value provided, will use header or default
             };
 
-            // Currently only silence removal is supported
-            if remove_silence.is_none() {
-                anyhow::bail!("No optimization specified. Use --remove-silence to remove silence.");
+            let trimming = from.is_some() || to.is_some();
+            if remove_silence.is_none()
+                && speed.is_none()
+                && !redact
+                && !dedupe_resizes
+                && !trimming
+            {
+                anyhow::bail!(
+                    "No optimization specified. Use --remove-silence to remove silence, --speed to change speed, --from/--to to trim, --dedupe-resizes to drop redundant resizes or --redact to redact secrets."
+                );
             }
 
-            commands::transform::handle_remove_silence(&file, threshold, output.as_deref())
+            commands::transform::handle_optimize(
+                &file,
+                &commands::transform::OptimizeOptions {
+                    remove_silence: remove_silence.is_some(),
+                    threshold,
+                    speed,
+                    redact,
+                    dedupe_resizes,
+                    from,
+                    to,
+                    output: output.as_deref(),
+                },
+            )
         }
         Commands::Completions {
             shell,
@@ -390,7 +495,9 @@ mod tests {
     fn cli_cleanup_parses_with_no_args() {
         let cli = Cli::try_parse_from(["agr", "cleanup"]).unwrap();
         match cli.command {
-            Commands::Cleanup { agent, older_than } => {
+            Commands::Cleanup {
+                agent, older_than, ..
+            } => {
                 assert!(agent.is_none());
                 assert!(older_than.is_none());
             }
@@ -402,7 +509,9 @@ mod tests {
     fn cli_cleanup_parses_with_agent_flag() {
         let cli = Cli::try_parse_from(["agr", "cleanup", "--agent", "claude"]).unwrap();
         match cli.command {
-            Commands::Cleanup { agent, older_than } => {
+            Commands::Cleanup {
+                agent, older_than, ..
+            } => {
                 assert_eq!(agent, Some("claude".to_string()));
                 assert!(older_than.is_none());
             }
@@ -414,7 +523,9 @@ mod tests {
     fn cli_cleanup_parses_with_older_than_flag() {
         let cli = Cli::try_parse_from(["agr", "cleanup", "--older-than", "30"]).unwrap();
         match cli.command {
-            Commands::Cleanup { agent, older_than } => {
+            Commands::Cleanup {
+                agent, older_than, ..
+            } => {
                 assert!(agent.is_none());
                 assert_eq!(older_than, Some(30));
             }
@@ -427,7 +538,9 @@ mod tests {
         let cli = Cli::try_parse_from(["agr", "cleanup", "--agent", "codex", "--older-than", "60"])
             .unwrap();
         match cli.command {
-            Commands::Cleanup { agent, older_than } => {
+            Commands::Cleanup {
+                agent, older_than, ..
+            } => {
                 assert_eq!(agent, Some("codex".to_string()));
                 assert_eq!(older_than, Some(60));
             }
@@ -435,6 +548,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_cleanup_parses_age_units_and_batch_flags() {
+        let cli =
+            Cli::try_parse_from(["agr", "cleanup", "--older-than", "4w", "--dry-run", "--yes"])
+                .unwrap();
+        match cli.command {
+            Commands::Cleanup {
+                older_than,
+                dry_run,
+                yes,
+                ..
+            } => {
+                assert_eq!(older_than, Some(28));
+                assert!(dry_run);
+                assert!(yes);
+            }
+            _ => panic!("Expected Cleanup command"),
+        }
+
+        let cli = Cli::try_parse_from(["agr", "cleanup", "--older-than", "30d"]).unwrap();
+        match cli.command {
+            Commands::Cleanup { older_than, .. } => assert_eq!(older_than, Some(30)),
+            _ => panic!("Expected Cleanup command"),
+        }
+
+        assert!(Cli::try_parse_from(["agr", "cleanup", "--older-than", "30x"]).is_err());
+    }
+
+    #[test]
+    fn cli_storage_parses_defaults_and_flags() {
+        let cli = Cli::try_parse_from(["agr", "storage"]).unwrap();
+        match cli.command {
+            Commands::Storage { top, json } => {
+                assert_eq!(top, 10);
+                assert!(!json);
+            }
+            _ => panic!("Expected Storage command"),
+        }
+
+        let cli = Cli::try_parse_from(["agr", "storage", "--top", "3", "--json"]).unwrap();
+        match cli.command {
+            Commands::Storage { top, json } => {
+                assert_eq!(top, 3);
+                assert!(json);
+            }
+            _ => panic!("Expected Storage command"),
+        }
+    }
+
     #[test]
     fn cli_shell_status_parses() {
         let cli = Cli::try_parse_from(["agr", "shell", "status"]).unwrap();
@@ -476,6 +638,11 @@ mod tests {
                 debug: _,
                 output: _,
                 fast,
+                output_format,
+                dry_run,
+                dump_content,
+                segments_only,
+                full,
                 wait,
             } => {
                 assert_eq!(file, "session.cast");
@@ -485,6 +652,11 @@ mod tests {
                 assert!(!no_parallel);
                 assert!(!curate);
                 assert!(!fast);
+                assert!(output_format.is_none());
+                assert!(!dry_run);
+                assert!(!dump_content);
+                assert!(!segments_only);
+                assert!(!full);
                 assert!(!wait);
             }
             _ => panic!("Expected Analyze command"),
@@ -568,6 +740,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_analyze_parses_with_output_format_flag() {
+        let cli = Cli::try_parse_from([
+            "agr",
+            "analyze",
+            "session.cast",
+            "--output-format",
+            "markdown",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Analyze { output_format, .. } => {
+                assert_eq!(output_format, Some(agr::cli::OutputFormat::Markdown));
+            }
+            _ => panic!("Expected Analyze command"),
+        }
+    }
+
+    #[test]
+    fn cli_analyze_parses_with_dry_run_flag() {
+        let cli = Cli::try_parse_from(["agr", "analyze", "session.cast", "--dry-run"]).unwrap();
+        match cli.command {
+            Commands::Analyze { dry_run, .. } => assert!(dry_run),
+            _ => panic!("Expected Analyze command"),
+        }
+    }
+
+    #[test]
+    fn cli_analyze_parses_with_dump_content_flags() {
+        let cli = Cli::try_parse_from([
+            "agr",
+            "analyze",
+            "session.cast",
+            "--dump-content",
+            "--segments-only",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Analyze {
+                dump_content,
+                segments_only,
+                ..
+            } => {
+                assert!(dump_content);
+                assert!(segments_only);
+            }
+            _ => panic!("Expected Analyze command"),
+        }
+    }
+
+    #[test]
+    fn cli_analyze_segments_only_requires_dump_content() {
+        let result = Cli::try_parse_from(["agr", "analyze", "session.cast", "--segments-only"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn cli_analyze_parses_with_all_flags() {
         let cli = Cli::try_parse_from([
@@ -597,6 +825,11 @@ mod tests {
                 debug,
                 output,
                 fast,
+                output_format: _,
+                dry_run: _,
+                dump_content: _,
+                segments_only: _,
+                full: _,
                 wait,
             } => {
                 assert_eq!(file, "session.cast");
@@ -746,7 +979,7 @@ mod tests {
         // Test that 'ls' is accepted as an alias for 'list'
         let cli = Cli::try_parse_from(["agr", "ls"]).unwrap();
         match cli.command {
-            Commands::List { agent } => {
+            Commands::List { agent, .. } => {
                 assert!(agent.is_none());
             }
             _ => panic!("Expected List command from 'ls' alias"),
@@ -757,13 +990,47 @@ mod tests {
     fn cli_ls_alias_accepts_agent_argument() {
         let cli = Cli::try_parse_from(["agr", "ls", "claude"]).unwrap();
         match cli.command {
-            Commands::List { agent } => {
+            Commands::List { agent, .. } => {
                 assert_eq!(agent, Some("claude".to_string()));
             }
             _ => panic!("Expected List command from 'ls' alias with agent"),
         }
     }
 
+    #[test]
+    fn cli_ls_parses_json_sort_and_filter_agent() {
+        let cli = Cli::try_parse_from([
+            "agr",
+            "ls",
+            "--json",
+            "--sort",
+            "size",
+            "--asc",
+            "--filter-agent",
+            "codex",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::List {
+                agent,
+                filter_agent,
+                json,
+                sort,
+                asc,
+            } => {
+                assert!(agent.is_none());
+                assert_eq!(filter_agent, Some("codex".to_string()));
+                assert!(json);
+                assert_eq!(sort, Some(agr::tui::widgets::SortField::Size));
+                assert!(asc);
+            }
+            _ => panic!("Expected List command"),
+        }
+        assert!(Cli::try_parse_from(["agr", "ls", "--sort", "size"]).is_err());
+        assert!(Cli::try_parse_from(["agr", "ls", "--json", "--sort", "bogus"]).is_err());
+        assert!(Cli::try_parse_from(["agr", "ls", "claude", "--filter-agent", "codex"]).is_err());
+    }
+
     #[test]
     fn cli_list_alias_is_visible() {
         // The 'ls' alias should be visible in help
@@ -806,6 +1073,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_markers_alias_add_parses_mm_ss() {
+        let cli =
+            Cli::try_parse_from(["agr", "markers", "add", "test.cast", "1:30.5", "label"]).unwrap();
+        match cli.command {
+            Commands::Marker(MarkerCommands::Add { time, .. }) => {
+                assert!((time - 90.5).abs() < f64::EPSILON);
+            }
+            _ => panic!("Expected Marker Add command"),
+        }
+    }
+
+    #[test]
+    fn cli_marker_add_rejects_invalid_time() {
+        assert!(Cli::try_parse_from(["agr", "marker", "add", "t.cast", "1:75", "x"]).is_err());
+        assert!(Cli::try_parse_from(["agr", "marker", "add", "t.cast", "-3", "x"]).is_err());
+    }
+
+    #[test]
+    fn cli_markers_remove_parses() {
+        let cli = Cli::try_parse_from(["agr", "markers", "remove", "test.cast", "2"]).unwrap();
+        match cli.command {
+            Commands::Marker(MarkerCommands::Remove { file, index }) => {
+                assert_eq!(file, "test.cast");
+                assert_eq!(index, 2);
+            }
+            _ => panic!("Expected Marker Remove command"),
+        }
+        assert!(Cli::try_parse_from(["agr", "markers", "remove", "test.cast", "0"]).is_err());
+    }
+
     #[test]
     fn cli_record_parses_with_agent_only() {
         let cli = Cli::try_parse_from(["agr", "record", "claude"]).unwrap();
@@ -928,6 +1226,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_config_validate_parses() {
+        let cli = Cli::try_parse_from(["agr", "config", "validate"]).unwrap();
+        match cli.command {
+            Commands::Config(ConfigCommands::Validate) => {}
+            _ => panic!("Expected Config Validate command"),
+        }
+    }
+
+    #[test]
+    fn cli_config_get_parses() {
+        let cli = Cli::try_parse_from(["agr", "config", "get", "analysis.timeout"]).unwrap();
+        match cli.command {
+            Commands::Config(ConfigCommands::Get { key }) => {
+                assert_eq!(key, "analysis.timeout");
+            }
+            _ => panic!("Expected Config Get command"),
+        }
+    }
+
+    #[test]
+    fn cli_config_set_parses() {
+        let cli = Cli::try_parse_from(["agr", "config", "set", "analysis.timeout", "300"]).unwrap();
+        match cli.command {
+            Commands::Config(ConfigCommands::Set { key, value }) => {
+                assert_eq!(key, "analysis.timeout");
+                assert_eq!(value, "300");
+            }
+            _ => panic!("Expected Config Set command"),
+        }
+    }
+
     #[test]
     fn cli_config_reset_parses() {
         let cli = Cli::try_parse_from(["agr", "config", "reset"]).unwrap();
@@ -950,6 +1280,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_doctor_parses_with_default_timeout() {
+        let cli = Cli::try_parse_from(["agr", "doctor"]).unwrap();
+        match cli.command {
+            Commands::Doctor { timeout } => assert_eq!(timeout, 30),
+            _ => panic!("Expected Doctor command"),
+        }
+
+        let cli = Cli::try_parse_from(["agr", "doctor", "--timeout", "5"]).unwrap();
+        match cli.command {
+            Commands::Doctor { timeout } => assert_eq!(timeout, 5),
+            _ => panic!("Expected Doctor command"),
+        }
+    }
+
     #[test]
     fn cli_status_parses() {
         let cli = Cli::try_parse_from(["agr", "status"]).unwrap();
@@ -963,18 +1308,76 @@ mod tests {
     fn cli_play_parses_with_file() {
         let cli = Cli::try_parse_from(["agr", "play", "session.cast"]).unwrap();
         match cli.command {
-            Commands::Play { file } => {
+            Commands::Play { file, .. } => {
                 assert_eq!(file, "session.cast");
             }
             _ => panic!("Expected Play command"),
         }
     }
 
+    #[test]
+    fn cli_play_parses_resume_flag() {
+        let cli = Cli::try_parse_from(["agr", "play", "--resume", "session.cast"]).unwrap();
+        match cli.command {
+            Commands::Play { file, resume, .. } => {
+                assert_eq!(file, "session.cast");
+                assert!(resume);
+            }
+            _ => panic!("Expected Play command"),
+        }
+    }
+
+    #[test]
+    fn cli_play_parses_skip_idle() {
+        let cli =
+            Cli::try_parse_from(["agr", "play", "--skip-idle", "2.5", "session.cast"]).unwrap();
+        match cli.command {
+            Commands::Play { skip_idle, .. } => assert_eq!(skip_idle, Some(2.5)),
+            _ => panic!("Expected Play command"),
+        }
+        assert!(Cli::try_parse_from(["agr", "play", "--skip-idle", "0", "session.cast"]).is_err());
+    }
+
+    #[test]
+    fn cli_play_parses_speed_and_loop() {
+        let cli =
+            Cli::try_parse_from(["agr", "play", "--speed", "2", "--loop", "session.cast"]).unwrap();
+        match cli.command {
+            Commands::Play {
+                speed,
+                loop_playback,
+                ..
+            } => {
+                assert_eq!(speed, Some(2.0));
+                assert!(loop_playback);
+            }
+            _ => panic!("Expected Play command"),
+        }
+        assert!(Cli::try_parse_from(["agr", "play", "--speed", "32", "session.cast"]).is_err());
+    }
+
+    #[test]
+    fn cli_play_last_loop_flag_wins() {
+        let cli =
+            Cli::try_parse_from(["agr", "play", "--loop", "--no-loop", "session.cast"]).unwrap();
+        match cli.command {
+            Commands::Play {
+                loop_playback,
+                no_loop,
+                ..
+            } => {
+                assert!(!loop_playback);
+                assert!(no_loop);
+            }
+            _ => panic!("Expected Play command"),
+        }
+    }
+
     #[test]
     fn cli_play_parses_with_path() {
         let cli = Cli::try_parse_from(["agr", "play", "/path/to/session.cast"]).unwrap();
         match cli.command {
-            Commands::Play { file } => {
+            Commands::Play { file, .. } => {
                 assert_eq!(file, "/path/to/session.cast");
             }
             _ => panic!("Expected Play command"),
@@ -985,7 +1388,7 @@ mod tests {
     fn cli_play_parses_with_short_format() {
         let cli = Cli::try_parse_from(["agr", "play", "claude/session.cast"]).unwrap();
         match cli.command {
-            Commands::Play { file } => {
+            Commands::Play { file, .. } => {
                 assert_eq!(file, "claude/session.cast");
             }
             _ => panic!("Expected Play command"),
@@ -1003,6 +1406,165 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cli_export_parses_with_defaults() {
+        let cli = Cli::try_parse_from(["agr", "export", "session.cast"]).unwrap();
+        match cli.command {
+            Commands::Export {
+                format,
+                fps,
+                output,
+                file,
+            } => {
+                assert_eq!(format, agr::cli::ExportFormat::Gif);
+                assert_eq!(fps, 10);
+                assert!(output.is_none());
+                assert_eq!(file, "session.cast");
+            }
+            _ => panic!("Expected Export command"),
+        }
+    }
+
+    #[test]
+    fn cli_export_parses_fps_and_output() {
+        let cli = Cli::try_parse_from([
+            "agr", "export", "--format", "gif", "--fps", "25", "-o", "demo.gif", "s.cast",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Export { fps, output, .. } => {
+                assert_eq!(fps, 25);
+                assert_eq!(output.as_deref(), Some("demo.gif"));
+            }
+            _ => panic!("Expected Export command"),
+        }
+    }
+
+    #[test]
+    fn cli_export_rejects_out_of_range_fps() {
+        assert!(Cli::try_parse_from(["agr", "export", "--fps", "0", "s.cast"]).is_err());
+        assert!(Cli::try_parse_from(["agr", "export", "--fps", "60", "s.cast"]).is_err());
+    }
+
+    #[test]
+    fn cli_validate_parses_multiple_files() {
+        let cli = Cli::try_parse_from(["agr", "validate", "--strict", "a.cast", "b.cast"]).unwrap();
+        match cli.command {
+            Commands::Validate { strict, files } => {
+                assert!(strict);
+                assert_eq!(files, vec!["a.cast", "b.cast"]);
+            }
+            _ => panic!("Expected Validate command"),
+        }
+    }
+
+    #[test]
+    fn cli_validate_requires_a_file() {
+        assert!(Cli::try_parse_from(["agr", "validate"]).is_err());
+    }
+
+    #[test]
+    fn cli_info_parses_json_flag() {
+        let cli = Cli::try_parse_from(["agr", "info", "--json", "s.cast"]).unwrap();
+        match cli.command {
+            Commands::Info { json, file } => {
+                assert!(json);
+                assert_eq!(file, "s.cast");
+            }
+            _ => panic!("Expected Info command"),
+        }
+    }
+
+    #[test]
+    fn cli_optimize_parses_speed() {
+        let cli = Cli::try_parse_from(["agr", "optimize", "--speed", "2.5", "s.cast"]).unwrap();
+        match cli.command {
+            Commands::Optimize {
+                remove_silence,
+                speed,
+                ..
+            } => {
+                assert!(remove_silence.is_none());
+                assert_eq!(speed, Some(2.5));
+            }
+            _ => panic!("Expected Optimize command"),
+        }
+    }
+
+    #[test]
+    fn cli_optimize_parses_dedupe_resizes() {
+        let cli = Cli::try_parse_from(["agr", "optimize", "--dedupe-resizes", "s.cast"]).unwrap();
+        match cli.command {
+            Commands::Optimize {
+                dedupe_resizes,
+                redact,
+                ..
+            } => {
+                assert!(dedupe_resizes);
+                assert!(!redact);
+            }
+            _ => panic!("Expected Optimize command"),
+        }
+    }
+
+    #[test]
+    fn cli_optimize_parses_trim_range() {
+        let cli =
+            Cli::try_parse_from(["agr", "optimize", "--from", "1:30", "--to", "200", "s.cast"])
+                .unwrap();
+        match cli.command {
+            Commands::Optimize { from, to, .. } => {
+                assert_eq!(from, Some(90.0));
+                assert_eq!(to, Some(200.0));
+            }
+            _ => panic!("Expected Optimize command"),
+        }
+        assert!(Cli::try_parse_from(["agr", "optimize", "--from", "1:75", "s.cast"]).is_err());
+    }
+
+    #[test]
+    fn cli_optimize_parses_redact() {
+        let cli = Cli::try_parse_from(["agr", "optimize", "--redact", "s.cast"]).unwrap();
+        match cli.command {
+            Commands::Optimize { redact, speed, .. } => {
+                assert!(redact);
+                assert!(speed.is_none());
+            }
+            _ => panic!("Expected Optimize command"),
+        }
+    }
+
+    #[test]
+    fn cli_color_defaults_to_auto_and_is_global() {
+        let cli = Cli::try_parse_from(["agr", "status"]).unwrap();
+        assert_eq!(cli.color, theme::ColorChoice::Auto);
+
+        let cli = Cli::try_parse_from(["agr", "config", "show", "--color", "never"]).unwrap();
+        assert_eq!(cli.color, theme::ColorChoice::Never);
+    }
+
+    #[test]
+    fn color_choice_from_args_finds_flag() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            color_choice_from_args(&args(&["agr", "--help"])),
+            theme::ColorChoice::Auto
+        );
+        assert_eq!(
+            color_choice_from_args(&args(&["agr", "--color", "always", "--help"])),
+            theme::ColorChoice::Always
+        );
+        assert_eq!(
+            color_choice_from_args(&args(&["agr", "status", "--color=never"])),
+            theme::ColorChoice::Never
+        );
+        assert_eq!(
+            color_choice_from_args(&args(&["agr", "--color", "bogus"])),
+            theme::ColorChoice::Auto
+        );
+    }
+
     #[test]
     fn cli_copy_parses_with_path() {
         let cli = Cli::try_parse_from(["agr", "copy", "/path/to/session.cast"]).unwrap();
@@ -8,7 +8,11 @@ use std::io::{self, Write};
 use std::time::{Duration, Instant};
 
 use crate::asciicast::AsciicastFile;
-use crate::player::playback::{find_event_index_at_time, seek_to_time};
+use crate::clipboard::copy_text_to_clipboard;
+use crate::player::playback::{
+    find_event_index_at_time, find_in_cells, find_next_output_time, find_output_match,
+    find_previous_output_time, seek_to_time, SearchDirection,
+};
 use crate::player::state::{InputResult, MarkerPosition, PlaybackState};
 use crate::terminal::TerminalBuffer;
 
@@ -34,6 +38,19 @@ pub fn handle_key_event(
         return InputResult::Continue;
     }
 
+    // While the search prompt is open, keys edit the query
+    if state.search_prompt.is_some() {
+        return handle_search_prompt_key(
+            key,
+            state,
+            buffer,
+            cast,
+            total_duration,
+            rec_cols,
+            rec_rows,
+        );
+    }
+
     match key.code {
         // === Quit ===
         KeyCode::Char('q') => InputResult::Quit,
@@ -59,6 +76,10 @@ pub fn handle_key_event(
             state.toggle_free_mode(buffer.cursor_row());
             InputResult::Continue
         }
+        KeyCode::Char('y') if state.free_mode => {
+            handle_copy_free_selection(state, buffer);
+            InputResult::Continue
+        }
 
         // === Playback controls ===
         KeyCode::Char(' ') => {
@@ -73,26 +94,103 @@ pub fn handle_key_event(
             state.speed_down();
             InputResult::Continue
         }
+        KeyCode::Char('l') => {
+            state.toggle_loop();
+            InputResult::Continue
+        }
+        KeyCode::Char('C') => {
+            state.request_clear_saved_position();
+            InputResult::Continue
+        }
+        KeyCode::Char('i') => {
+            state.toggle_show_input();
+            InputResult::Continue
+        }
 
         // === Resize terminal ===
         KeyCode::Char('r') => {
             handle_resize_to_recording(state, buffer);
             InputResult::Continue
         }
+        KeyCode::Char('c') => {
+            state.toggle_center_viewport();
+            InputResult::Continue
+        }
 
         // === Marker navigation ===
         KeyCode::Char('m') => {
             handle_jump_to_marker(state, buffer, cast, markers, rec_cols, rec_rows);
             InputResult::Continue
         }
+        KeyCode::Char('P') => {
+            state.toggle_pause_at_markers();
+            InputResult::Continue
+        }
+
+        // === Search ===
+        KeyCode::Char('/') => {
+            state.open_search_prompt();
+            InputResult::Continue
+        }
+        KeyCode::Char('n') => {
+            handle_search(
+                state,
+                buffer,
+                cast,
+                SearchDirection::Forward,
+                total_duration,
+                rec_cols,
+                rec_rows,
+            );
+            InputResult::Continue
+        }
+        KeyCode::Char('N') => {
+            handle_search(
+                state,
+                buffer,
+                cast,
+                SearchDirection::Backward,
+                total_duration,
+                rec_cols,
+                rec_rows,
+            );
+            InputResult::Continue
+        }
 
         // === Seeking ===
+        // While paused these step one output event at a time instead
         KeyCode::Char('<') | KeyCode::Char(',') => {
-            handle_seek_backward(state, buffer, cast, 5.0, rec_cols, rec_rows);
+            if state.paused {
+                handle_step_backward(state, buffer, cast, rec_cols, rec_rows);
+            } else {
+                let step = state.seek_step_secs;
+                handle_seek_backward(state, buffer, cast, step, rec_cols, rec_rows);
+            }
             InputResult::Continue
         }
         KeyCode::Char('>') | KeyCode::Char('.') => {
-            handle_seek_forward(state, buffer, cast, 5.0, total_duration, rec_cols, rec_rows);
+            if state.paused {
+                handle_step_forward(state, buffer, cast, total_duration, rec_cols, rec_rows);
+            } else {
+                let step = state.seek_step_secs;
+                handle_seek_forward(
+                    state,
+                    buffer,
+                    cast,
+                    step,
+                    total_duration,
+                    rec_cols,
+                    rec_rows,
+                );
+            }
+            InputResult::Continue
+        }
+        KeyCode::Char('[') => {
+            state.seek_step_down();
+            InputResult::Continue
+        }
+        KeyCode::Char(']') => {
+            state.seek_step_up();
             InputResult::Continue
         }
         KeyCode::Home => {
@@ -103,6 +201,19 @@ pub fn handle_key_event(
             handle_seek_to_end(state, buffer, cast, total_duration, rec_cols, rec_rows);
             InputResult::Continue
         }
+        KeyCode::Char(digit @ '0'..='9') => {
+            let tenths = digit.to_digit(10).unwrap_or(0);
+            handle_jump_to_percent(
+                state,
+                buffer,
+                cast,
+                tenths,
+                total_duration,
+                rec_cols,
+                rec_rows,
+            );
+            InputResult::Continue
+        }
 
         // === Arrow keys (context-dependent) ===
         KeyCode::Left => {
@@ -130,11 +241,20 @@ pub fn handle_key_event(
             InputResult::Continue
         }
         KeyCode::Up => {
-            handle_up_key(state);
+            let selecting = update_free_selection(state, key.modifiers);
+            handle_up_key(state, buffer);
+            if selecting {
+                // The highlight spans several lines, so redraw the viewport
+                state.free_line_only = false;
+            }
             InputResult::Continue
         }
         KeyCode::Down => {
+            let selecting = update_free_selection(state, key.modifiers);
             handle_down_key(state, buffer);
+            if selecting {
+                state.free_line_only = false;
+            }
             InputResult::Continue
         }
 
@@ -196,7 +316,14 @@ fn handle_jump_to_marker(
     rec_rows: u32,
 ) {
     if let Some(next) = markers.iter().find(|m| m.time > state.current_time() + 0.1) {
-        seek_to_time(buffer, cast, next.time, rec_cols, rec_rows);
+        seek_to_time(
+            buffer,
+            &mut state.annotations,
+            cast,
+            next.time,
+            rec_cols,
+            rec_rows,
+        );
         state.set_current_time(next.time, f64::MAX);
         state.set_time_offset(state.current_time());
         state.start_time = Instant::now();
@@ -207,8 +334,169 @@ fn handle_jump_to_marker(
     }
 }
 
+/// Handle a key while the search prompt is open.
+///
+/// Enter searches forward for the typed query, Esc cancels and
+/// Ctrl+C still quits.
+fn handle_search_prompt_key(
+    key: KeyEvent,
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    total_duration: f64,
+    rec_cols: u32,
+    rec_rows: u32,
+) -> InputResult {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            return InputResult::Quit;
+        }
+        KeyCode::Enter => {
+            let submitted = state.submit_search_prompt();
+            if submitted {
+                handle_search(
+                    state,
+                    buffer,
+                    cast,
+                    SearchDirection::Forward,
+                    total_duration,
+                    rec_cols,
+                    rec_rows,
+                );
+            }
+        }
+        KeyCode::Esc => state.cancel_search_prompt(),
+        KeyCode::Backspace => {
+            if let Some(query) = state.search_prompt.as_mut() {
+                query.pop();
+            }
+            state.needs_render = true;
+        }
+        KeyCode::Char(c) => {
+            if let Some(query) = state.search_prompt.as_mut() {
+                query.push(c);
+            }
+            state.needs_render = true;
+        }
+        _ => {}
+    }
+    InputResult::Continue
+}
+
+/// Handle jumping to the next or previous output matching the active search.
+///
+/// Playback pauses on the match and the viewport scrolls to show it.
+fn handle_search(
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    direction: SearchDirection,
+    total_duration: f64,
+    rec_cols: u32,
+    rec_rows: u32,
+) {
+    let Some(query) = state.search_query.clone() else {
+        state.set_status_message("no search (press /)");
+        return;
+    };
+    let Some(target) = find_output_match(cast, &query, state.current_time(), direction) else {
+        state.set_status_message(format!("not found: {}", query));
+        return;
+    };
+
+    step_to_time(
+        state,
+        buffer,
+        cast,
+        target,
+        total_duration,
+        rec_cols,
+        rec_rows,
+    );
+    state.paused = true;
+    scroll_to_match(state, buffer, &query);
+}
+
+/// Scroll the viewport so the last on-screen match of `query` is visible.
+fn scroll_to_match(state: &mut PlaybackState, buffer: &TerminalBuffer, query: &str) {
+    let Some(row) = (0..buffer.height()).rev().find(|&row| {
+        buffer
+            .row(row)
+            .is_some_and(|cells| !find_in_cells(cells, query).is_empty())
+    }) else {
+        return;
+    };
+    let offset = state.view_row_offset();
+    if row < offset || row >= offset + state.view_rows {
+        let max_offset = buffer.height().saturating_sub(state.view_rows);
+        state.set_view_row_offset(row.saturating_sub(state.view_rows / 2), max_offset);
+    }
+}
+
+/// Handle stepping forward to the next output event (paused only).
+fn handle_step_forward(
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    total_duration: f64,
+    rec_cols: u32,
+    rec_rows: u32,
+) {
+    if let Some(target) = find_next_output_time(cast, state.current_time()) {
+        step_to_time(
+            state,
+            buffer,
+            cast,
+            target,
+            total_duration,
+            rec_cols,
+            rec_rows,
+        );
+    }
+}
+
+/// Handle stepping back to before the last shown output event (paused only).
+///
+/// The buffer is rebuilt from scratch so the previous screen state is exact.
+fn handle_step_backward(
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    rec_cols: u32,
+    rec_rows: u32,
+) {
+    let target = find_previous_output_time(cast, state.current_time());
+    step_to_time(state, buffer, cast, target, f64::MAX, rec_cols, rec_rows);
+}
+
+/// Replay the buffer up to an event boundary and park playback there.
+fn step_to_time(
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    target: f64,
+    max_time: f64,
+    rec_cols: u32,
+    rec_rows: u32,
+) {
+    seek_to_time(
+        buffer,
+        &mut state.annotations,
+        cast,
+        target,
+        rec_cols,
+        rec_rows,
+    );
+    state.set_current_time(target, max_time);
+    state.set_time_offset(state.current_time());
+    state.start_time = Instant::now();
+    let (idx, cumulative) = find_event_index_at_time(cast, state.current_time());
+    state.set_event_position(idx, cumulative, cast.events.len());
+    state.needs_render = true;
+}
+
 /// Handle seeking backward by a given amount.
-fn handle_seek_backward(
+pub(super) fn handle_seek_backward(
     state: &mut PlaybackState,
     buffer: &mut TerminalBuffer,
     cast: &AsciicastFile,
@@ -217,7 +505,14 @@ fn handle_seek_backward(
     rec_rows: u32,
 ) {
     let new_time = (state.current_time() - amount).max(0.0);
-    seek_to_time(buffer, cast, new_time, rec_cols, rec_rows);
+    seek_to_time(
+        buffer,
+        &mut state.annotations,
+        cast,
+        new_time,
+        rec_cols,
+        rec_rows,
+    );
     state.set_current_time(new_time, f64::MAX);
     state.set_time_offset(state.current_time());
     state.start_time = Instant::now();
@@ -227,7 +522,7 @@ fn handle_seek_backward(
 }
 
 /// Handle seeking forward by a given amount.
-fn handle_seek_forward(
+pub(super) fn handle_seek_forward(
     state: &mut PlaybackState,
     buffer: &mut TerminalBuffer,
     cast: &AsciicastFile,
@@ -244,22 +539,48 @@ fn handle_seek_forward(
     state.set_event_position(idx, cumulative, cast.events.len());
 
     // Rebuild buffer from scratch for forward seek
-    *buffer = TerminalBuffer::new(rec_cols as usize, rec_rows as usize);
-    let mut cumulative = 0.0f64;
-    for event in &cast.events {
-        cumulative += event.time;
-        if cumulative > state.current_time() {
-            break;
-        }
-        if event.is_output() {
-            buffer.process(&event.data, None);
-        } else if let Some((cols, rows)) = event.parse_resize() {
-            buffer.resize(cols as usize, rows as usize);
-        }
-    }
+    let target = state.current_time();
+    seek_to_time(
+        buffer,
+        &mut state.annotations,
+        cast,
+        target,
+        rec_cols,
+        rec_rows,
+    );
     state.needs_render = true;
 }
 
+/// Jump to `tenths` × 10% of the recording, keeping the play/pause state.
+///
+/// Goes through the regular seek helpers so the buffer is rebuilt the same
+/// way whether the target lies ahead of or behind the current position.
+fn handle_jump_to_percent(
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    tenths: u32,
+    total_duration: f64,
+    rec_cols: u32,
+    rec_rows: u32,
+) {
+    let target = total_duration * f64::from(tenths) / 10.0;
+    let delta = target - state.current_time();
+    if delta >= 0.0 {
+        handle_seek_forward(
+            state,
+            buffer,
+            cast,
+            delta,
+            total_duration,
+            rec_cols,
+            rec_rows,
+        );
+    } else {
+        handle_seek_backward(state, buffer, cast, -delta, rec_cols, rec_rows);
+    }
+}
+
 /// Handle seek to start of recording.
 fn handle_seek_to_start(
     state: &mut PlaybackState,
@@ -268,7 +589,14 @@ fn handle_seek_to_start(
     rec_cols: u32,
     rec_rows: u32,
 ) {
-    seek_to_time(buffer, cast, 0.0, rec_cols, rec_rows);
+    seek_to_time(
+        buffer,
+        &mut state.annotations,
+        cast,
+        0.0,
+        rec_cols,
+        rec_rows,
+    );
     state.set_current_time(0.0, f64::MAX);
     state.set_time_offset(0.0);
     state.start_time = Instant::now();
@@ -287,16 +615,15 @@ fn handle_seek_to_end(
     rec_cols: u32,
     rec_rows: u32,
 ) {
-    *buffer = TerminalBuffer::new(rec_cols as usize, rec_rows as usize);
-
     // Process all events
-    for event in &cast.events {
-        if event.is_output() {
-            buffer.process(&event.data, None);
-        } else if let Some((cols, rows)) = event.parse_resize() {
-            buffer.resize(cols as usize, rows as usize);
-        }
-    }
+    seek_to_time(
+        buffer,
+        &mut state.annotations,
+        cast,
+        f64::INFINITY,
+        rec_cols,
+        rec_rows,
+    );
 
     state.set_current_time(total_duration, total_duration);
     state.set_time_offset(state.current_time());
@@ -323,7 +650,7 @@ fn handle_left_key(
         let step = if modifiers.contains(KeyModifiers::SHIFT) {
             total_duration * 0.05 // 5% jump
         } else {
-            5.0 // 5 seconds
+            state.seek_step_secs
         };
         handle_seek_backward(state, buffer, cast, step, rec_cols, rec_rows);
     }
@@ -348,7 +675,7 @@ fn handle_right_key(
         let step = if modifiers.contains(KeyModifiers::SHIFT) {
             total_duration * 0.05 // 5% jump
         } else {
-            5.0 // 5 seconds
+            state.seek_step_secs
         };
         handle_seek_forward(
             state,
@@ -362,8 +689,40 @@ fn handle_right_key(
     }
 }
 
+/// Extend the free mode selection on Shift+Up/Down, or drop it on a plain arrow.
+///
+/// Returns true if a selection was extended or dropped.
+fn update_free_selection(state: &mut PlaybackState, modifiers: KeyModifiers) -> bool {
+    if !state.free_mode {
+        false
+    } else if modifiers.contains(KeyModifiers::SHIFT) {
+        state.extend_free_selection();
+        true
+    } else {
+        state.clear_free_selection()
+    }
+}
+
+/// Collect the plain text of the lines selected in free mode.
+fn free_selection_lines(state: &PlaybackState, buffer: &TerminalBuffer) -> Vec<String> {
+    state
+        .free_selection()
+        .map_while(|row| buffer.line_text(row))
+        .collect()
+}
+
+/// Copy the lines selected in free mode to the clipboard.
+fn handle_copy_free_selection(state: &mut PlaybackState, buffer: &TerminalBuffer) {
+    let lines = free_selection_lines(state, buffer);
+    match copy_text_to_clipboard(&lines.join("\n")) {
+        Ok(_) if lines.len() == 1 => state.set_status_message("Copied line"),
+        Ok(_) => state.set_status_message(format!("Copied {} lines", lines.len())),
+        Err(e) => state.set_status_message(format!("Copy failed: {}", e)),
+    }
+}
+
 /// Handle up arrow key (free mode or viewport scroll).
-fn handle_up_key(state: &mut PlaybackState) {
+fn handle_up_key(state: &mut PlaybackState, buffer: &TerminalBuffer) {
     if state.free_mode {
         // Move highlight up one line
         let old_offset = state.view_row_offset();
@@ -382,9 +741,7 @@ fn handle_up_key(state: &mut PlaybackState) {
         }
         state.needs_render = true;
     } else if state.viewport_mode {
-        let new_offset = state.view_row_offset().saturating_sub(1);
-        state.set_view_row_offset(new_offset, usize::MAX);
-        state.needs_render = true;
+        state.scroll_view_up(buffer.scrollback_lines().len());
     }
     // In normal mode, up does nothing
 }
@@ -411,9 +768,7 @@ fn handle_down_key(state: &mut PlaybackState, buffer: &TerminalBuffer) {
         state.needs_render = true;
     } else if state.viewport_mode {
         let max_offset = buffer.height().saturating_sub(state.view_rows);
-        let new_offset = state.view_row_offset() + 1;
-        state.set_view_row_offset(new_offset, max_offset);
-        state.needs_render = true;
+        state.scroll_view_down(max_offset);
     }
     // In normal mode, down does nothing
 }
@@ -421,7 +776,7 @@ fn handle_down_key(state: &mut PlaybackState, buffer: &TerminalBuffer) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::asciicast::{AsciicastFile, Event, Header, TermInfo};
+    use crate::asciicast::{AsciicastFile, Event, EventType, Header, TermInfo};
 
     fn create_test_state() -> PlaybackState {
         PlaybackState::new(80, 27)
@@ -436,6 +791,7 @@ mod tests {
                 cols: Some(80),
                 rows: Some(24),
                 term_type: None,
+                theme: None,
             }),
             timestamp: None,
             duration: None,
@@ -763,15 +1119,14 @@ mod tests {
     }
 
     #[test]
-    fn handle_key_event_home_seeks_to_start() {
+    fn handle_key_event_l_toggles_loop() {
         let mut state = create_test_state();
-        state.set_current_time(5.0, 10.0);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
 
         let _ = handle_key_event(
-            create_key_event(KeyCode::Home),
+            create_key_event(KeyCode::Char('l')),
             &mut state,
             &mut buffer,
             &cast,
@@ -781,44 +1136,40 @@ mod tests {
             24,
         );
 
-        assert_eq!(state.current_time(), 0.0);
-        assert_eq!(state.view_row_offset(), 0);
-        assert_eq!(state.view_col_offset(), 0);
+        assert!(state.loop_playback);
     }
 
     #[test]
-    fn handle_key_event_end_seeks_to_end() {
+    fn handle_key_event_shift_c_clears_saved_position() {
         let mut state = create_test_state();
+        state.resume = true;
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
-        let total_duration = 10.0;
 
         let _ = handle_key_event(
-            create_key_event(KeyCode::End),
+            create_key_event_with_mods(KeyCode::Char('C'), KeyModifiers::SHIFT),
             &mut state,
             &mut buffer,
             &cast,
             &markers,
-            total_duration,
+            10.0,
             80,
             24,
         );
 
-        assert_eq!(state.current_time(), total_duration);
-        assert!(state.paused);
+        assert!(state.clear_saved_position);
     }
 
     #[test]
-    fn handle_key_event_less_than_seeks_backward() {
+    fn handle_key_event_shift_p_toggles_pause_at_markers() {
         let mut state = create_test_state();
-        state.set_current_time(8.0, 10.0);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
 
         let _ = handle_key_event(
-            create_key_event(KeyCode::Char('<')),
+            create_key_event_with_mods(KeyCode::Char('P'), KeyModifiers::SHIFT),
             &mut state,
             &mut buffer,
             &cast,
@@ -828,19 +1179,19 @@ mod tests {
             24,
         );
 
-        assert_eq!(state.current_time(), 3.0); // 8 - 5 = 3
+        assert!(state.pause_at_markers);
+        assert!(!state.paused); // Only toggles the mode
     }
 
     #[test]
-    fn handle_key_event_comma_seeks_backward() {
+    fn handle_key_event_c_toggles_centering() {
         let mut state = create_test_state();
-        state.set_current_time(8.0, 10.0);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
 
-        let _ = handle_key_event(
-            create_key_event(KeyCode::Char(',')),
+        let result = handle_key_event(
+            create_key_event(KeyCode::Char('c')),
             &mut state,
             &mut buffer,
             &cast,
@@ -850,41 +1201,58 @@ mod tests {
             24,
         );
 
-        assert_eq!(state.current_time(), 3.0);
+        assert_eq!(result, InputResult::Continue);
+        assert!(!state.center_viewport);
     }
 
     #[test]
-    fn handle_key_event_greater_than_seeks_forward() {
+    fn search_prompt_seeks_to_first_match() {
         let mut state = create_test_state();
-        state.set_current_time(2.0, 10.0);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
 
-        let _ = handle_key_event(
-            create_key_event(KeyCode::Char('>')),
-            &mut state,
-            &mut buffer,
-            &cast,
-            &markers,
-            10.0,
-            80,
-            24,
-        );
+        for code in [
+            KeyCode::Char('/'),
+            KeyCode::Char('w'),
+            KeyCode::Char('o'),
+            KeyCode::Char('x'),
+            KeyCode::Backspace,
+            KeyCode::Enter,
+        ] {
+            let result = handle_key_event(
+                create_key_event(code),
+                &mut state,
+                &mut buffer,
+                &cast,
+                &markers,
+                10.0,
+                80,
+                24,
+            );
+            assert_eq!(result, InputResult::Continue);
+        }
 
-        assert_eq!(state.current_time(), 7.0); // 2 + 5 = 7
+        assert!(state.search_prompt.is_none());
+        assert_eq!(state.search_query.as_deref(), Some("wo"));
+        assert!((state.current_time() - 0.3).abs() < 1e-9);
+        assert!(state.paused);
+        assert!(buffer.row(0).unwrap()[0..11]
+            .iter()
+            .map(|c| c.char)
+            .eq("hello world".chars()));
     }
 
     #[test]
-    fn handle_key_event_period_seeks_forward() {
+    fn search_previous_without_earlier_match_reports_not_found() {
         let mut state = create_test_state();
-        state.set_current_time(2.0, 10.0);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
+        state.search_query = Some("hello".to_string());
 
         let _ = handle_key_event(
-            create_key_event(KeyCode::Char('.')),
+            create_key_event(KeyCode::Char('N')),
             &mut state,
             &mut buffer,
             &cast,
@@ -894,18 +1262,19 @@ mod tests {
             24,
         );
 
-        assert_eq!(state.current_time(), 7.0);
+        assert_eq!(state.active_status_message(), Some("not found: hello"));
+        assert_eq!(state.current_time(), 0.0);
     }
 
     #[test]
-    fn handle_key_event_unknown_key_continues() {
+    fn handle_key_event_i_toggles_input_echo() {
         let mut state = create_test_state();
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
 
-        let result = handle_key_event(
-            create_key_event(KeyCode::Char('z')),
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('i')),
             &mut state,
             &mut buffer,
             &cast,
@@ -915,21 +1284,19 @@ mod tests {
             24,
         );
 
-        assert_eq!(result, InputResult::Continue);
+        assert!(state.show_input);
     }
 
-    // === Arrow key tests ===
-
     #[test]
-    fn handle_key_event_left_seeks_in_normal_mode() {
+    fn handle_key_event_home_seeks_to_start() {
         let mut state = create_test_state();
-        state.set_current_time(8.0, 10.0);
+        state.set_current_time(5.0, 10.0);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
 
         let _ = handle_key_event(
-            create_key_event(KeyCode::Left),
+            create_key_event(KeyCode::Home),
             &mut state,
             &mut buffer,
             &cast,
@@ -939,42 +1306,43 @@ mod tests {
             24,
         );
 
-        assert_eq!(state.current_time(), 3.0);
+        assert_eq!(state.current_time(), 0.0);
+        assert_eq!(state.view_row_offset(), 0);
+        assert_eq!(state.view_col_offset(), 0);
     }
 
     #[test]
-    fn handle_key_event_right_seeks_in_normal_mode() {
+    fn handle_key_event_end_seeks_to_end() {
         let mut state = create_test_state();
-        state.set_current_time(2.0, 10.0);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
+        let total_duration = 10.0;
 
         let _ = handle_key_event(
-            create_key_event(KeyCode::Right),
+            create_key_event(KeyCode::End),
             &mut state,
             &mut buffer,
             &cast,
             &markers,
-            10.0,
+            total_duration,
             80,
             24,
         );
 
-        assert_eq!(state.current_time(), 7.0);
+        assert_eq!(state.current_time(), total_duration);
+        assert!(state.paused);
     }
 
     #[test]
-    fn handle_key_event_left_scrolls_in_viewport_mode() {
+    fn handle_key_event_digit_jumps_to_percentage() {
         let mut state = create_test_state();
-        state.viewport_mode = true;
-        state.set_view_col_offset(5, 100);
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
         let markers = vec![];
 
         let _ = handle_key_event(
-            create_key_event(KeyCode::Left),
+            create_key_event(KeyCode::Char('5')),
             &mut state,
             &mut buffer,
             &cast,
@@ -984,7 +1352,366 @@ mod tests {
             24,
         );
 
-        assert_eq!(state.view_col_offset(), 4);
+        assert!((state.current_time() - 5.0).abs() < 1e-9);
+        assert!(!state.paused);
+    }
+
+    #[test]
+    fn handle_key_event_digit_seeks_backward_while_paused() {
+        let mut state = create_test_state();
+        state.paused = true;
+        state.set_current_time(8.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('2')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+        assert!((state.current_time() - 2.0).abs() < 1e-9);
+        assert!(state.paused);
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('0')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+        assert_eq!(state.current_time(), 0.0);
+        assert!(state.paused);
+    }
+
+    #[test]
+    fn handle_key_event_less_than_seeks_backward() {
+        let mut state = create_test_state();
+        state.set_current_time(8.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('<')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 3.0); // 8 - 5 = 3
+    }
+
+    #[test]
+    fn handle_key_event_comma_seeks_backward() {
+        let mut state = create_test_state();
+        state.set_current_time(8.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char(',')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 3.0);
+    }
+
+    #[test]
+    fn handle_key_event_greater_than_seeks_forward() {
+        let mut state = create_test_state();
+        state.set_current_time(2.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('>')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 7.0); // 2 + 5 = 7
+    }
+
+    #[test]
+    fn handle_key_event_period_seeks_forward() {
+        let mut state = create_test_state();
+        state.set_current_time(2.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('.')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 7.0);
+    }
+
+    #[test]
+    fn handle_key_event_period_uses_configured_seek_step() {
+        let mut state = create_test_state();
+        state.seek_step_secs = 1.0;
+        state.set_current_time(2.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('.')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 3.0);
+    }
+
+    #[test]
+    fn handle_key_event_brackets_adjust_seek_step() {
+        let mut state = create_test_state();
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char(']')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+        assert_eq!(state.seek_step_secs, 10.0);
+        assert_eq!(state.active_status_message(), Some("step:10s"));
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('[')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+        assert_eq!(state.seek_step_secs, 5.0);
+    }
+
+    #[test]
+    fn handle_key_event_period_steps_one_event_when_paused() {
+        let mut state = create_test_state();
+        state.paused = true;
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast(); // Output at t=0.1, 0.3, 0.6
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('.')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert!((state.current_time() - 0.1).abs() < 1e-9);
+        assert_eq!(state.event_idx(), 1);
+        assert!(state.paused);
+        let row: String = buffer
+            .row(0)
+            .unwrap()
+            .iter()
+            .take(5)
+            .map(|c| c.char)
+            .collect();
+        assert_eq!(row, "hello");
+    }
+
+    #[test]
+    fn handle_key_event_comma_steps_back_one_event_when_paused() {
+        let mut state = create_test_state();
+        state.paused = true;
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        for code in [KeyCode::Char('.'), KeyCode::Char('.'), KeyCode::Char(',')] {
+            let _ = handle_key_event(
+                create_key_event(code),
+                &mut state,
+                &mut buffer,
+                &cast,
+                &markers,
+                10.0,
+                80,
+                24,
+            );
+        }
+
+        assert!((state.current_time() - 0.1).abs() < 1e-9);
+        assert_eq!(state.event_idx(), 1);
+        let row: String = buffer
+            .row(0)
+            .unwrap()
+            .iter()
+            .take(11)
+            .map(|c| c.char)
+            .collect();
+        assert_eq!(row, "hello      "); // " world" was undone
+    }
+
+    #[test]
+    fn handle_key_event_period_at_last_event_does_nothing_when_paused() {
+        let mut state = create_test_state();
+        state.paused = true;
+        state.set_current_time(1.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Char('>')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 1.0);
+    }
+
+    #[test]
+    fn handle_key_event_unknown_key_continues() {
+        let mut state = create_test_state();
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let result = handle_key_event(
+            create_key_event(KeyCode::Char('z')),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(result, InputResult::Continue);
+    }
+
+    // === Arrow key tests ===
+
+    #[test]
+    fn handle_key_event_left_seeks_in_normal_mode() {
+        let mut state = create_test_state();
+        state.set_current_time(8.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Left),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 3.0);
+    }
+
+    #[test]
+    fn handle_key_event_right_seeks_in_normal_mode() {
+        let mut state = create_test_state();
+        state.set_current_time(2.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Right),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 7.0);
+    }
+
+    #[test]
+    fn handle_key_event_left_scrolls_in_viewport_mode() {
+        let mut state = create_test_state();
+        state.viewport_mode = true;
+        state.set_view_col_offset(5, 100);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Left),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.view_col_offset(), 4);
     }
 
     #[test]
@@ -1010,6 +1737,29 @@ mod tests {
         assert_eq!(state.view_col_offset(), 6);
     }
 
+    #[test]
+    fn handle_key_event_left_uses_configured_seek_step() {
+        let mut state = create_test_state();
+        state.seek_step_secs = 2.0;
+        state.set_current_time(5.0, 10.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let markers = vec![];
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Left),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &markers,
+            10.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.current_time(), 3.0);
+    }
+
     #[test]
     fn handle_key_event_shift_left_seeks_5_percent() {
         let mut state = create_test_state();
@@ -1063,8 +1813,9 @@ mod tests {
         let mut state = create_test_state();
         state.free_mode = true;
         state.set_free_line(5, 100);
+        let buffer = TerminalBuffer::new(80, 24);
 
-        handle_up_key(&mut state);
+        handle_up_key(&mut state, &buffer);
 
         assert_eq!(state.free_line(), 4);
         assert_eq!(state.prev_free_line, 5);
@@ -1075,8 +1826,9 @@ mod tests {
         let mut state = create_test_state();
         state.free_mode = true;
         state.set_free_line(0, 100);
+        let buffer = TerminalBuffer::new(80, 24);
 
-        handle_up_key(&mut state);
+        handle_up_key(&mut state, &buffer);
 
         assert_eq!(state.free_line(), 0); // Can't go below 0
     }
@@ -1087,8 +1839,9 @@ mod tests {
         state.free_mode = true;
         state.set_free_line(5, 100);
         state.set_view_row_offset(5, 100);
+        let buffer = TerminalBuffer::new(80, 24);
 
-        handle_up_key(&mut state);
+        handle_up_key(&mut state, &buffer);
 
         assert_eq!(state.free_line(), 4);
         assert_eq!(state.view_row_offset(), 4); // Auto-scrolled up
@@ -1100,12 +1853,87 @@ mod tests {
         state.free_mode = true;
         state.set_free_line(10, 100);
         state.set_view_row_offset(0, 100); // Viewport at top
+        let buffer = TerminalBuffer::new(80, 24);
 
-        handle_up_key(&mut state);
+        handle_up_key(&mut state, &buffer);
 
         assert!(state.free_line_only); // Only line changed, viewport didn't scroll
     }
 
+    // === Free mode selection tests ===
+
+    #[test]
+    fn free_selection_lines_extracts_free_line() {
+        let mut state = create_test_state();
+        let mut buffer = TerminalBuffer::new(80, 24);
+        buffer.process("$ ls\r\n\x1b[32msrc\x1b[0m  tests   \r\n$ ", None);
+        state.toggle_free_mode(1);
+
+        assert_eq!(free_selection_lines(&state, &buffer), vec!["src  tests"]);
+    }
+
+    #[test]
+    fn free_selection_lines_extracts_blank_free_line() {
+        let mut state = create_test_state();
+        let buffer = TerminalBuffer::new(80, 24);
+        state.toggle_free_mode(10);
+
+        assert_eq!(free_selection_lines(&state, &buffer), vec![""]);
+    }
+
+    #[test]
+    fn shift_arrows_extend_free_selection() {
+        let mut state = create_test_state();
+        let mut buffer = TerminalBuffer::new(80, 24);
+        buffer.process("one\r\ntwo\r\nthree\r\nfour", None);
+        let cast = create_test_cast();
+        state.toggle_free_mode(1);
+
+        for _ in 0..2 {
+            let _ = handle_key_event(
+                create_key_event_with_mods(KeyCode::Down, KeyModifiers::SHIFT),
+                &mut state,
+                &mut buffer,
+                &cast,
+                &[],
+                100.0,
+                80,
+                24,
+            );
+        }
+
+        assert_eq!(state.free_selection(), 1..=3);
+        assert!(!state.free_line_only);
+        assert_eq!(
+            free_selection_lines(&state, &buffer),
+            vec!["two", "three", "four"]
+        );
+    }
+
+    #[test]
+    fn plain_arrow_drops_free_selection() {
+        let mut state = create_test_state();
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        state.toggle_free_mode(5);
+        state.extend_free_selection();
+        state.set_free_line(8, 23);
+
+        let _ = handle_key_event(
+            create_key_event(KeyCode::Up),
+            &mut state,
+            &mut buffer,
+            &cast,
+            &[],
+            100.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.free_selection(), 7..=7);
+        assert!(!state.free_line_only);
+    }
+
     #[test]
     fn test_handle_down_key_free_mode() {
         let mut state = create_test_state();
@@ -1151,8 +1979,9 @@ mod tests {
         let mut state = create_test_state();
         state.viewport_mode = true;
         state.set_view_row_offset(5, 100);
+        let buffer = TerminalBuffer::new(80, 24);
 
-        handle_up_key(&mut state);
+        handle_up_key(&mut state, &buffer);
 
         assert_eq!(state.view_row_offset(), 4);
     }
@@ -1162,8 +1991,9 @@ mod tests {
         let mut state = create_test_state();
         state.viewport_mode = true;
         state.set_view_row_offset(0, 100);
+        let buffer = TerminalBuffer::new(80, 24);
 
-        handle_up_key(&mut state);
+        handle_up_key(&mut state, &buffer);
 
         assert_eq!(state.view_row_offset(), 0); // Can't go below 0
     }
@@ -1193,12 +2023,45 @@ mod tests {
         assert_eq!(state.view_row_offset(), 24); // At max
     }
 
+    #[test]
+    fn test_handle_up_key_viewport_mode_scrolls_into_scrollback() {
+        let mut state = create_test_state();
+        state.viewport_mode = true;
+        let mut buffer = TerminalBuffer::with_scrollback(80, 2, 10);
+        buffer.process("a\r\nb\r\nc\r\nd", None);
+
+        handle_up_key(&mut state, &buffer);
+        handle_up_key(&mut state, &buffer);
+        handle_up_key(&mut state, &buffer);
+
+        assert_eq!(state.view_row_offset(), 0);
+        assert_eq!(state.scrollback_offset(), 2); // Limited by history length
+    }
+
+    #[test]
+    fn test_handle_down_key_viewport_mode_leaves_scrollback_first() {
+        let mut state = create_test_state();
+        state.viewport_mode = true;
+        state.view_rows = 2;
+        let mut buffer = TerminalBuffer::with_scrollback(80, 4, 10);
+        buffer.process("a\r\nb\r\nc\r\nd\r\ne", None);
+        handle_up_key(&mut state, &buffer);
+
+        handle_down_key(&mut state, &buffer);
+        assert_eq!(state.scrollback_offset(), 0);
+        assert_eq!(state.view_row_offset(), 0);
+
+        handle_down_key(&mut state, &buffer);
+        assert_eq!(state.view_row_offset(), 1);
+    }
+
     #[test]
     fn test_handle_up_key_normal_mode_does_nothing() {
         let mut state = create_test_state();
         state.set_view_row_offset(5, 100);
+        let buffer = TerminalBuffer::new(80, 24);
 
-        handle_up_key(&mut state);
+        handle_up_key(&mut state, &buffer);
 
         assert_eq!(state.view_row_offset(), 5); // Unchanged
     }
@@ -1241,6 +2104,22 @@ mod tests {
         assert_eq!(state.current_time(), 10.0);
     }
 
+    #[test]
+    fn seeking_replays_input_events_for_echo() {
+        let mut state = create_test_state();
+        state.show_input = true;
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let mut cast = create_test_cast();
+        cast.events.push(Event::new(1.0, EventType::Input, "ls\r"));
+        let total_duration = cast.duration();
+
+        handle_seek_forward(&mut state, &mut buffer, &cast, 5.0, total_duration, 80, 24);
+        assert_eq!(state.input_echo(), Some("ls⏎"));
+
+        handle_seek_backward(&mut state, &mut buffer, &cast, 1.0, 80, 24);
+        assert_eq!(state.input_echo(), None);
+    }
+
     // === Marker navigation tests ===
 
     #[test]
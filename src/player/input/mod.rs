@@ -88,6 +88,7 @@ mod tests {
                 cols: Some(80),
                 rows: Some(24),
                 term_type: None,
+                theme: None,
             }),
             timestamp: None,
             duration: None,
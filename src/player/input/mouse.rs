@@ -1,6 +1,7 @@
 //! Mouse input handling for the native player.
 //!
-//! Handles mouse events, primarily for click-to-seek on the progress bar.
+//! Handles mouse events: click or drag on the progress bar to seek, and the
+//! scroll wheel.
 
 use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use std::time::Instant;
@@ -10,10 +11,15 @@ use crate::player::playback::{find_event_index_at_time, seek_to_time};
 use crate::player::state::{InputResult, PlaybackState};
 use crate::terminal::TerminalBuffer;
 
+use super::keyboard::{handle_seek_backward, handle_seek_forward};
+
 /// Handle a mouse event.
 ///
 /// Currently handles:
-/// - Left click on progress bar to seek to that position
+/// - Left click or drag on the progress bar to scrub to that position;
+///   playback stays paused until the button is released
+/// - Scroll wheel: scrolls the viewport in viewport/free mode, otherwise
+///   seeks by the configured seek step
 #[allow(clippy::too_many_arguments)]
 pub fn handle_mouse_event(
     mouse: MouseEvent,
@@ -24,45 +30,154 @@ pub fn handle_mouse_event(
     rec_cols: u32,
     rec_rows: u32,
 ) -> InputResult {
-    if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
-        let progress_row = state.term_rows.saturating_sub(2);
-
-        if mouse.row == progress_row {
-            // Calculate time from x position on progress bar
-            // Bar starts at column 1, width is term_cols - 14
-            let bar_start = 1u16;
-            let bar_width = (state.term_cols as usize).saturating_sub(14);
-
-            if mouse.column >= bar_start && mouse.column < bar_start + bar_width as u16 {
-                let click_pos = (mouse.column - bar_start) as f64;
-                let ratio = click_pos / bar_width as f64;
-                let new_time = (ratio * total_duration).clamp(0.0, total_duration);
-
-                // Exit free mode if active
-                state.free_mode = false;
-
-                // Seek to clicked position
-                seek_to_time(buffer, cast, new_time, rec_cols, rec_rows);
-                state.set_current_time(new_time, total_duration);
-                state.set_time_offset(state.current_time());
-                state.start_time = Instant::now();
-                let (idx, cumulative) = find_event_index_at_time(cast, state.current_time());
-                state.set_event_position(idx, cumulative, cast.events.len());
-
-                // Resume playback after seeking
-                state.paused = false;
-                state.needs_render = true;
+    match mouse.kind {
+        MouseEventKind::ScrollUp => {
+            if state.viewport_mode || state.free_mode {
+                // Free mode highlights live rows only, so it stays out of scrollback
+                let scrollback_len = if state.viewport_mode {
+                    buffer.scrollback_lines().len()
+                } else {
+                    0
+                };
+                state.scroll_view_up(scrollback_len);
+            } else {
+                let step = state.seek_step_secs;
+                handle_seek_backward(state, buffer, cast, step, rec_cols, rec_rows);
+            }
+            return InputResult::Continue;
+        }
+        MouseEventKind::ScrollDown => {
+            if state.viewport_mode || state.free_mode {
+                let max_offset = buffer.height().saturating_sub(state.view_rows);
+                state.scroll_view_down(max_offset);
+            } else {
+                let step = state.seek_step_secs;
+                handle_seek_forward(
+                    state,
+                    buffer,
+                    cast,
+                    step,
+                    total_duration,
+                    rec_cols,
+                    rec_rows,
+                );
+            }
+            return InputResult::Continue;
+        }
+        _ => {}
+    }
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) if mouse.row == progress_row(state) => {
+            if let Some(new_time) = progress_bar_time(state, mouse.column, total_duration) {
+                scrub_to(
+                    state,
+                    buffer,
+                    cast,
+                    new_time,
+                    total_duration,
+                    rec_cols,
+                    rec_rows,
+                );
             }
         }
+        // Once scrubbing, follow the pointer even if it leaves the bar row
+        MouseEventKind::Drag(MouseButton::Left)
+            if state.scrubbing || mouse.row == progress_row(state) =>
+        {
+            let column = clamp_to_bar(state, mouse.column);
+            if let Some(new_time) = progress_bar_time(state, column, total_duration) {
+                scrub_to(
+                    state,
+                    buffer,
+                    cast,
+                    new_time,
+                    total_duration,
+                    rec_cols,
+                    rec_rows,
+                );
+            }
+        }
+        MouseEventKind::Up(MouseButton::Left) if state.scrubbing => {
+            // Resume playback from where the playhead was dropped
+            state.scrubbing = false;
+            state.set_time_offset(state.current_time());
+            state.start_time = Instant::now();
+            state.paused = false;
+            state.needs_render = true;
+        }
+        _ => {}
     }
 
     InputResult::Continue
 }
 
+/// Row of the progress bar (above the status bar).
+fn progress_row(state: &PlaybackState) -> u16 {
+    state.term_rows.saturating_sub(2)
+}
+
+/// First column of the progress bar and its width in columns.
+///
+/// The bar starts at column 1 and is `term_cols - 14` wide.
+fn progress_bar_span(state: &PlaybackState) -> (u16, usize) {
+    (1, (state.term_cols as usize).saturating_sub(14))
+}
+
+/// Map a column on the progress bar to a time, or `None` off the bar.
+fn progress_bar_time(state: &PlaybackState, column: u16, total_duration: f64) -> Option<f64> {
+    let (bar_start, bar_width) = progress_bar_span(state);
+    if column < bar_start || column >= bar_start + bar_width as u16 {
+        return None;
+    }
+    let ratio = (column - bar_start) as f64 / bar_width as f64;
+    Some((ratio * total_duration).clamp(0.0, total_duration))
+}
+
+/// Clamp a column to the progress bar so drags past either end pin the
+/// playhead to the start or end.
+fn clamp_to_bar(state: &PlaybackState, column: u16) -> u16 {
+    let (bar_start, bar_width) = progress_bar_span(state);
+    let bar_end = bar_start + (bar_width as u16).saturating_sub(1);
+    column.clamp(bar_start, bar_end.max(bar_start))
+}
+
+/// Seek to `new_time` and redraw there, holding playback until the mouse
+/// button is released.
+fn scrub_to(
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    new_time: f64,
+    total_duration: f64,
+    rec_cols: u32,
+    rec_rows: u32,
+) {
+    state.scrubbing = true;
+    state.paused = true;
+    // Exit free mode if active
+    state.free_mode = false;
+
+    seek_to_time(
+        buffer,
+        &mut state.annotations,
+        cast,
+        new_time,
+        rec_cols,
+        rec_rows,
+    );
+    state.set_current_time(new_time, total_duration);
+    state.set_time_offset(state.current_time());
+    state.start_time = Instant::now();
+    let (idx, cumulative) = find_event_index_at_time(cast, state.current_time());
+    state.set_event_position(idx, cumulative, cast.events.len());
+    state.needs_render = true;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::asciicast::{AsciicastFile, Event as CastEvent, Header, TermInfo};
+    use crate::asciicast::{AsciicastFile, Event as CastEvent, Header};
     use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
     fn create_test_state() -> PlaybackState {
@@ -70,22 +185,7 @@ mod tests {
     }
 
     fn create_test_cast() -> AsciicastFile {
-        let mut cast = AsciicastFile::new(Header {
-            version: 3,
-            width: Some(80),
-            height: Some(24),
-            term: Some(TermInfo {
-                cols: Some(80),
-                rows: Some(24),
-                term_type: None,
-            }),
-            timestamp: None,
-            duration: None,
-            title: None,
-            command: None,
-            env: None,
-            idle_time_limit: None,
-        });
+        let mut cast = AsciicastFile::new(Header::v3(80, 24));
         cast.events.push(CastEvent::output(0.1, "hello"));
         cast.events.push(CastEvent::output(0.2, " world"));
         cast
@@ -144,7 +244,164 @@ mod tests {
         // The time should have changed based on click position
         assert!(state.current_time() > 0.0);
         assert!(state.current_time() < total_duration);
+        // Held until the button is released
+        assert!(state.paused);
+        assert!(state.scrubbing);
+
+        let release = MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            ..mouse
+        };
+        handle_mouse_event(
+            release,
+            &mut state,
+            &mut buffer,
+            &cast,
+            total_duration,
+            80,
+            24,
+        );
         assert!(!state.paused); // Resumes playback after seeking
+        assert!(!state.scrubbing);
+    }
+
+    fn create_mouse_drag(column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Drag(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn mouse_drag_across_progress_bar_scrubs_while_paused() {
+        let mut state = create_test_state();
+        let progress_row = state.term_rows - 2;
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let total_duration = 100.0;
+
+        handle_mouse_event(
+            create_mouse_click(5, progress_row),
+            &mut state,
+            &mut buffer,
+            &cast,
+            total_duration,
+            80,
+            24,
+        );
+        let mut last_time = state.current_time();
+
+        for column in [10, 20, 30, 45, 60] {
+            state.needs_render = false;
+            handle_mouse_event(
+                create_mouse_drag(column, progress_row),
+                &mut state,
+                &mut buffer,
+                &cast,
+                total_duration,
+                80,
+                24,
+            );
+            assert!(state.current_time() > last_time, "column {}", column);
+            assert!(state.paused);
+            assert!(state.needs_render);
+            last_time = state.current_time();
+        }
+
+        // Dragging back moves the playhead back
+        handle_mouse_event(
+            create_mouse_drag(15, progress_row),
+            &mut state,
+            &mut buffer,
+            &cast,
+            total_duration,
+            80,
+            24,
+        );
+        assert!(state.current_time() < last_time);
+        assert!(state.paused);
+    }
+
+    #[test]
+    fn mouse_drag_off_the_bar_pins_to_the_ends() {
+        let mut state = create_test_state();
+        let progress_row = state.term_rows - 2;
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+        let total_duration = 100.0;
+
+        handle_mouse_event(
+            create_mouse_click(30, progress_row),
+            &mut state,
+            &mut buffer,
+            &cast,
+            total_duration,
+            80,
+            24,
+        );
+        // Past the right end and above the bar row
+        handle_mouse_event(
+            create_mouse_drag(79, 3),
+            &mut state,
+            &mut buffer,
+            &cast,
+            total_duration,
+            80,
+            24,
+        );
+        assert!(state.current_time() > 95.0);
+
+        handle_mouse_event(
+            create_mouse_drag(0, progress_row),
+            &mut state,
+            &mut buffer,
+            &cast,
+            total_duration,
+            80,
+            24,
+        );
+        assert_eq!(state.current_time(), 0.0);
+        assert!(state.paused);
+    }
+
+    #[test]
+    fn mouse_drag_elsewhere_without_scrubbing_does_nothing() {
+        let mut state = create_test_state();
+        state.set_current_time(50.0, 100.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+
+        handle_mouse_event(
+            create_mouse_drag(30, 5),
+            &mut state,
+            &mut buffer,
+            &cast,
+            100.0,
+            80,
+            24,
+        );
+        assert_eq!(state.current_time(), 50.0);
+        assert!(!state.paused);
+        assert!(!state.scrubbing);
+    }
+
+    #[test]
+    fn mouse_release_without_scrubbing_keeps_pause_state() {
+        let mut state = create_test_state();
+        state.paused = true;
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+
+        let release = MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            column: 30,
+            row: state.term_rows - 2,
+            modifiers: KeyModifiers::NONE,
+        };
+        handle_mouse_event(release, &mut state, &mut buffer, &cast, 100.0, 80, 24);
+        assert!(state.paused);
     }
 
     #[test]
@@ -250,33 +507,145 @@ mod tests {
         assert_eq!(state.current_time(), 50.0); // Unchanged
     }
 
+    fn create_mouse_scroll(kind: MouseEventKind) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column: 34,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
     #[test]
-    fn mouse_scroll_event_does_nothing() {
+    fn mouse_scroll_up_seeks_backward_in_normal_mode() {
         let mut state = create_test_state();
         state.set_current_time(50.0, 100.0);
-        let progress_row = state.term_rows - 2;
         let mut buffer = TerminalBuffer::new(80, 24);
         let cast = create_test_cast();
-        let total_duration = 100.0;
 
-        let mouse = MouseEvent {
-            kind: MouseEventKind::ScrollUp,
-            column: 34,
-            row: progress_row,
-            modifiers: KeyModifiers::NONE,
-        };
         let result = handle_mouse_event(
-            mouse,
+            create_mouse_scroll(MouseEventKind::ScrollUp),
             &mut state,
             &mut buffer,
             &cast,
-            total_duration,
+            100.0,
             80,
             24,
         );
 
         assert_eq!(result, InputResult::Continue);
-        assert_eq!(state.current_time(), 50.0); // Unchanged
+        assert_eq!(state.current_time(), 45.0);
+    }
+
+    #[test]
+    fn mouse_scroll_down_seeks_forward_by_configured_step() {
+        let mut state = create_test_state();
+        state.seek_step_secs = 10.0;
+        state.set_current_time(50.0, 100.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+
+        let result = handle_mouse_event(
+            create_mouse_scroll(MouseEventKind::ScrollDown),
+            &mut state,
+            &mut buffer,
+            &cast,
+            100.0,
+            80,
+            24,
+        );
+
+        assert_eq!(result, InputResult::Continue);
+        assert_eq!(state.current_time(), 60.0);
+    }
+
+    #[test]
+    fn mouse_scroll_down_scrolls_viewport_in_viewport_mode() {
+        let mut state = create_test_state();
+        state.viewport_mode = true;
+        state.view_rows = 10;
+        state.set_current_time(50.0, 100.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+
+        let _ = handle_mouse_event(
+            create_mouse_scroll(MouseEventKind::ScrollDown),
+            &mut state,
+            &mut buffer,
+            &cast,
+            100.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.view_row_offset(), 1);
+        assert_eq!(state.current_time(), 50.0); // No seek
+    }
+
+    #[test]
+    fn mouse_scroll_down_clamps_to_buffer_height() {
+        let mut state = create_test_state();
+        state.viewport_mode = true;
+        state.view_rows = 20;
+        state.set_view_row_offset(4, 4);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+
+        let _ = handle_mouse_event(
+            create_mouse_scroll(MouseEventKind::ScrollDown),
+            &mut state,
+            &mut buffer,
+            &cast,
+            100.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.view_row_offset(), 4); // 24 - 20 = 4 max
+    }
+
+    #[test]
+    fn mouse_scroll_up_scrolls_viewport_in_free_mode() {
+        let mut state = create_test_state();
+        state.free_mode = true;
+        state.paused = true;
+        state.set_view_row_offset(3, 10);
+        state.set_current_time(50.0, 100.0);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+
+        let _ = handle_mouse_event(
+            create_mouse_scroll(MouseEventKind::ScrollUp),
+            &mut state,
+            &mut buffer,
+            &cast,
+            100.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.view_row_offset(), 2);
+        assert_eq!(state.current_time(), 50.0); // No seek
+    }
+
+    #[test]
+    fn mouse_scroll_up_at_top_stays_at_zero() {
+        let mut state = create_test_state();
+        state.viewport_mode = true;
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let cast = create_test_cast();
+
+        let _ = handle_mouse_event(
+            create_mouse_scroll(MouseEventKind::ScrollUp),
+            &mut state,
+            &mut buffer,
+            &cast,
+            100.0,
+            80,
+            24,
+        );
+
+        assert_eq!(state.view_row_offset(), 0);
     }
 
     #[test]
@@ -20,7 +20,7 @@
 //!
 //! let result = play_session(Path::new("session.cast")).unwrap();
 //! match result {
-//!     PlaybackResult::Success(name) => println!("Finished: {}", name),
+//!     PlaybackResult::Success(name, _exit_code) => println!("Finished: {}", name),
 //!     PlaybackResult::Interrupted => println!("Stopped by user"),
 //!     PlaybackResult::Error(e) => eprintln!("Error: {}", e),
 //! }
@@ -9,32 +9,38 @@
 
 use std::io::{self, Write};
 use std::path::Path;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use crossterm::{
-    cursor::{Hide, Show},
+    cursor::{Hide, SetCursorStyle, Show},
     event::{self, DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen},
 };
 
 use crate::asciicast::AsciicastFile;
+use crate::config::{Config, PlayerConfig};
 use crate::terminal::TerminalBuffer;
+use crate::util::format_duration;
 
 use super::input::handle_event;
-use super::playback::collect_markers;
+use super::playback::{
+    clear_position, collect_markers, find_event_index_at_time, find_idle_skip, find_marker_crossed,
+    load_position, save_position, seek_to_time,
+};
 use super::render::{
-    render_help, render_progress_bar, render_scroll_indicator, render_separator_line,
-    render_single_line, render_status_bar, render_viewport,
+    render_cursor, render_help, render_progress_bar, render_scroll_indicator,
+    render_separator_line, render_single_line, render_status_bar, render_viewport, ViewportContext,
 };
 use super::state::{InputResult, PlaybackState};
 
 /// Result of a playback operation
 #[derive(Debug, Clone)]
 pub enum PlaybackResult {
-    /// Playback completed successfully
-    Success(String),
+    /// Playback completed successfully, with the recorded exit code if
+    /// playback reached an exit event
+    Success(String, Option<i32>),
     /// Playback was interrupted (e.g., user pressed q)
     Interrupted,
     /// Playback failed with an error
@@ -45,7 +51,10 @@ impl PlaybackResult {
     /// Get a human-readable message for this result
     pub fn message(&self) -> String {
         match self {
-            PlaybackResult::Success(name) => format!("Played: {}", name),
+            PlaybackResult::Success(name, None) => format!("Played: {}", name),
+            PlaybackResult::Success(name, Some(code)) => {
+                format!("Played: {} (exited: {})", name, code)
+            }
             PlaybackResult::Interrupted => "Playback interrupted".to_string(),
             PlaybackResult::Error(e) => format!("Failed to play: {}", e),
         }
@@ -53,8 +62,12 @@ impl PlaybackResult {
 }
 
 /// Play a session using the native renderer (default).
+///
+/// Always starts from the beginning; see [`play_session_native`] for resuming.
+/// Playback preferences come from the `[player]` config section.
 pub fn play_session(path: &Path) -> Result<PlaybackResult> {
-    play_session_native(path)
+    let player = Config::load().map(|c| c.player).unwrap_or_default();
+    play_session_native(path, false, &player)
 }
 
 /// Play a session using the native renderer.
@@ -68,13 +81,33 @@ pub fn play_session(path: &Path) -> Result<PlaybackResult> {
 /// - Space: Pause/resume
 /// - Arrow keys: Seek (or scroll in viewport mode)
 /// - +/-: Adjust speed
+/// - l: Toggle loop playback
+/// - [/]: Shrink/grow seek step (1, 2, 5, 10, 30, 60s)
 /// - m: Jump to next marker
-/// - </> or ,/.: Seek backward/forward 5s
+/// - P: Toggle pause at markers
+/// - </> or ,/.: Seek backward/forward by the seek step (default 5s),
+///   or step one output event at a time while paused
 /// - Home/End: Go to start/end
+/// - 0-9: Jump to 0%-90% of the recording
 /// - v: Toggle viewport mode
 /// - r: Resize terminal to recording size
+/// - C: Clear saved resume position
+/// - i: Show recorded keystrokes in the status bar
 /// - ?: Show help
-pub fn play_session_native(path: &Path) -> Result<PlaybackResult> {
+///
+/// When `resume` is true, playback starts at the position saved in the
+/// `<name>.cast.pos` sidecar (if any) and the position is saved again on quit.
+/// Leave it off for scripted playback so runs stay deterministic.
+///
+/// Initial speed, viewport mode, loop playback and the idle limit come from
+/// `player`. Speed, viewport mode and loop playback changed during playback
+/// are saved back to the `[player]` section of the user config on exit. With `skip_idle` set, pauses between events longer than that
+/// many seconds are cut short during playback; the file is not modified.
+pub fn play_session_native(
+    path: &Path,
+    resume: bool,
+    player: &PlayerConfig,
+) -> Result<PlaybackResult> {
     let cast = AsciicastFile::parse(path)?;
     let name = path
         .file_name()
@@ -90,13 +123,36 @@ pub fn play_session_native(path: &Path) -> Result<PlaybackResult> {
     let markers = collect_markers(&cast);
 
     // Create virtual terminal at recording size
-    let mut buffer = TerminalBuffer::new(rec_cols as usize, rec_rows as usize);
+    let mut buffer = TerminalBuffer::with_scrollback(
+        rec_cols as usize,
+        rec_rows as usize,
+        PlaybackState::SCROLLBACK_LINES,
+    );
 
     // Get current terminal size for viewport
     let (term_cols, term_rows) = crossterm::terminal::size()?;
 
     // Initialize playback state
-    let mut state = PlaybackState::new(term_cols, term_rows);
+    let mut state = PlaybackState::with_config(term_cols, term_rows, player);
+    state.resume = resume;
+
+    if resume {
+        if let Some(time) = load_position(path, total_duration) {
+            seek_to_time(
+                &mut buffer,
+                &mut state.annotations,
+                &cast,
+                time,
+                rec_cols,
+                rec_rows,
+            );
+            state.set_current_time(time, total_duration);
+            state.set_time_offset(state.current_time());
+            let (idx, cumulative) = find_event_index_at_time(&cast, state.current_time());
+            state.set_event_position(idx, cumulative, cast.events.len());
+            state.set_status_message(format!("Resumed at {}", format_duration(time)));
+        }
+    }
 
     // Setup terminal
     let mut stdout = io::stdout();
@@ -115,10 +171,38 @@ pub fn play_session_native(path: &Path) -> Result<PlaybackResult> {
         &name,
     );
 
-    // Cleanup
-    execute!(stdout, Show, DisableMouseCapture, LeaveAlternateScreen)?;
+    // Cleanup (the recording may have changed the cursor shape)
+    execute!(
+        stdout,
+        SetCursorStyle::DefaultUserShape,
+        Show,
+        DisableMouseCapture,
+        LeaveAlternateScreen
+    )?;
     crossterm::terminal::disable_raw_mode()?;
 
+    if resume {
+        // Intentionally ignore errors - a missing sidecar only loses the resume point
+        let _ = match result {
+            Ok(PlaybackResult::Interrupted) if !state.clear_saved_position => {
+                save_position(path, state.current_time())
+            }
+            // Finished, or the user asked to forget the position
+            Ok(_) => clear_position(path),
+            Err(_) => Ok(()),
+        };
+    }
+
+    if result.is_ok() {
+        let changed = state.changed_preferences(player);
+        if !changed.is_empty() {
+            let values: Vec<(&str, &str)> = changed.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            if let Err(e) = Config::save_values(&values) {
+                eprintln!("Warning: failed to save player preferences: {}", e);
+            }
+        }
+    }
+
     result
 }
 
@@ -135,6 +219,12 @@ fn run_main_loop(
     rec_rows: u32,
     name: &str,
 ) -> Result<PlaybackResult> {
+    // Colors resolve against the recorded palette when the cast has one
+    let theme = cast.header.term.as_ref().and_then(|t| t.theme.as_ref());
+    // Place the recording as if it were at its largest size so the view
+    // doesn't jump when it resizes
+    let (fit_cols, fit_rows) = cast.effective_dimensions();
+
     loop {
         // Handle all pending input events before rendering
         // First poll waits up to 16ms, then drain any queued events with zero timeout
@@ -160,7 +250,12 @@ fn run_main_loop(
 
             match result {
                 InputResult::Quit => return Ok(PlaybackResult::Interrupted),
-                InputResult::QuitWithFile => return Ok(PlaybackResult::Success(name.to_string())),
+                InputResult::QuitWithFile => {
+                    return Ok(PlaybackResult::Success(
+                        name.to_string(),
+                        state.annotations.exit_code(),
+                    ))
+                }
                 InputResult::Continue => {}
             }
         }
@@ -170,7 +265,17 @@ fn run_main_loop(
             let elapsed =
                 state.start_time.elapsed().as_secs_f64() * state.speed + state.time_offset();
             // Cap elapsed time to total duration
-            let elapsed = elapsed.min(total_duration);
+            let mut elapsed = elapsed.min(total_duration);
+
+            // Stop exactly on the next marker when pause-at-markers is on
+            let mut reached_marker = None;
+            if state.pause_at_markers {
+                if let Some(marker) = find_marker_crossed(markers, state.current_time(), elapsed) {
+                    elapsed = marker.time;
+                    reached_marker = Some(marker);
+                }
+            }
+
             state.set_current_time(elapsed, total_duration);
             state.needs_render = true; // Always render when playing (time changes)
 
@@ -183,6 +288,7 @@ fn run_main_loop(
                 }
 
                 state.set_cumulative_time(next_time);
+                state.annotations.observe(next_time, evt);
 
                 if evt.is_output() {
                     buffer.process(&evt.data, None);
@@ -192,8 +298,27 @@ fn run_main_loop(
 
                 state.increment_event_idx(cast.events.len());
             }
+
+            if let Some(marker) = reached_marker {
+                state.paused = true;
+                state.set_status_message(format!("◆ {}", marker.label));
+            } else if let Some(skipped) = state.skip_idle.and_then(|limit| {
+                find_idle_skip(
+                    cast,
+                    state.event_idx(),
+                    state.cumulative_time(),
+                    elapsed,
+                    limit,
+                )
+            }) {
+                // Shift the clock so the next pass reaches the next event
+                state.set_time_offset(state.time_offset() + skipped);
+                state.set_status_message(format!("skipped {:.1}s idle", skipped));
+            }
         }
 
+        state.expire_status_message();
+
         // Render only when needed
         if !state.needs_render {
             std::thread::sleep(Duration::from_millis(8));
@@ -202,6 +327,7 @@ fn run_main_loop(
         state.needs_render = false;
 
         if state.show_help {
+            execute!(stdout, Hide)?;
             render_help(stdout, state.term_cols, state.term_rows)?;
         } else {
             // Begin synchronized update to prevent flicker
@@ -209,6 +335,7 @@ fn run_main_loop(
 
             // Partial update: only re-render changed highlight lines in free mode
             // Skip all UI chrome (progress bar, status bar, etc.) for partial updates
+            let padding = state.viewport_padding(fit_cols as usize, fit_rows as usize);
             if state.free_line_only && state.free_mode {
                 render_single_line(
                     stdout,
@@ -218,6 +345,9 @@ fn run_main_loop(
                     state.view_col_offset(),
                     state.view_cols,
                     false, // not highlighted
+                    padding,
+                    state.search_query.as_deref(),
+                    theme,
                 )?;
                 render_single_line(
                     stdout,
@@ -227,6 +357,9 @@ fn run_main_loop(
                     state.view_col_offset(),
                     state.view_cols,
                     true, // highlighted
+                    padding,
+                    state.search_query.as_deref(),
+                    theme,
                 )?;
                 state.free_line_only = false;
                 // End synchronized update and skip UI chrome
@@ -234,17 +367,20 @@ fn run_main_loop(
                 stdout.flush()?;
                 continue; // Skip the sleep at end of loop for faster response
             } else {
+                state.clamp_scrollback_offset(buffer.scrollback_lines().len());
                 render_viewport(
                     stdout,
                     buffer,
-                    state.view_row_offset(),
-                    state.view_col_offset(),
-                    state.view_rows,
-                    state.view_cols,
-                    if state.free_mode {
-                        Some(state.free_line())
-                    } else {
-                        None
+                    &ViewportContext {
+                        row_offset: state.view_row_offset(),
+                        scrollback_offset: state.scrollback_offset(),
+                        col_offset: state.view_col_offset(),
+                        view_rows: state.view_rows,
+                        view_cols: state.view_cols,
+                        highlight: state.free_mode.then(|| state.free_selection()),
+                        padding,
+                        search: state.search_query.as_deref(),
+                        theme,
                     },
                 )?;
 
@@ -271,6 +407,7 @@ fn run_main_loop(
                     markers,
                 )?;
 
+                let search_prompt = state.search_prompt_text();
                 render_status_bar(
                     stdout,
                     state.term_cols,
@@ -286,6 +423,24 @@ fn run_main_loop(
                     markers.len(),
                     state.viewport_mode,
                     state.free_mode,
+                    state.loop_playback,
+                    state.pause_at_markers,
+                    search_prompt.as_deref().or(state.status_text()),
+                    state.input_echo(),
+                    state.annotations.exit_code(),
+                )?;
+
+                // Last, since everything above moves the cursor
+                render_cursor(
+                    stdout,
+                    buffer,
+                    state.view_row_offset(),
+                    state.scrollback_offset(),
+                    state.view_col_offset(),
+                    state.view_rows,
+                    state.view_cols,
+                    padding,
+                    !state.free_mode && state.search_prompt.is_none(),
                 )?;
 
                 // End synchronized update
@@ -296,24 +451,62 @@ fn run_main_loop(
         stdout.flush()?;
 
         if state.event_idx() >= cast.events.len() && !state.paused {
+            if state.loop_playback && total_duration > 0.0 {
+                restart_playback(state, buffer, cast, rec_cols, rec_rows);
+                continue;
+            }
             std::thread::sleep(Duration::from_millis(500));
-            return Ok(PlaybackResult::Success(name.to_string()));
+            return Ok(PlaybackResult::Success(
+                name.to_string(),
+                state.annotations.exit_code(),
+            ));
         }
 
         std::thread::sleep(Duration::from_millis(8));
     }
 }
 
+/// Rewind playback to the beginning for the next loop pass.
+///
+/// Keeps the viewport position and all modes; only the buffer and timing reset.
+fn restart_playback(
+    state: &mut PlaybackState,
+    buffer: &mut TerminalBuffer,
+    cast: &AsciicastFile,
+    rec_cols: u32,
+    rec_rows: u32,
+) {
+    seek_to_time(
+        buffer,
+        &mut state.annotations,
+        cast,
+        0.0,
+        rec_cols,
+        rec_rows,
+    );
+    state.set_current_time(0.0, f64::MAX);
+    state.set_time_offset(0.0);
+    state.start_time = Instant::now();
+    state.set_event_position(0, 0.0, cast.events.len());
+    state.needs_render = true;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn playback_result_success_message() {
-        let result = PlaybackResult::Success("test.cast".to_string());
+        let result = PlaybackResult::Success("test.cast".to_string(), None);
         assert_eq!(result.message(), "Played: test.cast");
     }
 
+    #[test]
+    fn playback_result_success_message_includes_exit_code() {
+        let result = PlaybackResult::Success("test.cast".to_string(), Some(1));
+        assert_eq!(result.message(), "Played: test.cast (exited: 1)");
+    }
+
     #[test]
     fn playback_result_interrupted_message() {
         let result = PlaybackResult::Interrupted;
@@ -328,11 +521,50 @@ mod tests {
 
     #[test]
     fn playback_result_clone() {
-        let result = PlaybackResult::Success("test.cast".to_string());
+        let result = PlaybackResult::Success("test.cast".to_string(), Some(0));
         let cloned = result.clone();
         assert_eq!(result.message(), cloned.message());
     }
 
+    #[test]
+    fn restart_playback_rewinds_to_start() {
+        use crate::asciicast::{Event, Header};
+
+        let mut cast = AsciicastFile::new(Header {
+            version: 3,
+            width: None,
+            height: None,
+            term: None,
+            timestamp: None,
+            duration: None,
+            title: None,
+            command: None,
+            env: None,
+            idle_time_limit: None,
+        });
+        cast.events.push(Event::output(0.5, "hello"));
+        cast.events.push(Event::output(0.5, " world"));
+
+        let mut state = PlaybackState::new(80, 27);
+        state.loop_playback = true;
+        state.set_current_time(1.0, 1.0);
+        state.set_time_offset(1.0);
+        state.set_event_position(2, 1.0, 2);
+        state.set_view_row_offset(3, 10);
+        let mut buffer = TerminalBuffer::new(80, 24);
+        buffer.process("hello world", None);
+
+        restart_playback(&mut state, &mut buffer, &cast, 80, 24);
+
+        assert_eq!(state.current_time(), 0.0);
+        assert_eq!(state.time_offset(), 0.0);
+        assert_eq!(state.event_idx(), 0);
+        assert_eq!(state.cumulative_time(), 0.0);
+        assert_eq!(state.view_row_offset(), 3); // Viewport is kept
+        assert!(state.loop_playback);
+        assert!(buffer.row(0).unwrap().iter().all(|c| c.char == ' '));
+    }
+
     #[test]
     fn playback_result_debug() {
         let result = PlaybackResult::Interrupted;
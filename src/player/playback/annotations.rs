@@ -0,0 +1,230 @@
+//! Status-area annotations derived from non-output events.
+//!
+//! Input (`"i"`) and exit (`"x"`) events don't change the screen, so the
+//! replay loops feed them here instead of discarding them. Seeking rebuilds
+//! the annotations from scratch along with the terminal buffer, so jumping
+//! backward past an event drops whatever it contributed.
+
+use crate::asciicast::Event;
+
+/// What the status bar can show about events near the playhead.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventAnnotations {
+    /// Recent keystrokes, already escaped for display
+    input_text: String,
+    /// Cumulative time of the last input event folded into `input_text`
+    input_time: Option<f64>,
+    /// Exit code from the last exit event replayed
+    exit_code: Option<i32>,
+}
+
+impl EventAnnotations {
+    /// Input events closer together than this are shown as one run.
+    pub const INPUT_MERGE_GAP: f64 = 1.0;
+    /// How long input stays visible after its last keystroke.
+    pub const INPUT_VISIBLE_SECS: f64 = 3.0;
+    /// Longest input run kept; older characters scroll off the front.
+    pub const INPUT_MAX_CHARS: usize = 32;
+
+    /// Forget everything seen so far (e.g. before replaying from the start).
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Record an event replayed at cumulative time `time`.
+    ///
+    /// Events other than input and exit are ignored.
+    pub fn observe(&mut self, time: f64, event: &Event) {
+        if let Some(code) = event.parse_exit_code() {
+            self.exit_code = Some(code);
+            return;
+        }
+        if !event.is_input() {
+            return;
+        }
+        let continues_run = self
+            .input_time
+            .is_some_and(|last| time - last <= Self::INPUT_MERGE_GAP);
+        if !continues_run {
+            self.input_text.clear();
+        }
+        self.input_text.push_str(&printable_input(&event.data));
+
+        let excess = self
+            .input_text
+            .chars()
+            .count()
+            .saturating_sub(Self::INPUT_MAX_CHARS);
+        if excess > 0 {
+            self.input_text = self.input_text.chars().skip(excess).collect();
+        }
+        self.input_time = Some(time);
+    }
+
+    /// Input typed shortly before `time`, if any.
+    pub fn input_at(&self, time: f64) -> Option<&str> {
+        let last = self.input_time?;
+        if time < last || time - last > Self::INPUT_VISIBLE_SECS || self.input_text.is_empty() {
+            return None;
+        }
+        Some(&self.input_text)
+    }
+
+    /// Exit code of the recorded process, once playback has reached it.
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+}
+
+/// Escape raw terminal input so it can be drawn on a single status line.
+///
+/// Enter, tab, backspace, escape and the arrow keys get symbols; other
+/// control characters use caret notation (`^C`). Bracketed paste markers
+/// (`ESC [200~` / `ESC [201~`) are dropped, leaving just the pasted text.
+pub fn printable_input(data: &str) -> String {
+    let mut out = String::with_capacity(data.len());
+    let mut chars = data.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' | '\n' => out.push('⏎'),
+            '\t' => out.push('⇥'),
+            '\x7f' | '\x08' => out.push('⌫'),
+            '\x1b'
+                if ["[200~", "[201~"]
+                    .iter()
+                    .any(|m| chars.as_str().starts_with(m)) =>
+            {
+                chars.nth(4);
+            }
+            '\x1b' => {
+                // Arrow keys arrive as CSI (`ESC [ A`) or SS3 (`ESC O A`)
+                let mut lookahead = chars.clone();
+                let arrow = match (lookahead.next(), lookahead.next()) {
+                    (Some('[' | 'O'), Some('A')) => Some('↑'),
+                    (Some('[' | 'O'), Some('B')) => Some('↓'),
+                    (Some('[' | 'O'), Some('C')) => Some('→'),
+                    (Some('[' | 'O'), Some('D')) => Some('←'),
+                    _ => None,
+                };
+                match arrow {
+                    Some(symbol) => {
+                        chars.next();
+                        chars.next();
+                        out.push(symbol);
+                    }
+                    None => out.push('⎋'),
+                }
+            }
+            c if (c as u32) < 0x20 => {
+                out.push('^');
+                out.push((b'@' + c as u8) as char);
+            }
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::EventType;
+
+    fn input(data: &str) -> Event {
+        Event::new(0.0, EventType::Input, data)
+    }
+
+    #[test]
+    fn observe_ignores_non_input_events() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(1.0, &Event::output(0.0, "ls"));
+        assert_eq!(annotations.input_at(1.0), None);
+    }
+
+    #[test]
+    fn nearby_input_is_merged_into_one_run() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(1.0, &input("l"));
+        annotations.observe(1.2, &input("s"));
+        annotations.observe(1.5, &input("\r"));
+        assert_eq!(annotations.input_at(2.0), Some("ls⏎"));
+    }
+
+    #[test]
+    fn pause_in_typing_starts_a_new_run() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(1.0, &input("ls"));
+        annotations.observe(5.0, &input("pwd"));
+        assert_eq!(annotations.input_at(5.0), Some("pwd"));
+    }
+
+    #[test]
+    fn input_expires_after_visible_window() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(1.0, &input("ls"));
+        assert_eq!(annotations.input_at(3.9), Some("ls"));
+        assert_eq!(annotations.input_at(4.1), None);
+    }
+
+    #[test]
+    fn input_is_hidden_before_it_was_typed() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(2.0, &input("ls"));
+        assert_eq!(annotations.input_at(1.0), None);
+    }
+
+    #[test]
+    fn long_runs_keep_the_most_recent_characters() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(1.0, &input(&"a".repeat(40)));
+        annotations.observe(1.1, &input("END"));
+        let text = annotations.input_at(1.1).unwrap();
+        assert_eq!(text.chars().count(), EventAnnotations::INPUT_MAX_CHARS);
+        assert!(text.ends_with("aEND"));
+    }
+
+    #[test]
+    fn clear_forgets_input() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(1.0, &input("ls"));
+        annotations.clear();
+        assert_eq!(annotations.input_at(1.0), None);
+    }
+
+    #[test]
+    fn observe_records_exit_code() {
+        let mut annotations = EventAnnotations::default();
+        assert_eq!(annotations.exit_code(), None);
+        annotations.observe(1.0, &Event::new(1.0, EventType::Exit, "2"));
+        assert_eq!(annotations.exit_code(), Some(2));
+        annotations.clear();
+        assert_eq!(annotations.exit_code(), None);
+    }
+
+    #[test]
+    fn malformed_exit_event_is_ignored() {
+        let mut annotations = EventAnnotations::default();
+        annotations.observe(1.0, &Event::new(1.0, EventType::Exit, "oops"));
+        assert_eq!(annotations.exit_code(), None);
+    }
+
+    #[test]
+    fn printable_input_escapes_control_keys() {
+        assert_eq!(printable_input("ls -la\r"), "ls -la⏎");
+        assert_eq!(printable_input("a\tb\x7f"), "a⇥b⌫");
+        assert_eq!(printable_input("\x03"), "^C");
+        assert_eq!(printable_input("\x1b[A\x1b[B\x1bOC\x1b[D"), "↑↓→←");
+        assert_eq!(printable_input("\x1b"), "⎋");
+    }
+
+    #[test]
+    fn printable_input_drops_bracketed_paste_markers() {
+        assert_eq!(
+            printable_input("\x1b[200~git status\x1b[201~\r"),
+            "git status⏎"
+        );
+        // A lone marker split from its paste is dropped too
+        assert_eq!(printable_input("done\x1b[201~"), "done");
+    }
+}
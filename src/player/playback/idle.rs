@@ -0,0 +1,61 @@
+//! Live idle skipping for the native player.
+//!
+//! Mirrors the `CapIdleTime` transform at playback time: a pause between
+//! two events that is longer than the limit is cut short once the limit has
+//! elapsed, without touching the recording.
+
+use crate::asciicast::AsciicastFile;
+
+/// Seconds to skip so the pause before the next event lasts at most `limit`.
+///
+/// `event_idx` and `cumulative_time` describe the next event to play, as
+/// tracked by the playback loop, and `elapsed` is the current playback time.
+///
+/// # Returns
+/// `None` while the pause is still within the limit, or when there is no
+/// next event.
+pub fn find_idle_skip(
+    cast: &AsciicastFile,
+    event_idx: usize,
+    cumulative_time: f64,
+    elapsed: f64,
+    limit: f64,
+) -> Option<f64> {
+    let gap = cast.events.get(event_idx)?.time;
+    let next_time = cumulative_time + gap;
+    let skip = next_time - elapsed;
+    (gap > limit && elapsed - cumulative_time >= limit && skip > 0.0).then_some(skip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::{Event, Header};
+
+    fn make_cast(event_times: &[f64]) -> AsciicastFile {
+        let mut cast = AsciicastFile::new(Header::v3(80, 24));
+        cast.events = event_times.iter().map(|&t| Event::output(t, "x")).collect();
+        cast
+    }
+
+    #[test]
+    fn long_pause_is_skipped_once_limit_elapsed() {
+        // Second event comes 30s after the first one at 1s
+        let cast = make_cast(&[1.0, 30.0]);
+
+        assert_eq!(find_idle_skip(&cast, 1, 1.0, 2.5, 2.0), None);
+        assert_eq!(find_idle_skip(&cast, 1, 1.0, 3.0, 2.0), Some(28.0));
+    }
+
+    #[test]
+    fn short_pause_is_never_skipped() {
+        let cast = make_cast(&[1.0, 1.5]);
+        assert_eq!(find_idle_skip(&cast, 1, 1.0, 2.4, 2.0), None);
+    }
+
+    #[test]
+    fn nothing_to_skip_after_last_event() {
+        let cast = make_cast(&[1.0]);
+        assert_eq!(find_idle_skip(&cast, 1, 1.0, 60.0, 2.0), None);
+    }
+}
@@ -8,8 +8,8 @@ use crate::player::state::MarkerPosition;
 
 /// Collect markers from the cast file with their cumulative times.
 ///
-/// Iterates through all events and extracts markers, calculating
-/// their cumulative time position in the recording.
+/// Thin wrapper over [`AsciicastFile::marker_positions`], kept for the
+/// player's call sites.
 ///
 /// # Arguments
 /// * `cast` - The parsed asciicast file
@@ -17,20 +17,24 @@ use crate::player::state::MarkerPosition;
 /// # Returns
 /// A vector of `MarkerPosition` structs sorted by time
 pub fn collect_markers(cast: &AsciicastFile) -> Vec<MarkerPosition> {
-    let mut markers = Vec::new();
-    let mut cumulative = 0.0f64;
-
-    for event in &cast.events {
-        cumulative += event.time;
-        if event.is_marker() {
-            markers.push(MarkerPosition {
-                time: cumulative,
-                label: event.data.clone(),
-            });
-        }
-    }
+    cast.marker_positions()
+}
 
-    markers
+/// Find the first marker crossed when playback advances from `from` to `to`.
+///
+/// The range is half-open (`from < time <= to`) so a playhead parked exactly
+/// on a marker does not stop there again when playback resumes.
+///
+/// # Arguments
+/// * `markers` - Markers sorted by time
+/// * `from` - Playback time before advancing
+/// * `to` - Playback time after advancing
+pub fn find_marker_crossed(
+    markers: &[MarkerPosition],
+    from: f64,
+    to: f64,
+) -> Option<&MarkerPosition> {
+    markers.iter().find(|m| m.time > from && m.time <= to)
 }
 
 #[cfg(test)]
@@ -141,4 +145,32 @@ mod tests {
         assert_eq!(markers[0].time, 0.0);
         assert_eq!(markers[0].label, "start");
     }
+
+    fn marker(time: f64, label: &str) -> MarkerPosition {
+        MarkerPosition {
+            time,
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn find_marker_crossed_returns_first_in_range() {
+        let markers = vec![marker(1.0, "a"), marker(2.0, "b"), marker(3.0, "c")];
+        let found = find_marker_crossed(&markers, 1.5, 3.5).unwrap();
+        assert_eq!(found.label, "b");
+    }
+
+    #[test]
+    fn find_marker_crossed_includes_end_excludes_start() {
+        let markers = vec![marker(2.0, "b")];
+        assert!(find_marker_crossed(&markers, 1.0, 2.0).is_some());
+        assert!(find_marker_crossed(&markers, 2.0, 3.0).is_none());
+    }
+
+    #[test]
+    fn find_marker_crossed_none_when_no_marker_in_range() {
+        let markers = vec![marker(5.0, "a")];
+        assert!(find_marker_crossed(&markers, 0.0, 4.9).is_none());
+        assert!(find_marker_crossed(&[], 0.0, 10.0).is_none());
+    }
 }
@@ -1,9 +1,21 @@
 //! Playback logic for the native player.
 //!
-//! This module handles seeking, marker collection, and playback time management.
+//! This module handles seeking, marker collection, playback time management,
+//! status annotations from non-output events, text search, live idle skipping,
+//! and persisting the resume position.
 
+mod annotations;
+mod idle;
 mod markers;
+mod resume;
+mod search;
 mod seeking;
 
-pub use markers::collect_markers;
-pub use seeking::{find_event_index_at_time, seek_to_time};
+pub use annotations::EventAnnotations;
+pub use idle::find_idle_skip;
+pub use markers::{collect_markers, find_marker_crossed};
+pub use resume::{clear_position, load_position, save_position};
+pub use search::{find_in_cells, find_output_match, SearchDirection};
+pub use seeking::{
+    find_event_index_at_time, find_next_output_time, find_previous_output_time, seek_to_time,
+};
@@ -0,0 +1,98 @@
+//! Resume position persistence for the native player.
+//!
+//! The last playback position is stored in a small sidecar file next to
+//! the recording (`<name>.cast.pos`) containing the time in seconds.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::files::sidecar::position_path;
+
+/// Load the saved position for a recording.
+///
+/// Returns `None` if there is no sidecar, it cannot be parsed, or the
+/// position is not strictly inside `(0, total_duration)`.
+pub fn load_position(cast_path: &Path, total_duration: f64) -> Option<f64> {
+    let content = fs::read_to_string(position_path(cast_path)).ok()?;
+    let time: f64 = content.trim().parse().ok()?;
+    (time.is_finite() && time > 0.0 && time < total_duration).then_some(time)
+}
+
+/// Save the playback position for a recording.
+pub fn save_position(cast_path: &Path, time: f64) -> Result<()> {
+    let path = position_path(cast_path);
+    fs::write(&path, format!("{:.3}\n", time))
+        .with_context(|| format!("Failed to write resume position: {}", path.display()))
+}
+
+/// Remove the saved position for a recording, if any.
+pub fn clear_position(cast_path: &Path) -> Result<()> {
+    let path = position_path(cast_path);
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => {
+            Err(e).with_context(|| format!("Failed to remove resume position: {}", path.display()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn save_then_load_roundtrips() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+
+        save_position(&cast, 42.5).unwrap();
+
+        assert_eq!(load_position(&cast, 100.0), Some(42.5));
+    }
+
+    #[test]
+    fn load_missing_sidecar_returns_none() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+
+        assert_eq!(load_position(&cast, 100.0), None);
+    }
+
+    #[test]
+    fn load_ignores_position_outside_duration() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+
+        save_position(&cast, 150.0).unwrap();
+        assert_eq!(load_position(&cast, 100.0), None);
+
+        save_position(&cast, 0.0).unwrap();
+        assert_eq!(load_position(&cast, 100.0), None);
+    }
+
+    #[test]
+    fn load_ignores_garbage() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+        fs::write(position_path(&cast), "not a number").unwrap();
+
+        assert_eq!(load_position(&cast, 100.0), None);
+    }
+
+    #[test]
+    fn clear_removes_sidecar_and_tolerates_missing() {
+        let dir = TempDir::new().unwrap();
+        let cast = dir.path().join("session.cast");
+        save_position(&cast, 10.0).unwrap();
+
+        clear_position(&cast).unwrap();
+        assert!(!position_path(&cast).exists());
+
+        clear_position(&cast).unwrap();
+    }
+}
@@ -0,0 +1,249 @@
+//! Text search through the recording for the native player.
+//!
+//! Queries are matched against the ANSI-stripped output of each event.
+//! The scan is incremental: the tail of the previous event is carried over,
+//! so text that an agent streams across several events is still found, at
+//! the event that completes it. On screen, matches are located cell by cell
+//! so the viewport can highlight them.
+//!
+//! Matching uses smart case: a query without uppercase letters ignores case.
+
+use std::ops::Range;
+
+use crate::analyzer::{ContentCleaner, ExtractionConfig};
+use crate::asciicast::AsciicastFile;
+use crate::terminal::Cell;
+
+/// Which way to search from the playhead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDirection {
+    /// First match after the playhead
+    Forward,
+    /// Last match before the playhead
+    Backward,
+}
+
+/// Whether `query` should be matched without regard to case.
+fn ignores_case(query: &str) -> bool {
+    !query.chars().any(char::is_uppercase)
+}
+
+fn chars_match(a: char, b: char, ignore_case: bool) -> bool {
+    a == b || (ignore_case && a.to_lowercase().eq(b.to_lowercase()))
+}
+
+/// Find the time of the output event that completes a match of `query`.
+///
+/// Searching forward returns the first match strictly after `time`,
+/// searching backward the last match strictly before it. The returned time
+/// is the event's cumulative time, so seeking there shows the match.
+///
+/// # Returns
+/// `None` if the query is empty or there is no match in that direction.
+pub fn find_output_match(
+    cast: &AsciicastFile,
+    query: &str,
+    time: f64,
+    direction: SearchDirection,
+) -> Option<f64> {
+    if query.is_empty() {
+        return None;
+    }
+    let ignore_case = ignores_case(query);
+    let fold = |text: &str| {
+        if ignore_case {
+            text.to_lowercase()
+        } else {
+            text.to_string()
+        }
+    };
+    let query = fold(query);
+    // A match needs at least one new char, so a carry one char shorter than
+    // the query can never repeat a match already reported
+    let carry_len = query.chars().count() - 1;
+
+    let mut cleaner = ContentCleaner::new(&ExtractionConfig::default());
+    let mut carry = String::new();
+    let mut cumulative = 0.0f64;
+    let mut last_before = None;
+
+    for event in &cast.events {
+        cumulative += event.time;
+        if direction == SearchDirection::Backward && cumulative >= time {
+            break;
+        }
+        if !event.is_output() {
+            continue;
+        }
+
+        let window = carry + &fold(&cleaner.clean(&event.data));
+        if window.contains(&query) {
+            match direction {
+                SearchDirection::Forward if cumulative > time => return Some(cumulative),
+                SearchDirection::Forward => {}
+                SearchDirection::Backward => last_before = Some(cumulative),
+            }
+        }
+        let skip = window.chars().count().saturating_sub(carry_len);
+        carry = window.chars().skip(skip).collect();
+    }
+
+    last_before
+}
+
+/// Find the cell ranges of a row that match `query`.
+///
+/// Wide-glyph continuation cells are skipped when comparing, and a range
+/// ending on a wide glyph includes its continuation cell. Matches do not
+/// overlap.
+pub fn find_in_cells(row: &[Cell], query: &str) -> Vec<Range<usize>> {
+    let ignore_case = ignores_case(query);
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    // Printable glyphs with the cell index they start at
+    let glyphs: Vec<(usize, char)> = row
+        .iter()
+        .enumerate()
+        .filter(|(_, cell)| !cell.is_wide_continuation())
+        .map(|(i, cell)| (i, cell.char))
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + query.len() <= glyphs.len() {
+        let is_match = glyphs[i..i + query.len()]
+            .iter()
+            .zip(&query)
+            .all(|(&(_, c), &q)| chars_match(c, q, ignore_case));
+        if is_match {
+            let start = glyphs[i].0;
+            let end = glyphs
+                .get(i + query.len())
+                .map_or(row.len(), |&(cell, _)| cell);
+            matches.push(start..end);
+            i += query.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asciicast::{Event, EventType, Header};
+    use crate::terminal::TerminalBuffer;
+
+    fn make_cast(events: Vec<Event>) -> AsciicastFile {
+        let mut cast = AsciicastFile::new(Header::v3(80, 24));
+        cast.events = events;
+        cast
+    }
+
+    fn sample_cast() -> AsciicastFile {
+        make_cast(vec![
+            Event::output(1.0, "$ cargo test\r\n"),
+            Event::output(1.0, "\x1b[31merror\x1b[0m: build failed\r\n"),
+            Event::new(1.0, EventType::Input, "error"),
+            Event::output(1.0, "retrying\r\n"),
+            Event::output(1.0, "Error: still failing\r\n"),
+        ])
+    }
+
+    #[test]
+    fn forward_search_finds_first_match_after_time() {
+        let cast = sample_cast();
+        assert_eq!(
+            find_output_match(&cast, "error", 0.0, SearchDirection::Forward),
+            Some(2.0)
+        );
+        // The playhead sits on the first match, so the next one is found
+        assert_eq!(
+            find_output_match(&cast, "error", 2.0, SearchDirection::Forward),
+            Some(5.0)
+        );
+        assert_eq!(
+            find_output_match(&cast, "error", 5.0, SearchDirection::Forward),
+            None
+        );
+    }
+
+    #[test]
+    fn backward_search_finds_last_match_before_time() {
+        let cast = sample_cast();
+        assert_eq!(
+            find_output_match(&cast, "error", 5.0, SearchDirection::Backward),
+            Some(2.0)
+        );
+        assert_eq!(
+            find_output_match(&cast, "error", 2.0, SearchDirection::Backward),
+            None
+        );
+    }
+
+    #[test]
+    fn uppercase_query_is_case_sensitive() {
+        let cast = sample_cast();
+        assert_eq!(
+            find_output_match(&cast, "Error", 0.0, SearchDirection::Forward),
+            Some(5.0)
+        );
+    }
+
+    #[test]
+    fn input_events_are_not_searched() {
+        let cast = make_cast(vec![
+            Event::new(1.0, EventType::Input, "needle"),
+            Event::output(1.0, "haystack"),
+        ]);
+        assert_eq!(
+            find_output_match(&cast, "needle", 0.0, SearchDirection::Forward),
+            None
+        );
+    }
+
+    #[test]
+    fn match_split_across_events_is_found_at_completing_event() {
+        let cast = make_cast(vec![
+            Event::output(1.0, "tests pa"),
+            Event::output(1.0, "\x1b[32mssed"),
+            Event::output(1.0, "\r\n"),
+        ]);
+        assert_eq!(
+            find_output_match(&cast, "tests passed", 0.0, SearchDirection::Forward),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn empty_query_never_matches() {
+        assert_eq!(
+            find_output_match(&sample_cast(), "", 0.0, SearchDirection::Forward),
+            None
+        );
+    }
+
+    #[test]
+    fn find_in_cells_returns_all_ranges() {
+        let mut buffer = TerminalBuffer::new(20, 1);
+        buffer.process("Error and error", None);
+
+        let ranges = find_in_cells(buffer.row(0).unwrap(), "error");
+
+        assert_eq!(ranges, vec![0..5, 10..15]);
+    }
+
+    #[test]
+    fn find_in_cells_covers_wide_glyphs() {
+        let mut buffer = TerminalBuffer::new(10, 1);
+        buffer.process("a日本b", None);
+
+        let ranges = find_in_cells(buffer.row(0).unwrap(), "日本");
+
+        assert_eq!(ranges, vec![1..5]);
+    }
+}
@@ -3,6 +3,7 @@
 //! Handles seeking to specific times in the recording, including
 //! rebuilding the terminal buffer state.
 
+use super::EventAnnotations;
 use crate::asciicast::AsciicastFile;
 use crate::terminal::TerminalBuffer;
 
@@ -28,31 +29,76 @@ pub fn find_event_index_at_time(cast: &AsciicastFile, target_time: f64) -> (usiz
     (cast.events.len(), cumulative)
 }
 
+/// Find the cumulative time of the first output event after `time`.
+///
+/// Used for frame stepping: seeking to the returned time replays exactly
+/// one more output event (plus any events sharing its timestamp).
+///
+/// # Returns
+/// `None` if there is no output event after `time`.
+pub fn find_next_output_time(cast: &AsciicastFile, time: f64) -> Option<f64> {
+    let mut cumulative = 0.0f64;
+    for event in &cast.events {
+        cumulative += event.time;
+        if cumulative > time && event.is_output() {
+            return Some(cumulative);
+        }
+    }
+    None
+}
+
+/// Find the time to seek to so the last output event at or before `time` is undone.
+///
+/// Returns the cumulative time of the output event before that one, or 0.0
+/// if there is none, so the screen can be rebuilt from scratch via [`seek_to_time`].
+pub fn find_previous_output_time(cast: &AsciicastFile, time: f64) -> f64 {
+    let mut cumulative = 0.0f64;
+    let mut last = None;
+    let mut previous = None;
+    for event in &cast.events {
+        cumulative += event.time;
+        if cumulative > time {
+            break;
+        }
+        if event.is_output() && last != Some(cumulative) {
+            previous = last;
+            last = Some(cumulative);
+        }
+    }
+    previous.unwrap_or(0.0)
+}
+
 /// Seek to a specific time by re-rendering the buffer from scratch.
 ///
 /// This clears the terminal buffer and replays all events up to the target time.
 /// This is necessary because terminal state depends on all previous output.
+/// The annotations are rebuilt the same way, so input seen before the target
+/// time is available to the status bar.
 ///
 /// # Arguments
 /// * `buffer` - The terminal buffer to update
+/// * `annotations` - Status annotations to rebuild alongside the buffer
 /// * `cast` - The parsed asciicast file
 /// * `target_time` - The time to seek to (in seconds)
 /// * `cols` - Recording width (for buffer reset)
 /// * `rows` - Recording height (for buffer reset)
 pub fn seek_to_time(
     buffer: &mut TerminalBuffer,
+    annotations: &mut EventAnnotations,
     cast: &AsciicastFile,
     target_time: f64,
     cols: u32,
     rows: u32,
 ) {
-    *buffer = TerminalBuffer::new(cols as usize, rows as usize);
+    buffer.reset(cols as usize, rows as usize);
+    annotations.clear();
     let mut cumulative = 0.0f64;
     for event in &cast.events {
         cumulative += event.time;
         if cumulative > target_time {
             break;
         }
+        annotations.observe(cumulative, event);
         if event.is_output() {
             buffer.process(&event.data, None);
         } else if let Some((new_cols, new_rows)) = event.parse_resize() {
@@ -144,6 +190,39 @@ mod tests {
         assert_eq!(cumulative, 3.0);
     }
 
+    #[test]
+    fn next_output_time_finds_following_event() {
+        let cast = make_cast(&[1.0, 1.0, 1.0]); // Events at t=1, t=2, t=3
+        assert_eq!(find_next_output_time(&cast, 0.0), Some(1.0));
+        assert_eq!(find_next_output_time(&cast, 1.0), Some(2.0));
+        assert_eq!(find_next_output_time(&cast, 1.5), Some(2.0));
+        assert_eq!(find_next_output_time(&cast, 3.0), None);
+    }
+
+    #[test]
+    fn next_output_time_skips_markers() {
+        let cast = AsciicastFile {
+            header: make_header(),
+            events: vec![Event::marker(1.0, "m"), Event::output(1.0, "x")],
+        };
+        assert_eq!(find_next_output_time(&cast, 0.0), Some(2.0));
+    }
+
+    #[test]
+    fn previous_output_time_undoes_last_event() {
+        let cast = make_cast(&[1.0, 1.0, 1.0]); // Events at t=1, t=2, t=3
+        assert_eq!(find_previous_output_time(&cast, 3.0), 2.0);
+        assert_eq!(find_previous_output_time(&cast, 2.5), 1.0);
+        assert_eq!(find_previous_output_time(&cast, 1.0), 0.0);
+        assert_eq!(find_previous_output_time(&cast, 0.5), 0.0);
+    }
+
+    #[test]
+    fn previous_output_time_treats_same_timestamp_as_one_frame() {
+        let cast = make_cast(&[1.0, 1.0, 0.0]); // Events at t=1, t=2, t=2
+        assert_eq!(find_previous_output_time(&cast, 2.0), 1.0);
+    }
+
     #[test]
     fn seek_to_zero_clears_buffer() {
         let cast = AsciicastFile {
@@ -157,7 +236,14 @@ mod tests {
         let mut buffer = TerminalBuffer::new(80, 24);
         buffer.process("some content", None);
 
-        seek_to_time(&mut buffer, &cast, 0.0, 80, 24);
+        seek_to_time(
+            &mut buffer,
+            &mut EventAnnotations::default(),
+            &cast,
+            0.0,
+            80,
+            24,
+        );
 
         // Buffer should be cleared (no content at 0.0)
         let row = buffer.row(0).unwrap();
@@ -176,7 +262,14 @@ mod tests {
         };
         let mut buffer = TerminalBuffer::new(80, 24);
 
-        seek_to_time(&mut buffer, &cast, 2.0, 80, 24);
+        seek_to_time(
+            &mut buffer,
+            &mut EventAnnotations::default(),
+            &cast,
+            2.0,
+            80,
+            24,
+        );
 
         // Buffer should contain "hello"
         let row = buffer.row(0).unwrap();
@@ -203,11 +296,61 @@ mod tests {
         };
         let mut buffer = TerminalBuffer::new(80, 24);
 
-        seek_to_time(&mut buffer, &cast, 3.0, 80, 24);
+        seek_to_time(
+            &mut buffer,
+            &mut EventAnnotations::default(),
+            &cast,
+            3.0,
+            80,
+            24,
+        );
 
         // Buffer should contain "text" (marker data not rendered)
         let row = buffer.row(0).unwrap();
         let content: String = row.iter().take(4).map(|c| c.char).collect();
         assert_eq!(content, "text");
     }
+
+    #[test]
+    fn seek_rebuilds_input_annotations() {
+        let cast = AsciicastFile {
+            header: make_header(),
+            events: vec![
+                Event::new(1.0, EventType::Input, "ls\r"),
+                Event::output(0.1, "file.txt\r\n"),
+                Event::new(5.0, EventType::Input, "pwd"),
+            ],
+        };
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let mut annotations = EventAnnotations::default();
+
+        seek_to_time(&mut buffer, &mut annotations, &cast, 6.5, 80, 24);
+        assert_eq!(annotations.input_at(6.5), Some("pwd"));
+
+        // Seeking backward past the second input drops it again
+        seek_to_time(&mut buffer, &mut annotations, &cast, 2.0, 80, 24);
+        assert_eq!(annotations.input_at(2.0), Some("ls⏎"));
+
+        seek_to_time(&mut buffer, &mut annotations, &cast, 0.5, 80, 24);
+        assert_eq!(annotations.input_at(0.5), None);
+    }
+
+    #[test]
+    fn seek_backward_past_exit_clears_exit_code() {
+        let cast = AsciicastFile {
+            header: make_header(),
+            events: vec![
+                Event::output(1.0, "bye"),
+                Event::new(1.0, EventType::Exit, "1"),
+            ],
+        };
+        let mut buffer = TerminalBuffer::new(80, 24);
+        let mut annotations = EventAnnotations::default();
+
+        seek_to_time(&mut buffer, &mut annotations, &cast, 2.0, 80, 24);
+        assert_eq!(annotations.exit_code(), Some(1));
+
+        seek_to_time(&mut buffer, &mut annotations, &cast, 1.5, 80, 24);
+        assert_eq!(annotations.exit_code(), None);
+    }
 }
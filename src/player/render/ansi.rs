@@ -1,9 +1,206 @@
 //! ANSI escape code conversion utilities.
 //!
 //! Converts terminal cell styles to ANSI escape sequences for rendering.
+//! RGB colors are emitted as truecolor codes unless the output terminal only
+//! supports 256 colors, in which case they are downsampled (see
+//! [`set_color_depth`]). Recordings that carry a theme can have their palette
+//! and default colors resolved to RGB first (see [`resolve_theme_colors`]).
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::asciicast::{RgbColor, TtyTheme};
 use crate::terminal::{CellStyle, Color as TermColor};
 
+/// How `TermColor::Rgb` is written to the output terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 24-bit `38;2;r;g;b` codes
+    TrueColor,
+    /// Nearest xterm 256-color palette entry, as `38;5;n` codes
+    Ansi256,
+}
+
+impl ColorDepth {
+    /// Resolve the color depth from the `player.color_depth` setting and the
+    /// terminal environment.
+    ///
+    /// `"truecolor"` and `"256"` force a depth. Anything else (normally
+    /// `"auto"`) stays truecolor unless the terminal is known to lack it:
+    /// Apple Terminal (`$TERM_PROGRAM`) or the Linux console (`$TERM`).
+    /// `$COLORTERM` of `truecolor` or `24bit` always means truecolor.
+    pub fn detect(setting: &str, env: &dyn Fn(&str) -> Option<String>) -> Self {
+        match setting {
+            "truecolor" => ColorDepth::TrueColor,
+            "256" => ColorDepth::Ansi256,
+            _ => {
+                let colorterm = env("COLORTERM");
+                if matches!(colorterm.as_deref(), Some("truecolor") | Some("24bit")) {
+                    return ColorDepth::TrueColor;
+                }
+                let apple_terminal = env("TERM_PROGRAM").as_deref() == Some("Apple_Terminal");
+                let linux_console = env("TERM").as_deref() == Some("linux");
+                if apple_terminal || linux_console {
+                    ColorDepth::Ansi256
+                } else {
+                    ColorDepth::TrueColor
+                }
+            }
+        }
+    }
+
+    /// [`ColorDepth::detect`] using the process's environment.
+    pub fn detect_from_env(setting: &str) -> Self {
+        Self::detect(setting, &|name| std::env::var(name).ok())
+    }
+}
+
+/// Whether RGB colors are emitted as truecolor (the default until detected).
+static TRUECOLOR: AtomicBool = AtomicBool::new(true);
+
+/// Set the color depth used for RGB colors by all subsequent renders.
+pub fn set_color_depth(depth: ColorDepth) {
+    TRUECOLOR.store(depth == ColorDepth::TrueColor, Ordering::Relaxed);
+}
+
+/// The color depth currently used for RGB colors.
+pub fn color_depth() -> ColorDepth {
+    if TRUECOLOR.load(Ordering::Relaxed) {
+        ColorDepth::TrueColor
+    } else {
+        ColorDepth::Ansi256
+    }
+}
+
+/// Map an RGB color to the nearest entry of the xterm 256-color palette.
+///
+/// Considers the 6x6x6 color cube (16-231) and the grayscale ramp
+/// (232-255), picking whichever is closer.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    fn cube_index(v: u8) -> u8 {
+        match v {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (v - 35) / 40,
+        }
+    }
+
+    fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+        let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2) as u32;
+        d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+    }
+
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = (
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    );
+
+    let avg = ((u32::from(r) + u32::from(g) + u32::from(b)) / 3) as u8;
+    let gray_index = (avg.saturating_sub(3) / 10).min(23);
+    let gray_level = 8 + gray_index * 10;
+    let gray = (gray_level, gray_level, gray_level);
+
+    if distance((r, g, b), gray) < distance((r, g, b), cube) {
+        232 + gray_index
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+/// Palette index of a named or indexed color.
+fn palette_index(color: TermColor) -> Option<usize> {
+    let index = match color {
+        TermColor::Default | TermColor::Rgb(..) => return None,
+        TermColor::Black => 0,
+        TermColor::Red => 1,
+        TermColor::Green => 2,
+        TermColor::Yellow => 3,
+        TermColor::Blue => 4,
+        TermColor::Magenta => 5,
+        TermColor::Cyan => 6,
+        TermColor::White => 7,
+        TermColor::BrightBlack => 8,
+        TermColor::BrightRed => 9,
+        TermColor::BrightGreen => 10,
+        TermColor::BrightYellow => 11,
+        TermColor::BrightBlue => 12,
+        TermColor::BrightMagenta => 13,
+        TermColor::BrightCyan => 14,
+        TermColor::BrightWhite => 15,
+        TermColor::Indexed(n) => n,
+    };
+    Some(usize::from(index))
+}
+
+/// Resolve one color against a theme, using `default` for the default color.
+fn resolve_theme_color(color: TermColor, default: RgbColor, palette: &[RgbColor]) -> TermColor {
+    let rgb = match color {
+        TermColor::Default => Some(default),
+        _ => palette_index(color).and_then(|i| palette.get(i).copied()),
+    };
+    rgb.map_or(color, |c| TermColor::Rgb(c.r, c.g, c.b))
+}
+
+/// Resolve a style's colors against the recording's theme.
+///
+/// Named and indexed colors covered by the theme palette become its RGB
+/// values, and the default colors become the theme's foreground and
+/// background. Without a theme the style is returned unchanged, leaving the
+/// colors to the host terminal.
+pub fn resolve_theme_colors(style: &CellStyle, theme: Option<&TtyTheme>) -> CellStyle {
+    let Some(theme) = theme else {
+        return *style;
+    };
+    CellStyle {
+        fg: resolve_theme_color(style.fg, theme.fg, &theme.palette),
+        bg: resolve_theme_color(style.bg, theme.bg, &theme.palette),
+        ..*style
+    }
+}
+
+/// SGR parameters selecting `color` on `layer` (`38` foreground, `48`
+/// background), or `None` for the terminal default.
+///
+/// RGB colors are downsampled when truecolor is unavailable.
+fn color_params(color: &TermColor, layer: u8) -> Option<String> {
+    // 30-37/90-97 for foreground, 40-47/100-107 for background
+    let basic = |offset: u8| Some((layer - 8 + offset).to_string());
+    match color {
+        TermColor::Default => None,
+        TermColor::Black => basic(0),
+        TermColor::Red => basic(1),
+        TermColor::Green => basic(2),
+        TermColor::Yellow => basic(3),
+        TermColor::Blue => basic(4),
+        TermColor::Magenta => basic(5),
+        TermColor::Cyan => basic(6),
+        TermColor::White => basic(7),
+        TermColor::BrightBlack => basic(60),
+        TermColor::BrightRed => basic(61),
+        TermColor::BrightGreen => basic(62),
+        TermColor::BrightYellow => basic(63),
+        TermColor::BrightBlue => basic(64),
+        TermColor::BrightMagenta => basic(65),
+        TermColor::BrightCyan => basic(66),
+        TermColor::BrightWhite => basic(67),
+        TermColor::Indexed(n) => Some(format!("{};5;{}", layer, n)),
+        TermColor::Rgb(r, g, b) => Some(match color_depth() {
+            ColorDepth::TrueColor => format!("{};2;{};{};{}", layer, r, g, b),
+            ColorDepth::Ansi256 => format!("{};5;{}", layer, rgb_to_ansi256(*r, *g, *b)),
+        }),
+    }
+}
+
+/// Append a single SGR escape sequence with the given parameters.
+fn push_sgr(buf: &mut String, params: &str) {
+    buf.push_str("\x1b[");
+    buf.push_str(params);
+    buf.push('m');
+}
+
 /// Convert cell style foreground to ANSI escape code.
 ///
 /// Appends the appropriate ANSI escape sequence to the buffer
@@ -16,88 +213,12 @@ use crate::terminal::{CellStyle, Color as TermColor};
 /// # Returns
 /// `true` if a code was appended, `false` if the color is default
 pub fn style_to_ansi_fg(style: &CellStyle, buf: &mut String) -> bool {
-    match &style.fg {
-        TermColor::Default => false,
-        TermColor::Black => {
-            buf.push_str("\x1b[30m");
-            true
-        }
-        TermColor::Red => {
-            buf.push_str("\x1b[31m");
-            true
-        }
-        TermColor::Green => {
-            buf.push_str("\x1b[32m");
-            true
-        }
-        TermColor::Yellow => {
-            buf.push_str("\x1b[33m");
-            true
-        }
-        TermColor::Blue => {
-            buf.push_str("\x1b[34m");
-            true
-        }
-        TermColor::Magenta => {
-            buf.push_str("\x1b[35m");
-            true
-        }
-        TermColor::Cyan => {
-            buf.push_str("\x1b[36m");
-            true
-        }
-        TermColor::White => {
-            buf.push_str("\x1b[37m");
-            true
-        }
-        TermColor::BrightBlack => {
-            buf.push_str("\x1b[90m");
-            true
-        }
-        TermColor::BrightRed => {
-            buf.push_str("\x1b[91m");
-            true
-        }
-        TermColor::BrightGreen => {
-            buf.push_str("\x1b[92m");
-            true
-        }
-        TermColor::BrightYellow => {
-            buf.push_str("\x1b[93m");
-            true
-        }
-        TermColor::BrightBlue => {
-            buf.push_str("\x1b[94m");
-            true
-        }
-        TermColor::BrightMagenta => {
-            buf.push_str("\x1b[95m");
-            true
-        }
-        TermColor::BrightCyan => {
-            buf.push_str("\x1b[96m");
-            true
-        }
-        TermColor::BrightWhite => {
-            buf.push_str("\x1b[97m");
-            true
-        }
-        TermColor::Indexed(n) => {
-            buf.push_str("\x1b[38;5;");
-            buf.push_str(&n.to_string());
-            buf.push('m');
-            true
-        }
-        TermColor::Rgb(r, g, b) => {
-            buf.push_str("\x1b[38;2;");
-            buf.push_str(&r.to_string());
-            buf.push(';');
-            buf.push_str(&g.to_string());
-            buf.push(';');
-            buf.push_str(&b.to_string());
-            buf.push('m');
+    match color_params(&style.fg, 38) {
+        Some(params) => {
+            push_sgr(buf, &params);
             true
         }
+        None => false,
     }
 }
 
@@ -113,88 +234,12 @@ pub fn style_to_ansi_fg(style: &CellStyle, buf: &mut String) -> bool {
 /// # Returns
 /// `true` if a code was appended, `false` if the color is default
 pub fn style_to_ansi_bg(style: &CellStyle, buf: &mut String) -> bool {
-    match &style.bg {
-        TermColor::Default => false,
-        TermColor::Black => {
-            buf.push_str("\x1b[40m");
-            true
-        }
-        TermColor::Red => {
-            buf.push_str("\x1b[41m");
-            true
-        }
-        TermColor::Green => {
-            buf.push_str("\x1b[42m");
-            true
-        }
-        TermColor::Yellow => {
-            buf.push_str("\x1b[43m");
-            true
-        }
-        TermColor::Blue => {
-            buf.push_str("\x1b[44m");
-            true
-        }
-        TermColor::Magenta => {
-            buf.push_str("\x1b[45m");
-            true
-        }
-        TermColor::Cyan => {
-            buf.push_str("\x1b[46m");
-            true
-        }
-        TermColor::White => {
-            buf.push_str("\x1b[47m");
-            true
-        }
-        TermColor::BrightBlack => {
-            buf.push_str("\x1b[100m");
-            true
-        }
-        TermColor::BrightRed => {
-            buf.push_str("\x1b[101m");
-            true
-        }
-        TermColor::BrightGreen => {
-            buf.push_str("\x1b[102m");
-            true
-        }
-        TermColor::BrightYellow => {
-            buf.push_str("\x1b[103m");
-            true
-        }
-        TermColor::BrightBlue => {
-            buf.push_str("\x1b[104m");
-            true
-        }
-        TermColor::BrightMagenta => {
-            buf.push_str("\x1b[105m");
-            true
-        }
-        TermColor::BrightCyan => {
-            buf.push_str("\x1b[106m");
-            true
-        }
-        TermColor::BrightWhite => {
-            buf.push_str("\x1b[107m");
-            true
-        }
-        TermColor::Indexed(n) => {
-            buf.push_str("\x1b[48;5;");
-            buf.push_str(&n.to_string());
-            buf.push('m');
-            true
-        }
-        TermColor::Rgb(r, g, b) => {
-            buf.push_str("\x1b[48;2;");
-            buf.push_str(&r.to_string());
-            buf.push(';');
-            buf.push_str(&g.to_string());
-            buf.push(';');
-            buf.push_str(&b.to_string());
-            buf.push('m');
+    match color_params(&style.bg, 48) {
+        Some(params) => {
+            push_sgr(buf, &params);
             true
         }
+        None => false,
     }
 }
 
@@ -221,10 +266,121 @@ pub fn style_to_ansi_attrs(style: &CellStyle, buf: &mut String) {
     }
 }
 
+/// Emits only the SGR changes between consecutive cell styles.
+///
+/// Rendering absolute codes for every style change (reset, then fg, bg and
+/// attributes) repeats everything the cells have in common. The differ
+/// remembers the last emitted style and writes one combined sequence with
+/// just the parameters that changed, or a single `\x1b[0m` when returning
+/// to the default style.
+#[derive(Debug, Clone, Default)]
+pub struct StyleDiffer {
+    current: CellStyle,
+}
+
+impl StyleDiffer {
+    /// Create a differ that assumes the terminal is at the default style.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append the codes needed to switch from the current style to `style`.
+    pub fn transition(&mut self, style: &CellStyle, buf: &mut String) {
+        let from = self.current;
+        if *style == from {
+            return;
+        }
+        self.current = *style;
+
+        if *style == CellStyle::default() {
+            buf.push_str("\x1b[0m");
+            return;
+        }
+
+        let mut params: Vec<String> = Vec::new();
+        // Bold and dim share the "normal intensity" code, so clearing either
+        // clears both and the survivor has to be set again.
+        let clear_intensity = (from.bold && !style.bold) || (from.dim && !style.dim);
+        if clear_intensity {
+            params.push("22".to_string());
+        }
+        if style.bold && (clear_intensity || !from.bold) {
+            params.push("1".to_string());
+        }
+        if style.dim && (clear_intensity || !from.dim) {
+            params.push("2".to_string());
+        }
+
+        let mut toggle = |on: bool, was: bool, set: &str, unset: &str| {
+            if on != was {
+                params.push(if on { set } else { unset }.to_string());
+            }
+        };
+        toggle(style.italic, from.italic, "3", "23");
+        toggle(style.underline, from.underline, "4", "24");
+        toggle(style.reverse, from.reverse, "7", "27");
+        if style.fg != from.fg {
+            params.push(color_params(&style.fg, 38).unwrap_or_else(|| "39".to_string()));
+        }
+        if style.bg != from.bg {
+            params.push(color_params(&style.bg, 48).unwrap_or_else(|| "49".to_string()));
+        }
+
+        push_sgr(buf, &params.join(";"));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn test_theme() -> TtyTheme {
+        TtyTheme {
+            fg: RgbColor::new(0xcc, 0xcc, 0xcc),
+            bg: RgbColor::new(0x11, 0x11, 0x11),
+            palette: (0..8)
+                .map(|i| RgbColor::new(i * 10, i * 20, i * 30))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn resolve_theme_colors_maps_indexed_to_palette() {
+        let theme = test_theme();
+        let style = CellStyle {
+            fg: TermColor::Indexed(1),
+            bg: TermColor::Red,
+            ..CellStyle::default()
+        };
+
+        let resolved = resolve_theme_colors(&style, Some(&theme));
+
+        assert_eq!(resolved.fg, TermColor::Rgb(10, 20, 30));
+        assert_eq!(resolved.bg, TermColor::Rgb(10, 20, 30));
+    }
+
+    #[test]
+    fn resolve_theme_colors_maps_default_to_theme_fg_and_bg() {
+        let resolved = resolve_theme_colors(&CellStyle::default(), Some(&test_theme()));
+
+        assert_eq!(resolved.fg, TermColor::Rgb(0xcc, 0xcc, 0xcc));
+        assert_eq!(resolved.bg, TermColor::Rgb(0x11, 0x11, 0x11));
+    }
+
+    #[test]
+    fn resolve_theme_colors_keeps_colors_outside_palette() {
+        let style = CellStyle {
+            fg: TermColor::BrightRed,
+            bg: TermColor::Indexed(200),
+            bold: true,
+            ..CellStyle::default()
+        };
+
+        // An 8-color palette doesn't cover the bright colors
+        assert_eq!(resolve_theme_colors(&style, Some(&test_theme())), style);
+        assert_eq!(resolve_theme_colors(&style, None), style);
+    }
+
     #[test]
     fn style_to_ansi_fg_default_returns_false() {
         let style = CellStyle::default();
@@ -391,4 +547,154 @@ mod tests {
         style_to_ansi_attrs(&style, &mut buf);
         assert_eq!(buf, "\x1b[1m\x1b[3m\x1b[4m");
     }
+
+    #[test]
+    fn rgb_to_ansi256_known_mappings() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(0, 255, 0), 46);
+        assert_eq!(rgb_to_ansi256(0, 0, 255), 21);
+        assert_eq!(rgb_to_ansi256(255, 128, 64), 209);
+    }
+
+    #[test]
+    fn rgb_to_ansi256_prefers_grayscale_ramp_for_grays() {
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(8, 8, 8), 232);
+        assert_eq!(rgb_to_ansi256(238, 238, 238), 255);
+    }
+
+    fn detect_with(setting: &str, vars: &[(&str, &str)]) -> ColorDepth {
+        ColorDepth::detect(setting, &|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn color_depth_auto_defaults_to_truecolor() {
+        assert_eq!(detect_with("auto", &[]), ColorDepth::TrueColor);
+        assert_eq!(
+            detect_with("auto", &[("TERM", "xterm-256color"), ("COLORTERM", "yes")]),
+            ColorDepth::TrueColor
+        );
+    }
+
+    #[test]
+    fn color_depth_auto_limits_known_terminals() {
+        assert_eq!(
+            detect_with("auto", &[("TERM_PROGRAM", "Apple_Terminal")]),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect_with("auto", &[("TERM", "linux")]),
+            ColorDepth::Ansi256
+        );
+        // An explicit COLORTERM wins over the terminal heuristics
+        assert_eq!(
+            detect_with(
+                "auto",
+                &[("TERM_PROGRAM", "Apple_Terminal"), ("COLORTERM", "24bit")]
+            ),
+            ColorDepth::TrueColor
+        );
+    }
+
+    #[test]
+    fn color_depth_detect_setting_overrides_environment() {
+        assert_eq!(
+            detect_with("256", &[("COLORTERM", "truecolor")]),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect_with("truecolor", &[("TERM", "linux")]),
+            ColorDepth::TrueColor
+        );
+    }
+
+    // === StyleDiffer tests ===
+
+    fn styled(fg: TermColor, bold: bool) -> CellStyle {
+        CellStyle {
+            fg,
+            bold,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn style_differ_same_style_emits_nothing() {
+        let mut differ = StyleDiffer::new();
+        let mut buf = String::new();
+        differ.transition(&styled(TermColor::Red, true), &mut buf);
+        let first = buf.len();
+        differ.transition(&styled(TermColor::Red, true), &mut buf);
+        assert_eq!(buf.len(), first);
+        assert_eq!(buf, "\x1b[1;31m");
+    }
+
+    #[test]
+    fn style_differ_emits_only_changed_color() {
+        let mut differ = StyleDiffer::new();
+        let mut buf = String::new();
+        differ.transition(&styled(TermColor::Red, true), &mut buf);
+        buf.clear();
+        differ.transition(&styled(TermColor::Blue, true), &mut buf);
+        assert_eq!(buf, "\x1b[34m");
+    }
+
+    #[test]
+    fn style_differ_default_is_single_reset() {
+        let mut differ = StyleDiffer::new();
+        let mut buf = String::new();
+        differ.transition(&styled(TermColor::Red, true), &mut buf);
+        buf.clear();
+        differ.transition(&CellStyle::default(), &mut buf);
+        assert_eq!(buf, "\x1b[0m");
+    }
+
+    #[test]
+    fn style_differ_clears_attributes_and_colors() {
+        let mut differ = StyleDiffer::new();
+        let mut buf = String::new();
+        let from = CellStyle {
+            fg: TermColor::Green,
+            bg: TermColor::Indexed(17),
+            italic: true,
+            underline: true,
+            ..Default::default()
+        };
+        differ.transition(&from, &mut buf);
+        buf.clear();
+        let to = CellStyle {
+            fg: TermColor::Green,
+            underline: true,
+            ..Default::default()
+        };
+        differ.transition(&to, &mut buf);
+        assert_eq!(buf, "\x1b[23;49m");
+    }
+
+    #[test]
+    fn style_differ_restores_dim_after_clearing_bold() {
+        let mut differ = StyleDiffer::new();
+        let mut buf = String::new();
+        let both = CellStyle {
+            fg: TermColor::Red,
+            bold: true,
+            dim: true,
+            ..Default::default()
+        };
+        differ.transition(&both, &mut buf);
+        buf.clear();
+        let dim_only = CellStyle {
+            fg: TermColor::Red,
+            dim: true,
+            ..Default::default()
+        };
+        differ.transition(&dim_only, &mut buf);
+        assert_eq!(buf, "\x1b[22;2m");
+    }
 }
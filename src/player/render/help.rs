@@ -21,26 +21,41 @@ pub const HELP_LINES: &[&str] = &[
     "  ║                                           ║",
     "  ║  Playback                                 ║",
     "  ║    Space      Pause / Resume              ║",
-    "  ║    <-/->      Seek +/-5s                  ║",
+    "  ║    <-/->      Seek by step (default 5s)   ║",
     "  ║    Shift+<-/->  Seek +/-5%                ║",
     "  ║    +/-        Speed up / down             ║",
+    "  ║    l          Toggle loop playback        ║",
+    "  ║    [ / ]      Shrink / grow seek step     ║",
+    "  ║    , / .      Step one event (paused)     ║",
     "  ║    Home/End   Go to start / end           ║",
+    "  ║    0-9        Jump to 0% - 90%            ║",
     "  ║                                           ║",
     "  ║  Markers                                  ║",
     "  ║    m          Jump to next marker         ║",
+    "  ║    P          Toggle pause at markers     ║",
     "  ║                                           ║",
     "  ║  Free Mode (line-by-line navigation)      ║",
     "  ║    f          Toggle free mode            ║",
     "  ║    Up/Down    Move highlight up/down      ║",
+    "  ║    Shift+Up/Down  Extend selection        ║",
+    "  ║    y          Copy selected line(s)       ║",
     "  ║    Esc        Exit free mode              ║",
     "  ║                                           ║",
     "  ║  Viewport                                 ║",
     "  ║    v          Toggle viewport mode        ║",
     "  ║    Up/Down/L/R Scroll viewport (v mode)   ║",
     "  ║    r          Resize to recording         ║",
+    "  ║    c          Center small recordings     ║",
     "  ║    Esc        Exit viewport mode          ║",
     "  ║                                           ║",
+    "  ║  Search                                   ║",
+    "  ║    /          Search output               ║",
+    "  ║    n / N      Next / previous match       ║",
+    "  ║    Esc        Clear search highlight      ║",
+    "  ║                                           ║",
     "  ║  General                                  ║",
+    "  ║    C          Clear saved resume position ║",
+    "  ║    i          Show recorded keystrokes    ║",
     "  ║    ?          Show this help              ║",
     "  ║    q          Quit player                 ║",
     "  ║                                           ║",
@@ -10,9 +10,17 @@ mod scroll;
 mod status;
 mod viewport;
 
-pub use ansi::{style_to_ansi_attrs, style_to_ansi_bg, style_to_ansi_fg};
+pub use ansi::{
+    color_depth, resolve_theme_colors, rgb_to_ansi256, set_color_depth, style_to_ansi_attrs,
+    style_to_ansi_bg, style_to_ansi_fg, ColorDepth, StyleDiffer,
+};
 pub use help::{calc_help_start_col, calc_help_start_row, render_help, HELP_BOX_WIDTH, HELP_LINES};
-pub use progress::{build_progress_bar_chars, format_duration, render_progress_bar};
-pub use scroll::{build_scroll_arrows, calc_scroll_directions, render_scroll_indicator};
+pub use progress::{build_progress_bar_chars, render_progress_bar};
+pub use scroll::{
+    build_scroll_arrows, calc_minimap_thumb, calc_scroll_directions, render_scroll_indicator,
+    render_scroll_minimap,
+};
 pub use status::{count_digits, render_separator_line, render_status_bar};
-pub use viewport::{render_single_line, render_viewport};
+pub use viewport::{
+    render_cursor, render_single_line, render_viewport, ViewportContext, ViewportPadding,
+};
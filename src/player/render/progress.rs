@@ -1,31 +1,26 @@
 //! Progress bar rendering for the native player.
 //!
-//! Displays playback progress with marker indicators.
+//! Displays playback progress with marker indicators, plus the label of
+//! any marker the playhead is passing.
 
 use std::io::{self, Write};
 
 use anyhow::Result;
 
 use crate::player::state::MarkerPosition;
+use crate::util::format_duration;
 
-/// Format a duration in seconds to MM:SS format.
-///
-/// # Arguments
-/// * `seconds` - Duration in seconds
-///
-/// # Returns
-/// A string in MM:SS format
-pub fn format_duration(seconds: f64) -> String {
-    let total_secs = seconds as u64;
-    let mins = total_secs / 60;
-    let secs = total_secs % 60;
-    format!("{:02}:{:02}", mins, secs)
-}
+/// Progress bar glyph for a single marker.
+pub const MARKER_GLYPH: char = '◆';
+
+/// Progress bar glyph for a column shared by two or more markers.
+pub const MARKER_CLUSTER_GLYPH: char = '◈';
 
 /// Build the progress bar character array.
 ///
 /// Creates a visual representation of the progress bar including
-/// the playhead position and marker indicators.
+/// the playhead position and marker indicators. Columns holding more than
+/// one marker use [`MARKER_CLUSTER_GLYPH`] instead of [`MARKER_GLYPH`].
 ///
 /// # Arguments
 /// * `bar_width` - Width of the bar in characters
@@ -57,19 +52,96 @@ pub fn build_progress_bar_chars(
     }
 
     for marker in markers {
-        let marker_pos = if total_duration > 0.0 {
-            ((marker.time / total_duration) * bar_width as f64) as usize
-        } else {
-            0
-        };
-        if marker_pos < bar_width && bar[marker_pos] != '⏺' {
-            bar[marker_pos] = '◆';
+        let marker_pos = bar_column(marker.time, total_duration, bar_width);
+        if marker_pos < bar_width {
+            bar[marker_pos] = match bar[marker_pos] {
+                '⏺' => '⏺',
+                MARKER_GLYPH | MARKER_CLUSTER_GLYPH => MARKER_CLUSTER_GLYPH,
+                _ => MARKER_GLYPH,
+            };
         }
     }
 
     (bar, filled)
 }
 
+/// How many columns away from the playhead a marker still shows its label.
+pub const MARKER_TOOLTIP_RANGE: usize = 1;
+
+/// A marker label drawn over the progress bar near the playhead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkerTooltip {
+    /// Bar column where the text starts
+    pub start: usize,
+    /// Text to draw, already padded and clipped to the bar
+    pub text: String,
+}
+
+fn is_marker_glyph(c: char) -> bool {
+    c == MARKER_GLYPH || c == MARKER_CLUSTER_GLYPH
+}
+
+/// Column of the bar that a marker at `time` falls into.
+fn bar_column(time: f64, total_duration: f64, bar_width: usize) -> usize {
+    if total_duration > 0.0 {
+        ((time / total_duration) * bar_width as f64) as usize
+    } else {
+        0
+    }
+}
+
+/// Find the marker label to show while the playhead is near a marker.
+///
+/// Picks the marker column closest to the playhead within
+/// [`MARKER_TOOLTIP_RANGE`]. When several markers share that column, the
+/// first label is shown with a count of the others. The tooltip is placed
+/// just right of the marker, or left of it when it would run off the bar,
+/// leaving the marker and an adjacent playhead visible.
+pub fn marker_tooltip(
+    bar_width: usize,
+    current_time: f64,
+    total_duration: f64,
+    markers: &[MarkerPosition],
+) -> Option<MarkerTooltip> {
+    if bar_width == 0 {
+        return None;
+    }
+    let progress = if total_duration > 0.0 {
+        (current_time / total_duration).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let playhead = (bar_width as f64 * progress) as usize;
+
+    let column = markers
+        .iter()
+        .map(|m| bar_column(m.time, total_duration, bar_width))
+        .filter(|&col| col < bar_width && col.abs_diff(playhead) <= MARKER_TOOLTIP_RANGE)
+        .min_by_key(|&col| col.abs_diff(playhead))?;
+    let mut here = markers
+        .iter()
+        .filter(|m| bar_column(m.time, total_duration, bar_width) == column);
+    let first = here.next()?;
+    let others = here.count();
+
+    let label = if others == 0 {
+        first.label.clone()
+    } else {
+        format!("{} (+{} more)", first.label, others)
+    };
+    let text: String = format!(" {} ", label).chars().take(bar_width).collect();
+    let len = text.chars().count();
+
+    let start = if column + 3 + len <= bar_width {
+        column + 3
+    } else if column >= len + 2 {
+        column - 2 - len
+    } else {
+        bar_width - len
+    };
+    Some(MarkerTooltip { start, text })
+}
+
 /// Render the progress bar with markers.
 ///
 /// # Arguments
@@ -107,11 +179,28 @@ pub fn render_progress_bar(
     const WHITE: &str = "\x1b[97m";
     const DARK_GREY: &str = "\x1b[90m";
     const GREY: &str = "\x1b[37m";
+    const TOOLTIP: &str = "\x1b[30;43m"; // Black on yellow, like the marker glyph
+    const BAR_BG: &str = "\x1b[48;5;236m";
+
+    let tooltip = marker_tooltip(bar_width, current_time, total_duration, markers);
+    let tooltip_chars: Vec<char> = tooltip
+        .as_ref()
+        .map(|t| t.text.chars().collect())
+        .unwrap_or_default();
+    let tooltip_start = tooltip.as_ref().map_or(usize::MAX, |t| t.start);
 
     output.push_str(GREEN);
     for (i, &c) in bar.iter().enumerate() {
-        if i < filled {
-            if c == '◆' {
+        if let Some(&t) = i
+            .checked_sub(tooltip_start)
+            .and_then(|offset| tooltip_chars.get(offset))
+        {
+            output.push_str(TOOLTIP);
+            output.push(t);
+            output.push_str(BAR_BG);
+            output.push_str(GREEN);
+        } else if i < filled {
+            if is_marker_glyph(c) {
                 output.push_str(YELLOW);
                 output.push(c);
                 output.push_str(GREEN);
@@ -121,7 +210,7 @@ pub fn render_progress_bar(
         } else if i == filled {
             output.push_str(WHITE);
             output.push(c);
-        } else if c == '◆' {
+        } else if is_marker_glyph(c) {
             output.push_str(YELLOW);
             output.push(c);
         } else {
@@ -150,29 +239,6 @@ pub fn render_progress_bar(
 mod tests {
     use super::*;
 
-    #[test]
-    fn format_duration_formats_correctly() {
-        assert_eq!(format_duration(0.0), "00:00");
-        assert_eq!(format_duration(65.0), "01:05");
-        assert_eq!(format_duration(3661.0), "61:01");
-    }
-
-    #[test]
-    fn format_duration_edge_cases() {
-        // Fractional seconds are truncated
-        assert_eq!(format_duration(0.9), "00:00");
-        assert_eq!(format_duration(1.5), "00:01");
-        assert_eq!(format_duration(59.9), "00:59");
-        // Very large durations (hours)
-        assert_eq!(format_duration(7200.0), "120:00"); // 2 hours
-    }
-
-    #[test]
-    fn format_duration_negative_treated_as_zero() {
-        // Negative durations should still format (as 0 due to u64 cast)
-        assert_eq!(format_duration(-5.0), "00:00");
-    }
-
     #[test]
     fn empty_bar_at_zero() {
         let (bar, filled) = build_progress_bar_chars(10, 0.0, 10.0, &[]);
@@ -234,6 +300,22 @@ mod tests {
         assert_eq!(bar[8], '◆');
     }
 
+    #[test]
+    fn markers_sharing_a_column_use_cluster_glyph() {
+        // 5.0 and 5.4 both land in column 5 of a 10-wide bar
+        let markers = vec![marker(5.0, "m1"), marker(5.4, "m2"), marker(8.0, "m3")];
+        let (bar, _) = build_progress_bar_chars(10, 0.0, 10.0, &markers);
+        assert_eq!(bar[5], MARKER_CLUSTER_GLYPH);
+        assert_eq!(bar[8], MARKER_GLYPH);
+    }
+
+    #[test]
+    fn playhead_wins_over_marker_cluster() {
+        let markers = vec![marker(5.0, "m1"), marker(5.4, "m2")];
+        let (bar, _) = build_progress_bar_chars(10, 5.0, 10.0, &markers);
+        assert_eq!(bar[5], '⏺');
+    }
+
     #[test]
     fn zero_duration_returns_full() {
         let (_, filled) = build_progress_bar_chars(10, 5.0, 0.0, &[]);
@@ -247,6 +329,63 @@ mod tests {
         assert_eq!(filled, 10); // Clamped to 100%
     }
 
+    fn marker(time: f64, label: &str) -> MarkerPosition {
+        MarkerPosition {
+            time,
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn tooltip_hidden_when_playhead_is_far_from_markers() {
+        let markers = vec![marker(50.0, "build")];
+        assert_eq!(marker_tooltip(100, 10.0, 100.0, &markers), None);
+        assert_eq!(marker_tooltip(100, 10.0, 100.0, &[]), None);
+    }
+
+    #[test]
+    fn tooltip_shows_label_right_of_nearby_marker() {
+        let markers = vec![marker(50.0, "build")];
+        let tooltip = marker_tooltip(100, 51.0, 100.0, &markers).unwrap();
+        assert_eq!(tooltip.text, " build ");
+        assert_eq!(tooltip.start, 53);
+    }
+
+    #[test]
+    fn tooltip_flips_left_near_the_end_of_the_bar() {
+        let markers = vec![marker(98.0, "done")];
+        let tooltip = marker_tooltip(100, 98.0, 100.0, &markers).unwrap();
+        assert_eq!(tooltip.text, " done ");
+        assert_eq!(tooltip.start, 98 - 2 - 6);
+    }
+
+    #[test]
+    fn tooltip_counts_markers_sharing_a_column() {
+        let markers = vec![
+            marker(50.1, "tests"),
+            marker(50.4, "lint"),
+            marker(50.7, "docs"),
+            marker(80.0, "elsewhere"),
+        ];
+        let tooltip = marker_tooltip(100, 50.0, 100.0, &markers).unwrap();
+        assert_eq!(tooltip.text, " tests (+2 more) ");
+    }
+
+    #[test]
+    fn tooltip_prefers_the_closest_marker() {
+        let markers = vec![marker(49.0, "before"), marker(50.0, "at")];
+        let tooltip = marker_tooltip(100, 50.0, 100.0, &markers).unwrap();
+        assert_eq!(tooltip.text, " at ");
+    }
+
+    #[test]
+    fn tooltip_is_clipped_to_narrow_bars() {
+        let markers = vec![marker(0.0, "a very long marker label")];
+        let tooltip = marker_tooltip(8, 0.0, 10.0, &markers).unwrap();
+        assert_eq!(tooltip.text.chars().count(), 8);
+        assert_eq!(tooltip.start, 0);
+    }
+
     #[test]
     fn marker_at_zero_duration() {
         let markers = vec![MarkerPosition {
@@ -1,7 +1,8 @@
 //! Scroll indicator rendering for the native player.
 //!
 //! Displays arrows indicating available scroll directions when
-//! the recording is larger than the viewport.
+//! the recording is larger than the viewport, plus a minimap on the
+//! right edge showing where the viewport sits vertically.
 
 use std::io;
 
@@ -80,8 +81,85 @@ pub fn build_scroll_arrows(
     }
 }
 
+/// Calculate the minimap thumb for a vertical track of `track_len` cells.
+///
+/// The thumb length is proportional to the visible share of the recording
+/// (at least one cell) and its start is proportional to how far the
+/// viewport is scrolled, so it touches the top at offset 0 and the bottom
+/// when scrolled all the way down.
+///
+/// # Returns
+/// `None` if everything fits vertically, otherwise `Some((start, len))`
+/// in track cells.
+pub fn calc_minimap_thumb(
+    row_offset: usize,
+    view_rows: usize,
+    rec_rows: usize,
+    track_len: usize,
+) -> Option<(usize, usize)> {
+    if rec_rows <= view_rows || track_len == 0 {
+        return None;
+    }
+
+    let len = ((track_len * view_rows + rec_rows / 2) / rec_rows).clamp(1, track_len);
+    let max_offset = rec_rows - view_rows;
+    let free = track_len - len;
+    let start = (free * row_offset.min(max_offset) + max_offset / 2) / max_offset;
+    Some((start, len))
+}
+
+/// Render the vertical minimap along the right edge of the viewport.
+///
+/// The track starts at `top_row` and spans `track_len` rows; the filled
+/// part marks the rows currently in view. Nothing is drawn when the
+/// recording fits vertically.
+///
+/// # Arguments
+/// * `stdout` - The stdout handle to write to
+/// * `term_cols` - Terminal width
+/// * `top_row` - First terminal row of the track
+/// * `track_len` - Number of rows in the track
+/// * `row_offset` - Current vertical scroll offset
+/// * `view_rows` - Number of visible rows
+/// * `rec_rows` - Total recording height
+pub fn render_scroll_minimap(
+    stdout: &mut io::Stdout,
+    term_cols: u16,
+    top_row: u16,
+    track_len: usize,
+    row_offset: usize,
+    view_rows: usize,
+    rec_rows: usize,
+) -> Result<()> {
+    let Some((start, len)) = calc_minimap_thumb(row_offset, view_rows, rec_rows, track_len) else {
+        return Ok(());
+    };
+
+    let col = term_cols.saturating_sub(1);
+    let bg_color = Color::AnsiValue(236); // Same as progress bar
+    for cell in 0..track_len {
+        let (glyph, color) = if (start..start + len).contains(&cell) {
+            ("█", Color::Yellow)
+        } else {
+            ("│", Color::DarkGrey)
+        };
+        execute!(
+            stdout,
+            MoveTo(col, top_row + cell as u16),
+            SetBackgroundColor(bg_color),
+            SetForegroundColor(color),
+            Print(glyph),
+        )?;
+    }
+    execute!(stdout, ResetColor)?;
+    Ok(())
+}
+
 /// Render scroll indicator in top-right showing available scroll directions.
 ///
+/// Below the arrows, a minimap on the right edge shows the viewport's
+/// vertical position (see [`render_scroll_minimap`]).
+///
 /// # Arguments
 /// * `stdout` - The stdout handle to write to
 /// * `term_cols` - Terminal width
@@ -106,6 +184,17 @@ pub fn render_scroll_indicator(
         row_offset, col_offset, view_rows, view_cols, rec_rows, rec_cols,
     );
 
+    // The arrows take the first row; the minimap runs down the rest
+    render_scroll_minimap(
+        stdout,
+        term_cols,
+        1,
+        view_rows.saturating_sub(1),
+        row_offset,
+        view_rows,
+        rec_rows,
+    )?;
+
     let arrow_str = match build_scroll_arrows(can_up, can_down, can_left, can_right) {
         Some(s) => s,
         None => return Ok(()),
@@ -205,6 +294,39 @@ mod tests {
         assert!(!right); // At right edge
     }
 
+    #[test]
+    fn no_minimap_when_recording_fits() {
+        assert_eq!(calc_minimap_thumb(0, 24, 24, 23), None);
+        assert_eq!(calc_minimap_thumb(0, 24, 10, 23), None);
+        assert_eq!(calc_minimap_thumb(0, 24, 48, 0), None);
+    }
+
+    #[test]
+    fn minimap_thumb_is_proportional_to_visible_share() {
+        // Half the recording visible -> half the track
+        assert_eq!(calc_minimap_thumb(0, 24, 48, 20), Some((0, 10)));
+        // A quarter visible
+        assert_eq!(calc_minimap_thumb(0, 10, 40, 20), Some((0, 5)));
+    }
+
+    #[test]
+    fn minimap_thumb_tracks_scroll_position() {
+        // Offsets 0..=30 map onto track starts 0..=15
+        assert_eq!(calc_minimap_thumb(15, 10, 40, 20), Some((8, 5)));
+        assert_eq!(calc_minimap_thumb(30, 10, 40, 20), Some((15, 5)));
+    }
+
+    #[test]
+    fn minimap_thumb_clamps_offset_past_bottom() {
+        assert_eq!(calc_minimap_thumb(100, 10, 40, 20), Some((15, 5)));
+    }
+
+    #[test]
+    fn minimap_thumb_is_at_least_one_cell() {
+        assert_eq!(calc_minimap_thumb(0, 10, 10_000, 20), Some((0, 1)));
+        assert_eq!(calc_minimap_thumb(9_990, 10, 10_000, 20), Some((19, 1)));
+    }
+
     #[test]
     fn no_arrows_when_no_scroll() {
         let result = build_scroll_arrows(false, false, false, false);
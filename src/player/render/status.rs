@@ -57,6 +57,11 @@ pub fn render_separator_line(stdout: &mut io::Stdout, width: u16, row: u16) -> R
 /// * `marker_count` - Number of markers in the recording
 /// * `viewport_mode` - Whether viewport mode is active
 /// * `free_mode` - Whether free mode is active
+/// * `loop_playback` - Whether loop playback is active
+/// * `pause_at_markers` - Whether pause-at-markers mode is active
+/// * `status_message` - Transient message to show (e.g. the new seek step)
+/// * `input_echo` - Recorded input near the playhead, when input echo is on
+/// * `exit_code` - Exit code of the recorded process, once playback reached it
 #[allow(clippy::too_many_arguments)]
 pub fn render_status_bar(
     stdout: &mut io::Stdout,
@@ -73,14 +78,21 @@ pub fn render_status_bar(
     marker_count: usize,
     viewport_mode: bool,
     free_mode: bool,
+    loop_playback: bool,
+    pause_at_markers: bool,
+    status_message: Option<&str>,
+    input_echo: Option<&str>,
+    exit_code: Option<i32>,
 ) -> Result<()> {
     // ANSI color codes
     const WHITE: &str = "\x1b[97m";
     const MAGENTA: &str = "\x1b[35m";
     const GREEN: &str = "\x1b[32m";
+    const RED: &str = "\x1b[31m";
     const DARK_GREY: &str = "\x1b[90m";
     const YELLOW: &str = "\x1b[33m";
     const CYAN: &str = "\x1b[36m";
+    const BLUE: &str = "\x1b[34m";
     const RESET: &str = "\x1b[0m";
 
     let mut output = String::with_capacity(256);
@@ -109,6 +121,18 @@ pub fn render_status_bar(
         visible_len += 4;
     }
 
+    if loop_playback {
+        output.push_str(BLUE);
+        output.push_str("[L] ");
+        visible_len += 4;
+    }
+
+    if pause_at_markers {
+        output.push_str(YELLOW);
+        output.push_str("[P] ");
+        visible_len += 4;
+    }
+
     output.push_str(DARK_GREY);
     output.push_str("spd:");
     visible_len += 4;
@@ -131,6 +155,30 @@ pub fn render_status_bar(
         output.push_str(&offset_str);
     }
 
+    if let Some(message) = status_message {
+        output.push_str(WHITE);
+        output.push_str(message);
+        output.push(' ');
+        visible_len += message.chars().count() + 1;
+    }
+
+    if let Some(input) = input_echo {
+        output.push_str(DARK_GREY);
+        output.push_str("in:");
+        visible_len += 3;
+        output.push_str(WHITE);
+        output.push_str(input);
+        output.push(' ');
+        visible_len += input.chars().count() + 1;
+    }
+
+    if let Some(code) = exit_code {
+        output.push_str(if code == 0 { GREEN } else { RED });
+        let exit_str = format!("exited: {} ", code);
+        visible_len += exit_str.len();
+        output.push_str(&exit_str);
+    }
+
     let play_action = if paused { ":play " } else { ":pause " };
     output.push_str(DARK_GREY);
     output.push_str("│ ");
@@ -258,6 +306,11 @@ mod tests {
             0,     // marker_count
             false, // viewport_mode
             false, // free_mode
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -280,6 +333,11 @@ mod tests {
             0,
             false,
             false,
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -302,6 +360,11 @@ mod tests {
             0,
             true, // viewport_mode
             false,
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -324,6 +387,11 @@ mod tests {
             0,
             false,
             true, // free_mode
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -346,6 +414,11 @@ mod tests {
             5, // marker_count
             false,
             false,
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -369,6 +442,11 @@ mod tests {
             0,
             false,
             false,
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -391,6 +469,11 @@ mod tests {
             0,
             false,
             false,
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -413,6 +496,11 @@ mod tests {
             0,
             false,
             false,
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -435,10 +523,152 @@ mod tests {
             0,
             false,
             false,
+            false,
+            false,
+            None,
+            None,
+            None,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn render_status_bar_with_loop_playback() {
+        let mut stdout = io::stdout();
+        let result = render_status_bar(
+            &mut stdout,
+            80,
+            25,
+            false,
+            1.0,
+            80,
+            24,
+            80,
+            24,
+            0,
+            0,
+            0,
+            false,
+            false,
+            true, // loop_playback
+            false,
+            None,
+            None,
+            None,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn render_status_bar_with_status_message() {
+        let mut stdout = io::stdout();
+        let result = render_status_bar(
+            &mut stdout,
+            80,
+            25,
+            false,
+            1.0,
+            80,
+            24,
+            80,
+            24,
+            0,
+            0,
+            0,
+            false,
+            false,
+            false,
+            false,
+            Some("step:10s"),
+            None,
+            None,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn render_status_bar_with_pause_at_markers() {
+        let mut stdout = io::stdout();
+        let result = render_status_bar(
+            &mut stdout,
+            80,
+            25,
+            true,
+            1.0,
+            80,
+            24,
+            80,
+            24,
+            0,
+            0,
+            3,
+            false,
+            false,
+            false,
+            true, // pause_at_markers
+            Some("◆ build done"),
+            None,
+            None,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn render_status_bar_with_input_echo() {
+        let mut stdout = io::stdout();
+        let result = render_status_bar(
+            &mut stdout,
+            80,
+            25,
+            false,
+            1.0,
+            80,
+            24,
+            80,
+            24,
+            0,
+            0,
+            0,
+            false,
+            false,
+            false,
+            false,
+            None,
+            Some("ls -la⏎"), // input_echo
+            None,
         );
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn render_status_bar_with_exit_codes() {
+        let mut stdout = io::stdout();
+        for code in [0, 1] {
+            let result = render_status_bar(
+                &mut stdout,
+                80,
+                25,
+                false,
+                1.0,
+                80,
+                24,
+                80,
+                24,
+                0,
+                0,
+                0,
+                false,
+                false,
+                false,
+                false,
+                None,
+                None,
+                Some(code), // exit_code
+            );
+            assert!(result.is_ok());
+        }
+    }
+
     #[test]
     fn render_status_bar_all_options() {
         let mut stdout = io::stdout();
@@ -458,6 +688,11 @@ mod tests {
             7,     // marker_count
             true,  // viewport_mode
             false, // free_mode (can't be both)
+            false,
+            false,
+            None,
+            None,
+            None,
         );
         assert!(result.is_ok());
     }
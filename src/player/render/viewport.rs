@@ -3,108 +3,136 @@
 //! Renders the terminal buffer content within the visible viewport area.
 
 use std::io::{self, Write};
+use std::ops::{Range, RangeInclusive};
 
 use anyhow::Result;
 
-use crate::player::render::ansi::{style_to_ansi_attrs, style_to_ansi_bg, style_to_ansi_fg};
-use crate::terminal::{CellStyle, TerminalBuffer};
+use crate::asciicast::TtyTheme;
+use crate::player::playback::find_in_cells;
+use crate::player::render::ansi::{resolve_theme_colors, StyleDiffer};
+use crate::terminal::{Cell, CellStyle, Color, TerminalBuffer};
+
+/// Style of cells matching the active search (black on yellow).
+const SEARCH_MATCH_STYLE: CellStyle = CellStyle {
+    fg: Color::Black,
+    bg: Color::Yellow,
+    bold: false,
+    dim: false,
+    italic: false,
+    underline: false,
+    reverse: false,
+};
+
+/// Blank space kept around the recording inside the viewport.
+///
+/// Used to center a recording that is smaller than the viewport.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ViewportPadding {
+    /// Blank columns left of the recording
+    pub left: usize,
+    /// Blank rows above the recording
+    pub top: usize,
+}
+
+impl ViewportPadding {
+    /// Padding that centers a `buf_cols` x `buf_rows` recording.
+    ///
+    /// Dimensions where the recording fills or exceeds the viewport get no
+    /// padding, so scrolling works as before.
+    pub fn centered(view_cols: usize, view_rows: usize, buf_cols: usize, buf_rows: usize) -> Self {
+        Self {
+            left: view_cols.saturating_sub(buf_cols) / 2,
+            top: view_rows.saturating_sub(buf_rows) / 2,
+        }
+    }
+}
+
+/// What [`render_viewport`] draws: which part of the buffer, where, and how.
+#[derive(Debug, Clone, Default)]
+pub struct ViewportContext<'a> {
+    /// Vertical scroll offset
+    pub row_offset: usize,
+    /// Lines scrolled above the live screen into scrollback
+    pub scrollback_offset: usize,
+    /// Horizontal scroll offset
+    pub col_offset: usize,
+    /// Number of visible rows
+    pub view_rows: usize,
+    /// Number of visible columns
+    pub view_cols: usize,
+    /// Lines to highlight, in buffer coordinates (for free mode)
+    pub highlight: Option<RangeInclusive<usize>>,
+    /// Blank space left of and above the content
+    pub padding: ViewportPadding,
+    /// Active search query to highlight
+    pub search: Option<&'a str>,
+    /// Recorded color theme, if any
+    pub theme: Option<&'a TtyTheme>,
+}
 
 /// Render a viewport of the terminal buffer to stdout.
 ///
-/// If `highlight_line` is Some, that line (in buffer coordinates) gets a green background.
+/// If `highlight` is Some, those lines (in buffer coordinates) get a green background.
+/// When `scrollback_offset` is non-zero, that many scrollback lines are shown
+/// above the live screen. `padding` shifts the content right and down,
+/// leaving blank cells around it. Text matching `search` is highlighted.
+/// Cell colors are resolved against `theme`, the recording's own palette,
+/// when it has one.
 ///
 /// # Arguments
 /// * `stdout` - The stdout handle to write to
 /// * `buffer` - The terminal buffer to render
-/// * `row_offset` - Vertical scroll offset
-/// * `col_offset` - Horizontal scroll offset
-/// * `view_rows` - Number of visible rows
-/// * `view_cols` - Number of visible columns
-/// * `highlight_line` - Optional line to highlight (for free mode)
-#[allow(clippy::too_many_arguments)]
+/// * `ctx` - Offsets, size and decorations of the viewport
 pub fn render_viewport(
     stdout: &mut io::Stdout,
     buffer: &TerminalBuffer,
-    row_offset: usize,
-    col_offset: usize,
-    view_rows: usize,
-    view_cols: usize,
-    highlight_line: Option<usize>,
+    ctx: &ViewportContext,
 ) -> Result<()> {
+    let ViewportContext {
+        row_offset,
+        scrollback_offset,
+        col_offset,
+        view_rows,
+        view_cols,
+        ref highlight,
+        padding,
+        search,
+        theme,
+    } = *ctx;
+
     // Build output string to minimize syscalls
     let mut output = String::with_capacity(view_rows * view_cols * 2);
 
     for view_row in 0..view_rows {
-        let buf_row = view_row + row_offset;
-        let is_highlighted = highlight_line == Some(buf_row);
-
         // Move cursor to start of line (no clear - we'll overwrite)
         output.push_str(&format!("\x1b[{};1H", view_row + 1));
 
-        // Set highlight style if needed
-        if is_highlighted {
-            output.push_str("\x1b[97;42m"); // White text on green background
-        }
-
-        let mut chars_written = 0;
-
-        if let Some(row) = buffer.row(buf_row) {
-            let mut current_style = CellStyle::default();
-            let mut in_highlight_style = is_highlighted;
-
-            for view_col in 0..view_cols {
-                let buf_col = view_col + col_offset;
-
-                if buf_col < row.len() {
-                    let cell = &row[buf_col];
-
-                    if !is_highlighted && cell.style != current_style {
-                        // Apply style using ANSI codes directly
-                        output.push_str("\x1b[0m"); // Reset
-                        style_to_ansi_fg(&cell.style, &mut output);
-                        style_to_ansi_bg(&cell.style, &mut output);
-                        style_to_ansi_attrs(&cell.style, &mut output);
-                        current_style = cell.style;
-                        in_highlight_style = false;
-                    } else if is_highlighted && !in_highlight_style {
-                        output.push_str("\x1b[97;42m");
-                        in_highlight_style = true;
-                    }
-
-                    output.push(cell.char);
-                    chars_written += 1;
-                } else {
-                    // Past end of row content - fill with spaces
-                    if !is_highlighted && current_style != CellStyle::default() {
-                        output.push_str("\x1b[0m");
-                        current_style = CellStyle::default();
-                    }
-                    output.push(' ');
-                    chars_written += 1;
-                }
-            }
+        let Some(content_row) = view_row.checked_sub(padding.top) else {
+            push_row(&mut output, None, 0, view_cols, false, &[], None);
+            continue;
+        };
 
-            // Reset at end of line
-            if current_style != CellStyle::default() || is_highlighted {
-                output.push_str("\x1b[0m");
-            }
+        // Rows above the live screen come from scrollback, newest at the bottom
+        let (buf_row, row) = if content_row < scrollback_offset {
+            (None, buffer.scrollback_row(scrollback_offset - content_row))
         } else {
-            // Empty row - fill with spaces
-            if is_highlighted {
-                for _ in 0..view_cols {
-                    output.push(' ');
-                }
-                output.push_str("\x1b[0m");
-            } else {
-                for _ in 0..view_cols {
-                    output.push(' ');
-                }
-            }
-            chars_written = view_cols;
-        }
+            let buf_row = content_row - scrollback_offset + row_offset;
+            (Some(buf_row), buffer.row(buf_row))
+        };
+        let is_highlighted = buf_row
+            .zip(highlight.as_ref())
+            .is_some_and(|(row, lines)| lines.contains(&row));
 
-        // Ensure we've written the full width (clear any trailing content)
-        let _ = chars_written; // Already writing full width above
+        push_padded_row(
+            &mut output,
+            row,
+            col_offset,
+            view_cols,
+            padding.left,
+            is_highlighted,
+            &search_matches(row, search),
+            theme,
+        );
     }
 
     write!(stdout, "{}", output)?;
@@ -124,6 +152,9 @@ pub fn render_viewport(
 /// * `col_offset` - Horizontal scroll offset
 /// * `view_cols` - Number of visible columns
 /// * `is_highlighted` - Whether this line should be highlighted
+/// * `padding` - Blank space left of and above the content
+/// * `search` - Active search query to highlight
+/// * `theme` - Recorded color theme, if any
 #[allow(clippy::too_many_arguments)]
 pub fn render_single_line(
     stdout: &mut io::Stdout,
@@ -133,64 +164,224 @@ pub fn render_single_line(
     col_offset: usize,
     view_cols: usize,
     is_highlighted: bool,
+    padding: ViewportPadding,
+    search: Option<&str>,
+    theme: Option<&TtyTheme>,
 ) -> Result<()> {
     // Calculate screen row from buffer row
     if buf_row < view_row_offset {
         return Ok(()); // Line is above viewport
     }
-    let screen_row = buf_row - view_row_offset;
+    let screen_row = buf_row - view_row_offset + padding.top;
 
     let mut output = String::with_capacity(view_cols * 2);
 
     // Move cursor to start of line
     output.push_str(&format!("\x1b[{};1H", screen_row + 1));
 
+    let row = buffer.row(buf_row);
+    push_padded_row(
+        &mut output,
+        row,
+        col_offset,
+        view_cols,
+        padding.left,
+        is_highlighted,
+        &search_matches(row, search),
+        theme,
+    );
+
+    write!(stdout, "{}", output)?;
+    Ok(())
+}
+
+/// Show the terminal cursor where the recording's cursor is.
+///
+/// The cursor takes the recorded shape. It is hidden when `show` is false,
+/// when the recording hid it, or when it is scrolled out of the viewport.
+/// Call this last in a frame, since other rendering moves the cursor.
+///
+/// # Arguments
+/// * `stdout` - The stdout handle to write to
+/// * `buffer` - The terminal buffer being shown
+/// * `row_offset` - Vertical scroll offset
+/// * `scrollback_offset` - Lines scrolled above the live screen into scrollback
+/// * `col_offset` - Horizontal scroll offset
+/// * `view_rows` - Number of visible rows
+/// * `view_cols` - Number of visible columns
+/// * `padding` - Blank space left of and above the content
+/// * `show` - Whether the player wants a cursor at all (off in free mode)
+#[allow(clippy::too_many_arguments)]
+pub fn render_cursor(
+    stdout: &mut io::Stdout,
+    buffer: &TerminalBuffer,
+    row_offset: usize,
+    scrollback_offset: usize,
+    col_offset: usize,
+    view_rows: usize,
+    view_cols: usize,
+    padding: ViewportPadding,
+    show: bool,
+) -> Result<()> {
+    let position = if show && buffer.cursor_visible() {
+        cursor_screen_position(
+            buffer,
+            row_offset,
+            scrollback_offset,
+            col_offset,
+            view_rows,
+            view_cols,
+            padding,
+        )
+    } else {
+        None
+    };
+
+    match position {
+        Some((row, col)) => write!(
+            stdout,
+            "\x1b[{};{}H\x1b[{} q\x1b[?25h",
+            row + 1,
+            col + 1,
+            buffer.cursor_shape().decscusr()
+        )?,
+        None => write!(stdout, "\x1b[?25l")?,
+    }
+    Ok(())
+}
+
+/// Screen position (0-indexed row, col) of the buffer cursor, if in view.
+fn cursor_screen_position(
+    buffer: &TerminalBuffer,
+    row_offset: usize,
+    scrollback_offset: usize,
+    col_offset: usize,
+    view_rows: usize,
+    view_cols: usize,
+    padding: ViewportPadding,
+) -> Option<(usize, usize)> {
+    let row = (buffer.cursor_row() + scrollback_offset).checked_sub(row_offset)? + padding.top;
+    let col = buffer.cursor_col().checked_sub(col_offset)? + padding.left;
+    (row < view_rows && col < view_cols).then_some((row, col))
+}
+
+/// Cell ranges of `row` matching the search query, if any.
+fn search_matches(row: Option<&[Cell]>, search: Option<&str>) -> Vec<Range<usize>> {
+    match (row, search) {
+        (Some(row), Some(query)) => find_in_cells(row, query),
+        _ => Vec::new(),
+    }
+}
+
+/// Push `left` blank columns, then the row in the remaining width.
+///
+/// The blank columns are never highlighted, so a highlighted line only
+/// covers the recording.
+#[allow(clippy::too_many_arguments)]
+fn push_padded_row(
+    output: &mut String,
+    row: Option<&[Cell]>,
+    col_offset: usize,
+    view_cols: usize,
+    left: usize,
+    is_highlighted: bool,
+    matches: &[Range<usize>],
+    theme: Option<&TtyTheme>,
+) {
+    let left = left.min(view_cols);
+    output.push_str(&" ".repeat(left));
+
     if is_highlighted {
-        output.push_str("\x1b[97;42m"); // White on green
+        output.push_str("\x1b[97;42m"); // White text on green background
     }
 
-    if let Some(row) = buffer.row(buf_row) {
-        let mut current_style = CellStyle::default();
+    push_row(
+        output,
+        row,
+        col_offset,
+        view_cols - left,
+        is_highlighted,
+        matches,
+        theme,
+    );
+}
 
-        for view_col in 0..view_cols {
-            let buf_col = view_col + col_offset;
-
-            if buf_col < row.len() {
-                let cell = &row[buf_col];
-
-                if !is_highlighted && cell.style != current_style {
-                    output.push_str("\x1b[0m");
-                    style_to_ansi_fg(&cell.style, &mut output);
-                    style_to_ansi_bg(&cell.style, &mut output);
-                    style_to_ansi_attrs(&cell.style, &mut output);
-                    current_style = cell.style;
-                }
-
-                output.push(cell.char);
-            } else {
-                if !is_highlighted && current_style != CellStyle::default() {
-                    output.push_str("\x1b[0m");
-                    current_style = CellStyle::default();
-                }
-                output.push(' ');
+/// Push one viewport row, padded with spaces to `view_cols`.
+///
+/// Cell styles are written through a [`StyleDiffer`], so adjacent cells only
+/// emit the codes that differ. Highlighted rows keep the highlight colors the
+/// caller already set and ignore cell styles. Cells within `matches` (buffer
+/// columns) use the search match style. Other cells have their colors
+/// resolved against `theme`. The row always ends in the default style.
+fn push_row(
+    output: &mut String,
+    row: Option<&[Cell]>,
+    col_offset: usize,
+    view_cols: usize,
+    is_highlighted: bool,
+    matches: &[Range<usize>],
+    theme: Option<&TtyTheme>,
+) {
+    let row = row.unwrap_or(&[]);
+    let mut differ = StyleDiffer::new();
+
+    for view_col in 0..view_cols {
+        let buf_col = view_col + col_offset;
+
+        if buf_col < row.len() {
+            if !is_highlighted {
+                let style = if matches.iter().any(|m| m.contains(&buf_col)) {
+                    SEARCH_MATCH_STYLE
+                } else {
+                    resolve_theme_colors(&row[buf_col].style, theme)
+                };
+                differ.transition(&style, output);
+            }
+            push_cell_char(output, row, buf_col, view_col, view_cols);
+        } else {
+            // Past end of row content - fill with spaces
+            if !is_highlighted {
+                differ.transition(&CellStyle::default(), output);
             }
+            output.push(' ');
         }
+    }
 
-        if current_style != CellStyle::default() || is_highlighted {
-            output.push_str("\x1b[0m");
-        }
+    if is_highlighted {
+        output.push_str("\x1b[0m");
     } else {
-        // Empty row
-        for _ in 0..view_cols {
+        differ.transition(&CellStyle::default(), output);
+    }
+}
+
+/// Push a cell's glyph, keeping double-width glyphs aligned to the viewport.
+///
+/// Continuation cells are skipped because the glyph before them already
+/// covers that column, unless the glyph itself is scrolled out of view.
+/// A wide glyph cut off by the right edge is replaced by a space.
+fn push_cell_char(
+    output: &mut String,
+    row: &[Cell],
+    buf_col: usize,
+    view_col: usize,
+    view_cols: usize,
+) {
+    let cell = &row[buf_col];
+    if cell.is_wide_continuation() {
+        if view_col == 0 {
             output.push(' ');
         }
-        if is_highlighted {
-            output.push_str("\x1b[0m");
-        }
+        return;
     }
 
-    write!(stdout, "{}", output)?;
-    Ok(())
+    let is_wide = row
+        .get(buf_col + 1)
+        .is_some_and(|next| next.is_wide_continuation());
+    if is_wide && view_col + 1 >= view_cols {
+        output.push(' ');
+    } else {
+        output.push(cell.char);
+    }
 }
 
 #[cfg(test)]
@@ -204,13 +395,243 @@ mod tests {
         buffer
     }
 
+    // === push_row tests ===
+
+    fn push_styled_row(buffer: &TerminalBuffer, view_cols: usize) -> String {
+        let mut output = String::new();
+        push_row(&mut output, buffer.row(0), 0, view_cols, false, &[], None);
+        output
+    }
+
+    /// Encode a row the old way: a full reset plus absolute codes on every style change.
+    fn push_row_absolute(row: &[Cell]) -> String {
+        use crate::player::render::ansi::{
+            style_to_ansi_attrs, style_to_ansi_bg, style_to_ansi_fg,
+        };
+
+        let mut output = String::new();
+        let mut current = CellStyle::default();
+        for cell in row {
+            if cell.style != current {
+                output.push_str("\x1b[0m");
+                style_to_ansi_fg(&cell.style, &mut output);
+                style_to_ansi_bg(&cell.style, &mut output);
+                style_to_ansi_attrs(&cell.style, &mut output);
+                current = cell.style;
+            }
+            output.push(cell.char);
+        }
+        output.push_str("\x1b[0m");
+        output
+    }
+
+    #[test]
+    fn push_row_identical_style_run_emits_one_sequence() {
+        let run = "x".repeat(40);
+        let buffer = create_buffer_with_content(40, 1, &format!("\x1b[1;31;44m{}", run));
+
+        let output = push_styled_row(&buffer, 40);
+
+        assert_eq!(output, format!("\x1b[1;31;44m{}\x1b[0m", run));
+    }
+
+    #[test]
+    fn push_row_diff_is_smaller_than_absolute_codes() {
+        // Bold text on blue with the foreground alternating every cell
+        let mut content = String::from("\x1b[1;44m");
+        for i in 0..40 {
+            content.push_str(if i % 2 == 0 { "\x1b[31mx" } else { "\x1b[32mx" });
+        }
+        let buffer = create_buffer_with_content(40, 1, &content);
+
+        let diffed = push_styled_row(&buffer, 40);
+        let absolute = push_row_absolute(buffer.row(0).unwrap());
+
+        assert!(
+            diffed.len() * 2 < absolute.len(),
+            "diffed {} bytes, absolute {} bytes",
+            diffed.len(),
+            absolute.len()
+        );
+    }
+
+    #[test]
+    fn push_row_resets_before_unstyled_padding() {
+        let buffer = create_buffer_with_content(10, 1, "\x1b[31mab");
+
+        let output = push_styled_row(&buffer, 4);
+
+        assert_eq!(output, "\x1b[31mab\x1b[0m  ");
+    }
+
+    #[test]
+    fn push_row_highlighted_ignores_cell_styles() {
+        let buffer = create_buffer_with_content(10, 1, "\x1b[31mab");
+        let mut output = String::new();
+
+        push_row(&mut output, buffer.row(0), 0, 3, true, &[], None);
+
+        assert_eq!(output, "ab \x1b[0m");
+    }
+
+    #[test]
+    fn push_row_styles_search_matches() {
+        let buffer = create_buffer_with_content(10, 1, "a fail b");
+        let mut output = String::new();
+
+        let matches = find_in_cells(buffer.row(0).unwrap(), "fail");
+        push_row(&mut output, buffer.row(0), 0, 8, false, &matches, None);
+
+        assert_eq!(output, "a \x1b[30;43mfail\x1b[0m b");
+    }
+
+    #[test]
+    fn push_row_resolves_colors_against_theme() {
+        use crate::asciicast::RgbColor;
+
+        let buffer = create_buffer_with_content(1, 1, "\x1b[31mx");
+        let theme = TtyTheme {
+            fg: RgbColor::new(255, 255, 255),
+            bg: RgbColor::new(0, 0, 0),
+            palette: vec![RgbColor::new(0, 0, 0), RgbColor::new(200, 0, 0)],
+        };
+        let mut output = String::new();
+
+        push_row(&mut output, buffer.row(0), 0, 1, false, &[], Some(&theme));
+
+        assert_eq!(output, "\x1b[38;2;200;0;0;48;2;0;0;0mx\x1b[0m");
+    }
+
+    // === push_cell_char tests ===
+
+    fn push_all(buffer: &TerminalBuffer, col_offset: usize, view_cols: usize) -> String {
+        let row = buffer.row(0).unwrap();
+        let mut output = String::new();
+        for view_col in 0..view_cols {
+            push_cell_char(&mut output, row, view_col + col_offset, view_col, view_cols);
+        }
+        output
+    }
+
+    #[test]
+    fn push_cell_char_skips_wide_continuation() {
+        let buffer = create_buffer_with_content(6, 1, "a\u{4e2d}b");
+        assert_eq!(push_all(&buffer, 0, 6), "a\u{4e2d}b  ");
+    }
+
+    #[test]
+    fn push_cell_char_pads_continuation_at_left_edge() {
+        let buffer = create_buffer_with_content(6, 1, "a\u{4e2d}b");
+        // Offset 2 starts on the right half of the wide glyph
+        assert_eq!(push_all(&buffer, 2, 4), " b  ");
+    }
+
+    #[test]
+    fn push_cell_char_replaces_wide_glyph_cut_at_right_edge() {
+        let buffer = create_buffer_with_content(6, 1, "a\u{4e2d}b");
+        assert_eq!(push_all(&buffer, 0, 2), "a ");
+    }
+
+    // === padding tests ===
+
+    #[test]
+    fn centered_padding_splits_free_space() {
+        let padding = ViewportPadding::centered(120, 40, 80, 24);
+        assert_eq!(padding, ViewportPadding { left: 20, top: 8 });
+    }
+
+    #[test]
+    fn centered_padding_is_zero_when_recording_does_not_fit() {
+        assert_eq!(
+            ViewportPadding::centered(80, 20, 100, 24),
+            ViewportPadding::default()
+        );
+        assert_eq!(
+            ViewportPadding::centered(81, 24, 80, 24),
+            ViewportPadding::default()
+        );
+    }
+
+    #[test]
+    fn push_padded_row_leaves_padding_unhighlighted() {
+        let buffer = create_buffer_with_content(4, 1, "abcd");
+        let mut output = String::new();
+
+        push_padded_row(&mut output, buffer.row(0), 0, 8, 2, true, &[], None);
+
+        assert_eq!(output, "  \x1b[97;42mabcd  \x1b[0m");
+    }
+
+    #[test]
+    fn push_padded_row_clamps_padding_to_width() {
+        let buffer = create_buffer_with_content(4, 1, "abcd");
+        let mut output = String::new();
+
+        push_padded_row(&mut output, buffer.row(0), 0, 3, 5, false, &[], None);
+
+        assert_eq!(output, "   ");
+    }
+
+    // === cursor tests ===
+
+    #[test]
+    fn cursor_position_follows_offsets_and_padding() {
+        let buffer = create_buffer_with_content(20, 10, "\x1b[5;8H");
+        let padding = ViewportPadding { left: 3, top: 2 };
+
+        assert_eq!(
+            cursor_screen_position(&buffer, 0, 0, 0, 24, 80, padding),
+            Some((6, 10))
+        );
+        assert_eq!(
+            cursor_screen_position(&buffer, 2, 0, 5, 24, 80, ViewportPadding::default()),
+            Some((2, 2))
+        );
+        // Scrolled into scrollback, the live screen moves down
+        assert_eq!(
+            cursor_screen_position(&buffer, 0, 3, 0, 24, 80, ViewportPadding::default()),
+            Some((7, 7))
+        );
+    }
+
+    #[test]
+    fn cursor_outside_viewport_has_no_position() {
+        let buffer = create_buffer_with_content(20, 10, "\x1b[5;8H");
+        let padding = ViewportPadding::default();
+
+        assert_eq!(
+            cursor_screen_position(&buffer, 5, 0, 0, 4, 80, padding),
+            None
+        );
+        assert_eq!(
+            cursor_screen_position(&buffer, 0, 0, 8, 24, 80, padding),
+            None
+        );
+        assert_eq!(
+            cursor_screen_position(&buffer, 0, 0, 0, 4, 80, padding),
+            None
+        );
+        assert_eq!(
+            cursor_screen_position(&buffer, 0, 0, 0, 24, 7, padding),
+            None
+        );
+    }
+
     // === render_viewport tests ===
 
     #[test]
     fn render_viewport_does_not_panic_empty_buffer() {
         let mut stdout = io::stdout();
         let buffer = TerminalBuffer::new(80, 24);
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -218,7 +639,15 @@ mod tests {
     fn render_viewport_does_not_panic_with_content() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello, World!");
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -226,7 +655,16 @@ mod tests {
     fn render_viewport_with_row_offset() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Line 1\nLine 2\nLine 3");
-        let result = render_viewport(&mut stdout, &buffer, 1, 0, 20, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                row_offset: 1,
+                view_rows: 20,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -234,7 +672,16 @@ mod tests {
     fn render_viewport_with_col_offset() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello, World!");
-        let result = render_viewport(&mut stdout, &buffer, 0, 5, 24, 75, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                col_offset: 5,
+                view_rows: 24,
+                view_cols: 75,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -242,7 +689,17 @@ mod tests {
     fn render_viewport_with_both_offsets() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Line 1\nLine 2\nLine 3");
-        let result = render_viewport(&mut stdout, &buffer, 1, 3, 20, 75, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                row_offset: 1,
+                col_offset: 3,
+                view_rows: 20,
+                view_cols: 75,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -250,7 +707,33 @@ mod tests {
     fn render_viewport_with_highlight_line() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Line 1\nLine 2\nLine 3");
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, Some(1));
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                highlight: Some(1..=1),
+                ..Default::default()
+            },
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn render_viewport_with_highlight_range() {
+        let mut stdout = io::stdout();
+        let buffer = create_buffer_with_content(80, 24, "Line 1\nLine 2\nLine 3");
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                highlight: Some(0..=2),
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -258,7 +741,16 @@ mod tests {
     fn render_viewport_highlight_at_top() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Line 1\nLine 2\nLine 3");
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, Some(0));
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                highlight: Some(0..=0),
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -266,7 +758,16 @@ mod tests {
     fn render_viewport_highlight_at_bottom() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Line 1\nLine 2\nLine 3");
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, Some(23));
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                highlight: Some(23..=23),
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -274,7 +775,15 @@ mod tests {
     fn render_viewport_small_view() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello");
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 5, 10, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 5,
+                view_cols: 10,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -283,7 +792,15 @@ mod tests {
         let mut stdout = io::stdout();
         let buffer = TerminalBuffer::new(40, 10);
         // View is larger than buffer
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -292,7 +809,17 @@ mod tests {
         let mut stdout = io::stdout();
         let buffer = TerminalBuffer::new(80, 24);
         // Offset would be past buffer content
-        let result = render_viewport(&mut stdout, &buffer, 20, 70, 24, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                row_offset: 20,
+                col_offset: 70,
+                view_rows: 24,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -301,7 +828,15 @@ mod tests {
         let mut stdout = io::stdout();
         // Add content with ANSI color codes
         let buffer = create_buffer_with_content(80, 24, "\x1b[31mRed\x1b[0m \x1b[32mGreen\x1b[0m");
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -309,7 +844,15 @@ mod tests {
     fn render_viewport_with_bold_text() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "\x1b[1mBold\x1b[0m Normal");
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 24, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 24,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -318,7 +861,35 @@ mod tests {
         let mut stdout = io::stdout();
         let content = "Line 1\nLine 2\nLine 3\nLine 4\nLine 5";
         let buffer = create_buffer_with_content(80, 24, content);
-        let result = render_viewport(&mut stdout, &buffer, 0, 0, 5, 80, None);
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                view_rows: 5,
+                view_cols: 80,
+                ..Default::default()
+            },
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn render_viewport_with_scrollback_offset() {
+        let mut stdout = io::stdout();
+        let mut buffer = TerminalBuffer::with_scrollback(80, 3, 10);
+        buffer.process("1\r\n2\r\n3\r\n4\r\n5", None);
+        // Scroll past the available history; missing rows render blank
+        let result = render_viewport(
+            &mut stdout,
+            &buffer,
+            &ViewportContext {
+                scrollback_offset: 4,
+                view_rows: 3,
+                view_cols: 80,
+                highlight: Some(0..=0),
+                ..Default::default()
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -328,7 +899,18 @@ mod tests {
     fn render_single_line_does_not_panic_empty() {
         let mut stdout = io::stdout();
         let buffer = TerminalBuffer::new(80, 24);
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 0, 80, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            0,
+            80,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -336,7 +918,18 @@ mod tests {
     fn render_single_line_with_content() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello, World!");
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 0, 80, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            0,
+            80,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -344,7 +937,18 @@ mod tests {
     fn render_single_line_with_highlight() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello, World!");
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 0, 80, true);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            0,
+            80,
+            true,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -353,7 +957,18 @@ mod tests {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello, World!");
         // buf_row 0 is above view_row_offset 5
-        let result = render_single_line(&mut stdout, &buffer, 0, 5, 0, 80, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            5,
+            0,
+            80,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -363,7 +978,18 @@ mod tests {
         let content = "Line 1\nLine 2\nLine 3";
         let buffer = create_buffer_with_content(80, 24, content);
         // Render line 2 (buf_row 1), viewport starts at 0
-        let result = render_single_line(&mut stdout, &buffer, 1, 0, 0, 80, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            1,
+            0,
+            0,
+            80,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -371,7 +997,18 @@ mod tests {
     fn render_single_line_with_col_offset() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello, World!");
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 5, 75, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            5,
+            75,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -379,7 +1016,18 @@ mod tests {
     fn render_single_line_with_ansi_colors() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "\x1b[31mRed\x1b[0m");
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 0, 80, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            0,
+            80,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -388,7 +1036,18 @@ mod tests {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "\x1b[31mRed\x1b[0m");
         // When highlighted, colors should be overridden
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 0, 80, true);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            0,
+            80,
+            true,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -397,7 +1056,18 @@ mod tests {
         let mut stdout = io::stdout();
         let buffer = TerminalBuffer::new(80, 24);
         // Row 10 is empty
-        let result = render_single_line(&mut stdout, &buffer, 10, 0, 0, 80, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            10,
+            0,
+            0,
+            80,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -405,7 +1075,18 @@ mod tests {
     fn render_single_line_empty_row_highlighted() {
         let mut stdout = io::stdout();
         let buffer = TerminalBuffer::new(80, 24);
-        let result = render_single_line(&mut stdout, &buffer, 10, 0, 0, 80, true);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            10,
+            0,
+            0,
+            80,
+            true,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -413,7 +1094,18 @@ mod tests {
     fn render_single_line_narrow_view() {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Hello, World! This is a longer line.");
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 0, 10, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            0,
+            10,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -422,7 +1114,18 @@ mod tests {
         let mut stdout = io::stdout();
         let buffer = create_buffer_with_content(80, 24, "Short");
         // col_offset beyond content length
-        let result = render_single_line(&mut stdout, &buffer, 0, 0, 50, 30, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            0,
+            0,
+            50,
+            30,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 
@@ -431,7 +1134,18 @@ mod tests {
         let mut stdout = io::stdout();
         let buffer = TerminalBuffer::new(80, 10);
         // Render row 15, but buffer only has 10 rows
-        let result = render_single_line(&mut stdout, &buffer, 15, 0, 0, 80, false);
+        let result = render_single_line(
+            &mut stdout,
+            &buffer,
+            15,
+            0,
+            0,
+            80,
+            false,
+            ViewportPadding::default(),
+            None,
+            None,
+        );
         assert!(result.is_ok());
     }
 }
@@ -3,7 +3,14 @@
 //! Contains the central `PlaybackState` struct that holds all playback state,
 //! as well as shared types used across player modules.
 
-use std::time::Instant;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+pub use crate::asciicast::MarkerPosition;
+use crate::config::PlayerConfig;
+
+use super::playback::EventAnnotations;
+use super::render::ViewportPadding;
 
 /// Result of processing an input event.
 ///
@@ -19,17 +26,6 @@ pub enum InputResult {
     QuitWithFile,
 }
 
-/// Marker information for the progress bar.
-///
-/// Tracks the cumulative time and label for each marker in the recording.
-#[derive(Debug, Clone)]
-pub struct MarkerPosition {
-    /// Cumulative time when the marker occurs
-    pub time: f64,
-    /// Marker label (from the cast file)
-    pub label: String,
-}
-
 /// Central playback state for the native player.
 ///
 /// This struct contains all state needed for playback, rendering,
@@ -57,6 +53,20 @@ pub struct PlaybackState {
     pub speed: f64,
     /// Wall clock time when playback started/resumed
     pub start_time: Instant,
+    /// Whether playback restarts from the beginning when it reaches the end
+    pub loop_playback: bool,
+    /// Whether playback pauses automatically when it reaches a marker
+    pub pause_at_markers: bool,
+    /// Seconds skipped by the seek keys (`<`/`>`, `,`/`.`, arrows)
+    pub seek_step_secs: f64,
+    /// Whether the position is saved on quit and restored next time
+    pub resume: bool,
+    /// Set when the user asked to forget the saved resume position
+    pub clear_saved_position: bool,
+    /// Longest pause (in seconds) played before skipping to the next event
+    pub skip_idle: Option<f64>,
+    /// Whether the playhead is being dragged along the progress bar
+    pub scrubbing: bool,
 
     // === UI modes ===
     /// Whether help overlay is visible
@@ -65,10 +75,22 @@ pub struct PlaybackState {
     pub viewport_mode: bool,
     /// Whether free mode is active (line-by-line navigation)
     pub free_mode: bool,
+    /// Whether recorded input events are echoed in the status bar
+    pub show_input: bool,
+    /// Whether a recording smaller than the viewport is centered in it
+    pub center_viewport: bool,
+
+    // === Search ===
+    /// Query being typed after `/`, while the search prompt is open
+    pub search_prompt: Option<String>,
+    /// Last submitted query, highlighted on screen and reused by `n`/`N`
+    pub search_query: Option<String>,
 
     // === Free mode state (guarded) ===
     /// Current highlighted line in free mode (private, use getter/setter)
     free_line: usize,
+    /// Line where a Shift-extended selection started (private, use getter)
+    free_anchor: Option<usize>,
 
     // === Free mode state (public) ===
     /// Previous highlighted line (for partial updates)
@@ -81,6 +103,8 @@ pub struct PlaybackState {
     view_row_offset: usize,
     /// Horizontal scroll offset into buffer (private, use getter/setter)
     view_col_offset: usize,
+    /// Lines scrolled above the live screen into scrollback (private, use getter)
+    scrollback_offset: usize,
 
     // === Viewport state (public) ===
     /// Current terminal width
@@ -95,12 +119,19 @@ pub struct PlaybackState {
     // === Rendering flags ===
     /// True when screen needs to be redrawn
     pub needs_render: bool,
+    /// Transient message shown in the status bar, with the time it was set
+    pub status_message: Option<(String, Instant)>,
+    /// Input seen while replaying, rebuilt on every seek
+    pub annotations: EventAnnotations,
 }
 
 impl PlaybackState {
     /// Number of status/chrome lines (separator + progress + status bar)
     pub const STATUS_LINES: u16 = 3;
 
+    /// Number of scrolled-off lines the player keeps for viewport scrolling
+    pub const SCROLLBACK_LINES: usize = 5_000;
+
     /// Create a new PlaybackState with default values.
     ///
     /// # Arguments
@@ -119,14 +150,28 @@ impl PlaybackState {
             cumulative_time: 0.0,
             start_time: Instant::now(),
             time_offset: 0.0,
+            loop_playback: false,
+            pause_at_markers: false,
+            seek_step_secs: Self::DEFAULT_SEEK_STEP,
+            resume: false,
+            clear_saved_position: false,
+            skip_idle: None,
+            scrubbing: false,
 
             // UI modes
             show_help: false,
             viewport_mode: false,
             free_mode: false,
+            show_input: false,
+            center_viewport: true,
+
+            // Search
+            search_prompt: None,
+            search_query: None,
 
             // Free mode state
             free_line: 0,
+            free_anchor: None,
             prev_free_line: 0,
             free_line_only: false,
 
@@ -137,10 +182,45 @@ impl PlaybackState {
             view_cols,
             view_row_offset: 0,
             view_col_offset: 0,
+            scrollback_offset: 0,
 
             // Rendering flags
             needs_render: true,
+            status_message: None,
+            annotations: EventAnnotations::default(),
+        }
+    }
+
+    /// Create a new PlaybackState starting from the `[player]` preferences.
+    ///
+    /// Speed, viewport mode, loop playback and the idle limit come from
+    /// `config`; everything else uses the defaults of [`PlaybackState::new`].
+    pub fn with_config(term_cols: u16, term_rows: u16, config: &PlayerConfig) -> Self {
+        let mut state = Self::new(term_cols, term_rows);
+        state.speed = config.speed;
+        state.viewport_mode = config.viewport_mode;
+        state.loop_playback = config.loop_playback;
+        state.skip_idle = config.skip_idle;
+        state
+    }
+
+    /// `[player]` preferences the user changed during this session.
+    ///
+    /// Returns config keys and values for speed, viewport mode and loop
+    /// playback that differ from `config`, the preferences playback started
+    /// with, so command-line overrides the user left alone are not saved.
+    pub fn changed_preferences(&self, config: &PlayerConfig) -> Vec<(&'static str, String)> {
+        let mut changed = Vec::new();
+        if self.speed != config.speed {
+            changed.push(("player.speed", format!("{:?}", self.speed)));
+        }
+        if self.viewport_mode != config.viewport_mode {
+            changed.push(("player.viewport_mode", self.viewport_mode.to_string()));
         }
+        if self.loop_playback != config.loop_playback {
+            changed.push(("player.loop_playback", self.loop_playback.to_string()));
+        }
+        changed
     }
 
     /// Handle terminal resize event.
@@ -226,6 +306,177 @@ impl PlaybackState {
         self.needs_render = true;
     }
 
+    /// Default seek step in seconds.
+    pub const DEFAULT_SEEK_STEP: f64 = 5.0;
+    /// Seek steps in seconds selectable with `[` and `]`.
+    /// A fixed table keeps the default reachable from either end.
+    const SEEK_STEPS: &'static [f64] = &[1.0, 2.0, 5.0, 10.0, 30.0, 60.0];
+    /// How long a transient status message stays visible.
+    pub const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(2);
+
+    /// Decrease the seek step to the next fixed step (min 1s) and flash the
+    /// new value in the status bar.
+    pub fn seek_step_down(&mut self) {
+        self.seek_step_secs = Self::SEEK_STEPS
+            .iter()
+            .rev()
+            .copied()
+            .find(|&step| step < self.seek_step_secs - f64::EPSILON)
+            .unwrap_or(Self::SEEK_STEPS[0]);
+        self.show_seek_step();
+    }
+
+    /// Increase the seek step to the next fixed step (max 60s) and flash the
+    /// new value in the status bar.
+    pub fn seek_step_up(&mut self) {
+        self.seek_step_secs = Self::SEEK_STEPS
+            .iter()
+            .copied()
+            .find(|&step| step > self.seek_step_secs + f64::EPSILON)
+            .unwrap_or(Self::SEEK_STEPS[Self::SEEK_STEPS.len() - 1]);
+        self.show_seek_step();
+    }
+
+    fn show_seek_step(&mut self) {
+        self.set_status_message(format!("step:{}s", self.seek_step_secs));
+    }
+
+    /// Show a transient message in the status bar.
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), Instant::now()));
+        self.needs_render = true;
+    }
+
+    /// Get the status message if it has not expired yet.
+    pub fn active_status_message(&self) -> Option<&str> {
+        self.status_message
+            .as_ref()
+            .filter(|(_, set_at)| set_at.elapsed() < Self::STATUS_MESSAGE_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    /// Text for the status bar's message slot.
+    ///
+    /// A transient message wins; otherwise a hint is shown while paused
+    /// because the seek keys step one event at a time then.
+    pub fn status_text(&self) -> Option<&str> {
+        self.active_status_message().or(if self.paused {
+            Some("step mode (paused)")
+        } else {
+            None
+        })
+    }
+
+    /// Drop the status message once it has expired.
+    ///
+    /// Requests a render so the status bar is redrawn without it.
+    pub fn expire_status_message(&mut self) {
+        if self.status_message.is_some() && self.active_status_message().is_none() {
+            self.status_message = None;
+            self.needs_render = true;
+        }
+    }
+
+    /// Forget the saved resume position when the player exits.
+    ///
+    /// Does nothing (and says so) when resume is off, since no position is
+    /// saved or loaded then.
+    pub fn request_clear_saved_position(&mut self) {
+        if !self.resume {
+            self.set_status_message("Resume is off: no position is saved");
+            return;
+        }
+        self.clear_saved_position = true;
+        self.set_status_message("Saved position will be cleared on exit");
+    }
+
+    /// Toggle loop playback.
+    ///
+    /// Turning looping off mid-playback lets the current pass finish normally.
+    pub fn toggle_loop(&mut self) {
+        self.loop_playback = !self.loop_playback;
+        self.needs_render = true;
+    }
+
+    /// Toggle pausing at markers.
+    pub fn toggle_pause_at_markers(&mut self) {
+        self.pause_at_markers = !self.pause_at_markers;
+        self.needs_render = true;
+    }
+
+    /// Toggle echoing recorded input in the status bar.
+    pub fn toggle_show_input(&mut self) {
+        self.show_input = !self.show_input;
+        self.set_status_message(if self.show_input {
+            "input: shown"
+        } else {
+            "input: hidden"
+        });
+    }
+
+    /// Toggle centering recordings that are smaller than the viewport.
+    pub fn toggle_center_viewport(&mut self) {
+        self.center_viewport = !self.center_viewport;
+        self.set_status_message(if self.center_viewport {
+            "center: on"
+        } else {
+            "center: off"
+        });
+    }
+
+    /// Padding that places a `buf_cols` x `buf_rows` recording in the viewport.
+    ///
+    /// Centers the recording when centering is on, otherwise keeps it at the
+    /// top-left.
+    pub fn viewport_padding(&self, buf_cols: usize, buf_rows: usize) -> ViewportPadding {
+        if self.center_viewport {
+            ViewportPadding::centered(self.view_cols, self.view_rows, buf_cols, buf_rows)
+        } else {
+            ViewportPadding::default()
+        }
+    }
+
+    /// Open the search prompt with an empty query.
+    pub fn open_search_prompt(&mut self) {
+        self.search_prompt = Some(String::new());
+        self.needs_render = true;
+    }
+
+    /// Close the search prompt, making a non-empty query the active search.
+    ///
+    /// Returns `true` if a query was submitted.
+    pub fn submit_search_prompt(&mut self) -> bool {
+        self.needs_render = true;
+        match self.search_prompt.take() {
+            Some(query) if !query.is_empty() => {
+                self.search_query = Some(query);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Close the search prompt without searching.
+    pub fn cancel_search_prompt(&mut self) {
+        self.search_prompt = None;
+        self.needs_render = true;
+    }
+
+    /// Text shown in the status bar while the search prompt is open.
+    pub fn search_prompt_text(&self) -> Option<String> {
+        self.search_prompt
+            .as_ref()
+            .map(|query| format!("/{}", query))
+    }
+
+    /// Recorded input near the playhead, when input echo is on.
+    pub fn input_echo(&self) -> Option<&str> {
+        if !self.show_input {
+            return None;
+        }
+        self.annotations.input_at(self.current_time)
+    }
+
     /// Toggle help overlay visibility.
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
@@ -237,6 +488,8 @@ impl PlaybackState {
         self.viewport_mode = !self.viewport_mode;
         if self.viewport_mode {
             self.free_mode = false; // Exit free mode when entering viewport mode
+        } else {
+            self.scrollback_offset = 0; // Return to the live screen
         }
         self.needs_render = true;
     }
@@ -252,19 +505,26 @@ impl PlaybackState {
             self.paused = true; // Enforce pause in free mode
             self.free_line = cursor_row;
         }
+        self.free_anchor = None;
         self.needs_render = true;
     }
 
-    /// Exit current mode (viewport or free) or quit.
+    /// Exit current mode (viewport or free), clear the search highlight, or quit.
     ///
     /// Returns true if a mode was exited, false if should quit.
     pub fn exit_mode_or_quit(&mut self) -> bool {
         if self.viewport_mode {
             self.viewport_mode = false;
+            self.scrollback_offset = 0;
             self.needs_render = true;
             true
         } else if self.free_mode {
             self.free_mode = false;
+            self.free_anchor = None;
+            self.needs_render = true;
+            true
+        } else if self.search_query.is_some() {
+            self.search_query = None;
             self.needs_render = true;
             true
         } else {
@@ -304,6 +564,14 @@ impl PlaybackState {
         self.free_line
     }
 
+    /// Get the lines selected in free mode, in buffer coordinates.
+    ///
+    /// Without a Shift-extended selection this is just the highlighted line.
+    pub fn free_selection(&self) -> RangeInclusive<usize> {
+        let anchor = self.free_anchor.unwrap_or(self.free_line);
+        anchor.min(self.free_line)..=anchor.max(self.free_line)
+    }
+
     /// Get the vertical scroll offset into buffer.
     #[inline]
     pub fn view_row_offset(&self) -> usize {
@@ -316,6 +584,12 @@ impl PlaybackState {
         self.view_col_offset
     }
 
+    /// Get the number of lines the viewport is scrolled above the live screen.
+    #[inline]
+    pub fn scrollback_offset(&self) -> usize {
+        self.scrollback_offset
+    }
+
     // === Setters with validation ===
 
     /// Set current playback time, clamped to valid range [0.0, max_time].
@@ -351,6 +625,20 @@ impl PlaybackState {
         self.free_line = line.min(max_line);
     }
 
+    /// Start extending the free mode selection from the highlighted line.
+    ///
+    /// Does nothing if a selection is already being extended.
+    pub fn extend_free_selection(&mut self) {
+        self.free_anchor.get_or_insert(self.free_line);
+    }
+
+    /// Drop the free mode selection, keeping only the highlighted line.
+    ///
+    /// Returns true if a selection was being extended.
+    pub fn clear_free_selection(&mut self) -> bool {
+        self.free_anchor.take().is_some()
+    }
+
     /// Set view row offset, clamped to valid range [0, max_offset].
     pub fn set_view_row_offset(&mut self, offset: usize, max_offset: usize) {
         self.view_row_offset = offset.min(max_offset);
@@ -361,6 +649,32 @@ impl PlaybackState {
         self.view_col_offset = offset.min(max_offset);
     }
 
+    /// Clamp the scrollback offset to the available history (e.g. after a seek).
+    pub fn clamp_scrollback_offset(&mut self, scrollback_len: usize) {
+        self.scrollback_offset = self.scrollback_offset.min(scrollback_len);
+    }
+
+    /// Scroll the viewport up one line, continuing into scrollback history
+    /// once the top of the live screen is reached.
+    pub fn scroll_view_up(&mut self, scrollback_len: usize) {
+        if self.view_row_offset > 0 {
+            self.view_row_offset -= 1;
+        } else if self.scrollback_offset < scrollback_len {
+            self.scrollback_offset += 1;
+        }
+        self.needs_render = true;
+    }
+
+    /// Scroll the viewport down one line, leaving scrollback history first.
+    pub fn scroll_view_down(&mut self, max_row_offset: usize) {
+        if self.scrollback_offset > 0 {
+            self.scrollback_offset -= 1;
+        } else {
+            self.view_row_offset = (self.view_row_offset + 1).min(max_row_offset);
+        }
+        self.needs_render = true;
+    }
+
     /// Increment event index by 1 if below max.
     /// Returns true if incremented, false if already at max.
     pub fn increment_event_idx(&mut self, max_idx: usize) -> bool {
@@ -393,11 +707,78 @@ mod tests {
         assert!(!state.show_help);
         assert!(!state.viewport_mode);
         assert!(!state.free_mode);
+        assert!(!state.loop_playback);
+        assert!(!state.pause_at_markers);
+        assert_eq!(state.seek_step_secs, 5.0);
+        assert!(!state.resume);
+        assert!(!state.clear_saved_position);
+        assert!(state.center_viewport);
+        assert!(state.status_message.is_none());
         assert_eq!(state.view_rows, 24); // 27 - 3 status lines
         assert_eq!(state.view_cols, 80);
         assert!(state.needs_render);
     }
 
+    #[test]
+    fn with_config_applies_player_preferences() {
+        let config = PlayerConfig {
+            speed: 2.0,
+            viewport_mode: true,
+            loop_playback: true,
+            skip_idle: Some(1.5),
+            ..PlayerConfig::default()
+        };
+        let state = PlaybackState::with_config(80, 27, &config);
+
+        assert_eq!(state.speed, 2.0);
+        assert!(state.viewport_mode);
+        assert!(state.loop_playback);
+        assert_eq!(state.skip_idle, Some(1.5));
+        assert!(!state.paused);
+        assert_eq!(state.view_rows, 24);
+    }
+
+    #[test]
+    fn changed_preferences_skips_unchanged_values() {
+        let config = PlayerConfig {
+            speed: 4.0,
+            ..PlayerConfig::default()
+        };
+        let state = PlaybackState::with_config(80, 27, &config);
+
+        assert!(state.changed_preferences(&config).is_empty());
+    }
+
+    #[test]
+    fn saved_preferences_are_reloaded() {
+        let config = PlayerConfig::default();
+        let mut state = PlaybackState::with_config(80, 27, &config);
+        state.speed_up();
+        state.toggle_viewport_mode();
+
+        let changed = state.changed_preferences(&config);
+        let values: Vec<(&str, &str)> = changed.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let saved =
+            crate::config::set_values("[player]\nspeed = 1.0 # usual speed\n", &values).unwrap();
+        assert!(saved.contains("speed = 2.0 # usual speed"), "{}", saved);
+
+        let reloaded: crate::Config = toml::from_str(&saved).unwrap();
+        let state = PlaybackState::with_config(80, 27, &reloaded.player);
+        assert_eq!(state.speed, 2.0);
+        assert!(state.viewport_mode);
+        assert!(!state.loop_playback);
+    }
+
+    #[test]
+    fn with_default_config_matches_new() {
+        let state = PlaybackState::with_config(80, 27, &PlayerConfig::default());
+
+        assert_eq!(state.speed, 1.0);
+        assert!(!state.viewport_mode);
+        assert!(!state.loop_playback);
+        assert_eq!(state.skip_idle, None);
+    }
+
     #[test]
     fn handle_resize_updates_dimensions() {
         let mut state = PlaybackState::new(80, 27);
@@ -576,6 +957,188 @@ mod tests {
         assert_eq!(state.speed, 1.0); // Should snap to next lower step
     }
 
+    #[test]
+    fn toggle_loop_flips_flag_and_requests_render() {
+        let mut state = PlaybackState::new(80, 27);
+        state.needs_render = false;
+
+        state.toggle_loop();
+        assert!(state.loop_playback);
+        assert!(state.needs_render);
+
+        state.toggle_loop();
+        assert!(!state.loop_playback);
+    }
+
+    #[test]
+    fn viewport_padding_follows_center_toggle() {
+        let mut state = PlaybackState::new(120, 43);
+        assert_eq!(
+            state.viewport_padding(80, 24),
+            ViewportPadding { left: 20, top: 8 }
+        );
+
+        state.toggle_center_viewport();
+        assert!(!state.center_viewport);
+        assert_eq!(state.active_status_message(), Some("center: off"));
+        assert_eq!(state.viewport_padding(80, 24), ViewportPadding::default());
+    }
+
+    #[test]
+    fn seek_step_up_uses_fixed_steps_until_max() {
+        let mut state = PlaybackState::new(80, 27);
+        let mut steps = Vec::new();
+        for _ in 0..5 {
+            state.seek_step_up();
+            steps.push(state.seek_step_secs);
+        }
+        assert_eq!(steps, vec![10.0, 30.0, 60.0, 60.0, 60.0]);
+    }
+
+    #[test]
+    fn seek_step_down_uses_fixed_steps_until_min() {
+        let mut state = PlaybackState::new(80, 27);
+        let mut steps = Vec::new();
+        for _ in 0..4 {
+            state.seek_step_down();
+            steps.push(state.seek_step_secs);
+        }
+        assert_eq!(steps, vec![2.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn seek_step_returns_to_default_from_either_end() {
+        let mut state = PlaybackState::new(80, 27);
+        for _ in 0..10 {
+            state.seek_step_up();
+        }
+        for _ in 0..3 {
+            state.seek_step_down();
+        }
+        assert_eq!(state.seek_step_secs, PlaybackState::DEFAULT_SEEK_STEP);
+
+        for _ in 0..10 {
+            state.seek_step_down();
+        }
+        for _ in 0..2 {
+            state.seek_step_up();
+        }
+        assert_eq!(state.seek_step_secs, PlaybackState::DEFAULT_SEEK_STEP);
+    }
+
+    #[test]
+    fn seek_step_change_sets_status_message() {
+        let mut state = PlaybackState::new(80, 27);
+        state.needs_render = false;
+        state.seek_step_up();
+        assert_eq!(state.active_status_message(), Some("step:10s"));
+        assert!(state.needs_render);
+    }
+
+    #[test]
+    fn expired_status_message_is_cleared() {
+        let mut state = PlaybackState::new(80, 27);
+        state.status_message = Some((
+            "old".to_string(),
+            Instant::now() - PlaybackState::STATUS_MESSAGE_DURATION,
+        ));
+        state.needs_render = false;
+        assert_eq!(state.active_status_message(), None);
+
+        state.expire_status_message();
+        assert!(state.status_message.is_none());
+        assert!(state.needs_render);
+    }
+
+    #[test]
+    fn fresh_status_message_is_kept() {
+        let mut state = PlaybackState::new(80, 27);
+        state.set_status_message("hello");
+        state.needs_render = false;
+
+        state.expire_status_message();
+        assert_eq!(state.active_status_message(), Some("hello"));
+        assert!(!state.needs_render);
+    }
+
+    #[test]
+    fn status_text_shows_step_hint_when_paused() {
+        let mut state = PlaybackState::new(80, 27);
+        assert_eq!(state.status_text(), None);
+
+        state.paused = true;
+        assert_eq!(state.status_text(), Some("step mode (paused)"));
+
+        state.set_status_message("step:10s");
+        assert_eq!(state.status_text(), Some("step:10s"));
+    }
+
+    #[test]
+    fn request_clear_saved_position_sets_flag_and_message() {
+        let mut state = PlaybackState::new(80, 27);
+        state.resume = true;
+        state.request_clear_saved_position();
+        assert!(state.clear_saved_position);
+        assert_eq!(
+            state.active_status_message(),
+            Some("Saved position will be cleared on exit")
+        );
+    }
+
+    #[test]
+    fn request_clear_saved_position_without_resume_reports_it() {
+        let mut state = PlaybackState::new(80, 27);
+        state.request_clear_saved_position();
+        assert!(!state.clear_saved_position);
+        assert_eq!(
+            state.active_status_message(),
+            Some("Resume is off: no position is saved")
+        );
+    }
+
+    #[test]
+    fn toggle_pause_at_markers_flips_flag() {
+        let mut state = PlaybackState::new(80, 27);
+        state.needs_render = false;
+
+        state.toggle_pause_at_markers();
+        assert!(state.pause_at_markers);
+        assert!(state.needs_render);
+
+        state.toggle_pause_at_markers();
+        assert!(!state.pause_at_markers);
+    }
+
+    #[test]
+    fn toggle_show_input_flips_flag_and_flashes_message() {
+        let mut state = PlaybackState::new(80, 27);
+        state.needs_render = false;
+
+        state.toggle_show_input();
+        assert!(state.show_input);
+        assert!(state.needs_render);
+        assert_eq!(state.active_status_message(), Some("input: shown"));
+
+        state.toggle_show_input();
+        assert!(!state.show_input);
+        assert_eq!(state.active_status_message(), Some("input: hidden"));
+    }
+
+    #[test]
+    fn input_echo_requires_show_input() {
+        use crate::asciicast::{Event, EventType};
+
+        let mut state = PlaybackState::new(80, 27);
+        state
+            .annotations
+            .observe(1.0, &Event::new(1.0, EventType::Input, "ls"));
+        state.set_current_time(1.5, 10.0);
+        assert_eq!(state.input_echo(), None);
+
+        state.show_input = true;
+        assert_eq!(state.input_echo(), Some("ls"));
+    }
+
     #[test]
     fn toggle_free_mode_enables_and_pauses() {
         let mut state = PlaybackState::new(80, 27);
@@ -618,6 +1181,30 @@ mod tests {
         assert!(!state.free_mode);
     }
 
+    #[test]
+    fn exit_mode_clears_search_before_quitting() {
+        let mut state = PlaybackState::new(80, 27);
+        state.search_query = Some("error".to_string());
+
+        assert!(state.exit_mode_or_quit());
+        assert!(state.search_query.is_none());
+        assert!(!state.exit_mode_or_quit());
+    }
+
+    #[test]
+    fn search_prompt_submits_only_non_empty_queries() {
+        let mut state = PlaybackState::new(80, 27);
+        state.open_search_prompt();
+        assert_eq!(state.search_prompt_text().as_deref(), Some("/"));
+        assert!(!state.submit_search_prompt());
+        assert!(state.search_prompt.is_none());
+
+        state.open_search_prompt();
+        state.search_prompt.as_mut().unwrap().push_str("fail");
+        assert!(state.submit_search_prompt());
+        assert_eq!(state.search_query.as_deref(), Some("fail"));
+    }
+
     #[test]
     fn exit_mode_returns_false_when_no_mode() {
         let mut state = PlaybackState::new(80, 27);
@@ -723,6 +1310,28 @@ mod tests {
         assert_eq!(state.prev_free_line, 5);
     }
 
+    #[test]
+    fn free_selection_is_highlighted_line_by_default() {
+        let mut state = PlaybackState::new(80, 27);
+        state.set_free_line(7, 100);
+        assert_eq!(state.free_selection(), 7..=7);
+    }
+
+    #[test]
+    fn free_selection_extends_in_either_direction() {
+        let mut state = PlaybackState::new(80, 27);
+        state.set_free_line(5, 100);
+        state.extend_free_selection();
+        state.set_free_line(8, 100);
+        assert_eq!(state.free_selection(), 5..=8);
+        state.set_free_line(2, 100);
+        assert_eq!(state.free_selection(), 2..=5);
+
+        assert!(state.clear_free_selection());
+        assert_eq!(state.free_selection(), 2..=2);
+        assert!(!state.clear_free_selection());
+    }
+
     #[test]
     fn set_view_row_offset_clamps_to_max() {
         let mut state = PlaybackState::new(80, 27);
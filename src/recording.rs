@@ -13,7 +13,7 @@ use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
-use crate::analyzer::{AgentType, AnalyzeOptions, AnalyzerService};
+use crate::analyzer::{recording_agent, AgentType, AnalyzeOptions, AnalyzerService};
 use crate::config::Config;
 use crate::files::filename;
 use crate::storage::StorageManager;
@@ -40,9 +40,11 @@ impl Recorder {
 
     /// Generate a filename using the configured template.
     ///
-    /// Uses the `filename_template` from config with tags like `{directory}`, `{date}`, `{time}`.
+    /// Uses the `filename_template` from config with tags like `{directory}`, `{date}`, `{time}`,
+    /// `{agent}`, `{hostname}`, and `{counter}`. The counter is resolved against `target_dir`,
+    /// and a `-2`, `-3`, ... suffix is appended if the name is still taken there.
     /// Falls back to a timestamp-based name if template generation fails.
-    pub fn generate_filename(&self) -> String {
+    pub fn generate_filename(&self, agent: &str, target_dir: &Path) -> String {
         // Get current working directory name
         let dir_name = env::current_dir()
             .ok()
@@ -53,16 +55,23 @@ impl Recorder {
         let filename_config = filename::Config::new(self.config.recording.directory_max_length);
 
         // Generate using template, fallback to simple timestamp on error
-        filename::generate(
+        filename::generate_unique(
             &dir_name,
+            agent,
             &self.config.recording.filename_template,
             &filename_config,
+            target_dir,
         )
         .unwrap_or_else(|_| {
             // Fallback: use directory + timestamp
             let sanitized_dir = filename::sanitize_directory(&dir_name, &filename_config);
             let now = chrono::Local::now();
-            format!("{}_{}.cast", sanitized_dir, now.format("%y%m%d_%H%M%S"))
+            format!(
+                "{}_{}{}",
+                sanitized_dir,
+                now.format("%y%m%d_%H%M%S"),
+                filename_config.extension
+            )
         })
     }
 
@@ -112,13 +121,17 @@ impl Recorder {
     ) -> Result<()> {
         Self::check_asciinema()?;
 
+        // The same detected name drives the directory (and so the TUI agent
+        // filter) and the {agent} filename tag
+        let agent_name = recording_agent(agent);
+
         // Ensure agent directory exists
-        let agent_dir = self.storage.ensure_agent_dir(agent)?;
+        let agent_dir = self.storage.ensure_agent_dir(&agent_name)?;
 
         // Generate filename - use provided name or template-based
         let filename = match session_name {
             Some(name) => Self::sanitize_filename(name),
-            None => self.generate_filename(),
+            None => self.generate_filename(&agent_name, &agent_dir),
         };
         let filepath = agent_dir.join(&filename);
 
@@ -137,7 +150,7 @@ impl Recorder {
         .ok(); // Ignore if handler already set
 
         theme::print_start_banner();
-        theme::print_box_line(&format!("  ⏺ {}/{}", agent, filename));
+        theme::print_box_line(&format!("  ⏺ {}/{}", agent_name, filename));
         theme::print_box_bottom();
         println!();
 
@@ -252,9 +265,10 @@ impl Recorder {
             "claude" => AgentType::Claude,
             "codex" => AgentType::Codex,
             "gemini" => AgentType::Gemini,
+            "openai" => AgentType::OpenAi,
             _ => {
                 eprintln!(
-                    "Auto-analyze skipped: unknown agent '{}'. Supported: claude, codex, gemini",
+                    "Auto-analyze skipped: unknown agent '{}'. Supported: claude, codex, gemini, openai",
                     agent_name
                 );
                 return;
@@ -262,7 +276,7 @@ impl Recorder {
         };
 
         // Create analyzer service with quiet mode (auto-analyze is background operation)
-        let options = AnalyzeOptions::with_agent(agent).quiet();
+        let options = AnalyzeOptions::with_agent(agent).incremental(true).quiet();
         let service = AnalyzerService::new(options);
 
         // Check if agent is installed
@@ -176,8 +176,8 @@ if [[ -n "$ZSH_VERSION" ]]; then
 {subcmd_cases}            *) [[ " $_agr_file_cmds " =~ " $cmd " ]] && _agr_complete_files "$cur" ;;
             esac
         elif (( CURRENT >= 4 )); then
-            # Position 4+: files for marker add/list, or other file-accepting contexts
-            if [[ "$cmd" == "marker" ]]; then
+            # Position 4+: files for marker add/list/remove, or other file-accepting contexts
+            if [[ "$cmd" == "marker" || "$cmd" == "markers" ]]; then
                 _agr_complete_files "$cur"
             fi
         fi
@@ -270,8 +270,8 @@ _agr_complete() {{
 {subcmd_cases}        *) [[ " $_agr_file_cmds " =~ " $cmd " ]] && _agr_complete_files "$cur" ;;
         esac
     elif [[ $COMP_CWORD -ge 3 ]]; then
-        # Position 3+: files for marker add/list, or other file-accepting contexts
-        if [[ "$cmd" == "marker" ]]; then
+        # Position 3+: files for marker add/list/remove, or other file-accepting contexts
+        if [[ "$cmd" == "marker" || "$cmd" == "markers" ]]; then
             _agr_complete_files "$cur"
         fi
     fi
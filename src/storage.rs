@@ -3,11 +3,15 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Local};
 use humansize::{format_size, BINARY};
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::analyzer::cache::cache_path;
 use crate::config::Config;
+use crate::files::backup::backup_paths;
+use crate::files::sidecar::position_path;
 
 /// Information about a recorded session
 #[derive(Debug, Clone)]
@@ -99,6 +103,41 @@ impl StorageStats {
     }
 }
 
+/// Disk usage of a group of recordings (one agent or one month)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UsageBucket {
+    /// Agent name or `YYYY-MM` month
+    pub name: String,
+    pub sessions: usize,
+    /// Bytes, including backups and sidecar files
+    pub size: u64,
+}
+
+/// A single recording in the largest-files list
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UsageFile {
+    /// Short path (`agent/file.cast`)
+    pub path: String,
+    /// Bytes, including backups and sidecar files
+    pub size: u64,
+}
+
+/// Storage usage broken down by agent and month
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UsageReport {
+    /// Bytes used by recordings and their associated files
+    pub total_size: u64,
+    /// Part of `total_size` used by backups and sidecar files
+    pub associated_size: u64,
+    pub session_count: usize,
+    /// Sorted by agent name
+    pub by_agent: Vec<UsageBucket>,
+    /// Sorted by month, oldest first
+    pub by_month: Vec<UsageBucket>,
+    /// Largest recordings first
+    pub largest: Vec<UsageFile>,
+}
+
 /// Storage manager for session recordings
 pub struct StorageManager {
     config: Config,
@@ -200,7 +239,7 @@ impl StorageManager {
         }
 
         // Sort by modification time (oldest first)
-        sessions.sort_by(|a, b| a.modified.cmp(&b.modified));
+        sessions.sort_by_key(|s| s.modified);
 
         Ok(sessions)
     }
@@ -266,21 +305,105 @@ impl StorageManager {
         0.0
     }
 
-    /// Delete sessions by path
+    /// Delete sessions by path, including their backups and sidecar files
     pub fn delete_sessions(&self, sessions: &[SessionInfo]) -> Result<u64> {
         let mut freed_size = 0u64;
 
         for session in sessions {
             if session.path.exists() {
-                fs::remove_file(&session.path)
-                    .with_context(|| format!("Failed to delete: {:?}", session.path))?;
-                freed_size += session.size;
+                freed_size += self.delete_recording(&session.path)?;
             }
         }
 
         Ok(freed_size)
     }
 
+    /// Files stored alongside a recording that exist on disk (the `.bak`
+    /// backups, the `.pos` resume-position and `.analysis` cache sidecars).
+    pub fn associated_files(path: &Path) -> Vec<PathBuf> {
+        backup_paths(path)
+            .into_iter()
+            .chain([position_path(path), cache_path(path)])
+            .filter(|p| p.exists())
+            .collect()
+    }
+
+    /// Total bytes a recording occupies, including its associated files
+    pub fn recording_footprint(path: &Path) -> u64 {
+        std::iter::once(path.to_path_buf())
+            .chain(Self::associated_files(path))
+            .filter_map(|p| fs::metadata(p).ok())
+            .map(|m| m.len())
+            .sum()
+    }
+
+    /// Delete a recording together with its backup and sidecar files.
+    ///
+    /// Returns the number of bytes actually freed. Fails only if the
+    /// recording itself cannot be removed; leftover associated files are
+    /// skipped and not counted.
+    pub fn delete_recording(&self, path: &Path) -> Result<u64> {
+        let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        fs::remove_file(path).with_context(|| format!("Failed to delete: {:?}", path))?;
+        let mut freed_size = size;
+
+        for extra in Self::associated_files(path) {
+            let extra_size = fs::metadata(&extra).map(|m| m.len()).unwrap_or(0);
+            if fs::remove_file(&extra).is_ok() {
+                freed_size += extra_size;
+            }
+        }
+
+        Ok(freed_size)
+    }
+
+    /// Break down storage usage by agent and by month of last change.
+    ///
+    /// Sizes include each recording's backup and sidecar files. `top`
+    /// limits the largest-files list.
+    pub fn usage_report(&self, top: usize) -> Result<UsageReport> {
+        let sessions = self.list_sessions(None)?;
+        let mut by_agent: BTreeMap<String, UsageBucket> = BTreeMap::new();
+        let mut by_month: BTreeMap<String, UsageBucket> = BTreeMap::new();
+        let mut files = Vec::with_capacity(sessions.len());
+        let mut total_size = 0;
+        let mut associated_size = 0;
+
+        for session in &sessions {
+            let size = Self::recording_footprint(&session.path);
+            total_size += size;
+            associated_size += size.saturating_sub(session.size);
+
+            let month = session.modified.format("%Y-%m").to_string();
+            for (buckets, name) in [(&mut by_agent, &session.agent), (&mut by_month, &month)] {
+                let bucket = buckets.entry(name.clone()).or_insert_with(|| UsageBucket {
+                    name: name.clone(),
+                    sessions: 0,
+                    size: 0,
+                });
+                bucket.sessions += 1;
+                bucket.size += size;
+            }
+
+            files.push(UsageFile {
+                path: format!("{}/{}", session.agent, session.filename),
+                size,
+            });
+        }
+
+        files.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+        files.truncate(top);
+
+        Ok(UsageReport {
+            total_size,
+            associated_size,
+            session_count: sessions.len(),
+            by_agent: by_agent.into_values().collect(),
+            by_month: by_month.into_values().collect(),
+            largest: files,
+        })
+    }
+
     /// Check if storage exceeds threshold
     pub fn exceeds_threshold(&self) -> Result<bool> {
         let stats = self.get_stats()?;
@@ -354,7 +477,7 @@ impl StorageManager {
         let mut matches: Vec<_> = sessions.iter().filter(|s| s.filename == filename).collect();
 
         // Sort by modification time (newest first) and return the first match
-        matches.sort_by(|a, b| b.modified.cmp(&a.modified));
+        matches.sort_by_key(|s| std::cmp::Reverse(s.modified));
         matches.first().map(|s| s.path.clone())
     }
 
@@ -366,7 +489,7 @@ impl StorageManager {
         let mut sessions = self.list_sessions(None)?;
 
         // Sort by modification time (most recent first)
-        sessions.sort_by(|a, b| b.modified.cmp(&a.modified));
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.modified));
 
         let mut files: Vec<String> = sessions
             .iter()
@@ -396,6 +519,7 @@ mod tests {
                 directory: storage_dir.to_string_lossy().to_string(),
                 size_threshold_gb: 5.0,
                 age_threshold_days: 30,
+                backup_limit: 1,
             },
             agents: crate::config::AgentsConfig {
                 enabled: vec!["claude".to_string(), "codex".to_string()],
@@ -0,0 +1,61 @@
+//! Character set designation handlers.
+//!
+//! Handles ESC sequences that select the G0 character set:
+//! - ESC ( 0: DEC Special Graphics (line drawing)
+//! - ESC ( B: US ASCII
+//!
+//! While the special graphics set is active, printable characters in the
+//! range `_` to `~` are shown as box-drawing and symbol glyphs.
+
+use super::super::performer::TerminalPerformer;
+
+impl TerminalPerformer<'_> {
+    /// Designate the G0 character set (ESC ( Final).
+    ///
+    /// `0` selects DEC Special Graphics; any other final byte (`B` for ASCII,
+    /// `A` for UK, ...) falls back to plain text.
+    pub fn handle_designate_g0(&mut self, byte: u8) {
+        *self.dec_graphics = byte == b'0';
+    }
+}
+
+/// Translate a character from the DEC Special Graphics set to Unicode.
+///
+/// Characters outside the translated range are returned unchanged.
+pub fn translate_dec_graphics(c: char) -> char {
+    match c {
+        '_' => ' ',
+        '`' => '◆',
+        'a' => '▒',
+        'b' => '␉',
+        'c' => '␌',
+        'd' => '␍',
+        'e' => '␊',
+        'f' => '°',
+        'g' => '±',
+        'h' => '␤',
+        'i' => '␋',
+        'j' => '┘',
+        'k' => '┐',
+        'l' => '┌',
+        'm' => '└',
+        'n' => '┼',
+        'o' => '⎺',
+        'p' => '⎻',
+        'q' => '─',
+        'r' => '⎼',
+        's' => '⎽',
+        't' => '├',
+        'u' => '┤',
+        'v' => '┴',
+        'w' => '┬',
+        'x' => '│',
+        'y' => '≤',
+        'z' => '≥',
+        '{' => 'π',
+        '|' => '≠',
+        '}' => '£',
+        '~' => '·',
+        _ => c,
+    }
+}
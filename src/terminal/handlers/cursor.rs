@@ -12,8 +12,10 @@
 //! - u: Restore Cursor Position
 //! - ESC 7: DEC Save Cursor
 //! - ESC 8: DEC Restore Cursor
+//! - SP q: Set Cursor Style (DECSCUSR)
 
 use super::super::performer::TerminalPerformer;
+use super::super::types::CursorShape;
 
 impl TerminalPerformer<'_> {
     /// Move cursor up by n rows (CSI A).
@@ -80,4 +82,11 @@ impl TerminalPerformer<'_> {
             *self.cursor_col = col.min(self.width.saturating_sub(1));
         }
     }
+
+    /// Set cursor style (CSI Ps SP q). Unknown styles are ignored.
+    pub fn handle_cursor_shape(&mut self, param: u16) {
+        if let Some(shape) = CursorShape::from_decscusr(param) {
+            *self.cursor_shape = shape;
+        }
+    }
 }
@@ -16,12 +16,17 @@ impl TerminalPerformer<'_> {
     /// Handle Erase in Display (CSI J).
     /// Mode 0: Erase from cursor to end of screen
     /// Mode 1: Erase from start of screen to cursor
-    /// Mode 2/3: Erase entire screen
+    /// Mode 2: Erase entire screen
+    /// Mode 3: Erase entire screen and scrollback
     pub fn handle_erase_display(&mut self, mode: u16) {
         match mode {
             0 => self.erase_to_eos(),
             1 => self.erase_from_sos(),
-            2 | 3 => self.clear_screen(),
+            2 => self.clear_screen(),
+            3 => {
+                self.clear_screen();
+                self.scrollback.clear();
+            }
             _ => {}
         }
     }
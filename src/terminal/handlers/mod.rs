@@ -1,15 +1,23 @@
 //! Terminal escape sequence handlers.
 //!
 //! This module contains handlers for various escape sequence categories:
-//! - cursor: Cursor movement and positioning
+//! - charset: Character set designation (DEC line drawing)
+//! - cursor: Cursor movement, positioning and style
 //! - editing: Erase and delete operations
+//! - modes: DEC private modes (alternate screen, cursor visibility)
+//! - osc: Operating System Commands (hyperlinks)
 //! - scroll: Scroll region management
 //! - style: SGR (Select Graphic Rendition) for colors and attributes
+//! - tabs: Horizontal tab and tab stops (HTS, TBC)
 
+pub mod charset;
 pub mod cursor;
 pub mod editing;
+pub mod modes;
+pub mod osc;
 pub mod scroll;
 pub mod style;
+pub mod tabs;
 
 use tracing::trace;
 
@@ -23,6 +31,15 @@ pub fn log_unhandled_csi(action: char, params: &[u16], intermediates: &[u8]) {
     );
 }
 
+/// Log an unhandled OSC sequence for debugging.
+pub fn log_unhandled_osc(params: &[&[u8]]) {
+    trace!(
+        command = %params.first().map(|p| String::from_utf8_lossy(p)).unwrap_or_default(),
+        param_count = params.len(),
+        "Unhandled OSC sequence"
+    );
+}
+
 /// Log an unhandled ESC sequence for debugging.
 pub fn log_unhandled_esc(byte: u8, intermediates: &[u8]) {
     trace!(
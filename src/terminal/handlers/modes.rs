@@ -0,0 +1,56 @@
+//! DEC private mode handlers.
+//!
+//! Handles CSI ? h/l sequences that change what is rendered:
+//! - ?47: Alternate screen buffer
+//! - ?1047: Alternate screen buffer, cleared on exit
+//! - ?1049: Save cursor and switch to a cleared alternate screen buffer
+//! - ?25: Cursor visibility (DECTCEM)
+//!
+//! All other private modes are ignored, including input-only modes such as
+//! bracketed paste (?2004) and focus reporting (?1004), which change what the
+//! terminal sends rather than what it shows.
+
+use super::super::performer::TerminalPerformer;
+use super::super::types::{Cell, SavedScreen};
+
+impl TerminalPerformer<'_> {
+    /// Handle DEC private mode set/reset (CSI ? Pm h / CSI ? Pm l).
+    pub fn handle_dec_private_mode(&mut self, params: &[u16], set: bool) {
+        for &mode in params {
+            match (mode, set) {
+                (47 | 1047 | 1049, true) => self.enter_alternate_screen(),
+                (47 | 1047 | 1049, false) => self.exit_alternate_screen(mode == 1049),
+                (25, visible) => *self.cursor_visible = visible,
+                _ => {}
+            }
+        }
+    }
+
+    /// Switch to a blank alternate screen, keeping the primary screen aside.
+    ///
+    /// Entering while already on the alternate screen is a no-op.
+    fn enter_alternate_screen(&mut self) {
+        if self.saved_primary.is_some() {
+            return;
+        }
+        let blank = vec![vec![Cell::default(); self.width]; self.height];
+        *self.saved_primary = Some(SavedScreen {
+            buffer: std::mem::replace(self.buffer, blank),
+            cursor_row: *self.cursor_row,
+            cursor_col: *self.cursor_col,
+        });
+    }
+
+    /// Restore the primary screen.
+    ///
+    /// The cursor is restored only for mode 1049, which saved it on entry.
+    fn exit_alternate_screen(&mut self, restore_cursor: bool) {
+        if let Some(saved) = self.saved_primary.take() {
+            *self.buffer = saved.buffer;
+            if restore_cursor {
+                *self.cursor_row = saved.cursor_row.min(self.height.saturating_sub(1));
+                *self.cursor_col = saved.cursor_col.min(self.width.saturating_sub(1));
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Operating System Command handlers.
+//!
+//! Handles OSC sequences:
+//! - 8: Hyperlink (`OSC 8 ; params ; URI ST`)
+
+use super::super::performer::TerminalPerformer;
+
+impl TerminalPerformer<'_> {
+    /// Handle OSC 8 hyperlinks.
+    ///
+    /// A non-empty URI starts a link that applies to every cell written until
+    /// the next OSC 8 with an empty URI. The `id=` parameter is ignored.
+    pub fn handle_hyperlink(&mut self, params: &[&[u8]]) {
+        // The parser splits on ';', so rejoin URIs that contain one
+        let uri = params
+            .get(2..)
+            .unwrap_or_default()
+            .iter()
+            .map(|p| String::from_utf8_lossy(p))
+            .collect::<Vec<_>>()
+            .join(";");
+
+        if uri.is_empty() {
+            *self.current_link = None;
+            return;
+        }
+
+        let id = match self.links.iter().position(|l| *l == uri) {
+            Some(id) => id,
+            None => {
+                self.links.push(uri);
+                self.links.len() - 1
+            }
+        };
+        *self.current_link = Some(id);
+    }
+}
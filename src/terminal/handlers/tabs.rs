@@ -0,0 +1,66 @@
+//! Tab stop handlers.
+//!
+//! Handles the horizontal tab and the sequences that edit tab stops:
+//! - HT (`\t`): Move to the next tab stop
+//! - HTS (ESC H): Set a tab stop at the cursor column
+//! - TBC (CSI Ps g): Clear the tab stop at the cursor (0) or all stops (3)
+//!
+//! Stops start out every 8 columns, like a freshly reset terminal.
+
+use super::super::performer::TerminalPerformer;
+
+/// Distance between the default tab stops.
+pub const TAB_WIDTH: usize = 8;
+
+/// Build the default tab stop table for a terminal `width` columns wide.
+pub fn default_tab_stops(width: usize) -> Vec<bool> {
+    (0..width).map(is_default_stop).collect()
+}
+
+/// Resize a tab stop table, keeping existing stops and giving any new
+/// columns the default stops.
+pub fn resize_tab_stops(stops: &mut Vec<bool>, width: usize) {
+    let old_width = stops.len();
+    stops.truncate(width);
+    stops.extend((old_width..width).map(is_default_stop));
+}
+
+fn is_default_stop(col: usize) -> bool {
+    col > 0 && col % TAB_WIDTH == 0
+}
+
+impl TerminalPerformer<'_> {
+    /// Move the cursor to the next tab stop (HT).
+    ///
+    /// Without a stop to the right the cursor moves to the last column.
+    pub fn handle_tab(&mut self) {
+        let last_col = self.width.saturating_sub(1);
+        let next = (*self.cursor_col + 1..last_col)
+            .find(|&col| self.tab_stops.get(col).copied().unwrap_or(false))
+            .unwrap_or(last_col);
+        *self.cursor_col = next;
+    }
+
+    /// Set a tab stop at the cursor column (HTS).
+    pub fn handle_set_tab_stop(&mut self) {
+        if let Some(stop) = self.tab_stops.get_mut(*self.cursor_col) {
+            *stop = true;
+        }
+    }
+
+    /// Clear tab stops (TBC).
+    ///
+    /// Mode 0 clears the stop at the cursor column, mode 3 clears every
+    /// stop. Other modes are ignored.
+    pub fn handle_clear_tab_stop(&mut self, mode: u16) {
+        match mode {
+            0 => {
+                if let Some(stop) = self.tab_stops.get_mut(*self.cursor_col) {
+                    *stop = false;
+                }
+            }
+            3 => self.tab_stops.iter_mut().for_each(|stop| *stop = false),
+            _ => {}
+        }
+    }
+}
@@ -10,8 +10,11 @@ mod handlers;
 mod performer;
 mod types;
 
-pub use types::{Cell, CellStyle, Color, StyledLine};
+pub use types::{cells_to_text, Cell, CellStyle, Color, CursorShape, StyledLine};
 
+use types::SavedScreen;
+
+use std::collections::VecDeque;
 use std::fmt;
 use vte::Parser;
 
@@ -31,6 +34,10 @@ pub struct TerminalBuffer {
     cursor_col: usize,
     /// Current cursor row (0-indexed)
     cursor_row: usize,
+    /// Whether the cursor is shown (DECTCEM, `CSI ?25h/l`)
+    cursor_visible: bool,
+    /// Cursor style (DECSCUSR, `CSI Ps SP q`)
+    cursor_shape: CursorShape,
     /// Current style for new characters
     current_style: CellStyle,
     /// VTE parser for handling ANSI sequences
@@ -41,6 +48,20 @@ pub struct TerminalBuffer {
     scroll_top: usize,
     /// Bottom margin of scroll region (0-indexed, inclusive)
     scroll_bottom: usize,
+    /// Hyperlink URLs (OSC 8), indexed by `Cell::link`
+    links: Vec<String>,
+    /// Hyperlink applied to newly written cells
+    current_link: Option<usize>,
+    /// Primary screen saved while the alternate screen is active
+    saved_primary: Option<SavedScreen>,
+    /// Whether G0 is designated as the DEC special graphics set (`ESC ( 0`)
+    dec_graphics: bool,
+    /// Lines scrolled off the top of the primary screen, oldest first
+    scrollback: VecDeque<Vec<Cell>>,
+    /// Maximum number of scrollback lines to retain (0 disables scrollback)
+    scrollback_limit: usize,
+    /// Tab stops, one flag per column (HTS / TBC)
+    tab_stops: Vec<bool>,
 }
 
 impl TerminalBuffer {
@@ -53,14 +74,35 @@ impl TerminalBuffer {
             buffer,
             cursor_col: 0,
             cursor_row: 0,
+            cursor_visible: true,
+            cursor_shape: CursorShape::default(),
             current_style: CellStyle::default(),
             parser: Parser::new(),
             saved_cursor: None,
             scroll_top: 0,
             scroll_bottom: height.saturating_sub(1),
+            links: Vec::new(),
+            current_link: None,
+            saved_primary: None,
+            dec_graphics: false,
+            scrollback: VecDeque::new(),
+            scrollback_limit: 0,
+            tab_stops: handlers::tabs::default_tab_stops(width),
         }
     }
 
+    /// Create a new terminal buffer that retains up to `limit` scrollback lines.
+    pub fn with_scrollback(width: usize, height: usize, limit: usize) -> Self {
+        let mut buffer = Self::new(width, height);
+        buffer.scrollback_limit = limit;
+        buffer
+    }
+
+    /// Clear all screen state and scrollback, keeping the scrollback limit.
+    pub fn reset(&mut self, width: usize, height: usize) {
+        *self = Self::with_scrollback(width, height, self.scrollback_limit);
+    }
+
     /// Process output data through the terminal emulator.
     ///
     /// This parses ANSI escape sequences and updates the buffer state.
@@ -71,6 +113,8 @@ impl TerminalBuffer {
             height: self.height,
             cursor_col: &mut self.cursor_col,
             cursor_row: &mut self.cursor_row,
+            cursor_visible: &mut self.cursor_visible,
+            cursor_shape: &mut self.cursor_shape,
             current_style: &mut self.current_style,
             saved_cursor: &mut self.saved_cursor,
             scroll_top: self.scroll_top,
@@ -78,6 +122,13 @@ impl TerminalBuffer {
             scroll_callback: scroll_callback
                 .as_mut()
                 .map(|cb| *cb as &mut dyn FnMut(Vec<Cell>)),
+            links: &mut self.links,
+            current_link: &mut self.current_link,
+            saved_primary: &mut self.saved_primary,
+            dec_graphics: &mut self.dec_graphics,
+            scrollback: &mut self.scrollback,
+            scrollback_limit: self.scrollback_limit,
+            tab_stops: &mut self.tab_stops,
         };
         self.parser.advance(&mut perf, data.as_bytes());
         // Update scroll region in case it was changed by DECSTBM
@@ -90,26 +141,14 @@ impl TerminalBuffer {
     /// Preserves existing content where possible, truncating or extending
     /// rows/columns as needed. Cursor position is clamped to the new bounds.
     pub fn resize(&mut self, new_width: usize, new_height: usize) {
-        // Create new buffer with new dimensions
-        let mut new_buffer = vec![vec![Cell::default(); new_width]; new_height];
-
         // Copy existing content, preserving as much as possible
-        for (row_idx, row) in self.buffer.iter().enumerate() {
-            if row_idx >= new_height {
-                break;
-            }
-            for (col_idx, cell) in row.iter().enumerate() {
-                if col_idx >= new_width {
-                    break;
-                }
-                new_buffer[row_idx][col_idx] = *cell;
-            }
-        }
-
-        self.buffer = new_buffer;
+        self.buffer = resize_grid(&self.buffer, new_width, new_height);
         self.width = new_width;
         self.height = new_height;
 
+        // Keep stops in surviving columns; new columns get the defaults
+        handlers::tabs::resize_tab_stops(&mut self.tab_stops, new_width);
+
         // Clamp cursor to new bounds
         self.cursor_col = self.cursor_col.min(new_width.saturating_sub(1));
         self.cursor_row = self.cursor_row.min(new_height.saturating_sub(1));
@@ -118,6 +157,14 @@ impl TerminalBuffer {
         self.scroll_top = 0;
         self.scroll_bottom = new_height.saturating_sub(1);
 
+        // Keep the hidden primary screen in step so leaving the alternate
+        // screen restores a grid of the right size
+        if let Some(saved) = self.saved_primary.as_mut() {
+            saved.buffer = resize_grid(&saved.buffer, new_width, new_height);
+            saved.cursor_col = saved.cursor_col.min(new_width.saturating_sub(1));
+            saved.cursor_row = saved.cursor_row.min(new_height.saturating_sub(1));
+        }
+
         // Invalidate saved cursor if it's now out of bounds
         if let Some((row, col)) = self.saved_cursor {
             if row >= new_height || col >= new_width {
@@ -146,6 +193,21 @@ impl TerminalBuffer {
         self.cursor_col
     }
 
+    /// Get the scroll region (DECSTBM) as 0-indexed, inclusive `(top, bottom)` rows.
+    pub fn scroll_region(&self) -> (usize, usize) {
+        (self.scroll_top, self.scroll_bottom)
+    }
+
+    /// Returns `true` unless the output hid the cursor (`CSI ?25l`).
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Get the cursor style last selected by the output.
+    pub fn cursor_shape(&self) -> CursorShape {
+        self.cursor_shape
+    }
+
     /// Get styled lines for rendering with color support.
     pub fn styled_lines(&self) -> Vec<StyledLine> {
         self.buffer
@@ -165,10 +227,72 @@ impl TerminalBuffer {
             .collect()
     }
 
+    /// Returns `true` while the alternate screen (`CSI ?1049h` / `?47h`) is active.
+    pub fn is_alternate_screen(&self) -> bool {
+        self.saved_primary.is_some()
+    }
+
+    /// Get the URL of a hyperlink referenced by `Cell::link`.
+    pub fn link_url(&self, link: usize) -> Option<&str> {
+        self.links.get(link).map(String::as_str)
+    }
+
+    /// Get the hyperlink URL of the cell at the given position, if any.
+    pub fn cell_link(&self, row: usize, col: usize) -> Option<&str> {
+        let link = self.buffer.get(row)?.get(col)?.link?;
+        self.link_url(link)
+    }
+
+    /// Get the maximum number of scrollback lines retained.
+    pub fn scrollback_limit(&self) -> usize {
+        self.scrollback_limit
+    }
+
+    /// Set the maximum number of scrollback lines, dropping the oldest
+    /// lines if the history is already longer.
+    pub fn set_scrollback_limit(&mut self, limit: usize) {
+        self.scrollback_limit = limit;
+        while self.scrollback.len() > limit {
+            self.scrollback.pop_front();
+        }
+    }
+
+    /// Get the lines scrolled off the top of the primary screen, oldest first.
+    pub fn scrollback_lines(&self) -> &VecDeque<Vec<Cell>> {
+        &self.scrollback
+    }
+
+    /// Get a scrollback line counting up from the top of the screen
+    /// (1 is the most recently scrolled-off line).
+    pub fn scrollback_row(&self, lines_above: usize) -> Option<&[Cell]> {
+        if lines_above == 0 {
+            return None;
+        }
+        let idx = self.scrollback.len().checked_sub(lines_above)?;
+        self.scrollback.get(idx).map(|r| r.as_slice())
+    }
+
     /// Get a reference to a specific row's cells (no cloning).
     pub fn row(&self, row_idx: usize) -> Option<&[Cell]> {
         self.buffer.get(row_idx).map(|r| r.as_slice())
     }
+
+    /// Get the plain text of a row, with trailing whitespace trimmed.
+    pub fn line_text(&self, row_idx: usize) -> Option<String> {
+        self.row(row_idx)
+            .map(|cells| cells_to_text(cells).trim_end().to_string())
+    }
+}
+
+/// Copy a grid into new dimensions, truncating or padding with blank cells.
+fn resize_grid(grid: &[Vec<Cell>], new_width: usize, new_height: usize) -> Vec<Vec<Cell>> {
+    let mut new_grid = vec![vec![Cell::default(); new_width]; new_height];
+    for (row_idx, row) in grid.iter().enumerate().take(new_height) {
+        for (col_idx, cell) in row.iter().enumerate().take(new_width) {
+            new_grid[row_idx][col_idx] = *cell;
+        }
+    }
+    new_grid
 }
 
 impl fmt::Display for TerminalBuffer {
@@ -180,13 +304,7 @@ impl fmt::Display for TerminalBuffer {
         let mut lines: Vec<String> = self
             .buffer
             .iter()
-            .map(|row| {
-                row.iter()
-                    .map(|c| c.char)
-                    .collect::<String>()
-                    .trim_end()
-                    .to_string()
-            })
+            .map(|row| cells_to_text(row).trim_end().to_string())
             .collect();
 
         // Remove empty trailing lines
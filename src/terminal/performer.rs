@@ -3,11 +3,14 @@
 //! Contains the `TerminalPerformer` struct that implements the vte::Perform trait.
 //! Handles escape sequence dispatch to handler modules.
 
+use std::collections::VecDeque;
+
 use unicode_width::UnicodeWidthChar;
 use vte::Perform;
 
-use super::handlers::{log_unhandled_csi, log_unhandled_esc};
-use super::types::Cell;
+use super::handlers::charset::translate_dec_graphics;
+use super::handlers::{log_unhandled_csi, log_unhandled_esc, log_unhandled_osc};
+use super::types::{Cell, SavedScreen};
 
 /// Performer that handles VTE callbacks and updates the buffer.
 pub(crate) struct TerminalPerformer<'a> {
@@ -16,6 +19,10 @@ pub(crate) struct TerminalPerformer<'a> {
     pub height: usize,
     pub cursor_col: &'a mut usize,
     pub cursor_row: &'a mut usize,
+    /// Whether the cursor is shown (DECTCEM)
+    pub cursor_visible: &'a mut bool,
+    /// Cursor style (DECSCUSR)
+    pub cursor_shape: &'a mut super::types::CursorShape,
     pub current_style: &'a mut super::types::CellStyle,
     pub saved_cursor: &'a mut Option<(usize, usize)>,
     /// Top margin of scroll region (0-indexed, inclusive)
@@ -24,6 +31,20 @@ pub(crate) struct TerminalPerformer<'a> {
     pub scroll_bottom: usize,
     /// Optional callback for lines that are scrolled off the screen
     pub scroll_callback: Option<&'a mut dyn FnMut(Vec<Cell>)>,
+    /// Hyperlink URLs referenced by `Cell::link`
+    pub links: &'a mut Vec<String>,
+    /// Hyperlink applied to newly written cells (OSC 8)
+    pub current_link: &'a mut Option<usize>,
+    /// Primary screen saved while the alternate screen is active
+    pub saved_primary: &'a mut Option<SavedScreen>,
+    /// Whether G0 is the DEC special graphics (line-drawing) set
+    pub dec_graphics: &'a mut bool,
+    /// Lines scrolled off the top of the primary screen, oldest first
+    pub scrollback: &'a mut VecDeque<Vec<Cell>>,
+    /// Maximum number of scrollback lines to retain
+    pub scrollback_limit: usize,
+    /// Tab stops, one flag per column
+    pub tab_stops: &'a mut Vec<bool>,
 }
 
 impl<'a> TerminalPerformer<'a> {
@@ -59,9 +80,20 @@ impl<'a> TerminalPerformer<'a> {
                 // Remove the line at scroll_top
                 let line = self.buffer.remove(self.scroll_top);
 
-                // If a callback is registered, pass the scrolled-off line to it
-                if let Some(ref mut cb) = self.scroll_callback {
-                    cb(line);
+                // If a callback is registered, pass the scrolled-off line to it.
+                // Lines leaving the alternate screen are not history, so skip them.
+                if self.saved_primary.is_none() {
+                    // Only lines leaving the top of the screen become scrollback,
+                    // not lines scrolled out of an inner region
+                    if self.scroll_top == 0 && self.scrollback_limit > 0 {
+                        if self.scrollback.len() >= self.scrollback_limit {
+                            self.scrollback.pop_front();
+                        }
+                        self.scrollback.push_back(line.clone());
+                    }
+                    if let Some(ref mut cb) = self.scroll_callback {
+                        cb(line);
+                    }
                 }
 
                 // Insert a new blank line at scroll_bottom
@@ -115,17 +147,31 @@ impl<'a> TerminalPerformer<'a> {
         }
 
         if *self.cursor_row < self.height && *self.cursor_col < self.width {
-            self.buffer[*self.cursor_row][*self.cursor_col] = Cell {
+            let row = *self.cursor_row;
+            let col = *self.cursor_col;
+
+            // Overwriting half of a wide glyph blanks its other half
+            if self.buffer[row][col].is_wide_continuation() && col > 0 {
+                self.buffer[row][col - 1] = Cell::default();
+            }
+            let end = (col + char_width).min(self.width);
+            if end < self.width && self.buffer[row][end].is_wide_continuation() {
+                self.buffer[row][end] = Cell::default();
+            }
+
+            self.buffer[row][col] = Cell {
                 char: c,
                 style: *self.current_style,
+                link: *self.current_link,
             };
             *self.cursor_col += 1;
 
-            // For wide characters, fill the next cell with a placeholder space
+            // For wide characters, mark the next cell as covered by this glyph
             if char_width == 2 && *self.cursor_col < self.width {
-                self.buffer[*self.cursor_row][*self.cursor_col] = Cell {
-                    char: ' ', // Placeholder for second half of wide char
+                self.buffer[row][*self.cursor_col] = Cell {
+                    char: Cell::WIDE_CONTINUATION,
                     style: *self.current_style,
+                    link: *self.current_link,
                 };
                 *self.cursor_col += 1;
             }
@@ -259,6 +305,11 @@ impl<'a> TerminalPerformer<'a> {
 
 impl Perform for TerminalPerformer<'_> {
     fn print(&mut self, c: char) {
+        let c = if *self.dec_graphics {
+            translate_dec_graphics(c)
+        } else {
+            c
+        };
         self.put_char(c);
     }
 
@@ -267,11 +318,7 @@ impl Perform for TerminalPerformer<'_> {
             b'\n' => self.line_feed(),
             b'\r' => self.carriage_return(),
             b'\x08' => self.backspace(), // Backspace
-            b'\t' => {
-                // Tab - move to next tab stop (every 8 columns)
-                let next_tab = (*self.cursor_col / 8 + 1) * 8;
-                *self.cursor_col = next_tab.min(self.width - 1);
-            }
+            b'\t' => self.handle_tab(),  // Tab (handlers/tabs.rs)
             _ => {}
         }
     }
@@ -283,7 +330,13 @@ impl Perform for TerminalPerformer<'_> {
 
     fn unhook(&mut self) {}
 
-    fn osc_dispatch(&mut self, _params: &[&[u8]], _bell_terminated: bool) {}
+    fn osc_dispatch(&mut self, params: &[&[u8]], _bell_terminated: bool) {
+        match params.first() {
+            // Hyperlinks (handlers/osc.rs)
+            Some(&b"8") => self.handle_hyperlink(params),
+            _ => log_unhandled_osc(params),
+        }
+    }
 
     fn csi_dispatch(
         &mut self,
@@ -298,11 +351,14 @@ impl Perform for TerminalPerformer<'_> {
             .collect();
 
         // Handle DEC private mode sequences (ESC[?...h/l) and mouse tracking (ESC[<...)
-        // These are safe to ignore for text rendering purposes
         if intermediates.contains(&b'?') || intermediates.contains(&b'<') {
-            // DEC private modes - we don't need to implement them for text rendering
-            // Common ones: ?25h/l (cursor visibility), ?2026h/l (synchronized update),
-            // ?1049h/l (alternate screen buffer), <... (mouse tracking SGR mode), etc.
+            // Alternate screen and cursor visibility are tracked (handlers/modes.rs).
+            // Others are safe to ignore: ?2004h/l (bracketed paste), ?1004h/l
+            // (focus reporting), ?2026h/l (synchronized update),
+            // <... (mouse tracking SGR mode), etc.
+            if intermediates.contains(&b'?') && (action == 'h' || action == 'l') {
+                self.handle_dec_private_mode(&params, action == 'h');
+            }
             return;
         }
 
@@ -337,6 +393,9 @@ impl Perform for TerminalPerformer<'_> {
                 let row = params.first().copied().unwrap_or(1) as usize;
                 self.handle_cursor_vertical_absolute(row);
             }
+            'q' if intermediates == [b' '] => {
+                self.handle_cursor_shape(params.first().copied().unwrap_or(0));
+            }
             's' => self.handle_save_cursor(),
             'u' => self.handle_restore_cursor(),
 
@@ -373,6 +432,9 @@ impl Perform for TerminalPerformer<'_> {
             // Style (handlers/style.rs)
             'm' => self.handle_sgr(&params),
 
+            // Tab stops (handlers/tabs.rs)
+            'g' => self.handle_clear_tab_stop(params.first().copied().unwrap_or(0)),
+
             // Scroll region (handlers/scroll.rs)
             'r' => {
                 let top = params.first().copied().unwrap_or(1) as usize;
@@ -388,6 +450,10 @@ impl Perform for TerminalPerformer<'_> {
                 self.handle_scroll_down(n);
             }
 
+            // Bracketed paste start/end markers (CSI 200~ / CSI 201~) only
+            // delimit pasted text; consume them so the paste renders as typed
+            '~' if matches!(params.first(), Some(200 | 201)) => {}
+
             _ => log_unhandled_csi(action, &params, intermediates),
         }
     }
@@ -397,6 +463,9 @@ impl Perform for TerminalPerformer<'_> {
             b'7' => self.handle_dec_save_cursor(),
             b'8' => self.handle_dec_restore_cursor(),
             b'M' => self.handle_reverse_index(),
+            b'H' if intermediates.is_empty() => self.handle_set_tab_stop(),
+            // Designate G0 character set (handlers/charset.rs)
+            _ if intermediates == [b'('] => self.handle_designate_g0(byte),
             _ => log_unhandled_esc(byte, intermediates),
         }
     }
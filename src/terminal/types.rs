@@ -3,8 +3,10 @@
 //! Contains the core data structures for representing terminal state:
 //! - Color: ANSI color codes (16 colors, 256-color palette, RGB)
 //! - CellStyle: Text attributes (bold, italic, underline, etc.)
-//! - Cell: A single character with its style
+//! - Cell: A single character with its style and optional hyperlink
 //! - StyledLine: A line of styled cells for rendering
+//! - CursorShape: Cursor style selected by DECSCUSR
+//! - SavedScreen: Primary screen kept aside while the alternate screen is shown
 
 /// ANSI color codes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -50,6 +52,31 @@ pub struct CellStyle {
 pub struct Cell {
     pub char: char,
     pub style: CellStyle,
+    /// OSC 8 hyperlink, as an index into the buffer's link table
+    /// (resolve with `TerminalBuffer::link_url`)
+    pub link: Option<usize>,
+}
+
+impl Cell {
+    /// Placeholder char stored in the second cell of a double-width glyph.
+    pub const WIDE_CONTINUATION: char = '\0';
+
+    /// Returns `true` if this cell is the right half of a double-width glyph.
+    ///
+    /// Such cells take up a column but must not be printed: the glyph in the
+    /// previous cell already covers it.
+    pub fn is_wide_continuation(&self) -> bool {
+        self.char == Self::WIDE_CONTINUATION
+    }
+}
+
+/// Collect the text of a row of cells, skipping wide-glyph continuations.
+pub fn cells_to_text(cells: &[Cell]) -> String {
+    cells
+        .iter()
+        .filter(|c| !c.is_wide_continuation())
+        .map(|c| c.char)
+        .collect()
 }
 
 impl Default for Cell {
@@ -57,10 +84,58 @@ impl Default for Cell {
         Self {
             char: ' ',
             style: CellStyle::default(),
+            link: None,
+        }
+    }
+}
+
+/// Cursor style selected by DECSCUSR (`CSI Ps SP q`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorShape {
+    #[default]
+    BlinkingBlock,
+    SteadyBlock,
+    BlinkingUnderline,
+    SteadyUnderline,
+    BlinkingBar,
+    SteadyBar,
+}
+
+impl CursorShape {
+    /// Shape for a DECSCUSR parameter (0 and 1 both select a blinking block).
+    pub fn from_decscusr(param: u16) -> Option<Self> {
+        match param {
+            0 | 1 => Some(Self::BlinkingBlock),
+            2 => Some(Self::SteadyBlock),
+            3 => Some(Self::BlinkingUnderline),
+            4 => Some(Self::SteadyUnderline),
+            5 => Some(Self::BlinkingBar),
+            6 => Some(Self::SteadyBar),
+            _ => None,
+        }
+    }
+
+    /// DECSCUSR parameter that selects this shape.
+    pub fn decscusr(self) -> u16 {
+        match self {
+            Self::BlinkingBlock => 1,
+            Self::SteadyBlock => 2,
+            Self::BlinkingUnderline => 3,
+            Self::SteadyUnderline => 4,
+            Self::BlinkingBar => 5,
+            Self::SteadyBar => 6,
         }
     }
 }
 
+/// Primary screen state saved while the alternate screen is active
+#[derive(Debug, Clone)]
+pub(crate) struct SavedScreen {
+    pub buffer: Vec<Vec<Cell>>,
+    pub cursor_row: usize,
+    pub cursor_col: usize,
+}
+
 /// A styled line for rendering
 #[derive(Debug, Clone)]
 pub struct StyledLine {
@@ -3,6 +3,10 @@
 //! Provides ANSI escape codes for CLI output, color conversion from
 //! ratatui colors, themed text wrappers, and help text colorization.
 
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::ValueEnum;
 use ratatui::style::Color;
 
 use super::Theme;
@@ -10,6 +14,79 @@ use super::Theme;
 /// ANSI reset sequence
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// When CLI output is colored, as chosen with `--color`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color unless NO_COLOR is set or stdout is not a terminal
+    #[default]
+    Auto,
+    /// Always color
+    Always,
+    /// Never color
+    Never,
+}
+
+/// Whether CLI text helpers emit ANSI codes. Set once at startup by
+/// [`init_color`]; library callers that never call it keep colors.
+static COLOR_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Decide whether to color CLI output.
+///
+/// `Auto` honors `NO_COLOR` (set and non-empty disables color), then
+/// `FORCE_COLOR` (set, non-empty and not `0` enables it), then falls back
+/// to whether stdout is a terminal.
+pub fn should_color(
+    choice: ColorChoice,
+    no_color: Option<&str>,
+    force_color: Option<&str>,
+    is_tty: bool,
+) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            if no_color.is_some_and(|v| !v.is_empty()) {
+                false
+            } else if force_color.is_some_and(|v| !v.is_empty() && v != "0") {
+                true
+            } else {
+                is_tty
+            }
+        }
+    }
+}
+
+/// Enable or disable colored CLI output for the rest of the process.
+///
+/// Resolves `choice` against the environment and stdout, see [`should_color`].
+pub fn init_color(choice: ColorChoice) {
+    let no_color = std::env::var("NO_COLOR").ok();
+    let force_color = std::env::var("FORCE_COLOR").ok();
+    let enabled = should_color(
+        choice,
+        no_color.as_deref(),
+        force_color.as_deref(),
+        std::io::stdout().is_terminal(),
+    );
+    COLOR_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if CLI output should contain ANSI color codes.
+pub fn color_enabled() -> bool {
+    COLOR_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Pass an ANSI escape code through, or drop it when color is disabled.
+///
+/// Use this around raw [`ansi`] codes so they honor `--color` and `NO_COLOR`.
+pub fn paint(code: &'static str) -> &'static str {
+    if color_enabled() {
+        code
+    } else {
+        ""
+    }
+}
+
 /// ANSI color codes for CLI output - exposed for clap styling
 pub mod ansi {
     /// Gray color (ANSI 37) - used for descriptions
@@ -25,8 +102,10 @@ pub mod ansi {
 }
 
 /// Convert a ratatui Color to an ANSI escape code.
+///
+/// Returns an empty string when color is disabled.
 pub fn color_to_ansi(color: Color) -> &'static str {
-    match color {
+    paint(match color {
         Color::Black => "\x1b[30m",
         Color::Red => "\x1b[31m",
         Color::Green => "\x1b[32m",
@@ -46,18 +125,28 @@ pub fn color_to_ansi(color: Color) -> &'static str {
         Color::Reset => "\x1b[0m",
         // For RGB and indexed colors, fall back to reset (no color)
         _ => "",
-    }
+    })
 }
 
 impl Theme {
     /// Format text with the accent color (for CLI output).
     pub fn accent_text(&self, text: &str) -> String {
-        format!("{}{}{}", color_to_ansi(self.accent), text, ANSI_RESET)
+        format!(
+            "{}{}{}",
+            color_to_ansi(self.accent),
+            text,
+            paint(ANSI_RESET)
+        )
     }
 
     /// Format text with the primary color (for CLI output).
     pub fn primary_text(&self, text: &str) -> String {
-        format!("{}{}{}", color_to_ansi(self.text_primary), text, ANSI_RESET)
+        format!(
+            "{}{}{}",
+            color_to_ansi(self.text_primary),
+            text,
+            paint(ANSI_RESET)
+        )
     }
 
     /// Format text with the secondary color (for CLI output).
@@ -66,18 +155,23 @@ impl Theme {
             "{}{}{}",
             color_to_ansi(self.text_secondary),
             text,
-            ANSI_RESET
+            paint(ANSI_RESET)
         )
     }
 
     /// Format text with the error color (for CLI output).
     pub fn error_text(&self, text: &str) -> String {
-        format!("{}{}{}", color_to_ansi(self.error), text, ANSI_RESET)
+        format!("{}{}{}", color_to_ansi(self.error), text, paint(ANSI_RESET))
     }
 
     /// Format text with the success color (for CLI output).
     pub fn success_text(&self, text: &str) -> String {
-        format!("{}{}{}", color_to_ansi(self.success), text, ANSI_RESET)
+        format!(
+            "{}{}{}",
+            color_to_ansi(self.success),
+            text,
+            paint(ANSI_RESET)
+        )
     }
 }
 
@@ -89,6 +183,9 @@ impl Theme {
 ///
 /// This post-processes clap's output to apply consistent theming.
 pub fn colorize_help(text: &str) -> String {
+    if !color_enabled() {
+        return text.to_string();
+    }
     let theme = super::current_theme();
     let green = color_to_ansi(theme.accent);
     let gray = color_to_ansi(theme.text_primary);
@@ -211,6 +308,20 @@ mod tests {
         assert!(primary.contains("hello"));
     }
 
+    #[test]
+    fn should_color_honors_choice_and_environment() {
+        assert!(should_color(ColorChoice::Always, Some("1"), None, false));
+        assert!(!should_color(ColorChoice::Never, None, Some("1"), true));
+
+        assert!(should_color(ColorChoice::Auto, None, None, true));
+        assert!(!should_color(ColorChoice::Auto, None, None, false));
+        assert!(!should_color(ColorChoice::Auto, Some("1"), None, true));
+        assert!(should_color(ColorChoice::Auto, Some(""), None, true));
+        assert!(should_color(ColorChoice::Auto, None, Some("1"), false));
+        assert!(!should_color(ColorChoice::Auto, None, Some("0"), false));
+        assert!(!should_color(ColorChoice::Auto, Some("1"), Some("1"), true));
+    }
+
     #[test]
     fn color_to_ansi_maps_standard_colors() {
         assert_eq!(color_to_ansi(Color::Green), "\x1b[32m");
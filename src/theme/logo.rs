@@ -5,7 +5,7 @@
 
 use unicode_width::UnicodeWidthStr;
 
-use super::cli::{color_to_ansi, ANSI_RESET};
+use super::cli::{color_to_ansi, paint, ANSI_RESET};
 use super::current_theme;
 use super::Theme;
 
@@ -49,7 +49,7 @@ fn colorize_recording_banner(text: &str, theme: &Theme) -> String {
         } else {
             result.push_str(accent);
             result.push_str(line);
-            result.push_str(ANSI_RESET);
+            result.push_str(paint(ANSI_RESET));
             result.push('\n');
         }
     }
@@ -69,9 +69,9 @@ fn colorize_rec_banner_line(line: &str, accent: &str, result: &mut String) {
         if c == 'R' && !in_rec {
             let rest: String = chars.clone().take(2).collect();
             if rest == "EC" {
-                result.push_str(BOLD);
+                result.push_str(paint(BOLD));
                 result.push_str("REC");
-                result.push_str(ANSI_RESET);
+                result.push_str(paint(ANSI_RESET));
                 result.push_str(accent);
                 chars.next(); // skip E
                 chars.next(); // skip C
@@ -83,7 +83,7 @@ fn colorize_rec_banner_line(line: &str, accent: &str, result: &mut String) {
             result.push(c);
         }
     }
-    result.push_str(ANSI_RESET);
+    result.push_str(paint(ANSI_RESET));
     result.push('\n');
 }
 
@@ -118,7 +118,7 @@ pub fn print_box_prompt(content: &str) {
         content,
         width = BOX_WIDTH
     );
-    print!("{}", ANSI_RESET);
+    print!("{}", paint(ANSI_RESET));
 }
 
 /// Print just the closing border character (after user input).
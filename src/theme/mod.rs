@@ -4,6 +4,8 @@
 //! Provides ratatui styles (for TUI), ANSI escape codes (for CLI),
 //! and logo/banner assets (for branding).
 
+use std::sync::RwLock;
+
 use ratatui::style::Color;
 
 pub mod cli;
@@ -12,7 +14,10 @@ pub mod tui;
 
 // Re-exports from cli.rs
 pub use cli::ansi;
-pub use cli::{color_to_ansi, colorize_help, ANSI_RESET};
+pub use cli::{
+    color_enabled, color_to_ansi, colorize_help, init_color, paint, should_color, ColorChoice,
+    ANSI_RESET,
+};
 
 // Re-exports from logo.rs
 pub use logo::{
@@ -85,11 +90,64 @@ impl Theme {
     }
 }
 
+/// Names of the built-in themes, in picker order.
+pub const THEME_NAMES: &[&str] = &["claude_code", "classic", "ocean"];
+
+/// Name of the default theme.
+pub const DEFAULT_THEME_NAME: &str = "claude_code";
+
+impl Theme {
+    /// Look up a built-in theme by name.
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "claude_code" => Some(Self::claude_code()),
+            "classic" => Some(Self::classic()),
+            "ocean" => Some(Self::ocean()),
+            _ => None,
+        }
+    }
+}
+
+/// Name of the active theme (None = default).
+static ACTIVE_THEME: RwLock<Option<&'static str>> = RwLock::new(None);
+
 /// Global theme instance.
 ///
-/// In the future, this could be loaded from config.
+/// Returns the theme chosen with [`set_theme`], or the default theme.
+/// Everything that draws reads this on every frame, so switching themes
+/// takes effect on the next draw.
 pub fn current_theme() -> Theme {
-    Theme::default()
+    Theme::by_name(current_theme_name()).unwrap_or_default()
+}
+
+/// Name of the active theme.
+pub fn current_theme_name() -> &'static str {
+    active_theme_name(&ACTIVE_THEME)
+}
+
+/// Switch the active theme by name.
+///
+/// Returns false (leaving the theme unchanged) if the name is unknown.
+pub fn set_theme(name: &str) -> bool {
+    store_theme_name(&ACTIVE_THEME, name)
+}
+
+fn active_theme_name(active: &RwLock<Option<&'static str>>) -> &'static str {
+    active
+        .read()
+        .ok()
+        .and_then(|name| *name)
+        .unwrap_or(DEFAULT_THEME_NAME)
+}
+
+fn store_theme_name(active: &RwLock<Option<&'static str>>, name: &str) -> bool {
+    let Some(&name) = THEME_NAMES.iter().find(|&&n| n == name) else {
+        return false;
+    };
+    if let Ok(mut active) = active.write() {
+        *active = Some(name);
+    }
+    true
 }
 
 #[cfg(test)]
@@ -114,4 +172,25 @@ mod tests {
         let theme = Theme::ocean();
         assert_eq!(theme.text_primary, Color::Cyan);
     }
+
+    #[test]
+    fn every_theme_name_resolves() {
+        for name in THEME_NAMES {
+            assert!(Theme::by_name(name).is_some(), "unknown theme {}", name);
+        }
+        assert!(Theme::by_name("neon").is_none());
+    }
+
+    #[test]
+    fn storing_theme_name_switches_active_theme() {
+        // Uses a local slot so the process-wide theme is left alone
+        let active = RwLock::new(None);
+        assert_eq!(active_theme_name(&active), DEFAULT_THEME_NAME);
+
+        assert!(store_theme_name(&active, "ocean"));
+        assert_eq!(active_theme_name(&active), "ocean");
+
+        assert!(!store_theme_name(&active, "neon"));
+        assert_eq!(active_theme_name(&active), "ocean");
+    }
 }
@@ -1,12 +1,15 @@
 //! Shared key dispatch for TUI applications
 //!
 //! Provides a unified `handle_shared_key()` dispatcher that handles modes
-//! common to all explorer apps (Search, AgentFilter, Help, navigation).
+//! common to all explorer apps (Search, AgentFilter, DateFilter, ThemePicker,
+//! Help, navigation).
 //! App-specific modes are handled by each app after receiving `NotConsumed`.
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::shared_state::SharedState;
+use crate::theme::{current_theme_name, set_theme, THEME_NAMES};
+use crate::tui::widgets::DateRange;
 
 /// Modes shared across all TUI explorer applications.
 ///
@@ -20,6 +23,10 @@ pub enum SharedMode {
     Search,
     /// Agent filter mode -- cycling through agents
     AgentFilter,
+    /// Date filter mode -- typing a `START..END` date range
+    DateFilter,
+    /// Theme picker mode -- choosing a color theme
+    ThemePicker,
     /// Help mode -- showing keyboard shortcuts
     Help,
     /// Confirm delete mode
@@ -51,6 +58,8 @@ pub fn handle_shared_key(mode: &SharedMode, key: KeyEvent, state: &mut SharedSta
     match mode {
         SharedMode::Search => handle_search_key(key, state),
         SharedMode::AgentFilter => handle_agent_filter_key(key, state),
+        SharedMode::DateFilter => handle_date_filter_key(key, state),
+        SharedMode::ThemePicker => handle_theme_picker_key(key, state),
         SharedMode::Help => handle_help_key(),
         SharedMode::Normal => handle_normal_navigation(key, state),
         SharedMode::ConfirmDelete => KeyResult::NotConsumed,
@@ -122,6 +131,70 @@ fn handle_agent_filter_key(key: KeyEvent, state: &mut SharedState) -> KeyResult
     }
 }
 
+/// Handle keys in date filter mode.
+///
+/// Enter applies the typed range (an empty input clears the filter),
+/// Escape cancels, Backspace deletes a character. An invalid range leaves
+/// the current filter untouched and reports the problem in the status bar.
+fn handle_date_filter_key(key: KeyEvent, state: &mut SharedState) -> KeyResult {
+    match key.code {
+        KeyCode::Esc => KeyResult::EnterMode(SharedMode::Normal),
+        KeyCode::Enter => {
+            let input = state.date_input.trim();
+            if input.is_empty() {
+                state.explorer.set_date_filter(None);
+            } else {
+                match DateRange::parse(input) {
+                    Ok(range) => state.explorer.set_date_filter(Some(range)),
+                    Err(e) => state.status_message = Some(format!("Invalid date range: {}", e)),
+                }
+            }
+            KeyResult::EnterMode(SharedMode::Normal)
+        }
+        KeyCode::Backspace => {
+            state.date_input.pop();
+            KeyResult::Consumed
+        }
+        KeyCode::Char(c) => {
+            if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT {
+                state.date_input.push(c);
+            }
+            KeyResult::Consumed
+        }
+        _ => KeyResult::Consumed,
+    }
+}
+
+/// Handle keys in theme picker mode.
+///
+/// Up/k and Down/j move through the themes, Enter applies the highlighted
+/// theme (live, and persisted to config), Escape cancels.
+fn handle_theme_picker_key(key: KeyEvent, state: &mut SharedState) -> KeyResult {
+    match key.code {
+        KeyCode::Esc => KeyResult::EnterMode(SharedMode::Normal),
+        KeyCode::Up | KeyCode::Char('k') => {
+            state.theme_picker_idx = if state.theme_picker_idx > 0 {
+                state.theme_picker_idx - 1
+            } else {
+                THEME_NAMES.len() - 1
+            };
+            KeyResult::Consumed
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            state.theme_picker_idx = (state.theme_picker_idx + 1) % THEME_NAMES.len();
+            KeyResult::Consumed
+        }
+        KeyCode::Enter => {
+            let name = THEME_NAMES[state.theme_picker_idx];
+            set_theme(name);
+            state.status_message = Some(format!("Theme: {}", name));
+            state.save_preferences();
+            KeyResult::EnterMode(SharedMode::Normal)
+        }
+        _ => KeyResult::Consumed,
+    }
+}
+
 /// Handle keys in help mode.
 ///
 /// Any key exits help and returns to normal mode.
@@ -132,8 +205,10 @@ fn handle_help_key() -> KeyResult {
 /// Handle navigation and mode-transition keys in normal mode.
 ///
 /// Handles only keys that are identical between list_app and cleanup_app:
-/// navigation (up/down/pgup/pgdn/home/end) and mode transitions
-/// ('/' for search, 'f' for agent filter, '?' for help).
+/// navigation (up/down/pgup/pgdn/home/end), preview scrolling (Ctrl+u/Ctrl+d),
+/// sorting ('s' cycles the field, 'S' flips the direction) and mode
+/// transitions ('/' for search, 'f' for agent filter, 'D' for date range,
+/// 'T' for theme picker, '?' for help).
 /// Returns `NotConsumed` for app-specific keys (Enter, Space, etc.).
 fn handle_normal_navigation(key: KeyEvent, state: &mut SharedState) -> KeyResult {
     match key.code {
@@ -163,6 +238,28 @@ fn handle_normal_navigation(key: KeyEvent, state: &mut SharedState) -> KeyResult
             KeyResult::Consumed
         }
 
+        // Preview scrolling
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.scroll_preview(true);
+            KeyResult::Consumed
+        }
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            state.scroll_preview(false);
+            KeyResult::Consumed
+        }
+
+        // Sorting
+        KeyCode::Char('s') => {
+            state.explorer.cycle_sort_field();
+            state.save_preferences();
+            KeyResult::Consumed
+        }
+        KeyCode::Char('S') => {
+            state.explorer.toggle_sort_direction();
+            state.save_preferences();
+            KeyResult::Consumed
+        }
+
         // Mode transitions (shared across both apps)
         KeyCode::Char('/') => {
             state.search_input.clear();
@@ -186,6 +283,21 @@ fn handle_normal_navigation(key: KeyEvent, state: &mut SharedState) -> KeyResult
             }
             KeyResult::EnterMode(SharedMode::AgentFilter)
         }
+        KeyCode::Char('D') => {
+            state.date_input = state
+                .explorer
+                .date_filter()
+                .map(|r| r.to_string())
+                .unwrap_or_default();
+            state.status_message = None;
+            KeyResult::EnterMode(SharedMode::DateFilter)
+        }
+        KeyCode::Char('T') => {
+            let current = current_theme_name();
+            state.theme_picker_idx = THEME_NAMES.iter().position(|&n| n == current).unwrap_or(0);
+            state.status_message = None;
+            KeyResult::EnterMode(SharedMode::ThemePicker)
+        }
         KeyCode::Char('?') => KeyResult::EnterMode(SharedMode::Help),
 
         // All other keys are app-specific
@@ -196,7 +308,8 @@ fn handle_normal_navigation(key: KeyEvent, state: &mut SharedState) -> KeyResult
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tui::widgets::FileItem;
+    use crate::terminal::StyledLine;
+    use crate::tui::widgets::{FileItem, SessionPreview, SortDirection, SortField};
     use chrono::Local;
 
     fn make_state(agents: &[&str]) -> SharedState {
@@ -373,6 +486,129 @@ mod tests {
         assert_eq!(state.agent_filter_idx, 2); // wraps to last
     }
 
+    // --- Date filter mode tests ---
+
+    fn type_str(state: &mut SharedState, text: &str) {
+        for c in text.chars() {
+            handle_shared_key(&SharedMode::DateFilter, key(KeyCode::Char(c)), state);
+        }
+    }
+
+    #[test]
+    fn normal_shift_d_enters_date_filter() {
+        let mut state = make_state(&["claude"]);
+        let result = handle_shared_key(
+            &SharedMode::Normal,
+            key_with_shift(KeyCode::Char('D')),
+            &mut state,
+        );
+        assert_eq!(result, KeyResult::EnterMode(SharedMode::DateFilter));
+        assert!(state.date_input.is_empty());
+    }
+
+    #[test]
+    fn shift_d_prefills_current_range() {
+        let mut state = make_state(&["claude"]);
+        state
+            .explorer
+            .set_date_filter(Some(DateRange::parse("2024-01-01..").unwrap()));
+        handle_shared_key(
+            &SharedMode::Normal,
+            key_with_shift(KeyCode::Char('D')),
+            &mut state,
+        );
+        assert_eq!(state.date_input, "2024-01-01..");
+    }
+
+    #[test]
+    fn date_filter_enter_applies_range() {
+        let mut state = make_state(&["claude"]);
+        type_str(&mut state, "2024-01-01..2024-02-01");
+        let result = handle_shared_key(&SharedMode::DateFilter, key(KeyCode::Enter), &mut state);
+        assert_eq!(result, KeyResult::EnterMode(SharedMode::Normal));
+        assert_eq!(
+            state.explorer.date_filter().map(|r| r.to_string()),
+            Some("2024-01-01..2024-02-01".to_string())
+        );
+    }
+
+    #[test]
+    fn date_filter_empty_enter_clears_range() {
+        let mut state = make_state(&["claude"]);
+        state
+            .explorer
+            .set_date_filter(Some(DateRange::parse("..2024-02-01").unwrap()));
+        handle_shared_key(&SharedMode::DateFilter, key(KeyCode::Enter), &mut state);
+        assert_eq!(state.explorer.date_filter(), None);
+    }
+
+    #[test]
+    fn date_filter_invalid_range_reports_error() {
+        let mut state = make_state(&["claude"]);
+        type_str(&mut state, "2024-99-01..");
+        let result = handle_shared_key(&SharedMode::DateFilter, key(KeyCode::Enter), &mut state);
+        assert_eq!(result, KeyResult::EnterMode(SharedMode::Normal));
+        assert_eq!(state.explorer.date_filter(), None);
+        assert!(state
+            .status_message
+            .as_deref()
+            .unwrap()
+            .starts_with("Invalid date range"));
+    }
+
+    #[test]
+    fn date_filter_backspace_and_esc() {
+        let mut state = make_state(&["claude"]);
+        type_str(&mut state, "2024");
+        handle_shared_key(&SharedMode::DateFilter, key(KeyCode::Backspace), &mut state);
+        assert_eq!(state.date_input, "202");
+        let result = handle_shared_key(&SharedMode::DateFilter, key(KeyCode::Esc), &mut state);
+        assert_eq!(result, KeyResult::EnterMode(SharedMode::Normal));
+        assert_eq!(state.explorer.date_filter(), None);
+    }
+
+    // --- Theme picker tests ---
+
+    #[test]
+    fn normal_shift_t_opens_picker_on_current_theme() {
+        let mut state = make_state(&["claude"]);
+        state.theme_picker_idx = 2;
+        let result = handle_shared_key(
+            &SharedMode::Normal,
+            key_with_shift(KeyCode::Char('T')),
+            &mut state,
+        );
+        assert_eq!(result, KeyResult::EnterMode(SharedMode::ThemePicker));
+        assert_eq!(
+            THEME_NAMES[state.theme_picker_idx],
+            crate::theme::current_theme_name()
+        );
+    }
+
+    #[test]
+    fn theme_picker_navigation_wraps() {
+        let mut state = make_state(&["claude"]);
+        state.theme_picker_idx = 0;
+        handle_shared_key(&SharedMode::ThemePicker, key(KeyCode::Up), &mut state);
+        assert_eq!(state.theme_picker_idx, THEME_NAMES.len() - 1);
+        handle_shared_key(
+            &SharedMode::ThemePicker,
+            key(KeyCode::Char('j')),
+            &mut state,
+        );
+        assert_eq!(state.theme_picker_idx, 0);
+        handle_shared_key(&SharedMode::ThemePicker, key(KeyCode::Down), &mut state);
+        assert_eq!(state.theme_picker_idx, 1);
+    }
+
+    #[test]
+    fn theme_picker_esc_cancels() {
+        let mut state = make_state(&["claude"]);
+        let result = handle_shared_key(&SharedMode::ThemePicker, key(KeyCode::Esc), &mut state);
+        assert_eq!(result, KeyResult::EnterMode(SharedMode::Normal));
+        assert!(state.status_message.is_none());
+    }
+
     // --- Help mode tests ---
 
     #[test]
@@ -499,6 +735,123 @@ mod tests {
         assert_eq!(result, KeyResult::NotConsumed);
     }
 
+    // --- Preview scrolling ---
+
+    fn key_with_ctrl(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::CONTROL)
+    }
+
+    fn state_with_preview(lines: usize) -> SharedState {
+        let mut state = make_state(&["claude", "codex"]);
+        let path = state.explorer.selected_item().unwrap().path.clone();
+        state.preview_cache.insert(
+            path,
+            SessionPreview {
+                duration_secs: 10.0,
+                marker_count: 0,
+                styled_preview: vec![StyledLine { cells: Vec::new() }; lines],
+            },
+        );
+        state
+    }
+
+    #[test]
+    fn ctrl_d_scrolls_preview_down_and_clamps() {
+        let mut state = state_with_preview(20);
+        let result = handle_shared_key(
+            &SharedMode::Normal,
+            key_with_ctrl(KeyCode::Char('d')),
+            &mut state,
+        );
+        assert_eq!(result, KeyResult::Consumed);
+        assert_eq!(state.preview_scroll_offset(), 6);
+
+        handle_shared_key(
+            &SharedMode::Normal,
+            key_with_ctrl(KeyCode::Char('d')),
+            &mut state,
+        );
+        assert_eq!(state.preview_scroll_offset(), 8);
+    }
+
+    #[test]
+    fn ctrl_u_scrolls_preview_up_to_top() {
+        let mut state = state_with_preview(40);
+        state.scroll_preview(true);
+        state.scroll_preview(true);
+        handle_shared_key(
+            &SharedMode::Normal,
+            key_with_ctrl(KeyCode::Char('u')),
+            &mut state,
+        );
+        assert_eq!(state.preview_scroll_offset(), 6);
+        state.scroll_preview(false);
+        state.scroll_preview(false);
+        assert_eq!(state.preview_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn preview_scroll_ignored_without_loaded_preview() {
+        let mut state = make_state(&["claude"]);
+        state.scroll_preview(true);
+        assert_eq!(state.preview_scroll, None);
+        assert_eq!(state.preview_scroll_offset(), 0);
+    }
+
+    #[test]
+    fn preview_scroll_resets_when_selection_changes() {
+        let mut state = state_with_preview(40);
+        state.scroll_preview(true);
+        assert_eq!(state.preview_scroll_offset(), 6);
+
+        handle_shared_key(&SharedMode::Normal, key(KeyCode::Down), &mut state);
+        assert_eq!(state.preview_scroll_offset(), 0);
+
+        handle_shared_key(&SharedMode::Normal, key(KeyCode::Up), &mut state);
+        assert_eq!(state.preview_scroll_offset(), 6);
+    }
+
+    #[test]
+    fn plain_d_and_u_are_not_consumed() {
+        let mut state = make_state(&["claude"]);
+        let result = handle_shared_key(&SharedMode::Normal, key(KeyCode::Char('d')), &mut state);
+        assert_eq!(result, KeyResult::NotConsumed);
+        let result = handle_shared_key(&SharedMode::Normal, key(KeyCode::Char('u')), &mut state);
+        assert_eq!(result, KeyResult::NotConsumed);
+    }
+
+    // --- Sorting ---
+
+    #[test]
+    fn normal_s_cycles_sort_field() {
+        let mut state = make_state(&["claude", "codex"]);
+        let result = handle_shared_key(&SharedMode::Normal, key(KeyCode::Char('s')), &mut state);
+        assert_eq!(result, KeyResult::Consumed);
+        assert_eq!(state.explorer.sort_field(), SortField::Size);
+        assert_eq!(state.explorer.sort_direction(), SortDirection::Descending);
+    }
+
+    #[test]
+    fn normal_shift_s_flips_sort_direction() {
+        let mut state = make_state(&["claude", "codex"]);
+        let result = handle_shared_key(
+            &SharedMode::Normal,
+            key_with_shift(KeyCode::Char('S')),
+            &mut state,
+        );
+        assert_eq!(result, KeyResult::Consumed);
+        assert_eq!(state.explorer.sort_field(), SortField::Date);
+        assert_eq!(state.explorer.sort_direction(), SortDirection::Ascending);
+    }
+
+    #[test]
+    fn sort_keys_do_not_persist_by_default() {
+        let mut state = make_state(&["claude"]);
+        assert!(!state.persist_preferences);
+        handle_shared_key(&SharedMode::Normal, key(KeyCode::Char('s')), &mut state);
+        assert!(state.status_message.is_none());
+    }
+
     // --- ConfirmDelete passthrough ---
 
     #[test]
@@ -13,17 +13,21 @@ use crate::tui::widgets::{FileExplorer, FileExplorerWidget, SessionPreview};
 /// Both `list_app` and `cleanup_app` use this with different flag combinations:
 /// - `show_checkboxes`: true for cleanup (multi-select), false for list
 /// - `has_backup`: true when the selected file has a backup (list only)
+///
+/// `preview_scroll` is the terminal snapshot offset for the selected session.
 pub fn render_explorer_list(
     frame: &mut Frame,
     area: Rect,
     explorer: &mut FileExplorer,
     preview: Option<&SessionPreview>,
+    preview_scroll: usize,
     show_checkboxes: bool,
     has_backup: bool,
 ) {
     let widget = FileExplorerWidget::new(explorer)
         .show_checkboxes(show_checkboxes)
         .session_preview(preview)
+        .preview_scroll(preview_scroll)
         .has_backup(has_backup);
     frame.render_widget(widget, area);
 }
@@ -11,7 +11,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::theme::current_theme;
+use crate::theme::{current_theme, current_theme_name};
 
 /// Calculate a centered modal area within the given parent area.
 ///
@@ -76,6 +76,56 @@ pub fn render_confirm_delete_modal(frame: &mut Frame, area: Rect, count: usize,
     frame.render_widget(confirm, modal_area);
 }
 
+/// Render the theme picker modal listing the available themes.
+///
+/// `selected` is the highlighted row; the active theme is marked with `*`.
+pub fn render_theme_picker_modal(frame: &mut Frame, area: Rect, names: &[&str], selected: usize) {
+    let theme = current_theme();
+    let active = current_theme_name();
+    let modal_area = center_modal(area, 36, names.len() as u16 + 4);
+
+    // Clear the area behind the modal
+    frame.render_widget(Clear, modal_area);
+
+    let mut lines: Vec<Line> = names
+        .iter()
+        .enumerate()
+        .map(|(idx, &name)| {
+            let marker = if name == active { "*" } else { " " };
+            let text = format!(
+                "{} {} {}",
+                if idx == selected { ">" } else { " " },
+                marker,
+                name
+            );
+            if idx == selected {
+                Line::from(Span::styled(
+                    text,
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(text, Style::default().fg(theme.text_primary)))
+            }
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Enter: apply | Esc: cancel",
+        Style::default().fg(theme.text_secondary),
+    )));
+
+    let picker = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.accent))
+            .title(" Theme "),
+    );
+
+    frame.render_widget(picker, modal_area);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,14 @@
 //! Shared state for TUI applications
 //!
 //! Contains the fields that are common across all TUI explorer apps
-//! (search input, agent filter, explorer, status message, preview cache).
+//! (search input, agent and date filters, explorer, status message, preview cache).
 
+use crate::config::{Config, TuiConfig};
+use crate::theme::current_theme_name;
 use crate::tui::lru_cache::{new_preview_cache, PreviewCache};
-use crate::tui::widgets::{FileExplorer, FileItem, SessionPreview};
+use crate::tui::widgets::{
+    FileExplorer, FileItem, SessionPreview, SortDirection, SortField, PREVIEW_VISIBLE_LINES,
+};
 
 /// Shared state fields used by all TUI explorer applications.
 ///
@@ -15,14 +19,22 @@ pub struct SharedState {
     pub explorer: FileExplorer,
     /// Current search input buffer
     pub search_input: String,
+    /// Current date range input buffer
+    pub date_input: String,
     /// Index into `available_agents` for agent filter cycling
     pub agent_filter_idx: usize,
+    /// Index into `THEME_NAMES` for the theme picker
+    pub theme_picker_idx: usize,
     /// Agent names available for filtering (first entry is "All")
     pub available_agents: Vec<String>,
     /// Transient status message displayed in the status bar
     pub status_message: Option<String>,
     /// Async LRU cache for session preview loading
     pub preview_cache: PreviewCache,
+    /// Whether sort changes are written back to the user config
+    pub persist_preferences: bool,
+    /// Preview scroll offset, keyed by the path it applies to
+    pub preview_scroll: Option<(String, usize)>,
 }
 
 impl SharedState {
@@ -50,10 +62,78 @@ impl SharedState {
         Self {
             explorer,
             search_input: String::new(),
+            date_input: String::new(),
             agent_filter_idx: 0,
+            theme_picker_idx: 0,
             available_agents,
             status_message: None,
             preview_cache,
+            persist_preferences: false,
+            preview_scroll: None,
+        }
+    }
+
+    /// Scroll offset of the preview for the currently selected session.
+    ///
+    /// Returns 0 when the stored offset belongs to a different path, so the
+    /// preview starts at the top whenever the selection changes.
+    pub fn preview_scroll_offset(&self) -> usize {
+        match (&self.preview_scroll, self.explorer.selected_item()) {
+            (Some((path, offset)), Some(item)) if *path == item.path => *offset,
+            _ => 0,
+        }
+    }
+
+    /// Scroll the preview of the selected session by half a panel.
+    ///
+    /// Does nothing until the preview has been loaded into the cache.
+    pub fn scroll_preview(&mut self, down: bool) {
+        let Some(path) = self.explorer.selected_item().map(|i| i.path.clone()) else {
+            return;
+        };
+        let Some(max) = self.preview_cache.get(&path).map(|p| p.max_scroll()) else {
+            return;
+        };
+        let step = PREVIEW_VISIBLE_LINES / 2;
+        let current = self.preview_scroll_offset();
+        let offset = if down {
+            (current + step).min(max)
+        } else {
+            current.saturating_sub(step)
+        };
+        self.preview_scroll = Some((path, offset));
+    }
+
+    /// Load the saved sort preferences and enable persisting sort and theme changes.
+    ///
+    /// Unknown values in the config fall back to the defaults. The theme itself
+    /// is applied by [`Config::load`].
+    pub fn load_preferences(&mut self, tui: &TuiConfig) {
+        let field = SortField::parse(&tui.sort_field).unwrap_or_default();
+        let direction = SortDirection::parse(&tui.sort_direction).unwrap_or_default();
+        self.explorer.set_sort_order(field, direction);
+        self.persist_preferences = true;
+    }
+
+    /// Write the current sort order and theme to the user config, if persisting is enabled.
+    ///
+    /// Only these keys are touched; the rest of the file, including comments,
+    /// is left as written. Failures are reported in the status bar rather than
+    /// interrupting the TUI.
+    pub fn save_preferences(&mut self) {
+        if !self.persist_preferences {
+            return;
+        }
+        let result = Config::save_values(&[
+            ("tui.sort_field", self.explorer.sort_field().as_str()),
+            (
+                "tui.sort_direction",
+                self.explorer.sort_direction().as_str(),
+            ),
+            ("tui.theme", current_theme_name()),
+        ]);
+        if let Err(e) = result {
+            self.status_message = Some(format!("Failed to save preferences: {}", e));
         }
     }
 
@@ -1,14 +1,16 @@
 //! Cleanup command TUI application
 //!
 //! Interactive file explorer for selecting and deleting session recordings.
-//! Features: multi-select, search, agent filter, glob select, storage preview.
+//! Features: multi-select, search, agent filter, glob select, storage preview,
+//! batch optimize.
 
+use std::path::Path;
 use std::time::Duration;
 
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
@@ -20,9 +22,13 @@ use super::app::list_view::render_explorer_list;
 use super::app::modals;
 use super::app::status_footer::{render_footer_text, render_status_line};
 use super::app::{handle_shared_key, App, KeyResult, SharedMode, SharedState, TuiApp};
+use super::list_app::format_duration;
 use super::widgets::preview::prefetch_adjacent_previews;
 use super::widgets::FileItem;
-use crate::theme::current_theme;
+use crate::asciicast::{apply_transforms_with_progress, OptimizeMode, TransformResult};
+use crate::config::Config;
+use crate::storage::StorageManager;
+use crate::theme::{current_theme, THEME_NAMES};
 
 /// UI mode for the cleanup application
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -34,12 +40,20 @@ pub enum Mode {
     Search,
     /// Agent filter mode - selecting agent to filter by
     AgentFilter,
+    /// Date filter mode - typing a recording date range
+    DateFilter,
+    /// Theme picker mode - choosing a color theme
+    ThemePicker,
     /// Glob select mode - enter pattern to select matching files
     GlobSelect,
+    /// Size select mode - enter a size to select files larger than it
+    SizeSelect,
     /// Help mode - showing keyboard shortcuts
     Help,
     /// Confirm delete mode
     ConfirmDelete,
+    /// Batch optimize summary modal
+    OptimizeSummary,
 }
 
 impl Mode {
@@ -48,9 +62,13 @@ impl Mode {
             Mode::Normal => Some(SharedMode::Normal),
             Mode::Search => Some(SharedMode::Search),
             Mode::AgentFilter => Some(SharedMode::AgentFilter),
+            Mode::DateFilter => Some(SharedMode::DateFilter),
+            Mode::ThemePicker => Some(SharedMode::ThemePicker),
             Mode::Help => Some(SharedMode::Help),
             Mode::ConfirmDelete => Some(SharedMode::ConfirmDelete),
-            Mode::GlobSelect => None, // app-specific
+            Mode::GlobSelect => None,      // app-specific
+            Mode::SizeSelect => None,      // app-specific
+            Mode::OptimizeSummary => None, // app-specific
         }
     }
 
@@ -59,12 +77,77 @@ impl Mode {
             SharedMode::Normal => Mode::Normal,
             SharedMode::Search => Mode::Search,
             SharedMode::AgentFilter => Mode::AgentFilter,
+            SharedMode::DateFilter => Mode::DateFilter,
+            SharedMode::ThemePicker => Mode::ThemePicker,
             SharedMode::Help => Mode::Help,
             SharedMode::ConfirmDelete => Mode::ConfirmDelete,
         }
     }
 }
 
+/// Outcome of optimizing several sessions at once, for the summary modal.
+#[derive(Debug, Clone, Default)]
+pub struct BatchOptimizeState {
+    /// Optimized files with their transform results
+    pub optimized: Vec<(String, TransformResult)>,
+    /// Files that failed, with the error message
+    pub failed: Vec<(String, String)>,
+}
+
+impl BatchOptimizeState {
+    /// Total duration of the optimized files before the transform (seconds).
+    pub fn original_duration(&self) -> f64 {
+        self.optimized
+            .iter()
+            .map(|(_, r)| r.original_duration)
+            .sum()
+    }
+
+    /// Total duration of the optimized files after the transform (seconds).
+    pub fn new_duration(&self) -> f64 {
+        self.optimized.iter().map(|(_, r)| r.new_duration).sum()
+    }
+
+    /// Total time saved across all optimized files (seconds).
+    pub fn time_saved(&self) -> f64 {
+        self.original_duration() - self.new_duration()
+    }
+
+    /// Number of optimized files that got a new backup.
+    ///
+    /// With a single kept backup, files that were already backed up keep
+    /// their original `.bak`.
+    pub fn backups_created(&self) -> usize {
+        self.optimized
+            .iter()
+            .filter(|(_, r)| r.backup_created)
+            .count()
+    }
+
+    /// Percentage of time saved across all optimized files.
+    pub fn percent_saved(&self) -> f64 {
+        let original = self.original_duration();
+        if original > 0.0 {
+            (self.time_saved() / original) * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// One-line summary, e.g. "3 optimized, 1 failed".
+    pub fn summary(&self) -> String {
+        if self.failed.is_empty() {
+            format!("{} optimized", self.optimized.len())
+        } else {
+            format!(
+                "{} optimized, {} failed",
+                self.optimized.len(),
+                self.failed.len()
+            )
+        }
+    }
+}
+
 /// Cleanup application state
 pub struct CleanupApp {
     /// Base app for terminal handling
@@ -75,22 +158,36 @@ pub struct CleanupApp {
     mode: Mode,
     /// Glob pattern input buffer
     glob_input: String,
+    /// Size threshold input buffer
+    size_input: String,
     /// Whether files were deleted (for success message)
     files_deleted: bool,
+    /// Storage manager used to delete recordings with their backups
+    storage: StorageManager,
+    /// Batch optimize result for modal display
+    batch_result: Option<BatchOptimizeState>,
+    /// Backup generations kept per file (`storage.backup_limit`)
+    backup_limit: usize,
 }
 
 impl CleanupApp {
     /// Create a new cleanup application with the given sessions.
-    pub fn new(items: Vec<FileItem>) -> Result<Self> {
+    pub fn new(items: Vec<FileItem>, storage: StorageManager) -> Result<Self> {
         let app = App::new(Duration::from_millis(250))?;
-        let shared = SharedState::new(items);
+        let mut shared = SharedState::new(items);
+        let config = Config::load().unwrap_or_default();
+        shared.load_preferences(&config.tui);
 
         Ok(Self {
             app,
             shared,
             mode: Mode::Normal,
             glob_input: String::new(),
+            size_input: String::new(),
             files_deleted: false,
+            storage,
+            batch_result: None,
+            backup_limit: config.storage.backup_limit,
         })
     }
 
@@ -117,11 +214,21 @@ impl CleanupApp {
                 self.mode = Mode::GlobSelect;
                 self.glob_input.clear();
             }
+            KeyCode::Char('>') => {
+                self.mode = Mode::SizeSelect;
+                self.size_input.clear();
+            }
 
             // Actions
-            KeyCode::Enter => {
+            KeyCode::Enter if self.shared.explorer.selected_count() > 0 => {
+                self.mode = Mode::ConfirmDelete;
+            }
+            KeyCode::Char('o') => {
                 if self.shared.explorer.selected_count() > 0 {
-                    self.mode = Mode::ConfirmDelete;
+                    self.optimize_selected()?;
+                } else {
+                    self.shared.status_message =
+                        Some("Select sessions to optimize first".to_string());
                 }
             }
 
@@ -165,10 +272,47 @@ impl CleanupApp {
             KeyCode::Backspace => {
                 self.glob_input.pop();
             }
-            KeyCode::Char(c) => {
-                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT {
-                    self.glob_input.push(c);
+            KeyCode::Char(c)
+                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT =>
+            {
+                self.glob_input.push(c);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle keys in size select mode.
+    fn handle_size_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                // Select items larger than the entered size
+                if !self.size_input.is_empty() {
+                    self.shared.status_message = Some(match parse_size(&self.size_input) {
+                        Ok(threshold) => {
+                            let added = self.shared.explorer.select_larger_than(threshold);
+                            format!(
+                                "Added {} files over {} ({} selected)",
+                                added,
+                                format_size(threshold),
+                                self.shared.explorer.selected_count()
+                            )
+                        }
+                        Err(e) => format!("Invalid size: {}", e),
+                    });
                 }
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Backspace => {
+                self.size_input.pop();
+            }
+            KeyCode::Char(c)
+                if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT =>
+            {
+                self.size_input.push(c);
             }
             _ => {}
         }
@@ -243,6 +387,70 @@ impl CleanupApp {
         Ok(())
     }
 
+    /// Handle keys in the batch optimize summary modal.
+    fn handle_optimize_summary_key(&mut self, key: KeyEvent) -> Result<()> {
+        if matches!(key.code, KeyCode::Enter | KeyCode::Esc) {
+            if let Some(batch) = self.batch_result.take() {
+                self.shared.status_message = Some(batch.summary());
+            }
+            self.mode = Mode::Normal;
+        }
+        Ok(())
+    }
+
+    /// Optimize all selected sessions, then show a summary modal.
+    ///
+    /// Each file is backed up before its transform runs. A failure on one
+    /// file is recorded and the batch moves on to the next.
+    fn optimize_selected(&mut self) -> Result<()> {
+        let targets: Vec<(String, String)> = self
+            .shared
+            .explorer
+            .selected_items()
+            .iter()
+            .map(|i| (i.path.clone(), i.name.clone()))
+            .collect();
+        let total = targets.len();
+
+        let backup_limit = self.backup_limit;
+        let mut batch = BatchOptimizeState::default();
+        for (idx, (path, name)) in targets.into_iter().enumerate() {
+            // Show progress while the (synchronous) transform runs
+            let mut on_progress = |fraction: f64| {
+                self.shared.status_message = Some(format!(
+                    "Optimizing {}/{}: {} ({:.0}%)",
+                    idx + 1,
+                    total,
+                    name,
+                    fraction * 100.0
+                ));
+                let _ = self.draw();
+            };
+            on_progress(0.0);
+
+            let file_path = Path::new(&path);
+            let result = apply_transforms_with_progress(
+                file_path,
+                OptimizeMode::RemoveSilence,
+                backup_limit,
+                Some(&mut on_progress),
+            );
+            match result {
+                Ok(result) => {
+                    self.shared.preview_cache.invalidate(&path);
+                    self.shared.explorer.update_item_metadata(&path);
+                    batch.optimized.push((name, result));
+                }
+                Err(e) => batch.failed.push((name, e.to_string())),
+            }
+        }
+
+        self.shared.status_message = None;
+        self.batch_result = Some(batch);
+        self.mode = Mode::OptimizeSummary;
+        Ok(())
+    }
+
     /// Delete all selected sessions.
     fn delete_selected(&mut self) -> Result<()> {
         let selected_items = self.shared.explorer.selected_items();
@@ -254,14 +462,12 @@ impl CleanupApp {
         let paths: Vec<String> = selected_items.iter().map(|i| i.path.clone()).collect();
         let count = paths.len();
 
-        // Delete files
+        // Delete files along with their backups and sidecars
         let mut deleted = 0;
         let mut total_freed: u64 = 0;
         for path in &paths {
-            if let Ok(metadata) = std::fs::metadata(path) {
-                total_freed += metadata.len();
-            }
-            if std::fs::remove_file(path).is_ok() {
+            if let Ok(freed) = self.storage.delete_recording(Path::new(path)) {
+                total_freed += freed;
                 deleted += 1;
             }
         }
@@ -292,13 +498,116 @@ impl CleanupApp {
         Ok(())
     }
 
+    /// Render the batch optimize summary modal overlay.
+    ///
+    /// This function is public to allow snapshot testing.
+    pub fn render_batch_optimize_modal(frame: &mut Frame, area: Rect, batch: &BatchOptimizeState) {
+        const MAX_LISTED_FAILURES: usize = 3;
+
+        let theme = current_theme();
+        let listed_failures = batch.failed.len().min(MAX_LISTED_FAILURES);
+        let failure_lines = if batch.failed.is_empty() {
+            0
+        } else {
+            // Blank separator, one line per listed failure, optional "and N more"
+            1 + listed_failures + usize::from(batch.failed.len() > MAX_LISTED_FAILURES)
+        };
+        let modal_area = modals::center_modal(area, 60, 10 + failure_lines as u16);
+
+        // Clear the area behind the modal
+        frame.render_widget(Clear, modal_area);
+
+        let (title, border_color) = if batch.failed.is_empty() {
+            (" Batch Optimize Complete ", theme.success)
+        } else {
+            (" Batch Optimize Finished With Errors ", theme.error)
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                batch.summary(),
+                Style::default()
+                    .fg(theme.text_primary)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Original: ", Style::default().fg(theme.text_secondary)),
+                Span::styled(
+                    format_duration(batch.original_duration()),
+                    Style::default().fg(theme.text_primary),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("New:      ", Style::default().fg(theme.text_secondary)),
+                Span::styled(
+                    format_duration(batch.new_duration()),
+                    Style::default().fg(theme.text_primary),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Saved:    ", Style::default().fg(theme.text_secondary)),
+                Span::styled(
+                    format!(
+                        "{} ({:.0}%)",
+                        format_duration(batch.time_saved()),
+                        batch.percent_saved()
+                    ),
+                    Style::default()
+                        .fg(theme.success)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+        ];
+
+        if !batch.failed.is_empty() {
+            lines.push(Line::from(""));
+            for (name, error) in batch.failed.iter().take(MAX_LISTED_FAILURES) {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{}: ", name), Style::default().fg(theme.error)),
+                    Span::styled(error.clone(), Style::default().fg(theme.text_secondary)),
+                ]));
+            }
+            if batch.failed.len() > MAX_LISTED_FAILURES {
+                lines.push(Line::from(Span::styled(
+                    format!("...and {} more", batch.failed.len() - MAX_LISTED_FAILURES),
+                    Style::default().fg(theme.text_secondary),
+                )));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} new backup(s) kept as .bak files",
+                batch.backups_created()
+            ),
+            Style::default().fg(theme.text_secondary),
+        )));
+        lines.push(Line::from(Span::styled(
+            "Press Enter or Esc to dismiss",
+            Style::default().fg(theme.text_secondary),
+        )));
+
+        let modal = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color))
+                    .title(title),
+            )
+            .alignment(Alignment::Left);
+
+        frame.render_widget(modal, modal_area);
+    }
+
     /// Render the help modal overlay.
     fn render_help_modal(frame: &mut Frame, area: Rect) {
         let theme = current_theme();
 
         // Center the modal
         let modal_width = 65.min(area.width.saturating_sub(4));
-        let modal_height = 20.min(area.height.saturating_sub(4));
+        let modal_height = 29.min(area.height.saturating_sub(4));
         let x = (area.width - modal_width) / 2;
         let y = (area.height - modal_height) / 2;
         let modal_area = Rect::new(x, y, modal_width, modal_height);
@@ -330,6 +639,10 @@ impl CleanupApp {
                 Span::styled("  Home/End", Style::default().fg(theme.accent)),
                 Span::raw("       Go to first/last"),
             ]),
+            Line::from(vec![
+                Span::styled("  Ctrl+u/d", Style::default().fg(theme.accent)),
+                Span::raw("       Scroll preview"),
+            ]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Selection",
@@ -347,6 +660,10 @@ impl CleanupApp {
                 Span::styled("  g", Style::default().fg(theme.accent)),
                 Span::raw("              Glob select (e.g., *2024*, claude/*.cast)"),
             ]),
+            Line::from(vec![
+                Span::styled("  >", Style::default().fg(theme.accent)),
+                Span::raw("              Select files larger than a size (e.g., 10M)"),
+            ]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Filtering",
@@ -360,7 +677,19 @@ impl CleanupApp {
                 Span::styled("  f", Style::default().fg(theme.accent)),
                 Span::raw("              Filter by agent"),
             ]),
+            Line::from(vec![
+                Span::styled("  D", Style::default().fg(theme.accent)),
+                Span::raw("              Filter by date (e.g., ..2024-01-01)"),
+            ]),
+            Line::from(vec![
+                Span::styled("  s/S", Style::default().fg(theme.accent)),
+                Span::raw("            Cycle sort field / Reverse order"),
+            ]),
             Line::from(""),
+            Line::from(vec![
+                Span::styled("  o", Style::default().fg(theme.accent)),
+                Span::raw("              Optimize selected (removes silence)"),
+            ]),
             Line::from(vec![
                 Span::styled("  Enter", Style::default().fg(theme.error)),
                 Span::raw("          Delete selected (with confirmation)"),
@@ -369,6 +698,10 @@ impl CleanupApp {
                 Span::styled("  Esc", Style::default().fg(theme.accent)),
                 Span::raw("            Clear selection / Clear filters"),
             ]),
+            Line::from(vec![
+                Span::styled("  T", Style::default().fg(theme.accent)),
+                Span::raw("              Choose theme"),
+            ]),
             Line::from(vec![
                 Span::styled("  q", Style::default().fg(theme.accent)),
                 Span::raw("              Quit without deleting"),
@@ -419,7 +752,9 @@ impl TuiApp for CleanupApp {
         match self.mode {
             Mode::Normal => self.handle_normal_key(key)?,
             Mode::GlobSelect => self.handle_glob_key(key)?,
+            Mode::SizeSelect => self.handle_size_key(key)?,
             Mode::ConfirmDelete => self.handle_confirm_delete_key(key)?,
+            Mode::OptimizeSummary => self.handle_optimize_summary_key(key)?,
             _ => {}
         }
         Ok(())
@@ -437,10 +772,15 @@ impl TuiApp for CleanupApp {
         prefetch_adjacent_previews(&self.shared.explorer, &mut self.shared.preview_cache);
 
         // Extract shared fields into local variables before closure
+        let preview_scroll = self.shared.preview_scroll_offset();
         let explorer = &mut self.shared.explorer;
         let mode = self.mode;
         let search_input = &self.shared.search_input;
+        let date_input = &self.shared.date_input;
+        let theme_picker_idx = self.shared.theme_picker_idx;
         let glob_input = &self.glob_input;
+        let size_input = &self.size_input;
+        let batch_result = self.batch_result.clone();
         let status = self.shared.status_message.clone();
         let agent_filter_idx = self.shared.agent_filter_idx;
         let available_agents = &self.shared.available_agents;
@@ -449,6 +789,17 @@ impl TuiApp for CleanupApp {
         let selected_size: u64 = explorer.selected_items().iter().map(|i| i.size).sum();
         let selected_count = explorer.selected_count();
 
+        // The confirm modal shows what will really be freed, backups and sidecars included
+        let delete_size: u64 = if mode == Mode::ConfirmDelete {
+            explorer
+                .selected_items()
+                .iter()
+                .map(|i| StorageManager::recording_footprint(Path::new(&i.path)))
+                .sum()
+        } else {
+            0
+        };
+
         // Get preview for current selection from cache
         let current_path = explorer.selected_item().map(|i| i.path.clone());
         let preview = current_path
@@ -462,7 +813,15 @@ impl TuiApp for CleanupApp {
             let chunks = build_explorer_layout(area);
 
             // Render file explorer with checkboxes (cleanup uses multi-select)
-            render_explorer_list(frame, chunks[0], explorer, preview, true, false);
+            render_explorer_list(
+                frame,
+                chunks[0],
+                explorer,
+                preview,
+                preview_scroll,
+                true,
+                false,
+            );
 
             // Render status line
             let status_text = if let Some(msg) = &status {
@@ -471,6 +830,10 @@ impl TuiApp for CleanupApp {
                 match mode {
                     Mode::Search => format!("Search: {}_", search_input),
                     Mode::GlobSelect => format!("Glob pattern: {}_", glob_input),
+                    Mode::SizeSelect => format!("Select files larger than: {}_", size_input),
+                    Mode::DateFilter => {
+                        format!("Date range (YYYY-MM-DD..YYYY-MM-DD): {}_", date_input)
+                    }
                     Mode::AgentFilter => {
                         let agent = &available_agents[agent_filter_idx];
                         format!(
@@ -480,6 +843,8 @@ impl TuiApp for CleanupApp {
                     }
                     Mode::ConfirmDelete => String::new(), // Modal shows this
                     Mode::Help => String::new(),
+                    Mode::ThemePicker => "Choose a theme".to_string(),
+                    Mode::OptimizeSummary => String::new(), // Modal shows this
                     Mode::Normal => {
                         // Show selection info
                         if selected_count > 0 {
@@ -497,15 +862,15 @@ impl TuiApp for CleanupApp {
                             if let Some(agent) = explorer.agent_filter() {
                                 parts.push(format!("agent: {}", agent));
                             }
-                            if parts.is_empty() {
-                                format!("{} sessions | Space to select", explorer.len())
-                            } else {
-                                format!(
-                                    "{} sessions ({}) | Space to select",
-                                    explorer.len(),
-                                    parts.join(", ")
-                                )
+                            if let Some(range) = explorer.date_filter() {
+                                parts.push(format!("date: {}", range));
                             }
+                            parts.push(format!("sorted: {}", explorer.sort_label()));
+                            format!(
+                                "{} sessions ({}) | Space to select",
+                                explorer.len(),
+                                parts.join(", ")
+                            )
                         }
                     }
                 }
@@ -516,12 +881,16 @@ impl TuiApp for CleanupApp {
             let footer_text = match mode {
                 Mode::Search => "Esc: cancel | Enter: apply | Backspace: delete",
                 Mode::GlobSelect => "Esc: cancel | Enter: select matching | Backspace: delete",
+                Mode::SizeSelect => "e.g. 500K, 10M, 2G | Enter: select larger | Esc: cancel",
                 Mode::AgentFilter => "left/right: change | Enter: apply | Esc: cancel",
+                Mode::DateFilter => "Esc: cancel | Enter: apply (empty clears) | Backspace: delete",
                 Mode::ConfirmDelete => "y: confirm | n/Esc: cancel",
                 Mode::Help => "Press any key to close",
+                Mode::ThemePicker => "up/down: choose theme | Enter: apply | Esc: cancel",
+                Mode::OptimizeSummary => "Enter/Esc: dismiss",
                 Mode::Normal => {
                     if selected_count > 0 {
-                        "Space: toggle | a: toggle all | Enter: delete selected | Esc: clear | ?: help"
+                        "Space: toggle | a: toggle all | o: optimize | Enter: delete selected | Esc: clear | ?: help"
                     } else {
                         "Space: select | a: all | g: glob | /: search | f: filter | ?: help | q: quit"
                     }
@@ -537,9 +906,17 @@ impl TuiApp for CleanupApp {
                         frame,
                         area,
                         selected_count,
-                        selected_size,
+                        delete_size,
                     );
                 }
+                Mode::OptimizeSummary => {
+                    if let Some(ref batch) = batch_result {
+                        Self::render_batch_optimize_modal(frame, area, batch);
+                    }
+                }
+                Mode::ThemePicker => {
+                    modals::render_theme_picker_modal(frame, area, THEME_NAMES, theme_picker_idx);
+                }
                 _ => {}
             }
         })?;
@@ -605,6 +982,34 @@ fn glob_match_recursive(text: &str, pattern: &str) -> bool {
     text_chars.next().is_none()
 }
 
+/// Parse a size like `500K`, `10M`, `2G` or `1.5GiB` into bytes.
+///
+/// Units are binary multiples, matching how `humansize::BINARY` displays
+/// sizes in the TUI. A bare number is bytes; unit letters are case-insensitive
+/// and may be followed by `B` or `iB`.
+fn parse_size(input: &str) -> std::result::Result<u64, String> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+
+    let value: f64 = number
+        .parse()
+        .map_err(|_| format!("`{}` is not a size (e.g. 500K, 10M, 2G)", input))?;
+
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        "t" | "tb" | "tib" => 1 << 40,
+        other => return Err(format!("unknown size unit `{}`", other)),
+    };
+
+    Ok((value * multiplier as f64) as u64)
+}
+
 /// Format a byte size as human-readable string.
 fn format_size(bytes: u64) -> String {
     humansize::format_size(bytes, humansize::BINARY)
@@ -649,6 +1054,105 @@ mod tests {
         assert!(debug.contains("GlobSelect"));
     }
 
+    // Batch optimize summary tests
+
+    fn transform_result(original_duration: f64, new_duration: f64) -> TransformResult {
+        TransformResult {
+            original_duration,
+            new_duration,
+            backup_path: None,
+            backup_created: true,
+            gaps_capped: 0,
+        }
+    }
+
+    #[test]
+    fn batch_counts_backups_created() {
+        let mut existing_backup = transform_result(10.0, 5.0);
+        existing_backup.backup_created = false;
+        let batch = BatchOptimizeState {
+            optimized: vec![
+                ("a".to_string(), transform_result(10.0, 5.0)),
+                ("b".to_string(), existing_backup),
+            ],
+            failed: Vec::new(),
+        };
+        assert_eq!(batch.backups_created(), 1);
+    }
+
+    #[test]
+    fn batch_summary_counts_optimized_and_failed() {
+        let mut batch = BatchOptimizeState::default();
+        for name in ["a", "b", "c"] {
+            batch
+                .optimized
+                .push((name.to_string(), transform_result(10.0, 5.0)));
+        }
+        assert_eq!(batch.summary(), "3 optimized");
+
+        batch
+            .failed
+            .push(("d".to_string(), "parse error".to_string()));
+        assert_eq!(batch.summary(), "3 optimized, 1 failed");
+    }
+
+    #[test]
+    fn batch_aggregates_time_saved() {
+        let batch = BatchOptimizeState {
+            optimized: vec![
+                ("a".to_string(), transform_result(600.0, 200.0)),
+                ("b".to_string(), transform_result(200.0, 200.0)),
+            ],
+            failed: vec![("c".to_string(), "boom".to_string())],
+        };
+        assert_eq!(batch.original_duration(), 800.0);
+        assert_eq!(batch.new_duration(), 400.0);
+        assert_eq!(batch.time_saved(), 400.0);
+        assert_eq!(batch.percent_saved(), 50.0);
+    }
+
+    #[test]
+    fn batch_percent_saved_handles_empty_batch() {
+        let batch = BatchOptimizeState::default();
+        assert_eq!(batch.percent_saved(), 0.0);
+        assert_eq!(batch.summary(), "0 optimized");
+    }
+
+    // Size parsing tests
+
+    #[test]
+    fn parse_size_kilobytes() {
+        assert_eq!(parse_size("500K"), Ok(500 * 1024));
+        assert_eq!(parse_size("500kb"), Ok(500 * 1024));
+        assert_eq!(parse_size("500 KiB"), Ok(500 * 1024));
+    }
+
+    #[test]
+    fn parse_size_gigabytes() {
+        assert_eq!(parse_size("2G"), Ok(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("1.5g"), Ok(1536 * 1024 * 1024));
+    }
+
+    #[test]
+    fn parse_size_plain_bytes() {
+        assert_eq!(parse_size("1024"), Ok(1024));
+        assert_eq!(parse_size(" 10M "), Ok(10 * 1024 * 1024));
+    }
+
+    #[test]
+    fn parse_size_invalid_input() {
+        assert!(parse_size("").is_err());
+        assert!(parse_size("M").is_err());
+        assert!(parse_size("10X").is_err());
+        assert!(parse_size("ten megs").is_err());
+        assert!(parse_size("1.2.3M").is_err());
+    }
+
+    #[test]
+    fn parse_size_threshold_matches_humansize_display() {
+        assert_eq!(format_size(parse_size("10M").unwrap()), "10 MiB");
+    }
+
     // Glob matching tests
 
     #[test]
@@ -21,11 +21,17 @@ use super::app::list_view::render_explorer_list;
 use super::app::modals;
 use super::app::status_footer::{render_footer_text, render_status_line};
 use super::app::{handle_shared_key, App, KeyResult, SharedMode, SharedState, TuiApp};
+use super::subprocess::{run_cancellable, ChildOutcome};
 use super::widgets::preview::prefetch_adjacent_previews;
 use super::widgets::FileItem;
-use crate::asciicast::{apply_transforms, TransformResult};
-use crate::files::backup::{backup_path_for, create_backup, has_backup, restore_from_backup};
-use crate::theme::current_theme;
+use crate::asciicast::{
+    apply_transforms_with_progress, parse_marker_timestamp, AsciicastFile, MarkerManager,
+    OptimizeMode, TransformResult, DEFAULT_MAX_IDLE_GAP,
+};
+use crate::config::Config;
+use crate::files::backup::{backup_paths, create_backup, has_backup, restore_from_backup};
+use crate::player::render::{set_color_depth, ColorDepth};
+use crate::theme::{current_theme, THEME_NAMES};
 
 /// UI mode for the list application
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -37,6 +43,10 @@ pub enum Mode {
     Search,
     /// Agent filter mode - selecting agent to filter by
     AgentFilter,
+    /// Date filter mode - typing a recording date range
+    DateFilter,
+    /// Theme picker mode - choosing a color theme
+    ThemePicker,
     /// Help mode - showing keyboard shortcuts
     Help,
     /// Confirm delete mode
@@ -45,6 +55,8 @@ pub enum Mode {
     ContextMenu,
     /// Optimize result mode - showing optimization results or error
     OptimizeResult,
+    /// Add marker mode - typing a timestamp and label for a new marker
+    AddMarker,
 }
 
 impl Mode {
@@ -53,9 +65,11 @@ impl Mode {
             Mode::Normal => Some(SharedMode::Normal),
             Mode::Search => Some(SharedMode::Search),
             Mode::AgentFilter => Some(SharedMode::AgentFilter),
+            Mode::DateFilter => Some(SharedMode::DateFilter),
+            Mode::ThemePicker => Some(SharedMode::ThemePicker),
             Mode::Help => Some(SharedMode::Help),
             Mode::ConfirmDelete => Some(SharedMode::ConfirmDelete),
-            Mode::ContextMenu | Mode::OptimizeResult => None,
+            Mode::ContextMenu | Mode::OptimizeResult | Mode::AddMarker => None,
         }
     }
 
@@ -64,6 +78,8 @@ impl Mode {
             SharedMode::Normal => Mode::Normal,
             SharedMode::Search => Mode::Search,
             SharedMode::AgentFilter => Mode::AgentFilter,
+            SharedMode::DateFilter => Mode::DateFilter,
+            SharedMode::ThemePicker => Mode::ThemePicker,
             SharedMode::Help => Mode::Help,
             SharedMode::ConfirmDelete => Mode::ConfirmDelete,
         }
@@ -75,7 +91,9 @@ impl Mode {
 pub enum ContextMenuItem {
     Play,
     Copy,
+    CopyTranscript,
     Optimize,
+    CapIdle,
     Analyze,
     Restore,
     Delete,
@@ -84,10 +102,12 @@ pub enum ContextMenuItem {
 
 impl ContextMenuItem {
     /// All menu items in display order
-    pub const ALL: [ContextMenuItem; 7] = [
+    pub const ALL: [ContextMenuItem; 9] = [
         ContextMenuItem::Play,
         ContextMenuItem::Copy,
+        ContextMenuItem::CopyTranscript,
         ContextMenuItem::Optimize,
+        ContextMenuItem::CapIdle,
         ContextMenuItem::Analyze,
         ContextMenuItem::Restore,
         ContextMenuItem::Delete,
@@ -99,7 +119,9 @@ impl ContextMenuItem {
         match self {
             ContextMenuItem::Play => "Play",
             ContextMenuItem::Copy => "Copy to clipboard",
+            ContextMenuItem::CopyTranscript => "Copy transcript",
             ContextMenuItem::Optimize => "Optimize",
+            ContextMenuItem::CapIdle => "Cap idle time",
             ContextMenuItem::Analyze => "Analyze",
             ContextMenuItem::Restore => "Restore from backup",
             ContextMenuItem::Delete => "Delete",
@@ -112,7 +134,9 @@ impl ContextMenuItem {
         match self {
             ContextMenuItem::Play => "p",
             ContextMenuItem::Copy => "c",
+            ContextMenuItem::CopyTranscript => "x",
             ContextMenuItem::Optimize => "t",
+            ContextMenuItem::CapIdle => "i",
             ContextMenuItem::Analyze => "a",
             ContextMenuItem::Restore => "r",
             ContextMenuItem::Delete => "d",
@@ -142,13 +166,21 @@ pub struct ListApp {
     context_menu_idx: usize,
     /// Optimize result for modal display
     optimize_result: Option<OptimizeResultState>,
+    /// Add marker input buffer (`<timestamp> <label>`)
+    marker_input: String,
+    /// Backup generations kept per file (`storage.backup_limit`)
+    backup_limit: usize,
 }
 
 impl ListApp {
     /// Create a new list application with the given sessions.
     pub fn new(items: Vec<FileItem>) -> Result<Self> {
         let app = App::new(Duration::from_millis(250))?;
-        let shared = SharedState::new(items);
+        let mut shared = SharedState::new(items);
+        let config = Config::load().unwrap_or_default();
+        shared.load_preferences(&config.tui);
+        // Sessions played from the list use the same color depth as `agr play`
+        set_color_depth(ColorDepth::detect_from_env(&config.player.color_depth));
 
         Ok(Self {
             app,
@@ -156,6 +188,8 @@ impl ListApp {
             mode: Mode::Normal,
             context_menu_idx: 0,
             optimize_result: None,
+            marker_input: String::new(),
+            backup_limit: config.storage.backup_limit,
         })
     }
 
@@ -176,22 +210,20 @@ impl ListApp {
     fn handle_normal_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             // Actions
-            KeyCode::Enter => {
-                if self.shared.explorer.selected_item().is_some() {
-                    self.context_menu_idx = 0; // Reset to first item (Play)
-                    self.mode = Mode::ContextMenu;
-                }
+            KeyCode::Enter if self.shared.explorer.selected_item().is_some() => {
+                self.context_menu_idx = 0; // Reset to first item (Play)
+                self.mode = Mode::ContextMenu;
             }
 
             // Direct shortcuts (bypass context menu)
             KeyCode::Char('p') => self.play_session()?,
             KeyCode::Char('c') => self.copy_to_clipboard()?,
+            KeyCode::Char('x') => self.copy_transcript()?,
             KeyCode::Char('t') => self.optimize_session()?,
+            KeyCode::Char('i') => self.cap_idle_session()?,
             KeyCode::Char('a') => self.analyze_session()?,
-            KeyCode::Char('d') => {
-                if self.shared.explorer.selected_item().is_some() {
-                    self.mode = Mode::ConfirmDelete;
-                }
+            KeyCode::Char('d') if self.shared.explorer.selected_item().is_some() => {
+                self.mode = Mode::ConfirmDelete;
             }
             KeyCode::Char('m') => self.add_marker()?,
 
@@ -258,6 +290,13 @@ impl ListApp {
                     .unwrap_or(0);
                 self.execute_context_menu_action()?;
             }
+            KeyCode::Char('x') => {
+                self.context_menu_idx = ContextMenuItem::ALL
+                    .iter()
+                    .position(|i| matches!(i, ContextMenuItem::CopyTranscript))
+                    .unwrap_or(0);
+                self.execute_context_menu_action()?;
+            }
             KeyCode::Char('t') => {
                 self.context_menu_idx = ContextMenuItem::ALL
                     .iter()
@@ -265,6 +304,13 @@ impl ListApp {
                     .unwrap_or(0);
                 self.execute_context_menu_action()?;
             }
+            KeyCode::Char('i') => {
+                self.context_menu_idx = ContextMenuItem::ALL
+                    .iter()
+                    .position(|i| matches!(i, ContextMenuItem::CapIdle))
+                    .unwrap_or(0);
+                self.execute_context_menu_action()?;
+            }
             KeyCode::Char('a') => {
                 self.context_menu_idx = ContextMenuItem::ALL
                     .iter()
@@ -304,6 +350,29 @@ impl ListApp {
         Ok(())
     }
 
+    /// Handle keys in add marker mode.
+    fn handle_add_marker_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.marker_input.clear();
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                self.submit_marker();
+                self.marker_input.clear();
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Backspace => {
+                self.marker_input.pop();
+            }
+            KeyCode::Char(c) => {
+                self.marker_input.push(c);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     /// Handle keys in optimize result mode.
     fn handle_optimize_result_key(&mut self, key: KeyEvent) -> Result<()> {
         // Enter or Esc dismisses the modal
@@ -336,7 +405,9 @@ impl ListApp {
         match action {
             ContextMenuItem::Play => self.play_session()?,
             ContextMenuItem::Copy => self.copy_to_clipboard()?,
+            ContextMenuItem::CopyTranscript => self.copy_transcript()?,
             ContextMenuItem::Optimize => self.optimize_session()?,
+            ContextMenuItem::CapIdle => self.cap_idle_session()?,
             ContextMenuItem::Analyze => self.analyze_session()?,
             ContextMenuItem::Restore => self.restore_session()?,
             ContextMenuItem::Delete => {
@@ -394,6 +465,33 @@ impl ListApp {
         Ok(())
     }
 
+    /// Copy the selected session's rendered text transcript to the clipboard.
+    fn copy_transcript(&mut self) -> Result<()> {
+        use crate::clipboard::copy_text_to_clipboard;
+
+        if let Some(item) = self.shared.explorer.selected_item() {
+            let transcript = match AsciicastFile::parse(&item.path) {
+                Ok(cast) => cast.to_transcript(),
+                Err(e) => {
+                    self.shared.status_message = Some(format!("Copy failed: {}", e));
+                    return Ok(());
+                }
+            };
+
+            match copy_text_to_clipboard(&transcript) {
+                Ok(_) => {
+                    let lines = transcript.lines().count();
+                    self.shared.status_message =
+                        Some(format!("Copied transcript ({} lines)", lines));
+                }
+                Err(e) => {
+                    self.shared.status_message = Some(format!("Copy failed: {}", e));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Delete the selected session.
     fn delete_session(&mut self) -> Result<()> {
         if let Some(item) = self.shared.explorer.selected_item() {
@@ -404,9 +502,12 @@ impl ListApp {
             if let Err(e) = std::fs::remove_file(&path) {
                 self.shared.status_message = Some(format!("Failed to delete: {}", e));
             } else {
-                // Also delete backup if it exists (remove_file returns Err if not found)
-                let backup = backup_path_for(std::path::Path::new(&path));
-                let backup_deleted = std::fs::remove_file(&backup).is_ok();
+                // Also delete any backups (remove_file returns Err if not found)
+                let backup_deleted = backup_paths(std::path::Path::new(&path))
+                    .iter()
+                    .filter(|backup| std::fs::remove_file(backup).is_ok())
+                    .count()
+                    > 0;
 
                 // Remove from explorer to keep UI in sync
                 self.shared.explorer.remove_item(&path);
@@ -448,13 +549,34 @@ impl ListApp {
 
     /// Optimize the selected session (apply silence removal).
     fn optimize_session(&mut self) -> Result<()> {
+        self.run_optimize(OptimizeMode::RemoveSilence)
+    }
+
+    /// Cap idle gaps in the selected session at the default maximum.
+    fn cap_idle_session(&mut self) -> Result<()> {
+        self.run_optimize(OptimizeMode::CapIdleTime {
+            max_gap: DEFAULT_MAX_IDLE_GAP,
+        })
+    }
+
+    /// Run an optimize pipeline on the selected session and show the result modal.
+    fn run_optimize(&mut self, mode: OptimizeMode) -> Result<()> {
         if let Some(item) = self.shared.explorer.selected_item() {
-            let path = std::path::Path::new(&item.path);
             let name = item.name.clone();
             let path_str = item.path.clone();
+            let path = std::path::Path::new(&path_str);
+            let backup_limit = self.backup_limit;
 
-            // Apply transforms and store result for modal display
-            let result = match apply_transforms(path) {
+            // Apply transforms, showing progress, and store result for modal display
+            let mut on_progress = |fraction: f64| {
+                self.shared.status_message =
+                    Some(format!("Optimizing {}... {:.0}%", name, fraction * 100.0));
+                let _ = self.draw();
+            };
+            let result =
+                apply_transforms_with_progress(path, mode, backup_limit, Some(&mut on_progress));
+            self.shared.status_message = None;
+            let result = match result {
                 Ok(result) => {
                     // Invalidate the preview cache for this file
                     self.shared.preview_cache.invalidate(&path_str);
@@ -482,7 +604,7 @@ impl ListApp {
 
             // Create backup before analysis
             let file_path = std::path::Path::new(&path);
-            if let Err(e) = create_backup(file_path) {
+            if let Err(e) = create_backup(file_path, self.backup_limit) {
                 self.shared.status_message =
                     Some(format!("ERROR: Backup failed for {}: {}", path, e));
                 return Ok(());
@@ -491,67 +613,37 @@ impl ListApp {
             // Suspend TUI - restores normal terminal mode
             self.app.suspend()?;
 
-            // Run the analyze subcommand (--wait pauses before returning to TUI)
-            let status = std::process::Command::new(std::env::current_exe()?)
-                .args(["analyze", &path, "--wait"])
-                .status();
+            // Run the analyze subcommand (--wait pauses before returning to TUI).
+            // Ctrl-C interrupts it and brings the TUI back.
+            let outcome = run_cancellable(
+                std::process::Command::new(std::env::current_exe()?)
+                    .args(["analyze", &path, "--wait"]),
+            );
 
             // Resume TUI - re-enters alternate screen and raw mode
             self.app.resume()?;
 
-            match status {
-                Ok(s) if s.success() => {
-                    // Check if the original file was renamed by the analyze command
-                    if !file_path.exists() {
-                        // File was renamed — find the newest .cast file in the same directory
-                        // (the renamed file will have the most recent mtime)
-                        let new_file = file_path.parent().and_then(|parent| {
-                            std::fs::read_dir(parent).ok().and_then(|entries| {
-                                entries
-                                    .flatten()
-                                    .filter(|e| {
-                                        e.path().extension().and_then(|ext| ext.to_str())
-                                            == Some("cast")
-                                    })
-                                    .max_by_key(|e| {
-                                        e.metadata()
-                                            .and_then(|m| m.modified())
-                                            .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
-                                    })
-                                    .map(|e| e.path())
-                            })
-                        });
-
-                        if let Some(new_path) = new_file {
-                            let new_path_str = new_path.to_string_lossy().to_string();
-                            self.shared.preview_cache.invalidate(&new_path_str);
-                            self.shared.explorer.update_item_path(&path, &new_path_str);
-                            self.shared.status_message = Some(format!(
-                                "Analysis complete (renamed to {})",
-                                new_path
-                                    .file_name()
-                                    .and_then(|n| n.to_str())
-                                    .unwrap_or("unknown")
-                            ));
-                        } else {
-                            // Couldn't find any .cast file — remove the stale item
-                            self.shared.explorer.remove_item(&path);
-                            self.shared.status_message =
-                                Some("Analysis complete (file was renamed)".to_string());
-                        }
-                    } else {
-                        // File still exists at original path — just invalidate cache
-                        self.shared.preview_cache.invalidate(&path);
-                        self.shared.explorer.update_item_metadata(&path);
-                        self.shared.status_message = Some("Analysis complete".to_string());
-                    }
+            match outcome {
+                Ok(ChildOutcome::Exited(s)) if s.success() => {
+                    self.shared.status_message = Some(match self.refresh_analyzed_item(&path) {
+                        Some(note) => format!("Analysis complete ({})", note),
+                        None => "Analysis complete".to_string(),
+                    });
                 }
-                Ok(s) => {
+                Ok(ChildOutcome::Exited(s)) => {
                     self.shared.status_message = Some(format!(
                         "Analyze exited with code {}",
                         s.code().unwrap_or(-1)
                     ));
                 }
+                Ok(ChildOutcome::Cancelled) => {
+                    // Analyze may have written markers or renamed the file
+                    // before it was interrupted; the backup is still in place.
+                    self.shared.status_message = Some(match self.refresh_analyzed_item(&path) {
+                        Some(note) => format!("Analysis cancelled ({})", note),
+                        None => "Analysis cancelled".to_string(),
+                    });
+                }
                 Err(e) => {
                     self.shared.status_message = Some(format!("Failed to run analyze: {}", e));
                 }
@@ -560,12 +652,99 @@ impl ListApp {
         Ok(())
     }
 
-    /// Add a marker to the selected session (placeholder).
+    /// Refresh the list entry for `path` after the analyze command ran on it.
+    ///
+    /// Returns a note for the status message if the file was renamed.
+    fn refresh_analyzed_item(&mut self, path: &String) -> Option<String> {
+        let file_path = Path::new(path);
+
+        // File still exists at original path — just invalidate cache
+        if file_path.exists() {
+            self.shared.preview_cache.invalidate(path);
+            self.shared.explorer.update_item_metadata(path);
+            return None;
+        }
+
+        // File was renamed — find the newest .cast file in the same directory
+        // (the renamed file will have the most recent mtime)
+        let new_file = file_path.parent().and_then(|parent| {
+            std::fs::read_dir(parent).ok().and_then(|entries| {
+                entries
+                    .flatten()
+                    .filter(|e| e.path().extension().and_then(|ext| ext.to_str()) == Some("cast"))
+                    .max_by_key(|e| {
+                        e.metadata()
+                            .and_then(|m| m.modified())
+                            .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+                    })
+                    .map(|e| e.path())
+            })
+        });
+
+        if let Some(new_path) = new_file {
+            let new_path_str = new_path.to_string_lossy().to_string();
+            self.shared.preview_cache.invalidate(&new_path_str);
+            self.shared.explorer.update_item_path(path, &new_path_str);
+            Some(format!(
+                "renamed to {}",
+                new_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("unknown")
+            ))
+        } else {
+            // Couldn't find any .cast file — remove the stale item
+            self.shared.explorer.remove_item(path);
+            Some("file was renamed".to_string())
+        }
+    }
+
+    /// Open the add marker prompt for the selected session.
     fn add_marker(&mut self) -> Result<()> {
-        self.shared.status_message = Some("Marker feature coming soon!".to_string());
+        if self.shared.explorer.selected_item().is_some() {
+            self.marker_input.clear();
+            self.shared.status_message = None;
+            self.mode = Mode::AddMarker;
+        }
         Ok(())
     }
 
+    /// Insert the marker typed into the prompt into the selected session.
+    fn submit_marker(&mut self) {
+        let Some(item) = self.shared.explorer.selected_item() else {
+            return;
+        };
+        let path_str = item.path.clone();
+        let name = item.name.clone();
+
+        let (timestamp, label) = match parse_marker_input(&self.marker_input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.shared.status_message = Some(format!("Invalid marker: {}", e));
+                return;
+            }
+        };
+
+        match MarkerManager::add_marker(Path::new(&path_str), timestamp, &label, self.backup_limit)
+        {
+            Ok(()) => {
+                // Invalidate the preview cache for this file
+                self.shared.preview_cache.invalidate(&path_str);
+                // Refresh file metadata in explorer
+                self.shared.explorer.update_item_metadata(&path_str);
+                self.shared.status_message = Some(format!(
+                    "Added marker \"{}\" at {} to {}",
+                    label,
+                    format_duration(timestamp),
+                    name
+                ));
+            }
+            Err(e) => {
+                self.shared.status_message = Some(format!("Failed to add marker: {}", e));
+            }
+        }
+    }
+
     /// Render the help modal overlay.
     /// Public for snapshot testing.
     pub fn render_help_modal(frame: &mut Frame, area: Rect) {
@@ -573,7 +752,7 @@ impl ListApp {
 
         // Center the modal
         let modal_width = 60.min(area.width.saturating_sub(4));
-        let modal_height = 28.min(area.height.saturating_sub(4)); // Updated: added a for analyze
+        let modal_height = 32.min(area.height.saturating_sub(4));
         let x = (area.width - modal_width) / 2;
         let y = (area.height - modal_height) / 2;
         let modal_area = Rect::new(x, y, modal_width, modal_height);
@@ -606,6 +785,10 @@ impl ListApp {
                 Span::styled("  Home/End", Style::default().fg(theme.accent)),
                 Span::raw("   First/last"),
             ]),
+            Line::from(vec![
+                Span::styled("  Ctrl+u/d", Style::default().fg(theme.accent)),
+                Span::raw("   Scroll preview"),
+            ]),
             Line::from(""),
             // Actions section
             Line::from(Span::styled(
@@ -624,10 +807,18 @@ impl ListApp {
                 Span::styled("  c", Style::default().fg(theme.accent)),
                 Span::raw("           Copy to clipboard"),
             ]),
+            Line::from(vec![
+                Span::styled("  x", Style::default().fg(theme.accent)),
+                Span::raw("           Copy transcript"),
+            ]),
             Line::from(vec![
                 Span::styled("  t", Style::default().fg(theme.accent)),
                 Span::raw("           Optimize (removes silence)"),
             ]),
+            Line::from(vec![
+                Span::styled("  i", Style::default().fg(theme.accent)),
+                Span::raw("           Cap idle time"),
+            ]),
             Line::from(vec![
                 Span::styled("  a", Style::default().fg(theme.accent)),
                 Span::raw("           Analyze session"),
@@ -650,12 +841,24 @@ impl ListApp {
                 Span::styled("  f", Style::default().fg(theme.accent)),
                 Span::raw("           Filter by agent"),
             ]),
+            Line::from(vec![
+                Span::styled("  D", Style::default().fg(theme.accent)),
+                Span::raw("           Filter by date range"),
+            ]),
+            Line::from(vec![
+                Span::styled("  s/S", Style::default().fg(theme.accent)),
+                Span::raw("         Cycle sort field / Reverse order"),
+            ]),
             Line::from(vec![
                 Span::styled("  Esc", Style::default().fg(theme.accent)),
                 Span::raw("         Clear filters"),
             ]),
             Line::from(""),
             // Other section
+            Line::from(vec![
+                Span::styled("  T", Style::default().fg(theme.accent)),
+                Span::raw("           Choose theme"),
+            ]),
             Line::from(vec![
                 Span::styled("  ?", Style::default().fg(theme.accent)),
                 Span::raw("           This help"),
@@ -782,8 +985,13 @@ impl ListApp {
 
         // Determine modal size based on success or error
         let is_success = result_state.result.is_ok();
+        let shows_gaps = matches!(&result_state.result, Ok(r) if r.gaps_capped > 0);
         let modal_width = 55.min(area.width.saturating_sub(4));
-        let modal_height = if is_success { 10 } else { 8 };
+        let modal_height = match (is_success, shows_gaps) {
+            (true, true) => 11,
+            (true, false) => 10,
+            _ => 8,
+        };
         let modal_height = modal_height.min(area.height.saturating_sub(4));
 
         // Center the modal
@@ -800,7 +1008,7 @@ impl ListApp {
                 let title = " Optimization Complete ";
                 let border_color = theme.success;
 
-                let lines = vec![
+                let mut lines = vec![
                     Line::from(Span::styled(
                         format!("File: {}", result_state.filename),
                         Style::default().fg(theme.text_primary),
@@ -833,19 +1041,32 @@ impl ListApp {
                                 .add_modifier(Modifier::BOLD),
                         ),
                     ]),
-                    Line::from(""),
-                    Line::from(vec![
-                        Span::styled("Backup: ", Style::default().fg(theme.text_secondary)),
+                ];
+                if result.gaps_capped > 0 {
+                    lines.push(Line::from(vec![
+                        Span::styled("Capped:   ", Style::default().fg(theme.text_secondary)),
                         Span::styled(
-                            if result.backup_created {
-                                "Created"
-                            } else {
-                                "Using existing"
-                            },
+                            format!(
+                                "{} gap{}",
+                                result.gaps_capped,
+                                if result.gaps_capped == 1 { "" } else { "s" }
+                            ),
                             Style::default().fg(theme.text_primary),
                         ),
-                    ]),
-                ];
+                    ]));
+                }
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![
+                    Span::styled("Backup: ", Style::default().fg(theme.text_secondary)),
+                    Span::styled(
+                        if result.backup_created {
+                            "Created"
+                        } else {
+                            "Using existing"
+                        },
+                        Style::default().fg(theme.text_primary),
+                    ),
+                ]));
                 (title, border_color, lines)
             }
             Err(error) => {
@@ -914,6 +1135,7 @@ impl TuiApp for ListApp {
             Mode::ConfirmDelete => self.handle_confirm_delete_key(key)?,
             Mode::ContextMenu => self.handle_context_menu_key(key)?,
             Mode::OptimizeResult => self.handle_optimize_result_key(key)?,
+            Mode::AddMarker => self.handle_add_marker_key(key)?,
             _ => {}
         }
         Ok(())
@@ -931,14 +1153,18 @@ impl TuiApp for ListApp {
         prefetch_adjacent_previews(&self.shared.explorer, &mut self.shared.preview_cache);
 
         // Extract shared fields into local variables before closure
+        let preview_scroll = self.shared.preview_scroll_offset();
         let explorer = &mut self.shared.explorer;
         let mode = self.mode;
         let search_input = &self.shared.search_input;
+        let date_input = &self.shared.date_input;
+        let theme_picker_idx = self.shared.theme_picker_idx;
         let status = self.shared.status_message.clone();
         let agent_filter_idx = self.shared.agent_filter_idx;
         let available_agents = &self.shared.available_agents;
         let context_menu_idx = self.context_menu_idx;
         let optimize_result = self.optimize_result.clone();
+        let marker_input = &self.marker_input;
 
         // Get preview for current selection from cache
         let current_path = explorer.selected_item().map(|i| i.path.clone());
@@ -959,7 +1185,15 @@ impl TuiApp for ListApp {
             let chunks = build_explorer_layout(area);
 
             // Render file explorer (no checkboxes in list view - it's single-select)
-            render_explorer_list(frame, chunks[0], explorer, preview, false, backup_exists);
+            render_explorer_list(
+                frame,
+                chunks[0],
+                explorer,
+                preview,
+                preview_scroll,
+                false,
+                backup_exists,
+            );
 
             // Render status line
             let status_text = if let Some(msg) = &status {
@@ -967,14 +1201,21 @@ impl TuiApp for ListApp {
             } else {
                 match mode {
                     Mode::Search => format!("Search: {}_", search_input),
+                    Mode::DateFilter => {
+                        format!("Date range (YYYY-MM-DD..YYYY-MM-DD): {}_", date_input)
+                    }
                     Mode::AgentFilter => {
                         let agent = &available_agents[agent_filter_idx];
                         format!("Filter by agent: {} (←/→ to change, Enter to apply)", agent)
                     }
                     Mode::ConfirmDelete => "Delete this session? (y/n)".to_string(),
                     Mode::Help => String::new(),
+                    Mode::ThemePicker => "Choose a theme".to_string(),
                     Mode::ContextMenu => String::new(),
                     Mode::OptimizeResult => String::new(),
+                    Mode::AddMarker => {
+                        format!("Add marker (MM:SS or seconds, then label): {}_", marker_input)
+                    }
                     Mode::Normal => {
                         // Show current filters if any
                         let mut parts = vec![];
@@ -984,11 +1225,11 @@ impl TuiApp for ListApp {
                         if let Some(agent) = explorer.agent_filter() {
                             parts.push(format!("agent: {}", agent));
                         }
-                        if parts.is_empty() {
-                            format!("{} sessions", explorer.len())
-                        } else {
-                            format!("{} sessions ({})", explorer.len(), parts.join(", "))
+                        if let Some(range) = explorer.date_filter() {
+                            parts.push(format!("date: {}", range));
                         }
+                        parts.push(format!("sorted: {}", explorer.sort_label()));
+                        format!("{} sessions ({})", explorer.len(), parts.join(", "))
                     }
                 }
             };
@@ -998,12 +1239,15 @@ impl TuiApp for ListApp {
             let footer_text = match mode {
                 Mode::Search => "Esc: cancel | Enter: apply search | Backspace: delete char",
                 Mode::AgentFilter => "←/→: change agent | Enter: apply | Esc: cancel",
+                Mode::DateFilter => "Enter: apply (empty clears) | Esc: cancel | Backspace: delete char",
                 Mode::ConfirmDelete => "y: confirm delete | n/Esc: cancel",
                 Mode::Help => "Press any key to close help",
+                Mode::ThemePicker => "↑↓: choose theme | Enter: apply | Esc: cancel",
                 Mode::ContextMenu => "↑↓: navigate | Enter: select | Esc: cancel",
                 Mode::OptimizeResult => "Enter/Esc: dismiss",
+                Mode::AddMarker => "Enter: add marker | Esc: cancel | Backspace: delete char",
                 Mode::Normal => {
-                    "↑↓: navigate | Enter: menu | p: play | c: copy | t: optimize | a: analyze | d: delete | ?: help | q: quit"
+                    "↑↓: navigate | Enter: menu | p: play | c: copy | x: transcript | t: optimize | a: analyze | d: delete | ?: help | q: quit"
                 }
             };
             render_footer_text(frame, chunks[2], footer_text);
@@ -1024,6 +1268,9 @@ impl TuiApp for ListApp {
                         Self::render_optimize_result_modal(frame, area, result_state);
                     }
                 }
+                Mode::ThemePicker => {
+                    modals::render_theme_picker_modal(frame, area, THEME_NAMES, theme_picker_idx);
+                }
                 _ => {}
             }
         })?;
@@ -1032,13 +1279,33 @@ impl TuiApp for ListApp {
     }
 }
 
+/// Parse add marker input of the form `<timestamp> <label>`.
+///
+/// The timestamp accepts `MM:SS` (e.g. `1:23`, `01:23.5`) or raw seconds (e.g. `83.5`).
+fn parse_marker_input(input: &str) -> std::result::Result<(f64, String), String> {
+    let input = input.trim();
+    let (time_str, label) = input
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| "expected `<MM:SS or seconds> <label>`".to_string())?;
+
+    let timestamp = parse_marker_timestamp(time_str)
+        .ok_or_else(|| format!("invalid timestamp '{}'", time_str))?;
+
+    let label = label.trim();
+    if label.is_empty() {
+        return Err("label cannot be empty".to_string());
+    }
+
+    Ok((timestamp, label.to_string()))
+}
+
 /// Format a duration in seconds as human-readable string.
 ///
 /// Examples:
 /// - 65.5 -> "1m 5s"
 /// - 3661.0 -> "1h 1m 1s"
 /// - 30.0 -> "30s"
-fn format_duration(seconds: f64) -> String {
+pub(crate) fn format_duration(seconds: f64) -> String {
     let total_secs = seconds.round() as u64;
     let hours = total_secs / 3600;
     let minutes = (total_secs % 3600) / 60;
@@ -1085,8 +1352,8 @@ mod tests {
     }
 
     #[test]
-    fn context_menu_has_seven_items() {
-        assert_eq!(ContextMenuItem::ALL.len(), 7);
+    fn context_menu_has_nine_items() {
+        assert_eq!(ContextMenuItem::ALL.len(), 9);
     }
 
     #[test]
@@ -1109,16 +1376,25 @@ mod tests {
         assert_eq!(ContextMenuItem::Copy.shortcut(), "c");
     }
 
+    #[test]
+    fn context_menu_copy_transcript_label_and_shortcut() {
+        assert_eq!(ContextMenuItem::CopyTranscript.label(), "Copy transcript");
+        assert_eq!(ContextMenuItem::CopyTranscript.shortcut(), "x");
+    }
+
     #[test]
     fn context_menu_item_order() {
-        // Verify expected order: Play, Copy, Optimize, Analyze, Restore, Delete, AddMarker
+        // Verify expected order: Play, Copy, CopyTranscript, Optimize, CapIdle, Analyze, Restore,
+        // Delete, AddMarker
         assert_eq!(ContextMenuItem::ALL[0], ContextMenuItem::Play);
         assert_eq!(ContextMenuItem::ALL[1], ContextMenuItem::Copy);
-        assert_eq!(ContextMenuItem::ALL[2], ContextMenuItem::Optimize);
-        assert_eq!(ContextMenuItem::ALL[3], ContextMenuItem::Analyze);
-        assert_eq!(ContextMenuItem::ALL[4], ContextMenuItem::Restore);
-        assert_eq!(ContextMenuItem::ALL[5], ContextMenuItem::Delete);
-        assert_eq!(ContextMenuItem::ALL[6], ContextMenuItem::AddMarker);
+        assert_eq!(ContextMenuItem::ALL[2], ContextMenuItem::CopyTranscript);
+        assert_eq!(ContextMenuItem::ALL[3], ContextMenuItem::Optimize);
+        assert_eq!(ContextMenuItem::ALL[4], ContextMenuItem::CapIdle);
+        assert_eq!(ContextMenuItem::ALL[5], ContextMenuItem::Analyze);
+        assert_eq!(ContextMenuItem::ALL[6], ContextMenuItem::Restore);
+        assert_eq!(ContextMenuItem::ALL[7], ContextMenuItem::Delete);
+        assert_eq!(ContextMenuItem::ALL[8], ContextMenuItem::AddMarker);
     }
 
     #[test]
@@ -1153,4 +1429,14 @@ mod tests {
         assert_eq!(Mode::OptimizeResult, Mode::OptimizeResult);
         assert_ne!(Mode::OptimizeResult, Mode::Normal);
     }
+
+    #[test]
+    fn parse_marker_input_splits_timestamp_and_label() {
+        assert_eq!(
+            parse_marker_input("1:23  Build failed "),
+            Ok((83.0, "Build failed".to_string()))
+        );
+        assert!(parse_marker_input("1:23").is_err());
+        assert!(parse_marker_input("soon label").is_err());
+    }
 }
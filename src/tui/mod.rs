@@ -11,6 +11,7 @@ pub mod cleanup_app;
 pub mod event_bus;
 pub mod list_app;
 pub mod lru_cache;
+pub mod subprocess;
 pub mod ui;
 pub mod widgets;
 
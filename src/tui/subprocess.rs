@@ -0,0 +1,127 @@
+//! Cancellable subprocesses for the TUI
+//!
+//! While the TUI is suspended for a subcommand such as `analyze`, the child
+//! owns the terminal. Pressing Ctrl-C there delivers SIGINT to the whole
+//! foreground process group, which would otherwise take the TUI down too.
+//! [`run_cancellable`] catches that interrupt instead, forwards it to the
+//! child and reports the run as cancelled so the caller can restore the TUI.
+
+use std::io;
+use std::process::{Child, Command, ExitStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Interval between checks for child exit or cancellation.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Time a child gets to exit after SIGINT before it is killed.
+const INTERRUPT_GRACE: Duration = Duration::from_secs(3);
+
+/// Set by the Ctrl-C handler while a cancellable child is running.
+static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+static INSTALL_HANDLER: Once = Once::new();
+
+/// How a cancellable child process finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildOutcome {
+    /// The child exited on its own
+    Exited(ExitStatus),
+    /// The user cancelled and the child was interrupted
+    Cancelled,
+}
+
+/// Spawn `command` and wait for it, cancelling on Ctrl-C.
+///
+/// The command inherits the terminal, so the TUI must be suspended first.
+pub fn run_cancellable(command: &mut Command) -> io::Result<ChildOutcome> {
+    // The handler stays installed for the life of the process. That is
+    // harmless while the TUI runs: raw mode turns Ctrl-C into a key event.
+    INSTALL_HANDLER.call_once(|| {
+        ctrlc::set_handler(|| CANCEL_REQUESTED.store(true, Ordering::SeqCst)).ok();
+    });
+    CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+
+    let mut child = command.spawn()?;
+    wait_cancellable(&mut child, &CANCEL_REQUESTED)
+}
+
+/// Poll `child` until it exits or `cancel` is set.
+///
+/// On cancellation the child is sent SIGINT so it can clean up, killed if it
+/// has not exited within a grace period, and then reaped.
+pub fn wait_cancellable(child: &mut Child, cancel: &AtomicBool) -> io::Result<ChildOutcome> {
+    loop {
+        if cancel.swap(false, Ordering::SeqCst) {
+            interrupt(child)?;
+            return Ok(ChildOutcome::Cancelled);
+        }
+        if let Some(status) = child.try_wait()? {
+            return Ok(ChildOutcome::Exited(status));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Interrupt `child` and reap it.
+fn interrupt(child: &mut Child) -> io::Result<()> {
+    if child.try_wait()?.is_some() {
+        return Ok(());
+    }
+
+    #[cfg(unix)]
+    {
+        // SAFETY: kill(2) has no memory-safety preconditions; the pid belongs
+        // to a child we have not reaped yet, so it cannot have been reused.
+        unsafe {
+            libc::kill(child.id() as libc::pid_t, libc::SIGINT);
+        }
+        let start = Instant::now();
+        while start.elapsed() < INTERRUPT_GRACE {
+            if child.try_wait()?.is_some() {
+                return Ok(());
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    // Kill and reap to prevent zombie process
+    let _ = child.kill();
+    child.wait().map(|_| ())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exited_child_reports_status() {
+        let mut child = Command::new("sh").args(["-c", "exit 3"]).spawn().unwrap();
+        let cancel = AtomicBool::new(false);
+
+        match wait_cancellable(&mut child, &cancel).unwrap() {
+            ChildOutcome::Exited(status) => assert_eq!(status.code(), Some(3)),
+            ChildOutcome::Cancelled => panic!("expected child to exit"),
+        }
+    }
+
+    #[test]
+    fn cancel_before_completion_interrupts_child() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let mut child = Command::new("sleep").arg("30").spawn().unwrap();
+        let cancel = AtomicBool::new(true);
+        let start = Instant::now();
+
+        let outcome = wait_cancellable(&mut child, &cancel).unwrap();
+
+        assert_eq!(outcome, ChildOutcome::Cancelled);
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert!(!cancel.load(Ordering::SeqCst));
+        // The child was reaped and died from the interrupt
+        let status = child.try_wait().unwrap().expect("child should be reaped");
+        assert_eq!(status.signal(), Some(libc::SIGINT));
+    }
+}
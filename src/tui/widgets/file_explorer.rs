@@ -5,14 +5,15 @@
 //! - Arrow key navigation
 //! - Page up/down, Home/End
 //! - Multi-select with space
-//! - Sort by date/size/name
-//! - Filter by agent
+//! - Sort by date/size/name/agent
+//! - Filter by agent and recording date range
+//! - Fuzzy filename search, ordered by match score
 //! - Enhanced preview with duration, markers, and terminal snapshot
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveDate};
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
@@ -21,6 +22,7 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Widget},
 };
 
+use super::fuzzy::FilenameMatcher;
 use crate::asciicast::EventType;
 use crate::files::backup::has_backup;
 use crate::storage::SessionInfo;
@@ -82,6 +84,9 @@ impl From<SessionInfo> for FileItem {
 
 use crate::terminal::{Color, StyledLine};
 
+/// Number of terminal snapshot lines shown in the preview panel at once.
+pub const PREVIEW_VISIBLE_LINES: usize = 12;
+
 /// Enhanced preview information for a session file.
 ///
 /// This data is loaded lazily when a file is selected for preview.
@@ -108,6 +113,13 @@ impl SessionPreview {
         Self::load_streaming(path)
     }
 
+    /// Largest useful scroll offset for the terminal snapshot.
+    pub fn max_scroll(&self) -> usize {
+        self.styled_preview
+            .len()
+            .saturating_sub(PREVIEW_VISIBLE_LINES)
+    }
+
     /// Streaming loader that minimizes memory usage and processing time.
     ///
     /// Single-pass approach:
@@ -244,7 +256,8 @@ impl SessionPreview {
         let mut current_text = String::new();
         let mut current_style: Option<crate::terminal::CellStyle> = None;
 
-        for cell in &line.cells {
+        // Ratatui lays out wide glyphs itself, so drop their continuation cells
+        for cell in line.cells.iter().filter(|c| !c.is_wide_continuation()) {
             if Some(cell.style) == current_style {
                 current_text.push(cell.char);
             } else {
@@ -341,6 +354,41 @@ pub enum SortField {
     /// Sort by modification date (default)
     #[default]
     Date,
+    /// Sort by agent name
+    Agent,
+}
+
+impl SortField {
+    /// Next field in the cycle order name → date → size → agent → name.
+    pub fn next(self) -> Self {
+        match self {
+            SortField::Name => SortField::Date,
+            SortField::Date => SortField::Size,
+            SortField::Size => SortField::Agent,
+            SortField::Agent => SortField::Name,
+        }
+    }
+
+    /// Lowercase name, as shown in the status line and stored in config.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SortField::Name => "name",
+            SortField::Size => "size",
+            SortField::Date => "date",
+            SortField::Agent => "agent",
+        }
+    }
+
+    /// Parse a field name as stored in config.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "name" => Some(SortField::Name),
+            "size" => Some(SortField::Size),
+            "date" => Some(SortField::Date),
+            "agent" => Some(SortField::Agent),
+            _ => None,
+        }
+    }
 }
 
 /// Sort direction
@@ -353,6 +401,101 @@ pub enum SortDirection {
     Descending,
 }
 
+impl SortDirection {
+    /// The opposite direction.
+    pub fn flipped(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+
+    /// Short name as stored in config (`asc` / `desc`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "asc",
+            SortDirection::Descending => "desc",
+        }
+    }
+
+    /// Parse a direction as stored in config.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "asc" | "ascending" => Some(SortDirection::Ascending),
+            "desc" | "descending" => Some(SortDirection::Descending),
+            _ => None,
+        }
+    }
+
+    /// Arrow shown in the status line.
+    pub fn arrow(self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "↑",
+            SortDirection::Descending => "↓",
+        }
+    }
+}
+
+/// Recording date range filter, parsed from `START..END`.
+///
+/// Either bound may be omitted (`2024-01-01..`, `..2024-02-01`). Like a Rust
+/// range, the start date is inclusive and the end date is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    /// First day included (None = unbounded)
+    pub start: Option<NaiveDate>,
+    /// First day excluded (None = unbounded)
+    pub end: Option<NaiveDate>,
+}
+
+impl DateRange {
+    /// Parse a range like `2024-01-01..2024-02-01`.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let (start, end) = input
+            .trim()
+            .split_once("..")
+            .ok_or_else(|| "expected `YYYY-MM-DD..YYYY-MM-DD`".to_string())?;
+        let parse_bound = |s: &str| -> Result<Option<NaiveDate>, String> {
+            let s = s.trim();
+            if s.is_empty() {
+                return Ok(None);
+            }
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .map(Some)
+                .map_err(|_| format!("invalid date `{}` (expected YYYY-MM-DD)", s))
+        };
+        let range = Self {
+            start: parse_bound(start)?,
+            end: parse_bound(end)?,
+        };
+        if let (Some(start), Some(end)) = (range.start, range.end) {
+            if start >= end {
+                return Err("start date must be before end date".to_string());
+            }
+        }
+        Ok(range)
+    }
+
+    /// Whether a modification time falls inside the range.
+    pub fn contains(&self, modified: &DateTime<Local>) -> bool {
+        let date = modified.date_naive();
+        self.start.map_or(true, |start| date >= start) && self.end.map_or(true, |end| date < end)
+    }
+}
+
+impl std::fmt::Display for DateRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(start) = self.start {
+            write!(f, "{}", start)?;
+        }
+        write!(f, "..")?;
+        if let Some(end) = self.end {
+            write!(f, "{}", end)?;
+        }
+        Ok(())
+    }
+}
+
 /// File explorer widget state
 #[derive(Debug, Clone)]
 pub struct FileExplorer {
@@ -370,8 +513,12 @@ pub struct FileExplorer {
     sort_direction: SortDirection,
     /// Agent filter (None = show all)
     agent_filter: Option<String>,
-    /// Search filter - matches filename (case-insensitive)
+    /// Search filter - fuzzy matches filename (case-insensitive)
     search_filter: Option<String>,
+    /// Date range filter on modification time (None = any date)
+    date_filter: Option<DateRange>,
+    /// Fuzzy match scores of visible items while a search is active (keyed by item index)
+    search_scores: HashMap<usize, i64>,
     /// List state for ratatui
     list_state: ListState,
     /// Page size for page up/down navigation
@@ -399,6 +546,8 @@ impl FileExplorer {
             sort_direction: SortDirection::default(),
             agent_filter: None,
             search_filter: None,
+            date_filter: None,
+            search_scores: HashMap::new(),
             list_state: ListState::default(),
             page_size: 10,
         };
@@ -551,6 +700,19 @@ impl FileExplorer {
         }
     }
 
+    /// Select all visible items larger than `threshold` bytes.
+    ///
+    /// Returns the number of newly selected items.
+    pub fn select_larger_than(&mut self, threshold: u64) -> usize {
+        let mut count = 0;
+        for &idx in &self.visible_indices {
+            if self.items[idx].size > threshold && self.multi_selected.insert(idx) {
+                count += 1;
+            }
+        }
+        count
+    }
+
     /// Deselect all items
     pub fn select_none(&mut self) {
         self.multi_selected.clear();
@@ -581,10 +743,7 @@ impl FileExplorer {
     pub fn set_sort(&mut self, field: SortField) {
         if self.sort_field == field {
             // Toggle direction
-            self.sort_direction = match self.sort_direction {
-                SortDirection::Ascending => SortDirection::Descending,
-                SortDirection::Descending => SortDirection::Ascending,
-            };
+            self.sort_direction = self.sort_direction.flipped();
         } else {
             self.sort_field = field;
             self.sort_direction = SortDirection::Descending;
@@ -594,10 +753,55 @@ impl FileExplorer {
         self.sync_list_state();
     }
 
+    /// Set both sort field and direction, selecting the first item.
+    pub fn set_sort_order(&mut self, field: SortField, direction: SortDirection) {
+        self.sort_field = field;
+        self.sort_direction = direction;
+        self.apply_sort();
+        self.selected = 0;
+        self.sync_list_state();
+    }
+
+    /// Switch to the next sort field, keeping the current direction and selected item.
+    pub fn cycle_sort_field(&mut self) {
+        self.sort_field = self.sort_field.next();
+        self.resort_keeping_selection();
+    }
+
+    /// Flip the sort direction, keeping the selected item.
+    pub fn toggle_sort_direction(&mut self) {
+        self.sort_direction = self.sort_direction.flipped();
+        self.resort_keeping_selection();
+    }
+
+    /// Current sort for the status line, e.g. `date ↓`.
+    pub fn sort_label(&self) -> String {
+        format!(
+            "{} {}",
+            self.sort_field.as_str(),
+            self.sort_direction.arrow()
+        )
+    }
+
+    /// Re-sort and move the cursor to wherever the selected item ended up.
+    fn resort_keeping_selection(&mut self) {
+        let current = self.visible_indices.get(self.selected).copied();
+        self.apply_sort();
+        if let Some(item_idx) = current {
+            self.selected = self
+                .visible_indices
+                .iter()
+                .position(|&idx| idx == item_idx)
+                .unwrap_or(0);
+        }
+        self.sync_list_state();
+    }
+
     /// Apply current sort to visible indices
     fn apply_sort(&mut self) {
         let items = &self.items;
         let dir = self.sort_direction;
+        let scores = &self.search_scores;
 
         self.visible_indices.sort_by(|&a, &b| {
             let item_a = &items[a];
@@ -607,12 +811,21 @@ impl FileExplorer {
                 SortField::Name => item_a.name.cmp(&item_b.name),
                 SortField::Size => item_a.size.cmp(&item_b.size),
                 SortField::Date => item_a.modified.cmp(&item_b.modified),
+                SortField::Agent => item_a
+                    .agent
+                    .cmp(&item_b.agent)
+                    .then_with(|| item_b.modified.cmp(&item_a.modified)),
             };
 
-            match dir {
+            let cmp = match dir {
                 SortDirection::Ascending => cmp,
                 SortDirection::Descending => cmp.reverse(),
-            }
+            };
+
+            // Best fuzzy matches first while searching
+            let score_a = scores.get(&a).copied().unwrap_or(0);
+            let score_b = scores.get(&b).copied().unwrap_or(0);
+            score_b.cmp(&score_a).then(cmp)
         });
     }
 
@@ -637,7 +850,10 @@ impl FileExplorer {
         self.search_filter.as_deref()
     }
 
-    /// Set the search filter (case-insensitive substring match on filename)
+    /// Set the search filter (case-insensitive fuzzy match on filename).
+    ///
+    /// While a search is active, results are ordered by match score, with
+    /// the current sort order breaking ties.
     pub fn set_search_filter(&mut self, search: Option<String>) {
         self.search_filter = search;
         self.apply_filter();
@@ -646,10 +862,25 @@ impl FileExplorer {
         self.sync_list_state();
     }
 
-    /// Clear both search and agent filters
+    /// Get the current date range filter
+    pub fn date_filter(&self) -> Option<&DateRange> {
+        self.date_filter.as_ref()
+    }
+
+    /// Set the date range filter (None = any date)
+    pub fn set_date_filter(&mut self, range: Option<DateRange>) {
+        self.date_filter = range;
+        self.apply_filter();
+        self.apply_sort();
+        self.selected = 0;
+        self.sync_list_state();
+    }
+
+    /// Clear search, agent and date filters
     pub fn clear_filters(&mut self) {
         self.search_filter = None;
         self.agent_filter = None;
+        self.date_filter = None;
         self.apply_filter();
         self.apply_sort();
         self.selected = 0;
@@ -692,29 +923,55 @@ impl FileExplorer {
 
     /// Apply current filter to rebuild visible indices
     fn apply_filter(&mut self) {
+        let matcher = FilenameMatcher::new();
+        let mut scores = HashMap::new();
+
         self.visible_indices = self
             .items
             .iter()
             .enumerate()
-            .filter(|(_, item)| {
+            .filter(|(idx, item)| {
                 // Agent filter
                 let agent_match = self
                     .agent_filter
                     .as_ref()
                     .map(|f| item.agent == *f)
                     .unwrap_or(true);
+                if !agent_match {
+                    return false;
+                }
 
-                // Search filter (case-insensitive substring match on filename)
-                let search_match = self
-                    .search_filter
-                    .as_ref()
-                    .map(|s| item.name.to_lowercase().contains(&s.to_lowercase()))
-                    .unwrap_or(true);
+                // Date range filter
+                if let Some(range) = &self.date_filter {
+                    if !range.contains(&item.modified) {
+                        return false;
+                    }
+                }
 
-                agent_match && search_match
+                // Search filter (case-insensitive fuzzy match on filename)
+                match &self.search_filter {
+                    Some(query) => match matcher.score(&item.name, query) {
+                        Some(score) => {
+                            scores.insert(*idx, score);
+                            true
+                        }
+                        None => false,
+                    },
+                    None => true,
+                }
             })
             .map(|(idx, _)| idx)
             .collect();
+
+        self.search_scores = scores;
+    }
+
+    /// Character indices in an item's name matched by the active search.
+    ///
+    /// Returns `None` when no search is active or the name does not match.
+    pub fn search_match_indices(&self, name: &str) -> Option<Vec<usize>> {
+        let query = self.search_filter.as_deref()?;
+        FilenameMatcher::new().matched_indices(name, query)
     }
 
     /// Get unique agent names from all items
@@ -791,6 +1048,8 @@ pub struct FileExplorerWidget<'a> {
     session_preview: Option<&'a SessionPreview>,
     /// Whether a backup exists for the selected file
     has_backup: bool,
+    /// First terminal snapshot line shown in the preview panel
+    preview_scroll: usize,
 }
 
 impl<'a> FileExplorerWidget<'a> {
@@ -802,6 +1061,7 @@ impl<'a> FileExplorerWidget<'a> {
             show_checkboxes: true,
             session_preview: None,
             has_backup: false,
+            preview_scroll: 0,
         }
     }
 
@@ -828,6 +1088,12 @@ impl<'a> FileExplorerWidget<'a> {
         self.has_backup = has_backup;
         self
     }
+
+    /// Set the scroll offset of the terminal snapshot in the preview panel
+    pub fn preview_scroll(mut self, offset: usize) -> Self {
+        self.preview_scroll = offset;
+        self
+    }
 }
 
 impl Widget for FileExplorerWidget<'_> {
@@ -907,8 +1173,9 @@ impl Widget for FileExplorerWidget<'_> {
             )
         });
 
-        // Capture backup status
+        // Capture backup status and preview scroll
         let has_backup = self.has_backup;
+        let preview_scroll = self.preview_scroll;
 
         // Render list
         let list = List::new(items)
@@ -986,7 +1253,13 @@ impl Widget for FileExplorerWidget<'_> {
                         )]));
 
                         // Add terminal preview lines with colors (limited to fit)
-                        for styled_line in styled_preview.iter().take(12) {
+                        let first = preview_scroll
+                            .min(styled_preview.len().saturating_sub(PREVIEW_VISIBLE_LINES));
+                        for styled_line in styled_preview
+                            .iter()
+                            .skip(first)
+                            .take(PREVIEW_VISIBLE_LINES)
+                        {
                             // Prepend a space and convert to ratatui Line with colors
                             let mut ratatui_line =
                                 SessionPreview::styled_line_to_ratatui(styled_line);
@@ -1259,6 +1532,78 @@ mod tests {
         assert_eq!(explorer.sort_direction(), SortDirection::Ascending);
     }
 
+    #[test]
+    fn sort_field_cycles_through_all_fields() {
+        assert_eq!(SortField::Name.next(), SortField::Date);
+        assert_eq!(SortField::Date.next(), SortField::Size);
+        assert_eq!(SortField::Size.next(), SortField::Agent);
+        assert_eq!(SortField::Agent.next(), SortField::Name);
+    }
+
+    #[test]
+    fn sort_field_and_direction_round_trip_through_strings() {
+        for field in [
+            SortField::Name,
+            SortField::Date,
+            SortField::Size,
+            SortField::Agent,
+        ] {
+            assert_eq!(SortField::parse(field.as_str()), Some(field));
+        }
+        for direction in [SortDirection::Ascending, SortDirection::Descending] {
+            assert_eq!(SortDirection::parse(direction.as_str()), Some(direction));
+        }
+        assert_eq!(SortField::parse("bogus"), None);
+        assert_eq!(SortDirection::parse("sideways"), None);
+    }
+
+    #[test]
+    fn cycle_sort_field_keeps_selected_item() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        explorer.down(); // session1 (second newest)
+        assert_eq!(explorer.selected_item().unwrap().name, "session1.cast");
+
+        explorer.cycle_sort_field(); // date -> size, descending
+        assert_eq!(explorer.sort_field(), SortField::Size);
+        assert_eq!(explorer.selected_item().unwrap().name, "session1.cast");
+        assert_eq!(explorer.selected(), 1);
+
+        explorer.cycle_sort_field(); // size -> agent
+        assert_eq!(explorer.sort_field(), SortField::Agent);
+        assert_eq!(explorer.selected_item().unwrap().name, "session1.cast");
+    }
+
+    #[test]
+    fn toggle_sort_direction_keeps_selected_item() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        assert_eq!(explorer.selected_item().unwrap().name, "session2.cast");
+
+        explorer.toggle_sort_direction();
+        assert_eq!(explorer.sort_direction(), SortDirection::Ascending);
+        assert_eq!(explorer.selected_item().unwrap().name, "session2.cast");
+        assert_eq!(explorer.selected(), 2);
+    }
+
+    #[test]
+    fn sort_label_shows_field_and_arrow() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        assert_eq!(explorer.sort_label(), "date ↓");
+        explorer.toggle_sort_direction();
+        assert_eq!(explorer.sort_label(), "date ↑");
+    }
+
+    #[test]
+    fn set_sort_order_applies_field_and_direction() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        explorer.set_sort_order(SortField::Agent, SortDirection::Ascending);
+        let names: Vec<_> = explorer
+            .visible_items()
+            .map(|(_, item, _)| item.agent.clone())
+            .collect();
+        assert_eq!(names, ["claude", "claude", "codex"]);
+        assert_eq!(explorer.selected(), 0);
+    }
+
     #[test]
     fn filter_by_agent_shows_only_matching() {
         let mut explorer = FileExplorer::new(create_test_items());
@@ -1357,6 +1702,173 @@ mod tests {
         assert!(explorer.selected_item().is_none());
     }
 
+    fn fuzzy_test_items() -> Vec<FileItem> {
+        [
+            "cxlxexaxn.cast",
+            "session.cast",
+            "cleanup.cast",
+            "my-clean-run.cast",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            FileItem::new(
+                format!("/sessions/claude/{}", name),
+                *name,
+                "claude",
+                100,
+                Local
+                    .with_ymd_and_hms(2024, 1, 10 + i as u32, 9, 0, 0)
+                    .unwrap(),
+            )
+        })
+        .collect()
+    }
+
+    #[test]
+    fn search_filter_matches_subsequence() {
+        let mut explorer = FileExplorer::new(fuzzy_test_items());
+        explorer.set_search_filter(Some("clup".to_string()));
+        let names: Vec<_> = explorer
+            .visible_items()
+            .map(|(_, item, _)| item.name.clone())
+            .collect();
+        assert_eq!(names, ["cleanup.cast"]);
+    }
+
+    #[test]
+    fn search_results_ordered_by_match_score() {
+        let mut explorer = FileExplorer::new(fuzzy_test_items());
+        explorer.set_search_filter(Some("clean".to_string()));
+        let names: Vec<_> = explorer
+            .visible_items()
+            .map(|(_, item, _)| item.name.clone())
+            .collect();
+        assert_eq!(names.len(), 3);
+        assert!(!names.contains(&"session.cast".to_string()));
+        // Scattered match ranks last regardless of date order
+        assert_eq!(names[2], "cxlxexaxn.cast");
+    }
+
+    #[test]
+    fn clearing_search_restores_sort_order() {
+        let mut explorer = FileExplorer::new(fuzzy_test_items());
+        explorer.set_search_filter(Some("clean".to_string()));
+        explorer.set_search_filter(None);
+        let names: Vec<_> = explorer
+            .visible_items()
+            .map(|(_, item, _)| item.name.clone())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "my-clean-run.cast",
+                "cleanup.cast",
+                "session.cast",
+                "cxlxexaxn.cast"
+            ]
+        );
+    }
+
+    #[test]
+    fn search_match_indices_follow_active_search() {
+        let mut explorer = FileExplorer::new(fuzzy_test_items());
+        assert_eq!(explorer.search_match_indices("cleanup.cast"), None);
+        explorer.set_search_filter(Some("clup".to_string()));
+        assert_eq!(
+            explorer.search_match_indices("cleanup.cast"),
+            Some(vec![0, 1, 5, 6])
+        );
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn date_range_parses_both_bounds() {
+        let range = DateRange::parse("2024-01-01..2024-02-01").unwrap();
+        assert_eq!(range.start, Some(date(2024, 1, 1)));
+        assert_eq!(range.end, Some(date(2024, 2, 1)));
+        assert_eq!(range.to_string(), "2024-01-01..2024-02-01");
+    }
+
+    #[test]
+    fn date_range_bounds_are_optional() {
+        let open_end = DateRange::parse("2024-01-01..").unwrap();
+        assert_eq!(open_end.end, None);
+        let open_start = DateRange::parse(" ..2024-02-01 ").unwrap();
+        assert_eq!(open_start.start, None);
+        assert_eq!(open_start.to_string(), "..2024-02-01");
+        assert_eq!(DateRange::parse("..").unwrap().to_string(), "..");
+    }
+
+    #[test]
+    fn date_range_rejects_invalid_input() {
+        assert!(DateRange::parse("2024-01-01").is_err());
+        assert!(DateRange::parse("2024-13-01..").is_err());
+        assert!(DateRange::parse("yesterday..").is_err());
+        assert!(DateRange::parse("2024-02-01..2024-01-01").is_err());
+        assert!(DateRange::parse("2024-02-01..2024-02-01").is_err());
+    }
+
+    #[test]
+    fn date_range_start_inclusive_end_exclusive() {
+        let range = DateRange::parse("2024-01-15..2024-01-16").unwrap();
+        let at = |d: u32, h: u32| Local.with_ymd_and_hms(2024, 1, d, h, 0, 0).unwrap();
+        assert!(!range.contains(&at(14, 23)));
+        assert!(range.contains(&at(15, 0)));
+        assert!(range.contains(&at(15, 23)));
+        assert!(!range.contains(&at(16, 0)));
+    }
+
+    #[test]
+    fn date_filter_keeps_items_in_range() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        explorer.set_date_filter(Some(DateRange::parse("2024-01-15..").unwrap()));
+        let names: Vec<_> = explorer
+            .visible_items()
+            .map(|(_, item, _)| item.name.clone())
+            .collect();
+        assert_eq!(names, ["session2.cast", "session1.cast"]);
+
+        explorer.set_date_filter(Some(DateRange::parse("..2024-01-15").unwrap()));
+        assert_eq!(explorer.len(), 1);
+        assert_eq!(explorer.selected_item().unwrap().name, "session3.cast");
+    }
+
+    #[test]
+    fn select_larger_than_selects_visible_items_over_threshold() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        assert_eq!(explorer.select_larger_than(1000), 2);
+        let mut names: Vec<_> = explorer
+            .selected_items()
+            .iter()
+            .map(|i| i.name.clone())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["session1.cast", "session2.cast"]);
+        // Already selected items are not counted again
+        assert_eq!(explorer.select_larger_than(1000), 0);
+    }
+
+    #[test]
+    fn select_larger_than_ignores_hidden_items() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        explorer.set_agent_filter(Some("claude".to_string()));
+        assert_eq!(explorer.select_larger_than(0), 2);
+        assert_eq!(explorer.selected_count(), 2);
+    }
+
+    #[test]
+    fn clear_filters_removes_date_filter() {
+        let mut explorer = FileExplorer::new(create_test_items());
+        explorer.set_date_filter(Some(DateRange::parse("..2024-01-15").unwrap()));
+        explorer.clear_filters();
+        assert_eq!(explorer.date_filter(), None);
+        assert_eq!(explorer.len(), 3);
+    }
+
     #[test]
     fn search_filter_none_shows_all() {
         let mut explorer = FileExplorer::new(create_test_items());
@@ -1424,6 +1936,19 @@ mod tests {
 
     // SessionPreview tests
 
+    #[test]
+    fn session_preview_max_scroll_leaves_a_full_panel() {
+        let line = crate::terminal::StyledLine { cells: Vec::new() };
+        let mut preview = SessionPreview {
+            duration_secs: 1.0,
+            marker_count: 0,
+            styled_preview: vec![line; PREVIEW_VISIBLE_LINES + 5],
+        };
+        assert_eq!(preview.max_scroll(), 5);
+        preview.styled_preview.truncate(3);
+        assert_eq!(preview.max_scroll(), 0);
+    }
+
     #[test]
     fn session_preview_format_duration_seconds() {
         let preview = SessionPreview {
@@ -0,0 +1,93 @@
+//! Fuzzy filename matching for the explorer search filter.
+//!
+//! Wraps `fuzzy-matcher`'s skim algorithm so typing a subsequence such as
+//! `clup` matches `cleanup`. Matching is case-insensitive. Higher scores mean
+//! tighter matches (contiguous runs, word starts), which the explorer uses to
+//! order search results.
+
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+
+/// Case-insensitive fuzzy matcher for session filenames.
+pub struct FilenameMatcher {
+    matcher: SkimMatcherV2,
+}
+
+impl Default for FilenameMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FilenameMatcher {
+    /// Create a new matcher.
+    pub fn new() -> Self {
+        Self {
+            matcher: SkimMatcherV2::default().ignore_case(),
+        }
+    }
+
+    /// Score `name` against `query`, or `None` if it does not match.
+    ///
+    /// An empty query matches everything with a score of 0.
+    pub fn score(&self, name: &str, query: &str) -> Option<i64> {
+        if query.is_empty() {
+            return Some(0);
+        }
+        self.matcher.fuzzy_match(name, query)
+    }
+
+    /// Character indices in `name` matched by `query`, for highlighting.
+    pub fn matched_indices(&self, name: &str, query: &str) -> Option<Vec<usize>> {
+        if query.is_empty() {
+            return Some(Vec::new());
+        }
+        self.matcher
+            .fuzzy_indices(name, query)
+            .map(|(_, indices)| indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_matches() {
+        let matcher = FilenameMatcher::new();
+        assert!(matcher.score("cleanup.cast", "clup").is_some());
+        assert!(matcher.score("session.cast", "clup").is_none());
+    }
+
+    #[test]
+    fn matching_ignores_case() {
+        let matcher = FilenameMatcher::new();
+        assert!(matcher.score("CleanUp.cast", "clup").is_some());
+        assert!(matcher.score("cleanup.cast", "CLUP").is_some());
+    }
+
+    #[test]
+    fn contiguous_match_scores_higher_than_scattered() {
+        let matcher = FilenameMatcher::new();
+        let tight = matcher.score("cleanup.cast", "clean").unwrap();
+        let loose = matcher.score("c_l_e_a_n.cast", "clean").unwrap();
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        let matcher = FilenameMatcher::new();
+        assert_eq!(matcher.score("anything.cast", ""), Some(0));
+    }
+
+    #[test]
+    fn matched_indices_point_at_query_characters() {
+        let matcher = FilenameMatcher::new();
+        let indices = matcher.matched_indices("cleanup.cast", "clup").unwrap();
+        let chars: String = indices
+            .iter()
+            .map(|&i| "cleanup.cast".chars().nth(i).unwrap())
+            .collect();
+        assert_eq!(chars, "clup");
+    }
+}
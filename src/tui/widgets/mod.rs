@@ -3,10 +3,13 @@
 //! Reusable UI components for the terminal interface.
 
 pub mod file_explorer;
+pub mod fuzzy;
 pub mod logo;
 pub mod preview;
 
 pub use file_explorer::{
-    FileExplorer, FileExplorerWidget, FileItem, SessionPreview, SortDirection, SortField,
+    DateRange, FileExplorer, FileExplorerWidget, FileItem, SessionPreview, SortDirection,
+    SortField, PREVIEW_VISIBLE_LINES,
 };
+pub use fuzzy::FilenameMatcher;
 pub use logo::Logo;
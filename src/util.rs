@@ -0,0 +1,43 @@
+//! Small formatting helpers shared across the CLI, player and analyzer.
+
+/// Format a duration in seconds to MM:SS format.
+///
+/// # Arguments
+/// * `seconds` - Duration in seconds
+///
+/// # Returns
+/// A string in MM:SS format
+pub fn format_duration(seconds: f64) -> String {
+    let total_secs = seconds as u64;
+    let mins = total_secs / 60;
+    let secs = total_secs % 60;
+    format!("{:02}:{:02}", mins, secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_formats_correctly() {
+        assert_eq!(format_duration(0.0), "00:00");
+        assert_eq!(format_duration(65.0), "01:05");
+        assert_eq!(format_duration(3661.0), "61:01");
+    }
+
+    #[test]
+    fn format_duration_edge_cases() {
+        // Fractional seconds are truncated
+        assert_eq!(format_duration(0.9), "00:00");
+        assert_eq!(format_duration(1.5), "00:01");
+        assert_eq!(format_duration(59.9), "00:59");
+        // Very large durations (hours)
+        assert_eq!(format_duration(7200.0), "120:00"); // 2 hours
+    }
+
+    #[test]
+    fn format_duration_negative_treated_as_zero() {
+        // Negative durations should still format (as 0 due to u64 cast)
+        assert_eq!(format_duration(-5.0), "00:00");
+    }
+}
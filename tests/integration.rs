@@ -15,12 +15,18 @@ mod branding_test;
 #[path = "integration/config_test.rs"]
 mod config_test;
 
+#[path = "integration/doctor_test.rs"]
+mod doctor_test;
+
 #[path = "integration/filename_test.rs"]
 mod filename_test;
 
 #[path = "integration/markers_test.rs"]
 mod markers_test;
 
+#[path = "integration/markers_cli_test.rs"]
+mod markers_cli_test;
+
 #[path = "integration/recording_test.rs"]
 mod recording_test;
 
@@ -66,6 +72,18 @@ mod terminal_editing_test;
 #[path = "integration/terminal_style_test.rs"]
 mod terminal_style_test;
 
+#[path = "integration/terminal_hyperlink_test.rs"]
+mod terminal_hyperlink_test;
+
+#[path = "integration/terminal_alt_screen_test.rs"]
+mod terminal_alt_screen_test;
+
+#[path = "integration/terminal_charset_test.rs"]
+mod terminal_charset_test;
+
+#[path = "integration/terminal_tabs_test.rs"]
+mod terminal_tabs_test;
+
 #[path = "integration/snapshot_player_test.rs"]
 mod snapshot_player_test;
 
@@ -78,5 +96,35 @@ mod clipboard_test;
 #[path = "integration/copy_test.rs"]
 mod copy_test;
 
+#[path = "integration/config_cli_test.rs"]
+mod config_cli_test;
+
 #[path = "integration/analyzer_content_test.rs"]
 mod analyzer_content_test;
+
+#[path = "integration/export_test.rs"]
+mod export_test;
+
+#[path = "integration/validate_test.rs"]
+mod validate_test;
+
+#[path = "integration/analyze_dump_test.rs"]
+mod analyze_dump_test;
+
+#[path = "integration/stdin_test.rs"]
+mod stdin_test;
+
+#[path = "integration/info_test.rs"]
+mod info_test;
+
+#[path = "integration/detect_agent_test.rs"]
+mod detect_agent_test;
+
+#[path = "integration/cleanup_cli_test.rs"]
+mod cleanup_cli_test;
+
+#[path = "integration/list_cli_test.rs"]
+mod list_cli_test;
+
+#[path = "integration/diff_test.rs"]
+mod diff_test;
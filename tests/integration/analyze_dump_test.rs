@@ -0,0 +1,68 @@
+//! Integration tests for `agr analyze --dump-content` (CLI)
+
+use regex::Regex;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Run `agr analyze` on the sample fixture with the given extra arguments.
+///
+/// PATH is emptied so any attempt to invoke an agent would fail.
+fn run_analyze(args: &[&str]) -> (String, String, i32) {
+    let home = TempDir::new().unwrap();
+    let empty_path = TempDir::new().unwrap();
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sample.cast");
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .arg("analyze")
+        .arg(fixture)
+        .args(args)
+        .env("HOME", home.path())
+        .env("PATH", empty_path.path())
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+        output.status.code().unwrap_or(-1),
+    )
+}
+
+/// Summary and segment header lines. The token count depends on whether the
+/// `tokenizer` feature is enabled, so it is matched loosely.
+const SUMMARY_LINE: &str = r"^1 segment\(s\), ~\d+ tokens, 00:00\.80 duration\n";
+const SEGMENT_LINE: &str = r"Segment 1 \[00:00\.80 - 00:00\.80\] ~\d+ tokens\n";
+
+#[test]
+fn dump_content_prints_segments_without_an_agent() {
+    let (stdout, stderr, exit_code) = run_analyze(&["--dump-content"]);
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        Regex::new(SUMMARY_LINE).unwrap().is_match(&stdout),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        Regex::new(SEGMENT_LINE).unwrap().is_match(&stdout),
+        "stdout: {}",
+        stdout
+    );
+    assert!(stdout.contains("$ echo hello"), "stdout: {}", stdout);
+}
+
+#[test]
+fn dump_content_segments_only_omits_text() {
+    let (stdout, stderr, exit_code) = run_analyze(&["--dump-content", "--segments-only"]);
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    let expected = Regex::new(&format!("{}{}$", SUMMARY_LINE, SEGMENT_LINE)).unwrap();
+    assert!(expected.is_match(&stdout), "stdout: {}", stdout);
+}
+
+#[test]
+fn segments_only_requires_dump_content() {
+    let (_, stderr, exit_code) = run_analyze(&["--segments-only"]);
+    assert_eq!(exit_code, 2);
+    assert!(stderr.contains("--dump-content"), "stderr: {}", stderr);
+}
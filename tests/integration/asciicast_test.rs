@@ -86,6 +86,28 @@ fn cumulative_times_sum_correctly() {
     assert!((times[2] - 0.8).abs() < 0.001);
 }
 
+#[test]
+fn duration_matches_last_cumulative_time() {
+    let content = load_fixture("sample.cast");
+    let cast = AsciicastFile::parse_str(&content).unwrap();
+
+    let last = *cast.cumulative_times().last().unwrap();
+    assert_eq!(cast.duration(), last);
+    assert!((cast.duration() - 0.8).abs() < 0.001);
+}
+
+#[test]
+fn event_count_by_type_on_sample_cast() {
+    let content = load_fixture("sample.cast");
+    let cast = AsciicastFile::parse_str(&content).unwrap();
+
+    let counts = cast.event_count_by_type();
+    assert_eq!(counts.values().sum::<usize>(), cast.events.len());
+    assert_eq!(counts.get(&EventType::Output), Some(&3));
+    // The fixture has no markers
+    assert_eq!(counts.get(&EventType::Marker), None);
+}
+
 // === Inline string tests (merged from src/asciicast.rs) ===
 
 fn sample_cast() -> &'static str {
@@ -95,6 +117,13 @@ fn sample_cast() -> &'static str {
 [0.2,"o","$ "]"#
 }
 
+fn cast_with_theme() -> &'static str {
+    r##"{"version":3,"term":{"cols":80,"rows":24,"theme":{"fg":"#d0d0d0","bg":"#1c1c1c","palette":"#000000:#cd0000:#00cd00:#cdcd00:#0000ee:#cd00cd:#00cdcd:#e5e5e5"}}}
+[0.5,"o","$ echo hello\r\n"]
+[0.1,"o","hello\r\n"]
+[0.2,"o","$ "]"##
+}
+
 fn cast_with_markers() -> &'static str {
     r#"{"version":3,"term":{"cols":80,"rows":24}}
 [0.5,"o","$ make build\r\n"]
@@ -127,6 +156,29 @@ fn parse_extracts_marker_events() {
     assert_eq!(markers[1].data, "Build finished");
 }
 
+#[test]
+fn marker_positions_use_cumulative_times() {
+    let cast = AsciicastFile::parse_str(cast_with_markers()).unwrap();
+    let positions = cast.marker_positions();
+
+    // Events: 0.5 output, +1.0 marker, +2.5 output, +0.1 marker
+    let expected = [
+        (0.5 + 1.0, "Build started"),
+        (0.5 + 1.0 + 2.5 + 0.1, "Build finished"),
+    ];
+    assert_eq!(positions.len(), expected.len());
+    for (position, (time, label)) in positions.iter().zip(expected) {
+        assert!((position.time - time).abs() < 1e-9);
+        assert_eq!(position.label, label);
+    }
+}
+
+#[test]
+fn marker_positions_empty_without_markers() {
+    let cast = AsciicastFile::parse_str(sample_cast()).unwrap();
+    assert!(cast.marker_positions().is_empty());
+}
+
 #[test]
 fn roundtrip_preserves_data() {
     let original = sample_cast();
@@ -143,6 +195,67 @@ fn roundtrip_preserves_data() {
     }
 }
 
+#[test]
+fn roundtrip_preserves_theme() {
+    let cast = AsciicastFile::parse_str(cast_with_theme()).unwrap();
+    let written = cast.to_string().unwrap();
+    let reparsed = AsciicastFile::parse_str(&written).unwrap();
+
+    let theme = cast.header.term.as_ref().unwrap().theme.as_ref().unwrap();
+    let reparsed_theme = reparsed.header.term.as_ref().unwrap().theme.as_ref();
+    assert_eq!(reparsed_theme, Some(theme));
+    assert_eq!(theme.palette.len(), 8);
+    assert_eq!(theme.fg.to_hex(), "#d0d0d0");
+    assert!(written.contains("#000000:#cd0000:#00cd00"));
+}
+
+#[test]
+fn parse_ignores_malformed_theme() {
+    let content = r##"{"version":3,"term":{"cols":80,"rows":24,"theme":{"fg":"#d0d0d0","bg":"#1c1c1c","palette":"#000000:#cd0000"}}}
+[0.5,"o","$ "]"##;
+    let cast = AsciicastFile::parse_str(content).unwrap();
+
+    assert!(cast.header.term.as_ref().unwrap().theme.is_none());
+    assert_eq!(cast.events.len(), 1);
+}
+
+#[test]
+fn roundtrip_without_theme_omits_theme_key() {
+    let cast = AsciicastFile::parse_str(cast_with_markers()).unwrap();
+    let written = cast.to_string().unwrap();
+
+    assert!(cast.header.term.as_ref().unwrap().theme.is_none());
+    assert!(!written.contains("theme"));
+}
+
+#[test]
+fn roundtrip_preserves_every_header_field() {
+    let original = r##"{"version":3,"width":100,"height":30,"term":{"cols":100,"rows":30,"type":"xterm-256color"},"timestamp":1700000000,"duration":12.345678,"title":"demo session","command":"claude --resume","env":{"SHELL":"/bin/zsh","TERM":"xterm-256color","LANG":"en_US.UTF-8","COLORTERM":"truecolor"},"idle_time_limit":2.5}
+[0.5,"o","hi"]"##;
+    let cast = AsciicastFile::parse_str(original).unwrap();
+    let written = cast.to_string().unwrap();
+    let reparsed = AsciicastFile::parse_str(&written).unwrap();
+
+    let header = &reparsed.header;
+    assert_eq!((header.width, header.height), (Some(100), Some(30)));
+    assert_eq!(header.timestamp, Some(1_700_000_000));
+    assert_eq!(header.duration, Some(12.345678));
+    assert_eq!(header.title.as_deref(), Some("demo session"));
+    assert_eq!(header.command.as_deref(), Some("claude --resume"));
+    assert_eq!(header.idle_time_limit, Some(2.5));
+    let env = header.env.as_ref().unwrap();
+    assert_eq!(env.shell.as_deref(), Some("/bin/zsh"));
+    assert_eq!(env.term.as_deref(), Some("xterm-256color"));
+    assert_eq!(env.other["LANG"], "en_US.UTF-8");
+    assert_eq!(env.other["COLORTERM"], "truecolor");
+
+    // Nothing is added or dropped relative to the source header
+    let source: serde_json::Value = serde_json::from_str(original.lines().next().unwrap()).unwrap();
+    let written_header: serde_json::Value =
+        serde_json::from_str(written.lines().next().unwrap()).unwrap();
+    assert_eq!(written_header, source);
+}
+
 #[test]
 fn cumulative_times_calculated_correctly() {
     let cast = AsciicastFile::parse_str(sample_cast()).unwrap();
@@ -179,7 +292,8 @@ fn event_type_conversion() {
     assert_eq!(EventType::from_code("m"), Some(EventType::Marker));
     assert_eq!(EventType::from_code("r"), Some(EventType::Resize));
     assert_eq!(EventType::from_code("x"), Some(EventType::Exit));
-    assert_eq!(EventType::from_code("z"), None);
+    assert_eq!(EventType::from_code("z"), Some(EventType::Other('z')));
+    assert_eq!(EventType::from_code("zz"), None);
 
     assert_eq!(EventType::Output.to_code(), "o");
     assert_eq!(EventType::Marker.to_code(), "m");
@@ -193,3 +307,28 @@ fn rejects_non_v3_files() {
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("v3"));
 }
+
+// === Headless rendering ===
+
+#[test]
+fn render_at_end_produces_final_screen() {
+    let content = load_fixture("sample.cast");
+    let cast = AsciicastFile::parse_str(&content).unwrap();
+
+    let screen = cast.render_at(cast.duration(), 80, 24);
+
+    assert_eq!(screen.len(), 24);
+    assert_eq!(&screen[..3], &["$ echo hello", "hello", "$"]);
+    assert!(screen[3..].iter().all(|line| line.is_empty()));
+}
+
+#[test]
+fn render_at_mid_recording_excludes_later_output() {
+    let content = load_fixture("with_markers.cast");
+    let cast = AsciicastFile::parse_str(&content).unwrap();
+
+    let screen = cast.render_at(2.0, 80, 24);
+
+    assert_eq!(screen[0], "$ make build");
+    assert_eq!(screen[1], "");
+}
@@ -0,0 +1,109 @@
+//! Integration tests for non-interactive `agr cleanup --dry-run|--yes` (CLI)
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+const CAST: &str =
+    "{\"version\":3,\"term\":{\"cols\":80,\"rows\":24}}\n[0.5,\"o\",\"$ ls\\r\\n\"]\n";
+
+/// Helper to run agr CLI with an isolated HOME and capture output
+fn run_agr(home: &Path, args: &[&str]) -> (String, String, i32) {
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(args)
+        .env("HOME", home)
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+        output.status.code().unwrap_or(-1),
+    )
+}
+
+/// Write a recording into the default storage directory, `age_days` old.
+fn write_session(home: &Path, agent: &str, name: &str, age_days: i64) -> PathBuf {
+    let dir = home.join("recorded_agent_sessions").join(agent);
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, CAST).unwrap();
+    // `touch -t` is understood by both GNU and BSD touch
+    let modified = chrono::Local::now() - chrono::Duration::days(age_days);
+    let status = Command::new("touch")
+        .arg("-t")
+        .arg(modified.format("%Y%m%d%H%M").to_string())
+        .arg(&path)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    path
+}
+
+#[test]
+fn dry_run_lists_matches_without_deleting() {
+    let home = TempDir::new().unwrap();
+    let old = write_session(home.path(), "codex", "old.cast", 40);
+    let recent = write_session(home.path(), "codex", "recent.cast", 2);
+    let other = write_session(home.path(), "claude", "old.cast", 40);
+
+    let (stdout, stderr, exit_code) = run_agr(
+        home.path(),
+        &[
+            "cleanup",
+            "--older-than",
+            "30d",
+            "--agent",
+            "codex",
+            "--dry-run",
+        ],
+    );
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stdout.contains(&format!("Would delete 1 sessions ({} B):", CAST.len())),
+        "stdout: {}",
+        stdout
+    );
+    assert!(stdout.contains("codex/old.cast"), "stdout: {}", stdout);
+    assert!(!stdout.contains("recent.cast"), "stdout: {}", stdout);
+    assert!(!stdout.contains("claude/"), "stdout: {}", stdout);
+    assert!(old.exists() && recent.exists() && other.exists());
+}
+
+#[test]
+fn yes_deletes_matches_and_their_backups() {
+    let home = TempDir::new().unwrap();
+    let old = write_session(home.path(), "codex", "old.cast", 40);
+    let backup = old.with_extension("cast.bak");
+    std::fs::write(&backup, CAST).unwrap();
+    let recent = write_session(home.path(), "codex", "recent.cast", 2);
+
+    let (stdout, stderr, exit_code) =
+        run_agr(home.path(), &["cleanup", "--older-than", "30", "--yes"]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stdout.contains(&format!("Deleted 1 sessions (freed {} B).", CAST.len() * 2)),
+        "stdout: {}",
+        stdout
+    );
+    assert!(!old.exists());
+    assert!(!backup.exists());
+    assert!(recent.exists());
+}
+
+#[test]
+fn yes_without_filters_is_refused() {
+    let home = TempDir::new().unwrap();
+    let session = write_session(home.path(), "codex", "old.cast", 40);
+
+    let (_, stderr, exit_code) = run_agr(home.path(), &["cleanup", "--yes"]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("--yes needs"), "stderr: {}", stderr);
+    assert!(session.exists());
+}
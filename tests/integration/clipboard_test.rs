@@ -17,6 +17,8 @@ mod result_tests {
         assert_eq!(CopyMethod::Xclip.name(), "xclip");
         assert_eq!(CopyMethod::Xsel.name(), "xsel");
         assert_eq!(CopyMethod::WlCopy.name(), "wl-copy");
+        assert_eq!(CopyMethod::WinClip.name(), "clip");
+        assert_eq!(CopyMethod::Osc52.name(), "osc52");
     }
 
     #[test]
@@ -385,6 +387,35 @@ mod copy_tests {
         assert!(matches!(result, Err(ClipboardError::NoToolAvailable)));
     }
 
+    #[test]
+    fn text_copies_content_without_trying_file_copy() {
+        let tool = MockTool::new(CopyMethod::OsaScript).can_files(true);
+        let copy = Copy::with_tools(vec![Box::new(tool)]);
+
+        let result = copy.text("transcript").unwrap();
+
+        assert_eq!(
+            result,
+            CopyResult::ContentCopied {
+                tool: CopyMethod::OsaScript,
+                size_bytes: "transcript".len(),
+            }
+        );
+    }
+
+    #[test]
+    fn text_returns_no_tool_available_when_all_tools_fail() {
+        let failing = MockTool::new(CopyMethod::Xclip)
+            .text_result(Err(CopyToolError::Failed("fail".to_string())));
+
+        let copy = Copy::with_tools(vec![Box::new(failing)]);
+
+        assert!(matches!(
+            copy.text("transcript"),
+            Err(ClipboardError::NoToolAvailable)
+        ));
+    }
+
     #[test]
     fn file_tries_tools_in_order_first_available_wins() {
         // Use a static counter to track call order
@@ -682,6 +713,96 @@ mod wl_copy_tests {
 // Stage 5: Platform Selection & Public API Tests
 // =============================================================================
 
+mod win_clip_tests {
+    use agr::clipboard::tool::{CopyTool, CopyToolError};
+    use agr::clipboard::tools::WinClip;
+    use agr::clipboard::CopyMethod;
+    use std::path::Path;
+
+    #[test]
+    fn method_returns_winclip() {
+        let tool = WinClip::new();
+        assert_eq!(tool.method(), CopyMethod::WinClip);
+    }
+
+    #[test]
+    #[cfg(target_os = "windows")]
+    fn is_available_returns_true_on_windows() {
+        let tool = WinClip::new();
+        assert!(tool.is_available());
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn is_available_returns_false_on_non_windows() {
+        let tool = WinClip::new();
+        assert!(!tool.is_available());
+    }
+
+    #[test]
+    fn can_copy_files_returns_false() {
+        let tool = WinClip::new();
+        assert!(!tool.can_copy_files());
+    }
+
+    #[test]
+    fn try_copy_file_returns_not_supported() {
+        let tool = WinClip::new();
+        let result = tool.try_copy_file(Path::new("C:\\some\\file.cast"));
+        assert!(matches!(result, Err(CopyToolError::NotSupported)));
+    }
+
+    #[test]
+    fn encode_utf16le_adds_bom_and_encodes_units() {
+        assert_eq!(
+            WinClip::encode_utf16le("Aé"),
+            vec![0xFF, 0xFE, 0x41, 0x00, 0xE9, 0x00]
+        );
+    }
+}
+
+mod osc52_tests {
+    use agr::clipboard::tool::{CopyTool, CopyToolError};
+    use agr::clipboard::tools::Osc52;
+    use agr::clipboard::CopyMethod;
+    use std::path::Path;
+
+    #[test]
+    fn method_returns_osc52() {
+        let tool = Osc52::new();
+        assert_eq!(tool.method(), CopyMethod::Osc52);
+    }
+
+    #[test]
+    fn can_copy_files_returns_false() {
+        let tool = Osc52::new();
+        assert!(!tool.can_copy_files());
+    }
+
+    #[test]
+    fn try_copy_file_returns_not_supported() {
+        let tool = Osc52::new();
+        let result = tool.try_copy_file(Path::new("/some/file.cast"));
+        assert!(matches!(result, Err(CopyToolError::NotSupported)));
+    }
+
+    #[test]
+    fn escape_sequence_encodes_payload() {
+        assert_eq!(
+            Osc52::escape_sequence("hello", false),
+            "\x1b]52;c;aGVsbG8=\x07"
+        );
+    }
+
+    #[test]
+    fn escape_sequence_wraps_for_tmux() {
+        assert_eq!(
+            Osc52::escape_sequence("hello", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGVsbG8=\x07\x1b\\"
+        );
+    }
+}
+
 mod platform_tools_tests {
     use agr::clipboard::tools::platform_tools;
 
@@ -689,33 +810,45 @@ mod platform_tools_tests {
     #[cfg(target_os = "macos")]
     fn platform_tools_returns_osascript_and_pbcopy_on_macos() {
         let tools = platform_tools();
-        assert_eq!(tools.len(), 2);
+        assert_eq!(tools.len(), 3);
         assert_eq!(tools[0].name(), "osascript");
         assert_eq!(tools[1].name(), "pbcopy");
+        assert_eq!(tools[2].name(), "osc52");
     }
 
     #[test]
     #[cfg(target_os = "linux")]
     fn platform_tools_returns_xclip_xsel_wlcopy_on_linux() {
         let tools = platform_tools();
-        assert_eq!(tools.len(), 3);
+        assert_eq!(tools.len(), 4);
         assert_eq!(tools[0].name(), "xclip");
         assert_eq!(tools[1].name(), "xsel");
         assert_eq!(tools[2].name(), "wl-copy");
+        assert_eq!(tools[3].name(), "osc52");
     }
 
     #[test]
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-    fn platform_tools_returns_empty_on_other_platforms() {
+    #[cfg(target_os = "windows")]
+    fn platform_tools_returns_winclip_on_windows() {
         let tools = platform_tools();
-        assert!(tools.is_empty());
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[0].name(), "clip");
+        assert_eq!(tools[1].name(), "osc52");
+    }
+
+    #[test]
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    fn platform_tools_returns_osc52_on_other_platforms() {
+        let tools = platform_tools();
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name(), "osc52");
     }
 }
 
 mod public_api_tests {
     use agr::clipboard::{copy_file_to_clipboard, ClipboardError};
     use std::path::Path;
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
     use tempfile::NamedTempFile;
 
     #[test]
@@ -733,4 +866,16 @@ mod public_api_tests {
         let result = copy_file_to_clipboard(temp.path());
         assert!(result.is_ok());
     }
+    #[test]
+    #[cfg(target_os = "windows")]
+    fn copy_file_to_clipboard_succeeds_or_fails_gracefully_on_windows() {
+        let temp = NamedTempFile::new().unwrap();
+        std::fs::write(temp.path(), "test content").unwrap();
+
+        // clip.exe may be missing in minimal environments; that must surface as an error, not a panic
+        match copy_file_to_clipboard(temp.path()) {
+            Ok(result) => assert!(!result.is_file_copy()),
+            Err(err) => assert!(matches!(err, ClipboardError::NoToolAvailable)),
+        }
+    }
 }
@@ -0,0 +1,331 @@
+//! Integration tests for `agr config get` / `agr config set` (CLI)
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Helper to run agr CLI with an isolated HOME and capture output
+fn run_agr(home: &Path, args: &[&str]) -> (String, String, i32) {
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(args)
+        .env("HOME", home)
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let exit_code = output.status.code().unwrap_or(-1);
+
+    (stdout, stderr, exit_code)
+}
+
+fn config_file(home: &Path) -> std::path::PathBuf {
+    home.join(".config").join("agr").join("config.toml")
+}
+
+fn write_config(home: &Path, content: &str) {
+    let path = config_file(home);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+// ============================================================================
+// config get
+// ============================================================================
+
+#[test]
+fn config_get_prints_default_without_config_file() {
+    let home = TempDir::new().unwrap();
+    let (stdout, _stderr, exit_code) = run_agr(home.path(), &["config", "get", "analysis.timeout"]);
+
+    assert_eq!(exit_code, 0);
+    assert_eq!(stdout.trim(), "120");
+}
+
+#[test]
+fn config_get_reads_value_from_file() {
+    let home = TempDir::new().unwrap();
+    write_config(home.path(), "[tui]\ntheme = \"ocean\"\n");
+
+    let (stdout, _stderr, exit_code) = run_agr(home.path(), &["config", "get", "tui.theme"]);
+
+    assert_eq!(exit_code, 0);
+    assert_eq!(stdout.trim(), "ocean");
+}
+
+#[test]
+fn config_get_unknown_key_exits_nonzero() {
+    let home = TempDir::new().unwrap();
+    let (_stdout, stderr, exit_code) = run_agr(home.path(), &["config", "get", "analysis.nope"]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Unknown config key 'analysis.nope'"));
+}
+
+// ============================================================================
+// config set
+// ============================================================================
+
+#[test]
+fn config_set_then_get_roundtrips() {
+    let home = TempDir::new().unwrap();
+    let (_stdout, _stderr, exit_code) =
+        run_agr(home.path(), &["config", "set", "analysis.timeout", "300"]);
+    assert_eq!(exit_code, 0);
+
+    let (stdout, _stderr, exit_code) = run_agr(home.path(), &["config", "get", "analysis.timeout"]);
+    assert_eq!(exit_code, 0);
+    assert_eq!(stdout.trim(), "300");
+}
+
+#[test]
+fn config_set_preserves_comments_and_unrelated_keys() {
+    let home = TempDir::new().unwrap();
+    write_config(
+        home.path(),
+        "# personal settings\n[storage]\ndirectory = \"~/casts\" # keep\n\n[analysis]\ntimeout = 120\n",
+    );
+
+    let (_stdout, _stderr, exit_code) =
+        run_agr(home.path(), &["config", "set", "analysis.timeout", "90"]);
+    assert_eq!(exit_code, 0);
+
+    let content = fs::read_to_string(config_file(home.path())).unwrap();
+    assert_eq!(
+        content,
+        "# personal settings\n[storage]\ndirectory = \"~/casts\" # keep\n\n[analysis]\ntimeout = 90\n"
+    );
+}
+
+#[test]
+fn config_set_type_mismatch_exits_nonzero_and_keeps_file() {
+    let home = TempDir::new().unwrap();
+    write_config(home.path(), "[analysis]\ntimeout = 120\n");
+
+    let (_stdout, stderr, exit_code) =
+        run_agr(home.path(), &["config", "set", "analysis.timeout", "soon"]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Invalid value 'soon' for analysis.timeout"));
+    let content = fs::read_to_string(config_file(home.path())).unwrap();
+    assert_eq!(content, "[analysis]\ntimeout = 120\n");
+}
+
+#[test]
+fn config_set_out_of_range_value_exits_nonzero() {
+    let home = TempDir::new().unwrap();
+    let (_stdout, stderr, exit_code) =
+        run_agr(home.path(), &["config", "set", "analysis.timeout", "0"]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("analysis.timeout must be > 0"));
+    assert!(!config_file(home.path()).exists());
+}
+
+#[test]
+fn config_set_unknown_key_exits_nonzero() {
+    let home = TempDir::new().unwrap();
+    let (_stdout, stderr, exit_code) =
+        run_agr(home.path(), &["config", "set", "storage.dir", "/tmp"]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Unknown config key 'storage.dir'"));
+}
+
+// ============================================================================
+// config validate
+// ============================================================================
+
+fn validate_output(home: &Path) -> String {
+    let (stdout, stderr, exit_code) = run_agr(home, &["config", "validate"]);
+    format!(
+        "=== agr config validate ===\nExit code: {}\n\n--- stdout ---\n{}\n--- stderr ---\n{}",
+        exit_code, stdout, stderr
+    )
+}
+
+#[test]
+fn snapshot_config_validate_ok() {
+    let home = TempDir::new().unwrap();
+    write_config(home.path(), "[analysis]\ntimeout = 300\n");
+
+    insta::assert_snapshot!("config_validate_ok", validate_output(home.path()));
+}
+
+#[test]
+fn snapshot_config_validate_bad_filename_template() {
+    let home = TempDir::new().unwrap();
+    write_config(
+        home.path(),
+        "[recording]\nfilename_template = \"{directory}_{nonsense}\"\n",
+    );
+
+    insta::assert_snapshot!(
+        "config_validate_bad_filename_template",
+        validate_output(home.path())
+    );
+}
+
+#[test]
+fn config_validate_reports_low_token_budget() {
+    let home = TempDir::new().unwrap();
+    write_config(home.path(), "[agents.claude]\ntoken_budget = 10\n");
+
+    let (_stdout, stderr, exit_code) = run_agr(home.path(), &["config", "validate"]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("agents.claude.token_budget 10 is below minimum (1000)"));
+}
+
+// ============================================================================
+// AGR_* environment overrides
+// ============================================================================
+
+#[test]
+fn env_override_changes_effective_agent() {
+    let home = TempDir::new().unwrap();
+    write_config(home.path(), "[analysis]\nagent = \"codex\"\n");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(["config", "get", "analysis.agent"])
+        .env("HOME", home.path())
+        .env("AGR_ANALYSIS__AGENT", "claude")
+        .output()
+        .expect("Failed to execute agr");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "claude");
+}
+
+#[test]
+fn env_override_is_not_persisted_by_config_set() {
+    let home = TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(["config", "set", "analysis.fast", "true"])
+        .env("HOME", home.path())
+        .env("AGR_ANALYSIS__TIMEOUT", "60")
+        .output()
+        .expect("Failed to execute agr");
+
+    assert!(output.status.success());
+    let content = fs::read_to_string(config_file(home.path())).unwrap();
+    assert!(!content.contains("timeout"));
+}
+
+#[test]
+fn env_override_unknown_key_warns_and_continues() {
+    let home = TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(["config", "get", "analysis.timeout"])
+        .env("HOME", home.path())
+        .env("AGR_ANALYSIS__TIMEOT", "60")
+        .output()
+        .expect("Failed to execute agr");
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Warning: ignoring AGR_ANALYSIS__TIMEOT"));
+}
+
+#[test]
+fn env_override_invalid_value_exits_nonzero() {
+    let home = TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(["config", "validate"])
+        .env("HOME", home.path())
+        .env("AGR_ANALYSIS__TIMEOUT", "0")
+        .output()
+        .expect("Failed to execute agr");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("analysis.timeout must be > 0"));
+}
+
+// ============================================================================
+// Project .agr.toml discovery
+// ============================================================================
+
+#[test]
+fn project_config_overrides_user_config_from_subdirectory() {
+    let home = TempDir::new().unwrap();
+    write_config(
+        home.path(),
+        "[analysis]\nagent = \"codex\"\ntimeout = 300\n",
+    );
+    let project = TempDir::new().unwrap();
+    let subdir = project.path().join("packages").join("cli");
+    fs::create_dir_all(&subdir).unwrap();
+    fs::write(
+        project.path().join(".agr.toml"),
+        "[analysis]\nagent = \"gemini\"\n",
+    )
+    .unwrap();
+
+    let get = |key: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+            .args(["config", "get", key])
+            .current_dir(&subdir)
+            .env("HOME", home.path())
+            .output()
+            .expect("Failed to execute agr");
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    };
+
+    assert_eq!(get("analysis.agent"), "gemini");
+    assert_eq!(get("analysis.timeout"), "300");
+}
+
+#[test]
+fn project_config_cannot_set_agent_arguments() {
+    let home = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    fs::write(
+        project.path().join(".agr.toml"),
+        "[analysis]\nagent = \"gemini\"\n\n[agents.gemini]\nanalyze_extra_args = [\"--yolo\"]\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(["config", "get", "agents.gemini.analyze_extra_args"])
+        .current_dir(project.path())
+        .env("HOME", home.path())
+        .output()
+        .expect("Failed to execute agr");
+
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("--yolo"));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("ignoring agents.gemini.analyze_extra_args"));
+}
+
+// ============================================================================
+// Color output
+// ============================================================================
+
+#[test]
+fn config_show_with_no_color_has_no_escape_codes() {
+    let home = TempDir::new().unwrap();
+    let (stdout, _stderr, exit_code) = run_agr(home.path(), &["config", "show"]);
+
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("[storage]"));
+    assert!(
+        !stdout.contains('\x1b'),
+        "unexpected escape codes: {stdout:?}"
+    );
+}
+
+#[test]
+fn config_show_color_always_overrides_no_color() {
+    let home = TempDir::new().unwrap();
+    let (stdout, _stderr, exit_code) =
+        run_agr(home.path(), &["config", "show", "--color", "always"]);
+
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains('\x1b'));
+}
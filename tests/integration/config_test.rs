@@ -184,6 +184,118 @@ timeout = 60
     assert_eq!(config.analysis.agent, None);
 }
 
+#[test]
+fn analysis_prompt_template_loads_when_it_has_content_placeholder() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("prompt.txt");
+    std::fs::write(&path, "Find security issues in {duration}s:\n{content}").unwrap();
+
+    let mut config = Config::default();
+    config.analysis.prompt_template = Some(path);
+
+    assert!(config.analysis.validate().is_ok());
+    let template = config.analysis.load_prompt_template().unwrap().unwrap();
+    assert!(template.starts_with("Find security issues"));
+}
+
+#[test]
+fn analysis_prompt_template_accepts_cleaned_content_placeholder() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("prompt.txt");
+    std::fs::write(&path, "Copied from the built-in prompt:\n{cleaned_content}").unwrap();
+
+    let mut config = Config::default();
+    config.analysis.prompt_template = Some(path);
+
+    assert!(config.analysis.validate().is_ok());
+}
+
+#[test]
+fn analysis_prompt_template_without_content_placeholder_is_invalid() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("prompt.txt");
+    std::fs::write(&path, "Find security issues in {duration}s").unwrap();
+
+    let mut config = Config::default();
+    config.analysis.prompt_template = Some(path);
+
+    let err = config.analysis.validate().unwrap_err();
+    assert!(err.contains("{content}"));
+}
+
+#[test]
+fn analysis_prompt_template_missing_file_is_invalid() {
+    let mut config = Config::default();
+    config.analysis.prompt_template = Some("/nonexistent/agr/prompt.txt".into());
+
+    let err = config.analysis.validate().unwrap_err();
+    assert!(err.contains("could not be read"));
+}
+
+#[test]
+fn agent_prompt_template_overrides_global_for_that_agent_only() {
+    let dir = tempfile::tempdir().unwrap();
+    let global = dir.path().join("global.txt");
+    let codex = dir.path().join("codex.txt");
+    std::fs::write(&global, "Global prompt:\n{content}").unwrap();
+    std::fs::write(&codex, "Codex prompt:\n{content}").unwrap();
+
+    let mut config = Config::default();
+    config.analysis.prompt_template = Some(global);
+    config.agents.codex.prompt_template = Some(codex);
+    assert!(config.validate().is_ok());
+
+    let for_codex = config
+        .analysis
+        .load_prompt_template_for("codex", config.analysis_agent_config("codex"))
+        .unwrap()
+        .unwrap();
+    assert!(for_codex.starts_with("Codex prompt"));
+
+    let for_claude = config
+        .analysis
+        .load_prompt_template_for("claude", config.analysis_agent_config("claude"))
+        .unwrap()
+        .unwrap();
+    assert!(for_claude.starts_with("Global prompt"));
+}
+
+#[test]
+fn agent_prompt_template_without_content_placeholder_is_invalid() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("codex.txt");
+    std::fs::write(&path, "No placeholder here").unwrap();
+
+    let mut config = Config::default();
+    config.agents.codex.prompt_template = Some(path);
+
+    let err = config.validate().unwrap_err();
+    assert!(err.starts_with("agents.codex.prompt_template"));
+    assert!(err.contains("{content}"));
+}
+
+#[test]
+fn analysis_retry_config_parses_and_validates() {
+    let toml_str = r#"
+[analysis]
+retry_attempts = 5
+retry_delay_ms = 250
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.analysis.retry_attempts, Some(5));
+    assert_eq!(config.analysis.retry_delay_ms, Some(250));
+    assert_eq!(config.analysis.retry_max_delay_ms, Some(60000));
+    assert!(config.analysis.validate().is_ok());
+
+    let mut config = Config::default();
+    config.analysis.retry_attempts = Some(0);
+    assert!(config.analysis.validate().is_err());
+
+    let mut config = Config::default();
+    config.analysis.retry_delay_ms = Some(120_000);
+    assert!(config.analysis.validate().is_err());
+}
+
 #[test]
 fn recording_config_defaults_when_missing() {
     let toml_str = r#"
@@ -243,3 +355,80 @@ fn storage_directory_handles_relative_path() {
     let path = config.storage_directory();
     assert_eq!(path, std::path::PathBuf::from("relative/path"));
 }
+
+#[test]
+fn default_config_validates() {
+    assert!(Config::default().validate().is_ok());
+}
+
+#[test]
+fn validate_rejects_bad_filename_template() {
+    let mut config = Config::default();
+    config.recording.filename_template = "{directory}_{bogus}".to_string();
+    let err = config.validate().unwrap_err();
+    assert!(err.starts_with("recording.filename_template:"));
+}
+
+#[test]
+fn validate_rejects_missing_script_path() {
+    let mut config = Config::default();
+    config.shell.script_path = Some("/nonexistent/agr/shell.sh".into());
+    let err = config.validate().unwrap_err();
+    assert!(err.contains("shell.script_path"));
+}
+
+#[test]
+fn validate_rejects_zero_backup_limit() {
+    let mut config = Config::default();
+    config.storage.backup_limit = 0;
+    let err = config.validate().unwrap_err();
+    assert!(err.starts_with("storage.backup_limit"));
+}
+
+#[test]
+fn validate_rejects_out_of_range_player_speed() {
+    let mut config = Config::default();
+    config.player.speed = 0.0;
+    let err = config.validate().unwrap_err();
+    assert!(err.starts_with("player.speed"));
+}
+
+#[test]
+fn player_section_parses_from_toml() {
+    let config: Config =
+        toml::from_str("[player]\nspeed = 2.0\nloop_playback = true\nskip_idle = 1.5\n").unwrap();
+    assert_eq!(config.player.speed, 2.0);
+    assert!(config.player.loop_playback);
+    assert!(!config.player.viewport_mode);
+    assert_eq!(config.player.skip_idle, Some(1.5));
+}
+
+#[test]
+fn load_from_merges_nested_project_config() {
+    let root = tempfile::TempDir::new().unwrap();
+    let nested = root.path().join("services").join("api");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(
+        root.path().join("services").join(".agr.toml"),
+        "[analysis]\nagent = \"gemini\"\n\n[agents]\nno_wrap = [\"codex\"]\n",
+    )
+    .unwrap();
+
+    let user = Config::load_file().unwrap();
+    let config = Config::load_from(&nested).unwrap();
+
+    assert_eq!(config.analysis.agent.as_deref(), Some("gemini"));
+    assert_eq!(config.agents.no_wrap, vec!["codex"]);
+    // Fields absent from the project file inherit from the user config
+    assert_eq!(config.agents.enabled, user.agents.enabled);
+    assert_eq!(config.storage.directory, user.storage.directory);
+}
+
+#[test]
+fn load_from_rejects_invalid_project_config() {
+    let root = tempfile::TempDir::new().unwrap();
+    std::fs::write(root.path().join(".agr.toml"), "[analysis]\ntimeout = 0\n").unwrap();
+
+    let err = Config::load_from(root.path()).unwrap_err();
+    assert!(err.to_string().contains("analysis.timeout must be > 0"));
+}
@@ -0,0 +1,46 @@
+//! Integration tests for agent detection on recorded sessions
+
+use agr::analyzer::detect_agent;
+use agr::asciicast::AsciicastFile;
+
+fn detect_fixture(name: &str) -> Option<String> {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    detect_agent(&AsciicastFile::parse(path).unwrap())
+}
+
+#[test]
+fn detects_claude_from_output() {
+    assert_eq!(
+        detect_fixture("agents/claude.cast").as_deref(),
+        Some("claude")
+    );
+}
+
+#[test]
+fn detects_codex_from_output() {
+    assert_eq!(
+        detect_fixture("agents/codex.cast").as_deref(),
+        Some("codex")
+    );
+}
+
+#[test]
+fn detects_gemini_from_output() {
+    assert_eq!(
+        detect_fixture("agents/gemini.cast").as_deref(),
+        Some("gemini")
+    );
+}
+
+#[test]
+fn detects_agent_from_recorder_header() {
+    assert_eq!(
+        detect_fixture("resize_stress_excerpt.cast").as_deref(),
+        Some("claude")
+    );
+}
+
+#[test]
+fn plain_recording_has_no_agent() {
+    assert_eq!(detect_fixture("sample.cast"), None);
+}
@@ -0,0 +1,92 @@
+//! Integration tests for `agr diff` (CLI)
+
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Run `agr diff` with paths relative to the fixtures directory.
+fn run_diff(args: &[&str]) -> (String, String, i32) {
+    let home = TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .arg("diff")
+        .args(args)
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))
+        .env("HOME", home.path())
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+        output.status.code().unwrap_or(-1),
+    )
+}
+
+#[test]
+fn diff_shows_changed_transcript_lines() {
+    let (stdout, stderr, exit_code) = run_diff(&["diff_a.cast", "diff_b.cast"]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stdout.starts_with("--- diff_a.cast\n+++ diff_b.cast\n"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("\n-test render ... ok\n"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("\n+test render ... FAILED\n"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("\n test parse ... ok\n"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(!stdout.contains("Tests passed"), "stdout: {}", stdout);
+}
+
+#[test]
+fn diff_markers_only_compares_marker_lists() {
+    let (stdout, stderr, exit_code) = run_diff(&["--markers-only", "diff_a.cast", "diff_b.cast"]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stdout.contains("\n 1.5s: Tests started\n"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("\n-4.7s: Tests passed\n"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("\n+4.7s: Tests failed\n"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(!stdout.contains("render"), "stdout: {}", stdout);
+}
+
+#[test]
+fn diff_of_identical_recordings_reports_no_differences() {
+    let (stdout, stderr, exit_code) = run_diff(&["diff_a.cast", "diff_a.cast"]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert_eq!(stdout.trim(), "No differences.");
+}
+
+#[test]
+fn diff_missing_file_fails() {
+    let (_, stderr, exit_code) = run_diff(&["diff_a.cast", "missing.cast"]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("missing.cast"), "stderr: {}", stderr);
+}
@@ -0,0 +1,50 @@
+//! Integration tests for `agr doctor` (CLI)
+
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Run `agr doctor` with no agents reachable: an empty PATH and no API key.
+fn run_doctor_without_agents() -> (String, String, i32) {
+    let home = TempDir::new().unwrap();
+    let empty_path = TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(["doctor", "--timeout", "1"])
+        .env("HOME", home.path())
+        .env("PATH", empty_path.path())
+        .env("NO_COLOR", "1")
+        .env_remove("OPENAI_API_KEY")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+        output.status.code().unwrap_or(-1),
+    )
+}
+
+#[test]
+fn doctor_lists_every_agent_as_unavailable() {
+    let (stdout, _, _) = run_doctor_without_agents();
+    assert!(stdout.starts_with("AGENT"), "stdout: {}", stdout);
+    for agent in ["claude (default)", "codex", "gemini", "openai"] {
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with(agent))
+            .unwrap_or_else(|| panic!("missing row for {}: {}", agent, stdout));
+        assert!(row.contains("no"), "row: {}", row);
+    }
+}
+
+#[test]
+fn doctor_fails_when_default_agent_is_unusable() {
+    let (_, stderr, exit_code) = run_doctor_without_agents();
+    assert_eq!(exit_code, 1);
+    assert!(
+        stderr.contains("Analysis agent 'claude' is not installed"),
+        "stderr: {}",
+        stderr
+    );
+}
@@ -0,0 +1,106 @@
+//! Integration tests for the export command (CLI)
+
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::load_fixture;
+
+/// Helper to run agr CLI and capture output
+fn run_agr(args: &[&str]) -> (String, String, i32) {
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(args)
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let exit_code = output.status.code().unwrap_or(-1);
+
+    (stdout, stderr, exit_code)
+}
+
+/// Write the sample fixture into a temp dir and return its path
+fn sample_cast(temp_dir: &TempDir) -> String {
+    let cast_path = temp_dir.path().join("session.cast");
+    std::fs::write(&cast_path, load_fixture("sample.cast")).unwrap();
+    cast_path.to_string_lossy().to_string()
+}
+
+#[test]
+fn snapshot_cli_help_export() {
+    let (stdout, stderr, exit_code) = run_agr(&["export", "--help"]);
+    let output = format!(
+        "=== agr export --help ===\nExit code: {}\n\n--- stdout ---\n{}\n--- stderr ---\n{}",
+        exit_code, stdout, stderr
+    );
+    insta::assert_snapshot!("cli_help_export", output);
+}
+
+#[test]
+fn export_nonexistent_file_shows_error() {
+    let (_stdout, stderr, exit_code) = run_agr(&["export", "/some/path/to/missing.cast"]);
+
+    assert_eq!(exit_code, 1);
+    assert!(stderr.contains("File not found"));
+}
+
+#[test]
+fn export_rejects_unknown_format() {
+    let (_stdout, stderr, exit_code) = run_agr(&["export", "--format", "mp4", "x.cast"]);
+
+    assert_eq!(exit_code, 2);
+    assert!(stderr.contains("invalid value 'mp4'"));
+}
+
+#[test]
+#[cfg(not(feature = "gif-export"))]
+fn export_gif_without_feature_explains_how_to_enable() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast = sample_cast(&temp_dir);
+
+    let (_stdout, stderr, exit_code) = run_agr(&["export", &cast]);
+
+    assert_eq!(exit_code, 1);
+    assert!(
+        stderr.contains("--features gif-export"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(!temp_dir.path().join("session.gif").exists());
+}
+
+#[test]
+#[cfg(feature = "gif-export")]
+fn export_gif_writes_file_next_to_recording() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast = sample_cast(&temp_dir);
+
+    let (stdout, stderr, exit_code) = run_agr(&["export", "--fps", "5", &cast]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Exported"));
+    let gif = std::fs::read(temp_dir.path().join("session.gif")).unwrap();
+    assert!(gif.starts_with(b"GIF89a"));
+}
+
+#[test]
+#[cfg(feature = "gif-export")]
+fn export_gif_failure_leaves_no_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = temp_dir.path().join("huge.cast");
+    std::fs::write(
+        &cast_path,
+        "{\"version\":3,\"term\":{\"cols\":10000,\"rows\":24}}\n[0.1,\"o\",\"hi\"]\n",
+    )
+    .unwrap();
+
+    let (_stdout, stderr, exit_code) = run_agr(&["export", &cast_path.to_string_lossy()]);
+
+    assert_eq!(exit_code, 1);
+    assert!(stderr.contains("too large for a GIF"), "stderr: {}", stderr);
+    assert!(!temp_dir.path().join("huge.gif").exists());
+    assert!(!temp_dir.path().join("huge.gif.tmp").exists());
+}
@@ -2,7 +2,10 @@
 //!
 //! These tests are written BEFORE implementation (TDD approach).
 
-use agr::files::filename::{self, Config, FilenameError, Template, TemplateError};
+use agr::files::filename::{
+    self, Config, FilenameError, Segment, Template, TemplateError, TemplateValues,
+};
+use std::path::Path;
 
 // ============================================================================
 // Space Replacement Tests
@@ -135,6 +138,66 @@ fn sanitize_handles_mixed_unicode_and_ascii() {
     assert_eq!(result, "my-project_v2");
 }
 
+#[test]
+fn sanitize_transliterates_japanese_by_default() {
+    let config = Config::default();
+    let result = filename::sanitize("日本語プロジェクト", &config);
+    assert!(result.is_ascii());
+    assert!(!result.is_empty());
+}
+
+#[test]
+fn sanitize_preserve_unicode_keeps_japanese() {
+    let config = Config::default().with_preserve_unicode(true);
+    assert_eq!(
+        filename::sanitize("日本語プロジェクト", &config),
+        "日本語プロジェクト"
+    );
+}
+
+#[test]
+fn sanitize_preserve_unicode_still_strips_invalid_and_control_chars() {
+    let config = Config::default().with_preserve_unicode(true);
+    assert_eq!(
+        filename::sanitize("日本/語:\u{7}プロ ジェクト", &config),
+        "日本語プロ-ジェクト"
+    );
+}
+
+#[test]
+fn sanitize_directory_preserve_unicode_truncates_by_chars() {
+    let config = Config {
+        directory_max_length: 3,
+        ..Config::default()
+    }
+    .with_preserve_unicode(true);
+    assert_eq!(
+        filename::sanitize_directory("日本語プロジェクト", &config),
+        "日本語"
+    );
+}
+
+#[test]
+fn generate_preserve_unicode_japanese_directory() {
+    let config = Config::default().with_preserve_unicode(true);
+    let result = filename::generate(
+        "日本語 プロジェクト",
+        "claude",
+        "{directory}",
+        &config,
+        Path::new("."),
+    )
+    .unwrap();
+    assert_eq!(result, "日本語-プロジェクト.cast");
+}
+
+#[test]
+fn sanitize_uses_configured_fallback_name() {
+    let config = Config::default().with_fallback_name("session");
+    assert_eq!(filename::sanitize("///", &config), "session");
+    assert_eq!(filename::sanitize("///", &Config::default()), "recording");
+}
+
 // ============================================================================
 // Leading/Trailing Trimming Tests
 // ============================================================================
@@ -298,6 +361,7 @@ fn sanitize_directory_truncates_to_max_length() {
     // Final hard truncation to 10 chars: "t-i-a-v-l-"
     let config = Config {
         directory_max_length: 10,
+        ..Config::default()
     };
     let long_name = "this-is-a-very-long-directory-name";
     let result = filename::sanitize_directory(long_name, &config);
@@ -315,6 +379,7 @@ fn sanitize_directory_truncates_to_max_length() {
 fn sanitize_directory_preserves_short_names() {
     let config = Config {
         directory_max_length: 50,
+        ..Config::default()
     };
     let result = filename::sanitize_directory("short", &config);
     assert_eq!(result, "short");
@@ -324,6 +389,7 @@ fn sanitize_directory_preserves_short_names() {
 fn sanitize_directory_truncates_after_sanitization() {
     let config = Config {
         directory_max_length: 10,
+        ..Config::default()
     };
     // Spaces become hyphens, then truncate
     let result = filename::sanitize_directory("my long project name", &config);
@@ -482,6 +548,34 @@ fn template_parse_time_tag_custom_format() {
     assert_eq!(template.segments().len(), 1);
 }
 
+#[test]
+fn template_parse_agent_tag() {
+    let template = Template::parse("{agent}").unwrap();
+    assert_eq!(template.segments(), &[Segment::Agent]);
+}
+
+#[test]
+fn template_parse_hostname_tag() {
+    let template = Template::parse("{hostname}").unwrap();
+    assert_eq!(template.segments(), &[Segment::Hostname]);
+}
+
+#[test]
+fn template_parse_counter_tag() {
+    let template = Template::parse("{directory}-{counter}").unwrap();
+    assert!(template.has_counter());
+    assert_eq!(template.segments()[2], Segment::Counter);
+    assert!(!Template::parse("{directory}").unwrap().has_counter());
+}
+
+#[test]
+fn template_parse_new_tags_reject_format() {
+    for tag in ["{agent:x}", "{hostname:x}", "{counter:3}"] {
+        let result = Template::parse(tag);
+        assert!(matches!(result, Err(TemplateError::InvalidFormat(_))));
+    }
+}
+
 #[test]
 fn template_parse_mixed_tags_and_literals() {
     // Default template: {directory}_{date:%y%m%d}_{time:%H%M}
@@ -608,6 +702,7 @@ fn template_render_directory_truncated() {
     let template = Template::parse("{directory}").unwrap();
     let config = Config {
         directory_max_length: 10,
+        ..Config::default()
     };
     // "very-long-directory-name" = 24 chars, limit 10
     // After first syllable: "very-long-dir-nam" = 17 chars
@@ -659,6 +754,46 @@ fn template_render_full_default_template() {
     assert!(result.contains('_'));
 }
 
+#[test]
+fn template_render_agent_tag() {
+    let template = Template::parse("{agent}_{directory}").unwrap();
+    let config = Config::default();
+    let values = TemplateValues::new("my-project").agent("claude");
+    assert_eq!(template.render_with(&values, &config), "claude_my-project");
+}
+
+#[test]
+fn template_render_agent_tag_without_agent() {
+    let template = Template::parse("{agent}").unwrap();
+    let config = Config::default();
+    assert_eq!(template.render("dir", &config), "unknown");
+}
+
+#[test]
+fn template_render_hostname_tag() {
+    let template = Template::parse("{hostname}-{directory}").unwrap();
+    let config = Config::default();
+    let values = TemplateValues::new("dir").hostname("my host");
+    assert_eq!(template.render_with(&values, &config), "my-host-dir");
+}
+
+#[test]
+fn template_render_hostname_tag_uses_system_hostname() {
+    let template = Template::parse("{hostname}").unwrap();
+    let config = Config::default();
+    let result = template.render("dir", &config);
+    assert!(!result.is_empty());
+    assert!(!result.contains('/'));
+}
+
+#[test]
+fn template_render_counter_tag() {
+    let template = Template::parse("{directory}_{counter}").unwrap();
+    let config = Config::default();
+    let values = TemplateValues::new("dir").counter(7);
+    assert_eq!(template.render_with(&values, &config), "dir_7");
+}
+
 #[test]
 fn template_render_preserves_literal_separators() {
     let template = Template::parse("{directory}--{date}").unwrap();
@@ -674,21 +809,36 @@ fn template_render_preserves_literal_separators() {
 #[test]
 fn generate_returns_filename_with_cast_extension() {
     let config = Config::default();
-    let result = filename::generate("my-project", "{directory}", &config).unwrap();
+    let result = filename::generate(
+        "my-project",
+        "claude",
+        "{directory}",
+        &config,
+        Path::new("."),
+    )
+    .unwrap();
     assert!(result.ends_with(".cast"));
 }
 
 #[test]
 fn generate_uses_template() {
     let config = Config::default();
-    let result = filename::generate("test-dir", "{directory}", &config).unwrap();
+    let result =
+        filename::generate("test-dir", "claude", "{directory}", &config, Path::new(".")).unwrap();
     assert_eq!(result, "test-dir.cast");
 }
 
 #[test]
 fn generate_sanitizes_directory() {
     let config = Config::default();
-    let result = filename::generate("My Project", "{directory}", &config).unwrap();
+    let result = filename::generate(
+        "My Project",
+        "claude",
+        "{directory}",
+        &config,
+        Path::new("."),
+    )
+    .unwrap();
     assert_eq!(result, "My-Project.cast");
 }
 
@@ -697,8 +847,10 @@ fn generate_with_default_template() {
     let config = Config::default();
     let result = filename::generate(
         "my-project",
+        "claude",
         "{directory}_{date:%y%m%d}_{time:%H%M}",
         &config,
+        Path::new("."),
     )
     .unwrap();
     assert!(result.starts_with("my-project_"));
@@ -709,18 +861,177 @@ fn generate_with_default_template() {
 fn generate_validates_final_length() {
     let config = Config {
         directory_max_length: 300, // Allow long directory
+        ..Config::default()
     };
     // Create a template that would produce a very long filename
     let long_dir = "a".repeat(260);
-    let result = filename::generate(&long_dir, "{directory}", &config);
+    let result = filename::generate(&long_dir, "claude", "{directory}", &config, Path::new("."));
     // Should fail because final filename > 255 chars
     assert!(result.is_err());
 }
 
+#[test]
+fn generate_fills_agent_tag() {
+    let config = Config::default();
+    let result = filename::generate(
+        "dir",
+        "codex",
+        "{agent}-{directory}",
+        &config,
+        Path::new("."),
+    )
+    .unwrap();
+    assert_eq!(result, "codex-dir.cast");
+}
+
+#[test]
+fn generate_counter_starts_at_one_in_empty_dir() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let config = Config::default();
+    let result = filename::generate(
+        "dir",
+        "claude",
+        "{directory}_{counter}",
+        &config,
+        temp.path(),
+    )
+    .unwrap();
+    assert_eq!(result, "dir_1.cast");
+}
+
+#[test]
+fn generate_counter_picks_next_free_integer() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp.path().join("dir_1.cast"), "").unwrap();
+    std::fs::write(temp.path().join("dir_2.cast"), "").unwrap();
+    std::fs::write(temp.path().join("dir_4.cast"), "").unwrap();
+    let config = Config::default();
+    let result = filename::generate(
+        "dir",
+        "claude",
+        "{directory}_{counter}",
+        &config,
+        temp.path(),
+    )
+    .unwrap();
+    assert_eq!(result, "dir_3.cast");
+}
+
+#[test]
+fn generate_unique_returns_plain_name_when_free() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let config = Config::default();
+    let result =
+        filename::generate_unique("dir", "claude", "{directory}", &config, temp.path()).unwrap();
+    assert_eq!(result, "dir.cast");
+}
+
+#[test]
+fn generate_unique_appends_suffixes_in_order() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let config = Config::default();
+
+    let mut names = Vec::new();
+    for _ in 0..4 {
+        let name = filename::generate_unique("dir", "claude", "{directory}", &config, temp.path())
+            .unwrap();
+        std::fs::write(temp.path().join(&name), "").unwrap();
+        names.push(name);
+    }
+
+    assert_eq!(
+        names,
+        ["dir.cast", "dir-2.cast", "dir-3.cast", "dir-4.cast"]
+    );
+}
+
+#[test]
+fn generate_unique_skips_taken_suffixes() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp.path().join("dir.cast"), "").unwrap();
+    std::fs::write(temp.path().join("dir-2.cast"), "").unwrap();
+    let config = Config::default();
+    let result =
+        filename::generate_unique("dir", "claude", "{directory}", &config, temp.path()).unwrap();
+    assert_eq!(result, "dir-3.cast");
+}
+
+#[test]
+fn generate_unique_truncates_base_to_fit_length_limit() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let config = Config {
+        directory_max_length: 300,
+        ..Config::default()
+    };
+    // 250 + ".cast" is exactly the 255-character limit
+    let long_dir = "a".repeat(250);
+    std::fs::write(temp.path().join(format!("{}.cast", long_dir)), "").unwrap();
+
+    let result =
+        filename::generate_unique(&long_dir, "claude", "{directory}", &config, temp.path())
+            .unwrap();
+    assert_eq!(result.len(), 255);
+    assert!(result.ends_with("-2.cast"));
+    assert!(filename::validate_length(&result).is_ok());
+}
+
+#[test]
+fn generate_uses_configured_extension() {
+    let config = Config::default().with_extension(".cast.gz");
+    let result =
+        filename::generate("dir", "claude", "{directory}", &config, Path::new(".")).unwrap();
+    assert_eq!(result, "dir.cast.gz");
+}
+
+#[test]
+fn generate_does_not_duplicate_configured_extension() {
+    let config = Config::default().with_extension(".log");
+    let result =
+        filename::generate("dir", "claude", "session.log", &config, Path::new(".")).unwrap();
+    assert_eq!(result, "session.log");
+}
+
+#[test]
+fn generate_length_limit_counts_extension() {
+    // 250 + ".cast" fits exactly, 250 + ".cast.gz" does not
+    let long_dir = "a".repeat(250);
+    let config = Config {
+        directory_max_length: 300,
+        ..Config::default()
+    };
+    assert!(
+        filename::generate(&long_dir, "claude", "{directory}", &config, Path::new(".")).is_ok()
+    );
+
+    let config = config.with_extension(".cast.gz");
+    let result = filename::generate(&long_dir, "claude", "{directory}", &config, Path::new("."));
+    assert!(result.is_err());
+}
+
+#[test]
+fn config_with_extension_adds_leading_dot() {
+    assert_eq!(Config::default().extension, ".cast");
+    assert_eq!(
+        Config::default().with_extension("cast.gz").extension,
+        ".cast.gz"
+    );
+    assert_eq!(Config::default().with_extension(".txt").extension, ".txt");
+}
+
+#[test]
+fn generate_unique_suffixes_before_configured_extension() {
+    let temp = tempfile::TempDir::new().unwrap();
+    std::fs::write(temp.path().join("dir.cast.gz"), "").unwrap();
+    let config = Config::default().with_extension(".cast.gz");
+    let result =
+        filename::generate_unique("dir", "claude", "{directory}", &config, temp.path()).unwrap();
+    assert_eq!(result, "dir-2.cast.gz");
+}
+
 #[test]
 fn generate_with_invalid_template_returns_error() {
     let config = Config::default();
-    let result = filename::generate("dir", "{unknown}", &config);
+    let result = filename::generate("dir", "claude", "{unknown}", &config, Path::new("."));
     assert!(result.is_err());
 }
 
@@ -0,0 +1,69 @@
+//! Integration tests for `agr info` (CLI)
+
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Run `agr info` on a fixture, passed relative to the fixtures directory.
+///
+/// TZ is pinned so the formatted start time is stable.
+fn run_info(args: &[&str]) -> (String, String, i32) {
+    let home = TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .arg("info")
+        .args(args)
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))
+        .env("HOME", home.path())
+        .env("TZ", "UTC")
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+        output.status.code().unwrap_or(-1),
+    )
+}
+
+#[test]
+fn snapshot_info_summary() {
+    let (stdout, stderr, exit_code) = run_info(&["resize_stress_excerpt.cast"]);
+    let output = format!(
+        "=== agr info resize_stress_excerpt.cast ===\nExit code: {}\n\n--- stdout ---\n{}\n--- stderr ---\n{}",
+        exit_code, stdout, stderr
+    );
+    insta::assert_snapshot!("info_summary", output);
+}
+
+#[test]
+fn info_json_reports_counts() {
+    let (stdout, stderr, exit_code) = run_info(&["--json", "with_markers.cast"]);
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+
+    let info: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(info["cols"], 80);
+    assert_eq!(info["rows"], 24);
+    assert_eq!(info["title"], serde_json::Value::Null);
+    assert_eq!(info["duration"], 4.1);
+    assert_eq!(info["events"]["total"], 4);
+    assert_eq!(info["events"]["output"], 2);
+    assert_eq!(info["markers"], 2);
+    assert_eq!(
+        info["size"].as_u64().unwrap(),
+        std::fs::metadata(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/with_markers.cast"
+        ))
+        .unwrap()
+        .len()
+    );
+}
+
+#[test]
+fn info_missing_file_fails() {
+    let (_, stderr, exit_code) = run_info(&["does_not_exist.cast"]);
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("File not found"), "stderr: {}", stderr);
+}
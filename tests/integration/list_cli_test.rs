@@ -0,0 +1,100 @@
+//! Integration tests for `agr ls --json`
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+const CAST: &str =
+    "{\"version\":3,\"term\":{\"cols\":80,\"rows\":24}}\n[0.5,\"o\",\"$ ls\\r\\n\"]\n";
+
+/// Helper to run agr CLI with an isolated HOME and capture output
+fn run_agr(home: &Path, args: &[&str]) -> (String, String, i32) {
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(args)
+        .env("HOME", home)
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+        output.status.code().unwrap_or(-1),
+    )
+}
+
+/// Write a recording with `extra` bytes of padding into the default storage directory.
+fn write_session(home: &Path, agent: &str, name: &str, extra: usize) -> PathBuf {
+    let dir = home.join("recorded_agent_sessions").join(agent);
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, format!("{}{}", CAST, "\n".repeat(extra))).unwrap();
+    path
+}
+
+fn parse(stdout: &str) -> Vec<serde_json::Value> {
+    serde_json::from_str::<serde_json::Value>(stdout)
+        .unwrap_or_else(|e| panic!("invalid JSON ({}): {}", e, stdout))
+        .as_array()
+        .expect("expected a JSON array")
+        .clone()
+}
+
+#[test]
+fn json_lists_sessions_with_explorer_fields() {
+    let home = TempDir::new().unwrap();
+    let path = write_session(home.path(), "claude", "session.cast", 0);
+    std::fs::write(format!("{}.bak", path.display()), CAST).unwrap();
+
+    let (stdout, stderr, exit_code) = run_agr(home.path(), &["ls", "--json"]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    let items = parse(&stdout);
+    assert_eq!(items.len(), 1);
+    let item = &items[0];
+    assert_eq!(item["path"], path.to_string_lossy().as_ref());
+    assert_eq!(item["name"], "session.cast");
+    assert_eq!(item["agent"], "claude");
+    assert_eq!(item["size"], CAST.len() as u64);
+    assert!(item["modified"].as_str().unwrap().contains('T'));
+    assert_eq!(item["has_backup"], true);
+}
+
+#[test]
+fn json_sorts_and_filters_by_agent() {
+    let home = TempDir::new().unwrap();
+    write_session(home.path(), "codex", "small.cast", 0);
+    write_session(home.path(), "codex", "large.cast", 100);
+    write_session(home.path(), "claude", "other.cast", 50);
+
+    let (stdout, stderr, exit_code) = run_agr(
+        home.path(),
+        &["ls", "--json", "--sort", "size", "--filter-agent", "codex"],
+    );
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    let names: Vec<_> = parse(&stdout)
+        .iter()
+        .map(|item| item["name"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, vec!["large.cast", "small.cast"]);
+
+    let (stdout, _, _) = run_agr(home.path(), &["ls", "--json", "--sort", "name", "--asc"]);
+    let names: Vec<_> = parse(&stdout)
+        .iter()
+        .map(|item| item["name"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, vec!["large.cast", "other.cast", "small.cast"]);
+}
+
+#[test]
+fn json_with_no_sessions_prints_empty_array() {
+    let home = TempDir::new().unwrap();
+
+    let (stdout, stderr, exit_code) = run_agr(home.path(), &["ls", "--json"]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(parse(&stdout).is_empty());
+}
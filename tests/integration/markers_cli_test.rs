@@ -0,0 +1,163 @@
+//! Integration tests for `agr markers list|add|remove` (CLI)
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+use agr::files::backup::has_backup;
+use agr::{AsciicastFile, MarkerManager};
+
+/// Helper to run agr CLI with an isolated HOME and capture output
+fn run_agr(home: &Path, args: &[&str]) -> (String, String, i32) {
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(args)
+        .env("HOME", home)
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let exit_code = output.status.code().unwrap_or(-1);
+
+    (stdout, stderr, exit_code)
+}
+
+/// A 100 second recording with one marker at 1:15
+fn write_cast(dir: &TempDir) -> PathBuf {
+    let path = dir.path().join("session.cast");
+    std::fs::write(
+        &path,
+        r#"{"version":3,"term":{"cols":80,"rows":24}}
+[0.5,"o","$ make build\r\n"]
+[74.5,"m","Build started"]
+[25.0,"o","Build complete\r\n"]
+"#,
+    )
+    .unwrap();
+    path
+}
+
+fn labels(path: &Path) -> Vec<(f64, String)> {
+    let cast = AsciicastFile::parse(path).unwrap();
+    MarkerManager::list_markers_from_cast(&cast)
+        .unwrap()
+        .into_iter()
+        .map(|m| (m.timestamp, m.label))
+        .collect()
+}
+
+#[test]
+fn markers_list_prints_mm_ss_and_label() {
+    let dir = TempDir::new().unwrap();
+    let cast = write_cast(&dir);
+
+    let (stdout, _stderr, exit_code) =
+        run_agr(dir.path(), &["markers", "list", cast.to_str().unwrap()]);
+
+    assert_eq!(exit_code, 0);
+    assert!(
+        stdout.contains("1  01:15  Build started"),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn markers_add_accepts_mm_ss_and_roundtrips() {
+    let dir = TempDir::new().unwrap();
+    let cast = write_cast(&dir);
+    let cast_arg = cast.to_str().unwrap();
+
+    let (_stdout, stderr, exit_code) = run_agr(
+        dir.path(),
+        &["markers", "add", cast_arg, "0:30", "Tests green"],
+    );
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    let (_stdout, stderr, exit_code) =
+        run_agr(dir.path(), &["markers", "add", cast_arg, "90.5", "Deploy"]);
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+
+    let markers = labels(&cast);
+    assert_eq!(markers.len(), 3);
+    assert_eq!(markers[0].1, "Tests green");
+    assert!((markers[0].0 - 30.0).abs() < 1e-9);
+    assert_eq!(markers[1].1, "Build started");
+    assert_eq!(markers[2].1, "Deploy");
+    assert!((markers[2].0 - 90.5).abs() < 1e-9);
+
+    // Output events keep their absolute times
+    let parsed = AsciicastFile::parse(&cast).unwrap();
+    assert!((parsed.duration() - 100.0).abs() < 1e-9);
+    assert!(has_backup(&cast));
+}
+
+#[test]
+fn markers_add_rejects_time_past_end() {
+    let dir = TempDir::new().unwrap();
+    let cast = write_cast(&dir);
+
+    let (_stdout, stderr, exit_code) = run_agr(
+        dir.path(),
+        &["markers", "add", cast.to_str().unwrap(), "2:00", "Too late"],
+    );
+
+    assert_eq!(exit_code, 1);
+    assert!(stderr.contains("past the end"), "stderr: {}", stderr);
+    assert_eq!(labels(&cast).len(), 1);
+    assert!(!has_backup(&cast));
+}
+
+#[test]
+fn markers_add_rejects_malformed_time() {
+    let dir = TempDir::new().unwrap();
+    let cast = write_cast(&dir);
+
+    let (_stdout, stderr, exit_code) = run_agr(
+        dir.path(),
+        &["markers", "add", cast.to_str().unwrap(), "1:75", "Bad"],
+    );
+
+    assert_eq!(exit_code, 2);
+    assert!(
+        stderr.contains("expected MM:SS or seconds"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn markers_remove_deletes_marker_and_keeps_timing() {
+    let dir = TempDir::new().unwrap();
+    let cast = write_cast(&dir);
+
+    let (stdout, stderr, exit_code) = run_agr(
+        dir.path(),
+        &["markers", "remove", cast.to_str().unwrap(), "1"],
+    );
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Build started"));
+    assert!(labels(&cast).is_empty());
+    let parsed = AsciicastFile::parse(&cast).unwrap();
+    assert_eq!(parsed.events.len(), 2);
+    assert!((parsed.duration() - 100.0).abs() < 1e-9);
+    assert!(has_backup(&cast));
+}
+
+#[test]
+fn markers_remove_out_of_range_fails() {
+    let dir = TempDir::new().unwrap();
+    let cast = write_cast(&dir);
+
+    let (_stdout, stderr, exit_code) = run_agr(
+        dir.path(),
+        &["markers", "remove", cast.to_str().unwrap(), "3"],
+    );
+
+    assert_eq!(exit_code, 1);
+    assert!(stderr.contains("No marker #3"), "stderr: {}", stderr);
+    assert!(!has_backup(&cast));
+}
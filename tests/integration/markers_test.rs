@@ -12,7 +12,7 @@ use tempfile::NamedTempFile;
 fn add_marker_creates_marker_event() {
     let (_temp_dir, path) = temp_fixture("sample.cast");
 
-    MarkerManager::add_marker(&path, 0.55, "Test marker").unwrap();
+    MarkerManager::add_marker(&path, 0.55, "Test marker", 1).unwrap();
 
     let cast = AsciicastFile::parse(&path).unwrap();
     let markers = cast.markers();
@@ -26,7 +26,7 @@ fn add_marker_preserves_existing_output() {
     let original = AsciicastFile::parse(&path).unwrap();
     let original_output_count = original.outputs().len();
 
-    MarkerManager::add_marker(&path, 0.55, "Test marker").unwrap();
+    MarkerManager::add_marker(&path, 0.55, "Test marker", 1).unwrap();
 
     let modified = AsciicastFile::parse(&path).unwrap();
     assert_eq!(modified.outputs().len(), original_output_count);
@@ -36,8 +36,8 @@ fn add_marker_preserves_existing_output() {
 fn add_multiple_markers() {
     let (_temp_dir, path) = temp_fixture("sample.cast");
 
-    MarkerManager::add_marker(&path, 0.3, "First marker").unwrap();
-    MarkerManager::add_marker(&path, 0.7, "Second marker").unwrap();
+    MarkerManager::add_marker(&path, 0.3, "First marker", 1).unwrap();
+    MarkerManager::add_marker(&path, 0.7, "Second marker", 1).unwrap();
 
     let markers = MarkerManager::list_markers(&path).unwrap();
     assert_eq!(markers.len(), 2);
@@ -59,7 +59,7 @@ fn list_markers_returns_timestamps() {
 fn add_marker_at_start() {
     let (_temp_dir, path) = temp_fixture("sample.cast");
 
-    MarkerManager::add_marker(&path, 0.1, "Start marker").unwrap();
+    MarkerManager::add_marker(&path, 0.1, "Start marker", 1).unwrap();
 
     let cast = AsciicastFile::parse(&path).unwrap();
     assert!(cast.events[0].is_marker());
@@ -69,7 +69,7 @@ fn add_marker_at_start() {
 fn add_marker_at_end() {
     let (_temp_dir, path) = temp_fixture("sample.cast");
 
-    MarkerManager::add_marker(&path, 100.0, "End marker").unwrap();
+    MarkerManager::add_marker(&path, 0.8, "End marker", 1).unwrap();
 
     let cast = AsciicastFile::parse(&path).unwrap();
     assert!(cast.events.last().unwrap().is_marker());
@@ -79,15 +79,23 @@ fn add_marker_at_end() {
 fn reject_negative_timestamp() {
     let (_temp_dir, path) = temp_fixture("sample.cast");
 
-    let result = MarkerManager::add_marker(&path, -1.0, "Bad marker");
+    let result = MarkerManager::add_marker(&path, -1.0, "Bad marker", 1);
     assert!(result.is_err());
 }
 
+#[test]
+fn reject_timestamp_past_end() {
+    let (_temp_dir, path) = temp_fixture("sample.cast");
+
+    let result = MarkerManager::add_marker(&path, 100.0, "Late marker", 1);
+    assert!(result.unwrap_err().to_string().contains("past the end"));
+}
+
 #[test]
 fn reject_empty_label() {
     let (_temp_dir, path) = temp_fixture("sample.cast");
 
-    let result = MarkerManager::add_marker(&path, 0.5, "");
+    let result = MarkerManager::add_marker(&path, 0.5, "", 1);
     assert!(result.is_err());
 }
 
@@ -121,7 +129,7 @@ fn add_marker_inserts_at_correct_position() {
 
     // Cumulative times: 0.5, 0.6, 0.8
     // Insert marker at 0.55 (between first two events)
-    MarkerManager::add_marker(temp.path(), 0.55, "Test marker").unwrap();
+    MarkerManager::add_marker(temp.path(), 0.55, "Test marker", 1).unwrap();
 
     let cast = AsciicastFile::parse(temp.path()).unwrap();
     assert_eq!(cast.events.len(), 4);
@@ -132,7 +140,7 @@ fn add_marker_inserts_at_correct_position() {
 #[test]
 fn add_marker_at_start_inline() {
     let temp = create_temp_cast(sample_cast());
-    MarkerManager::add_marker(temp.path(), 0.1, "Start marker").unwrap();
+    MarkerManager::add_marker(temp.path(), 0.1, "Start marker", 1).unwrap();
 
     let cast = AsciicastFile::parse(temp.path()).unwrap();
     assert_eq!(cast.events.len(), 4);
@@ -142,7 +150,7 @@ fn add_marker_at_start_inline() {
 #[test]
 fn add_marker_at_end_inline() {
     let temp = create_temp_cast(sample_cast());
-    MarkerManager::add_marker(temp.path(), 10.0, "End marker").unwrap();
+    MarkerManager::add_marker(temp.path(), 0.8, "End marker", 1).unwrap();
 
     let cast = AsciicastFile::parse(temp.path()).unwrap();
     assert_eq!(cast.events.len(), 4);
@@ -155,7 +163,7 @@ fn add_marker_preserves_existing_events() {
     let original_cast = AsciicastFile::parse(temp.path()).unwrap();
     let original_event_count = original_cast.events.len();
 
-    MarkerManager::add_marker(temp.path(), 0.55, "Test marker").unwrap();
+    MarkerManager::add_marker(temp.path(), 0.55, "Test marker", 1).unwrap();
 
     let modified_cast = AsciicastFile::parse(temp.path()).unwrap();
     assert_eq!(modified_cast.events.len(), original_event_count + 1);
@@ -189,7 +197,7 @@ fn list_markers_returns_empty_for_no_markers() {
 #[test]
 fn add_marker_rejects_negative_timestamp() {
     let temp = create_temp_cast(sample_cast());
-    let result = MarkerManager::add_marker(temp.path(), -1.0, "Bad marker");
+    let result = MarkerManager::add_marker(temp.path(), -1.0, "Bad marker", 1);
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("negative"));
 }
@@ -197,7 +205,7 @@ fn add_marker_rejects_negative_timestamp() {
 #[test]
 fn add_marker_rejects_empty_label() {
     let temp = create_temp_cast(sample_cast());
-    let result = MarkerManager::add_marker(temp.path(), 0.5, "  ");
+    let result = MarkerManager::add_marker(temp.path(), 0.5, "  ", 1);
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("empty"));
 }
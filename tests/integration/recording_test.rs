@@ -6,7 +6,7 @@ use agr::{Config, Recorder};
 fn generate_filename_has_correct_format() {
     let config = Config::default();
     let recorder = Recorder::new(config);
-    let filename = recorder.generate_filename();
+    let filename = recorder.generate_filename("claude", std::path::Path::new("."));
     assert!(filename.ends_with(".cast"));
     // New format: {directory}_{date}_{time}.cast
     assert!(filename.contains('_'));
@@ -592,6 +592,7 @@ fn snapshot_file_explorer_with_session_preview() {
                     .map(|c| Cell {
                         char: c,
                         style: CellStyle::default(),
+                        link: None,
                     })
                     .collect(),
             },
@@ -609,6 +610,7 @@ fn snapshot_file_explorer_with_session_preview() {
                         } else {
                             CellStyle::default()
                         },
+                        link: None,
                     })
                     .collect(),
             },
@@ -636,6 +638,7 @@ fn snapshot_file_explorer_preview_with_backup() {
                 .map(|c| Cell {
                     char: c,
                     style: CellStyle::default(),
+                    link: None,
                 })
                 .collect(),
         }],
@@ -664,6 +667,7 @@ fn snapshot_file_explorer_preview_without_backup() {
                 .map(|c| Cell {
                     char: c,
                     style: CellStyle::default(),
+                    link: None,
                 })
                 .collect(),
         }],
@@ -736,13 +740,13 @@ fn snapshot_context_menu_restore_selected_no_backup() {
 
 #[test]
 fn snapshot_context_menu_delete_selected() {
-    let output = render_context_menu_to_string(5, true);
+    let output = render_context_menu_to_string(7, true);
     insta::assert_snapshot!("context_menu_delete_selected", output);
 }
 
 #[test]
 fn snapshot_context_menu_last_item_selected() {
-    let output = render_context_menu_to_string(6, true);
+    let output = render_context_menu_to_string(8, true);
     insta::assert_snapshot!("context_menu_last_item", output);
 }
 
@@ -792,6 +796,7 @@ fn snapshot_optimize_result_success() {
             new_duration: 1234.0,      // 20m 34s
             backup_path: Some(PathBuf::from("/tmp/test.cast.bak")),
             backup_created: true,
+            gaps_capped: 0,
         }),
     };
 
@@ -808,6 +813,7 @@ fn snapshot_optimize_result_success_existing_backup() {
             new_duration: 180.0,      // 3m
             backup_path: Some(PathBuf::from("/tmp/test.cast.bak")),
             backup_created: false, // Using existing backup
+            gaps_capped: 0,
         }),
     };
 
@@ -815,6 +821,23 @@ fn snapshot_optimize_result_success_existing_backup() {
     insta::assert_snapshot!("optimize_result_existing_backup", output);
 }
 
+#[test]
+fn snapshot_optimize_result_cap_idle() {
+    let result_state = OptimizeResultState {
+        filename: "session.cast".to_string(),
+        result: Ok(TransformResult {
+            original_duration: 600.0, // 10m
+            new_duration: 95.0,       // 1m 35s
+            backup_path: Some(PathBuf::from("/tmp/test.cast.bak")),
+            backup_created: true,
+            gaps_capped: 12,
+        }),
+    };
+
+    let output = render_optimize_result_to_string(&result_state);
+    insta::assert_snapshot!("optimize_result_cap_idle", output);
+}
+
 #[test]
 fn snapshot_optimize_result_error() {
     let result_state = OptimizeResultState {
@@ -826,6 +849,114 @@ fn snapshot_optimize_result_error() {
     insta::assert_snapshot!("optimize_result_error", output);
 }
 
+// ============================================================================
+// Batch Optimize Modal Snapshots
+// ============================================================================
+
+use agr::tui::cleanup_app::BatchOptimizeState;
+use agr::tui::CleanupApp;
+
+fn transform_result(original_duration: f64, new_duration: f64) -> TransformResult {
+    TransformResult {
+        original_duration,
+        new_duration,
+        backup_path: None,
+        backup_created: true,
+        gaps_capped: 0,
+    }
+}
+
+/// Render the batch optimize summary modal to a buffer and return as string.
+fn render_batch_optimize_to_string(batch: &BatchOptimizeState) -> String {
+    let width = 70u16;
+    let height = 20u16;
+    let area = Rect::new(0, 0, width, height);
+
+    let backend = ratatui::backend::TestBackend::new(width, height);
+    let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|frame| {
+            CleanupApp::render_batch_optimize_modal(frame, area, batch);
+        })
+        .unwrap();
+
+    let backend = terminal.backend();
+    let mut output = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let cell = backend.buffer()[(x, y)].symbol();
+            output.push_str(cell);
+        }
+        output.push('\n');
+    }
+    output
+}
+
+#[test]
+fn snapshot_batch_optimize_all_succeeded() {
+    let batch = BatchOptimizeState {
+        optimized: vec![
+            ("a.cast".to_string(), transform_result(600.0, 200.0)),
+            ("b.cast".to_string(), transform_result(300.0, 100.0)),
+        ],
+        failed: vec![],
+    };
+
+    let output = render_batch_optimize_to_string(&batch);
+    insta::assert_snapshot!("batch_optimize_all_succeeded", output);
+}
+
+#[test]
+fn snapshot_batch_optimize_with_failures() {
+    let batch = BatchOptimizeState {
+        optimized: vec![
+            ("a.cast".to_string(), transform_result(600.0, 200.0)),
+            ("b.cast".to_string(), transform_result(300.0, 100.0)),
+            ("c.cast".to_string(), transform_result(120.0, 60.0)),
+        ],
+        failed: vec![(
+            "broken.cast".to_string(),
+            "invalid JSON at line 5".to_string(),
+        )],
+    };
+
+    let output = render_batch_optimize_to_string(&batch);
+    insta::assert_snapshot!("batch_optimize_with_failures", output);
+}
+
+// ============================================================================
+// Theme Picker Snapshots
+// ============================================================================
+
+#[test]
+fn snapshot_theme_picker() {
+    use agr::theme::THEME_NAMES;
+    use agr::tui::app::modals::render_theme_picker_modal;
+
+    let width = 50u16;
+    let height = 12u16;
+    let area = Rect::new(0, 0, width, height);
+    let backend = ratatui::backend::TestBackend::new(width, height);
+    let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|frame| {
+            render_theme_picker_modal(frame, area, THEME_NAMES, 2);
+        })
+        .unwrap();
+
+    let backend = terminal.backend();
+    let mut output = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            output.push_str(backend.buffer()[(x, y)].symbol());
+        }
+        output.push('\n');
+    }
+    insta::assert_snapshot!("theme_picker", output);
+}
+
 // ============================================================================
 // Help Modal Snapshots
 // ============================================================================
@@ -833,7 +964,7 @@ fn snapshot_optimize_result_error() {
 /// Render the help modal to a buffer and return as string.
 fn render_help_modal_to_string() -> String {
     let width = 70u16;
-    let height = 30u16;
+    let height = 36u16;
     let area = Rect::new(0, 0, width, height);
 
     // Create a mock terminal backend
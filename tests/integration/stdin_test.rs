@@ -0,0 +1,121 @@
+//! Integration tests for reading recordings from standard input (`-`)
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use tempfile::TempDir;
+
+/// Run `agr` with the given fixture piped to standard input.
+fn run_with_stdin(args: &[&str], fixture: &str) -> (String, String, i32) {
+    let home = TempDir::new().unwrap();
+    let input = std::fs::read(format!(
+        "{}/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        fixture
+    ))
+    .unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(args)
+        .env("HOME", home.path())
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute agr");
+    // The command may exit before reading, so a broken pipe is fine here
+    let _ = child.stdin.take().unwrap().write_all(&input);
+    let output = child.wait_with_output().unwrap();
+
+    (
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+        output.status.code().unwrap_or(-1),
+    )
+}
+
+#[test]
+fn validate_reads_stdin() {
+    let (stdout, stderr, exit_code) = run_with_stdin(&["validate", "-"], "sample.cast");
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("OK    -"), "stdout: {}", stdout);
+}
+
+#[test]
+fn validate_reports_broken_stdin() {
+    let (stdout, _, exit_code) = run_with_stdin(&["validate", "-"], "malformed.cast");
+    assert_ne!(exit_code, 0);
+    assert!(stdout.contains("FAIL  -"), "stdout: {}", stdout);
+}
+
+#[test]
+fn markers_list_reads_stdin() {
+    let (stdout, stderr, exit_code) =
+        run_with_stdin(&["markers", "list", "-"], "with_markers.cast");
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Build started"), "stdout: {}", stdout);
+    assert!(stdout.contains("Build finished"), "stdout: {}", stdout);
+}
+
+#[test]
+fn analyze_dump_content_reads_stdin() {
+    let (stdout, stderr, exit_code) =
+        run_with_stdin(&["analyze", "-", "--dump-content"], "sample.cast");
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("$ echo hello"), "stdout: {}", stdout);
+}
+
+#[test]
+fn analyze_without_dry_run_rejects_stdin() {
+    let (_, stderr, exit_code) = run_with_stdin(&["analyze", "-"], "sample.cast");
+    assert_ne!(exit_code, 0);
+    assert!(
+        stderr.contains("Cannot write markers to standard input"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn optimize_writes_stdin_to_output() {
+    let dir = TempDir::new().unwrap();
+    let out = dir.path().join("out.cast");
+    let (_, stderr, exit_code) = run_with_stdin(
+        &[
+            "optimize",
+            "--speed",
+            "2.0",
+            "--output",
+            out.to_str().unwrap(),
+            "-",
+        ],
+        "sample.cast",
+    );
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(out.exists());
+}
+
+#[test]
+fn optimize_in_place_rejects_stdin() {
+    let (_, stderr, exit_code) =
+        run_with_stdin(&["optimize", "--speed", "2.0", "-"], "sample.cast");
+    assert_ne!(exit_code, 0);
+    assert!(
+        stderr.contains("Cannot optimize standard input"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn markers_add_rejects_stdin() {
+    let (_, stderr, exit_code) =
+        run_with_stdin(&["markers", "add", "-", "1.0", "label"], "sample.cast");
+    assert_ne!(exit_code, 0);
+    assert!(
+        stderr.contains("Cannot add markers to standard input"),
+        "stderr: {}",
+        stderr
+    );
+}
@@ -218,6 +218,57 @@ fn delete_sessions_removes_files_inline() {
     assert!(sessions_after.is_empty());
 }
 
+#[test]
+fn delete_recording_removes_backup_and_sidecar() {
+    let temp = TempDir::new().unwrap();
+    let manager = StorageManager::new(create_test_config(&temp));
+
+    let path = create_test_session(temp.path(), "claude", "session.cast", "content");
+    let backup = PathBuf::from(format!("{}.bak", path.display()));
+    let position = PathBuf::from(format!("{}.pos", path.display()));
+    let analysis = PathBuf::from(format!("{}.analysis", path.display()));
+    fs::write(&backup, "backup content").unwrap();
+    fs::write(&position, "1.000\n").unwrap();
+    fs::write(&analysis, "{}").unwrap();
+
+    assert_eq!(StorageManager::recording_footprint(&path), 7 + 14 + 6 + 2);
+
+    let freed = manager.delete_recording(&path).unwrap();
+    assert_eq!(freed, 7 + 14 + 6 + 2);
+    assert!(!path.exists());
+    assert!(!backup.exists());
+    assert!(!position.exists());
+    assert!(!analysis.exists());
+}
+
+#[test]
+fn delete_recording_without_backup_frees_file_size() {
+    let temp = TempDir::new().unwrap();
+    let manager = StorageManager::new(create_test_config(&temp));
+
+    let path = create_test_session(temp.path(), "claude", "session.cast", "content");
+    assert!(StorageManager::associated_files(&path).is_empty());
+
+    let freed = manager.delete_recording(&path).unwrap();
+    assert_eq!(freed, 7);
+    assert!(!path.exists());
+}
+
+#[test]
+fn delete_sessions_removes_backups() {
+    let temp = TempDir::new().unwrap();
+    let manager = StorageManager::new(create_test_config(&temp));
+
+    let path = create_test_session(temp.path(), "claude", "session.cast", "content");
+    let backup = PathBuf::from(format!("{}.bak", path.display()));
+    fs::write(&backup, "backup").unwrap();
+
+    let sessions = manager.list_sessions(None).unwrap();
+    let freed = manager.delete_sessions(&sessions).unwrap();
+    assert_eq!(freed, 7 + 6);
+    assert!(!backup.exists());
+}
+
 #[test]
 fn ensure_storage_dir_creates_directory() {
     let temp = TempDir::new().unwrap();
@@ -649,3 +700,55 @@ fn list_cast_files_short_sorted_by_mtime_descending() {
     );
     assert_eq!(files[2], "claude/oldest.cast", "Oldest file should be last");
 }
+
+#[test]
+fn usage_report_sums_by_agent_and_month_including_backups() {
+    let temp = TempDir::new().unwrap();
+    let manager = StorageManager::new(create_test_config(&temp));
+
+    let big = create_test_session(temp.path(), "claude", "big.cast", &"x".repeat(100));
+    fs::write(format!("{}.bak", big.display()), "y".repeat(50)).unwrap();
+    create_test_session(temp.path(), "claude", "small.cast", "x");
+    create_test_session(temp.path(), "codex", "mid.cast", &"x".repeat(20));
+
+    let report = manager.usage_report(2).unwrap();
+
+    assert_eq!(report.session_count, 3);
+    assert_eq!(report.total_size, 100 + 50 + 1 + 20);
+    assert_eq!(report.associated_size, 50);
+
+    let agents: Vec<(&str, usize, u64)> = report
+        .by_agent
+        .iter()
+        .map(|b| (b.name.as_str(), b.sessions, b.size))
+        .collect();
+    assert_eq!(agents, vec![("claude", 2, 151), ("codex", 1, 20)]);
+
+    // All fixtures were just written, so they share the current month
+    let month = Local::now().format("%Y-%m").to_string();
+    assert_eq!(report.by_month.len(), 1);
+    assert_eq!(report.by_month[0].name, month);
+    assert_eq!(report.by_month[0].size, report.total_size);
+
+    let largest: Vec<(&str, u64)> = report
+        .largest
+        .iter()
+        .map(|f| (f.path.as_str(), f.size))
+        .collect();
+    assert_eq!(
+        largest,
+        vec![("claude/big.cast", 150), ("codex/mid.cast", 20)]
+    );
+}
+
+#[test]
+fn usage_report_of_empty_storage_is_zero() {
+    let temp = TempDir::new().unwrap();
+    let manager = StorageManager::new(create_test_config(&temp));
+
+    let report = manager.usage_report(10).unwrap();
+
+    assert_eq!(report.session_count, 0);
+    assert_eq!(report.total_size, 0);
+    assert!(report.by_agent.is_empty() && report.largest.is_empty());
+}
@@ -0,0 +1,82 @@
+//! Alternate screen buffer tests (CSI ?1049h/l, ?47h/l).
+
+use agr::terminal::{Cell, TerminalBuffer};
+
+#[test]
+fn enter_alt_screen_shows_blank_screen() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("$ vim notes.txt", None);
+    buf.process("\x1b[?1049h", None);
+
+    assert!(buf.is_alternate_screen());
+    assert_eq!(buf.to_string(), "");
+}
+
+#[test]
+fn exit_alt_screen_restores_primary_content_and_cursor() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("$ vim notes.txt", None);
+    buf.process("\x1b[?1049h\x1b[H~\r\n~\r\n~\x1b[5;1H-- INSERT --", None);
+    buf.process("\x1b[?1049l", None);
+
+    assert!(!buf.is_alternate_screen());
+    assert_eq!(buf.to_string(), "$ vim notes.txt");
+    assert_eq!(buf.cursor_row(), 0);
+    assert_eq!(buf.cursor_col(), 15);
+}
+
+#[test]
+fn output_after_exit_continues_on_primary_screen() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("$ htop\r\n", None);
+    buf.process("\x1b[?1049h\x1b[2J\x1b[HCPU 42%\x1b[?1049l", None);
+    buf.process("$ ", None);
+
+    assert_eq!(buf.to_string(), "$ htop\n$");
+}
+
+#[test]
+fn mode_47_switches_screens() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("primary", None);
+    buf.process("\x1b[?47h\x1b[HALT", None);
+    assert_eq!(buf.to_string(), "ALT");
+
+    buf.process("\x1b[?47l", None);
+    assert_eq!(buf.to_string(), "primary");
+}
+
+#[test]
+fn scroll_callback_only_fires_for_primary_screen() {
+    let mut buf = TerminalBuffer::new(20, 2);
+    let mut scrolled: Vec<Vec<Cell>> = Vec::new();
+    let mut cb = |line: Vec<Cell>| scrolled.push(line);
+
+    buf.process("\x1b[?1049ha\r\nb\r\nc\r\nd\x1b[?1049l", Some(&mut cb));
+    buf.process("one\r\ntwo\r\nthree", Some(&mut cb));
+
+    assert_eq!(scrolled.len(), 1);
+    assert_eq!(agr::terminal::cells_to_text(&scrolled[0]).trim_end(), "one");
+}
+
+#[test]
+fn resize_while_in_alt_screen_keeps_primary_consistent() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("hello world", None);
+    buf.process("\x1b[?1049h", None);
+    buf.resize(5, 3);
+    buf.process("\x1b[?1049l", None);
+
+    assert_eq!(buf.width(), 5);
+    assert_eq!(buf.height(), 3);
+    assert_eq!(buf.to_string(), "hello");
+    assert_eq!(buf.row(0).unwrap().len(), 5);
+}
+
+#[test]
+fn other_private_modes_are_still_ignored() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("\x1b[?25lHello\x1b[?25h\x1b[?2026h", None);
+    assert!(!buf.is_alternate_screen());
+    assert_eq!(buf.to_string(), "Hello");
+}
@@ -0,0 +1,41 @@
+//! DEC Special Graphics (line-drawing) charset tests.
+
+use agr::terminal::TerminalBuffer;
+
+#[test]
+fn dec_graphics_draws_box() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("\x1b(0lqqk\r\nx  x\r\nmqqj\x1b(B", None);
+    assert_eq!(buf.to_string(), "┌──┐\n│  │\n└──┘");
+}
+
+#[test]
+fn ascii_designation_restores_plain_letters() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("\x1b(0q\x1b(Bq", None);
+    assert_eq!(buf.to_string(), "─q");
+}
+
+#[test]
+fn dec_graphics_leaves_uppercase_and_digits_alone() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("\x1b(0AB12\x1b(B", None);
+    assert_eq!(buf.to_string(), "AB12");
+}
+
+#[test]
+fn dec_graphics_persists_across_process_calls() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("\x1b(0", None);
+    buf.process("tqu", None);
+    buf.process("\x1b(B", None);
+    buf.process("tqu", None);
+    assert_eq!(buf.to_string(), "├─┤tqu");
+}
+
+#[test]
+fn dec_graphics_box_advances_cursor_one_cell_per_glyph() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("\x1b(0lqqqk\x1b(B", None);
+    assert_eq!(buf.cursor_col(), 5);
+}
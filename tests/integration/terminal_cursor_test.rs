@@ -1,6 +1,6 @@
 //! Cursor movement and positioning tests.
 
-use agr::terminal::TerminalBuffer;
+use agr::terminal::{CursorShape, TerminalBuffer};
 
 #[test]
 fn cursor_movement_up() {
@@ -132,3 +132,62 @@ fn tab_moves_to_next_tab_stop() {
     assert!(output.contains("B"));
     assert!(output.len() >= 8);
 }
+
+#[test]
+fn cursor_visible_by_default() {
+    let buf = TerminalBuffer::new(80, 24);
+    assert!(buf.cursor_visible());
+    assert_eq!(buf.cursor_shape(), CursorShape::BlinkingBlock);
+}
+
+#[test]
+fn dectcem_hides_and_shows_cursor() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b[?25lWorking", None);
+    assert!(!buf.cursor_visible());
+    assert_eq!(buf.to_string(), "Working");
+
+    buf.process("\x1b[?25h", None);
+    assert!(buf.cursor_visible());
+}
+
+#[test]
+fn dectcem_combined_with_other_private_modes() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b[?2026;25l", None);
+    assert!(!buf.cursor_visible());
+}
+
+#[test]
+fn decscusr_changes_cursor_shape() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b[6 q", None);
+    assert_eq!(buf.cursor_shape(), CursorShape::SteadyBar);
+
+    buf.process("\x1b[3 qtext", None);
+    assert_eq!(buf.cursor_shape(), CursorShape::BlinkingUnderline);
+    assert_eq!(buf.to_string(), "text");
+
+    buf.process("\x1b[0 q", None);
+    assert_eq!(buf.cursor_shape(), CursorShape::BlinkingBlock);
+}
+
+#[test]
+fn decscusr_ignores_unknown_shapes() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b[2 q\x1b[9 q", None);
+    assert_eq!(buf.cursor_shape(), CursorShape::SteadyBlock);
+}
+
+#[test]
+fn cursor_shape_survives_resize_but_not_reset() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b[?25l\x1b[4 q", None);
+    buf.resize(100, 30);
+    assert!(!buf.cursor_visible());
+    assert_eq!(buf.cursor_shape(), CursorShape::SteadyUnderline);
+
+    buf.reset(80, 24);
+    assert!(buf.cursor_visible());
+    assert_eq!(buf.cursor_shape(), CursorShape::BlinkingBlock);
+}
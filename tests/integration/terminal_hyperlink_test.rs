@@ -0,0 +1,87 @@
+//! OSC 8 hyperlink tests.
+
+use agr::terminal::TerminalBuffer;
+
+#[test]
+fn hyperlink_text_is_rendered_without_escape_residue() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process(
+        "see \x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\ here",
+        None,
+    );
+    assert_eq!(buf.to_string(), "see docs here");
+}
+
+#[test]
+fn hyperlink_spans_multiple_cells() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process(
+        "see \x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\",
+        None,
+    );
+
+    for col in 4..8 {
+        assert_eq!(buf.cell_link(0, col), Some("https://example.com"));
+    }
+    assert_eq!(buf.cell_link(0, 3), None);
+}
+
+#[test]
+fn empty_uri_resets_hyperlink() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b]8;;https://example.com\x1b\\a\x1b]8;;\x1b\\b", None);
+
+    assert_eq!(buf.cell_link(0, 0), Some("https://example.com"));
+    assert_eq!(buf.cell_link(0, 1), None);
+}
+
+#[test]
+fn hyperlink_with_bel_terminator_and_id_param() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b]8;id=1;https://a.test/x\x07link\x1b]8;;\x07", None);
+
+    assert_eq!(buf.to_string(), "link");
+    assert_eq!(buf.cell_link(0, 0), Some("https://a.test/x"));
+}
+
+#[test]
+fn hyperlink_uri_containing_semicolon_is_preserved() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b]8;;https://a.test/?q=1;2\x1b\\x\x1b]8;;\x1b\\", None);
+
+    assert_eq!(buf.cell_link(0, 0), Some("https://a.test/?q=1;2"));
+}
+
+#[test]
+fn repeated_url_reuses_link_entry() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process(
+        "\x1b]8;;https://example.com\x1b\\a\x1b]8;;\x1b\\ \x1b]8;;https://example.com\x1b\\b\x1b]8;;\x1b\\",
+        None,
+    );
+
+    let first = buf.row(0).unwrap()[0].link;
+    let second = buf.row(0).unwrap()[2].link;
+    assert!(first.is_some());
+    assert_eq!(first, second);
+}
+
+#[test]
+fn scrolled_off_hyperlink_is_no_longer_visible() {
+    let mut buf = TerminalBuffer::new(20, 2);
+    buf.process(
+        "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\\r\nb\r\nc",
+        None,
+    );
+
+    // Row 0 scrolled off, so no visible cell carries the link anymore
+    assert_eq!(buf.cell_link(0, 0), None);
+    assert_eq!(buf.to_string(), "b\nc");
+}
+
+#[test]
+fn other_osc_sequences_do_not_corrupt_text() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b]0;window title\x07hello", None);
+    assert_eq!(buf.to_string(), "hello");
+}
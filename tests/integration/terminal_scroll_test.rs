@@ -322,3 +322,105 @@ fn scroll_region_preserves_on_invalid_params() {
         "Line4 preserved after invalid region attempts"
     );
 }
+
+// === Scrollback tests ===
+
+fn scrollback_text(buf: &TerminalBuffer) -> Vec<String> {
+    buf.scrollback_lines()
+        .iter()
+        .map(|row| agr::terminal::cells_to_text(row).trim_end().to_string())
+        .collect()
+}
+
+#[test]
+fn scrollback_disabled_by_default() {
+    let mut buf = TerminalBuffer::new(10, 2);
+    buf.process("Line 1\r\nLine 2\r\nLine 3", None);
+    assert!(buf.scrollback_lines().is_empty());
+}
+
+#[test]
+fn scrollback_keeps_lines_scrolled_off_top() {
+    let mut buf = TerminalBuffer::with_scrollback(10, 2, 100);
+    buf.process("Line 1\r\nLine 2\r\nLine 3\r\nLine 4", None);
+    assert_eq!(scrollback_text(&buf), vec!["Line 1", "Line 2"]);
+    assert_eq!(buf.to_string(), "Line 3\nLine 4");
+}
+
+#[test]
+fn scrollback_drops_oldest_lines_past_limit() {
+    let mut buf = TerminalBuffer::with_scrollback(10, 1, 2);
+    buf.process("a\r\nb\r\nc\r\nd", None);
+    assert_eq!(scrollback_text(&buf), vec!["b", "c"]);
+
+    buf.set_scrollback_limit(1);
+    assert_eq!(scrollback_text(&buf), vec!["c"]);
+}
+
+#[test]
+fn scrollback_row_counts_up_from_screen_top() {
+    let mut buf = TerminalBuffer::with_scrollback(10, 1, 10);
+    buf.process("a\r\nb\r\nc", None);
+    let row = |n| buf.scrollback_row(n).map(agr::terminal::cells_to_text);
+    assert_eq!(row(1).as_deref().map(str::trim_end), Some("b"));
+    assert_eq!(row(2).as_deref().map(str::trim_end), Some("a"));
+    assert_eq!(row(0), None);
+    assert_eq!(row(3), None);
+}
+
+#[test]
+fn scrollback_ignores_inner_scroll_region() {
+    let mut buf = TerminalBuffer::with_scrollback(10, 4, 10);
+    buf.process("\x1b[2;3r\x1b[3;1HX\n\n", None);
+    assert!(buf.scrollback_lines().is_empty());
+}
+
+#[test]
+fn scrollback_ignores_alternate_screen() {
+    let mut buf = TerminalBuffer::with_scrollback(10, 1, 10);
+    buf.process("\x1b[?1049ha\r\nb\r\nc\x1b[?1049l", None);
+    assert!(buf.scrollback_lines().is_empty());
+}
+
+#[test]
+fn erase_display_mode_3_clears_scrollback() {
+    let mut buf = TerminalBuffer::with_scrollback(10, 1, 10);
+    buf.process("a\r\nb", None);
+    buf.process("\x1b[3J", None);
+    assert!(buf.scrollback_lines().is_empty());
+}
+
+#[test]
+fn reset_keeps_scrollback_limit() {
+    let mut buf = TerminalBuffer::with_scrollback(10, 1, 10);
+    buf.process("a\r\nb", None);
+    buf.reset(20, 2);
+    assert!(buf.scrollback_lines().is_empty());
+    assert_eq!(buf.scrollback_limit(), 10);
+    assert_eq!(buf.width(), 20);
+}
+
+#[test]
+fn pinned_header_scroll_only_reports_region_lines() {
+    let mut buf = TerminalBuffer::with_scrollback(20, 6, 10);
+    let mut scrolled = Vec::new();
+    let mut cb = |cells: Vec<agr::terminal::Cell>| {
+        scrolled.push(agr::terminal::cells_to_text(&cells).trim_end().to_string());
+    };
+
+    // Header on row 1, footer on row 6, body scrolls in rows 2-5
+    buf.process("HEADER\x1b[6;1HFOOTER\x1b[2;5r\x1b[2;1H", Some(&mut cb));
+    for i in 1..=6 {
+        buf.process(&format!("line {}\r\n", i), Some(&mut cb));
+    }
+    // Explicit scroll up pushes one more body line out of the region
+    buf.process("\x1b[S", Some(&mut cb));
+    // Reverse index at the region top pulls the body down, not the header
+    buf.process("\x1b[2;1H\x1bM", Some(&mut cb));
+
+    assert_eq!(scrolled, vec!["line 1", "line 2", "line 3", "line 4"]);
+    assert_eq!(buf.scroll_region(), (1, 4));
+    assert_eq!(buf.to_string(), "HEADER\n\nline 5\nline 6\n\nFOOTER");
+    // Inner regions never feed the scrollback
+    assert!(buf.scrollback_lines().is_empty());
+}
@@ -0,0 +1,72 @@
+//! Tab stop tests (HT, HTS, TBC).
+
+use agr::terminal::TerminalBuffer;
+
+#[test]
+fn tab_uses_default_stops_every_eight_columns() {
+    let mut buf = TerminalBuffer::new(40, 5);
+    buf.process("A\tB\tC", None);
+    assert_eq!(buf.to_string(), "A       B       C");
+    assert_eq!(buf.cursor_col(), 17);
+}
+
+#[test]
+fn hts_sets_custom_stop() {
+    let mut buf = TerminalBuffer::new(40, 5);
+    // Set a stop at column 4, then tab to it from the start of the line
+    buf.process("\x1b[5G\x1bH\r\tX", None);
+    assert_eq!(buf.to_string(), "    X");
+}
+
+#[test]
+fn tbc_clears_stop_at_cursor() {
+    let mut buf = TerminalBuffer::new(40, 5);
+    buf.process("\x1b[9G\x1b[g\r\tX", None);
+    assert_eq!(buf.to_string(), format!("{}X", " ".repeat(16)));
+}
+
+#[test]
+fn tbc_3_clears_all_stops() {
+    let mut buf = TerminalBuffer::new(40, 5);
+    buf.process("\x1b[3g\t", None);
+    assert_eq!(buf.cursor_col(), 39);
+    buf.process("X", None);
+    assert_eq!(buf.to_string(), format!("{}X", " ".repeat(39)));
+}
+
+#[test]
+fn custom_stops_align_table_columns() {
+    let mut buf = TerminalBuffer::new(40, 5);
+    buf.process("\x1b[3g\x1b[4G\x1bH\x1b[13G\x1bH\r", None);
+    buf.process("id\tname\tstatus\r\n1\tagent\tok", None);
+    assert_eq!(buf.to_string(), "id name     status\n1  agent    ok");
+}
+
+#[test]
+fn tab_stops_at_last_column() {
+    let mut buf = TerminalBuffer::new(10, 5);
+    buf.process("\t\t\tX", None);
+    assert_eq!(buf.to_string(), "         X");
+}
+
+#[test]
+fn resize_keeps_custom_stops_and_defaults_new_columns() {
+    let mut buf = TerminalBuffer::new(10, 5);
+    buf.process("\x1b[3g\x1b[4G\x1bH\r", None);
+    buf.resize(20, 5);
+
+    buf.process("\t", None);
+    assert_eq!(buf.cursor_col(), 3);
+    // Column 8 was cleared before the resize; column 16 is new
+    buf.process("\t", None);
+    assert_eq!(buf.cursor_col(), 16);
+}
+
+#[test]
+fn resize_narrower_drops_stops_past_the_edge() {
+    let mut buf = TerminalBuffer::new(40, 5);
+    buf.resize(12, 5);
+    buf.resize(40, 5);
+    buf.process("\t\t", None);
+    assert_eq!(buf.cursor_col(), 16);
+}
@@ -64,10 +64,10 @@ fn dec_cursor_visibility_is_ignored() {
 }
 
 #[test]
-fn dec_alternate_screen_is_ignored() {
+fn dec_alternate_screen_content_is_discarded_on_exit() {
     let mut buf = TerminalBuffer::new(80, 24);
     buf.process("\x1b[?1049hContent\x1b[?1049l", None);
-    assert_eq!(buf.to_string(), "Content");
+    assert_eq!(buf.to_string(), "");
 }
 
 #[test]
@@ -212,3 +212,93 @@ fn scroll_region_fixture_test() {
     println!("Terminal size: {}x{}", cols, rows);
     println!("Events processed: {}", cast.events.len());
 }
+
+#[test]
+fn wide_character_to_string_has_no_extra_space() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("A\u{4e2d}B", None);
+    assert_eq!(buf.to_string(), "A\u{4e2d}B");
+}
+
+#[test]
+fn mixed_ascii_cjk_and_combining_mark_cursor_position() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    // "ab" (2) + CJK (2) + "e" (1) + combining acute (0) + "x" (1)
+    buf.process("ab\u{4e2d}e\u{0301}x", None);
+    assert_eq!(buf.cursor_col(), 6);
+
+    let row = buf.row(0).unwrap();
+    assert_eq!(row[2].char, '\u{4e2d}');
+    assert!(row[3].is_wide_continuation());
+    assert_eq!(row[4].char, 'e');
+    assert_eq!(row[5].char, 'x');
+}
+
+#[test]
+fn emoji_occupies_two_cells() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\u{1f680}!", None);
+    assert_eq!(buf.cursor_col(), 3);
+    assert_eq!(buf.to_string(), "\u{1f680}!");
+}
+
+#[test]
+fn overwriting_half_of_wide_character_blanks_other_half() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\u{4e2d}\r\x1b[1CX", None);
+    assert_eq!(buf.to_string(), " X");
+
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\u{4e2d}\rY", None);
+    assert_eq!(buf.to_string(), "Y");
+}
+
+#[test]
+fn bracketed_paste_renders_only_pasted_text() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process(
+        "\x1b[?2004h> \x1b[200~fn main() {}\x1b[201~\r\n\x1b[?2004l",
+        None,
+    );
+    assert_eq!(buf.to_string(), "> fn main() {}");
+    assert_eq!(buf.cursor_row(), 1);
+}
+
+#[test]
+fn bracketed_paste_markers_split_across_events() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b[20", None);
+    buf.process("0~pasted\x1b", None);
+    buf.process("[201~ ok", None);
+    assert_eq!(buf.to_string(), "pasted ok");
+}
+
+#[test]
+fn focus_reporting_toggles_are_consumed() {
+    let mut buf = TerminalBuffer::new(80, 24);
+    buf.process("\x1b[?1004hready\x1b[?1004l", None);
+    assert_eq!(buf.to_string(), "ready");
+    assert_eq!(buf.cursor_col(), 5);
+}
+
+#[test]
+fn line_text_returns_plain_row_text() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("first\r\n\x1b[1;31mred\x1b[0m text   \r\n", None);
+    assert_eq!(buf.line_text(0).as_deref(), Some("first"));
+    assert_eq!(buf.line_text(1).as_deref(), Some("red text"));
+    assert_eq!(buf.line_text(2).as_deref(), Some(""));
+}
+
+#[test]
+fn line_text_skips_wide_continuations() {
+    let mut buf = TerminalBuffer::new(20, 5);
+    buf.process("日本 ok", None);
+    assert_eq!(buf.line_text(0).as_deref(), Some("日本 ok"));
+}
+
+#[test]
+fn line_text_out_of_range_is_none() {
+    let buf = TerminalBuffer::new(20, 5);
+    assert_eq!(buf.line_text(5), None);
+}
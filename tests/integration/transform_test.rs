@@ -651,6 +651,321 @@ fn transform_cli_threshold_overrides_header() {
     );
 }
 
+// ============================================================================
+// Speed Tests
+// ============================================================================
+
+#[test]
+fn optimize_speed_halves_cumulative_times() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "speed.cast", sample_cast_with_long_pauses());
+    let original = AsciicastFile::parse(&cast_path).unwrap();
+
+    let (stdout, stderr, exit_code) =
+        run_agr(&["optimize", "--speed", "2.0", cast_path.to_str().unwrap()]);
+
+    assert_eq!(
+        exit_code, 0,
+        "Speed change should succeed. stderr: {}",
+        stderr
+    );
+    assert!(stdout.contains("2x"), "stdout: {}", stdout);
+    let sped_up = AsciicastFile::parse(&cast_path).unwrap();
+    for (before, after) in original
+        .cumulative_times()
+        .iter()
+        .zip(sped_up.cumulative_times())
+    {
+        assert!((after - before / 2.0).abs() < 0.001);
+    }
+}
+
+#[test]
+fn optimize_speed_one_reports_no_op() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "noop.cast", sample_cast_with_long_pauses());
+
+    let (stdout, stderr, exit_code) =
+        run_agr(&["optimize", "--speed", "1.0", cast_path.to_str().unwrap()]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("1x speed"), "stdout: {}", stdout);
+    assert!(!stdout.contains("threshold"), "stdout: {}", stdout);
+}
+
+#[test]
+fn optimize_speed_runs_after_silence_removal() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "both.cast", sample_cast_with_long_pauses());
+
+    let (_stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--remove-silence=2.0",
+        "--speed",
+        "2.0",
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    let transformed = AsciicastFile::parse(&cast_path).unwrap();
+    let longest = transformed
+        .events
+        .iter()
+        .map(|e| e.time)
+        .fold(0.0f64, f64::max);
+    assert!((longest - 1.0).abs() < 0.001, "longest gap {}", longest);
+}
+
+#[test]
+fn optimize_speed_rejects_non_positive_factor() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "bad.cast", sample_cast_with_long_pauses());
+    let original = std::fs::read_to_string(&cast_path).unwrap();
+
+    for factor in ["--speed=0", "--speed=-2"] {
+        let (_stdout, stderr, exit_code) =
+            run_agr(&["optimize", factor, cast_path.to_str().unwrap()]);
+
+        assert_ne!(exit_code, 0, "{} should fail", factor);
+        assert!(stderr.contains("positive"), "stderr: {}", stderr);
+    }
+    assert_eq!(std::fs::read_to_string(&cast_path).unwrap(), original);
+}
+
+// ============================================================================
+// Redaction Tests
+// ============================================================================
+
+#[test]
+fn optimize_redact_scrubs_secrets_and_keeps_timing() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = r#"{"version":3,"term":{"cols":80,"rows":24}}
+[0.5,"o","$ env\r\n"]
+[10.0,"o","OPENAI_API_KEY=sk-proj-0123456789abcdefXYZ\r\n"]
+[0.2,"o","$ "]"#;
+    let cast_path = create_cast_file(&temp_dir, "secret.cast", content);
+    let output_path = temp_dir.path().join("shareable.cast");
+
+    let (stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--redact",
+        "--output",
+        output_path.to_str().unwrap(),
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(stdout.contains("Redacted 1 secret"), "stdout: {}", stdout);
+    let original = AsciicastFile::parse(&cast_path).unwrap();
+    let redacted = AsciicastFile::parse(&output_path).unwrap();
+    assert_eq!(redacted.events[1].data, "OPENAI_API_KEY=***REDACTED***\r\n");
+    assert_eq!(redacted.cumulative_times(), original.cumulative_times());
+}
+
+// ============================================================================
+// Trim Tests
+// ============================================================================
+
+/// Events at 1s, 2s, 3s, 13s and 14s
+fn sample_cast_for_trimming() -> &'static str {
+    r#"{"version":3,"term":{"cols":80,"rows":24}}
+[1.0,"o","one\r\n"]
+[1.0,"o","two\r\n"]
+[1.0,"o","three\r\n"]
+[10.0,"o","four\r\n"]
+[1.0,"o","five\r\n"]"#
+}
+
+fn event_data(cast: &AsciicastFile) -> Vec<&str> {
+    cast.events.iter().map(|e| e.data.as_str()).collect()
+}
+
+#[test]
+fn optimize_trim_only_keeps_range_and_backs_up() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "trim.cast", sample_cast_for_trimming());
+
+    let (stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--from",
+        "0:02",
+        "--to",
+        "13",
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stdout.contains("Trimmed to 00:02-00:13"),
+        "stdout: {}",
+        stdout
+    );
+    let trimmed = AsciicastFile::parse(&cast_path).unwrap();
+    assert_eq!(
+        event_data(&trimmed),
+        vec!["two\r\n", "three\r\n", "four\r\n"]
+    );
+    assert_eq!(trimmed.cumulative_times(), vec![0.0, 1.0, 11.0]);
+
+    let backup = temp_dir.path().join("trim.cast.bak");
+    assert_eq!(
+        fs::read_to_string(backup).unwrap(),
+        sample_cast_for_trimming()
+    );
+}
+
+#[test]
+fn optimize_without_trim_keeps_every_event() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "full.cast", sample_cast_for_trimming());
+
+    let (stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--remove-silence=2",
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(!stdout.contains("Trimmed"), "stdout: {}", stdout);
+    let optimized = AsciicastFile::parse(&cast_path).unwrap();
+    assert_eq!(optimized.events.len(), 5);
+    assert!((optimized.duration() - 6.0).abs() < 0.001);
+    // Every in-place edit is backed up, not just trimming
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("full.cast.bak")).unwrap(),
+        sample_cast_for_trimming()
+    );
+}
+
+#[test]
+fn optimize_trims_before_removing_silence() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "both.cast", sample_cast_for_trimming());
+    let output_path = temp_dir.path().join("both-out.cast");
+
+    let (stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--from",
+        "2",
+        "--remove-silence=2",
+        "--output",
+        output_path.to_str().unwrap(),
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stdout.contains("Trimmed to 00:02-00:14"),
+        "stdout: {}",
+        stdout
+    );
+    let optimized = AsciicastFile::parse(&output_path).unwrap();
+    assert_eq!(optimized.events.len(), 4);
+    // 12s after trimming, with the 10s gap capped at 2s
+    assert_eq!(optimized.cumulative_times(), vec![0.0, 1.0, 3.0, 4.0]);
+    // The original is untouched and needs no backup
+    assert_eq!(
+        fs::read_to_string(&cast_path).unwrap(),
+        sample_cast_for_trimming()
+    );
+    assert!(!temp_dir.path().join("both.cast.bak").exists());
+}
+
+#[test]
+fn optimize_trim_clamps_past_the_end_with_warning() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "clamp.cast", sample_cast_for_trimming());
+
+    let (stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--from",
+        "3",
+        "--to",
+        "5:00",
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stderr.contains("Warning: --to 05:00 is past the end of the recording (00:14)"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(
+        stdout.contains("Trimmed to 00:03-00:14"),
+        "stdout: {}",
+        stdout
+    );
+    let trimmed = AsciicastFile::parse(&cast_path).unwrap();
+    assert_eq!(
+        event_data(&trimmed),
+        vec!["three\r\n", "four\r\n", "five\r\n"]
+    );
+}
+
+#[test]
+fn optimize_trim_rejects_from_after_to() {
+    let temp_dir = TempDir::new().unwrap();
+    let cast_path = create_cast_file(&temp_dir, "bad.cast", sample_cast_for_trimming());
+
+    let (_stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--from",
+        "10",
+        "--to",
+        "5",
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("must be before --to"), "stderr: {}", stderr);
+    assert_eq!(
+        fs::read_to_string(&cast_path).unwrap(),
+        sample_cast_for_trimming()
+    );
+}
+
+// ============================================================================
+// Resize Deduplication Tests
+// ============================================================================
+
+#[test]
+fn optimize_dedupe_resizes_keeps_distinct_sizes_and_duration() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = r#"{"version":3,"term":{"cols":80,"rows":24}}
+[0.5,"r","100x30"]
+[0.25,"o","$ ls\r\n"]
+[1.0,"r","100x30"]
+[0.5,"r","120x40"]
+[0.2,"o","$ "]"#;
+    let cast_path = create_cast_file(&temp_dir, "resizes.cast", content);
+    let output_path = temp_dir.path().join("deduped.cast");
+
+    let (stdout, stderr, exit_code) = run_agr(&[
+        "optimize",
+        "--dedupe-resizes",
+        "--output",
+        output_path.to_str().unwrap(),
+        cast_path.to_str().unwrap(),
+    ]);
+
+    assert_eq!(exit_code, 0, "stderr: {}", stderr);
+    assert!(
+        stdout.contains("Removed 1 redundant resize event(s)"),
+        "stdout: {}",
+        stdout
+    );
+    let original = AsciicastFile::parse(&cast_path).unwrap();
+    let deduped = AsciicastFile::parse(&output_path).unwrap();
+    let sizes: Vec<_> = deduped
+        .events
+        .iter()
+        .filter_map(|e| e.parse_resize())
+        .collect();
+    assert_eq!(sizes, vec![(100, 30), (120, 40)]);
+    assert!((deduped.duration() - original.duration()).abs() < 1e-9);
+}
+
 // ============================================================================
 // CLI Help Tests
 // ============================================================================
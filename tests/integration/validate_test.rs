@@ -0,0 +1,81 @@
+//! Integration tests for `agr validate` (CLI)
+
+use std::process::Command;
+use tempfile::TempDir;
+
+use crate::helpers::fixtures_dir;
+
+/// Helper to run agr CLI from the fixtures directory and capture output
+fn run_agr(args: &[&str]) -> (String, String, i32) {
+    let home = TempDir::new().unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_agr"))
+        .args(args)
+        .current_dir(fixtures_dir())
+        .env("HOME", home.path())
+        .env("NO_COLOR", "1")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE")
+        .output()
+        .expect("Failed to execute agr");
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    let exit_code = output.status.code().unwrap_or(-1);
+
+    (stdout, stderr, exit_code)
+}
+
+fn snapshot_output(args: &[&str]) -> (String, i32) {
+    let (stdout, stderr, exit_code) = run_agr(args);
+    let output = format!(
+        "=== agr {} ===\nExit code: {}\n\n--- stdout ---\n{}\n--- stderr ---\n{}",
+        args.join(" "),
+        exit_code,
+        stdout,
+        stderr
+    );
+    (output, exit_code)
+}
+
+#[test]
+fn snapshot_validate_good_file() {
+    let (output, exit_code) = snapshot_output(&["validate", "sample.cast"]);
+    assert_eq!(exit_code, 0);
+    insta::assert_snapshot!("validate_good_file", output);
+}
+
+#[test]
+fn snapshot_validate_malformed_file() {
+    let (output, exit_code) = snapshot_output(&["validate", "sample.cast", "malformed.cast"]);
+    assert_eq!(exit_code, 1);
+    insta::assert_snapshot!("validate_malformed_file", output);
+}
+
+#[test]
+fn snapshot_validate_strict_warns_on_zero_duration() {
+    let (output, exit_code) = snapshot_output(&["validate", "--strict", "zero_duration.cast"]);
+    assert_eq!(exit_code, 0);
+    insta::assert_snapshot!("validate_strict_zero_duration", output);
+}
+
+#[test]
+fn validate_zero_duration_is_silent_without_strict() {
+    let (stdout, _stderr, exit_code) = run_agr(&["validate", "zero_duration.cast"]);
+    assert_eq!(exit_code, 0);
+    assert!(!stdout.contains("warning"));
+}
+
+#[test]
+fn validate_missing_file_fails() {
+    let (stdout, stderr, exit_code) = run_agr(&["validate", "does-not-exist.cast"]);
+    assert_eq!(exit_code, 1);
+    assert!(stdout.contains("FAIL  does-not-exist.cast"));
+    assert!(stderr.contains("1 of 1 files failed validation"));
+}
+
+#[test]
+fn validate_strict_rejects_unknown_event_codes() {
+    let (stdout, _stderr, exit_code) = run_agr(&["validate", "--strict", "malformed.cast"]);
+    assert_eq!(exit_code, 1);
+    assert!(stdout.contains("line 3: Unknown event type: z"));
+}